@@ -0,0 +1,46 @@
+//! Render a plot straight into an HTML canvas
+//!
+//! Build for the browser and generate the JavaScript bindings:
+//!
+//! ```sh
+//! cargo build --example wasm_canvas --no-default-features --features wasm \
+//!     --target wasm32-unknown-unknown
+//! wasm-bindgen --target web --out-dir examples/wasm/pkg \
+//!     target/wasm32-unknown-unknown/debug/examples/wasm_canvas.wasm
+//! ```
+//!
+//! Then serve `examples/wasm/` with any static file server and open
+//! `index.html`. Browsers have no system fonts for ruviz to find, so the
+//! example registers the bundled Noto Sans before rendering.
+
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    use ruviz::prelude::*;
+    use wasm_bindgen::JsCast;
+
+    ruviz::render::register_font_bytes(
+        include_bytes!("../../crates/ruviz-web/assets/NotoSans-Regular.ttf").to_vec(),
+    )
+    .expect("bundled font should register");
+
+    let canvas: web_sys::HtmlCanvasElement = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id("plot"))
+        .and_then(|element| element.dyn_into().ok())
+        .expect("index.html should contain <canvas id=\"plot\">");
+
+    let x: Vec<f64> = (0..200).map(|i| i as f64 * 0.05).collect();
+    let y: Vec<f64> = x.iter().map(|v| v.sin()).collect();
+    Plot::new()
+        .title("Rendered into a canvas")
+        .xlabel("x")
+        .ylabel("sin(x)")
+        .line(&x, &y)
+        .render_to_canvas(&canvas)
+        .expect("plot should render into the canvas");
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    println!("This example runs in the browser; see the build steps at the top of the file.");
+}
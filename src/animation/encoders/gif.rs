@@ -3,7 +3,7 @@
 //! Provides animated GIF encoding using the `gif` crate.
 
 use std::fs::File;
-use std::io::BufWriter;
+use std::io::{BufWriter, Write};
 use std::path::Path;
 
 use gif::{Encoder as GifEncoderInner, Frame, Repeat};
@@ -190,8 +190,13 @@ impl Encoder for GifEncoder {
     }
 
     fn finalize(self: Box<Self>) -> Result<()> {
-        // Encoder is finalized when dropped
-        drop(self.encoder);
+        // Write the trailer and flush explicitly so errors aren't lost on drop
+        if let Some(encoder) = self.encoder {
+            encoder
+                .into_inner()
+                .map_err(gif_error_to_plotting_error)?
+                .flush()?;
+        }
         Ok(())
     }
 
@@ -8,6 +8,7 @@ pub mod scale;
 pub mod secondary;
 pub mod tick_layout;
 pub mod ticks;
+pub mod time;
 
 pub use inset::{ConnectorStyle, InsetAxes};
 pub use polar::PolarAxes;
@@ -16,6 +17,8 @@ pub use scale::{AxisScale, LinearScale, LogScale, Scale, SymLogScale};
 pub use secondary::{AxisType, DualAxes, SecondaryAxis};
 pub use tick_layout::TickLayout;
 pub use ticks::{
-    generate_log_minor_ticks, generate_log_ticks, generate_minor_ticks, generate_symlog_ticks,
-    generate_ticks, generate_ticks_for_scale,
+    generate_integer_ticks_for_scale, generate_log_minor_ticks, generate_log_ticks,
+    generate_minor_ticks, generate_symlog_ticks, generate_ticks, generate_ticks_for_scale,
+    integer_ticks,
 };
+pub use time::{TimeFormat, format_time_tick_labels, format_timestamp, generate_time_ticks};
@@ -2,6 +2,8 @@
 //!
 //! Provides linear and logarithmic scale transformations for axis mapping.
 
+use super::time::TimeFormat;
+
 /// Scale transformation trait
 pub trait Scale {
     /// Transform a value from data space to normalized [0, 1] space
@@ -196,6 +198,13 @@ pub enum AxisScale {
         /// Linear threshold (values within ±linthresh are scaled linearly)
         linthresh: f64,
     },
+    /// Date/time scale
+    /// Values are Unix timestamps in seconds (UTC), mapped linearly; ticks land
+    /// on calendar boundaries and are labelled with `format`
+    Time {
+        /// Tick label format
+        format: TimeFormat,
+    },
 }
 
 #[inline]
@@ -236,6 +245,32 @@ pub(crate) fn linear_normalized_position_with_range(
     }
 }
 
+/// Two-slope normalization (matplotlib's `TwoSlopeNorm`).
+///
+/// Maps `center` to 0.5 and scales the values on each side of it
+/// independently, so a diverging colormap's midpoint lands on `center` even
+/// when the range is asymmetric. Falls back to `scale`'s plain normalization
+/// when `center` is not strictly inside the range.
+pub(crate) fn centered_normalized_position(
+    scale: &AxisScale,
+    value: f64,
+    min: f64,
+    max: f64,
+    center: f64,
+) -> f64 {
+    let position = scale.normalized_position(value, min, max);
+    let center_position = scale.normalized_position(center, min, max);
+    if !(center_position > 0.0 && center_position < 1.0) {
+        return position;
+    }
+
+    if position <= center_position {
+        0.5 * position / center_position
+    } else {
+        0.5 + 0.5 * (position - center_position) / (1.0 - center_position)
+    }
+}
+
 #[inline]
 fn linear_inverse_normalized_position(normalized: f64, min: f64, max: f64) -> f64 {
     let range = max - min;
@@ -276,13 +311,18 @@ impl AxisScale {
         AxisScale::SymLog { linthresh }
     }
 
+    /// Create a date/time scale for Unix timestamps with the given tick label format
+    pub fn time(format: TimeFormat) -> Self {
+        AxisScale::Time { format }
+    }
+
     /// Normalize a value into `[0, 1]` for the provided range.
     ///
     /// This preserves range direction, so reversed ranges produce inverted
     /// normalized coordinates.
     pub fn normalized_position(&self, value: f64, min: f64, max: f64) -> f64 {
         match self {
-            AxisScale::Linear => {
+            AxisScale::Linear | AxisScale::Time { .. } => {
                 let range = max - min;
                 if linear_range_is_degenerate(range) {
                     0.5
@@ -341,7 +381,9 @@ impl AxisScale {
     /// `min` and `1.0` maps to `max` even when the range is reversed.
     pub fn inverse_normalized_position(&self, normalized: f64, min: f64, max: f64) -> f64 {
         match self {
-            AxisScale::Linear => linear_inverse_normalized_position(normalized, min, max),
+            AxisScale::Linear | AxisScale::Time { .. } => {
+                linear_inverse_normalized_position(normalized, min, max)
+            }
             AxisScale::Log => {
                 if min.is_finite() && min > 0.0 && max.is_finite() && max > 0.0 {
                     if normalized == 0.0 {
@@ -391,7 +433,7 @@ impl AxisScale {
     /// Create a scale instance for the given data range
     pub fn create_scale(&self, min: f64, max: f64) -> Box<dyn Scale> {
         match self {
-            AxisScale::Linear => Box::new(LinearScale::new(min, max)),
+            AxisScale::Linear | AxisScale::Time { .. } => Box::new(LinearScale::new(min, max)),
             AxisScale::Log => {
                 let (min, max) = log_normalization_bounds(min, max);
                 Box::new(LogScale::new(min, max))
@@ -403,7 +445,7 @@ impl AxisScale {
     /// Check if this scale is valid for the given data range
     pub fn validate_range(&self, min: f64, max: f64) -> Result<(), String> {
         match self {
-            AxisScale::Linear => Ok(()),
+            AxisScale::Linear | AxisScale::Time { .. } => Ok(()),
             AxisScale::Log => {
                 if min <= 0.0 || max <= 0.0 {
                     Err("Logarithmic scale requires positive values. Use SymLog for data with zero or negative values.".to_string())
@@ -4,6 +4,7 @@
 //! tick marks, and tick labels to ensure perfect alignment.
 
 use super::{AxisScale, generate_ticks_for_scale};
+use crate::core::TickLabelFormatter;
 
 /// Complete tick layout for an axis
 ///
@@ -114,8 +115,51 @@ impl TickLayout {
         }
     }
 
+    /// Drop non-integer ticks when `enabled`, for axes showing counts
+    ///
+    /// Apply before [`with_label_formatter`](Self::with_label_formatter).
+    pub fn with_integer_ticks(mut self, enabled: bool) -> Self {
+        if !enabled {
+            return self;
+        }
+        let mut data_positions = Vec::with_capacity(self.data_positions.len());
+        let mut pixel_positions = Vec::with_capacity(self.data_positions.len());
+        let mut labels = Vec::with_capacity(self.data_positions.len());
+        for ((&position, &pixel), label) in self
+            .data_positions
+            .iter()
+            .zip(&self.pixel_positions)
+            .zip(self.labels.drain(..))
+        {
+            let Some(integer) = super::ticks::integer_tick(position) else {
+                continue;
+            };
+            if data_positions.last() != Some(&integer) {
+                data_positions.push(integer);
+                pixel_positions.push(pixel);
+                labels.push(label);
+            }
+        }
+        self.data_positions = data_positions;
+        self.pixel_positions = pixel_positions;
+        self.labels = labels;
+        self
+    }
+
+    /// Replace the labels using a custom formatter, if one is provided
+    pub fn with_label_formatter(mut self, formatter: Option<&TickLabelFormatter>) -> Self {
+        if let Some(formatter) = formatter {
+            self.labels = formatter.format_ticks(&self.data_positions);
+        }
+        self
+    }
+
     /// Format tick labels with appropriate precision
     fn format_labels(positions: &[f64], scale: &AxisScale) -> Vec<String> {
+        if let AxisScale::Time { format } = scale {
+            return super::format_time_tick_labels(positions, format);
+        }
+
         positions
             .iter()
             .map(|&pos| Self::format_tick_value(pos, scale))
@@ -69,9 +69,44 @@ pub fn generate_ticks_for_scale(
         AxisScale::SymLog { linthresh } => {
             generate_symlog_ticks(min, max, *linthresh, target_count)
         }
+        AxisScale::Time { .. } => super::generate_time_ticks(min, max, target_count),
     }
 }
 
+/// Keep only integer-valued ticks, for axes showing counts
+///
+/// Candidates within floating-point noise of an integer are rounded and
+/// deduplicated; the rest are dropped. Nice-number steps below one divide one
+/// evenly, so the remaining linear ticks stay evenly spaced. Ranges that
+/// contain no integer yield no ticks.
+pub fn integer_ticks(ticks: &[f64]) -> Vec<f64> {
+    let mut integers: Vec<f64> = ticks
+        .iter()
+        .filter_map(|&tick| integer_tick(tick))
+        .collect();
+    integers.dedup_by(|left, right| left == right);
+    integers
+}
+
+/// Round `tick` to the integer it approximates, or `None` if it is fractional
+pub(crate) fn integer_tick(tick: f64) -> Option<f64> {
+    let rounded = tick.round();
+    (tick.is_finite() && (tick - rounded).abs() <= 1e-9 * rounded.abs().max(1.0)).then_some(rounded)
+}
+
+/// Generate integer-only tick positions for a specific axis scale type
+///
+/// Same as [`generate_ticks_for_scale`] with the result passed through
+/// [`integer_ticks`].
+pub fn generate_integer_ticks_for_scale(
+    min: f64,
+    max: f64,
+    target_count: usize,
+    scale: &AxisScale,
+) -> Vec<f64> {
+    integer_ticks(&generate_ticks_for_scale(min, max, target_count, scale))
+}
+
 /// Generate logarithmic tick positions at powers of 10
 ///
 /// # Arguments
@@ -439,4 +474,21 @@ mod tests {
         let symlog_ticks = generate_ticks_for_scale(-100.0, 100.0, 10, &AxisScale::symlog(1.0));
         assert!(symlog_ticks.contains(&0.0) || symlog_ticks.iter().any(|&t| t.abs() < 0.1));
     }
+
+    #[test]
+    fn test_integer_ticks_drop_fractional_candidates() {
+        // A 0..3 range picks a 0.5 step; only the whole numbers survive
+        let ticks = generate_integer_ticks_for_scale(0.0, 3.0, 8, &AxisScale::Linear);
+        assert_eq!(ticks, vec![0.0, 1.0, 2.0, 3.0]);
+
+        let log_ticks = generate_integer_ticks_for_scale(0.1, 100.0, 10, &AxisScale::Log);
+        assert!(log_ticks.iter().all(|tick| tick.fract() == 0.0));
+        assert!(log_ticks.contains(&1.0) && log_ticks.contains(&100.0));
+
+        assert_eq!(
+            integer_ticks(&[0.9999999999999, 1.5, 2.0000000000001]),
+            vec![1.0, 2.0]
+        );
+        assert!(generate_integer_ticks_for_scale(0.2, 0.8, 8, &AxisScale::Linear).is_empty());
+    }
 }
@@ -0,0 +1,463 @@
+//! Date/time axis support
+//!
+//! Time axes interpret data values as Unix timestamps in seconds (UTC). Ticks are
+//! placed on calendar boundaries (whole seconds, minutes, hours, days, months, or
+//! years) and labelled with a strftime-style pattern, without depending on a
+//! date/time crate.
+
+use std::fmt;
+use std::sync::Arc;
+
+const SECONDS_PER_MINUTE: f64 = 60.0;
+const SECONDS_PER_HOUR: f64 = 3_600.0;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+const SECONDS_PER_WEEK: f64 = 7.0 * SECONDS_PER_DAY;
+const AVERAGE_SECONDS_PER_MONTH: f64 = 30.436_875 * SECONDS_PER_DAY;
+/// 1970-01-05 was the first Monday after the Unix epoch; weekly ticks align to it.
+const FIRST_MONDAY_OFFSET: f64 = 4.0 * SECONDS_PER_DAY;
+
+/// Fixed-length tick intervals in seconds, paired with their alignment offset.
+const FIXED_INTERVALS: &[(f64, f64)] = &[
+    (1.0, 0.0),
+    (2.0, 0.0),
+    (5.0, 0.0),
+    (10.0, 0.0),
+    (15.0, 0.0),
+    (30.0, 0.0),
+    (SECONDS_PER_MINUTE, 0.0),
+    (2.0 * SECONDS_PER_MINUTE, 0.0),
+    (5.0 * SECONDS_PER_MINUTE, 0.0),
+    (10.0 * SECONDS_PER_MINUTE, 0.0),
+    (15.0 * SECONDS_PER_MINUTE, 0.0),
+    (30.0 * SECONDS_PER_MINUTE, 0.0),
+    (SECONDS_PER_HOUR, 0.0),
+    (2.0 * SECONDS_PER_HOUR, 0.0),
+    (3.0 * SECONDS_PER_HOUR, 0.0),
+    (6.0 * SECONDS_PER_HOUR, 0.0),
+    (12.0 * SECONDS_PER_HOUR, 0.0),
+    (SECONDS_PER_DAY, 0.0),
+    (2.0 * SECONDS_PER_DAY, 0.0),
+    (SECONDS_PER_WEEK, FIRST_MONDAY_OFFSET),
+    (2.0 * SECONDS_PER_WEEK, FIRST_MONDAY_OFFSET),
+];
+
+/// Month-based tick intervals; ticks land on the first day of the month.
+const MONTH_INTERVALS: &[i64] = &[1, 2, 3, 6];
+
+const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAY_ABBREVIATIONS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Tick label format for time axes
+///
+/// # Example
+///
+/// ```rust
+/// use ruviz::axes::TimeFormat;
+///
+/// let format = TimeFormat::new("%Y-%m-%d %H:%M");
+/// assert_eq!(format.format_timestamp(1_700_000_000.0), "2023-11-14 22:13");
+///
+/// let custom = TimeFormat::custom(|ts| format!("t+{}", ts as i64 % 60));
+/// assert_eq!(custom.format_timestamp(1_700_000_000.0), "t+20");
+/// ```
+#[derive(Clone, Default)]
+pub enum TimeFormat {
+    /// Pick a pattern from the spacing between ticks (default)
+    #[default]
+    Auto,
+    /// strftime-style pattern
+    ///
+    /// Supported specifiers: `%Y`, `%y`, `%m`, `%d`, `%e`, `%H`, `%I`, `%p`, `%M`,
+    /// `%S`, `%f` (milliseconds), `%j`, `%b`, `%B`, `%a`, and `%%`. Unknown
+    /// specifiers are emitted verbatim.
+    Pattern(String),
+    /// User-provided formatter, e.g. a closure backed by `chrono`
+    Custom(Arc<dyn Fn(f64) -> String + Send + Sync>),
+}
+
+impl TimeFormat {
+    /// Pick a pattern automatically from the tick spacing
+    pub fn auto() -> Self {
+        TimeFormat::Auto
+    }
+
+    /// Format ticks with a strftime-style pattern such as `"%Y-%m-%d"`
+    pub fn new(pattern: impl Into<String>) -> Self {
+        TimeFormat::Pattern(pattern.into())
+    }
+
+    /// Format ticks with a custom closure receiving the Unix timestamp in seconds
+    pub fn custom<F>(formatter: F) -> Self
+    where
+        F: Fn(f64) -> String + Send + Sync + 'static,
+    {
+        TimeFormat::Custom(Arc::new(formatter))
+    }
+
+    /// Format a single Unix timestamp (seconds, UTC)
+    ///
+    /// [`TimeFormat::Auto`] uses `"%Y-%m-%d %H:%M:%S"` when no tick spacing is available.
+    pub fn format_timestamp(&self, timestamp: f64) -> String {
+        match self {
+            TimeFormat::Auto => format_timestamp(timestamp, "%Y-%m-%d %H:%M:%S"),
+            TimeFormat::Pattern(pattern) => format_timestamp(timestamp, pattern),
+            TimeFormat::Custom(formatter) => formatter(timestamp),
+        }
+    }
+}
+
+impl fmt::Debug for TimeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeFormat::Auto => f.write_str("Auto"),
+            TimeFormat::Pattern(pattern) => f.debug_tuple("Pattern").field(pattern).finish(),
+            TimeFormat::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl PartialEq for TimeFormat {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TimeFormat::Auto, TimeFormat::Auto) => true,
+            (TimeFormat::Pattern(left), TimeFormat::Pattern(right)) => left == right,
+            (TimeFormat::Custom(left), TimeFormat::Custom(right)) => Arc::ptr_eq(left, right),
+            _ => false,
+        }
+    }
+}
+
+/// Format tick values on a time axis
+///
+/// [`TimeFormat::Auto`] chooses the pattern from the spacing of `values`, so
+/// second-level ticks show `HH:MM:SS` while month-level ticks show `YYYY-MM`.
+pub fn format_time_tick_labels(values: &[f64], format: &TimeFormat) -> Vec<String> {
+    match format {
+        TimeFormat::Auto => {
+            let pattern = auto_pattern(values);
+            values
+                .iter()
+                .map(|&value| format_timestamp(value, pattern))
+                .collect()
+        }
+        _ => values
+            .iter()
+            .map(|&value| format.format_timestamp(value))
+            .collect(),
+    }
+}
+
+fn auto_pattern(values: &[f64]) -> &'static str {
+    let step = values
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).abs())
+        .filter(|step| *step > 0.0)
+        .fold(f64::INFINITY, f64::min);
+    if !step.is_finite() {
+        return "%Y-%m-%d %H:%M:%S";
+    }
+
+    let spans_days = match (values.first(), values.last()) {
+        (Some(&first), Some(&last)) => {
+            (first / SECONDS_PER_DAY).floor() != (last / SECONDS_PER_DAY).floor()
+        }
+        _ => false,
+    };
+
+    if step < 1.0 {
+        "%H:%M:%S.%f"
+    } else if step < SECONDS_PER_MINUTE {
+        if spans_days {
+            "%m-%d %H:%M:%S"
+        } else {
+            "%H:%M:%S"
+        }
+    } else if step < SECONDS_PER_DAY {
+        if spans_days { "%m-%d %H:%M" } else { "%H:%M" }
+    } else if step < 28.0 * SECONDS_PER_DAY {
+        "%Y-%m-%d"
+    } else if step < 365.0 * SECONDS_PER_DAY {
+        "%Y-%m"
+    } else {
+        "%Y"
+    }
+}
+
+/// Format a Unix timestamp (seconds, UTC) with a strftime-style pattern
+///
+/// Fractional seconds are rounded to the nearest millisecond. Non-finite
+/// timestamps are returned as their numeric representation.
+pub fn format_timestamp(timestamp: f64, pattern: &str) -> String {
+    if !timestamp.is_finite() {
+        return timestamp.to_string();
+    }
+
+    let total_millis = (timestamp * 1000.0).round() as i64;
+    let seconds = total_millis.div_euclid(1000);
+    let millis = total_millis.rem_euclid(1000);
+    let days = seconds.div_euclid(86_400);
+    let second_of_day = seconds.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+    let hour = second_of_day / 3_600;
+    let minute = second_of_day % 3_600 / 60;
+    let second = second_of_day % 60;
+
+    let mut output = String::with_capacity(pattern.len() + 8);
+    let mut chars = pattern.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '%' {
+            output.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some('Y') => output.push_str(&year.to_string()),
+            Some('y') => output.push_str(&format!("{:02}", year.rem_euclid(100))),
+            Some('m') => output.push_str(&format!("{month:02}")),
+            Some('d') => output.push_str(&format!("{day:02}")),
+            Some('e') => output.push_str(&format!("{day:>2}")),
+            Some('H') => output.push_str(&format!("{hour:02}")),
+            Some('I') => output.push_str(&format!("{:02}", (hour + 11) % 12 + 1)),
+            Some('p') => output.push_str(if hour < 12 { "AM" } else { "PM" }),
+            Some('M') => output.push_str(&format!("{minute:02}")),
+            Some('S') => output.push_str(&format!("{second:02}")),
+            Some('f') => output.push_str(&format!("{millis:03}")),
+            Some('j') => {
+                let day_of_year = days - days_from_civil(year, 1, 1) + 1;
+                output.push_str(&format!("{day_of_year:03}"));
+            }
+            Some('b') => output.push_str(MONTH_ABBREVIATIONS[(month - 1) as usize]),
+            Some('B') => output.push_str(MONTH_NAMES[(month - 1) as usize]),
+            Some('a') => {
+                output.push_str(WEEKDAY_ABBREVIATIONS[(days + 3).rem_euclid(7) as usize]);
+            }
+            Some('%') => output.push('%'),
+            Some(other) => {
+                output.push('%');
+                output.push(other);
+            }
+            None => output.push('%'),
+        }
+    }
+
+    output
+}
+
+/// Generate tick positions on calendar boundaries for a time axis
+///
+/// Picks the smallest interval from seconds up to years that yields at most
+/// `target_count` ticks (clamped to 3-7, since date labels are wider than
+/// numeric labels). Ranges shorter than one second fall back to
+/// [`generate_ticks`](super::generate_ticks).
+pub fn generate_time_ticks(min: f64, max: f64, target_count: usize) -> Vec<f64> {
+    if !min.is_finite() || !max.is_finite() {
+        return Vec::new();
+    }
+
+    let (min, max) = if min <= max { (min, max) } else { (max, min) };
+    let span = max - min;
+    if span < 1.0 {
+        return super::generate_ticks(min, max, target_count);
+    }
+
+    let max_ticks = target_count.clamp(3, 7);
+    let fits = |estimated: f64| estimated <= max_ticks as f64 + 1.0;
+
+    for &(step, offset) in FIXED_INTERVALS {
+        if !fits(span / step) {
+            continue;
+        }
+        let first = ((min - offset) / step).ceil() as i64;
+        let last = ((max - offset) / step).floor() as i64;
+        if last - first < max_ticks as i64 {
+            return (first..=last)
+                .map(|index| index as f64 * step + offset)
+                .collect();
+        }
+    }
+
+    for &step in MONTH_INTERVALS {
+        if !fits(span / (step as f64 * AVERAGE_SECONDS_PER_MONTH)) {
+            continue;
+        }
+        let ticks = month_ticks(min, max, step);
+        if ticks.len() <= max_ticks {
+            return ticks;
+        }
+    }
+
+    let span_years = span / (365.2425 * SECONDS_PER_DAY);
+    let mut magnitude = 1_i64;
+    loop {
+        for multiplier in [1, 2, 5] {
+            let step = multiplier * magnitude;
+            if !fits(span_years / step as f64) {
+                continue;
+            }
+            let ticks = month_ticks(min, max, step * 12);
+            if ticks.len() <= max_ticks {
+                return ticks;
+            }
+        }
+        match magnitude.checked_mul(10) {
+            Some(next) => magnitude = next,
+            None => return vec![min, max],
+        }
+    }
+}
+
+/// Ticks at the first of every `step_months`-th month (aligned to January) in `[min, max]`.
+fn month_ticks(min: f64, max: f64, step_months: i64) -> Vec<f64> {
+    let (year, month, _) = civil_from_days((min / SECONDS_PER_DAY).floor() as i64);
+    let mut month_index = (year * 12 + month - 1).div_euclid(step_months) * step_months;
+
+    let mut ticks = Vec::new();
+    loop {
+        let year = month_index.div_euclid(12);
+        let month = month_index.rem_euclid(12) + 1;
+        let timestamp = days_from_civil(year, month, 1) as f64 * SECONDS_PER_DAY;
+        if timestamp > max {
+            break;
+        }
+        if timestamp >= min {
+            ticks.push(timestamp);
+        }
+        month_index += step_months;
+    }
+    ticks
+}
+
+/// Convert days since 1970-01-01 into a proleptic Gregorian (year, month, day).
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Convert a proleptic Gregorian date into days since 1970-01-01.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = (month + 9) % 12;
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_date_round_trip() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_675), (2023, 11, 14));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        for days in [-800_000, -1, 0, 59, 11_016, 19_675, 2_932_896] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn test_format_timestamp_specifiers() {
+        let ts = 1_700_000_000.25;
+        assert_eq!(
+            format_timestamp(ts, "%Y-%m-%d %H:%M:%S.%f"),
+            "2023-11-14 22:13:20.250"
+        );
+        assert_eq!(format_timestamp(ts, "%a %e %b %y"), "Tue 14 Nov 23");
+        assert_eq!(
+            format_timestamp(ts, "%B %j %I%p 100%%"),
+            "November 318 10PM 100%"
+        );
+        assert_eq!(format_timestamp(ts, "%q"), "%q");
+        assert_eq!(
+            format_timestamp(-1.0, "%Y-%m-%d %H:%M:%S"),
+            "1969-12-31 23:59:59"
+        );
+    }
+
+    #[test]
+    fn test_time_ticks_pick_natural_intervals() {
+        let base = 1_700_000_000.0;
+
+        let seconds = generate_time_ticks(base, base + 40.0, 6);
+        assert_eq!(
+            seconds,
+            vec![base, base + 10.0, base + 20.0, base + 30.0, base + 40.0]
+        );
+
+        let hours = generate_time_ticks(base, base + 12.0 * 3_600.0, 6);
+        assert!(hours.iter().all(|tick| tick % 7_200.0 == 0.0));
+        assert_eq!(hours.len(), 6);
+
+        let days = generate_time_ticks(base, base + 5.0 * 86_400.0, 6);
+        assert!(days.iter().all(|tick| tick % 86_400.0 == 0.0));
+        assert_eq!(days.len(), 5);
+    }
+
+    #[test]
+    fn test_time_ticks_land_on_month_and_year_starts() {
+        let start = days_from_civil(2023, 1, 15) as f64 * SECONDS_PER_DAY;
+        let end = days_from_civil(2023, 12, 20) as f64 * SECONDS_PER_DAY;
+        let labels =
+            format_time_tick_labels(&generate_time_ticks(start, end, 6), &TimeFormat::Auto);
+        assert_eq!(
+            labels,
+            vec!["2023-03", "2023-05", "2023-07", "2023-09", "2023-11"]
+        );
+
+        let decades_end = days_from_civil(2024, 6, 1) as f64 * SECONDS_PER_DAY;
+        let years = generate_time_ticks(0.0, decades_end, 6);
+        let labels = format_time_tick_labels(&years, &TimeFormat::Auto);
+        assert_eq!(labels, vec!["1970", "1980", "1990", "2000", "2010", "2020"]);
+    }
+
+    #[test]
+    fn test_auto_format_follows_tick_spacing() {
+        let base = 1_700_006_400.0; // 2023-11-15 00:00:00
+        assert_eq!(
+            format_time_tick_labels(&[base, base + 30.0], &TimeFormat::Auto),
+            vec!["00:00:00", "00:00:30"]
+        );
+        assert_eq!(
+            format_time_tick_labels(&[base - 3_600.0, base], &TimeFormat::Auto),
+            vec!["11-14 23:00", "11-15 00:00"]
+        );
+        assert_eq!(
+            format_time_tick_labels(&[base, base + 86_400.0], &TimeFormat::new("%d/%m")),
+            vec!["15/11", "16/11"]
+        );
+    }
+}
@@ -43,6 +43,46 @@ pub enum TextVAlign {
     Bottom,
 }
 
+/// Coordinate system used to position an annotation
+///
+/// Fractional systems measure from the lower-left corner, so `(0, 0)` is the
+/// bottom-left and `(1, 1)` the top-right; values outside `0..=1` place the
+/// annotation beyond that edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoordSystem {
+    /// Data coordinates, following the axis limits and scales (default)
+    #[default]
+    Data,
+    /// Fractions of the plot area, like matplotlib's `transAxes`
+    Axes,
+    /// Fractions of the whole figure canvas, like matplotlib's `transFigure`;
+    /// annotations placed this way are never clipped to the plot area
+    Figure,
+}
+
+impl CoordSystem {
+    /// Pixel position of a fractional `(x, y)`, or `None` for data coordinates
+    pub(crate) fn fraction_to_pixels(
+        self,
+        x: f64,
+        y: f64,
+        plot_area: tiny_skia::Rect,
+        canvas_size: (f32, f32),
+    ) -> Option<(f32, f32)> {
+        let (left, bottom, width, height) = match self {
+            CoordSystem::Data => return None,
+            CoordSystem::Axes => (
+                plot_area.left(),
+                plot_area.bottom(),
+                plot_area.width(),
+                plot_area.height(),
+            ),
+            CoordSystem::Figure => (0.0, canvas_size.1, canvas_size.0, canvas_size.1),
+        };
+        Some((left + x as f32 * width, bottom - y as f32 * height))
+    }
+}
+
 /// Style configuration for text annotations
 #[derive(Debug, Clone)]
 pub struct TextStyle {
@@ -64,6 +104,8 @@ pub struct TextStyle {
     pub border_color: Option<Color>,
     /// Border width in points
     pub border_width: f32,
+    /// Corner radius of the background box in points (0 for square corners)
+    pub corner_radius: f32,
 }
 
 impl Default for TextStyle {
@@ -78,6 +120,7 @@ impl Default for TextStyle {
             padding: 2.0,
             border_color: None,
             border_width: 1.0,
+            corner_radius: 0.0,
         }
     }
 }
@@ -136,6 +179,29 @@ impl TextStyle {
         self.border_width = width;
         self
     }
+
+    /// Round the corners of the background box and border, in points
+    ///
+    /// Together with [`background`](Self::background),
+    /// [`padding`](Self::padding), and [`border`](Self::border) this gives a
+    /// box like matplotlib's `bbox=dict(boxstyle="round", ...)`, keeping
+    /// labels readable over busy data.
+    ///
+    /// ```rust
+    /// use ruviz::core::TextStyle;
+    /// use ruviz::render::Color;
+    ///
+    /// let style = TextStyle::new()
+    ///     .background(Color::WHITE)
+    ///     .padding(4.0)
+    ///     .border(Color::new(80, 80, 80), 0.8)
+    ///     .corner_radius(3.0);
+    /// assert_eq!(style.corner_radius, 3.0);
+    /// ```
+    pub fn corner_radius(mut self, radius: f32) -> Self {
+        self.corner_radius = radius;
+        self
+    }
 }
 
 /// Arrow head style
@@ -389,6 +455,26 @@ pub enum HatchPattern {
     Dots,
 }
 
+impl HatchPattern {
+    /// Perpendicular distance between neighbouring hatch strokes, in points.
+    pub(crate) const SPACING_PT: f32 = 6.0;
+    /// Hatch stroke width in points (matplotlib's `hatch.linewidth`).
+    pub(crate) const LINE_WIDTH_PT: f32 = 1.0;
+
+    /// Stroke angles in degrees counter-clockwise from +x; empty for [`HatchPattern::Dots`].
+    pub(crate) fn line_angles(self) -> &'static [f32] {
+        match self {
+            Self::Diagonal => &[45.0],
+            Self::BackDiagonal => &[135.0],
+            Self::Horizontal => &[0.0],
+            Self::Vertical => &[90.0],
+            Self::Cross => &[0.0, 90.0],
+            Self::DiagonalCross => &[45.0, 135.0],
+            Self::Dots => &[],
+        }
+    }
+}
+
 /// Annotation element that can be added to a plot
 ///
 /// Annotations are rendered after data series and can be used to
@@ -406,6 +492,10 @@ pub enum Annotation {
         text: String,
         /// Text style
         style: TextStyle,
+        /// Coordinate system of `x` and `y`
+        coords: CoordSystem,
+        /// Clip to the plot area; `None` clips only in data coordinates
+        clip: Option<bool>,
     },
 
     /// Arrow annotation between two points in data coordinates
@@ -420,6 +510,30 @@ pub enum Annotation {
         y2: f64,
         /// Arrow style
         style: ArrowStyle,
+        /// Coordinate system of both end points
+        coords: CoordSystem,
+        /// Clip to the plot area; `None` clips only in data coordinates
+        clip: Option<bool>,
+    },
+
+    /// Text offset from a data point with an arrow from the text box to it
+    Callout {
+        /// X coordinate of the annotated point in data space
+        x: f64,
+        /// Y coordinate of the annotated point in data space
+        y: f64,
+        /// Text content
+        text: String,
+        /// Text anchor offset from the point in points (positive y is up)
+        offset: (f32, f32),
+        /// Text style
+        text_style: TextStyle,
+        /// Arrow style
+        arrow_style: ArrowStyle,
+        /// Coordinate system of `x` and `y`
+        coords: CoordSystem,
+        /// Clip to the plot area; `None` clips only in data coordinates
+        clip: Option<bool>,
     },
 
     /// Horizontal line spanning the entire plot width
@@ -458,6 +572,10 @@ pub enum Annotation {
         height: f64,
         /// Shape style
         style: ShapeStyle,
+        /// Coordinate system of the position and size
+        coords: CoordSystem,
+        /// Clip to the plot area; `None` clips only in data coordinates
+        clip: Option<bool>,
     },
 
     /// Filled region between two curves
@@ -472,6 +590,9 @@ pub enum Annotation {
         style: FillStyle,
         /// Only fill where y1 > y2
         where_positive: bool,
+        /// Only fill where the mask is `true` (one entry per x value);
+        /// takes precedence over `where_positive`
+        where_mask: Option<Vec<bool>>,
     },
 
     /// Horizontal span (shaded vertical region)
@@ -503,6 +624,8 @@ impl Annotation {
             y,
             text: text.into(),
             style: TextStyle::default(),
+            coords: CoordSystem::Data,
+            clip: None,
         }
     }
 
@@ -513,6 +636,8 @@ impl Annotation {
             y,
             text: text.into(),
             style,
+            coords: CoordSystem::Data,
+            clip: None,
         }
     }
 
@@ -524,6 +649,8 @@ impl Annotation {
             x2,
             y2,
             style: ArrowStyle::default(),
+            coords: CoordSystem::Data,
+            clip: None,
         }
     }
 
@@ -535,6 +662,153 @@ impl Annotation {
             x2,
             y2,
             style,
+            coords: CoordSystem::Data,
+            clip: None,
+        }
+    }
+
+    /// Create a callout: text offset from `(x, y)` with an arrow pointing at it
+    ///
+    /// `offset` moves the text anchor away from the point in points, with
+    /// positive y upward, like matplotlib's
+    /// `annotate(text, xy, xytext=offset, textcoords="offset points")`. The
+    /// arrow starts at the edge of the text box (including its padding) and
+    /// ends at the point; it is omitted when the point lies inside the box.
+    pub fn callout(
+        x: f64,
+        y: f64,
+        text: impl Into<String>,
+        offset: (f32, f32),
+        arrow_style: ArrowStyle,
+    ) -> Self {
+        Self::callout_styled(x, y, text, offset, arrow_style, TextStyle::default())
+    }
+
+    /// Create a callout with a custom text style
+    pub fn callout_styled(
+        x: f64,
+        y: f64,
+        text: impl Into<String>,
+        offset: (f32, f32),
+        arrow_style: ArrowStyle,
+        text_style: TextStyle,
+    ) -> Self {
+        Annotation::Callout {
+            x,
+            y,
+            text: text.into(),
+            offset,
+            text_style,
+            arrow_style,
+            coords: CoordSystem::Data,
+            clip: None,
+        }
+    }
+
+    /// Position this annotation in another coordinate system
+    ///
+    /// Applies to text, arrow, rectangle, and callout annotations, which are
+    /// in [`CoordSystem::Data`] by default; reference lines, spans, and fills
+    /// always follow the data and are returned unchanged.
+    ///
+    /// ```rust
+    /// use ruviz::core::{Annotation, CoordSystem, TextAlign, TextStyle, TextVAlign};
+    ///
+    /// // Panel label pinned just inside the top-left corner of the axes
+    /// let style = TextStyle::new()
+    ///     .font_size(14.0)
+    ///     .align(TextAlign::Left)
+    ///     .valign(TextVAlign::Top);
+    /// let label = Annotation::text_styled(0.02, 0.98, "(a)", style).coords(CoordSystem::Axes);
+    /// assert_eq!(label.coord_system(), Some(CoordSystem::Axes));
+    /// ```
+    pub fn coords(mut self, coords: CoordSystem) -> Self {
+        match &mut self {
+            Annotation::Text {
+                coords: current, ..
+            }
+            | Annotation::Arrow {
+                coords: current, ..
+            }
+            | Annotation::Callout {
+                coords: current, ..
+            }
+            | Annotation::Rectangle {
+                coords: current, ..
+            } => *current = coords,
+            Annotation::HLine { .. }
+            | Annotation::VLine { .. }
+            | Annotation::FillBetween { .. }
+            | Annotation::HSpan { .. }
+            | Annotation::VSpan { .. } => {}
+        }
+        self
+    }
+
+    /// Clip this annotation to the plot area or let it extend into the margins
+    ///
+    /// Like matplotlib's `annotation_clip`, annotations in
+    /// [`CoordSystem::Data`] are clipped by default and those in
+    /// [`CoordSystem::Axes`] are not, so panel labels can sit beside the axes.
+    /// Applies to text, arrow, rectangle, and callout annotations;
+    /// [`CoordSystem::Figure`] annotations are never clipped, and reference
+    /// lines, spans, and fills are always clipped and are returned unchanged.
+    ///
+    /// ```rust
+    /// use ruviz::core::{Annotation, CoordSystem};
+    ///
+    /// assert!(Annotation::text(0.5, 0.5, "peak").clips());
+    /// assert!(!Annotation::text(0.5, 0.5, "peak").clip(false).clips());
+    ///
+    /// let label = Annotation::text(-0.08, 1.04, "(a)").coords(CoordSystem::Axes);
+    /// assert!(!label.clips());
+    /// assert!(label.clip(true).clips());
+    /// ```
+    pub fn clip(mut self, clip: bool) -> Self {
+        match &mut self {
+            Annotation::Text { clip: current, .. }
+            | Annotation::Arrow { clip: current, .. }
+            | Annotation::Callout { clip: current, .. }
+            | Annotation::Rectangle { clip: current, .. } => *current = Some(clip),
+            Annotation::HLine { .. }
+            | Annotation::VLine { .. }
+            | Annotation::FillBetween { .. }
+            | Annotation::HSpan { .. }
+            | Annotation::VSpan { .. } => {}
+        }
+        self
+    }
+
+    /// Whether this annotation is drawn clipped to the plot area
+    pub fn clips(&self) -> bool {
+        match self {
+            Annotation::Text { coords, clip, .. }
+            | Annotation::Arrow { coords, clip, .. }
+            | Annotation::Callout { coords, clip, .. }
+            | Annotation::Rectangle { coords, clip, .. } => {
+                *coords != CoordSystem::Figure && clip.unwrap_or(*coords == CoordSystem::Data)
+            }
+            Annotation::HLine { .. }
+            | Annotation::VLine { .. }
+            | Annotation::FillBetween { .. }
+            | Annotation::HSpan { .. }
+            | Annotation::VSpan { .. } => true,
+        }
+    }
+
+    /// Coordinate system of a positioned annotation, or `None` for reference
+    /// lines, spans, and fills, which always use data coordinates
+    pub fn coord_system(&self) -> Option<CoordSystem> {
+        match self {
+            Annotation::Text { coords, .. }
+            | Annotation::Arrow { coords, .. }
+            | Annotation::Callout { coords, .. }
+            | Annotation::Rectangle { coords, .. } => Some(*coords),
+            Annotation::HLine { .. }
+            | Annotation::VLine { .. }
+            | Annotation::FillBetween { .. }
+            | Annotation::HSpan { .. }
+            | Annotation::VSpan { .. } => None,
         }
     }
 
@@ -586,6 +860,8 @@ impl Annotation {
             width,
             height,
             style: ShapeStyle::default(),
+            coords: CoordSystem::Data,
+            clip: None,
         }
     }
 
@@ -597,6 +873,8 @@ impl Annotation {
             width,
             height,
             style,
+            coords: CoordSystem::Data,
+            clip: None,
         }
     }
 
@@ -608,6 +886,7 @@ impl Annotation {
             y2,
             style: FillStyle::default(),
             where_positive: false,
+            where_mask: None,
         }
     }
 
@@ -620,6 +899,7 @@ impl Annotation {
             y2,
             style: FillStyle::default(),
             where_positive: false,
+            where_mask: None,
         }
     }
 
@@ -637,6 +917,30 @@ impl Annotation {
             y2,
             style,
             where_positive,
+            where_mask: None,
+        }
+    }
+
+    /// Create a fill between two curves restricted to where `mask` is `true`
+    ///
+    /// Like matplotlib's `fill_between(..., where=mask, interpolate=True)`:
+    /// each run of `true` entries becomes its own polygon, and where the
+    /// curves cross next to a run boundary the fill is extended to the exact
+    /// crossing point.
+    pub fn fill_between_where(
+        x: Vec<f64>,
+        y1: Vec<f64>,
+        y2: Vec<f64>,
+        mask: Vec<bool>,
+        style: FillStyle,
+    ) -> Self {
+        Annotation::FillBetween {
+            x,
+            y1,
+            y2,
+            style,
+            where_positive: false,
+            where_mask: Some(mask),
         }
     }
 
@@ -659,6 +963,75 @@ impl Annotation {
     }
 }
 
+/// Split a fill between `y1` and `y2` into the data-space polygons to draw.
+///
+/// Points are kept where `mask` is `true`, or where `y1 >= y2` when only
+/// `where_positive` is set, and each run of kept points becomes one polygon:
+/// forward along `y1`, then back along `y2`. When the curves cross between a
+/// run's end point and its excluded neighbour, the polygon is extended to the
+/// interpolated crossing so its edge lands exactly where the curves meet.
+/// Mismatched lengths produce no polygons.
+pub(crate) fn fill_between_polygons(
+    x: &[f64],
+    y1: &[f64],
+    y2: &[f64],
+    where_positive: bool,
+    where_mask: Option<&[bool]>,
+) -> Vec<Vec<(f64, f64)>> {
+    let len = x.len();
+    if len < 2 || y1.len() != len || y2.len() != len || where_mask.is_some_and(|m| m.len() != len) {
+        return Vec::new();
+    }
+
+    let keep = |index: usize| match where_mask {
+        Some(mask) => mask[index],
+        None => !where_positive || y1[index] >= y2[index],
+    };
+    let crossing = |index: usize| {
+        let d0 = y1[index] - y2[index];
+        let d1 = y1[index + 1] - y2[index + 1];
+        (d0 * d1 < 0.0).then(|| {
+            let t = d0 / (d0 - d1);
+            (
+                x[index] + t * (x[index + 1] - x[index]),
+                y1[index] + t * (y1[index + 1] - y1[index]),
+            )
+        })
+    };
+
+    let mut polygons = Vec::new();
+    let mut start = 0;
+    while start < len {
+        if !keep(start) {
+            start += 1;
+            continue;
+        }
+        let mut end = start;
+        while end + 1 < len && keep(end + 1) {
+            end += 1;
+        }
+
+        let mut polygon = Vec::with_capacity(2 * (end - start + 1) + 2);
+        if start > 0
+            && let Some(point) = crossing(start - 1)
+        {
+            polygon.push(point);
+        }
+        polygon.extend((start..=end).map(|index| (x[index], y1[index])));
+        if end + 1 < len
+            && let Some(point) = crossing(end)
+        {
+            polygon.push(point);
+        }
+        polygon.extend((start..=end).rev().map(|index| (x[index], y2[index])));
+        if polygon.len() >= 3 {
+            polygons.push(polygon);
+        }
+        start = end + 1;
+    }
+    polygons
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -689,6 +1062,7 @@ mod tests {
             padding: 3.0,
             border_color: Some(Color::BLACK),
             border_width: 0.75,
+            corner_radius: 2.0,
         };
     }
 
@@ -742,6 +1116,11 @@ mod tests {
         let arrow = Annotation::arrow(0.0, 0.0, 1.0, 1.0);
         assert!(matches!(arrow, Annotation::Arrow { .. }));
 
+        let callout = Annotation::callout(1.0, 2.0, "Peak", (20.0, 15.0), ArrowStyle::new());
+        assert!(
+            matches!(callout, Annotation::Callout { offset, ref text, .. } if offset == (20.0, 15.0) && text == "Peak")
+        );
+
         let hline = Annotation::hline(5.0);
         assert!(matches!(hline, Annotation::HLine { y, .. } if (y - 5.0).abs() < 0.001));
 
@@ -754,6 +1133,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_coord_system_places_fractions_and_skips_unpositioned_annotations() {
+        let plot_area = tiny_skia::Rect::from_xywh(50.0, 20.0, 200.0, 100.0).unwrap();
+        let canvas = (400.0, 300.0);
+        assert_eq!(
+            CoordSystem::Data.fraction_to_pixels(0.5, 0.5, plot_area, canvas),
+            None
+        );
+        assert_eq!(
+            CoordSystem::Axes.fraction_to_pixels(0.25, 1.0, plot_area, canvas),
+            Some((100.0, 20.0))
+        );
+        assert_eq!(
+            CoordSystem::Figure.fraction_to_pixels(0.5, 0.0, plot_area, canvas),
+            Some((200.0, 300.0))
+        );
+
+        let label = Annotation::text(0.02, 0.98, "(a)");
+        assert_eq!(label.coord_system(), Some(CoordSystem::Data));
+        let label = label.coords(CoordSystem::Axes);
+        assert_eq!(label.coord_system(), Some(CoordSystem::Axes));
+        let hline = Annotation::hline(1.0).coords(CoordSystem::Figure);
+        assert_eq!(hline.coord_system(), None);
+    }
+
     #[test]
     fn test_fill_between() {
         let x = vec![1.0, 2.0, 3.0];
@@ -775,6 +1179,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fill_between_polygons_split_at_mask_and_interpolate_crossings() {
+        let x = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let y1 = [1.0, 1.0, -1.0, -1.0, 1.0];
+        let y2 = [0.0; 5];
+
+        // Unmasked fills one polygon over the whole range
+        assert_eq!(fill_between_polygons(&x, &y1, &y2, false, None).len(), 1);
+
+        // y1 >= y2 holds at 0, 1 and 4; the crossings at x = 1.5 and 3.5 close
+        // the first region and open the second
+        let polygons = fill_between_polygons(&x, &y1, &y2, true, None);
+        assert_eq!(
+            polygons,
+            vec![
+                vec![(0.0, 1.0), (1.0, 1.0), (1.5, 0.0), (1.0, 0.0), (0.0, 0.0)],
+                vec![(3.5, 0.0), (4.0, 1.0), (4.0, 0.0)],
+            ]
+        );
+
+        // An explicit mask overrides where_positive and leaves a gap at index 2
+        let mask = [true, true, false, true, true];
+        let polygons = fill_between_polygons(&x, &y1, &y2, true, Some(&mask));
+        assert_eq!(polygons.len(), 2);
+        assert_eq!(polygons[0].first(), Some(&(0.0, 1.0)));
+        assert!(polygons[1].contains(&(3.0, -1.0)));
+
+        assert!(fill_between_polygons(&x, &y1, &y2, false, Some(&[true; 4])).is_empty());
+    }
+
     #[test]
     fn test_alpha_clamping() {
         let style = FillStyle::new().alpha(1.5);
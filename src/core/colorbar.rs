@@ -0,0 +1,278 @@
+//! Standalone colorbar images
+//!
+//! Renders only a colorbar (gradient, ticks, and label) on a canvas sized
+//! to fit it, for composite layouts assembled outside ruviz's subplot grid.
+
+use crate::axes::AxisScale;
+use crate::core::plot::{COLORBAR_MARGIN_PX, COLORBAR_WIDTH_PX, HeatmapColorbarStyle};
+use crate::core::{Image, PlottingError, REFERENCE_DPI, RenderScale, Result};
+use crate::plots::{ColorbarConfig, ColorbarOrientation};
+use crate::render::skia::{SkiaRenderer, compute_colorbar_ticks_with_options};
+use crate::render::{ColorMap, Theme};
+
+/// Default bar length in logical pixels
+const DEFAULT_LENGTH_PX: f32 = 300.0;
+
+/// A colorbar rendered on its own, without a plot.
+///
+/// Uses the same drawing code as heatmap colorbars, so ticks and labels
+/// match the colorbars ruviz draws next to plots.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ruviz::prelude::*;
+///
+/// Colorbar::new(ColorMap::viridis(), 0.0, 100.0)
+///     .orientation(ColorbarOrientation::Horizontal)
+///     .label("Temperature (°C)")
+///     .save("cbar.png")?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Colorbar {
+    colormap: ColorMap,
+    vmin: f64,
+    vmax: f64,
+    value_scale: AxisScale,
+    label: Option<String>,
+    config: ColorbarConfig,
+    length: f32,
+    tick_font_size: f32,
+    label_font_size: f32,
+    theme: Theme,
+}
+
+impl Colorbar {
+    /// Create a vertical colorbar mapping `vmin..vmax` through `colormap`
+    pub fn new(colormap: ColorMap, vmin: f64, vmax: f64) -> Self {
+        Self {
+            colormap,
+            vmin,
+            vmax,
+            value_scale: AxisScale::Linear,
+            label: None,
+            config: ColorbarConfig::default(),
+            length: DEFAULT_LENGTH_PX,
+            tick_font_size: 12.0,
+            label_font_size: 14.0,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Set the direction the bar runs in
+    pub fn orientation(mut self, orientation: ColorbarOrientation) -> Self {
+        self.config.orientation = orientation;
+        self
+    }
+
+    /// Set the colorbar label
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the value scale used for colors and ticks (default linear)
+    pub fn scale(mut self, scale: AxisScale) -> Self {
+        self.value_scale = scale;
+        self
+    }
+
+    /// Set tick count and label formatting
+    ///
+    /// The orientation set by [`Colorbar::orientation`] is kept.
+    pub fn config(mut self, config: ColorbarConfig) -> Self {
+        let orientation = self.config.orientation;
+        self.config = config.orientation(orientation);
+        self
+    }
+
+    /// Set the bar length in pixels at the reference DPI (default 300)
+    pub fn length(mut self, length: f32) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Set tick and label font sizes in points (defaults 12 and 14)
+    pub fn font_sizes(mut self, tick_font_size: f32, label_font_size: f32) -> Self {
+        self.tick_font_size = tick_font_size;
+        self.label_font_size = label_font_size;
+        self
+    }
+
+    /// Set the theme providing the background and foreground colors
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Render the colorbar to an image at the reference DPI
+    pub fn render(&self) -> Result<Image> {
+        Ok(self.render_renderer(REFERENCE_DPI)?.into_image())
+    }
+
+    /// Save the colorbar as a PNG at the reference DPI
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> Result<()> {
+        self.save_with_dpi(path, REFERENCE_DPI)
+    }
+
+    /// Save the colorbar as a PNG with the specified DPI
+    pub fn save_with_dpi<P: AsRef<std::path::Path>>(&self, path: P, dpi: f32) -> Result<()> {
+        self.render_renderer(dpi)?.save_png(path)
+    }
+
+    fn validate(&self, dpi: f32) -> Result<()> {
+        let dpi_range =
+            crate::core::constants::dpi::MIN as f32..=crate::core::constants::dpi::MAX as f32;
+        if !dpi.is_finite() || !dpi_range.contains(&dpi) {
+            return Err(PlottingError::InvalidInput(format!(
+                "Colorbar DPI must be between {} and {} (dpi={dpi})",
+                dpi_range.start(),
+                dpi_range.end()
+            )));
+        }
+        if !self.vmin.is_finite() || !self.vmax.is_finite() || self.vmin >= self.vmax {
+            return Err(PlottingError::InvalidInput(format!(
+                "Colorbar range must be finite with vmin < vmax (vmin={}, vmax={})",
+                self.vmin, self.vmax
+            )));
+        }
+        if !self.length.is_finite() || self.length < 1.0 {
+            return Err(PlottingError::InvalidInput(format!(
+                "Colorbar length must be at least 1 pixel (length={})",
+                self.length
+            )));
+        }
+        Ok(())
+    }
+
+    fn render_renderer(&self, dpi: f32) -> Result<SkiaRenderer> {
+        self.validate(dpi)?;
+        let render_scale = RenderScale::new(dpi);
+        let style = HeatmapColorbarStyle {
+            colormap: self.colormap.clone(),
+            value_scale: self.value_scale.clone(),
+            label: self.label.clone(),
+            tick_font_size: self.tick_font_size,
+            label_font_size: self.label_font_size,
+            show_log_subticks: true,
+            center: None,
+            colorbar: self.config.clone(),
+        };
+
+        // Text metrics do not depend on the canvas, so measure on a scratch one
+        let mut probe = SkiaRenderer::new(1, 1, self.theme.clone())?;
+        probe.set_render_scale(render_scale);
+        let extent = style.measure_extent(&probe, self.vmin, self.vmax)?;
+
+        let margin = render_scale.logical_pixels_to_pixels(COLORBAR_MARGIN_PX);
+        let thickness = render_scale.logical_pixels_to_pixels(COLORBAR_WIDTH_PX);
+        let length = render_scale.logical_pixels_to_pixels(self.length);
+        let tick_font_size = render_scale.points_to_pixels(self.tick_font_size);
+        let horizontal = self.config.orientation == ColorbarOrientation::Horizontal;
+
+        // Leave room for tick labels centered on the ends of the bar
+        let end_padding = if horizontal {
+            let ticks = compute_colorbar_ticks_with_options(
+                self.vmin,
+                self.vmax,
+                &self.value_scale,
+                true,
+                self.config.n_ticks,
+                self.config.formatter.as_ref(),
+            );
+            let mut max_label_width: f32 = 0.0;
+            for label in &ticks.major_labels {
+                max_label_width = max_label_width.max(probe.measure_text(label, tick_font_size)?.0);
+            }
+            max_label_width / 2.0 + margin
+        } else {
+            tick_font_size.max(margin)
+        };
+
+        let along = (length + 2.0 * end_padding).ceil() as u32;
+        let across = extent.ceil() as u32;
+        let (width, height) = if horizontal {
+            (along, across)
+        } else {
+            (across, along)
+        };
+        // A colorbar is narrow by nature, so skip the 100px figure minimum
+        PlottingError::validate_subplot_dimensions(width, height)?;
+
+        let mut renderer = SkiaRenderer::new(width, height, self.theme.clone())?;
+        renderer.set_render_scale(RenderScale::from_canvas_size(width, height, dpi));
+        let (x, y, bar_width, bar_height) = if horizontal {
+            (end_padding, margin, length, thickness)
+        } else {
+            (margin, end_padding, thickness, length)
+        };
+        renderer.draw_colorbar_with_center(
+            &self.colormap,
+            self.vmin,
+            self.vmax,
+            x,
+            y,
+            bar_width,
+            bar_height,
+            &self.value_scale,
+            self.label.as_deref(),
+            self.theme.foreground,
+            self.tick_font_size,
+            Some(self.label_font_size),
+            style.show_log_subticks,
+            None,
+            &self.config,
+        )?;
+        Ok(renderer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standalone_colorbar_canvas_follows_orientation() {
+        let vertical = Colorbar::new(ColorMap::viridis(), 0.0, 100.0)
+            .render()
+            .unwrap();
+        assert!(vertical.height > vertical.width);
+        assert!(vertical.height >= 300);
+
+        let horizontal = Colorbar::new(ColorMap::viridis(), 0.0, 100.0)
+            .orientation(ColorbarOrientation::Horizontal)
+            .render()
+            .unwrap();
+        assert!(horizontal.width > horizontal.height);
+        assert!(horizontal.width >= 300);
+
+        let labeled = Colorbar::new(ColorMap::viridis(), 0.0, 100.0)
+            .label("Temperature")
+            .render()
+            .unwrap();
+        assert!(labeled.width > vertical.width);
+    }
+
+    #[test]
+    fn test_standalone_colorbar_rejects_invalid_range_and_saves_png() {
+        assert!(
+            Colorbar::new(ColorMap::viridis(), 1.0, 1.0)
+                .render()
+                .is_err()
+        );
+        assert!(
+            Colorbar::new(ColorMap::viridis(), 0.0, f64::NAN)
+                .render()
+                .is_err()
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cbar.png");
+        Colorbar::new(ColorMap::plasma(), 1.0, 1000.0)
+            .scale(AxisScale::Log)
+            .save_with_dpi(&path, 150.0)
+            .unwrap();
+        assert!(path.exists());
+    }
+}
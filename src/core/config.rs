@@ -39,6 +39,8 @@ use crate::render::{FontFamily, FontWeight};
 /// Dimensions are specified in inches for DPI-independent layout.
 /// The DPI setting only affects the output pixel resolution.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct FigureConfig {
     /// Figure width in inches (default: 6.4)
     pub width: f32,
@@ -96,6 +98,8 @@ impl Default for FigureConfig {
 /// Font sizes can be specified as absolute values or as scale factors
 /// relative to `base_size`. Scale factors are multipliers (e.g., 1.4 = 140% of base).
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct TypographyConfig {
     /// Base font size in points (default: 10.0)
     pub base_size: f32,
@@ -215,6 +219,8 @@ impl Default for TypographyConfig {
 ///
 /// All line widths are specified in typographic points (1/72 inch).
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct LineConfig {
     /// Data line width in points (default: 1.5)
     pub data_width: f32,
@@ -226,6 +232,10 @@ pub struct LineConfig {
     pub tick_width: f32,
     /// Tick mark length in points (default: 4.0)
     pub tick_length: f32,
+    /// Minor tick mark width in points (default: None, 75% of `tick_width`)
+    pub minor_tick_width: Option<f32>,
+    /// Minor tick mark length in points (default: None, 60% of `tick_length`)
+    pub minor_tick_length: Option<f32>,
 }
 
 impl LineConfig {
@@ -254,6 +264,18 @@ impl LineConfig {
         pt_to_px(self.tick_length, dpi)
     }
 
+    /// Get minor tick mark width in points
+    pub fn effective_minor_tick_width(&self) -> f32 {
+        self.minor_tick_width
+            .unwrap_or((self.tick_width * 0.75).max(0.1))
+    }
+
+    /// Get minor tick mark length in points
+    pub fn effective_minor_tick_length(&self) -> f32 {
+        self.minor_tick_length
+            .unwrap_or((self.tick_length * 0.6).max(0.1))
+    }
+
     /// Set data line width
     pub fn data_width(mut self, width: f32) -> Self {
         self.data_width = width;
@@ -281,6 +303,8 @@ impl Default for LineConfig {
             grid_width: 0.5,
             tick_width: 0.6,
             tick_length: 4.0,
+            minor_tick_width: None,
+            minor_tick_length: None,
         }
     }
 }
@@ -293,6 +317,8 @@ impl Default for LineConfig {
 ///
 /// All spacing values are in typographic points.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct SpacingConfig {
     /// Space below title in points (default: 6.0)
     pub title_pad: f32,
@@ -371,6 +397,8 @@ impl Default for SpacingConfig {
 /// let fixed = MarginConfig::fixed(1.0, 0.5, 0.8, 0.6);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum MarginConfig {
     /// Proportional margins as fractions of figure dimensions (matplotlib-style)
     ///
@@ -694,6 +722,8 @@ impl Default for ComputedMargins {
 /// };
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct SpineConfig {
     /// Show left spine (y-axis border)
     pub left: bool,
@@ -706,6 +736,32 @@ pub struct SpineConfig {
     /// Offset spines from data area (in points)
     /// When > 0, spines are moved outward from the data area
     pub offset: f32,
+    /// Where the bottom and left spines are placed
+    pub position: SpinePosition,
+}
+
+/// Placement of the bottom and left spines
+///
+/// # Example
+///
+/// ```rust
+/// use ruviz::core::{SpineConfig, SpinePosition};
+///
+/// // Axes crossing at the data origin, without the top/right frame
+/// let math_axes = SpineConfig::despine().with_position(SpinePosition::Zero);
+/// assert_eq!(math_axes.position, SpinePosition::Zero);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SpinePosition {
+    /// Along the plot-area border (default)
+    #[default]
+    Edge,
+    /// Through the data origin: the bottom spine at y = 0 and the left spine
+    /// at x = 0, clamped to the border when zero is outside the view.
+    /// Ticks and tick labels follow the spines; `offset` is ignored.
+    Zero,
 }
 
 impl SpineConfig {
@@ -725,6 +781,7 @@ impl SpineConfig {
             top: false,
             bottom: true,
             offset: 0.0,
+            position: SpinePosition::Edge,
         }
     }
 
@@ -743,6 +800,7 @@ impl SpineConfig {
             top: false,
             bottom: false,
             offset: 0.0,
+            position: SpinePosition::Edge,
         }
     }
 
@@ -760,6 +818,12 @@ impl SpineConfig {
         self
     }
 
+    /// Set where the bottom and left spines are placed
+    pub fn with_position(mut self, position: SpinePosition) -> Self {
+        self.position = position;
+        self
+    }
+
     /// Hide the left spine
     pub fn hide_left(mut self) -> Self {
         self.left = false;
@@ -827,6 +891,7 @@ impl Default for SpineConfig {
             top: true,
             bottom: true,
             offset: 0.0,
+            position: SpinePosition::Edge,
         }
     }
 }
@@ -839,7 +904,9 @@ impl Default for SpineConfig {
 ///
 /// This struct aggregates all configuration options for a plot,
 /// using physical units for DPI-independent layout.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct PlotConfig {
     /// Figure dimensions and DPI
     pub figure: FigureConfig,
@@ -1315,4 +1382,23 @@ mod tests {
         assert!(!config.spines.top);
         assert!(config.spines.bottom);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_plot_config_serde_round_trip() {
+        let config = PlotConfig::builder()
+            .margins(MarginConfig::fixed(1.0, 0.5, 0.8, 0.6))
+            .despine()
+            .build();
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(serde_json::from_str::<PlotConfig>(&json).unwrap(), config);
+        let toml = ::toml::to_string(&config).unwrap();
+        assert_eq!(::toml::from_str::<PlotConfig>(&toml).unwrap(), config);
+
+        // Missing sections fall back to their defaults
+        let partial: PlotConfig = ::toml::from_str("[figure]\ndpi = 300.0").unwrap();
+        assert_eq!(partial.figure.dpi, 300.0);
+        assert_eq!(partial.typography, TypographyConfig::default());
+    }
 }
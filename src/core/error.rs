@@ -50,6 +50,8 @@ pub enum PlottingError {
     InvalidAnnotation { reason: String },
     /// Annotation ID does not belong to this session or is no longer present
     UnknownAnnotationId,
+    /// Render stopped because its cancel flag was set
+    Cancelled,
     /// Data contains invalid values (NaN, Inf)
     InvalidData {
         message: String,
@@ -250,6 +252,9 @@ impl fmt::Display for PlottingError {
             PlottingError::UnknownAnnotationId => {
                 write!(f, "Unknown annotation ID for this interactive session")
             }
+            PlottingError::Cancelled => {
+                write!(f, "Render cancelled")
+            }
             PlottingError::InvalidData { message, position } => match position {
                 Some(pos) => write!(f, "Invalid data at position {}: {}", pos, message),
                 None => write!(f, "Invalid data: {}", message),
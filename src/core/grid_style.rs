@@ -53,6 +53,10 @@ pub struct GridStyle {
     pub minor_line_width: f32,
     /// Minor grid line alpha
     pub minor_alpha: f32,
+    /// Minor grid line color (None uses the major grid color)
+    pub minor_color: Option<Color>,
+    /// Minor grid line style (None uses the major grid line style)
+    pub minor_line_style: Option<LineStyle>,
 }
 
 impl Default for GridStyle {
@@ -74,6 +78,8 @@ impl Default for GridStyle {
             minor: false,
             minor_line_width: 0.25,
             minor_alpha: 0.15,
+            minor_color: None,
+            minor_line_style: None,
         }
     }
 }
@@ -105,6 +111,8 @@ impl GridStyle {
             minor: false,
             minor_line_width: 0.4,
             minor_alpha: 0.25,
+            minor_color: None,
+            minor_line_style: None,
         }
     }
 
@@ -156,6 +164,18 @@ impl GridStyle {
         self
     }
 
+    /// Set minor grid line color
+    pub fn minor_color(mut self, color: Color) -> Self {
+        self.minor_color = Some(color);
+        self
+    }
+
+    /// Set minor grid line style
+    pub fn minor_line_style(mut self, style: LineStyle) -> Self {
+        self.minor_line_style = Some(style);
+        self
+    }
+
     /// Get the effective grid color with alpha applied
     pub fn effective_color(&self) -> Color {
         self.color.with_alpha(self.alpha)
@@ -163,7 +183,16 @@ impl GridStyle {
 
     /// Get the effective minor grid color with alpha applied
     pub fn effective_minor_color(&self) -> Color {
-        self.color.with_alpha(self.minor_alpha)
+        self.minor_color
+            .unwrap_or(self.color)
+            .with_alpha(self.minor_alpha)
+    }
+
+    /// Get the effective minor grid line style
+    pub fn effective_minor_line_style(&self) -> LineStyle {
+        self.minor_line_style
+            .clone()
+            .unwrap_or_else(|| self.line_style.clone())
     }
 }
 
@@ -242,6 +271,27 @@ mod tests {
         assert_eq!(effective.a, 76); // 0.3 * 255 = 76.5
     }
 
+    #[test]
+    fn test_minor_style_falls_back_to_major() {
+        let style = GridStyle::default()
+            .color(Color::BLUE)
+            .line_style(LineStyle::Dashed);
+        assert_eq!(style.effective_minor_color(), Color::BLUE.with_alpha(0.15));
+        assert!(matches!(
+            style.effective_minor_line_style(),
+            LineStyle::Dashed
+        ));
+
+        let style = style
+            .minor_color(Color::RED)
+            .minor_line_style(LineStyle::Dotted);
+        assert_eq!(style.effective_minor_color(), Color::RED.with_alpha(0.15));
+        assert!(matches!(
+            style.effective_minor_line_style(),
+            LineStyle::Dotted
+        ));
+    }
+
     #[test]
     fn test_clamping() {
         let style = GridStyle::default()
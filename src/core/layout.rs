@@ -184,6 +184,10 @@ pub struct MeasuredDimensions {
 pub(crate) struct LayoutMeasurements {
     pub(crate) dimensions: MeasuredDimensions,
     pub(crate) legend: Option<(f32, f32)>,
+    /// Height of the band reserved along the bottom edge for a horizontal colorbar.
+    pub(crate) colorbar_band: Option<f32>,
+    /// Heights of the `×10ⁿ` offset texts drawn past the X and Y axes.
+    pub(crate) tick_offset_texts: (Option<f32>, Option<f32>),
 }
 
 impl Deref for LayoutMeasurements {
@@ -241,6 +245,11 @@ pub fn estimate_text_height(font_size_px: f32) -> f32 {
     font_size_px * 1.2
 }
 
+/// Estimate the height of `text` in pixels, one line per `\n`-separated line
+fn estimate_text_block_height(text: &str, font_size_px: f32) -> f32 {
+    estimate_text_height(font_size_px) * text.split('\n').count() as f32
+}
+
 /// Estimate maximum width of tick labels in pixels
 ///
 /// Uses the maximum character count to estimate width.
@@ -325,29 +334,26 @@ impl LayoutCalculator {
         let measured_ytick = measurements.and_then(|m| m.ytick);
         let measured_right_margin = measurements.and_then(|m| m.right_margin);
 
-        let title_height = if content.title.is_some() {
-            measured_title
+        let title_height = match &content.title {
+            Some(title) => measured_title
                 .map(|(_, h)| h)
-                .unwrap_or_else(|| estimate_text_height(title_size_px))
-        } else {
-            0.0
+                .unwrap_or_else(|| estimate_text_block_height(title, title_size_px)),
+            None => 0.0,
         };
 
-        let xlabel_height = if content.xlabel.is_some() {
-            measured_xlabel
+        let xlabel_height = match &content.xlabel {
+            Some(xlabel) => measured_xlabel
                 .map(|(_, h)| h)
-                .unwrap_or_else(|| estimate_text_height(label_size_px))
-        } else {
-            0.0
+                .unwrap_or_else(|| estimate_text_block_height(xlabel, label_size_px)),
+            None => 0.0,
         };
 
-        let ylabel_width = if content.ylabel.is_some() {
-            // Rotated text: height becomes width
-            measured_ylabel
+        // Rotated text: height becomes width
+        let ylabel_width = match &content.ylabel {
+            Some(ylabel) => measured_ylabel
                 .map(|(_, h)| h)
-                .unwrap_or_else(|| estimate_text_height(label_size_px))
-        } else {
-            0.0
+                .unwrap_or_else(|| estimate_text_block_height(ylabel, label_size_px)),
+            None => 0.0,
         };
 
         let (xtick_height, ytick_width, tick_pad) = if content.show_tick_labels {
@@ -578,6 +584,41 @@ mod tests {
         assert!((xlabel.y - expected_xlabel_top).abs() < 1.0);
     }
 
+    #[test]
+    fn test_layout_estimates_reserve_every_line_of_multiline_text() {
+        let calculator = LayoutCalculator::default();
+        let compute = |content: &PlotContent| {
+            calculator.compute(
+                (640, 480),
+                content,
+                &default_typography(),
+                &default_spacing(),
+                100.0,
+                None,
+            )
+        };
+        let single = compute(
+            &PlotContent::new()
+                .with_title("Title")
+                .with_xlabel("Time")
+                .with_ylabel("Voltage"),
+        );
+        let multi = compute(
+            &PlotContent::new()
+                .with_title("Title\nSubtitle")
+                .with_xlabel("Time\n(s)")
+                .with_ylabel("Voltage\n(mV)"),
+        );
+        let typography = default_typography();
+        let pt_to_px = |pt: f32| pt * 100.0 / 72.0;
+        let title_line = estimate_text_height(pt_to_px(typography.title_size()));
+        let label_line = estimate_text_height(pt_to_px(typography.label_size()));
+
+        assert!((multi.margins.top - single.margins.top - title_line).abs() < 0.01);
+        assert!((multi.margins.bottom - single.margins.bottom - label_line).abs() < 0.01);
+        assert!((multi.margins.left - single.margins.left - label_line).abs() < 0.01);
+    }
+
     #[test]
     fn test_layout_uses_measured_dimensions_when_provided() {
         let calculator = LayoutCalculator::default();
@@ -222,6 +222,21 @@ pub enum LegendItemType {
 }
 
 impl LegendItem {
+    /// This item drawn washed out, as for a hidden series
+    pub(crate) fn faded(mut self) -> Self {
+        const FADED_ALPHA: f32 = 0.3;
+        self.color = self
+            .color
+            .with_alpha(self.color.a as f32 / 255.0 * FADED_ALPHA);
+        if let LegendItemType::Area {
+            edge_color: Some(edge),
+        } = &mut self.item_type
+        {
+            *edge = edge.with_alpha(edge.a as f32 / 255.0 * FADED_ALPHA);
+        }
+        self
+    }
+
     /// Create a legend item for a line series
     pub fn line(label: impl Into<String>, color: Color, style: LineStyle, width: f32) -> Self {
         Self {
@@ -750,7 +765,13 @@ impl Legend {
             0.0
         };
 
-        let width = content_width + spacing_px.border_pad * 2.0;
+        // The title sits above the entries and may be wider than them
+        let title_width = self
+            .title
+            .as_ref()
+            .map_or(0.0, |title| title.chars().count() as f32 * char_width);
+
+        let width = content_width.max(title_width) + spacing_px.border_pad * 2.0;
         let height = content_height + title_height + spacing_px.border_pad * 2.0;
 
         (width, height)
@@ -987,6 +1008,24 @@ mod tests {
         assert!(height > 0.0);
     }
 
+    #[test]
+    fn test_legend_size_includes_title() {
+        let items = vec![LegendItem::line("a", Color::BLUE, LineStyle::Solid, 1.5)];
+        let (width, height) = Legend::new().calculate_size(&items, 6.0);
+        let (titled_width, titled_height) = Legend::new()
+            .title("Temperature (°C)")
+            .calculate_size(&items, 6.0);
+
+        assert!(titled_height > height);
+        let legend = Legend::new();
+        let pad = legend.spacing.to_pixels(legend.font_size).border_pad;
+        assert!(
+            (titled_width - ("Temperature (°C)".chars().count() as f32 * 6.0 + pad * 2.0)).abs()
+                < 0.001
+        );
+        assert!(titled_width > width);
+    }
+
     #[test]
     fn test_scaled_for_render_scales_point_fields() {
         let legend = Legend {
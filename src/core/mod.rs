@@ -1,6 +1,7 @@
 //! Core plotting functionality and main API
 
 pub mod annotation;
+pub mod colorbar;
 pub mod config;
 pub mod constants;
 pub mod error;
@@ -18,12 +19,13 @@ pub mod types;
 pub mod units;
 
 pub use annotation::{
-    Annotation, ArrowHead, ArrowStyle, FillStyle, HatchPattern, ShapeStyle, TextAlign, TextStyle,
-    TextVAlign,
+    Annotation, ArrowHead, ArrowStyle, CoordSystem, FillStyle, HatchPattern, ShapeStyle, TextAlign,
+    TextStyle, TextVAlign,
 };
+pub use colorbar::Colorbar;
 pub use config::{
     ComputedMargins, FigureConfig, LineConfig, MarginConfig, PlotConfig, SpacingConfig,
-    SpineConfig, TypographyConfig,
+    SpineConfig, SpinePosition, TypographyConfig,
 };
 pub use constants::{dimensions, dpi, font_scales, font_sizes, line_widths, margins, spacing};
 pub use error::{PlottingError, Result};
@@ -39,20 +41,23 @@ pub use legend::{
     Legend, LegendAnchor, LegendItem, LegendItemType, LegendPosition, LegendSpacing,
     LegendSpacingPixels, LegendStyle, find_best_position,
 };
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+pub use plot::render_batch;
 pub use plot::{
-    AnnotationId, BackendFallbackReason, BackendOperation, BackendResolution, BackendType,
-    BuilderWhen, DirtyDomain, DirtyDomains, FramePacing, FrameStats, HitResult, Image, ImageTarget,
-    InsetAnchor, InsetLayout, InteractiveFrame, InteractiveFrameWithGeneration,
-    InteractivePlotSession, InteractiveViewportSnapshot, IntoPlot, LayerRenderState, Plot,
-    PlotBuilder, PlotInput, PlotInputEvent, PlotSource, PreparedPlot, QualityPolicy,
-    ReactiveSubscription, ReactiveValue, RenderTargetKind, SeriesStyle, SurfaceCapability,
-    SurfaceTarget, TextEngineMode, TickDirection, TickSides, ViewportPoint, ViewportRect,
+    AnnotationId, AspectMode, BackendFallbackReason, BackendOperation, BackendResolution,
+    BackendType, BuilderWhen, DirtyDomain, DirtyDomains, DownsampleMode, FramePacing, FrameStats,
+    GridAxis, HitResult, Image, ImageTarget, InsetAnchor, InsetLayout, InteractiveFrame,
+    InteractiveFrameWithGeneration, InteractivePlotSession, InteractiveViewportSnapshot, IntoPlot,
+    LayerRenderState, Plot, PlotBuilder, PlotInput, PlotInputEvent, PlotSource, PreparedPlot,
+    QualityPolicy, ReactiveSubscription, ReactiveValue, RenderTargetKind, SeriesStyle,
+    SurfaceCapability, SurfaceTarget, TextEngineMode, TickDirection, TickSides, ViewportPoint,
+    ViewportRect,
 };
 pub use position::Position;
 pub use style::PlotStyle;
 pub use style_utils::StyleResolver;
-pub use subplot::{GridSpec, SubplotFigure, subplots, subplots_default};
-pub use tick_formatter::TickFormatter;
+pub use subplot::{GridSpec, SubplotFigure, facet_grid, subplots, subplots_default};
+pub use tick_formatter::{TickFormatter, TickLabelFormatter};
 pub use transform::CoordinateTransform;
 pub use types::{BoundingBox, Orientation, Point2f};
 pub use units::{
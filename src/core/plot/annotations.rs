@@ -103,6 +103,72 @@ impl Plot {
         self
     }
 
+    /// Set a title drawn in bold above the legend entries
+    ///
+    /// The legend frame grows to fit the title, like matplotlib's
+    /// `legend(title=...)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .line(&[1.0, 2.0, 3.0], &[18.0, 21.0, 19.5])
+    ///     .label("Berlin")
+    ///     .legend(Position::TopRight)
+    ///     .legend_title("Temperature (°C)")
+    ///     .save("legend_title.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn legend_title(mut self, title: impl Into<String>) -> Self {
+        self.layout.legend.title = Some(title.into());
+        self
+    }
+
+    /// Add a legend entry that is not tied to a series
+    ///
+    /// Manual entries follow the series entries in the order they are added,
+    /// and work like matplotlib's proxy `Patch`/`Line2D` handles for encodings
+    /// such as a shaded confidence band or a dashed model line.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .line(&[1.0, 2.0, 3.0], &[1.0, 4.0, 9.0])
+    ///     .label("observed")
+    ///     .legend(Position::TopLeft)
+    ///     .legend_entry(
+    ///         "model",
+    ///         LegendItemType::Line { style: LineStyle::Dashed, width: 1.5 },
+    ///         Color::BLACK,
+    ///     )
+    ///     .legend_entry(
+    ///         "95% CI",
+    ///         LegendItemType::Area { edge_color: None },
+    ///         Color::from_hex("#1f77b4")?.with_alpha(0.3),
+    ///     )
+    ///     .save("legend_entries.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn legend_entry(
+        mut self,
+        label: impl Into<String>,
+        item_type: LegendItemType,
+        color: Color,
+    ) -> Self {
+        self.layout.legend.entries.push(LegendItem {
+            label: label.into(),
+            color,
+            item_type,
+            has_error_bars: false,
+        });
+        self
+    }
+
     /// Enable/disable grid
     ///
     /// # Example
@@ -213,6 +279,63 @@ impl Plot {
         self
     }
 
+    /// Choose which plot-area borders (spines) are drawn.
+    ///
+    /// Tick marks on a hidden spine are suppressed too; tick labels and any
+    /// configured spine offset are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0])
+    ///     .spines(false, false, true, true)
+    ///     .save("open_frame.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn spines(mut self, top: bool, right: bool, bottom: bool, left: bool) -> Self {
+        let spines = &mut self.display.config.spines;
+        spines.top = top;
+        spines.right = right;
+        spines.bottom = bottom;
+        spines.left = left;
+        self
+    }
+
+    /// Hide the top and right spines (seaborn-style despine).
+    pub fn despine(self) -> Self {
+        self.spines(false, false, true, true)
+    }
+
+    /// Place the bottom and left spines on the plot-area border or through
+    /// the data origin.
+    ///
+    /// With [`SpinePosition::Zero`] the axes cross at (0, 0), each clamped to
+    /// the border when zero is outside the view, and their ticks and tick
+    /// labels move with them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x: Vec<f64> = (-20..=20).map(|i| i as f64 * 0.25).collect();
+    /// let y: Vec<f64> = x.iter().map(|v| v * v * v / 10.0).collect();
+    ///
+    /// Plot::new()
+    ///     .line(&x, &y)
+    ///     .despine()
+    ///     .spine_position(SpinePosition::Zero)
+    ///     .save("cubic.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn spine_position(mut self, position: SpinePosition) -> Self {
+        self.display.config.spines.position = position;
+        self
+    }
+
     /// Set number of major ticks for both axes
     pub fn major_ticks(mut self, count: usize) -> Self {
         self.layout.tick_config.major_ticks_x = count;
@@ -251,6 +374,91 @@ impl Plot {
         self
     }
 
+    /// Label selected minor ticks on log axes
+    ///
+    /// Log-axis minor ticks sit at 2-9 times each power of ten. `mantissas`
+    /// picks the ones that also get a tick label, e.g. `&[2, 5]` labels 20,
+    /// 50, 200, and 500 between the decade labels, like matplotlib's
+    /// `LogFormatter(labelOnlyBase=False)`. This keeps plots spanning only
+    /// one or two decades readable. Values outside 2-9 match no tick, and
+    /// linear axes are unaffected. Applies to both axes.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .yscale(AxisScale::Log)
+    ///     .log_minor_labels(&[2, 5])
+    ///     .line(&[0.0, 1.0, 2.0], &[10.0, 40.0, 90.0])
+    ///     .save("log_minor_labels.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn log_minor_labels(mut self, mantissas: &[u8]) -> Self {
+        self.layout.tick_config.log_minor_labels_x = mantissas.to_vec();
+        self.layout.tick_config.log_minor_labels_y = mantissas.to_vec();
+        self
+    }
+
+    /// Label selected minor ticks on a log X axis
+    ///
+    /// See [`log_minor_labels`](Self::log_minor_labels).
+    pub fn log_minor_labels_x(mut self, mantissas: &[u8]) -> Self {
+        self.layout.tick_config.log_minor_labels_x = mantissas.to_vec();
+        self
+    }
+
+    /// Label selected minor ticks on a log Y axis
+    ///
+    /// See [`log_minor_labels`](Self::log_minor_labels).
+    pub fn log_minor_labels_y(mut self, mantissas: &[u8]) -> Self {
+        self.layout.tick_config.log_minor_labels_y = mantissas.to_vec();
+        self
+    }
+
+    /// Restrict X major ticks to integer values
+    ///
+    /// Fractional tick candidates are dropped, so count data never shows
+    /// ticks like `0.5` or `1.5`; the remaining ticks are labeled without
+    /// decimals. Ticks stay within the visible range, including manual
+    /// [`xlim`](Self::xlim) limits.
+    pub fn xaxis_integer(mut self, enabled: bool) -> Self {
+        self.layout.tick_config.integer_x = enabled;
+        self
+    }
+
+    /// Restrict Y major ticks to integer values
+    ///
+    /// Useful for histograms and count plots. See
+    /// [`xaxis_integer`](Self::xaxis_integer).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .bar(&["a", "b", "c"], &[1.0, 3.0, 2.0])
+    ///     .yaxis_integer(true)
+    ///     .save("counts.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn yaxis_integer(mut self, enabled: bool) -> Self {
+        self.layout.tick_config.integer_y = enabled;
+        self
+    }
+
+    /// Rotate X tick labels counter-clockwise by `degrees`
+    ///
+    /// Rotated labels end at their tick (start at it for negative angles, centered
+    /// for 90°) and the bottom margin grows to fit them. Useful for long category
+    /// names on bar charts; 45° and 90° are the common choices.
+    pub fn xtick_rotation(mut self, degrees: f32) -> Self {
+        self.layout.tick_config.x_label_rotation = if degrees.is_finite() { degrees } else { 0.0 };
+        self
+    }
+
     /// Grid lines only at major ticks
     pub fn grid_major_only(mut self) -> Self {
         self.layout.tick_config.grid_mode = GridMode::MajorOnly;
@@ -269,6 +477,28 @@ impl Plot {
         self
     }
 
+    /// Draw grid lines for one axis only
+    ///
+    /// `GridAxis::Y` keeps only the horizontal lines at the Y ticks, the
+    /// usual look for bar charts; `GridAxis::X` keeps only the vertical lines
+    /// at the X ticks. The default is `GridAxis::Both`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .grid_axis(GridAxis::Y)
+    ///     .line(&[0.0, 1.0, 2.0], &[3.0, 1.0, 2.0])
+    ///     .save("horizontal_grid.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn grid_axis(mut self, axis: GridAxis) -> Self {
+        self.layout.tick_config.grid_axis = axis;
+        self
+    }
+
     /// Enable tight layout (automatic margin adjustment like matplotlib)
     ///
     /// When enabled, computes minimum required margins based on:
@@ -341,6 +571,46 @@ impl Plot {
         self
     }
 
+    /// Set the color of minor grid lines
+    ///
+    /// Minor grid lines are drawn with [`grid_both`](Self::grid_both) or
+    /// [`grid_minor_only`](Self::grid_minor_only). They use the major grid
+    /// color unless set here, at the grid style's lower minor alpha.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .minor_ticks(4)
+    ///     .grid_both()
+    ///     .minor_grid_color(Color::LIGHT_GRAY)
+    ///     .minor_grid_style(LineStyle::Dotted)
+    ///     .minor_grid_width(0.3)
+    ///     .line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0])
+    ///     .save("minor_grid.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn minor_grid_color(mut self, color: Color) -> Self {
+        self.layout.grid_style.minor_color = Some(color);
+        self
+    }
+
+    /// Set the line style of minor grid lines
+    ///
+    /// Minor grid lines use the major grid line style unless set here.
+    pub fn minor_grid_style(mut self, style: LineStyle) -> Self {
+        self.layout.grid_style.minor_line_style = Some(style);
+        self
+    }
+
+    /// Set the width of minor grid lines in points
+    pub fn minor_grid_width(mut self, width: f32) -> Self {
+        self.layout.grid_style.minor_line_width = width.max(0.0);
+        self
+    }
+
     // ========== Annotation Methods ==========
 
     /// Add a text annotation at data coordinates
@@ -429,6 +699,71 @@ impl Plot {
         self
     }
 
+    /// Label a data point with offset text and an arrow pointing at it
+    ///
+    /// The text is anchored `offset` points away from `(x, y)` (positive y
+    /// is up) and the arrow runs from the edge of its text box to the point,
+    /// like matplotlib's `annotate(text, xy, xytext=offset,
+    /// textcoords="offset points", arrowprops=...)`. Offsetting in points keeps
+    /// the label clear of the feature regardless of the data range.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x: Vec<f64> = (0..100).map(|i| i as f64 * 0.1).collect();
+    /// let y: Vec<f64> = x.iter().map(|v| v.sin()).collect();
+    ///
+    /// Plot::new()
+    ///     .line(&x, &y)
+    ///     .annotate_point(
+    ///         std::f64::consts::FRAC_PI_2,
+    ///         1.0,
+    ///         "Maximum",
+    ///         (40.0, -30.0),
+    ///         ArrowStyle::new(),
+    ///     )
+    ///     .save("annotate_point.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn annotate_point<S: Into<String>>(
+        mut self,
+        x: f64,
+        y: f64,
+        text: S,
+        offset: (f32, f32),
+        arrow_style: ArrowStyle,
+    ) -> Self {
+        self.annotations
+            .push(Annotation::callout(x, y, text, offset, arrow_style));
+        self
+    }
+
+    /// Label a data point with offset text in a custom style
+    ///
+    /// See [`annotate_point`](Self::annotate_point); `text_style` controls the
+    /// label's font, alignment, and background box.
+    pub fn annotate_point_styled<S: Into<String>>(
+        mut self,
+        x: f64,
+        y: f64,
+        text: S,
+        offset: (f32, f32),
+        arrow_style: ArrowStyle,
+        text_style: TextStyle,
+    ) -> Self {
+        self.annotations.push(Annotation::callout_styled(
+            x,
+            y,
+            text,
+            offset,
+            arrow_style,
+            text_style,
+        ));
+        self
+    }
+
     /// Add a horizontal reference line spanning the plot width
     ///
     /// Uses dashed gray style by default.
@@ -586,6 +921,121 @@ impl Plot {
         self
     }
 
+    /// Add a fill between two curves only where `mask` is `true`
+    ///
+    /// Like matplotlib's `where=`: each run of `true` entries is filled as a
+    /// separate region, leaving gaps elsewhere, and where the curves cross at
+    /// a run boundary the fill edge is interpolated to the exact crossing.
+    /// `mask` needs one entry per x value; otherwise nothing is drawn.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Highlight where a exceeds b
+    /// let above: Vec<bool> = a.iter().zip(&b).map(|(a, b)| a > b).collect();
+    /// Plot::new()
+    ///     .line(&x, &a)
+    ///     .line(&x, &b)
+    ///     .fill_between_where(&x, &a, &b, &above, FillStyle::new().color(Color::RED))
+    ///     .save("exceedance.png")?;
+    /// ```
+    pub fn fill_between_where(
+        mut self,
+        x: &[f64],
+        y1: &[f64],
+        y2: &[f64],
+        mask: &[bool],
+        style: FillStyle,
+    ) -> Self {
+        self.annotations.push(Annotation::fill_between_where(
+            x.to_vec(),
+            y1.to_vec(),
+            y2.to_vec(),
+            mask.to_vec(),
+            style,
+        ));
+        self
+    }
+
+    /// Add a stacked area chart
+    ///
+    /// Each `(label, values)` layer is filled from the running sum of the
+    /// layers before it up to that sum plus its own values, so the top edge
+    /// traces the cumulative total, like matplotlib's `stackplot`. Layers take
+    /// the next palette colors and add area legend entries, shown once a
+    /// legend is enabled; empty labels are left out of the legend. Values pair
+    /// with `x` by index, and missing or non-finite values count as zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let years = vec![2020.0, 2021.0, 2022.0, 2023.0];
+    ///
+    /// Plot::new()
+    ///     .stackplot(
+    ///         &years,
+    ///         &[
+    ///             ("coal", vec![5.0, 4.5, 4.0, 3.2]),
+    ///             ("gas", vec![3.0, 3.2, 3.1, 3.0]),
+    ///             ("solar", vec![0.5, 0.9, 1.6, 2.4]),
+    ///         ],
+    ///     )
+    ///     .legend(Position::TopLeft)
+    ///     .save("stackplot.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn stackplot<S, D>(mut self, x: &[f64], layers: &[(S, D)]) -> Self
+    where
+        S: AsRef<str>,
+        D: AsRef<[f64]>,
+    {
+        let ys: Vec<Vec<f64>> = layers
+            .iter()
+            .map(|(_, values)| {
+                let values = values.as_ref();
+                (0..x.len())
+                    .map(|i| {
+                        values
+                            .get(i)
+                            .copied()
+                            .filter(|v| v.is_finite())
+                            .unwrap_or(0.0)
+                    })
+                    .collect()
+            })
+            .collect();
+        let stacks = crate::plots::continuous::compute_stack(
+            x,
+            &ys,
+            crate::plots::continuous::StackBaseline::Zero,
+        );
+
+        for ((label, _), (lower, upper)) in layers.iter().zip(stacks) {
+            let color = self
+                .display
+                .theme
+                .get_color(self.series_mgr.auto_color_index);
+            self.series_mgr.auto_color_index += 1;
+            let style = FillStyle::new().color(color).alpha(1.0);
+            self.annotations.push(Annotation::fill_between_styled(
+                x.to_vec(),
+                lower,
+                upper,
+                style,
+                false,
+            ));
+            if !label.as_ref().is_empty() {
+                self.layout
+                    .legend
+                    .entries
+                    .push(LegendItem::area(label.as_ref(), color, None));
+            }
+        }
+        self
+    }
+
     /// Add a horizontal span (shaded vertical region)
     ///
     /// Highlights a vertical region from x_min to x_max across the full plot height.
@@ -0,0 +1,56 @@
+//! Saving many independent plots concurrently
+
+use super::Plot;
+use crate::core::{PlottingError, Result};
+use rayon::prelude::*;
+use std::path::PathBuf;
+
+/// Render and save many plots concurrently, one PNG per path
+///
+/// Plots are rendered on the rayon thread pool, so independent figures use
+/// every core instead of saving one after another. `plots[i]` is saved to
+/// `paths[i]` exactly as [`Plot::save`] would, and the returned results are
+/// in the same order, so one failing plot does not stop the others.
+///
+/// When `plots` and `paths` differ in length nothing is rendered and every
+/// entry is [`PlottingError::InvalidInput`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ruviz::prelude::*;
+/// use std::path::PathBuf;
+///
+/// let (plots, paths): (Vec<Plot>, Vec<PathBuf>) = (1..=100)
+///     .map(|k| {
+///         let x: Vec<f64> = (0..200).map(|i| i as f64 * 0.05).collect();
+///         let y: Vec<f64> = x.iter().map(|v| (v * k as f64).sin()).collect();
+///         let plot = Plot::new().title(format!("k = {k}")).line(&x, &y).into_plot();
+///         (plot, PathBuf::from(format!("wave_{k}.png")))
+///     })
+///     .unzip();
+///
+/// for (index, result) in render_batch(plots, paths).into_iter().enumerate() {
+///     if let Err(error) = result {
+///         eprintln!("plot {index} failed: {error}");
+///     }
+/// }
+/// ```
+pub fn render_batch(plots: Vec<Plot>, paths: Vec<PathBuf>) -> Vec<Result<()>> {
+    if plots.len() != paths.len() {
+        let message = format!(
+            "render_batch needs one path per plot (got {} plots and {} paths)",
+            plots.len(),
+            paths.len()
+        );
+        return (0..plots.len().max(paths.len()))
+            .map(|_| Err(PlottingError::InvalidInput(message.clone())))
+            .collect();
+    }
+
+    plots
+        .into_par_iter()
+        .zip(paths)
+        .map(|(plot, path)| plot.save(path))
+        .collect()
+}
@@ -50,6 +50,8 @@
 //! ```
 
 use super::data::{PlotData, ReactiveValue};
+use super::types::{AspectMode, DownsampleMode};
+use crate::core::{Annotation, HatchPattern, LegendItemType};
 use crate::render::{Color, LineStyle, MarkerStyle};
 
 /// Extension trait providing a generic conditional combinator for fluent builders.
@@ -180,6 +182,16 @@ macro_rules! impl_terminal_methods {
                 self.finalize().render()
             }
 
+            /// Render the plot to an Image while reporting progress
+            ///
+            /// Finalizes the series before rendering.
+            pub fn render_with_progress<F>(self, progress: F) -> crate::core::Result<super::Image>
+            where
+                F: Fn(f32) + Send + Sync + 'static,
+            {
+                self.finalize().render_with_progress(progress)
+            }
+
             /// Render the plot to PNG bytes.
             ///
             /// Finalizes the series before rendering.
@@ -187,6 +199,28 @@ macro_rules! impl_terminal_methods {
                 self.finalize().render_png_bytes()
             }
 
+            /// Render the plot into an HTML canvas (requires the `wasm` feature)
+            ///
+            /// Finalizes the series before rendering.
+            #[cfg(feature = "wasm")]
+            pub fn render_to_canvas(
+                self,
+                canvas: &web_sys::HtmlCanvasElement,
+            ) -> crate::core::Result<()> {
+                self.finalize().render_to_canvas(canvas)
+            }
+
+            /// Render the plot and encode it into an in-memory buffer
+            ///
+            /// Finalizes the series before rendering.
+            #[cfg(not(target_arch = "wasm32"))]
+            pub fn save_to_buffer(
+                self,
+                format: crate::export::ImageFormat,
+            ) -> crate::core::Result<Vec<u8>> {
+                self.finalize().save_to_buffer(format)
+            }
+
             /// Render the plot to an SVG string
             ///
             /// Finalizes the series before rendering.
@@ -194,6 +228,16 @@ macro_rules! impl_terminal_methods {
                 self.finalize().render_to_svg()
             }
 
+            /// Render the plot to an SVG string with export options
+            ///
+            /// Finalizes the series before rendering.
+            pub fn render_to_svg_options(
+                self,
+                options: crate::export::SvgOptions,
+            ) -> crate::core::Result<String> {
+                self.finalize().render_to_svg_options(options)
+            }
+
             /// Export to SVG file
             ///
             /// Finalizes the series before exporting.
@@ -210,6 +254,18 @@ macro_rules! impl_terminal_methods {
                 self.finalize().save_pdf(path)
             }
 
+            /// Save to PDF file with document metadata
+            ///
+            /// Finalizes the series before saving.
+            #[cfg(all(feature = "pdf", not(target_arch = "wasm32")))]
+            pub fn save_pdf_with_metadata<P: AsRef<std::path::Path>>(
+                self,
+                path: P,
+                metadata: crate::export::PdfMetadata,
+            ) -> crate::core::Result<()> {
+                self.finalize().save_pdf_with_metadata(path, metadata)
+            }
+
             /// Save with specific dimensions
             ///
             /// Finalizes the series before saving.
@@ -223,6 +279,18 @@ macro_rules! impl_terminal_methods {
                 self.finalize().save_with_size(path, width, height)
             }
 
+            /// Save to a PNG file at a multiple of the DPI
+            ///
+            /// Finalizes the series before saving.
+            #[cfg(not(target_arch = "wasm32"))]
+            pub fn save_hires<P: AsRef<std::path::Path>>(
+                self,
+                path: P,
+                scale: f32,
+            ) -> crate::core::Result<()> {
+                self.finalize().save_hires(path, scale)
+            }
+
             impl_series_continuation_methods!(self.finalize());
 
             /// Set legend position
@@ -319,6 +387,24 @@ pub enum PlotInput {
     XY(Vec<f64>, Vec<f64>),
     /// Paired X-Y data from source-backed plot values.
     XYSource(super::PlotData, super::PlotData),
+    /// Paired X-Y data with a shaded band between `lower` and `upper`.
+    XYBand {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        lower: Vec<f64>,
+        upper: Vec<f64>,
+    },
+    /// Smoothed X-Y data drawn over a faint line of the `raw` values.
+    XYSmoothed {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        raw: Vec<f64>,
+    },
+    /// Least-squares fit line, with its confidence band drawn when `band` is set.
+    XYFit {
+        fit: crate::plots::regression::RegPlotData,
+        band: bool,
+    },
     /// 2D grid data (for heatmap, contour)
     Grid2D {
         x: Vec<f64>,
@@ -342,6 +428,14 @@ pub enum PlotInput {
         u: Vec<f64>,
         v: Vec<f64>,
     },
+    /// X-Y points with a per-point value for color-mapped scatter plots.
+    ColorMapped {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        c: Vec<f64>,
+    },
+    /// Labeled groups of values (for strip plots).
+    Groups(Vec<(String, Vec<f64>)>),
 }
 
 impl PlotInput {
@@ -351,10 +445,15 @@ impl PlotInput {
             PlotInput::Single(data) => data.len(),
             PlotInput::XY(x, _) => x.len(),
             PlotInput::XYSource(x, _) => x.len(),
+            PlotInput::XYBand { x, .. } => x.len(),
+            PlotInput::XYSmoothed { x, .. } => x.len(),
+            PlotInput::XYFit { fit, .. } => fit.line_x.len(),
             PlotInput::Grid2D { x, y, .. } => x.len() * y.len(),
             PlotInput::Categorical { values, .. } => values.len(),
             PlotInput::CategoricalSource { values, .. } => values.len(),
             PlotInput::Quiver { x, .. } => x.len(),
+            PlotInput::ColorMapped { x, .. } => x.len(),
+            PlotInput::Groups(groups) => groups.iter().map(|(_, values)| values.len()).sum(),
         }
     }
 }
@@ -414,6 +513,22 @@ pub struct SeriesStyle {
     pub marker_size: Option<f32>,
     /// Reactive marker size source
     pub marker_size_source: Option<ReactiveValue<f32>>,
+    /// Marker face color override (`Some(None)` draws outline-only markers)
+    pub marker_fill: Option<Option<Color>>,
+    /// Marker edge color and width in points
+    pub marker_edge: Option<(Color, f32)>,
+    /// Draw a marker on every nth data point only
+    pub marker_every: Option<usize>,
+    /// Douglas–Peucker tolerance in pixels applied to the drawn line
+    pub simplify: Option<f32>,
+    /// Pixel-column downsampling applied to the drawn line
+    pub downsample: Option<DownsampleMode>,
+    /// Whether the series is drawn (default `true`)
+    pub visible: Option<bool>,
+    /// Hatch pattern drawn over filled bars
+    pub hatch: Option<HatchPattern>,
+    /// Draw order; higher values draw on top (default 0)
+    pub zorder: Option<i32>,
     /// Alpha/transparency (0.0 = transparent, 1.0 = opaque)
     pub alpha: Option<f32>,
     /// Reactive alpha/transparency source
@@ -687,6 +802,37 @@ where
         self
     }
 
+    /// Set the draw order of this series
+    ///
+    /// Series with a higher z-order are drawn on top. Series share z-order 0
+    /// by default and ties keep insertion order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Plot::new()
+    ///     .line(&x, &fit)
+    ///     .zorder(1)
+    ///     .scatter(&x, &y)
+    ///     .save("fit_on_top.png")?;
+    /// ```
+    pub fn zorder(mut self, zorder: i32) -> Self {
+        self.style.zorder = Some(zorder);
+        self
+    }
+
+    /// Show or hide the series (default shown)
+    ///
+    /// A hidden series stays defined but is not drawn and does not count
+    /// toward the auto-scaled axis limits. A labeled hidden series keeps its
+    /// legend slot, drawn faded; leave the label off to drop it from the
+    /// legend too. Clicking the entry in the interactive window toggles it
+    /// back without moving the axes.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.style.visible = Some(visible);
+        self
+    }
+
     // ===== Error bar methods =====
 
     /// Attach symmetric Y error bars to this series
@@ -850,6 +996,27 @@ where
         self
     }
 
+    /// Set a title drawn in bold above the legend entries
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn legend_title(mut self, title: impl Into<String>) -> Self {
+        self.plot = self.plot.legend_title(title);
+        self
+    }
+
+    /// Add a legend entry that is not tied to a series
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn legend_entry(
+        mut self,
+        label: impl Into<String>,
+        item_type: LegendItemType,
+        color: Color,
+    ) -> Self {
+        self.plot = self.plot.legend_entry(label, item_type, color);
+        self
+    }
+
     /// Set figure size in inches
     ///
     /// This method forwards to the inner Plot.
@@ -911,6 +1078,38 @@ where
         self
     }
 
+    /// Pad auto-scaled axes by a fraction of the data range on each side
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn autoscale_margin(mut self, margin: f32) -> Self {
+        self.plot = self.plot.autoscale_margin(margin);
+        self
+    }
+
+    /// Set how the plot area's shape follows the axis limits
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn aspect(mut self, aspect: AspectMode) -> Self {
+        self.plot = self.plot.aspect(aspect);
+        self
+    }
+
+    /// Auto-scale the X axis symmetrically about zero
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn xlim_symmetric(mut self) -> Self {
+        self.plot = self.plot.xlim_symmetric();
+        self
+    }
+
+    /// Auto-scale the Y axis symmetrically about zero
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn ylim_symmetric(mut self) -> Self {
+        self.plot = self.plot.ylim_symmetric();
+        self
+    }
+
     /// Enable/disable grid
     ///
     /// This method forwards to the inner Plot.
@@ -959,6 +1158,46 @@ where
         self
     }
 
+    /// Set the major tick mark length in points.
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn tick_length(mut self, length: f32) -> Self {
+        self.plot = self.plot.tick_length(length);
+        self
+    }
+
+    /// Set the major tick mark width in points.
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn tick_width(mut self, width: f32) -> Self {
+        self.plot = self.plot.tick_width(width);
+        self
+    }
+
+    /// Set the minor tick mark length in points.
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn minor_tick_length(mut self, length: f32) -> Self {
+        self.plot = self.plot.minor_tick_length(length);
+        self
+    }
+
+    /// Set the minor tick mark width in points.
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn minor_tick_width(mut self, width: f32) -> Self {
+        self.plot = self.plot.minor_tick_width(width);
+        self
+    }
+
+    /// Set the gap between the axes and the tick labels in points.
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn tick_pad(mut self, pad: f32) -> Self {
+        self.plot = self.plot.tick_pad(pad);
+        self
+    }
+
     /// Show ticks on all four sides.
     ///
     /// This method forwards to the inner Plot.
@@ -1007,6 +1246,54 @@ where
         self
     }
 
+    /// Choose which plot-area borders (spines) are drawn.
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn spines(mut self, top: bool, right: bool, bottom: bool, left: bool) -> Self {
+        self.plot = self.plot.spines(top, right, bottom, left);
+        self
+    }
+
+    /// Hide the top and right spines.
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn despine(mut self) -> Self {
+        self.plot = self.plot.despine();
+        self
+    }
+
+    /// Place the bottom and left spines on the border or through the origin.
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn spine_position(mut self, position: crate::core::SpinePosition) -> Self {
+        self.plot = self.plot.spine_position(position);
+        self
+    }
+
+    /// Rotate X tick labels counter-clockwise by `degrees`.
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn xtick_rotation(mut self, degrees: f32) -> Self {
+        self.plot = self.plot.xtick_rotation(degrees);
+        self
+    }
+
+    /// Restrict X major ticks to integer values.
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn xaxis_integer(mut self, enabled: bool) -> Self {
+        self.plot = self.plot.xaxis_integer(enabled);
+        self
+    }
+
+    /// Restrict Y major ticks to integer values.
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn yaxis_integer(mut self, enabled: bool) -> Self {
+        self.plot = self.plot.yaxis_integer(enabled);
+        self
+    }
+
     /// Enable or disable Typst text rendering mode.
     ///
     /// This method forwards to the inner Plot.
@@ -1055,6 +1342,68 @@ where
         self
     }
 
+    /// Treat X values as Unix timestamps and label ticks as dates
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn xaxis_date(mut self, format: crate::axes::TimeFormat) -> Self {
+        self.plot = self.plot.xaxis_date(format);
+        self
+    }
+
+    /// Treat Y values as Unix timestamps and label ticks as dates
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn yaxis_date(mut self, format: crate::axes::TimeFormat) -> Self {
+        self.plot = self.plot.yaxis_date(format);
+        self
+    }
+
+    /// Format X-axis tick labels with a custom formatter
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn xtick_formatter(
+        mut self,
+        formatter: impl Into<crate::core::TickLabelFormatter>,
+    ) -> Self {
+        self.plot = self.plot.xtick_formatter(formatter);
+        self
+    }
+
+    /// Format Y-axis tick labels with a custom formatter
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn ytick_formatter(
+        mut self,
+        formatter: impl Into<crate::core::TickLabelFormatter>,
+    ) -> Self {
+        self.plot = self.plot.ytick_formatter(formatter);
+        self
+    }
+
+    /// Factor a common power of ten out of large or small tick labels
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn scientific_notation(mut self, enabled: bool) -> Self {
+        self.plot = self.plot.scientific_notation(enabled);
+        self
+    }
+
+    /// Factor a common power of ten out of the X tick labels
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn xaxis_scientific(mut self, enabled: bool) -> Self {
+        self.plot = self.plot.xaxis_scientific(enabled);
+        self
+    }
+
+    /// Factor a common power of ten out of the Y tick labels
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn yaxis_scientific(mut self, enabled: bool) -> Self {
+        self.plot = self.plot.yaxis_scientific(enabled);
+        self
+    }
+
     /// Set backend explicitly (overrides auto-optimization)
     ///
     /// This method forwards to the inner Plot.
@@ -1063,6 +1412,33 @@ where
         self
     }
 
+    /// Enable or disable anti-aliasing in raster output
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn antialias(mut self, enabled: bool) -> Self {
+        self.plot = self.plot.antialias(enabled);
+        self
+    }
+
+    /// Stop renders of this plot when `flag` is set
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn cancel_flag(mut self, flag: std::sync::Arc<std::sync::atomic::AtomicBool>) -> Self {
+        self.plot = self.plot.cancel_flag(flag);
+        self
+    }
+
+    /// Report render progress to `progress`
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn on_progress<F>(mut self, progress: F) -> Self
+    where
+        F: Fn(f32) + Send + Sync + 'static,
+    {
+        self.plot = self.plot.on_progress(progress);
+        self
+    }
+
     /// Store a GPU backend preference.
     ///
     /// This method forwards to the inner Plot. Public raster operations currently
@@ -1105,6 +1481,14 @@ where
         self
     }
 
+    /// Draw another plot inside this plot's axes
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn inset(mut self, inset: crate::axes::InsetAxes, plot: impl IntoPlot) -> Self {
+        self.plot = self.plot.inset(inset, plot);
+        self
+    }
+
     /// Add an arrow annotation
     ///
     /// This method forwards to the inner Plot.
@@ -1128,6 +1512,39 @@ where
         self
     }
 
+    /// Label a data point with offset text and an arrow pointing at it
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn annotate_point<S: Into<String>>(
+        mut self,
+        x: f64,
+        y: f64,
+        text: S,
+        offset: (f32, f32),
+        arrow_style: crate::core::ArrowStyle,
+    ) -> Self {
+        self.plot = self.plot.annotate_point(x, y, text, offset, arrow_style);
+        self
+    }
+
+    /// Label a data point with offset text in a custom style
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn annotate_point_styled<S: Into<String>>(
+        mut self,
+        x: f64,
+        y: f64,
+        text: S,
+        offset: (f32, f32),
+        arrow_style: crate::core::ArrowStyle,
+        text_style: crate::core::TextStyle,
+    ) -> Self {
+        self.plot = self
+            .plot
+            .annotate_point_styled(x, y, text, offset, arrow_style, text_style);
+        self
+    }
+
     /// Add a text annotation
     ///
     /// This method forwards to the inner Plot.
@@ -1238,6 +1655,33 @@ where
         self
     }
 
+    /// Add a stacked area chart
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn stackplot<S, D>(mut self, x: &[f64], layers: &[(S, D)]) -> Self
+    where
+        S: AsRef<str>,
+        D: AsRef<[f64]>,
+    {
+        self.plot = self.plot.stackplot(x, layers);
+        self
+    }
+
+    /// Add a fill between two curves only where `mask` is `true`
+    ///
+    /// This method forwards to the inner Plot.
+    pub fn fill_between_where(
+        mut self,
+        x: &[f64],
+        y1: &[f64],
+        y2: &[f64],
+        mask: &[bool],
+        style: crate::core::FillStyle,
+    ) -> Self {
+        self.plot = self.plot.fill_between_where(x, y1, y2, mask, style);
+        self
+    }
+
     /// Add a vertical span (shaded region)
     ///
     /// This method forwards to the inner Plot.
@@ -1286,6 +1730,16 @@ impl PlotBuilder<crate::plots::KdeConfig> {
         self
     }
 
+    /// Set the automatic bandwidth rule
+    ///
+    /// `BandwidthMethod::Silverman` uses the smaller of the standard deviation
+    /// and normalized IQR, which oversmooths multimodal data less than the
+    /// default Scott's rule. An explicit [`bandwidth`](Self::bandwidth) takes precedence.
+    pub fn bandwidth_method(mut self, method: crate::plots::BandwidthMethod) -> Self {
+        self.config.bandwidth_method = method;
+        self
+    }
+
     /// Set number of points for density curve
     ///
     /// More points create a smoother curve but increase computation time.
@@ -1448,10 +1902,107 @@ impl PlotBuilder<crate::plots::EcdfConfig> {
 impl_terminal_methods!(crate::plots::EcdfConfig);
 
 // =============================================================================
-// Contour Plot Builder
+// Rug Plot Builder
 // =============================================================================
 
-impl PlotBuilder<crate::plots::ContourConfig> {
+impl PlotBuilder<crate::plots::RugConfig> {
+    /// Set the length of the rug marks as a fraction of the plot area
+    ///
+    /// Default is 0.05 (5% of the plot height for X rugs).
+    pub fn height(mut self, height: f32) -> Self {
+        self.config.height = height.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set which axis the rug marks are drawn along
+    pub fn axis(mut self, axis: crate::plots::RugAxis) -> Self {
+        self.config.axis = axis;
+        self
+    }
+
+    /// Set the gap between the axis edge and the marks as a fraction of the
+    /// plot area
+    pub fn offset(mut self, offset: f32) -> Self {
+        self.config.offset = offset.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Finalize the rug series and add it to the plot
+    fn finalize(mut self) -> super::Plot {
+        let data = match &self.input {
+            PlotInput::Single(d) => d.clone(),
+            _ => vec![], // Should not happen for rugs
+        };
+
+        // Unset series styles fall back to the rug's thin, translucent marks
+        self.style.line_width = self.style.line_width.or(Some(self.config.line_width));
+        self.style.alpha = self.style.alpha.or(Some(self.config.alpha));
+        if self.style.color_source.is_none() {
+            self.style.color = self.style.color.or(self.config.color);
+        }
+
+        let rug_data = crate::plots::RugData {
+            points: data.into_iter().filter(|value| value.is_finite()).collect(),
+            config: self.config,
+        };
+        self.plot.add_rug_series(rug_data, self.style)
+    }
+}
+
+impl_terminal_methods!(crate::plots::RugConfig);
+
+// =============================================================================
+// Strip Plot Builder
+// =============================================================================
+
+impl PlotBuilder<crate::plots::categorical::StripConfig> {
+    /// Set the seed of the jitter's random number generator
+    ///
+    /// The same seed always places the points the same way, so figures are
+    /// reproducible. Default is 42.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = seed;
+        self
+    }
+
+    /// Set marker style
+    pub fn marker(mut self, style: crate::render::MarkerStyle) -> Self {
+        self.style.marker_style = Some(style);
+        self.style.marker_style_source = None;
+        self
+    }
+
+    /// Set marker size in points
+    pub fn marker_size(mut self, size: f32) -> Self {
+        self.style.marker_size = Some(size.max(0.1));
+        self.style.marker_size_source = None;
+        self
+    }
+
+    /// Finalize the strip series and add it to the plot
+    fn finalize(mut self) -> super::Plot {
+        let groups = match &self.input {
+            PlotInput::Groups(groups) => groups.as_slice(),
+            _ => &[], // Should not happen for strip plots
+        };
+        let strip_data = crate::plots::categorical::compute_strip_groups(groups, &self.config);
+
+        // Unset series styles fall back to the strip's translucent markers
+        self.style.alpha = self.style.alpha.or(Some(self.config.alpha));
+        if self.style.marker_size_source.is_none() {
+            self.style.marker_size = self.style.marker_size.or(Some(self.config.size));
+        }
+        self.plot.add_strip_series(strip_data, self.style)
+    }
+}
+
+impl_terminal_methods!(crate::plots::categorical::StripConfig);
+
+// =============================================================================
+// Contour Plot Builder
+// =============================================================================
+
+impl PlotBuilder<crate::plots::ContourConfig> {
     /// Set number of contour levels
     ///
     /// # Example
@@ -2048,6 +2599,54 @@ impl PlotBuilder<crate::plots::PolarPlotConfig> {
         self
     }
 
+    /// Set theta direction (true = counter-clockwise, false = clockwise)
+    pub fn theta_direction(mut self, counter_clockwise: bool) -> Self {
+        self.config.theta_direction = counter_clockwise;
+        self
+    }
+
+    /// Set the unit of the input theta values (radians by default)
+    pub fn theta_unit(mut self, unit: crate::plots::ThetaUnit) -> Self {
+        self.config.theta_unit = unit;
+        self
+    }
+
+    /// Set the unit used for angular tick labels (degrees by default)
+    pub fn theta_label_unit(mut self, unit: crate::plots::ThetaUnit) -> Self {
+        self.config.theta_label_unit = unit;
+        self
+    }
+
+    /// Show/hide radial grid circles
+    pub fn show_rgrid(mut self, show: bool) -> Self {
+        self.config.show_rgrid = show;
+        self
+    }
+
+    /// Show/hide angular grid spokes
+    pub fn show_thetgrid(mut self, show: bool) -> Self {
+        self.config.show_thetgrid = show;
+        self
+    }
+
+    /// Set target number of radial grid circles
+    pub fn rgrid_count(mut self, count: usize) -> Self {
+        self.config.rgrid_count = count.max(1);
+        self
+    }
+
+    /// Set number of angular grid spokes
+    pub fn thetgrid_count(mut self, count: usize) -> Self {
+        self.config.thetgrid_count = count.max(1);
+        self
+    }
+
+    /// Show/hide connecting lines between points
+    pub fn show_line(mut self, show: bool) -> Self {
+        self.config.show_line = show;
+        self
+    }
+
     /// Finalize the polar series and add it to the plot
     fn finalize(self) -> super::Plot {
         let (r, theta) = match &self.input {
@@ -2323,6 +2922,165 @@ impl PlotBuilder<crate::plots::QuiverConfig> {
 
 impl_terminal_methods!(crate::plots::QuiverConfig);
 
+// =============================================================================
+// Hexbin Plot Builder
+// =============================================================================
+
+impl PlotBuilder<crate::plots::HexbinConfig> {
+    /// Set the number of hexagons across the x-axis (minimum 5)
+    pub fn gridsize(mut self, size: usize) -> Self {
+        self.config.gridsize = size.max(5);
+        self
+    }
+
+    /// Set colormap by name (e.g., "viridis", "plasma", "magma")
+    pub fn cmap<S: Into<String>>(mut self, cmap: S) -> Self {
+        self.config.cmap = cmap.into();
+        self
+    }
+
+    /// Hide hexagons containing fewer than `count` points
+    pub fn mincnt(mut self, count: usize) -> Self {
+        self.config.mincnt = Some(count);
+        self
+    }
+
+    /// Clamp the color scale at `count` points
+    pub fn maxcnt(mut self, count: usize) -> Self {
+        self.config.maxcnt = Some(count);
+        self
+    }
+
+    /// Outline each hexagon with the given color and width (in points)
+    pub fn edge(mut self, color: Color, width: f32) -> Self {
+        self.config.edge_color = Some(color);
+        self.config.edge_width = width.max(0.0);
+        self
+    }
+
+    /// Use a logarithmic color scale for counts
+    pub fn log_scale(mut self, log: bool) -> Self {
+        self.config.log_scale = log;
+        self
+    }
+
+    /// Enable/disable colorbar showing the count scale
+    pub fn colorbar(mut self, show: bool) -> Self {
+        self.config.colorbar = show;
+        self
+    }
+
+    /// Set the colorbar label
+    pub fn colorbar_label(mut self, label: &str) -> Self {
+        self.config.colorbar_label = Some(label.to_string());
+        self
+    }
+
+    /// Finalize the hexbin series and add it to the plot.
+    fn finalize(self) -> super::Plot {
+        if self.plot.pending_ingestion_error().is_some() {
+            return self.plot;
+        }
+
+        let (x, y) = match &self.input {
+            PlotInput::XY(x, y) => (x.clone(), y.clone()),
+            _ => return self.plot,
+        };
+
+        if x.len() != y.len() {
+            let mut plot = self.plot;
+            plot.set_pending_ingestion_error(crate::core::PlottingError::DataLengthMismatch {
+                x_len: x.len(),
+                y_len: y.len(),
+                series_index: None,
+            });
+            return plot;
+        }
+
+        let hexbin_data = crate::plots::compute_hexbin(&x, &y, None, &self.config);
+        self.plot.add_hexbin_series(hexbin_data, self.style)
+    }
+}
+
+impl_terminal_methods!(crate::plots::HexbinConfig);
+
+// =============================================================================
+// Color-Mapped Scatter Builder
+// =============================================================================
+
+impl PlotBuilder<crate::plots::ScatterMappedConfig> {
+    /// Set marker style
+    pub fn marker(mut self, style: crate::render::MarkerStyle) -> Self {
+        self.style.marker_style = Some(style);
+        self.style.marker_style_source = None;
+        self
+    }
+
+    /// Set marker size in points
+    pub fn marker_size(mut self, size: f32) -> Self {
+        self.style.marker_size = Some(size.max(0.1));
+        self.style.marker_size_source = None;
+        self
+    }
+
+    /// Set the colormap
+    pub fn colormap(mut self, colormap: crate::render::ColorMap) -> Self {
+        self.config.colormap = colormap;
+        self
+    }
+
+    /// Set the value range mapped onto the colormap
+    ///
+    /// Values outside the range are clamped to the colormap ends.
+    pub fn clim(mut self, vmin: f64, vmax: f64) -> Self {
+        self.config.vmin = Some(vmin);
+        self.config.vmax = Some(vmax);
+        self
+    }
+
+    /// Enable/disable colorbar showing the value scale
+    pub fn colorbar(mut self, show: bool) -> Self {
+        self.config.colorbar = show;
+        self
+    }
+
+    /// Set the colorbar label
+    pub fn colorbar_label(mut self, label: &str) -> Self {
+        self.config.colorbar_label = Some(label.to_string());
+        self
+    }
+
+    /// Finalize the color-mapped scatter series and add it to the plot.
+    fn finalize(self) -> super::Plot {
+        if self.plot.pending_ingestion_error().is_some() {
+            return self.plot;
+        }
+
+        let (x, y, c) = match &self.input {
+            PlotInput::ColorMapped { x, y, c } => (x, y, c),
+            _ => return self.plot,
+        };
+
+        if x.len() != y.len() || x.len() != c.len() {
+            let x_len = x.len();
+            let y_len = if y.len() != x_len { y.len() } else { c.len() };
+            let mut plot = self.plot;
+            plot.set_pending_ingestion_error(crate::core::PlottingError::DataLengthMismatch {
+                x_len,
+                y_len,
+                series_index: None,
+            });
+            return plot;
+        }
+
+        let scatter_data = crate::plots::compute_scatter_mapped(x, y, c, &self.config);
+        self.plot
+            .add_scatter_mapped_series(scatter_data, self.style)
+    }
+}
+
+impl_terminal_methods!(crate::plots::ScatterMappedConfig);
+
 // ============================================================================
 // LineConfig PlotBuilder Implementation
 // ============================================================================
@@ -2376,9 +3134,126 @@ impl PlotBuilder<crate::plots::basic::LineConfig> {
         self
     }
 
+    /// Set the marker face color
+    ///
+    /// `None` draws outline-only (hollow) markers, like matplotlib's
+    /// `markerfacecolor='none'`; the edge defaults to the series color.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Plot::new()
+    ///     .line(&x, &y)
+    ///     .marker(MarkerStyle::Circle)
+    ///     .marker_fill(None)
+    ///     .marker_edge(Color::new(31, 119, 180), 1.0)
+    ///     .save("hollow_markers.png")?;
+    /// ```
+    pub fn marker_fill(mut self, fill: Option<Color>) -> Self {
+        self.style.marker_fill = Some(fill);
+        self
+    }
+
+    /// Set the marker edge color and width
+    ///
+    /// # Arguments
+    /// * `color` - Outline color, drawn over the marker face
+    /// * `width` - Outline width in points
+    pub fn marker_edge(mut self, color: Color, width: f32) -> Self {
+        self.style.marker_edge = Some((color, width.max(0.0)));
+        self
+    }
+
+    /// Draw a marker on every nth data point only
+    ///
+    /// Markers start at the first point and the line stays continuous,
+    /// like matplotlib's `markevery`. Enables circle markers if no marker
+    /// style was set.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Plot::new()
+    ///     .line(&x, &y)
+    ///     .marker(MarkerStyle::Square)
+    ///     .markevery(10)
+    ///     .save("markevery.png")?;
+    /// ```
+    pub fn markevery(mut self, n: usize) -> Self {
+        self.style.marker_every = Some(n.max(1));
+        self.show_markers(true)
+    }
+
+    /// Simplify the drawn line with Douglas–Peucker before stroking it
+    ///
+    /// Points are dropped after the data-to-pixel transform while the line
+    /// stays within `tolerance_px` pixels (at the reference DPI) of the
+    /// original path, like matplotlib's `path.simplify`. Dense curves draw
+    /// far fewer segments with no visible change. Markers still use every
+    /// data point. A tolerance of zero or less disables simplification.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x: Vec<f64> = (0..1_000_000).map(|i| i as f64 * 1e-5).collect();
+    /// let y: Vec<f64> = x.iter().map(|v| v.sin()).collect();
+    ///
+    /// Plot::new()
+    ///     .line(&x, &y)
+    ///     .simplify(0.5)
+    ///     .save("simplified.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn simplify(mut self, tolerance_px: f32) -> Self {
+        self.style.simplify = (tolerance_px > 0.0).then_some(tolerance_px);
+        self
+    }
+
+    /// Downsample the drawn line per pixel column before stroking it
+    ///
+    /// With [`DownsampleMode::MinMax`], a line with more points than the plot
+    /// is wide keeps only the lowest and highest point of each pixel column,
+    /// like an oscilloscope or audio waveform view. Spikes stay visible while
+    /// the stroked path shrinks to about two points per column. Shorter lines
+    /// and lines whose x values are not sorted draw every point. Markers
+    /// still use every data point.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let t: Vec<f64> = (0..2_000_000).map(|i| i as f64 / 48_000.0).collect();
+    /// let signal: Vec<f64> = t.iter().map(|t| (t * 440.0 * std::f64::consts::TAU).sin()).collect();
+    ///
+    /// Plot::new()
+    ///     .line(&t, &signal)
+    ///     .downsample(DownsampleMode::MinMax)
+    ///     .save("waveform.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn downsample(mut self, mode: DownsampleMode) -> Self {
+        self.style.downsample = Some(mode);
+        self
+    }
+
     /// Enable or disable markers on data points
+    ///
+    /// Enabling markers without a marker style draws circles.
     pub fn show_markers(mut self, show: bool) -> Self {
         self.config.show_markers = show;
+        if !show {
+            self.style.marker_style = None;
+            self.style.marker_style_source = None;
+        } else if self.style.marker_style.is_none() && self.style.marker_style_source.is_none() {
+            self.style.marker_style = Some(
+                self.config
+                    .marker
+                    .unwrap_or(crate::render::MarkerStyle::Circle),
+            );
+        }
         self
     }
 
@@ -2415,11 +3290,66 @@ impl PlotBuilder<crate::plots::basic::LineConfig> {
         self
     }
 
+    /// Draw a hatch pattern over the fill of an [`area`](super::Plot::area) plot
+    ///
+    /// Has no effect on plain line series.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Plot::new()
+    ///     .area(&x, &y, 0.0)
+    ///     .hatch(HatchPattern::DiagonalCross)
+    ///     .save("hatched_area.png")?;
+    /// ```
+    pub fn hatch(mut self, pattern: HatchPattern) -> Self {
+        if let PlotInput::XY(x_values, y_values) = &self.input
+            && let Some(Annotation::FillBetween { x, y1, style, .. }) =
+                self.plot.annotations.last_mut()
+            && x == x_values
+            && y1 == y_values
+        {
+            style.hatch = Some(pattern);
+        }
+        self
+    }
+
+    /// Label a [`regression`](super::Plot::regression) fit line with its
+    /// equation and R², e.g. `y = 2.000x - 1.000 (R² = 0.950)`
+    ///
+    /// Has no effect on other line series.
+    pub fn fit_label(mut self) -> Self {
+        if let PlotInput::XYFit { fit, .. } = &self.input {
+            self.style.label = Some(fit.equation());
+        }
+        self
+    }
+
+    /// Shade the approximate 95% confidence band around a
+    /// [`regression`](super::Plot::regression) fit line
+    ///
+    /// The band is drawn underneath the line in its color with reduced alpha,
+    /// as for [`line_with_band`](super::Plot::line_with_band). Has no effect on
+    /// other line series.
+    pub fn confidence_band(mut self) -> Self {
+        if let PlotInput::XYFit { band, .. } = &mut self.input {
+            *band = true;
+        }
+        self
+    }
+
     /// Finalize the line series and add it to the plot
     fn finalize(self) -> super::Plot {
         let (x_data, y_data) = match &self.input {
             PlotInput::XY(x, y) => (PlotData::Static(x.clone()), PlotData::Static(y.clone())),
             PlotInput::XYSource(x, y) => (x.clone(), y.clone()),
+            PlotInput::XYBand { x, y, .. } | PlotInput::XYSmoothed { x, y, .. } => {
+                (PlotData::Static(x.clone()), PlotData::Static(y.clone()))
+            }
+            PlotInput::XYFit { fit, .. } => (
+                PlotData::Static(fit.line_x.clone()),
+                PlotData::Static(fit.line_y.clone()),
+            ),
             PlotInput::Single(y) => {
                 // Generate x values as indices
                 let x: Vec<f64> = (0..y.len()).map(|i| i as f64).collect();
@@ -2428,8 +3358,74 @@ impl PlotBuilder<crate::plots::basic::LineConfig> {
             _ => (PlotData::Static(vec![]), PlotData::Static(vec![])),
         };
 
-        self.plot
-            .add_line_series(x_data, y_data, &self.config, self.style)
+        let mut plot = self.plot;
+        let band = match self.input {
+            PlotInput::XYBand {
+                x, lower, upper, ..
+            } => Some((x, lower, upper)),
+            PlotInput::XYFit {
+                fit:
+                    crate::plots::regression::RegPlotData {
+                        line_x,
+                        ci_lower: Some(lower),
+                        ci_upper: Some(upper),
+                        ..
+                    },
+                band: true,
+            } => Some((line_x, lower, upper)),
+            PlotInput::XYSmoothed { x, raw, .. } => {
+                // The faint raw line takes the palette slot and draws first; the
+                // trend reuses that slot and carries the legend entry
+                let mut raw_config = self.config.clone();
+                raw_config.show_markers = false;
+                let raw_style = SeriesStyle {
+                    label: None,
+                    marker_style: None,
+                    marker_style_source: None,
+                    y_errors: None,
+                    x_errors: None,
+                    alpha: Some(self.style.alpha.unwrap_or(self.config.alpha) * 0.3),
+                    alpha_source: None,
+                    ..self.style.clone()
+                };
+                plot = plot.add_line_series(
+                    PlotData::Static(x),
+                    PlotData::Static(raw),
+                    &raw_config,
+                    raw_style,
+                );
+                return plot.add_line_series_grouped(
+                    x_data,
+                    y_data,
+                    &self.config,
+                    self.style,
+                    None,
+                    false,
+                );
+            }
+            _ => None,
+        };
+
+        if let Some((x, lower, upper)) = band {
+            // Match the band to the color the line will resolve to
+            let color = self.style.color.unwrap_or_else(|| {
+                plot.display
+                    .theme
+                    .get_color(plot.series_mgr.auto_color_index)
+            });
+            let style = crate::core::FillStyle::new().color(color).alpha(0.25);
+            plot.annotations.push(Annotation::fill_between_styled(
+                x, lower, upper, style, false,
+            ));
+            let band_annotation = plot.annotations.len() - 1;
+            let mut plot = plot.add_line_series(x_data, y_data, &self.config, self.style);
+            if let Some(series) = plot.series_mgr.series.last_mut() {
+                series.band_annotation = Some(band_annotation);
+            }
+            return plot;
+        }
+
+        plot.add_line_series(x_data, y_data, &self.config, self.style)
     }
 }
 
@@ -2502,6 +3498,35 @@ impl PlotBuilder<crate::plots::basic::ScatterConfig> {
         self
     }
 
+    /// Set the marker face color
+    ///
+    /// `None` draws outline-only (hollow) markers, like matplotlib's
+    /// `markerfacecolor='none'`; the edge defaults to the series color.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Plot::new()
+    ///     .scatter(&x, &y)
+    ///     .marker_fill(None)
+    ///     .marker_edge(Color::new(31, 119, 180), 1.0)
+    ///     .save("hollow_markers.png")?;
+    /// ```
+    pub fn marker_fill(mut self, fill: Option<Color>) -> Self {
+        self.style.marker_fill = Some(fill);
+        self
+    }
+
+    /// Set the marker edge color and width
+    ///
+    /// # Arguments
+    /// * `color` - Outline color, drawn over the marker face
+    /// * `width` - Outline width in points
+    pub fn marker_edge(mut self, color: Color, width: f32) -> Self {
+        self.style.marker_edge = Some((color, width.max(0.0)));
+        self
+    }
+
     /// Finalize the scatter series and add it to the plot
     fn finalize(self) -> super::Plot {
         let (x_data, y_data) = match &self.input {
@@ -2575,6 +3600,62 @@ impl PlotBuilder<crate::plots::basic::BarConfig> {
         self
     }
 
+    /// Draw a hatch pattern over each bar
+    ///
+    /// Hatching keeps bars distinguishable when printed in grayscale.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Plot::new()
+    ///     .bar(&["A", "B", "C"], &[1.0, 2.0, 3.0])
+    ///     .hatch(HatchPattern::Diagonal)
+    ///     .save("hatched_bar.png")?;
+    /// ```
+    pub fn hatch(mut self, pattern: HatchPattern) -> Self {
+        self.style.hatch = Some(pattern);
+        self
+    }
+
+    /// Print each bar's value at its end, like matplotlib's `bar_label`
+    ///
+    /// Labels sit just above positive bars and just below negative ones,
+    /// in the theme's foreground color at the tick label size.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Plot::new()
+    ///     .bar(&["A", "B", "C"], &[1.0, 2.5, -0.5])
+    ///     .bar_labels()
+    ///     .save("labeled_bar.png")?;
+    /// ```
+    pub fn bar_labels(mut self) -> Self {
+        self.config.show_labels = true;
+        self
+    }
+
+    /// Print each bar's value formatted with a [`TickLabelFormatter`] or
+    /// `Fn(f64) -> String` closure
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Plot::new()
+    ///     .bar(&["A", "B", "C"], &[0.12, 0.5, 0.38])
+    ///     .bar_label_formatter(TickLabelFormatter::percent(0))
+    ///     .save("share.png")?;
+    /// ```
+    ///
+    /// [`TickLabelFormatter`]: crate::core::TickLabelFormatter
+    pub fn bar_label_formatter(
+        mut self,
+        formatter: impl Into<crate::core::TickLabelFormatter>,
+    ) -> Self {
+        self.config = self.config.label_formatter(formatter);
+        self
+    }
+
     /// Finalize the bar series and add it to the plot
     fn finalize(self) -> super::Plot {
         let (categories, values) = match &self.input {
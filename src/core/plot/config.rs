@@ -201,3 +201,27 @@ pub enum GridMode {
     /// Show grid lines at both major and minor ticks
     Both,
 }
+
+/// Axes whose ticks get grid lines
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum GridAxis {
+    /// Vertical grid lines at the X ticks only
+    X,
+    /// Horizontal grid lines at the Y ticks only
+    Y,
+    /// Grid lines at the ticks of both axes
+    #[default]
+    Both,
+}
+
+impl GridAxis {
+    /// Whether vertical grid lines are drawn at the X ticks.
+    pub fn shows_x(self) -> bool {
+        matches!(self, GridAxis::X | GridAxis::Both)
+    }
+
+    /// Whether horizontal grid lines are drawn at the Y ticks.
+    pub fn shows_y(self) -> bool {
+        matches!(self, GridAxis::Y | GridAxis::Both)
+    }
+}
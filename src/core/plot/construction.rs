@@ -76,12 +76,12 @@ fn resolve_plot_text(source: &PlotText, time: f64, cache: &mut Vec<(PlotText, St
 }
 
 fn resolve_series_for_frame<'a>(
-    series: &'a SeriesType,
+    series: &'a PlotSeries,
     time: f64,
     cache: &mut Vec<CachedResolvedData>,
     acknowledgements: &mut Vec<crate::data::StreamingBuffer<f64>>,
 ) -> Result<ResolvedSeries<'a>> {
-    Ok(match series {
+    Ok(match &series.series_type {
         SeriesType::Line { x_data, y_data } => ResolvedSeries::Line {
             x: resolve_plot_data(x_data, time, cache, acknowledgements),
             y: resolve_plot_data(y_data, time, cache, acknowledgements),
@@ -90,9 +90,12 @@ fn resolve_series_for_frame<'a>(
             x: resolve_plot_data(x_data, time, cache, acknowledgements),
             y: resolve_plot_data(y_data, time, cache, acknowledgements),
         },
-        SeriesType::Bar { categories, values } => ResolvedSeries::Bar {
+        SeriesType::Bar {
+            categories, values, ..
+        } => ResolvedSeries::Bar {
             categories,
             values: resolve_plot_data(values, time, cache, acknowledgements),
+            errors: series.y_errors.as_ref().map(ErrorValuesRef::from),
         },
         SeriesType::ErrorBars {
             x_data,
@@ -102,6 +105,7 @@ fn resolve_series_for_frame<'a>(
             x: resolve_plot_data(x_data, time, cache, acknowledgements),
             y: resolve_plot_data(y_data, time, cache, acknowledgements),
             y_errors: resolve_plot_data(y_errors, time, cache, acknowledgements),
+            errors: series.y_errors.as_ref().map(ErrorValuesRef::from),
         },
         SeriesType::ErrorBarsXY {
             x_data,
@@ -188,6 +192,7 @@ impl Plot {
             layout,
             render: RenderPipeline::new(),
             annotations: Vec::new(),
+            insets: Vec::new(),
             null_policy: NullPolicy::Error,
             pending_ingestion_error: None,
             series_groups: Vec::new(),
@@ -259,6 +264,50 @@ impl Plot {
         Self::new().theme(theme)
     }
 
+    /// Copy this plot's styling without its data
+    ///
+    /// The returned plot keeps the theme, configuration, dimensions, title and
+    /// axis labels, axis limits and scales, ticks, grid, and legend settings,
+    /// but has no series or annotations. Build one configured base plot and
+    /// call `template()` for each figure instead of repeating the builder
+    /// chain.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let base = Plot::with_theme(Theme::publication())
+    ///     .xlabel("Time (s)")
+    ///     .ylabel("Signal")
+    ///     .grid(true);
+    ///
+    /// for (name, scale) in [("a", 1.0), ("b", 2.0)] {
+    ///     base.template()
+    ///         .title(name)
+    ///         .line(&[0.0, 1.0, 2.0], &[0.0, scale, 0.0])
+    ///         .save(format!("{name}.png"))?;
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn template(&self) -> Self {
+        self.clone().with_series_cleared()
+    }
+
+    /// Remove all series, annotations, and insets, keeping the plot's styling
+    ///
+    /// Consuming counterpart of [`Plot::template`]. Auto colors restart at the
+    /// first palette entry.
+    pub fn with_series_cleared(mut self) -> Self {
+        self.series_mgr.clear();
+        self.series_groups.clear();
+        self.next_group_id = 0;
+        self.annotations.clear();
+        self.insets.clear();
+        self.pending_ingestion_error = None;
+        self
+    }
+
     /// Set null handling policy for dataframe-backed numeric ingestion.
     ///
     /// Default is [`NullPolicy::Error`], which fails on null values with
@@ -312,16 +361,35 @@ impl Plot {
         );
     }
 
-    pub(super) fn collect_legend_items(&self) -> Vec<LegendItem> {
-        let mut legend_items = Vec::new();
+    pub(crate) fn collect_legend_items(&self) -> Vec<LegendItem> {
+        self.collect_legend_entries()
+            .into_iter()
+            .map(|(item, _)| item)
+            .collect()
+    }
+
+    /// Legend items paired with the indices of the series each one stands for
+    ///
+    /// A group entry covers every series in the group. Entries whose series
+    /// are all hidden are faded. Manual entries come last and cover no series.
+    pub(crate) fn collect_legend_entries(&self) -> Vec<(LegendItem, Vec<usize>)> {
+        let mut legend_entries = Vec::new();
         let mut seen_group_ids = HashSet::new();
         let group_labels: HashMap<usize, &str> = self
             .series_groups
             .iter()
             .filter_map(|group| group.label.as_deref().map(|label| (group.id, label)))
             .collect();
+        let series = &self.series_mgr.series;
+        let fade = |item: LegendItem, indices: &[usize]| {
+            if indices.iter().all(|&index| !series[index].visible) {
+                item.faded()
+            } else {
+                item
+            }
+        };
 
-        for (idx, series) in self.series_mgr.series.iter().enumerate() {
+        for (idx, series_item) in series.iter().enumerate() {
             let palette_slot = self
                 .series_mgr
                 .auto_color_slots
@@ -329,7 +397,7 @@ impl Plot {
                 .copied()
                 .flatten()
                 .unwrap_or(idx);
-            if let Some(group_id) = series.group_id {
+            if let Some(group_id) = series_item.group_id {
                 if !seen_group_ids.insert(group_id) {
                     continue;
                 }
@@ -339,20 +407,39 @@ impl Plot {
                 };
 
                 let default_color = self.display.theme.get_color(palette_slot);
-                if let Some(item) = series.to_legend_item_with_label(
+                if let Some(item) = series_item.to_legend_item_with_label(
                     label.to_string(),
                     default_color,
                     &self.display.theme,
                 ) {
-                    legend_items.push(item);
+                    let indices: Vec<usize> = series
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, member)| member.group_id == Some(group_id))
+                        .map(|(index, _)| index)
+                        .collect();
+                    legend_entries.push((fade(item, &indices), indices));
                 }
                 continue;
             }
 
-            legend_items.extend(series.to_legend_items(palette_slot, &self.display.theme));
+            legend_entries.extend(
+                series_item
+                    .to_legend_items(palette_slot, &self.display.theme)
+                    .into_iter()
+                    .map(|item| (fade(item, &[idx]), vec![idx])),
+            );
         }
 
-        legend_items
+        // Manual entries stand for no series
+        legend_entries.extend(
+            self.layout
+                .legend
+                .entries
+                .iter()
+                .map(|item| (item.clone(), Vec::new())),
+        );
+        legend_entries
     }
 
     /// Set the theme for the plot (fluent API)
@@ -430,6 +517,35 @@ impl Plot {
         self
     }
 
+    /// Run this plot's parallel rendering on a caller-owned rayon pool
+    ///
+    /// Pass clones of the same `Arc` to every plot in a batch so they share
+    /// one set of worker threads. Plots configured with [`Plot::with_parallel`]
+    /// already share a lazily built pool per thread count.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    /// use std::sync::Arc;
+    ///
+    /// let pool = Arc::new(rayon::ThreadPoolBuilder::new().num_threads(4).build()?);
+    /// for i in 0..10 {
+    ///     let x: Vec<f64> = (0..100_000).map(|v| v as f64).collect();
+    ///     let y: Vec<f64> = x.iter().map(|v| (v * 1e-3 + i as f64).sin()).collect();
+    ///     Plot::new()
+    ///         .with_shared_pool(Arc::clone(&pool))
+    ///         .line(&x, &y)
+    ///         .save(format!("plot_{i}.png"))?;
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn with_shared_pool(mut self, pool: std::sync::Arc<rayon::ThreadPool>) -> Self {
+        self.render.parallel_renderer = self.render.parallel_renderer.with_pool(pool);
+        self
+    }
+
     /// Set parallel processing threshold
     #[cfg(feature = "parallel")]
     pub fn parallel_threshold(mut self, threshold: usize) -> Self {
@@ -632,8 +748,103 @@ impl Plot {
         self
     }
 
+    /// Pad auto-scaled axes by a fraction of the data range on each side
+    ///
+    /// The default of `0.05` adds 5% of the range beyond the data on both
+    /// ends, like matplotlib's `axes.xmargin`/`axes.ymargin`; `0.0` lets data
+    /// touch the plot edges. Log axes are instead rounded out to whole decades,
+    /// and symlog axes are padded in scale space.
+    /// Axes with `xlim`/`ylim` are not padded, and bars, histograms, and
+    /// `fill_between` areas keep their baseline flush with the axis (sticky
+    /// edges). Negative or non-finite values are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .autoscale_margin(0.0)
+    ///     .line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0])
+    ///     .save("margin.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn autoscale_margin(mut self, margin: f32) -> Self {
+        if margin.is_finite() && margin >= 0.0 {
+            self.layout.autoscale_margin = f64::from(margin);
+        }
+        self
+    }
+
+    /// Auto-scale the X axis symmetrically about zero
+    ///
+    /// The limits become `(-m, m)` with `m` the largest absolute X value in
+    /// the data, before the autoscale margin is added. Replaces any earlier
+    /// [`xlim`](Self::xlim); a later `xlim` takes precedence again. Ignored
+    /// on log axes.
+    pub fn xlim_symmetric(mut self) -> Self {
+        self.layout.x_limits = None;
+        self.layout.x_symmetric = true;
+        self
+    }
+
+    /// Auto-scale the Y axis symmetrically about zero
+    ///
+    /// Makes positive and negative deviations directly comparable, e.g. for
+    /// residual plots. See [`xlim_symmetric`](Self::xlim_symmetric).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .scatter(&[1.0, 2.0, 3.0, 4.0], &[0.4, -1.2, 0.3, -0.1])
+    ///     .ylim_symmetric()
+    ///     .hline(0.0)
+    ///     .save("residuals.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn ylim_symmetric(mut self) -> Self {
+        self.layout.y_limits = None;
+        self.layout.y_symmetric = true;
+        self
+    }
+
+    /// Set how the plot area's shape follows the axis limits
+    ///
+    /// With [`AspectMode::Equal`] one data unit covers the same number of
+    /// pixels on both axes, so circles stay circular. The axis limits are
+    /// kept and the plot area shrinks to the matching shape, centered in the
+    /// space it would otherwise fill. Ignored when either axis is log or
+    /// symlog scaled.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let t: Vec<f64> = (0..=100).map(|i| i as f64 * std::f64::consts::TAU / 100.0).collect();
+    /// let x: Vec<f64> = t.iter().map(|t| t.cos()).collect();
+    /// let y: Vec<f64> = t.iter().map(|t| t.sin()).collect();
+    ///
+    /// Plot::new()
+    ///     .line(&x, &y)
+    ///     .aspect(AspectMode::Equal)
+    ///     .save("circle.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn aspect(mut self, aspect: AspectMode) -> Self {
+        self.layout.aspect = aspect;
+        self
+    }
+
     /// Set X-axis scale type
     ///
+    /// An auto-scaled log axis starts at the smallest positive data value,
+    /// skipping zeros and negatives, and rendering fails if the data has no
+    /// positive values. Use [`AxisScale::SymLog`] for data around zero.
+    ///
     /// # Example
     ///
     /// ```rust,ignore
@@ -658,6 +869,10 @@ impl Plot {
 
     /// Set Y-axis scale type
     ///
+    /// An auto-scaled log axis starts at the smallest positive data value,
+    /// skipping zeros and negatives, and rendering fails if the data has no
+    /// positive values. Use [`AxisScale::SymLog`] for data around zero.
+    ///
     /// # Example
     ///
     /// ```rust,ignore
@@ -681,6 +896,62 @@ impl Plot {
         self
     }
 
+    /// Treat X values as Unix timestamps (seconds, UTC) and label ticks as dates
+    ///
+    /// Shorthand for `.xscale(AxisScale::time(format))`. Ticks land on natural
+    /// calendar boundaries (seconds, minutes, hours, days, months, years).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .line(&timestamps, &values)
+    ///     .xaxis_date(TimeFormat::new("%Y-%m-%d"))
+    ///     .save("timeseries.png")?;
+    /// ```
+    pub fn xaxis_date(self, format: TimeFormat) -> Self {
+        self.xscale(AxisScale::time(format))
+    }
+
+    /// Treat Y values as Unix timestamps (seconds, UTC) and label ticks as dates
+    ///
+    /// Shorthand for `.yscale(AxisScale::time(format))`.
+    pub fn yaxis_date(self, format: TimeFormat) -> Self {
+        self.yscale(AxisScale::time(format))
+    }
+
+    /// Format X-axis tick labels with a custom formatter
+    ///
+    /// Accepts a [`TickLabelFormatter`] or any `Fn(f64) -> String` closure and
+    /// takes precedence over the scale's default labels, like matplotlib's
+    /// `FuncFormatter`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .line(&x, &y)
+    ///     .xtick_formatter(TickLabelFormatter::si_prefix(1))
+    ///     .ytick_formatter(|v: f64| format!("{v:.0} ms"))
+    ///     .save("formatted.png")?;
+    /// ```
+    pub fn xtick_formatter(mut self, formatter: impl Into<TickLabelFormatter>) -> Self {
+        self.layout.x_tick_formatter = Some(formatter.into());
+        self
+    }
+
+    /// Format Y-axis tick labels with a custom formatter
+    ///
+    /// See [`Plot::xtick_formatter`].
+    pub fn ytick_formatter(mut self, formatter: impl Into<TickLabelFormatter>) -> Self {
+        self.layout.y_tick_formatter = Some(formatter.into());
+        self
+    }
+
     /// Set canvas dimensions in pixels
     ///
     /// This method automatically scales DPI based on canvas size to maintain
@@ -787,12 +1058,57 @@ impl Plot {
         self
     }
 
+    /// Multiply the DPI by `scale`, keeping the figure size in inches.
+    pub(crate) fn scale_dpi(mut self, scale: f32) -> Self {
+        let dpi = self.display.config.figure.dpi * scale;
+        self.display.config.figure.dpi = dpi;
+        self.display.dpi = dpi.round() as u32;
+        self.display.dimensions = match self.render.explicit_output_pixels {
+            Some((width, height)) => {
+                let scaled = (
+                    (width as f32 * scale).round() as u32,
+                    (height as f32 * scale).round() as u32,
+                );
+                self.render.explicit_output_pixels = Some(scaled);
+                scaled
+            }
+            None => self.display.config.canvas_size(),
+        };
+        self
+    }
+
     pub(crate) fn set_subplot_output_pixels(mut self, width: u32, height: u32) -> Self {
         self = self.set_output_pixels(width, height);
         self.render.allow_subplot_dimensions = true;
         self
     }
 
+    /// Pin the plot area with fixed margins given in pixels at `dpi`.
+    pub(crate) fn set_subplot_margins_px(
+        mut self,
+        left: f32,
+        right: f32,
+        top: f32,
+        bottom: f32,
+        dpi: f32,
+    ) -> Self {
+        use crate::core::units::px_to_in;
+
+        self.display.config.margins = MarginConfig::fixed(
+            px_to_in(left, dpi),
+            px_to_in(right, dpi),
+            px_to_in(top, dpi),
+            px_to_in(bottom, dpi),
+        );
+        self
+    }
+
+    /// Hide this plot's own legend, e.g. when a figure-level legend replaces it.
+    pub(crate) fn without_legend(mut self) -> Self {
+        self.layout.legend.enabled = false;
+        self
+    }
+
     /// Set DPI for export quality
     ///
     /// DPI only affects output resolution, not layout proportions.
@@ -965,6 +1281,58 @@ impl Plot {
         self
     }
 
+    /// Set the major tick mark length in points
+    ///
+    /// Minor ticks stay at 60% of this length unless set with
+    /// [`minor_tick_length`](Self::minor_tick_length).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .tick_length(6.0)
+    ///     .tick_width(1.0)
+    ///     .tick_pad(5.0)
+    ///     .minor_ticks(4)
+    ///     .minor_tick_length(3.0)
+    ///     .line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0])
+    ///     .save("ticks.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tick_length(mut self, length: f32) -> Self {
+        self.display.config.lines.tick_length = length.max(0.0);
+        self
+    }
+
+    /// Set the major tick mark width in points
+    ///
+    /// Minor ticks stay at 75% of this width unless set with
+    /// [`minor_tick_width`](Self::minor_tick_width).
+    pub fn tick_width(mut self, width: f32) -> Self {
+        self.display.config.lines.tick_width = width.max(0.0);
+        self
+    }
+
+    /// Set the minor tick mark length in points
+    pub fn minor_tick_length(mut self, length: f32) -> Self {
+        self.display.config.lines.minor_tick_length = Some(length.max(0.0));
+        self
+    }
+
+    /// Set the minor tick mark width in points
+    pub fn minor_tick_width(mut self, width: f32) -> Self {
+        self.display.config.lines.minor_tick_width = Some(width.max(0.0));
+        self
+    }
+
+    /// Set the gap between the axes and the tick labels in points
+    pub fn tick_pad(mut self, pad: f32) -> Self {
+        self.display.config.spacing.tick_pad = pad.max(0.0);
+        self
+    }
+
     /// Get the current PlotConfig
     pub fn get_config(&self) -> &PlotConfig {
         &self.display.config
@@ -1233,6 +1601,22 @@ impl Plot {
         }
     }
 
+    /// Pixel half-width of a box `box_width` data units wide; a degenerate x
+    /// range falls back to 15% of the plot width
+    pub(super) fn box_half_width_px(
+        plot_width: f32,
+        box_width: f64,
+        x_min: f64,
+        x_max: f64,
+    ) -> f32 {
+        let x_range = x_max - x_min;
+        if x_range.abs() < f64::EPSILON {
+            plot_width * 0.15
+        } else {
+            (box_width / x_range) as f32 * plot_width * 0.5
+        }
+    }
+
     pub(super) fn categorical_x_tick_pixels(
         plot_area: tiny_skia::Rect,
         x_min: f64,
@@ -1412,7 +1796,7 @@ impl Plot {
                 });
             } else {
                 resolved.push(resolve_series_for_frame(
-                    &series.series_type,
+                    series,
                     time,
                     &mut data_cache,
                     &mut streaming_acknowledgements,
@@ -1534,6 +1918,7 @@ impl Plot {
             layout: self.layout.clone(),
             render: self.render.clone(),
             annotations: self.annotations.clone(),
+            insets: self.insets.clone(),
             null_policy: self.null_policy,
             pending_ingestion_error: self.pending_ingestion_error.clone(),
             series_groups: self.series_groups.clone(),
@@ -1772,9 +2157,61 @@ impl Plot {
         self
     }
 
-    /// Enable/disable scientific notation on axes
+    /// Factor a common power of ten out of large or small tick labels
+    ///
+    /// Like matplotlib's offset text: on linear axes without a custom tick
+    /// formatter, ticks that would otherwise switch to e-notation are divided
+    /// by a common power of ten and the factor is drawn once as `"×10ⁿ"`,
+    /// below the right end of the X axis or above the top of the Y axis. Use
+    /// [`xaxis_scientific`](Self::xaxis_scientific) or
+    /// [`yaxis_scientific`](Self::yaxis_scientific) to enable it for one axis.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// // Y ticks read 0, 1, 2, 3, 4 with "×10⁴" above the axis
+    /// Plot::new()
+    ///     .line(&[0.0, 1.0, 2.0], &[0.0, 20_000.0, 40_000.0])
+    ///     .scientific_notation(true)
+    ///     .save("offset.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     pub fn scientific_notation(mut self, enabled: bool) -> Self {
-        self.layout.scientific_notation = enabled;
+        self.layout.x_scientific = enabled;
+        self.layout.y_scientific = enabled;
+        self
+    }
+
+    /// Factor a common power of ten out of the X tick labels
+    ///
+    /// The X-axis half of [`scientific_notation`](Self::scientific_notation);
+    /// the Y axis keeps its own setting.
+    pub fn xaxis_scientific(mut self, enabled: bool) -> Self {
+        self.layout.x_scientific = enabled;
+        self
+    }
+
+    /// Factor a common power of ten out of the Y tick labels
+    ///
+    /// The Y-axis half of [`scientific_notation`](Self::scientific_notation);
+    /// the X axis keeps its own setting.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// // Plain year labels on X, "×10⁶" above the Y axis
+    /// Plot::new()
+    ///     .line(&[2020.0, 2021.0, 2022.0], &[1.5e6, 2.5e6, 4.0e6])
+    ///     .yaxis_scientific(true)
+    ///     .save("population.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn yaxis_scientific(mut self, enabled: bool) -> Self {
+        self.layout.y_scientific = enabled;
         self
     }
 }
@@ -25,6 +25,25 @@ impl Image {
         }
     }
 
+    /// Create an image from an RGBA buffer, checking its length
+    ///
+    /// Unlike [`Image::new`], this rejects buffers that are not exactly
+    /// `width * height * 4` bytes, so it is the safer entry point for pixels
+    /// coming from outside the crate (GUI frameworks, decoders, GPU readback).
+    pub fn from_rgba(width: u32, height: u32, pixels: Vec<u8>) -> crate::core::Result<Self> {
+        let expected_len = (width as usize)
+            .saturating_mul(height as usize)
+            .saturating_mul(4);
+        if pixels.len() != expected_len {
+            return Err(crate::core::PlottingError::InvalidInput(format!(
+                "RGBA image buffer length mismatch: expected {expected_len} bytes for {width}x{height}, got {}",
+                pixels.len()
+            )));
+        }
+
+        Ok(Self::new(width, height, pixels))
+    }
+
     /// Get image width
     pub fn width(&self) -> u32 {
         self.width
@@ -35,8 +54,120 @@ impl Image {
         self.height
     }
 
+    /// Borrow the raw RGBA bytes, row-major from the top-left pixel
+    pub fn as_rgba_slice(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Copy the pixels with color channels multiplied by alpha
+    ///
+    /// The pixels are interpreted as straight alpha, such as decoded PNGs or
+    /// [`SkiaRenderer::into_image_demultiplied`](crate::render::SkiaRenderer::into_image_demultiplied).
+    /// `Plot::render()` already returns premultiplied pixels, so pass those
+    /// to premultiplied consumers directly, or use
+    /// [`to_straight_alpha`](Self::to_straight_alpha) for straight-alpha
+    /// consumers; converting them again would darken translucent pixels.
+    /// Fully opaque images come back unchanged.
+    pub fn to_premultiplied(&self) -> Vec<u8> {
+        self.pixels
+            .chunks_exact(4)
+            .flat_map(|px| crate::render::color::premultiply_rgba(px[0], px[1], px[2], px[3]))
+            .collect()
+    }
+
+    /// Copy the pixels with color channels divided by alpha
+    ///
+    /// The pixels are interpreted as premultiplied alpha, as returned by
+    /// `Plot::render()`. Use this for toolkits that take straight-alpha
+    /// RGBA, such as egui's `ColorImage::from_rgba_unmultiplied`. Fully
+    /// opaque images come back unchanged.
+    pub fn to_straight_alpha(&self) -> Vec<u8> {
+        self.pixels
+            .chunks_exact(4)
+            .flat_map(|px| {
+                tiny_skia::PremultipliedColorU8::from_rgba(px[0], px[1], px[2], px[3])
+                    .map(|color| {
+                        let color = color.demultiply();
+                        [color.red(), color.green(), color.blue(), color.alpha()]
+                    })
+                    .unwrap_or([px[0], px[1], px[2], px[3]])
+            })
+            .collect()
+    }
+
+    /// Copy the pixels in BGRA byte order
+    ///
+    /// Alpha is left as-is; only the red and blue channels are swapped.
+    pub fn to_bgra(&self) -> Vec<u8> {
+        self.pixels
+            .chunks_exact(4)
+            .flat_map(|px| [px[2], px[1], px[0], px[3]])
+            .collect()
+    }
+
     /// Encode the image as PNG bytes.
     pub fn encode_png(&self) -> crate::core::Result<Vec<u8>> {
         crate::export::encode_rgba_png(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_rgba_validates_buffer_length() {
+        let image = Image::from_rgba(2, 1, vec![1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        assert_eq!(image.as_rgba_slice(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        assert!(Image::from_rgba(2, 1, vec![0; 7]).is_err());
+        assert!(Image::from_rgba(2, 2, vec![0; 8]).is_err());
+    }
+
+    #[test]
+    fn test_premultiplied_and_bgra_conversions() {
+        let image = Image::from_rgba(2, 1, vec![200, 100, 50, 255, 200, 100, 50, 128]).unwrap();
+
+        assert_eq!(
+            image.to_premultiplied(),
+            vec![200, 100, 50, 255, 100, 50, 25, 128]
+        );
+        assert_eq!(image.to_bgra(), vec![50, 100, 200, 255, 50, 100, 200, 128]);
+
+        let premultiplied = Image::from_rgba(1, 1, vec![100, 50, 25, 128]).unwrap();
+        assert_eq!(premultiplied.to_straight_alpha(), vec![199, 100, 50, 128]);
+    }
+
+    #[test]
+    fn test_straight_alpha_undoes_render_premultiplication() {
+        let image = crate::core::Plot::new()
+            .theme(
+                crate::render::Theme::builder()
+                    .background(crate::render::Color::TRANSPARENT)
+                    .build(),
+            )
+            .ticks(false)
+            .grid(false)
+            .line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 0.0])
+            .color(crate::render::Color::RED)
+            .line_width(6.0)
+            .render()
+            .unwrap();
+        let straight = image.to_straight_alpha();
+
+        let mut translucent_red = 0;
+        for (premultiplied, straight) in image.pixels.chunks_exact(4).zip(straight.chunks_exact(4))
+        {
+            assert_eq!(premultiplied[3], straight[3]);
+            let red_only = premultiplied[0] > 0 && premultiplied[1] == 0 && premultiplied[2] == 0;
+            if red_only && premultiplied[3] < 255 {
+                translucent_red += 1;
+                assert!(straight[0] >= 250, "antialiased red edge: {straight:?}");
+            }
+        }
+        assert!(
+            translucent_red > 0,
+            "the transparent background leaves translucent edges"
+        );
+    }
+}
@@ -0,0 +1,256 @@
+//! Inset axes: a second plot drawn over a region of the main plot area
+
+use super::*;
+use crate::axes::InsetAxes;
+use crate::render::skia::map_data_to_pixels_scaled;
+
+/// A plot drawn into a fraction of its parent's plot area
+#[derive(Clone, Debug)]
+pub(crate) struct PlotInset {
+    pub(super) axes: InsetAxes,
+    pub(super) plot: Box<Plot>,
+}
+
+/// Pixel placement of one inset inside the parent canvas
+struct InsetPlacement {
+    left: u32,
+    top: u32,
+    width: u32,
+    height: u32,
+}
+
+impl Plot {
+    /// Draw another plot inside this plot's axes
+    ///
+    /// `inset` places the plot as fractions of the plot area, measured from
+    /// the lower-left corner like matplotlib's `inset_axes`. The inset keeps
+    /// its own series, axes, and styling, and is drawn over the series and
+    /// annotations but under the legend. Its tick labels and title are drawn
+    /// inside the inset bounds.
+    ///
+    /// When [`InsetAxes::data_limits`] is set, the inset zooms to that region
+    /// and the main plot outlines it, with connector lines to the inset
+    /// (matplotlib's `indicate_inset_zoom`) unless connectors are disabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::axes::InsetAxes;
+    /// use ruviz::prelude::*;
+    ///
+    /// let x: Vec<f64> = (0..500).map(|i| i as f64 * 0.02).collect();
+    /// let y: Vec<f64> = x.iter().map(|v| v.sin() + 0.05 * (v * 40.0).sin()).collect();
+    ///
+    /// let zoom = Plot::new().line(&x, &y).grid(false);
+    /// Plot::new()
+    ///     .line(&x, &y)
+    ///     .inset(
+    ///         InsetAxes::new(0.55, 0.55, 0.4, 0.4).data_limits(1.2, 2.0, 0.8, 1.1),
+    ///         zoom,
+    ///     )
+    ///     .save("inset_zoom.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn inset(mut self, inset: InsetAxes, plot: impl IntoPlot) -> Self {
+        self.insets.push(PlotInset {
+            axes: inset,
+            plot: Box::new(plot.into_plot()),
+        });
+        self
+    }
+
+    fn inset_placement(axes: &InsetAxes, plot_area: tiny_skia::Rect) -> Result<InsetPlacement> {
+        let (x, y, width, height) = axes.screen_rect((
+            f64::from(plot_area.x()),
+            f64::from(plot_area.y()),
+            f64::from(plot_area.width()),
+            f64::from(plot_area.height()),
+        ));
+        let (left, top) = (x.round().max(0.0) as u32, y.round().max(0.0) as u32);
+        let (width, height) = (width.round() as u32, height.round() as u32);
+        if width == 0 || height == 0 {
+            return Err(PlottingError::InvalidInput(format!(
+                "Inset bounds must cover at least one pixel (bounds={:?})",
+                axes.bounds
+            )));
+        }
+        Ok(InsetPlacement {
+            left,
+            top,
+            width,
+            height,
+        })
+    }
+
+    /// The inset plot as drawn: zoomed to the inset's data limits and
+    /// filled with its background
+    fn inset_plot(inset: &PlotInset, dpi: f32) -> Result<Plot> {
+        let mut plot = (*inset.plot).clone();
+        if let Some((x_min, x_max, y_min, y_max)) = inset.axes.data_limits {
+            plot = plot.xlim(x_min, x_max).ylim(y_min, y_max);
+        }
+        plot.display.theme.background = match &inset.axes.background {
+            Some(color) => Color::from_hex(color)?,
+            None => Color::TRANSPARENT,
+        };
+        plot.display.config.figure.dpi = dpi;
+        Ok(plot)
+    }
+
+    /// Inset axes outline, zoom rectangle, and connector polylines for one
+    /// inset, in parent canvas pixels
+    fn inset_decorations(
+        &self,
+        inset: &PlotInset,
+        placement: &InsetPlacement,
+        inset_axes_area: tiny_skia::Rect,
+        plot_area: tiny_skia::Rect,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+    ) -> Vec<Vec<(f32, f32)>> {
+        let rectangle = |left: f32, top: f32, right: f32, bottom: f32| {
+            vec![
+                (left, top),
+                (right, top),
+                (right, bottom),
+                (left, bottom),
+                (left, top),
+            ]
+        };
+        let (inset_left, inset_top) = (placement.left as f32, placement.top as f32);
+        let mut paths = vec![rectangle(
+            inset_left + inset_axes_area.left(),
+            inset_top + inset_axes_area.top(),
+            inset_left + inset_axes_area.right(),
+            inset_top + inset_axes_area.bottom(),
+        )];
+
+        let Some(zoom) = inset.axes.zoom_region() else {
+            return paths;
+        };
+        let to_pixels = |x: f64, y: f64| {
+            map_data_to_pixels_scaled(
+                x,
+                y,
+                x_min,
+                x_max,
+                y_min,
+                y_max,
+                plot_area,
+                &self.layout.x_scale,
+                &self.layout.y_scale,
+            )
+        };
+        let (zoom_left, zoom_bottom) = to_pixels(zoom.0, zoom.2);
+        let (zoom_right, zoom_top) = to_pixels(zoom.1, zoom.3);
+        paths.push(rectangle(zoom_left, zoom_top, zoom_right, zoom_bottom));
+
+        // Connector ends are given on the inset bounds; move them onto the
+        // inset's own axes box so they meet its spines
+        let (bounds_x, bounds_y, bounds_width, bounds_height) = inset.axes.bounds;
+        for ((data_x, data_y), (parent_x, parent_y)) in inset.axes.connector_lines(zoom) {
+            let fraction_x = ((parent_x - bounds_x) / bounds_width) as f32;
+            let fraction_y = ((parent_y - bounds_y) / bounds_height) as f32;
+            let inset_end = (
+                inset_left + inset_axes_area.left() + fraction_x * inset_axes_area.width(),
+                inset_top + inset_axes_area.bottom() - fraction_y * inset_axes_area.height(),
+            );
+            paths.push(vec![to_pixels(data_x, data_y), inset_end]);
+        }
+        paths
+    }
+
+    fn inset_border_style(&self, inset: &PlotInset) -> Result<Option<(Color, f32)>> {
+        let Some(color) = inset.axes.border_color.as_deref() else {
+            return Ok(None);
+        };
+        if inset.axes.border_width <= 0.0 {
+            return Ok(None);
+        }
+        Ok(Some((
+            Color::from_hex(color)?,
+            self.render_scale()
+                .points_to_pixels(inset.axes.border_width as f32),
+        )))
+    }
+
+    pub(super) fn draw_insets(
+        &self,
+        renderer: &mut SkiaRenderer,
+        plot_area: tiny_skia::Rect,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+    ) -> Result<()> {
+        let dpi = self.render_scale().dpi();
+        for inset in &self.insets {
+            let placement = Self::inset_placement(&inset.axes, plot_area)?;
+            let plot = Self::inset_plot(inset, dpi)?;
+            let inset_axes_area = plot.subplot_plot_area(placement.width, placement.height, dpi)?;
+            renderer.draw_subplot(
+                plot.render_subplot_image(placement.width, placement.height, dpi)?,
+                placement.left,
+                placement.top,
+            )?;
+
+            if let Some((color, width)) = self.inset_border_style(inset)? {
+                for path in self.inset_decorations(
+                    inset,
+                    &placement,
+                    inset_axes_area,
+                    plot_area,
+                    x_min,
+                    x_max,
+                    y_min,
+                    y_max,
+                ) {
+                    renderer.draw_polyline(&path, color, width, LineStyle::Solid)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn draw_insets_svg(
+        &self,
+        svg: &mut crate::export::SvgRenderer,
+        plot_area: tiny_skia::Rect,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+    ) -> Result<()> {
+        let dpi = self.render_scale().dpi();
+        for (index, inset) in self.insets.iter().enumerate() {
+            let placement = Self::inset_placement(&inset.axes, plot_area)?;
+            let plot = Self::inset_plot(inset, dpi)?
+                .set_subplot_output_pixels(placement.width, placement.height);
+            let inset_axes_area = plot.subplot_plot_area(placement.width, placement.height, dpi)?;
+            svg.draw_embedded_svg(
+                &plot.render_to_svg()?,
+                placement.left as f32,
+                placement.top as f32,
+                &format!("inset{index}-"),
+            );
+
+            if let Some((color, width)) = self.inset_border_style(inset)? {
+                for path in self.inset_decorations(
+                    inset,
+                    &placement,
+                    inset_axes_area,
+                    plot_area,
+                    x_min,
+                    x_max,
+                    y_min,
+                    y_max,
+                ) {
+                    svg.draw_polyline(&path, color, width, LineStyle::Solid);
+                }
+            }
+        }
+        Ok(())
+    }
+}
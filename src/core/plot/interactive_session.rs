@@ -5,8 +5,9 @@ use super::{
 use crate::{
     axes::{AxisScale, expand_degenerate_range},
     core::{
-        Annotation, CoordinateTransform, FillStyle, LayoutCalculator, LayoutConfig, MarginConfig,
-        PlotLayout, PlottingError, REFERENCE_DPI, RenderScale, Result, ShapeStyle,
+        Annotation, ArrowStyle, CoordSystem, CoordinateTransform, FillStyle, LayoutCalculator,
+        LayoutConfig, MarginConfig, PlotLayout, PlottingError, REFERENCE_DPI, RenderScale, Result,
+        ShapeStyle, TextStyle,
     },
     render::{
         Color, FontConfig, FontFamily, LineStyle, MarkerStyle, TextRenderer, Theme,
@@ -15,7 +16,7 @@ use crate::{
 };
 use std::{
     cell::RefCell,
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     sync::{
         Arc, Mutex, OnceLock,
         atomic::{AtomicU64, Ordering},
@@ -414,6 +415,14 @@ pub enum PlotInputEvent {
         position_px: ViewportPoint,
     },
     HideTooltip,
+    /// Toggle the series behind the legend entry at `position_px`
+    ToggleLegendEntry {
+        position_px: ViewportPoint,
+    },
+    SetSeriesVisible {
+        series_index: usize,
+        visible: bool,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -557,6 +566,7 @@ struct SessionState {
     overlay_cache: Option<OverlayFrameCache>,
     geometry: Option<GeometrySnapshot>,
     last_reactive_epoch: u64,
+    hidden_series: BTreeSet<usize>,
 }
 
 impl Default for SessionState {
@@ -582,6 +592,7 @@ impl Default for SessionState {
             overlay_cache: None,
             geometry: None,
             last_reactive_epoch: 0,
+            hidden_series: BTreeSet::new(),
         }
     }
 }
@@ -608,6 +619,7 @@ struct InteractiveFrameKey {
     y_min_bits: u64,
     y_max_bits: u64,
     versions: Vec<u64>,
+    hidden_series: Vec<usize>,
 }
 
 #[derive(Clone, Debug)]
@@ -694,6 +706,7 @@ enum AxisScaleIdentity {
     Linear,
     Log,
     SymLog { linthresh_bits: u64 },
+    Time,
 }
 
 impl From<&AxisScale> for AxisScaleIdentity {
@@ -704,6 +717,7 @@ impl From<&AxisScale> for AxisScaleIdentity {
             AxisScale::SymLog { linthresh } => Self::SymLog {
                 linthresh_bits: linthresh.to_bits(),
             },
+            AxisScale::Time { .. } => Self::Time,
         }
     }
 }
@@ -887,6 +901,7 @@ impl PointHitIndex {
             .iter()
             .enumerate()
             .map(|(series_index, series)| match &series.series_type {
+                _ if geometry.key.is_hidden(series_index) => None,
                 SeriesType::Line { .. }
                 | SeriesType::Scatter { .. }
                 | SeriesType::ErrorBars { .. }
@@ -1044,6 +1059,8 @@ struct GeometrySnapshot {
     annotation_render_scale: RenderScale,
     annotation_text_engine: TextEngineMode,
     transform: CoordinateTransform,
+    /// Legend entry bounds with the series each entry toggles
+    legend_entries: Vec<(ViewportRect, Vec<usize>)>,
 }
 
 impl GeometrySnapshot {
@@ -1250,6 +1267,11 @@ impl InteractiveFrameKey {
             && self.x_max_bits == other.x_max_bits
             && self.y_min_bits == other.y_min_bits
             && self.y_max_bits == other.y_max_bits
+            && self.hidden_series == other.hidden_series
+    }
+
+    fn is_hidden(&self, series_index: usize) -> bool {
+        self.hidden_series.binary_search(&series_index).is_ok()
     }
 }
 
@@ -1287,6 +1309,15 @@ impl InteractivePlotSession {
             data_bounds: initial_data_bounds,
             base_bounds: initial_bounds,
             visible_bounds: initial_bounds,
+            hidden_series: prepared
+                .plot()
+                .series_mgr
+                .series
+                .iter()
+                .enumerate()
+                .filter(|(_, series)| !series.visible)
+                .map(|(index, _)| index)
+                .collect(),
             ..SessionState::default()
         };
         sync_legacy_viewport_fields(
@@ -1779,7 +1810,100 @@ impl InteractivePlotSession {
                     self.mark_dirty(DirtyDomain::Overlay);
                 }
             }
+            PlotInputEvent::ToggleLegendEntry { position_px } => {
+                drop(state);
+                let Some(series) = self.legend_entry_at(position_px) else {
+                    return;
+                };
+                let mut state = self
+                    .inner
+                    .state
+                    .lock()
+                    .expect("InteractivePlotSession state lock poisoned");
+                self.begin_mutation();
+                // Show the whole entry again unless every series in it is shown
+                let visible = series
+                    .iter()
+                    .any(|index| state.hidden_series.contains(index));
+                let changed = Self::set_series_visibility(&mut state, &series, visible);
+                drop(state);
+                if changed {
+                    self.mark_dirty(DirtyDomain::Data);
+                    self.mark_dirty(DirtyDomain::Overlay);
+                }
+            }
+            PlotInputEvent::SetSeriesVisible {
+                series_index,
+                visible,
+            } => {
+                if series_index >= self.inner.prepared.plot().series_mgr.series.len() {
+                    return;
+                }
+                let changed = Self::set_series_visibility(&mut state, &[series_index], visible);
+                drop(state);
+                if changed {
+                    self.mark_dirty(DirtyDomain::Data);
+                    self.mark_dirty(DirtyDomain::Overlay);
+                }
+            }
+        }
+    }
+
+    /// Returns the series indices behind the legend entry at `position_px`.
+    ///
+    /// Group entries cover every series in the group, and manual entries from
+    /// [`Plot::legend_entry`] cover none. Returns `None` when no
+    /// legend entry is displayed at that position or before a base frame has
+    /// been rendered.
+    pub fn legend_entry_at(&self, position_px: ViewportPoint) -> Option<Vec<usize>> {
+        let geometry = self.displayed_geometry().ok()?;
+        geometry
+            .legend_entries
+            .iter()
+            .find(|(rect, _)| rect.contains(position_px))
+            .map(|(_, series)| series.clone())
+    }
+
+    /// Returns the indices of the series currently hidden, in ascending order.
+    pub fn hidden_series(&self) -> Vec<usize> {
+        self.inner
+            .state
+            .lock()
+            .expect("InteractivePlotSession state lock poisoned")
+            .hidden_series
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    fn set_series_visibility(state: &mut SessionState, series: &[usize], visible: bool) -> bool {
+        let mut changed = false;
+        for &index in series {
+            changed |= if visible {
+                state.hidden_series.remove(&index)
+            } else {
+                state.hidden_series.insert(index)
+            };
+        }
+        if changed {
+            let is_shown = |hit: &HitResult| match hit {
+                HitResult::SeriesPoint { series_index, .. }
+                | HitResult::HeatmapCell { series_index, .. } => {
+                    !state.hidden_series.contains(series_index)
+                }
+                HitResult::None => true,
+            };
+            if state.hovered.as_ref().is_some_and(|hit| !is_shown(hit)) {
+                state.hovered = None;
+                if state.tooltip_source == Some(TooltipSource::Hover) {
+                    state.tooltip = None;
+                    state.tooltip_source = None;
+                }
+            }
+            let selected = std::mem::take(&mut state.selected);
+            state.selected = selected.into_iter().filter(|hit| is_shown(hit)).collect();
         }
+        changed
     }
 
     pub fn hit_test(&self, position_px: ViewportPoint) -> HitResult {
@@ -2211,9 +2335,12 @@ impl InteractivePlotSession {
                     .expect("InteractivePlotSession state lock poisoned");
                 let previous_base = state.base_bounds;
                 let previous_visible = state.visible_bounds;
+                // Propagate bounds failures (e.g. a log axis left with no positive
+                // values) so the frame fails instead of keeping stale bounds
                 let next_data_bounds = resolved_frame
                     .as_ref()
-                    .and_then(|frame| compute_data_bounds_from_frame(source_plot, frame).ok())
+                    .map(|frame| compute_data_bounds_from_frame(source_plot, frame))
+                    .transpose()?
                     .unwrap_or(state.data_bounds);
                 state.data_bounds = next_data_bounds;
                 state.base_bounds = constraints.apply(next_data_bounds);
@@ -2457,6 +2584,11 @@ impl InteractivePlotSession {
             .prepared_frame_shell_with_style(state.size_px, state.scale_factor, &frame.style)
             .xlim(geometry.x_bounds.0, geometry.x_bounds.1)
             .ylim(geometry.y_bounds.0, geometry.y_bounds.1);
+        for &series_index in &state.hidden_series {
+            if let Some(series) = plot.series_mgr.series.get_mut(series_index) {
+                series.visible = false;
+            }
+        }
         if self.prefer_gpu() {
             #[cfg(feature = "gpu")]
             {
@@ -2919,7 +3051,9 @@ impl InteractivePlotSession {
             let Some(cached) = state.base_cache.clone() else {
                 return Ok(None);
             };
-            if !cached.key.same_viewport(key) {
+            // Streaming draws append to every series, so only take the
+            // incremental path when nothing is hidden
+            if !cached.key.same_viewport(key) || !key.hidden_series.is_empty() {
                 return Ok(None);
             }
             (cached, state.clone())
@@ -3084,7 +3218,8 @@ fn compute_data_bounds(plot: &Plot, time: f64) -> Result<DataBounds> {
         ));
     }
 
-    let (x_min, x_max, y_min, y_max) = plot.calculate_data_bounds_for_series(&series)?;
+    let (x_min, x_max, y_min, y_max) =
+        plot.apply_autoscale_margin(&series, plot.calculate_data_bounds_for_series(&series)?);
     Ok(DataBounds::from_limits(x_min, x_max, y_min, y_max))
 }
 
@@ -3096,11 +3231,14 @@ fn compute_data_bounds_from_frame(plot: &Plot, frame: &ResolvedFrame<'_>) -> Res
         ));
     }
 
-    let (mut x_min, mut x_max, mut y_min, mut y_max) =
-        plot.calculate_data_bounds_from_resolved(&frame.series)?;
+    let (mut x_min, mut x_max, mut y_min, mut y_max) = plot.calculate_data_bounds_from_resolved(
+        Plot::visible_resolved_series(&plot.series_mgr.series, &frame.series),
+    )?;
 
     (x_min, x_max) = expand_degenerate_range(x_min, x_max, &plot.layout.x_scale);
     (y_min, y_max) = expand_degenerate_range(y_min, y_max, &plot.layout.y_scale);
+    (x_min, x_max, y_min, y_max) =
+        plot.apply_autoscale_margin(&plot.series_mgr.series, (x_min, x_max, y_min, y_max));
 
     Ok(DataBounds::from_limits(x_min, x_max, y_min, y_max))
 }
@@ -3453,23 +3591,40 @@ fn validate_annotation_fill_style(style: &FillStyle) -> Result<()> {
     require_non_negative_annotation_f32(style.edge_width, "fill edge width")
 }
 
+fn validate_annotation_text_style(style: &TextStyle) -> Result<()> {
+    if !style.font_size.is_finite() || style.font_size <= 0.0 {
+        return Err(invalid_annotation(
+            "text font size must be finite and positive",
+        ));
+    }
+    require_finite_annotation_f64(f64::from(style.rotation), "text rotation")?;
+    require_non_negative_annotation_f32(style.padding, "text padding")?;
+    require_non_negative_annotation_f32(style.border_width, "text border width")
+}
+
+fn validate_annotation_arrow_style(style: &ArrowStyle) -> Result<()> {
+    require_non_negative_annotation_f32(style.line_width, "arrow line width")?;
+    require_non_negative_annotation_f32(style.head_length, "arrow head length")?;
+    require_non_negative_annotation_f32(style.head_width, "arrow head width")?;
+    validate_annotation_line_style(&style.line_style, "arrow line style")
+}
+
 fn validate_dynamic_annotation(
     annotation: &Annotation,
     x_scale: &crate::axes::AxisScale,
     y_scale: &crate::axes::AxisScale,
 ) -> Result<()> {
+    // Axes and figure fractions are placed without the axis scales
+    let linear = crate::axes::AxisScale::Linear;
+    let (x_scale, y_scale) = match annotation.coord_system() {
+        Some(CoordSystem::Axes | CoordSystem::Figure) => (&linear, &linear),
+        Some(CoordSystem::Data) | None => (x_scale, y_scale),
+    };
     match annotation {
         Annotation::Text { x, y, style, .. } => {
             require_annotation_coord_in_scale_domain(*x, x_scale, "text x")?;
             require_annotation_coord_in_scale_domain(*y, y_scale, "text y")?;
-            if !style.font_size.is_finite() || style.font_size <= 0.0 {
-                return Err(invalid_annotation(
-                    "text font size must be finite and positive",
-                ));
-            }
-            require_finite_annotation_f64(f64::from(style.rotation), "text rotation")?;
-            require_non_negative_annotation_f32(style.padding, "text padding")?;
-            require_non_negative_annotation_f32(style.border_width, "text border width")
+            validate_annotation_text_style(style)
         }
         Annotation::Arrow {
             x1,
@@ -3477,6 +3632,7 @@ fn validate_dynamic_annotation(
             x2,
             y2,
             style,
+            ..
         } => {
             for (value, scale, label) in [
                 (*x1, x_scale, "arrow x1"),
@@ -3486,10 +3642,22 @@ fn validate_dynamic_annotation(
             ] {
                 require_annotation_coord_in_scale_domain(value, scale, label)?;
             }
-            require_non_negative_annotation_f32(style.line_width, "arrow line width")?;
-            require_non_negative_annotation_f32(style.head_length, "arrow head length")?;
-            require_non_negative_annotation_f32(style.head_width, "arrow head width")?;
-            validate_annotation_line_style(&style.line_style, "arrow line style")
+            validate_annotation_arrow_style(style)
+        }
+        Annotation::Callout {
+            x,
+            y,
+            offset,
+            text_style,
+            arrow_style,
+            ..
+        } => {
+            require_annotation_coord_in_scale_domain(*x, x_scale, "callout x")?;
+            require_annotation_coord_in_scale_domain(*y, y_scale, "callout y")?;
+            require_finite_annotation_f64(f64::from(offset.0), "callout x offset")?;
+            require_finite_annotation_f64(f64::from(offset.1), "callout y offset")?;
+            validate_annotation_text_style(text_style)?;
+            validate_annotation_arrow_style(arrow_style)
         }
         Annotation::HLine {
             y, style, width, ..
@@ -3511,6 +3679,7 @@ fn validate_dynamic_annotation(
             width,
             height,
             style,
+            ..
         } => {
             require_annotation_coord_in_scale_domain(*x, x_scale, "rectangle x")?;
             require_annotation_coord_in_scale_domain(*y, y_scale, "rectangle y")?;
@@ -3521,13 +3690,26 @@ fn validate_dynamic_annotation(
             validate_annotation_shape_style(style, "rectangle")
         }
         Annotation::FillBetween {
-            x, y1, y2, style, ..
+            x,
+            y1,
+            y2,
+            style,
+            where_mask,
+            ..
         } => {
             if x.len() < 2 || x.len() != y1.len() || x.len() != y2.len() {
                 return Err(invalid_annotation(
                     "FillBetween x, y1, and y2 must have equal lengths of at least 2",
                 ));
             }
+            if where_mask
+                .as_ref()
+                .is_some_and(|mask| mask.len() != x.len())
+            {
+                return Err(invalid_annotation(
+                    "FillBetween where mask must have one entry per x value",
+                ));
+            }
             for (label, scale, values) in [
                 ("FillBetween x", x_scale, x),
                 ("FillBetween y1", y_scale, y1),
@@ -3598,7 +3780,7 @@ fn clip_overlay_to_plot_area(pixels: &mut [u8], size_px: (u32, u32), plot_area:
 
 fn axis_accepts_value(scale: &AxisScale, value: f64) -> bool {
     match scale {
-        AxisScale::Linear | AxisScale::SymLog { .. } => true,
+        AxisScale::Linear | AxisScale::SymLog { .. } | AxisScale::Time { .. } => true,
         AxisScale::Log => value > 0.0,
     }
 }
@@ -3676,6 +3858,9 @@ fn hit_test_displayed_frame(
     let mut best_distance = f64::INFINITY;
 
     for (series_index, series) in plot.series_mgr.series.iter().enumerate() {
+        if geometry.key.is_hidden(series_index) {
+            continue;
+        }
         match &series.series_type {
             SeriesType::Line { .. }
             | SeriesType::Scatter { .. }
@@ -3782,6 +3967,7 @@ fn build_frame_key(plot: &Plot, state: &SessionState) -> InteractiveFrameKey {
         y_min_bits: visible.y_min.to_bits(),
         y_max_bits: visible.y_max.to_bits(),
         versions: plot.collect_reactive_versions(),
+        hidden_series: state.hidden_series.iter().copied().collect(),
     }
 }
 
@@ -3799,6 +3985,7 @@ struct ComputedSessionLayout {
     annotation_font_family: FontFamily,
     annotation_render_scale: RenderScale,
     annotation_text_engine: TextEngineMode,
+    legend_entries: Vec<(ViewportRect, Vec<usize>)>,
 }
 
 fn geometry_snapshot_for_state(
@@ -3834,6 +4021,7 @@ fn geometry_snapshot_for_state(
             layout.plot_area_rect.left()..layout.plot_area_rect.right(),
             layout.plot_area_rect.top()..layout.plot_area_rect.bottom(),
         ),
+        legend_entries: layout.legend_entries,
     })
 }
 
@@ -3843,6 +4031,13 @@ fn refresh_hit_result(
     displayed_data: &DisplayedFrameData,
     geometry: &GeometrySnapshot,
 ) -> Option<HitResult> {
+    if let HitResult::SeriesPoint { series_index, .. }
+    | HitResult::HeatmapCell { series_index, .. } = hit
+    {
+        if geometry.key.is_hidden(*series_index) {
+            return None;
+        }
+    }
     match hit {
         HitResult::SeriesPoint {
             series_index,
@@ -3941,19 +4136,43 @@ fn compute_plot_layout_from_frame(
         visible.y_min,
         visible.y_max,
     );
-    let measured_dimensions = layout_plot.measure_layout_text_with_ticks(
+    let (x_label_ticks, y_label_ticks) = layout_plot.tick_label_values(
+        &x_ticks,
+        &y_ticks,
+        visible.x_min,
+        visible.x_max,
+        visible.y_min,
+        visible.y_max,
+    );
+    let mut measured_dimensions = layout_plot.measure_layout_text_with_ticks(
         &renderer,
         &content,
         dpi,
-        &crate::render::skia::format_tick_labels(&x_ticks),
-        &crate::render::skia::format_tick_labels(&y_ticks),
+        &layout_plot.layout.x_tick_labels(&x_label_ticks),
+        &layout_plot.layout.y_tick_labels(&y_label_ticks),
     )?;
-    let layout = layout_plot.compute_layout_from_measurements(
+    if content.show_tick_labels {
+        layout_plot.measure_tick_offset_texts(
+            &renderer,
+            dpi,
+            &x_ticks,
+            &y_ticks,
+            &mut measured_dimensions,
+        )?;
+    }
+    let mut layout = layout_plot.compute_layout_from_measurements(
         size_px,
         &content,
         dpi,
         measured_dimensions.as_ref(),
     );
+    layout_plot.fit_layout_to_aspect(
+        &mut layout,
+        visible.x_min,
+        visible.x_max,
+        visible.y_min,
+        visible.y_max,
+    );
 
     let plot_area_rect = tiny_skia::Rect::from_ltrb(
         layout.plot_area.left,
@@ -3966,8 +4185,27 @@ fn compute_plot_layout_from_frame(
         position: None,
     })?;
 
+    let (legend_items, legend_series): (Vec<_>, Vec<_>) =
+        layout_plot.collect_legend_entries().into_iter().unzip();
+    let legend_entries = renderer
+        .legend_entry_rects(
+            &legend_items,
+            &frame.style.legend,
+            plot_area_rect,
+            layout.legend_rect.as_ref().map(|rect| rect.bounds()),
+        )
+        .into_iter()
+        .map(|(index, rect)| {
+            (
+                plot_area_to_viewport_rect(rect),
+                legend_series[index].clone(),
+            )
+        })
+        .collect();
+
     Ok(ComputedSessionLayout {
         plot_area_rect,
+        legend_entries,
         annotation_theme: layout_plot.display.theme.clone(),
         annotation_font_family: layout_plot.display.config.typography.family.clone(),
         annotation_render_scale: layout_plot.render_scale(),
@@ -59,6 +59,14 @@ pub(super) fn collect_streaming_draw_ops(
     let mut saw_streaming_update = false;
 
     for (series_index, series) in plot.series_mgr.series.iter().enumerate() {
+        // Painted or subsampled markers are only drawn by the full render path.
+        if series.series_type.is_reactive()
+            && (series.marker_fill.is_some()
+                || series.marker_edge.is_some()
+                || series.marker_step() > 1)
+        {
+            return Ok(None);
+        }
         let style = frame.style.series.get(series_index).ok_or_else(|| {
             PlottingError::RenderError("resolved series style count mismatch".to_string())
         })?;
@@ -182,6 +182,83 @@ fn test_interactive_capture_honors_outside_legend_layout() {
     assert!(snapshot.plot_area.max.x < plain_snapshot.plot_area.max.x);
 }
 
+#[test]
+fn test_clicking_legend_entry_toggles_series_visibility() {
+    let plot: Plot = Plot::new()
+        .line(&[0.0, 0.5, 2.0], &[0.0, 0.5, 2.0])
+        .label("rising")
+        .line(&[0.0, 1.0, 2.0], &[2.0, 1.0, 0.0])
+        .label("falling")
+        .legend_position(crate::core::LegendPosition::UpperRight)
+        .xlim(0.0, 2.0)
+        .ylim(0.0, 2.0)
+        .into();
+    let session = plot.prepare_interactive();
+    let before = session
+        .render_to_surface(render_target())
+        .expect("initial frame should render");
+
+    let geometry = session.geometry_snapshot().unwrap();
+    assert_eq!(geometry.legend_entries.len(), 2);
+    let (rising_rect, rising_series) = geometry.legend_entries[0].clone();
+    assert_eq!(rising_series, vec![0]);
+    let entry_center = ViewportPoint::new(
+        (rising_rect.min.x + rising_rect.max.x) / 2.0,
+        (rising_rect.min.y + rising_rect.max.y) / 2.0,
+    );
+    assert_eq!(session.legend_entry_at(entry_center), Some(vec![0]));
+
+    // (0.5, 0.5) is a point of the rising line only
+    let (screen_x, screen_y) = map_data_to_pixels(
+        0.5,
+        0.5,
+        geometry.x_bounds.0,
+        geometry.x_bounds.1,
+        geometry.y_bounds.0,
+        geometry.y_bounds.1,
+        geometry.plot_area,
+    );
+    let point = ViewportPoint::new(screen_x as f64, screen_y as f64);
+    session.apply_input(PlotInputEvent::SelectAt { position_px: point });
+    assert!(matches!(
+        session.hit_test(point),
+        HitResult::SeriesPoint {
+            series_index: 0,
+            ..
+        }
+    ));
+
+    session.apply_input(PlotInputEvent::ToggleLegendEntry {
+        position_px: entry_center,
+    });
+    assert_eq!(session.hidden_series(), vec![0]);
+    let hidden = session
+        .render_to_surface(render_target())
+        .expect("frame with hidden series should render");
+    assert_ne!(before.image.pixels, hidden.image.pixels);
+    assert!(
+        !matches!(
+            session.hit_test(point),
+            HitResult::SeriesPoint {
+                series_index: 0,
+                ..
+            }
+        ),
+        "hidden series should not be hit"
+    );
+    assert!(session.inner.state.lock().unwrap().selected.is_empty());
+    assert_eq!(session.legend_entry_at(entry_center), Some(vec![0]));
+
+    session.apply_input(PlotInputEvent::ToggleLegendEntry {
+        position_px: entry_center,
+    });
+    assert!(session.hidden_series().is_empty());
+    let shown = session
+        .render_to_surface(render_target())
+        .expect("frame with restored series should render");
+    assert_eq!(before.image.pixels, shown.image.pixels);
+}
+
 #[test]
 fn test_render_to_image_uses_fitted_size_when_requested() {
     let plot: Plot = Plot::new()
@@ -847,6 +924,7 @@ fn test_interactive_log_auto_bounds_preserve_sub_epsilon_positive_range() {
     let plot: Plot = Plot::new()
         .line(&[min, max], &[0.0, 1.0])
         .xscale(crate::axes::AxisScale::Log)
+        .autoscale_margin(0.0)
         .ylim(0.0, 1.0)
         .ticks(false)
         .grid(false)
@@ -3929,12 +4007,20 @@ fn test_dynamic_annotation_validation_happens_before_mutation() {
         Annotation::fill_between(Vec::new(), Vec::new(), Vec::new()),
         Annotation::fill_between(vec![0.5], vec![0.5], vec![0.5]),
         Annotation::fill_between(vec![0.0, 1.0], vec![0.0], vec![0.0, 1.0]),
+        Annotation::fill_between_where(
+            vec![0.0, 1.0],
+            vec![0.0, 1.0],
+            vec![0.0, 0.0],
+            vec![true],
+            FillStyle::default(),
+        ),
         Annotation::FillBetween {
             x: vec![0.0, 1.0],
             y1: vec![0.0, f64::NEG_INFINITY],
             y2: vec![0.0, 0.0],
             style: FillStyle::default(),
             where_positive: false,
+            where_mask: None,
         },
         Annotation::hspan(1.0, 0.0),
         Annotation::VLine {
@@ -4110,6 +4196,8 @@ fn test_dynamic_annotations_are_clipped_to_displayed_plot_area() {
             width: 3.0,
             height: 3.0,
             style: ShapeStyle::default().fill(Color::RED),
+            coords: CoordSystem::Data,
+            clip: None,
         })
         .unwrap();
     let frame = session.render_to_surface(render_target()).unwrap();
@@ -5,9 +5,9 @@
 //! tick marks, margins, and axis settings.
 
 use crate::axes::AxisScale;
-use crate::core::{GridStyle, LegendPosition, Position};
+use crate::core::{GridStyle, LegendPosition, Position, TickLabelFormatter};
 
-use super::{LegendConfig, TickConfig};
+use super::{AspectMode, LegendConfig, TickConfig};
 
 /// Manages layout configuration for plots
 ///
@@ -38,16 +38,30 @@ pub struct LayoutManager {
     pub(crate) tick_config: TickConfig,
     /// Margin around plot area (fraction of canvas)
     pub(crate) margin: Option<f32>,
-    /// Whether to use scientific notation on axes
-    pub(crate) scientific_notation: bool,
+    /// Whether to use scientific notation on the X axis
+    pub(crate) x_scientific: bool,
+    /// Whether to use scientific notation on the Y axis
+    pub(crate) y_scientific: bool,
     /// Manual X-axis limits (min, max)
     pub(crate) x_limits: Option<(f64, f64)>,
     /// Manual Y-axis limits (min, max)
     pub(crate) y_limits: Option<(f64, f64)>,
+    /// Fraction of the data range added to each side of auto-scaled axes
+    pub(crate) autoscale_margin: f64,
+    /// Auto-scale the X axis symmetrically about zero
+    pub(crate) x_symmetric: bool,
+    /// Auto-scale the Y axis symmetrically about zero
+    pub(crate) y_symmetric: bool,
+    /// Plot area shape relative to the axis limits
+    pub(crate) aspect: AspectMode,
     /// X-axis scale (linear, log, symlog)
     pub(crate) x_scale: AxisScale,
     /// Y-axis scale (linear, log, symlog)
     pub(crate) y_scale: AxisScale,
+    /// Custom X-axis tick label formatter
+    pub(crate) x_tick_formatter: Option<TickLabelFormatter>,
+    /// Custom Y-axis tick label formatter
+    pub(crate) y_tick_formatter: Option<TickLabelFormatter>,
 }
 
 impl Default for LayoutManager {
@@ -64,11 +78,18 @@ impl LayoutManager {
             grid_style: GridStyle::default(),
             tick_config: TickConfig::default(),
             margin: None,
-            scientific_notation: false,
+            x_scientific: false,
+            y_scientific: false,
             x_limits: None,
             y_limits: None,
+            autoscale_margin: 0.05,
+            x_symmetric: false,
+            y_symmetric: false,
+            aspect: AspectMode::Auto,
             x_scale: AxisScale::Linear,
             y_scale: AxisScale::Linear,
+            x_tick_formatter: None,
+            y_tick_formatter: None,
         }
     }
 
@@ -196,6 +217,66 @@ impl LayoutManager {
         &self.y_scale
     }
 
+    // Tick label formatting
+
+    /// Set a custom X-axis tick label formatter
+    pub fn set_x_tick_formatter(&mut self, formatter: TickLabelFormatter) {
+        self.x_tick_formatter = Some(formatter);
+    }
+
+    /// Set a custom Y-axis tick label formatter
+    pub fn set_y_tick_formatter(&mut self, formatter: TickLabelFormatter) {
+        self.y_tick_formatter = Some(formatter);
+    }
+
+    /// X-axis formatter used for drawing: the custom one, or the scientific
+    /// offset formatter when scientific notation is on for a linear axis
+    pub(crate) fn x_label_formatter(&self) -> Option<TickLabelFormatter> {
+        Self::label_formatter(
+            self.x_tick_formatter.as_ref(),
+            &self.x_scale,
+            self.x_scientific,
+        )
+    }
+
+    /// Y-axis counterpart of [`Self::x_label_formatter`]
+    pub(crate) fn y_label_formatter(&self) -> Option<TickLabelFormatter> {
+        Self::label_formatter(
+            self.y_tick_formatter.as_ref(),
+            &self.y_scale,
+            self.y_scientific,
+        )
+    }
+
+    fn label_formatter(
+        custom: Option<&TickLabelFormatter>,
+        scale: &AxisScale,
+        scientific: bool,
+    ) -> Option<TickLabelFormatter> {
+        custom.cloned().or_else(|| {
+            (scientific && matches!(scale, AxisScale::Linear))
+                .then(TickLabelFormatter::scientific_offset)
+        })
+    }
+
+    /// Format X-axis tick labels with the custom formatter or the scale default
+    pub(crate) fn x_tick_labels(&self, ticks: &[f64]) -> Vec<String> {
+        crate::render::skia::format_axis_tick_labels(
+            ticks,
+            &self.x_scale,
+            self.x_label_formatter().as_ref(),
+        )
+    }
+
+    /// Format Y-axis tick labels with the custom formatter or the scale default
+    pub(crate) fn y_tick_labels(&self, ticks: &[f64]) -> Vec<String> {
+        crate::render::skia::format_axis_tick_labels(
+            ticks,
+            &self.y_scale,
+            self.y_label_formatter().as_ref(),
+        )
+    }
+
     // Margin methods
 
     /// Set margin as fraction of canvas
@@ -210,14 +291,35 @@ impl LayoutManager {
 
     // Scientific notation
 
-    /// Enable or disable scientific notation on axes
+    /// Enable or disable the `×10ⁿ` offset text on both linear axes
     pub fn set_scientific_notation(&mut self, enabled: bool) {
-        self.scientific_notation = enabled;
+        self.x_scientific = enabled;
+        self.y_scientific = enabled;
     }
 
-    /// Check if scientific notation is enabled
+    /// Check if scientific notation is enabled on either axis
     pub fn scientific_notation(&self) -> bool {
-        self.scientific_notation
+        self.x_scientific || self.y_scientific
+    }
+
+    /// Enable or disable the `×10ⁿ` offset text on a linear X axis
+    pub fn set_x_scientific(&mut self, enabled: bool) {
+        self.x_scientific = enabled;
+    }
+
+    /// Check if scientific notation is enabled on the X axis
+    pub fn x_scientific(&self) -> bool {
+        self.x_scientific
+    }
+
+    /// Enable or disable the `×10ⁿ` offset text on a linear Y axis
+    pub fn set_y_scientific(&mut self, enabled: bool) {
+        self.y_scientific = enabled;
+    }
+
+    /// Check if scientific notation is enabled on the Y axis
+    pub fn y_scientific(&self) -> bool {
+        self.y_scientific
     }
 }
 
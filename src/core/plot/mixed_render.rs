@@ -1,4 +1,5 @@
 use super::*;
+use crate::core::plot::raster_fast_path::{downsample_line_points, simplify_pixel_run};
 
 fn adjust_boxen_saturation_svg(color: Color, factor: f32) -> Color {
     let gray = ((color.r as f32 + color.g as f32 + color.b as f32) / 3.0) as u8;
@@ -27,6 +28,8 @@ impl Plot {
                 SeriesType::Heatmap { data } => data.n_rows * data.n_cols,
                 SeriesType::Kde { data } => data.x.len(),
                 SeriesType::Ecdf { data } => data.x.len(),
+                SeriesType::Rug { data } => data.len(),
+                SeriesType::Strip { data } => data.points.len(),
                 SeriesType::Violin { data } => data.data.len(),
                 SeriesType::Boxen { data } => data.boxes.len() * 4, // Each box has 4 points
                 SeriesType::Contour { data } => data.x.len() * data.y.len(),
@@ -34,6 +37,8 @@ impl Plot {
                 SeriesType::Radar { data } => data.series.iter().map(|s| s.values.len()).sum(),
                 SeriesType::Polar { data } => data.points.len(),
                 SeriesType::Quiver { data } => data.arrows.len(),
+                SeriesType::Hexbin { data } => data.bins.len(),
+                SeriesType::ScatterMapped { data } => data.x.len(),
             })
             .sum()
     }
@@ -55,6 +60,8 @@ impl Plot {
                     SeriesType::Heatmap { data } => data.n_rows * data.n_cols,
                     SeriesType::Kde { data } => data.x.len(),
                     SeriesType::Ecdf { data } => data.x.len(),
+                    SeriesType::Rug { data } => data.len(),
+                    SeriesType::Strip { data } => data.points.len(),
                     SeriesType::Violin { data } => data.data.len(),
                     SeriesType::Boxen { data } => data.boxes.len() * 4,
                     SeriesType::Contour { data } => data.x.len() * data.y.len(),
@@ -64,12 +71,19 @@ impl Plot {
                     }
                     SeriesType::Polar { data } => data.points.len(),
                     SeriesType::Quiver { data } => data.arrows.len(),
+                    SeriesType::Hexbin { data } => data.bins.len(),
+                    SeriesType::ScatterMapped { data } => data.x.len(),
                     _ => unreachable!("PlotData-backed series resolve to dedicated variants"),
                 },
             })
             .sum()
     }
 
+    /// Progress weight of one series: its point count, at least 1
+    pub(super) fn series_progress_work(resolved: &ResolvedSeries<'_>) -> usize {
+        Self::calculate_total_points_from_resolved(std::slice::from_ref(resolved)).max(1)
+    }
+
     pub(super) fn should_auto_use_datashader(
         series_list: &[PlotSeries],
         total_points: usize,
@@ -144,8 +158,13 @@ impl Plot {
         renderer.note_auto_datashader();
 
         let inset_rects = self.inset_rects_for_series(series_list, plot_area, render_scale)?;
+        let mut progress = self
+            .render
+            .progress(resolved_series.iter().map(Self::series_progress_work).sum());
 
-        for (idx, (series, resolved)) in series_list.iter().zip(resolved_series).enumerate() {
+        for idx in PlotSeries::draw_order(series_list) {
+            progress.check_cancelled()?;
+            let (series, resolved) = (&series_list[idx], &resolved_series[idx]);
             let (series_area, series_bounds) = if let Some(inset_rect) = inset_rects[idx] {
                 (
                     inset_rect,
@@ -162,13 +181,18 @@ impl Plot {
                         series_area.height() as usize,
                     );
 
-                    datashader.aggregate_with_bounds(
+                    // Aggregation dominates large renders, so report per chunk
+                    datashader.aggregate_with_bounds_chunked(
                         x,
                         y,
                         series_bounds.0,
                         series_bounds.1,
                         series_bounds.2,
                         series_bounds.3,
+                        |points| {
+                            progress.advance(points);
+                            progress.check_cancelled()
+                        },
                     )?;
                     let image = datashader.render();
                     renderer.draw_datashader_image(&image, series_area)?;
@@ -185,10 +209,12 @@ impl Plot {
                         series_bounds.3,
                         mode,
                     )?;
+                    progress.advance(Self::series_progress_work(resolved));
                 }
             }
         }
 
+        progress.finish();
         Ok(true)
     }
 
@@ -206,6 +232,26 @@ impl Plot {
         self.apply_manual_axis_limits((x.0, x.1, y.0, y.1))
     }
 
+    /// Data bounds with an axis that nothing spans, such as the y-axis under
+    /// a lone x rug, given its empty-plot range; `None` when neither axis is
+    /// spanned
+    pub(super) fn fill_unspanned_axis(
+        &self,
+        bounds: (f64, f64, f64, f64),
+    ) -> Option<(f64, f64, f64, f64)> {
+        let (x_min, x_max, y_min, y_max) = bounds;
+        let empty = self.empty_cartesian_bounds();
+        match (
+            x_min.is_finite() && x_max.is_finite(),
+            y_min.is_finite() && y_max.is_finite(),
+        ) {
+            (true, true) => Some(bounds),
+            (true, false) => Some((x_min, x_max, empty.2, empty.3)),
+            (false, true) => Some((empty.0, empty.1, y_min, y_max)),
+            (false, false) => None,
+        }
+    }
+
     pub(super) fn effective_main_panel_bounds_for_series(
         &self,
         series_list: &[PlotSeries],
@@ -240,10 +286,15 @@ impl Plot {
                 series_list
                     .iter()
                     .zip(resolved_series)
-                    .filter(|(series, _)| Self::is_cartesian_series(series))
+                    .filter(|(series, _)| series.visible && Self::is_cartesian_series(series))
                     .map(|(_, resolved)| resolved),
             )?;
-            Ok(self.apply_manual_axis_limits(self.expand_bounds_with_annotations(bounds)))
+            Ok(self.apply_manual_axis_limits(
+                self.apply_autoscale_margin(
+                    series_list,
+                    self.expand_bounds_with_annotations(bounds),
+                ),
+            ))
         } else {
             self.effective_data_bounds_from_resolved(resolved_series)
         }
@@ -428,8 +479,13 @@ impl Plot {
         mode: RenderExecutionMode,
     ) -> Result<()> {
         let inset_rects = self.inset_rects_for_series(series_list, plot_area, render_scale)?;
+        let mut progress = self
+            .render
+            .progress(resolved_series.iter().map(Self::series_progress_work).sum());
 
-        for (idx, (series, resolved)) in series_list.iter().zip(resolved_series).enumerate() {
+        for idx in PlotSeries::draw_order(series_list) {
+            progress.check_cancelled()?;
+            let (series, resolved) = (&series_list[idx], &resolved_series[idx]);
             let (series_area, series_bounds) = if let Some(inset_rect) = inset_rects[idx] {
                 (
                     inset_rect,
@@ -450,8 +506,10 @@ impl Plot {
                 series_bounds.3,
                 mode,
             )?;
+            progress.advance(Self::series_progress_work(resolved));
         }
 
+        progress.finish();
         Ok(())
     }
 
@@ -491,19 +549,72 @@ impl Plot {
                         )
                     })
                     .collect();
-
-                svg.draw_polyline(&points, color, line_width, line_style);
+                let line_points = series
+                    .downsample
+                    .and_then(|mode| {
+                        let projected: Vec<_> = points
+                            .iter()
+                            .map(|&(px, py)| crate::core::types::Point2f::new(px, py))
+                            .collect();
+                        downsample_line_points(
+                            &projected,
+                            mode,
+                            plot_area.left(),
+                            plot_area.width(),
+                        )
+                    })
+                    .map(|downsampled| {
+                        downsampled
+                            .into_iter()
+                            .map(|point| (point.x, point.y))
+                            .collect::<Vec<_>>()
+                    });
+                let line_points = line_points.as_deref().unwrap_or(&points);
+
+                // Non-finite samples break the line into separate runs.
+                let tolerance = series
+                    .simplify
+                    .map(|tolerance| render_scale.logical_pixels_to_pixels(tolerance));
+                for run in line_points
+                    .split(|&(px, py)| !(px.is_finite() && py.is_finite()))
+                    .filter(|run| !run.is_empty())
+                {
+                    match tolerance {
+                        Some(tolerance) => svg.draw_polyline(
+                            &simplify_pixel_run(run, tolerance),
+                            color,
+                            line_width,
+                            line_style.clone(),
+                        ),
+                        None => svg.draw_polyline(run, color, line_width, line_style.clone()),
+                    }
+                }
                 if let Some(marker_style) = series.marker_style {
                     let marker_size =
                         render_scale.points_to_pixels(series.marker_size.unwrap_or(8.0));
-                    for &(px, py) in &points {
-                        svg.draw_marker(px, py, marker_size, marker_style, color);
+                    let paint = series.marker_paint(marker_style, color, |width| {
+                        render_scale.points_to_pixels(width)
+                    });
+                    for &(px, py) in points
+                        .iter()
+                        .step_by(series.marker_step())
+                        .filter(|(px, py)| px.is_finite() && py.is_finite())
+                    {
+                        match paint {
+                            Some(paint) => {
+                                svg.draw_marker_painted(px, py, marker_size, marker_style, paint)
+                            }
+                            None => svg.draw_marker(px, py, marker_size, marker_style, color),
+                        }
                     }
                 }
             }
             (SeriesType::Scatter { .. }, ResolvedSeries::Scatter { x, y }) => {
                 let marker_style = series.marker_style.unwrap_or(MarkerStyle::Circle);
                 let marker_size = render_scale.points_to_pixels(series.marker_size.unwrap_or(10.0));
+                let paint = series.marker_paint(marker_style, color, |width| {
+                    render_scale.points_to_pixels(width)
+                });
                 for (&x, &y) in x.iter().zip(y.iter()) {
                     let (px, py) = crate::render::skia::map_data_to_pixels_scaled(
                         x,
@@ -516,17 +627,18 @@ impl Plot {
                         &self.layout.x_scale,
                         &self.layout.y_scale,
                     );
-                    svg.draw_marker(px, py, marker_size, marker_style, color);
+                    match paint {
+                        Some(paint) => {
+                            svg.draw_marker_painted(px, py, marker_size, marker_style, paint)
+                        }
+                        None => svg.draw_marker(px, py, marker_size, marker_style, color),
+                    }
                 }
             }
-            (SeriesType::Bar { categories, .. }, ResolvedSeries::Bar { values, .. }) => {
-                let num_bars = categories.len();
-                let bar_width = plot_area.width() / num_bars as f32 * 0.7;
-
+            (SeriesType::Bar { value_labels, .. }, ResolvedSeries::Bar { values, errors, .. }) => {
                 for (i, &value) in values.iter().enumerate() {
-                    let bar_x = plot_area.x()
-                        + (i as f32 + 0.5) * (plot_area.width() / num_bars as f32)
-                        - bar_width / 2.0;
+                    let (center, bar_width) = Self::category_bar_span(i, x_min, x_max, plot_area);
+                    let bar_x = center - bar_width / 2.0;
                     let (_, py) = crate::render::skia::map_data_to_pixels(
                         0.0, value, x_min, x_max, y_min, y_max, plot_area,
                     );
@@ -537,6 +649,59 @@ impl Plot {
                     let bar_y = py.min(py_zero);
 
                     svg.draw_rectangle(bar_x, bar_y, bar_width, bar_height, color, true);
+                    if let Some(pattern) = series.hatch {
+                        svg.draw_hatch_rectangle(
+                            bar_x,
+                            bar_y,
+                            bar_width,
+                            bar_height,
+                            pattern,
+                            color.darken(BAR_HATCH_DARKEN),
+                        );
+                    }
+                    if let Some(formatter) = value_labels
+                        && value.is_finite()
+                    {
+                        // Keep the label clear of the error bar, as matplotlib does
+                        let (low, high) = bar_y_extent(value, *errors, i);
+                        let (_, py_end) = crate::render::skia::map_data_to_pixels(
+                            0.0,
+                            if value < 0.0 { low } else { high },
+                            x_min,
+                            x_max,
+                            y_min,
+                            y_max,
+                            plot_area,
+                        );
+                        let (size, top) = self.bar_value_label_placement(py_end, py_zero);
+                        svg.draw_text_centered(
+                            &formatter.format(value),
+                            center,
+                            top,
+                            size,
+                            self.display.theme.foreground,
+                        )?;
+                    }
+                }
+
+                if errors.is_some() {
+                    let positions: Vec<f64> = (0..values.len()).map(|i| i as f64).collect();
+                    self.render_error_bars_series_svg(
+                        svg,
+                        series,
+                        &positions,
+                        values,
+                        *errors,
+                        None,
+                        false,
+                        self.display.theme.foreground,
+                        line_width,
+                        plot_area,
+                        x_min,
+                        x_max,
+                        y_min,
+                        y_max,
+                    );
                 }
             }
             (SeriesType::Heatmap { data }, ResolvedSeries::Other(_)) => {
@@ -553,14 +718,20 @@ impl Plot {
                 let alpha = data.config.alpha * series.alpha.unwrap_or(1.0);
                 for (row, values) in data.values.iter().enumerate() {
                     for (col, &value) in values.iter().enumerate() {
-                        if data.should_mask_value(value) {
+                        let Some(cell_color) = data.cell_color(value, alpha) else {
                             continue;
-                        }
+                        };
                         let (x, y, width, height) = data.cell_screen_rect(&area, row, col);
-                        let cell_color = data.get_color(value).with_alpha(alpha);
                         svg.draw_rectangle(x, y, width, height, cell_color, true);
                     }
                 }
+                if let Some((color, width)) = data.config.cell_border {
+                    svg.draw_rectangle_outlines(
+                        &data.cell_border_rects(&area, false),
+                        color,
+                        render_scale.points_to_pixels(width),
+                    );
+                }
             }
             (SeriesType::Kde { data }, ResolvedSeries::Other(_)) => {
                 let points: Vec<(f32, f32)> = data
@@ -645,6 +816,32 @@ impl Plot {
                     }
                 }
             }
+            (SeriesType::Rug { data }, ResolvedSeries::Other(_)) => {
+                for ((x1, y1), (x2, y2)) in
+                    self.rug_segments(data, plot_area, x_min, x_max, y_min, y_max)
+                {
+                    svg.draw_line(x1, y1, x2, y2, color, line_width, LineStyle::Solid);
+                }
+            }
+            (SeriesType::Strip { data }, ResolvedSeries::Other(_)) => {
+                let marker_size =
+                    render_scale.points_to_pixels(series.marker_size.unwrap_or(data.config.size));
+                let marker_style = series.marker_style.unwrap_or(MarkerStyle::Circle);
+                for point in &data.points {
+                    let (px, py) = crate::render::skia::map_data_to_pixels_scaled(
+                        point.x,
+                        point.y,
+                        x_min,
+                        x_max,
+                        y_min,
+                        y_max,
+                        plot_area,
+                        &self.layout.x_scale,
+                        &self.layout.y_scale,
+                    );
+                    svg.draw_marker(px, py, marker_size, marker_style, color);
+                }
+            }
             (SeriesType::Violin { data }, ResolvedSeries::Other(_)) => {
                 let half_width = data.config.width / 2.0;
                 let (left, right) =
@@ -726,6 +923,65 @@ impl Plot {
                     }
                 }
             }
+            (SeriesType::Hexbin { data }, ResolvedSeries::Other(_)) => {
+                let alpha = series.alpha.unwrap_or(1.0);
+                let edge_width = render_scale
+                    .points_to_pixels(series.line_width.unwrap_or(data.config.edge_width));
+                for bin in &data.bins {
+                    let hexagon: Vec<(f32, f32)> = bin
+                        .vertices
+                        .iter()
+                        .map(|&(x, y)| {
+                            crate::render::skia::map_data_to_pixels_scaled(
+                                x,
+                                y,
+                                x_min,
+                                x_max,
+                                y_min,
+                                y_max,
+                                plot_area,
+                                &self.layout.x_scale,
+                                &self.layout.y_scale,
+                            )
+                        })
+                        .collect();
+                    let fill_color = data.bin_color(bin);
+                    svg.draw_filled_polygon(
+                        &hexagon,
+                        fill_color.with_alpha((f32::from(fill_color.a) / 255.0) * alpha),
+                    );
+                    if let Some(edge_color) = data.config.edge_color {
+                        if edge_width > 0.0 {
+                            svg.draw_polygon_outline(&hexagon, edge_color, edge_width);
+                        }
+                    }
+                }
+            }
+            (SeriesType::ScatterMapped { data }, ResolvedSeries::Other(_)) => {
+                let alpha = series.alpha.unwrap_or(1.0);
+                let marker_style = series.marker_style.unwrap_or(MarkerStyle::Circle);
+                let marker_size = render_scale.points_to_pixels(series.marker_size.unwrap_or(10.0));
+                for (x, y, point_color) in data.colored_points() {
+                    let (px, py) = crate::render::skia::map_data_to_pixels_scaled(
+                        x,
+                        y,
+                        x_min,
+                        x_max,
+                        y_min,
+                        y_max,
+                        plot_area,
+                        &self.layout.x_scale,
+                        &self.layout.y_scale,
+                    );
+                    svg.draw_marker(
+                        px,
+                        py,
+                        marker_size,
+                        marker_style,
+                        point_color.with_alpha(alpha),
+                    );
+                }
+            }
             (SeriesType::Pie { data }, ResolvedSeries::Other(_)) => {
                 self.render_pie_series_svg(svg, data, series, plot_area)?;
             }
@@ -770,14 +1026,15 @@ impl Plot {
                     );
                 }
             }
-            (SeriesType::ErrorBars { .. }, ResolvedSeries::ErrorBars { x, y, y_errors }) => self
-                .render_error_bars_series_svg(
+            (SeriesType::ErrorBars { .. }, ResolvedSeries::ErrorBars { x, y, y_errors, .. }) => {
+                self.render_error_bars_series_svg(
                     svg,
                     series,
                     x,
                     y,
                     Some(effective_error_values(series.y_errors.as_ref(), y_errors)),
                     series.x_errors.as_ref().map(ErrorValuesRef::from),
+                    true,
                     color,
                     line_width,
                     plot_area,
@@ -785,7 +1042,8 @@ impl Plot {
                     x_max,
                     y_min,
                     y_max,
-                ),
+                )
+            }
             (
                 SeriesType::ErrorBarsXY { .. },
                 ResolvedSeries::ErrorBarsXY {
@@ -801,6 +1059,7 @@ impl Plot {
                 y,
                 Some(effective_error_values(series.y_errors.as_ref(), y_errors)),
                 Some(effective_error_values(series.x_errors.as_ref(), x_errors)),
+                true,
                 color,
                 line_width,
                 plot_area,
@@ -831,6 +1090,7 @@ impl Plot {
         y: &[f64],
         y_errors: Option<ErrorValuesRef<'_>>,
         x_errors: Option<ErrorValuesRef<'_>>,
+        draw_markers: bool,
         color: Color,
         default_line_width: f32,
         plot_area: tiny_skia::Rect,
@@ -857,7 +1117,9 @@ impl Plot {
             let (px, py) = crate::render::skia::map_data_to_pixels(
                 x_value, y_value, x_min, x_max, y_min, y_max, plot_area,
             );
-            svg.draw_marker(px, py, marker_size, marker_style, color);
+            if draw_markers {
+                svg.draw_marker(px, py, marker_size, marker_style, color);
+            }
 
             if let Some((lower, upper)) = y_errors.and_then(|errors| errors.bounds_at(index)) {
                 let lower = lower.abs();
@@ -969,7 +1231,13 @@ impl Plot {
                 PlottingError::RenderError(format!("Box plot calculation failed: {error}"))
             })?;
         let (x_center, _) = crate::render::skia::map_data_to_pixels(
-            0.5, 0.0, x_min, x_max, y_min, y_max, plot_area,
+            config.x_position,
+            0.0,
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+            plot_area,
         );
         let map_y = |value| {
             crate::render::skia::map_data_to_pixels(
@@ -982,7 +1250,7 @@ impl Plot {
         let q3 = map_y(box_data.q3);
         let lower_whisker = map_y(box_data.min);
         let upper_whisker = map_y(box_data.max);
-        let half_width = plot_area.width() * 0.15;
+        let half_width = Self::box_half_width_px(plot_area.width(), 0.3, x_min, x_max);
         let left = x_center - half_width;
         let right = x_center + half_width;
         let cap_width = half_width * 0.6;
@@ -1592,6 +1860,37 @@ impl Plot {
         let render_scale = svg.render_scale();
         let label_font_size = render_scale.points_to_pixels(data.config.label_font_size);
 
+        if self.layout.grid_style.visible {
+            let grid_color = self.layout.grid_style.effective_color();
+            let grid_line_width = render_scale.points_to_pixels(self.layout.grid_style.line_width);
+            for &radius in &data.r_grid {
+                let circle: Vec<(f32, f32)> =
+                    crate::plots::polar::circle_vertices(0.0, 0.0, radius, 72)
+                        .into_iter()
+                        .map(|(x, y)| area.data_to_screen(x, y))
+                        .collect();
+                svg.draw_polyline(
+                    &circle,
+                    grid_color,
+                    grid_line_width,
+                    self.layout.grid_style.line_style.clone(),
+                );
+            }
+            for &((x1, y1), (x2, y2)) in &data.theta_grid {
+                let (sx1, sy1) = area.data_to_screen(x1, y1);
+                let (sx2, sy2) = area.data_to_screen(x2, y2);
+                svg.draw_line(
+                    sx1,
+                    sy1,
+                    sx2,
+                    sy2,
+                    grid_color,
+                    grid_line_width,
+                    self.layout.grid_style.line_style.clone(),
+                );
+            }
+        }
+
         if data.config.fill && !data.fill_polygon.is_empty() {
             let polygon: Vec<(f32, f32)> = data
                 .fill_polygon
@@ -1604,7 +1903,7 @@ impl Plot {
             );
         }
 
-        if data.points.len() > 1 {
+        if data.config.show_line && data.points.len() > 1 {
             let points: Vec<(f32, f32)> = data
                 .points
                 .iter()
@@ -1653,7 +1952,7 @@ impl Plot {
     ///
     /// Some plot types (Pie, Radar, Polar) have their own coordinate system
     /// and don't use standard X/Y axes with tick labels.
-    pub(super) fn needs_cartesian_axes(&self) -> bool {
+    pub(crate) fn needs_cartesian_axes(&self) -> bool {
         Self::needs_cartesian_axes_for_series(&self.series_mgr.series)
     }
 
@@ -1681,13 +1980,9 @@ impl Plot {
         (x_min, x_max, y_min, y_max)
     }
 
+    /// Axis limits the main panel renders with for the current static series.
     pub(super) fn effective_data_bounds(&self) -> Result<(f64, f64, f64, f64)> {
-        if self.series_mgr.series.is_empty() {
-            return Ok(self.empty_cartesian_bounds());
-        }
-
-        self.calculate_data_bounds()
-            .map(|bounds| self.apply_manual_axis_limits(bounds))
+        self.effective_main_panel_bounds_for_series(&self.series_mgr.series)
     }
 
     pub(super) fn effective_data_bounds_for_series(
@@ -1700,10 +1995,57 @@ impl Plot {
 
         self.calculate_data_bounds_for_series(series_list)
             .map(|bounds| {
-                self.apply_manual_axis_limits(self.expand_bounds_with_annotations(bounds))
+                self.apply_manual_axis_limits(self.apply_autoscale_margin(
+                    series_list,
+                    self.expand_bounds_with_annotations(bounds),
+                ))
             })
     }
 
+    /// X range of the main panel before the autoscale margin, for sharing an
+    /// axis across subplots.
+    ///
+    /// Explicit X limits are returned as-is. Returns `None` when the plot has
+    /// no Cartesian data or explicit X limits to contribute.
+    pub(crate) fn shared_x_range(&self) -> Result<Option<(f64, f64)>> {
+        if let Some(limits) = self.layout.x_limits {
+            return Ok(Some(limits));
+        }
+        Ok(self
+            .shared_data_bounds()?
+            .map(|(x_min, x_max, _, _)| (x_min, x_max)))
+    }
+
+    /// Y counterpart of [`Self::shared_x_range`].
+    pub(crate) fn shared_y_range(&self) -> Result<Option<(f64, f64)>> {
+        if let Some(limits) = self.layout.y_limits {
+            return Ok(Some(limits));
+        }
+        Ok(self
+            .shared_data_bounds()?
+            .map(|(_, _, y_min, y_max)| (y_min, y_max)))
+    }
+
+    /// Main-panel data bounds, with annotations, before the autoscale margin
+    fn shared_data_bounds(&self) -> Result<Option<(f64, f64, f64, f64)>> {
+        let series = &self.series_mgr.series;
+        if !Self::has_cartesian_series(series) {
+            return Ok(None);
+        }
+
+        let bounds = if Self::has_mixed_coordinate_series(series) {
+            let cartesian_series: Vec<PlotSeries> = series
+                .iter()
+                .filter(|series| Self::is_cartesian_series(series))
+                .cloned()
+                .collect();
+            self.calculate_data_bounds_for_series(&cartesian_series)?
+        } else {
+            self.calculate_data_bounds_for_series(series)?
+        };
+        Ok(Some(self.expand_bounds_with_annotations(bounds)))
+    }
+
     pub(super) fn effective_data_bounds_from_resolved(
         &self,
         resolved_series: &[ResolvedSeries<'_>],
@@ -1712,32 +2054,146 @@ impl Plot {
             return Ok(self.empty_cartesian_bounds());
         }
 
-        self.calculate_data_bounds_from_resolved(resolved_series)
-            .map(|bounds| {
-                self.apply_manual_axis_limits(self.expand_bounds_with_annotations(bounds))
-            })
+        self.calculate_data_bounds_from_resolved(Self::visible_resolved_series(
+            &self.series_mgr.series,
+            resolved_series,
+        ))
+        .map(|bounds| {
+            self.apply_manual_axis_limits(self.apply_autoscale_margin(
+                &self.series_mgr.series,
+                self.expand_bounds_with_annotations(bounds),
+            ))
+        })
     }
 
-    pub(super) fn apply_auto_padding_to_bounds(
+    /// Resolved data of the series that are drawn, for bounds computation
+    pub(super) fn visible_resolved_series<'frame, 'data>(
+        series_list: &'frame [PlotSeries],
+        resolved_series: &'frame [ResolvedSeries<'data>],
+    ) -> impl Iterator<Item = &'frame ResolvedSeries<'data>> {
+        series_list
+            .iter()
+            .zip(resolved_series)
+            .filter(|(series, _)| series.visible)
+            .map(|(_, resolved)| resolved)
+    }
+
+    /// Widen auto-scaled axes by `autoscale_margin` of their range on each side.
+    ///
+    /// Axes marked symmetric are first widened to `(-m, m)` around zero. The
+    /// margin is applied in axis-scale space, except that a log axis is
+    /// rounded out to whole decades instead. Axes with manual limits
+    /// and degenerate or invalid ranges are left unchanged, and the margin
+    /// never pads past a sticky edge (see [`Self::sticky_edges`]) that the data
+    /// touches.
+    pub(super) fn apply_autoscale_margin(
         &self,
+        series_list: &[PlotSeries],
         bounds: (f64, f64, f64, f64),
-        fraction: f64,
     ) -> (f64, f64, f64, f64) {
+        let margin = self.layout.autoscale_margin;
         let (mut x_min, mut x_max, mut y_min, mut y_max) = bounds;
+        if self.layout.x_symmetric && self.layout.x_limits.is_none() {
+            (x_min, x_max) = symmetric_range(x_min, x_max, &self.layout.x_scale);
+        }
+        if self.layout.y_symmetric && self.layout.y_limits.is_none() {
+            (y_min, y_max) = symmetric_range(y_min, y_max, &self.layout.y_scale);
+        }
+        if margin <= 0.0 {
+            return (x_min, x_max, y_min, y_max);
+        }
 
+        let (sticky_x, sticky_y) = self.sticky_edges(series_list);
         if self.layout.x_limits.is_none() {
-            let x_range = x_max - x_min;
-            x_min -= x_range * fraction;
-            x_max += x_range * fraction;
+            (x_min, x_max) =
+                autoscale_padded_range(x_min, x_max, &self.layout.x_scale, margin, &sticky_x);
         }
-
         if self.layout.y_limits.is_none() {
-            let y_range = y_max - y_min;
-            y_min -= y_range * fraction;
-            y_max += y_range * fraction;
+            (y_min, y_max) =
+                autoscale_padded_range(y_min, y_max, &self.layout.y_scale, margin, &sticky_y);
         }
+        (x_min, x_max, y_min, y_max)
+    }
+
+    /// Pad a shared X range the way this plot pads its own auto-scaled X axis,
+    /// including its symmetric-limit setting.
+    ///
+    /// Returns `None` when the X axis has manual limits and is not padded.
+    pub(crate) fn autoscale_x_range(&self, (min, max): (f64, f64)) -> Option<(f64, f64)> {
+        if self.layout.x_limits.is_some() {
+            return None;
+        }
+        let (min, max) = if self.layout.x_symmetric {
+            symmetric_range(min, max, &self.layout.x_scale)
+        } else {
+            (min, max)
+        };
+        let (sticky_x, _) = self.sticky_edges(&self.series_mgr.series);
+        Some(autoscale_padded_range(
+            min,
+            max,
+            &self.layout.x_scale,
+            self.layout.autoscale_margin,
+            &sticky_x,
+        ))
+    }
+
+    /// Y counterpart of [`Self::autoscale_x_range`].
+    pub(crate) fn autoscale_y_range(&self, (min, max): (f64, f64)) -> Option<(f64, f64)> {
+        if self.layout.y_limits.is_some() {
+            return None;
+        }
+        let (min, max) = if self.layout.y_symmetric {
+            symmetric_range(min, max, &self.layout.y_scale)
+        } else {
+            (min, max)
+        };
+        let (_, sticky_y) = self.sticky_edges(&self.series_mgr.series);
+        Some(autoscale_padded_range(
+            min,
+            max,
+            &self.layout.y_scale,
+            self.layout.autoscale_margin,
+            &sticky_y,
+        ))
+    }
 
-        self.apply_manual_axis_limits((x_min, x_max, y_min, y_max))
+    /// X and Y values the autoscale margin must not pad past, like matplotlib's
+    /// sticky edges.
+    ///
+    /// Bars, histograms, and KDE curves stick to their zero baseline, ECDFs to
+    /// 0 and 1, `fill_between` areas to a constant baseline, and heatmaps to
+    /// their cell extent, so they sit flush on the axes.
+    pub(super) fn sticky_edges(&self, series_list: &[PlotSeries]) -> (Vec<f64>, Vec<f64>) {
+        let mut x_edges = Vec::new();
+        let mut y_edges = Vec::new();
+        for series in series_list.iter().filter(|series| series.visible) {
+            match &series.series_type {
+                SeriesType::Bar { .. } | SeriesType::Histogram { .. } | SeriesType::Kde { .. } => {
+                    y_edges.push(0.0)
+                }
+                SeriesType::Ecdf { .. } => y_edges.extend([0.0, 1.0]),
+                SeriesType::Heatmap { data } => {
+                    let ((x_min, x_max), (y_min, y_max)) =
+                        crate::plots::traits::PlotData::data_bounds(data.as_ref());
+                    x_edges.extend([x_min, x_max]);
+                    y_edges.extend([y_min, y_max]);
+                }
+                _ => {}
+            }
+        }
+        for annotation in &self.annotations {
+            if let Annotation::FillBetween { y1, y2, .. } = annotation {
+                for baseline in [y1, y2] {
+                    if let Some(&first) = baseline.first()
+                        && baseline.iter().all(|&value| value == first)
+                    {
+                        y_edges.push(first);
+                    }
+                }
+            }
+        }
+        (x_edges, y_edges)
     }
 
     /// Helper to render attached error bars on Line/Scatter series
@@ -1966,3 +2422,64 @@ impl Plot {
         Ok(())
     }
 }
+
+/// Pad `min..max` by `margin` of its scale-space length on each side, stopping
+/// at any sticky edge the unpadded range already touches.
+///
+/// Log axes are instead widened out to whole decades so their major ticks
+/// land on both ends.
+fn autoscale_padded_range(
+    min: f64,
+    max: f64,
+    scale: &AxisScale,
+    margin: f64,
+    sticky: &[f64],
+) -> (f64, f64) {
+    if margin <= 0.0 || min == max || scale.validate_range(min, max).is_err() {
+        return (min, max);
+    }
+    if matches!(scale, AxisScale::Log) {
+        return decade_range(min, max);
+    }
+    let (mut padded_min, mut padded_max) = (
+        scale.inverse_normalized_position(-margin, min, max),
+        scale.inverse_normalized_position(1.0 + margin, min, max),
+    );
+    if !padded_min.is_finite() || !padded_max.is_finite() {
+        return (min, max);
+    }
+    let tolerance = (max - min).abs() * 1e-9;
+    for &edge in sticky {
+        if min >= edge - tolerance && padded_min < edge {
+            padded_min = edge;
+        }
+        if max <= edge + tolerance && padded_max > edge {
+            padded_max = edge;
+        }
+    }
+    (padded_min, padded_max)
+}
+
+/// Widen a positive `min..max` to the enclosing powers of ten.
+fn decade_range(min: f64, max: f64) -> (f64, f64) {
+    // Tolerate rounding in log10 so exact decades are not widened further
+    let lower = 10f64.powf((min.log10() + 1e-9).floor());
+    let upper = 10f64.powf((max.log10() - 1e-9).ceil());
+    if lower > 0.0 && upper.is_finite() && lower < upper {
+        (lower, upper)
+    } else {
+        (min, max)
+    }
+}
+
+/// Widen `min..max` to `(-m, m)` with `m = max(|min|, |max|)`.
+///
+/// Ranges that are all zero or invalid for `scale` (such as any log range)
+/// are returned unchanged.
+fn symmetric_range(min: f64, max: f64, scale: &AxisScale) -> (f64, f64) {
+    let extent = min.abs().max(max.abs());
+    if extent == 0.0 || !extent.is_finite() || scale.validate_range(-extent, extent).is_err() {
+        return (min, max);
+    }
+    (-extent, extent)
+}
@@ -46,9 +46,15 @@
 
 pub(super) const COLORBAR_MARGIN_PX: f32 = 10.0;
 pub(super) const COLORBAR_WIDTH_PX: f32 = 20.0;
+/// Marker edge width in points when only the face is overridden (matplotlib's default).
+pub(super) const DEFAULT_MARKER_EDGE_WIDTH: f32 = 1.0;
+/// How much darker than the bar face its hatch strokes are drawn.
+pub(super) const BAR_HATCH_DARKEN: f32 = 0.5;
+/// Gap between a bar's end and its value label, in points.
+pub(super) const BAR_VALUE_LABEL_GAP_PT: f32 = 3.0;
 
 #[derive(Clone, Copy)]
-pub(super) enum ErrorValuesRef<'a> {
+pub(crate) enum ErrorValuesRef<'a> {
     Symmetric(&'a [f64]),
     Asymmetric(&'a [f64], &'a [f64]),
 }
@@ -74,6 +80,27 @@ impl<'a> From<&'a ErrorValues> for ErrorValuesRef<'a> {
     }
 }
 
+/// Lowest and highest value reached by the error bar at `index`, if the value
+/// and both of its errors are finite
+fn error_bar_extent(value: f64, errors: ErrorValuesRef<'_>, index: usize) -> Option<(f64, f64)> {
+    match errors.bounds_at(index) {
+        Some((lower, upper)) if value.is_finite() && lower.is_finite() && upper.is_finite() => {
+            Some((value - lower, value + upper))
+        }
+        _ => None,
+    }
+}
+
+/// Lowest and highest Y reached by the bar at `index`, including its error bar
+fn bar_y_extent(value: f64, errors: Option<ErrorValuesRef<'_>>, index: usize) -> (f64, f64) {
+    match errors.and_then(|errors| errors.bounds_at(index)) {
+        Some((lower, upper)) if lower.is_finite() && upper.is_finite() => {
+            (value - lower.abs(), value + upper.abs())
+        }
+        _ => (value, value),
+    }
+}
+
 fn effective_error_values<'a>(
     configured: Option<&'a ErrorValues>,
     resolved: &'a [f64],
@@ -186,6 +213,22 @@ macro_rules! impl_series_continuation_methods {
             $self_.$finalize().scatter(x_data, y_data)
         }
 
+        /// Continue with a new color-mapped scatter series.
+        pub fn scatter_mapped<X, Y, C>(
+            $self_,
+            x_data: &X,
+            y_data: &Y,
+            c_data: &C,
+            colormap: $crate::render::ColorMap,
+        ) -> $crate::core::plot::PlotBuilder<$crate::plots::ScatterMappedConfig>
+        where
+            X: $crate::data::NumericData1D,
+            Y: $crate::data::NumericData1D,
+            C: $crate::data::NumericData1D,
+        {
+            $self_.$finalize().scatter_mapped(x_data, y_data, c_data, colormap)
+        }
+
         /// Continue with a new scatter series from source-backed data.
         pub fn scatter_source<X, Y>(
             $self_,
@@ -262,6 +305,19 @@ macro_rules! impl_series_continuation_methods {
             $self_.$finalize().boxplot(data, config)
         }
 
+        /// Continue with one box plot per labeled group.
+        pub fn boxplots<S, D>(
+            $self_,
+            groups: &[(S, D)],
+            config: Option<$crate::plots::BoxPlotConfig>,
+        ) -> $crate::core::plot::Plot
+        where
+            S: AsRef<str>,
+            D: $crate::data::NumericData1D,
+        {
+            $self_.$finalize().boxplots(groups, config)
+        }
+
         /// Continue with a box plot series from source-backed values.
         pub fn boxplot_source<D: $crate::core::plot::IntoPlotData>(
             $self_,
@@ -283,6 +339,33 @@ macro_rules! impl_series_continuation_methods {
             $self_.$finalize().heatmap(data, config)
         }
 
+        /// Continue with a correlation matrix heatmap.
+        pub fn corr_matrix<S, D>(
+            $self_,
+            variables: &[(S, D)],
+        ) -> $crate::core::plot::PlotSeriesBuilder
+        where
+            S: AsRef<str>,
+            D: $crate::data::NumericData1D,
+        {
+            $self_.$finalize().corr_matrix(variables)
+        }
+
+        /// Continue with a 2D histogram heatmap.
+        pub fn hist2d<X, Y>(
+            $self_,
+            x_data: &X,
+            y_data: &Y,
+            bins: (usize, usize),
+            colormap: $crate::render::ColorMap,
+        ) -> $crate::core::plot::PlotSeriesBuilder
+        where
+            X: $crate::data::NumericData1D,
+            Y: $crate::data::NumericData1D,
+        {
+            $self_.$finalize().hist2d(x_data, y_data, bins, colormap)
+        }
+
         /// Continue with a KDE series.
         pub fn kde<T, D: $crate::data::Data1D<T>>(
             $self_,
@@ -294,6 +377,30 @@ macro_rules! impl_series_continuation_methods {
             $self_.$finalize().kde(data)
         }
 
+        /// Continue with a rug series.
+        pub fn rug<T, D: $crate::data::Data1D<T>>(
+            $self_,
+            data: &D,
+        ) -> $crate::core::plot::PlotBuilder<$crate::plots::RugConfig>
+        where
+            T: Into<f64> + Copy,
+        {
+            $self_.$finalize().rug(data)
+        }
+
+        /// Continue with a strip series.
+        pub fn strip<S, D>(
+            $self_,
+            groups: &[(S, D)],
+            jitter_width: f64,
+        ) -> $crate::core::plot::PlotBuilder<$crate::plots::categorical::StripConfig>
+        where
+            S: AsRef<str>,
+            D: $crate::data::NumericData1D,
+        {
+            $self_.$finalize().strip(groups, jitter_width)
+        }
+
         /// Continue with an ECDF series.
         pub fn ecdf<T, D: $crate::data::Data1D<T>>(
             $self_,
@@ -320,6 +427,30 @@ macro_rules! impl_series_continuation_methods {
             $self_.$finalize().contour(x, y, z)
         }
 
+        /// Continue with contour lines over a 2D grid.
+        pub fn contour_grid<D>(
+            $self_,
+            grid: &D,
+            levels: &[f64],
+        ) -> $crate::core::plot::PlotBuilder<$crate::plots::ContourConfig>
+        where
+            D: $crate::data::NumericData2D + ?Sized,
+        {
+            $self_.$finalize().contour_grid(grid, levels)
+        }
+
+        /// Continue with filled contours over a 2D grid.
+        pub fn contourf_grid<D>(
+            $self_,
+            grid: &D,
+            levels: &[f64],
+        ) -> $crate::core::plot::PlotBuilder<$crate::plots::ContourConfig>
+        where
+            D: $crate::data::NumericData2D + ?Sized,
+        {
+            $self_.$finalize().contourf_grid(grid, levels)
+        }
+
         /// Continue with a pie series.
         pub fn pie<V>(
             $self_,
@@ -352,6 +483,19 @@ macro_rules! impl_series_continuation_methods {
             $self_.$finalize().polar_line(r, theta)
         }
 
+        /// Continue with a polar scatter series.
+        pub fn polar_scatter<R, T>(
+            $self_,
+            r: &R,
+            theta: &T,
+        ) -> $crate::core::plot::PlotBuilder<$crate::plots::PolarPlotConfig>
+        where
+            R: $crate::data::Data1D<f64>,
+            T: $crate::data::Data1D<f64>,
+        {
+            $self_.$finalize().polar_scatter(r, theta)
+        }
+
         /// Continue with a violin series.
         pub fn violin<T, D: $crate::data::Data1D<T>>(
             $self_,
@@ -391,6 +535,20 @@ macro_rules! impl_series_continuation_methods {
             $self_.$finalize().quiver(x_data, y_data, u_data, v_data)
         }
 
+        /// Continue with a hexagonal binning series.
+        pub fn hexbin<X, Y>(
+            $self_,
+            x_data: &X,
+            y_data: &Y,
+            gridsize: usize,
+        ) -> $crate::core::plot::PlotBuilder<$crate::plots::HexbinConfig>
+        where
+            X: $crate::data::NumericData1D,
+            Y: $crate::data::NumericData1D,
+        {
+            $self_.$finalize().hexbin(x_data, y_data, gridsize)
+        }
+
         /// Continue with a new streaming line series.
         pub fn line_streaming(
             $self_,
@@ -422,6 +580,23 @@ macro_rules! impl_series_continuation_methods {
             $self_.$finalize().error_bars(x_data, y_data, y_errors)
         }
 
+        /// Continue with a new error bar series with separate lower and upper Y errors.
+        pub fn error_bars_asym<X, Y, L, U>(
+            $self_,
+            x_data: &X,
+            y_data: &Y,
+            y_lower: &L,
+            y_upper: &U,
+        ) -> $crate::core::plot::PlotSeriesBuilder
+        where
+            X: $crate::data::NumericData1D,
+            Y: $crate::data::NumericData1D,
+            L: $crate::data::NumericData1D,
+            U: $crate::data::NumericData1D,
+        {
+            $self_.$finalize().error_bars_asym(x_data, y_data, y_lower, y_upper)
+        }
+
         /// Continue with a new Y-error-bar series from source-backed data.
         pub fn error_bars_source<X, Y, E>(
             $self_,
@@ -476,12 +651,15 @@ macro_rules! impl_series_continuation_methods {
 }
 
 mod annotations;
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+mod batch;
 mod builder;
 mod config;
 mod configuration;
 mod construction;
 pub mod data;
 mod image;
+mod inset_axes;
 mod interactive_session;
 mod layout_manager;
 mod mixed_render;
@@ -500,9 +678,11 @@ mod series_manager;
 mod tests;
 mod types;
 
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+pub use batch::render_batch;
 pub use builder::{BuilderWhen, IntoPlot, PlotBuilder, PlotInput, SeriesStyle};
 pub use config::{
-    BackendFallbackReason, BackendOperation, BackendResolution, BackendType, GridMode,
+    BackendFallbackReason, BackendOperation, BackendResolution, BackendType, GridAxis, GridMode,
     TickDirection, TickSides,
 };
 pub use configuration::{PlotConfiguration, TextEngineMode};
@@ -519,21 +699,22 @@ pub use prepared::{PreparedPlot, ReactiveSubscription};
 pub use render_pipeline::RenderPipeline;
 pub use series_builders::{PlotSeriesBuilder, SeriesGroupBuilder};
 pub use series_manager::SeriesManager;
-pub use types::{InsetAnchor, InsetLayout, Plot};
+pub use types::{AspectMode, DownsampleMode, InsetAnchor, InsetLayout, Plot};
 
 use crate::{
-    axes::AxisScale,
+    axes::{AxisScale, TimeFormat},
     core::{
-        Annotation, ArrowStyle, FillStyle, GridStyle, LayoutCalculator, LayoutConfig,
+        Annotation, ArrowStyle, FillStyle, GridStyle, HatchPattern, LayoutCalculator, LayoutConfig,
         LayoutMeasurements, Legend, LegendItem, LegendItemType, LegendPosition, MarginConfig,
         MeasuredDimensions, PlotConfig, PlotContent, PlotLayout, PlotStyle, PlottingError,
-        Position, REFERENCE_DPI, RenderScale, ResolvedLayout, Result, ShapeStyle, StyleResolver,
-        TextStyle, pt_to_px,
+        Position, REFERENCE_DPI, RenderScale, ResolvedLayout, Result, ShapeStyle, SpinePosition,
+        StyleResolver, TextStyle, TickLabelFormatter, pt_to_px,
     },
     data::{
         Data1D, DataShader, NullPolicy, NumericData1D, NumericData2D, StreamingXY,
         collect_numeric_data_1d, collect_numeric_data_2d,
     },
+    export::{ImageFormat, SvgOptions},
     plots::boxplot::BoxPlotConfig,
     plots::error::errorbar::{ErrorBarConfig, ErrorValues},
     plots::histogram::HistogramConfig,
@@ -542,16 +723,19 @@ use crate::{
         SkiaRenderer, calculate_plot_area_config, calculate_plot_area_dpi, generate_ticks,
         map_data_to_pixels,
     },
-    render::{Color, LineStyle, MarkerStyle, Theme},
+    render::{Color, FontWeight, LineStyle, MarkerPaint, MarkerStyle, Theme},
 };
 use std::{
     borrow::Cow,
     collections::{HashMap, HashSet},
     path::Path,
-    sync::Arc,
+    sync::{Arc, atomic::AtomicBool},
 };
 
 use self::data::{ReactiveTeardown, SharedReactiveCallback};
+use self::inset_axes::PlotInset;
+pub(crate) use self::render::HeatmapColorbarStyle;
+use self::render_pipeline::ProgressCallback;
 pub(crate) use self::types::{
     LegendConfig, PendingIngestionError, PlotSeries, ResolvedData, ResolvedFrame, ResolvedSeries,
     ResolvedSeriesStyle, ResolvedStreamingPair, ResolvedStyle, SeriesGroupMeta, SeriesType,
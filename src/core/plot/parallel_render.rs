@@ -1,16 +1,37 @@
 use super::*;
-use crate::core::Point2f;
 use crate::core::plot::raster_fast_path::{
-    canonicalize_line_points_exact, reduce_line_points_for_raster, should_reduce_line_series,
+    canonicalize_line_points_exact, downsample_line_points, finite_point_runs,
+    reduce_line_points_for_raster, should_reduce_line_series, simplify_line_points,
 };
+use crate::core::{CoordSystem, Point2f};
 use crate::render::skia::map_data_to_pixels_scaled;
 
+/// Which axes use a log scale, so their lower bound skips non-positive values.
+#[derive(Clone, Copy, Debug)]
+struct LogAxes {
+    x: bool,
+    y: bool,
+}
+
+/// Fold `value` into a running lower bound.
+///
+/// On a log axis non-positive values cannot be shown, so they are skipped and
+/// the bound settles on the smallest positive value instead.
+fn fold_lower_bound(bound: f64, value: f64, log_scale: bool) -> f64 {
+    if log_scale && value <= 0.0 {
+        bound
+    } else {
+        bound.min(value)
+    }
+}
+
 fn include_quiver_data_bounds(
     data: &crate::plots::QuiverPlotData,
     x_min: &mut f64,
     x_max: &mut f64,
     y_min: &mut f64,
     y_max: &mut f64,
+    log_axes: LogAxes,
 ) {
     data.arrows
         .iter()
@@ -25,38 +46,143 @@ fn include_quiver_data_bounds(
         })
         .for_each(|(x_val, y_val)| {
             if x_val.is_finite() {
-                *x_min = (*x_min).min(x_val);
+                *x_min = fold_lower_bound(*x_min, x_val, log_axes.x);
                 *x_max = (*x_max).max(x_val);
             }
             if y_val.is_finite() {
-                *y_min = (*y_min).min(y_val);
+                *y_min = fold_lower_bound(*y_min, y_val, log_axes.y);
                 *y_max = (*y_max).max(y_val);
             }
         });
 }
 
-fn include_plot_data_bounds<T: crate::plots::traits::PlotData>(
+/// Fold every finite value into a running lower bound with [`fold_lower_bound`].
+fn fold_lower_bound_all(bound: f64, values: impl IntoIterator<Item = f64>, log_scale: bool) -> f64 {
+    values
+        .into_iter()
+        .filter(|value| value.is_finite())
+        .fold(bound, |bound, value| {
+            fold_lower_bound(bound, value, log_scale)
+        })
+}
+
+/// Coordinates summarized by [`PlotData::data_bounds`](crate::plots::traits::PlotData::data_bounds).
+///
+/// A summary minimum at or below zero hides the smallest positive coordinate,
+/// so log axes rescan these values instead of dropping the series' minimum.
+trait AxisValues {
+    fn axis_values(&self) -> (Vec<f64>, Vec<f64>);
+}
+
+impl AxisValues for crate::plots::categorical::StripData {
+    fn axis_values(&self) -> (Vec<f64>, Vec<f64>) {
+        self.points.iter().map(|point| (point.x, point.y)).unzip()
+    }
+}
+
+impl AxisValues for crate::plots::BoxenData {
+    fn axis_values(&self) -> (Vec<f64>, Vec<f64>) {
+        let values: Vec<f64> = self
+            .boxes
+            .iter()
+            .flat_map(|level| [level.lower, level.upper])
+            .chain([self.median, self.data_range.0, self.data_range.1])
+            .chain(self.outliers.iter().copied())
+            .collect();
+        match self.config.orient {
+            crate::plots::BoxenOrientation::Vertical => (vec![0.0, 1.0], values),
+            crate::plots::BoxenOrientation::Horizontal => (values, vec![0.0, 1.0]),
+        }
+    }
+}
+
+impl AxisValues for crate::plots::HexbinPlotData {
+    fn axis_values(&self) -> (Vec<f64>, Vec<f64>) {
+        let ((x_min, x_max), (y_min, y_max)) = self.bounds;
+        self.bins
+            .iter()
+            .flat_map(|bin| bin.vertices)
+            .chain([(x_min, y_min), (x_max, y_max)])
+            .unzip()
+    }
+}
+
+impl AxisValues for crate::plots::ScatterMappedData {
+    fn axis_values(&self) -> (Vec<f64>, Vec<f64>) {
+        self.colored_points().map(|(x, y, _)| (x, y)).unzip()
+    }
+}
+
+impl AxisValues for crate::plots::HeatmapData {
+    fn axis_values(&self) -> (Vec<f64>, Vec<f64>) {
+        let edges = |(start, end): (f64, f64), cells: usize| {
+            let cells = cells.max(1);
+            (0..=cells)
+                .map(|edge| start + (end - start) * edge as f64 / cells as f64)
+                .collect()
+        };
+        (
+            edges(self.x_extent, self.n_cols),
+            edges(self.y_extent, self.n_rows),
+        )
+    }
+}
+
+fn include_plot_data_bounds<T: crate::plots::traits::PlotData + AxisValues>(
     data: &T,
     x_min: &mut f64,
     x_max: &mut f64,
     y_min: &mut f64,
     y_max: &mut f64,
+    log_axes: LogAxes,
 ) {
     let ((series_x_min, series_x_max), (series_y_min, series_y_max)) =
         crate::plots::traits::PlotData::data_bounds(data);
+    let rescan_x = log_axes.x && series_x_min <= 0.0;
+    let rescan_y = log_axes.y && series_y_min <= 0.0;
 
     if series_x_min.is_finite() {
-        *x_min = (*x_min).min(series_x_min);
+        *x_min = fold_lower_bound(*x_min, series_x_min, log_axes.x);
     }
     if series_x_max.is_finite() {
         *x_max = (*x_max).max(series_x_max);
     }
     if series_y_min.is_finite() {
-        *y_min = (*y_min).min(series_y_min);
+        *y_min = fold_lower_bound(*y_min, series_y_min, log_axes.y);
     }
     if series_y_max.is_finite() {
         *y_max = (*y_max).max(series_y_max);
     }
+
+    if rescan_x || rescan_y {
+        let (x_values, y_values) = data.axis_values();
+        if rescan_x {
+            *x_min = fold_lower_bound_all(*x_min, x_values, true);
+        }
+        if rescan_y {
+            *y_min = fold_lower_bound_all(*y_min, y_values, true);
+        }
+    }
+}
+
+/// Extend bounds along the axes a rug marks; rugs span no data range across
+/// their marks
+fn include_rug_bounds(
+    data: &crate::plots::RugData,
+    x_min: &mut f64,
+    x_max: &mut f64,
+    y_min: &mut f64,
+    y_max: &mut f64,
+    log_axes: LogAxes,
+) {
+    for &value in &data.points {
+        if data.config.axis != crate::plots::RugAxis::Y {
+            include_x_bounds(value, x_min, x_max, log_axes);
+        }
+        if data.config.axis != crate::plots::RugAxis::X {
+            include_y_bounds(value, y_min, y_max, log_axes);
+        }
+    }
 }
 
 fn include_point_bounds(
@@ -66,27 +192,28 @@ fn include_point_bounds(
     x_max: &mut f64,
     y_min: &mut f64,
     y_max: &mut f64,
+    log_axes: LogAxes,
 ) {
     if x_val.is_finite() {
-        *x_min = (*x_min).min(x_val);
+        *x_min = fold_lower_bound(*x_min, x_val, log_axes.x);
         *x_max = (*x_max).max(x_val);
     }
     if y_val.is_finite() {
-        *y_min = (*y_min).min(y_val);
+        *y_min = fold_lower_bound(*y_min, y_val, log_axes.y);
         *y_max = (*y_max).max(y_val);
     }
 }
 
-fn include_x_bounds(x_val: f64, x_min: &mut f64, x_max: &mut f64) {
+fn include_x_bounds(x_val: f64, x_min: &mut f64, x_max: &mut f64, log_axes: LogAxes) {
     if x_val.is_finite() {
-        *x_min = (*x_min).min(x_val);
+        *x_min = fold_lower_bound(*x_min, x_val, log_axes.x);
         *x_max = (*x_max).max(x_val);
     }
 }
 
-fn include_y_bounds(y_val: f64, y_min: &mut f64, y_max: &mut f64) {
+fn include_y_bounds(y_val: f64, y_min: &mut f64, y_max: &mut f64, log_axes: LogAxes) {
     if y_val.is_finite() {
-        *y_min = (*y_min).min(y_val);
+        *y_min = fold_lower_bound(*y_min, y_val, log_axes.y);
         *y_max = (*y_max).max(y_val);
     }
 }
@@ -97,34 +224,62 @@ fn include_annotation_data_bounds(
     x_max: &mut f64,
     y_min: &mut f64,
     y_max: &mut f64,
+    log_axes: LogAxes,
 ) {
     annotations.iter().for_each(|annotation| match annotation {
-        Annotation::Text { x, y, .. } => {
-            include_point_bounds(*x, *y, x_min, x_max, y_min, y_max);
+        Annotation::Text {
+            x,
+            y,
+            coords: CoordSystem::Data,
+            ..
         }
-        Annotation::Arrow { x1, y1, x2, y2, .. } => {
-            include_point_bounds(*x1, *y1, x_min, x_max, y_min, y_max);
-            include_point_bounds(*x2, *y2, x_min, x_max, y_min, y_max);
+        | Annotation::Callout {
+            x,
+            y,
+            coords: CoordSystem::Data,
+            ..
+        } => {
+            include_point_bounds(*x, *y, x_min, x_max, y_min, y_max, log_axes);
+        }
+        Annotation::Arrow {
+            x1,
+            y1,
+            x2,
+            y2,
+            coords: CoordSystem::Data,
+            ..
+        } => {
+            include_point_bounds(*x1, *y1, x_min, x_max, y_min, y_max, log_axes);
+            include_point_bounds(*x2, *y2, x_min, x_max, y_min, y_max, log_axes);
         }
-        Annotation::HLine { y, .. } => include_y_bounds(*y, y_min, y_max),
-        Annotation::VLine { x, .. } => include_x_bounds(*x, x_min, x_max),
+        Annotation::HLine { y, .. } => include_y_bounds(*y, y_min, y_max, log_axes),
+        Annotation::VLine { x, .. } => include_x_bounds(*x, x_min, x_max, log_axes),
         Annotation::Rectangle {
             x,
             y,
             width,
             height,
+            coords: CoordSystem::Data,
             ..
         } => {
-            include_point_bounds(*x, *y, x_min, x_max, y_min, y_max);
-            include_point_bounds(*x + *width, *y + *height, x_min, x_max, y_min, y_max);
+            include_point_bounds(*x, *y, x_min, x_max, y_min, y_max, log_axes);
+            include_point_bounds(
+                *x + *width,
+                *y + *height,
+                x_min,
+                x_max,
+                y_min,
+                y_max,
+                log_axes,
+            );
         }
         Annotation::FillBetween { x, y1, y2, .. } => {
             x.iter()
                 .zip(y1.iter())
                 .zip(y2.iter())
                 .for_each(|((&x_val, &y1_val), &y2_val)| {
-                    include_point_bounds(x_val, y1_val, x_min, x_max, y_min, y_max);
-                    include_point_bounds(x_val, y2_val, x_min, x_max, y_min, y_max);
+                    include_point_bounds(x_val, y1_val, x_min, x_max, y_min, y_max, log_axes);
+                    include_point_bounds(x_val, y2_val, x_min, x_max, y_min, y_max, log_axes);
                 });
         }
         Annotation::HSpan {
@@ -132,17 +287,22 @@ fn include_annotation_data_bounds(
             x_max: span_max,
             ..
         } => {
-            include_x_bounds(*span_min, x_min, x_max);
-            include_x_bounds(*span_max, x_min, x_max);
+            include_x_bounds(*span_min, x_min, x_max, log_axes);
+            include_x_bounds(*span_max, x_min, x_max, log_axes);
         }
         Annotation::VSpan {
             y_min: span_min,
             y_max: span_max,
             ..
         } => {
-            include_y_bounds(*span_min, y_min, y_max);
-            include_y_bounds(*span_max, y_min, y_max);
+            include_y_bounds(*span_min, y_min, y_max, log_axes);
+            include_y_bounds(*span_max, y_min, y_max, log_axes);
         }
+        // Axes- and figure-positioned annotations stay put as the limits change
+        Annotation::Text { .. }
+        | Annotation::Arrow { .. }
+        | Annotation::Callout { .. }
+        | Annotation::Rectangle { .. } => {}
     });
 }
 
@@ -183,6 +343,12 @@ impl Plot {
             self.display.config.typography.family.clone(),
         )?;
         renderer.set_text_engine_mode(self.display.text_engine);
+        renderer.set_antialias(self.render.antialias);
+        renderer.set_tick_label_formatters(
+            self.layout.x_label_formatter(),
+            self.layout.y_label_formatter(),
+        );
+        renderer.set_xtick_label_rotation(self.layout.tick_config.x_label_rotation);
         renderer.note_parallel_render();
         let render_scale = self.render_scale();
         let dpi = render_scale.dpi();
@@ -207,7 +373,7 @@ impl Plot {
 
         // Compute content-driven layout FIRST for consistent positioning
         let content = self.create_plot_content_from_resolved_text(bounds.2, bounds.3, frame);
-        let (layout, x_ticks, y_ticks) = self.compute_layout_with_configured_ticks(
+        let (mut layout, x_ticks, y_ticks) = self.compute_layout_with_configured_ticks(
             &renderer,
             (scaled_width, scaled_height),
             &content,
@@ -228,6 +394,9 @@ impl Plot {
             )
         });
 
+        let spine_origin = self.spine_origin_px(plot_area, bounds.0, bounds.1, bounds.2, bounds.3);
+        Self::follow_spine_origin(&mut layout, plot_area, spine_origin);
+
         // Convert to parallel renderer format
         let parallel_plot_area = PlotArea {
             left: plot_area.left(),
@@ -285,24 +454,34 @@ impl Plot {
         // Draw grid if enabled - using unified GridStyle (sequential - UI elements)
         // Skip grid for non-Cartesian plots (Pie, Radar, Polar)
         if self.layout.grid_style.visible && self.needs_cartesian_axes() {
-            let grid_color = self.layout.grid_style.effective_color();
-            let grid_x_pixels = Self::grid_tick_pixels(
+            let grid_style = &self.layout.grid_style;
+            let (grid_x_pixels, minor_grid_x_pixels) = Self::grid_tick_pixels(
                 &x_tick_pixels,
                 &x_minor_tick_pixels,
                 &self.layout.tick_config.grid_mode,
+                self.layout.tick_config.grid_axis.shows_x(),
             );
-            let grid_y_pixels = Self::grid_tick_pixels(
+            let (grid_y_pixels, minor_grid_y_pixels) = Self::grid_tick_pixels(
                 &y_tick_pixels,
                 &y_minor_tick_pixels,
                 &self.layout.tick_config.grid_mode,
+                self.layout.tick_config.grid_axis.shows_y(),
             );
             renderer.draw_grid(
-                &grid_x_pixels,
-                &grid_y_pixels,
+                minor_grid_x_pixels,
+                minor_grid_y_pixels,
                 plot_area,
-                grid_color,
-                self.layout.grid_style.line_style.clone(),
-                self.dpi_scaled_line_width(self.layout.grid_style.line_width),
+                grid_style.effective_minor_color(),
+                grid_style.effective_minor_line_style(),
+                self.dpi_scaled_line_width(grid_style.minor_line_width),
+            )?;
+            renderer.draw_grid(
+                grid_x_pixels,
+                grid_y_pixels,
+                plot_area,
+                grid_style.effective_color(),
+                grid_style.line_style.clone(),
+                self.dpi_scaled_line_width(grid_style.line_width),
             )?;
         }
 
@@ -328,7 +507,7 @@ impl Plot {
             };
             let (axis_width, major_tick_size, minor_tick_size, major_tick_width, minor_tick_width) =
                 self.axis_tick_metrics_px();
-            renderer.draw_axes_with_minor_ticks_styled(
+            renderer.draw_axes_with_spine_origin(
                 plot_area,
                 x_axis_ticks,
                 &y_tick_pixels,
@@ -343,11 +522,12 @@ impl Plot {
                 minor_tick_size,
                 major_tick_width,
                 minor_tick_width,
+                spine_origin,
             )?;
         } else if draw_axes {
             let (axis_width, major_tick_size, minor_tick_size, major_tick_width, minor_tick_width) =
                 self.axis_tick_metrics_px();
-            renderer.draw_axes_with_minor_ticks_styled(
+            renderer.draw_axes_with_spine_origin(
                 plot_area,
                 &[],
                 &[],
@@ -362,6 +542,7 @@ impl Plot {
                 minor_tick_size,
                 major_tick_width,
                 minor_tick_width,
+                spine_origin,
             )?;
         }
 
@@ -418,6 +599,26 @@ impl Plot {
                             points = reduced;
                         }
 
+                        if let Some(mode) = series.downsample
+                            && let Some(downsampled) = downsample_line_points(
+                                &points,
+                                mode,
+                                parallel_plot_area.left,
+                                parallel_plot_area.width(),
+                            )
+                        {
+                            points = downsampled;
+                        }
+
+                        if let Some(tolerance) = series.simplify
+                            && let Some(simplified) = simplify_line_points(
+                                &points,
+                                self.render_scale().logical_pixels_to_pixels(tolerance),
+                            )
+                        {
+                            points = simplified;
+                        }
+
                         RenderSeriesType::Polyline {
                             points,
                             style: series.line_style.clone().unwrap_or(LineStyle::Solid),
@@ -620,8 +821,8 @@ impl Plot {
                             })?;
 
                         // Transform coordinates for box plot elements
-                        let x_center = 0.5; // Center the box plot
-                        let box_width = 0.3; // Box width
+                        let x_center = config.x_position;
+                        let box_width = 0.3; // Box width in data units
 
                         // Map Y coordinates to plot area
                         let q1_y = map_data_to_pixels_scaled(
@@ -698,8 +899,14 @@ impl Plot {
                             &self.layout.y_scale,
                         )
                         .0;
-                        let box_left = x_center_px - box_width * plot_area.width() * 0.5;
-                        let box_right = x_center_px + box_width * plot_area.width() * 0.5;
+                        let box_half_width = Self::box_half_width_px(
+                            plot_area.width(),
+                            box_width,
+                            bounds.0,
+                            bounds.1,
+                        );
+                        let box_left = x_center_px - box_half_width;
+                        let box_right = x_center_px + box_half_width;
 
                         // Transform outliers
                         let mut outliers = Vec::new();
@@ -764,9 +971,8 @@ impl Plot {
                             .enumerate()
                             .flat_map(|(row_idx, row)| {
                                 row.iter().enumerate().filter_map(move |(col_idx, &value)| {
-                                    if data.should_mask_value(value) {
-                                        return None;
-                                    }
+                                    let cell_color =
+                                        data.cell_color(value, data.config.alpha * alpha)?;
 
                                     let (x, y, width, height) =
                                         data.cell_screen_rect(&heatmap_plot_area, row_idx, col_idx);
@@ -778,8 +984,6 @@ impl Plot {
                                         return None;
                                     }
 
-                                    let cell_color =
-                                        data.get_color(value).with_alpha(data.config.alpha * alpha);
                                     Some(crate::render::parallel::HeatmapCell {
                                         x: left,
                                         y: top,
@@ -799,6 +1003,9 @@ impl Plot {
                             cells,
                             n_rows: data.n_rows,
                             n_cols: data.n_cols,
+                            cell_border: data.config.cell_border.map(|(color, width)| {
+                                (color, self.render_scale().points_to_pixels(width))
+                            }),
                         }
                     }
                     SeriesType::Kde { data: kde_data } => {
@@ -927,7 +1134,13 @@ impl Plot {
 
                         RenderSeriesType::Line { segments }
                     }
-                    SeriesType::Quiver { .. } => RenderSeriesType::Line { segments: vec![] },
+                    SeriesType::Quiver { .. }
+                    | SeriesType::Hexbin { .. }
+                    | SeriesType::Rug { .. }
+                    | SeriesType::Strip { .. }
+                    | SeriesType::ScatterMapped { .. } => {
+                        RenderSeriesType::Line { segments: vec![] }
+                    }
                     SeriesType::Contour { data: contour_data } => {
                         // Contour plots use line segment rendering
                         let mut all_points = Vec::new();
@@ -1065,8 +1278,14 @@ impl Plot {
             Self::is_underlay_annotation,
         )?;
 
-        // Render processed series (sequential - final drawing)
-        for processed in processed_series {
+        // Render processed series (sequential - final drawing, in z-order)
+        let mut processed_series: Vec<_> = processed_series.into_iter().map(Some).collect();
+        for idx in PlotSeries::draw_order(&self.series_mgr.series) {
+            let (Some(processed), series) =
+                (processed_series[idx].take(), &self.series_mgr.series[idx])
+            else {
+                continue;
+            };
             match processed.series_type {
                 RenderSeriesType::Polyline {
                     points,
@@ -1074,9 +1293,17 @@ impl Plot {
                     color,
                     width,
                 } => {
-                    let points: Vec<(f32, f32)> =
-                        points.into_iter().map(|point| (point.x, point.y)).collect();
-                    renderer.draw_polyline_clipped(&points, color, width, style, clip_rect)?;
+                    for run in finite_point_runs(&points) {
+                        let run: Vec<(f32, f32)> =
+                            run.iter().map(|point| (point.x, point.y)).collect();
+                        renderer.draw_polyline_clipped(
+                            &run,
+                            color,
+                            width,
+                            style.clone(),
+                            clip_rect,
+                        )?;
+                    }
                 }
                 RenderSeriesType::Line { segments } => {
                     // Draw all line segments
@@ -1095,7 +1322,20 @@ impl Plot {
                 }
                 RenderSeriesType::Scatter { markers } => {
                     if let Some(first) = markers.first() {
-                        if markers.iter().all(|marker| {
+                        let paint = series.marker_paint(first.style, processed.color, |width| {
+                            self.dpi_scaled_line_width(width)
+                        });
+                        if let Some(paint) = paint {
+                            let points: Vec<Point2f> =
+                                markers.iter().map(|marker| marker.position).collect();
+                            renderer.draw_markers_painted_clipped(
+                                &points,
+                                first.size,
+                                first.style,
+                                paint,
+                                clip_rect,
+                            )?;
+                        } else if markers.iter().all(|marker| {
                             marker.style == first.style
                                 && marker.color == first.color
                                 && marker.size.to_bits() == first.size.to_bits()
@@ -1220,10 +1460,12 @@ impl Plot {
                 RenderSeriesType::ErrorBars { .. } => {
                     // Error bars implementation would go here
                 }
-                RenderSeriesType::Heatmap { cells, .. } => {
+                RenderSeriesType::Heatmap {
+                    cells, cell_border, ..
+                } => {
                     // Draw all heatmap cells as pixel-aligned rectangles so custom
                     // extents and clipped views stay seam-free.
-                    for cell in cells {
+                    for cell in &cells {
                         renderer.draw_pixel_aligned_solid_rectangle(
                             cell.x,
                             cell.y,
@@ -1241,6 +1483,14 @@ impl Plot {
                             )?;
                         }
                     }
+                    if let Some((color, width)) = cell_border {
+                        let rects: Vec<_> = cells
+                            .iter()
+                            .map(|cell| (cell.x, cell.y, cell.width, cell.height))
+                            .collect();
+                        renderer
+                            .draw_rectangle_outlines_clipped(&rects, color, width, clip_rect)?;
+                    }
                 }
             }
         }
@@ -1279,14 +1529,16 @@ impl Plot {
                     false,
                 )?;
             } else {
+                let (x_label_ticks, y_label_ticks) = self
+                    .tick_label_values(&x_ticks, &y_ticks, bounds.0, bounds.1, bounds.2, bounds.3);
                 renderer.draw_axis_labels_at_scaled(
                     &layout.plot_area,
                     bounds.0,
                     bounds.1,
                     bounds.2,
                     bounds.3,
-                    &x_ticks,
-                    &y_ticks,
+                    &x_label_ticks,
+                    &y_label_ticks,
                     layout.xtick_baseline_y,
                     layout.ytick_right_x,
                     tick_size_px,
@@ -1328,6 +1580,15 @@ impl Plot {
             }
         }
 
+        self.draw_insets(
+            &mut renderer,
+            plot_area,
+            bounds.0,
+            bounds.1,
+            bounds.2,
+            bounds.3,
+        )?;
+
         let legend_items = self.collect_legend_items();
         if !legend_items.is_empty() && frame.style.legend.enabled {
             renderer.draw_legend_full_resolved(
@@ -1339,22 +1600,54 @@ impl Plot {
             )?;
         }
 
-        // Record performance statistics
-        let duration = start_time.elapsed();
-        let total_points = self.calculate_total_points();
-
-        // Performance stats available via self.render.parallel_renderer.performance_stats()
-        // Uncomment for debugging:
-        // let stats = self.render.parallel_renderer.performance_stats();
-        // println!("⚡ Parallel: {} series, {} points in {:.1}ms ({:.1}x speedup, {} threads)",
-        //     self.series_mgr.series.len(), total_points, duration.as_millis(),
-        //     stats.estimated_speedup, stats.configured_threads);
-        let _ = (start_time, total_points); // suppress unused warnings
+        // Performance summary for `RUST_LOG=debug`; never printed to stdout
+        if log::log_enabled!(log::Level::Debug) {
+            let stats = self.render.parallel_renderer.performance_stats();
+            log::debug!(
+                "parallel render: {} series, {} points in {:.1}ms ({:.1}x speedup, {} threads)",
+                self.series_mgr.series.len(),
+                self.calculate_total_points(),
+                start_time.elapsed().as_secs_f64() * 1000.0,
+                stats.estimated_speedup,
+                stats.configured_threads
+            );
+        }
 
         // Convert renderer output to Image
         Ok(renderer.into_image())
     }
 
+    fn log_axes(&self) -> LogAxes {
+        LogAxes {
+            x: matches!(self.layout.x_scale, AxisScale::Log),
+            y: matches!(self.layout.y_scale, AxisScale::Log),
+        }
+    }
+
+    /// Reject auto-scaled log axes whose data has no positive value to start
+    /// the axis at.
+    ///
+    /// Data bounds on a log axis skip non-positive values, so a finite upper
+    /// bound without a finite lower bound means every value was zero or
+    /// negative.
+    fn check_log_axis_data(&self, x_min: f64, x_max: f64, y_min: f64, y_max: f64) -> Result<()> {
+        let log_axes = self.log_axes();
+        for (axis, log_scale, min, max, limits) in [
+            ("x", log_axes.x, x_min, x_max, self.layout.x_limits),
+            ("y", log_axes.y, y_min, y_max, self.layout.y_limits),
+        ] {
+            if log_scale && limits.is_none() && !min.is_finite() && max.is_finite() {
+                return Err(PlottingError::InvalidData {
+                    message: format!(
+                        "{axis} axis uses a log scale but has no positive data; use AxisScale::SymLog for zero or negative values"
+                    ),
+                    position: None,
+                });
+            }
+        }
+        Ok(())
+    }
+
     /// Expand already-computed data bounds with annotation geometry.
     pub(super) fn expand_bounds_with_annotations(
         &self,
@@ -1368,6 +1661,7 @@ impl Plot {
             &mut x_max,
             &mut y_min,
             &mut y_max,
+            self.log_axes(),
         );
 
         (x_min, x_max) = crate::axes::expand_degenerate_range(x_min, x_max, &self.layout.x_scale);
@@ -1376,7 +1670,7 @@ impl Plot {
         (x_min, x_max, y_min, y_max)
     }
 
-    /// Calculate data bounds across all series
+    /// Calculate data bounds across all visible series
     pub(super) fn calculate_data_bounds(&self) -> Result<(f64, f64, f64, f64)> {
         if let Some(err) = self.pending_ingestion_error() {
             return Err(err);
@@ -1386,34 +1680,48 @@ impl Plot {
         let mut x_max = f64::NEG_INFINITY;
         let mut y_min = f64::INFINITY;
         let mut y_max = f64::NEG_INFINITY;
+        let log_axes = self.log_axes();
 
-        for series in &self.series_mgr.series {
+        for series in self
+            .series_mgr
+            .series
+            .iter()
+            .filter(|series| series.visible)
+        {
             match &series.series_type {
                 SeriesType::Line { x_data, y_data } | SeriesType::Scatter { x_data, y_data } => {
                     let x_data = x_data.resolve_cow(0.0);
                     let y_data = y_data.resolve_cow(0.0);
                     for (&x_val, &y_val) in x_data.iter().zip(y_data.iter()) {
                         if x_val.is_finite() {
-                            x_min = x_min.min(x_val);
+                            x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                             x_max = x_max.max(x_val);
                         }
                         if y_val.is_finite() {
-                            y_min = y_min.min(y_val);
+                            y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                             y_max = y_max.max(y_val);
                         }
                     }
                 }
-                SeriesType::Bar { categories, values } => {
+                SeriesType::Bar {
+                    categories, values, ..
+                } => {
                     let values = values.resolve_cow(0.0);
                     // Add 0.5-unit padding on each side for bar charts (matplotlib-compatible)
                     // This ensures bars at positions 0 and n-1 are fully visible
-                    x_min = x_min.min(-0.5);
+                    x_min = fold_lower_bound(x_min, -0.5, log_axes.x);
                     x_max = x_max.max(categories.len() as f64 - 0.5);
 
-                    for &val in values.iter() {
+                    let errors = series.y_errors.as_ref().map(ErrorValuesRef::from);
+                    for (i, &val) in values.iter().enumerate() {
                         if val.is_finite() {
-                            y_min = y_min.min(val.min(0.0));
-                            y_max = y_max.max(val.max(0.0));
+                            let (low, high) = bar_y_extent(val, errors, i);
+                            y_min = fold_lower_bound(
+                                fold_lower_bound(y_min, low, log_axes.y),
+                                0.0,
+                                log_axes.y,
+                            );
+                            y_max = y_max.max(high.max(0.0));
                         }
                     }
                 }
@@ -1425,16 +1733,15 @@ impl Plot {
                     let x_data = x_data.resolve_cow(0.0);
                     let y_data = y_data.resolve_cow(0.0);
                     let y_errors = y_errors.resolve_cow(0.0);
-                    for ((&x_val, &y_val), &y_err) in
-                        x_data.iter().zip(y_data.iter()).zip(y_errors.iter())
-                    {
+                    let errors = effective_error_values(series.y_errors.as_ref(), &y_errors);
+                    for (i, (&x_val, &y_val)) in x_data.iter().zip(y_data.iter()).enumerate() {
                         if x_val.is_finite() {
-                            x_min = x_min.min(x_val);
+                            x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                             x_max = x_max.max(x_val);
                         }
-                        if y_val.is_finite() && y_err.is_finite() {
-                            y_min = y_min.min(y_val - y_err);
-                            y_max = y_max.max(y_val + y_err);
+                        if let Some((low, high)) = error_bar_extent(y_val, errors, i) {
+                            y_min = fold_lower_bound(y_min, low, log_axes.y);
+                            y_max = y_max.max(high);
                         }
                     }
                 }
@@ -1455,11 +1762,11 @@ impl Plot {
                         .zip(y_errors.iter())
                     {
                         if x_val.is_finite() && x_err.is_finite() {
-                            x_min = x_min.min(x_val - x_err);
+                            x_min = fold_lower_bound(x_min, x_val - x_err, log_axes.x);
                             x_max = x_max.max(x_val + x_err);
                         }
                         if y_val.is_finite() && y_err.is_finite() {
-                            y_min = y_min.min(y_val - y_err);
+                            y_min = fold_lower_bound(y_min, y_val - y_err, log_axes.y);
                             y_max = y_max.max(y_val + y_err);
                         }
                     }
@@ -1468,45 +1775,50 @@ impl Plot {
                     if let Ok(hist_data) = series.series_type.histogram_data_at(0.0) {
                         // X bounds from bin edges
                         if !hist_data.bin_edges.is_empty() {
-                            x_min = x_min.min(*hist_data.bin_edges.first().unwrap());
+                            x_min = fold_lower_bound_all(
+                                x_min,
+                                hist_data.bin_edges.iter().copied(),
+                                log_axes.x,
+                            );
                             x_max = x_max.max(*hist_data.bin_edges.last().unwrap());
                         }
 
                         // Y bounds from counts (include zero baseline)
-                        y_min = y_min.min(0.0);
+                        y_min = fold_lower_bound(y_min, 0.0, log_axes.y);
                         for &count in &hist_data.counts {
                             if count.is_finite() && count > 0.0 {
+                                y_min = fold_lower_bound(y_min, count, log_axes.y);
                                 y_max = y_max.max(count);
                             }
                         }
                     }
                 }
-                SeriesType::BoxPlot { data, .. } => {
+                SeriesType::BoxPlot { data, config } => {
                     let data = data.resolve_cow(0.0);
                     if data.is_empty() {
                         return Err(PlottingError::EmptyDataSet);
                     }
 
-                    // Set x bounds for box plot (centered at 0.5)
-                    x_min = x_min.min(0.0);
-                    x_max = x_max.max(1.0);
+                    // Set x bounds for box plot (one unit wide around its position)
+                    x_min = fold_lower_bound(x_min, config.x_position - 0.5, log_axes.x);
+                    x_max = x_max.max(config.x_position + 0.5);
 
                     // Y bounds include all data values
                     for &value in data.iter() {
                         if value.is_finite() {
-                            y_min = y_min.min(value);
+                            y_min = fold_lower_bound(y_min, value, log_axes.y);
                             y_max = y_max.max(value);
                         }
                     }
                 }
-                SeriesType::Heatmap { data } => {
-                    let ((series_x_min, series_x_max), (series_y_min, series_y_max)) =
-                        crate::plots::traits::PlotData::data_bounds(data.as_ref());
-                    x_min = x_min.min(series_x_min);
-                    x_max = x_max.max(series_x_max);
-                    y_min = y_min.min(series_y_min);
-                    y_max = y_max.max(series_y_max);
-                }
+                SeriesType::Heatmap { data } => include_plot_data_bounds(
+                    data.as_ref(),
+                    &mut x_min,
+                    &mut x_max,
+                    &mut y_min,
+                    &mut y_max,
+                    log_axes,
+                ),
                 SeriesType::Kde { data } => {
                     // KDE bounds from x/y data
                     for i in 0..data.x.len() {
@@ -1514,16 +1826,16 @@ impl Plot {
                         let y_val = data.y[i];
 
                         if x_val.is_finite() {
-                            x_min = x_min.min(x_val);
+                            x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                             x_max = x_max.max(x_val);
                         }
                         if y_val.is_finite() {
-                            y_min = y_min.min(y_val);
+                            y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                             y_max = y_max.max(y_val);
                         }
                     }
                     // Include zero baseline for density plots
-                    y_min = y_min.min(0.0);
+                    y_min = fold_lower_bound(y_min, 0.0, log_axes.y);
                 }
                 SeriesType::Ecdf { data } => {
                     // ECDF bounds from x/y data
@@ -1532,17 +1844,28 @@ impl Plot {
                         let y_val = data.y[i];
 
                         if x_val.is_finite() {
-                            x_min = x_min.min(x_val);
+                            x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                             x_max = x_max.max(x_val);
                         }
                         if y_val.is_finite() {
-                            y_min = y_min.min(y_val);
+                            y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                             y_max = y_max.max(y_val);
                         }
                     }
                     // Include zero baseline for ECDF
-                    y_min = y_min.min(0.0);
+                    y_min = fold_lower_bound(y_min, 0.0, log_axes.y);
                 }
+                SeriesType::Rug { data } => include_rug_bounds(
+                    data, &mut x_min, &mut x_max, &mut y_min, &mut y_max, log_axes,
+                ),
+                SeriesType::Strip { data } => include_plot_data_bounds(
+                    data.as_ref(),
+                    &mut x_min,
+                    &mut x_max,
+                    &mut y_min,
+                    &mut y_max,
+                    log_axes,
+                ),
                 SeriesType::Violin { data } => {
                     // Violin bounds from KDE range (extends beyond data range by 3 bandwidths)
                     // Use KDE's x values which represent the evaluation range
@@ -1555,13 +1878,16 @@ impl Plot {
                         data.range
                     };
                     if kde_min.is_finite() {
-                        y_min = y_min.min(kde_min);
+                        y_min = fold_lower_bound(y_min, kde_min, log_axes.y);
                     }
                     if kde_max.is_finite() {
                         y_max = y_max.max(kde_max);
                     }
+                    if log_axes.y && kde_min <= 0.0 {
+                        y_min = fold_lower_bound_all(y_min, data.kde.x.iter().copied(), true);
+                    }
                     // X bounds for centered violin
-                    x_min = x_min.min(0.0);
+                    x_min = fold_lower_bound(x_min, 0.0, log_axes.x);
                     x_max = x_max.max(1.0);
                 }
                 SeriesType::Boxen { data } => {
@@ -1571,33 +1897,54 @@ impl Plot {
                         &mut x_max,
                         &mut y_min,
                         &mut y_max,
+                        log_axes,
                     );
                 }
                 SeriesType::Quiver { data } => {
                     include_quiver_data_bounds(
-                        data, &mut x_min, &mut x_max, &mut y_min, &mut y_max,
+                        data, &mut x_min, &mut x_max, &mut y_min, &mut y_max, log_axes,
+                    );
+                }
+                SeriesType::Hexbin { data } => {
+                    include_plot_data_bounds(
+                        data.as_ref(),
+                        &mut x_min,
+                        &mut x_max,
+                        &mut y_min,
+                        &mut y_max,
+                        log_axes,
+                    );
+                }
+                SeriesType::ScatterMapped { data } => {
+                    include_plot_data_bounds(
+                        data.as_ref(),
+                        &mut x_min,
+                        &mut x_max,
+                        &mut y_min,
+                        &mut y_max,
+                        log_axes,
                     );
                 }
                 SeriesType::Contour { data } => {
                     // Contour bounds from grid coordinates
                     for &x_val in &data.x {
                         if x_val.is_finite() {
-                            x_min = x_min.min(x_val);
+                            x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                             x_max = x_max.max(x_val);
                         }
                     }
                     for &y_val in &data.y {
                         if y_val.is_finite() {
-                            y_min = y_min.min(y_val);
+                            y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                             y_max = y_max.max(y_val);
                         }
                     }
                 }
                 SeriesType::Pie { .. } => {
                     // Pie charts use normalized 0-1 coordinate space
-                    x_min = x_min.min(0.0);
+                    x_min = fold_lower_bound(x_min, 0.0, log_axes.x);
                     x_max = x_max.max(1.0);
-                    y_min = y_min.min(0.0);
+                    y_min = fold_lower_bound(y_min, 0.0, log_axes.y);
                     y_max = y_max.max(1.0);
                 }
                 SeriesType::Radar { data } => {
@@ -1606,11 +1953,11 @@ impl Plot {
                     for series_data in &data.series {
                         for &(x_val, y_val) in &series_data.polygon {
                             if x_val.is_finite() {
-                                x_min = x_min.min(x_val);
+                                x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                                 x_max = x_max.max(x_val);
                             }
                             if y_val.is_finite() {
-                                y_min = y_min.min(y_val);
+                                y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                                 y_max = y_max.max(y_val);
                             }
                         }
@@ -1635,11 +1982,14 @@ impl Plot {
             &mut x_max,
             &mut y_min,
             &mut y_max,
+            log_axes,
         );
 
-        if !x_min.is_finite() || !x_max.is_finite() || !y_min.is_finite() || !y_max.is_finite() {
+        self.check_log_axis_data(x_min, x_max, y_min, y_max)?;
+        let Some(bounds) = self.fill_unspanned_axis((x_min, x_max, y_min, y_max)) else {
             return Ok(self.empty_cartesian_bounds());
-        }
+        };
+        (x_min, x_max, y_min, y_max) = bounds;
 
         // Handle edge cases
         (x_min, x_max) = crate::axes::expand_degenerate_range(x_min, x_max, &self.layout.x_scale);
@@ -1663,40 +2013,56 @@ impl Plot {
         let mut x_max = f64::NEG_INFINITY;
         let mut y_min = f64::INFINITY;
         let mut y_max = f64::NEG_INFINITY;
+        let log_axes = self.log_axes();
 
         for resolved in resolved_series {
             match resolved {
                 ResolvedSeries::Line { x, y } | ResolvedSeries::Scatter { x, y } => {
                     for (&x_val, &y_val) in x.iter().zip(y.iter()) {
                         if x_val.is_finite() {
-                            x_min = x_min.min(x_val);
+                            x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                             x_max = x_max.max(x_val);
                         }
                         if y_val.is_finite() {
-                            y_min = y_min.min(y_val);
+                            y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                             y_max = y_max.max(y_val);
                         }
                     }
                 }
-                ResolvedSeries::Bar { categories, values } => {
-                    x_min = x_min.min(-0.5);
+                ResolvedSeries::Bar {
+                    categories,
+                    values,
+                    errors,
+                } => {
+                    x_min = fold_lower_bound(x_min, -0.5, log_axes.x);
                     x_max = x_max.max(categories.len() as f64 - 0.5);
-                    for &value in values.iter() {
+                    for (i, &value) in values.iter().enumerate() {
                         if value.is_finite() {
-                            y_min = y_min.min(value.min(0.0));
-                            y_max = y_max.max(value.max(0.0));
+                            let (low, high) = bar_y_extent(value, *errors, i);
+                            y_min = fold_lower_bound(
+                                fold_lower_bound(y_min, low, log_axes.y),
+                                0.0,
+                                log_axes.y,
+                            );
+                            y_max = y_max.max(high.max(0.0));
                         }
                     }
                 }
-                ResolvedSeries::ErrorBars { x, y, y_errors } => {
-                    for ((&x_val, &y_val), &y_err) in x.iter().zip(y.iter()).zip(y_errors.iter()) {
+                ResolvedSeries::ErrorBars {
+                    x,
+                    y,
+                    y_errors,
+                    errors,
+                } => {
+                    let errors = errors.unwrap_or(ErrorValuesRef::Symmetric(y_errors));
+                    for (i, (&x_val, &y_val)) in x.iter().zip(y.iter()).enumerate() {
                         if x_val.is_finite() {
-                            x_min = x_min.min(x_val);
+                            x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                             x_max = x_max.max(x_val);
                         }
-                        if y_val.is_finite() && y_err.is_finite() {
-                            y_min = y_min.min(y_val - y_err);
-                            y_max = y_max.max(y_val + y_err);
+                        if let Some((low, high)) = error_bar_extent(y_val, errors, i) {
+                            y_min = fold_lower_bound(y_min, low, log_axes.y);
+                            y_max = y_max.max(high);
                         }
                     }
                 }
@@ -1713,75 +2079,88 @@ impl Plot {
                         .zip(y_errors.iter())
                     {
                         if x_val.is_finite() && x_err.is_finite() {
-                            x_min = x_min.min(x_val - x_err);
+                            x_min = fold_lower_bound(x_min, x_val - x_err, log_axes.x);
                             x_max = x_max.max(x_val + x_err);
                         }
                         if y_val.is_finite() && y_err.is_finite() {
-                            y_min = y_min.min(y_val - y_err);
+                            y_min = fold_lower_bound(y_min, y_val - y_err, log_axes.y);
                             y_max = y_max.max(y_val + y_err);
                         }
                     }
                 }
                 ResolvedSeries::Histogram { data } => {
                     if !data.bin_edges.is_empty() {
-                        x_min = x_min.min(*data.bin_edges.first().unwrap());
+                        x_min =
+                            fold_lower_bound_all(x_min, data.bin_edges.iter().copied(), log_axes.x);
                         x_max = x_max.max(*data.bin_edges.last().unwrap());
                     }
-                    y_min = y_min.min(0.0);
+                    y_min = fold_lower_bound(y_min, 0.0, log_axes.y);
                     for &count in &data.counts {
                         if count.is_finite() && count > 0.0 {
+                            y_min = fold_lower_bound(y_min, count, log_axes.y);
                             y_max = y_max.max(count);
                         }
                     }
                 }
-                ResolvedSeries::BoxPlot { data, .. } => {
+                ResolvedSeries::BoxPlot { data, config } => {
                     if data.is_empty() {
                         return Err(PlottingError::EmptyDataSet);
                     }
-                    x_min = x_min.min(0.0);
-                    x_max = x_max.max(1.0);
+                    x_min = fold_lower_bound(x_min, config.x_position - 0.5, log_axes.x);
+                    x_max = x_max.max(config.x_position + 0.5);
                     for &value in data.iter() {
                         if value.is_finite() {
-                            y_min = y_min.min(value);
+                            y_min = fold_lower_bound(y_min, value, log_axes.y);
                             y_max = y_max.max(value);
                         }
                     }
                 }
                 ResolvedSeries::Other(series) => match series {
-                    SeriesType::Heatmap { data } => {
-                        let ((sx_min, sx_max), (sy_min, sy_max)) =
-                            crate::plots::traits::PlotData::data_bounds(data.as_ref());
-                        x_min = x_min.min(sx_min);
-                        x_max = x_max.max(sx_max);
-                        y_min = y_min.min(sy_min);
-                        y_max = y_max.max(sy_max);
-                    }
+                    SeriesType::Heatmap { data } => include_plot_data_bounds(
+                        data.as_ref(),
+                        &mut x_min,
+                        &mut x_max,
+                        &mut y_min,
+                        &mut y_max,
+                        log_axes,
+                    ),
                     SeriesType::Kde { data } => {
                         for (&x_val, &y_val) in data.x.iter().zip(&data.y) {
                             if x_val.is_finite() {
-                                x_min = x_min.min(x_val);
+                                x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                                 x_max = x_max.max(x_val);
                             }
                             if y_val.is_finite() {
-                                y_min = y_min.min(y_val);
+                                y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                                 y_max = y_max.max(y_val);
                             }
                         }
-                        y_min = y_min.min(0.0);
+                        y_min = fold_lower_bound(y_min, 0.0, log_axes.y);
                     }
                     SeriesType::Ecdf { data } => {
                         for (&x_val, &y_val) in data.x.iter().zip(&data.y) {
                             if x_val.is_finite() {
-                                x_min = x_min.min(x_val);
+                                x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                                 x_max = x_max.max(x_val);
                             }
                             if y_val.is_finite() {
-                                y_min = y_min.min(y_val);
+                                y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                                 y_max = y_max.max(y_val);
                             }
                         }
-                        y_min = y_min.min(0.0);
+                        y_min = fold_lower_bound(y_min, 0.0, log_axes.y);
                     }
+                    SeriesType::Rug { data } => include_rug_bounds(
+                        data, &mut x_min, &mut x_max, &mut y_min, &mut y_max, log_axes,
+                    ),
+                    SeriesType::Strip { data } => include_plot_data_bounds(
+                        data.as_ref(),
+                        &mut x_min,
+                        &mut x_max,
+                        &mut y_min,
+                        &mut y_max,
+                        log_axes,
+                    ),
                     SeriesType::Violin { data } => {
                         let (kde_min, kde_max) = if data.kde.x.is_empty() {
                             data.range
@@ -1791,9 +2170,12 @@ impl Plot {
                                 data.kde.x.last().copied().unwrap_or(data.range.1),
                             )
                         };
-                        y_min = y_min.min(kde_min);
+                        y_min = fold_lower_bound(y_min, kde_min, log_axes.y);
                         y_max = y_max.max(kde_max);
-                        x_min = x_min.min(0.0);
+                        if log_axes.y && kde_min <= 0.0 {
+                            y_min = fold_lower_bound_all(y_min, data.kde.x.iter().copied(), true);
+                        }
+                        x_min = fold_lower_bound(x_min, 0.0, log_axes.x);
                         x_max = x_max.max(1.0);
                     }
                     SeriesType::Boxen { data } => include_plot_data_bounds(
@@ -1802,38 +2184,55 @@ impl Plot {
                         &mut x_max,
                         &mut y_min,
                         &mut y_max,
+                        log_axes,
                     ),
                     SeriesType::Quiver { data } => include_quiver_data_bounds(
-                        data, &mut x_min, &mut x_max, &mut y_min, &mut y_max,
+                        data, &mut x_min, &mut x_max, &mut y_min, &mut y_max, log_axes,
+                    ),
+                    SeriesType::Hexbin { data } => include_plot_data_bounds(
+                        data.as_ref(),
+                        &mut x_min,
+                        &mut x_max,
+                        &mut y_min,
+                        &mut y_max,
+                        log_axes,
+                    ),
+                    SeriesType::ScatterMapped { data } => include_plot_data_bounds(
+                        data.as_ref(),
+                        &mut x_min,
+                        &mut x_max,
+                        &mut y_min,
+                        &mut y_max,
+                        log_axes,
                     ),
                     SeriesType::Contour { data } => {
                         for &value in &data.x {
                             if value.is_finite() {
-                                x_min = x_min.min(value);
+                                x_min = fold_lower_bound(x_min, value, log_axes.x);
                                 x_max = x_max.max(value);
                             }
                         }
                         for &value in &data.y {
                             if value.is_finite() {
-                                y_min = y_min.min(value);
+                                y_min = fold_lower_bound(y_min, value, log_axes.y);
                                 y_max = y_max.max(value);
                             }
                         }
                     }
                     SeriesType::Pie { .. } => {
-                        x_min = x_min.min(0.0);
+                        x_min = fold_lower_bound(x_min, 0.0, log_axes.x);
                         x_max = x_max.max(1.0);
-                        y_min = y_min.min(0.0);
+                        y_min = fold_lower_bound(y_min, 0.0, log_axes.y);
                         y_max = y_max.max(1.0);
                     }
                     SeriesType::Radar { data } => {
                         for point in data.series.iter().flat_map(|series| &series.polygon) {
                             if point.0.is_finite() {
-                                x_min = x_min.min(point.0);
+                                x_min = fold_lower_bound(x_min, point.0, log_axes.x);
                                 x_max = x_max.max(point.0);
                             }
                             if point.1.is_finite() {
-                                y_min = y_min.min(point.1);
+                                y_min = fold_lower_bound(y_min, point.1, log_axes.y);
                                 y_max = y_max.max(point.1);
                             }
                         }
@@ -1850,9 +2249,11 @@ impl Plot {
             }
         }
 
-        if !x_min.is_finite() || !x_max.is_finite() || !y_min.is_finite() || !y_max.is_finite() {
+        self.check_log_axis_data(x_min, x_max, y_min, y_max)?;
+        let Some(bounds) = self.fill_unspanned_axis((x_min, x_max, y_min, y_max)) else {
             return Ok(self.empty_cartesian_bounds());
-        }
+        };
+        (x_min, x_max, y_min, y_max) = bounds;
 
         (x_min, x_max) = crate::axes::expand_degenerate_range(x_min, x_max, &self.layout.x_scale);
         (y_min, y_max) = crate::axes::expand_degenerate_range(y_min, y_max, &self.layout.y_scale);
@@ -1872,31 +2273,40 @@ impl Plot {
         let mut x_max = f64::NEG_INFINITY;
         let mut y_min = f64::INFINITY;
         let mut y_max = f64::NEG_INFINITY;
+        let log_axes = self.log_axes();
 
-        for series in series_list {
+        for series in series_list.iter().filter(|series| series.visible) {
             match &series.series_type {
                 SeriesType::Line { x_data, y_data } | SeriesType::Scatter { x_data, y_data } => {
                     let x_data = x_data.resolve_cow(0.0);
                     let y_data = y_data.resolve_cow(0.0);
                     for (&x_val, &y_val) in x_data.iter().zip(y_data.iter()) {
                         if x_val.is_finite() {
-                            x_min = x_min.min(x_val);
+                            x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                             x_max = x_max.max(x_val);
                         }
                         if y_val.is_finite() {
-                            y_min = y_min.min(y_val);
+                            y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                             y_max = y_max.max(y_val);
                         }
                     }
                 }
-                SeriesType::Bar { categories, values } => {
+                SeriesType::Bar {
+                    categories, values, ..
+                } => {
                     let values = values.resolve_cow(0.0);
-                    x_min = x_min.min(-0.5);
+                    x_min = fold_lower_bound(x_min, -0.5, log_axes.x);
                     x_max = x_max.max(categories.len() as f64 - 0.5);
-                    for &value in values.iter() {
+                    let errors = series.y_errors.as_ref().map(ErrorValuesRef::from);
+                    for (i, &value) in values.iter().enumerate() {
                         if value.is_finite() {
-                            y_min = y_min.min(value.min(0.0));
-                            y_max = y_max.max(value.max(0.0));
+                            let (low, high) = bar_y_extent(value, errors, i);
+                            y_min = fold_lower_bound(
+                                fold_lower_bound(y_min, low, log_axes.y),
+                                0.0,
+                                log_axes.y,
+                            );
+                            y_max = y_max.max(high.max(0.0));
                         }
                     }
                 }
@@ -1908,16 +2318,15 @@ impl Plot {
                     let x_data = x_data.resolve_cow(0.0);
                     let y_data = y_data.resolve_cow(0.0);
                     let y_errors = y_errors.resolve_cow(0.0);
-                    for ((&x_val, &y_val), &y_err) in
-                        x_data.iter().zip(y_data.iter()).zip(y_errors.iter())
-                    {
+                    let errors = effective_error_values(series.y_errors.as_ref(), &y_errors);
+                    for (i, (&x_val, &y_val)) in x_data.iter().zip(y_data.iter()).enumerate() {
                         if x_val.is_finite() {
-                            x_min = x_min.min(x_val);
+                            x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                             x_max = x_max.max(x_val);
                         }
-                        if y_val.is_finite() && y_err.is_finite() {
-                            y_min = y_min.min(y_val - y_err);
-                            y_max = y_max.max(y_val + y_err);
+                        if let Some((low, high)) = error_bar_extent(y_val, errors, i) {
+                            y_min = fold_lower_bound(y_min, low, log_axes.y);
+                            y_max = y_max.max(high);
                         }
                     }
                 }
@@ -1938,11 +2347,11 @@ impl Plot {
                         .zip(y_errors.iter())
                     {
                         if x_val.is_finite() && x_err.is_finite() {
-                            x_min = x_min.min(x_val - x_err);
+                            x_min = fold_lower_bound(x_min, x_val - x_err, log_axes.x);
                             x_max = x_max.max(x_val + x_err);
                         }
                         if y_val.is_finite() && y_err.is_finite() {
-                            y_min = y_min.min(y_val - y_err);
+                            y_min = fold_lower_bound(y_min, y_val - y_err, log_axes.y);
                             y_max = y_max.max(y_val + y_err);
                         }
                     }
@@ -1950,65 +2359,81 @@ impl Plot {
                 SeriesType::Histogram { .. } => {
                     if let Ok(hist_data) = series.series_type.histogram_data_at(0.0) {
                         if !hist_data.bin_edges.is_empty() {
-                            x_min = x_min.min(*hist_data.bin_edges.first().unwrap());
+                            x_min = fold_lower_bound_all(
+                                x_min,
+                                hist_data.bin_edges.iter().copied(),
+                                log_axes.x,
+                            );
                             x_max = x_max.max(*hist_data.bin_edges.last().unwrap());
                         }
-                        y_min = y_min.min(0.0);
+                        y_min = fold_lower_bound(y_min, 0.0, log_axes.y);
                         for &count in &hist_data.counts {
                             if count.is_finite() && count > 0.0 {
+                                y_min = fold_lower_bound(y_min, count, log_axes.y);
                                 y_max = y_max.max(count);
                             }
                         }
                     }
                 }
-                SeriesType::BoxPlot { data, .. } => {
+                SeriesType::BoxPlot { data, config } => {
                     let data = data.resolve_cow(0.0);
                     if data.is_empty() {
                         return Err(PlottingError::EmptyDataSet);
                     }
-                    x_min = x_min.min(0.0);
-                    x_max = x_max.max(1.0);
+                    x_min = fold_lower_bound(x_min, config.x_position - 0.5, log_axes.x);
+                    x_max = x_max.max(config.x_position + 0.5);
                     for &value in data.iter() {
                         if value.is_finite() {
-                            y_min = y_min.min(value);
+                            y_min = fold_lower_bound(y_min, value, log_axes.y);
                             y_max = y_max.max(value);
                         }
                     }
                 }
-                SeriesType::Heatmap { data } => {
-                    let ((series_x_min, series_x_max), (series_y_min, series_y_max)) =
-                        crate::plots::traits::PlotData::data_bounds(data.as_ref());
-                    x_min = x_min.min(series_x_min);
-                    x_max = x_max.max(series_x_max);
-                    y_min = y_min.min(series_y_min);
-                    y_max = y_max.max(series_y_max);
-                }
+                SeriesType::Heatmap { data } => include_plot_data_bounds(
+                    data.as_ref(),
+                    &mut x_min,
+                    &mut x_max,
+                    &mut y_min,
+                    &mut y_max,
+                    log_axes,
+                ),
                 SeriesType::Kde { data } => {
                     for (&x_val, &y_val) in data.x.iter().zip(data.y.iter()) {
                         if x_val.is_finite() {
-                            x_min = x_min.min(x_val);
+                            x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                             x_max = x_max.max(x_val);
                         }
                         if y_val.is_finite() {
-                            y_min = y_min.min(y_val);
+                            y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                             y_max = y_max.max(y_val);
                         }
                     }
-                    y_min = y_min.min(0.0);
+                    y_min = fold_lower_bound(y_min, 0.0, log_axes.y);
                 }
                 SeriesType::Ecdf { data } => {
                     for (&x_val, &y_val) in data.x.iter().zip(data.y.iter()) {
                         if x_val.is_finite() {
-                            x_min = x_min.min(x_val);
+                            x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                             x_max = x_max.max(x_val);
                         }
                         if y_val.is_finite() {
-                            y_min = y_min.min(y_val);
+                            y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                             y_max = y_max.max(y_val);
                         }
                     }
-                    y_min = y_min.min(0.0);
+                    y_min = fold_lower_bound(y_min, 0.0, log_axes.y);
                 }
+                SeriesType::Rug { data } => include_rug_bounds(
+                    data, &mut x_min, &mut x_max, &mut y_min, &mut y_max, log_axes,
+                ),
+                SeriesType::Strip { data } => include_plot_data_bounds(
+                    data.as_ref(),
+                    &mut x_min,
+                    &mut x_max,
+                    &mut y_min,
+                    &mut y_max,
+                    log_axes,
+                ),
                 SeriesType::Violin { data } => {
                     let (kde_min, kde_max) = if !data.kde.x.is_empty() {
                         (
@@ -2019,12 +2444,15 @@ impl Plot {
                         data.range
                     };
                     if kde_min.is_finite() {
-                        y_min = y_min.min(kde_min);
+                        y_min = fold_lower_bound(y_min, kde_min, log_axes.y);
                     }
                     if kde_max.is_finite() {
                         y_max = y_max.max(kde_max);
                     }
-                    x_min = x_min.min(0.0);
+                    if log_axes.y && kde_min <= 0.0 {
+                        y_min = fold_lower_bound_all(y_min, data.kde.x.iter().copied(), true);
+                    }
+                    x_min = fold_lower_bound(x_min, 0.0, log_axes.x);
                     x_max = x_max.max(1.0);
                 }
                 SeriesType::Boxen { data } => {
@@ -2034,42 +2462,63 @@ impl Plot {
                         &mut x_max,
                         &mut y_min,
                         &mut y_max,
+                        log_axes,
                     );
                 }
                 SeriesType::Quiver { data } => {
                     include_quiver_data_bounds(
-                        data, &mut x_min, &mut x_max, &mut y_min, &mut y_max,
+                        data, &mut x_min, &mut x_max, &mut y_min, &mut y_max, log_axes,
+                    );
+                }
+                SeriesType::Hexbin { data } => {
+                    include_plot_data_bounds(
+                        data.as_ref(),
+                        &mut x_min,
+                        &mut x_max,
+                        &mut y_min,
+                        &mut y_max,
+                        log_axes,
+                    );
+                }
+                SeriesType::ScatterMapped { data } => {
+                    include_plot_data_bounds(
+                        data.as_ref(),
+                        &mut x_min,
+                        &mut x_max,
+                        &mut y_min,
+                        &mut y_max,
+                        log_axes,
                     );
                 }
                 SeriesType::Contour { data } => {
                     for &x_val in &data.x {
                         if x_val.is_finite() {
-                            x_min = x_min.min(x_val);
+                            x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                             x_max = x_max.max(x_val);
                         }
                     }
                     for &y_val in &data.y {
                         if y_val.is_finite() {
-                            y_min = y_min.min(y_val);
+                            y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                             y_max = y_max.max(y_val);
                         }
                     }
                 }
                 SeriesType::Pie { .. } => {
-                    x_min = x_min.min(0.0);
+                    x_min = fold_lower_bound(x_min, 0.0, log_axes.x);
                     x_max = x_max.max(1.0);
-                    y_min = y_min.min(0.0);
+                    y_min = fold_lower_bound(y_min, 0.0, log_axes.y);
                     y_max = y_max.max(1.0);
                 }
                 SeriesType::Radar { data } => {
                     for series_data in &data.series {
                         for &(x_val, y_val) in &series_data.polygon {
                             if x_val.is_finite() {
-                                x_min = x_min.min(x_val);
+                                x_min = fold_lower_bound(x_min, x_val, log_axes.x);
                                 x_max = x_max.max(x_val);
                             }
                             if y_val.is_finite() {
-                                y_min = y_min.min(y_val);
+                                y_min = fold_lower_bound(y_min, y_val, log_axes.y);
                                 y_max = y_max.max(y_val);
                             }
                         }
@@ -2085,9 +2534,11 @@ impl Plot {
             }
         }
 
-        if !x_min.is_finite() || !x_max.is_finite() || !y_min.is_finite() || !y_max.is_finite() {
+        self.check_log_axis_data(x_min, x_max, y_min, y_max)?;
+        let Some(bounds) = self.fill_unspanned_axis((x_min, x_max, y_min, y_max)) else {
             return Ok(self.empty_cartesian_bounds());
-        }
+        };
+        (x_min, x_max, y_min, y_max) = bounds;
 
         (x_min, x_max) = crate::axes::expand_degenerate_range(x_min, x_max, &self.layout.x_scale);
         (y_min, y_max) = crate::axes::expand_degenerate_range(y_min, y_max, &self.layout.y_scale);
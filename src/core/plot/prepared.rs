@@ -313,9 +313,11 @@ impl PreparedPlot {
                         renderer.note_prepared_geometry_cache();
                     }
 
-                    for (series_index, (series, resolved)) in
-                        snapshot_series.iter().zip(resolved_series).enumerate()
-                    {
+                    for series_index in super::PlotSeries::draw_order(snapshot_series) {
+                        let (series, resolved) = (
+                            &snapshot_series[series_index],
+                            &resolved_series[series_index],
+                        );
                         if let Some(plan) =
                             prepared_geometry.get(series_index).and_then(Option::as_ref)
                         {
@@ -1,7 +1,7 @@
 use super::*;
 use crate::core::types::Point2f;
 use crate::plots::{PlotArea, heatmap::HeatmapData};
-use crate::render::{Color, LineStyle, MarkerStyle, skia::SkiaRenderer};
+use crate::render::{Color, LineStyle, MarkerPaint, MarkerStyle, skia::SkiaRenderer};
 use std::sync::Arc;
 
 pub(super) type ClipRect = (f32, f32, f32, f32);
@@ -49,6 +49,7 @@ pub(super) struct MarkerBatch {
     size: f32,
     style: MarkerStyle,
     color: Color,
+    paint: Option<MarkerPaint>,
     clip_rect: ClipRect,
 }
 
@@ -58,6 +59,7 @@ impl MarkerBatch {
         size: f32,
         style: MarkerStyle,
         color: Color,
+        paint: Option<MarkerPaint>,
         clip_rect: ClipRect,
     ) -> Self {
         Self {
@@ -65,11 +67,22 @@ impl MarkerBatch {
             size,
             style,
             color,
+            paint,
             clip_rect,
         }
     }
 
     fn execute(&self, renderer: &mut SkiaRenderer) -> Result<()> {
+        if let Some(paint) = self.paint {
+            return renderer.draw_markers_painted_clipped(
+                self.points.as_ref(),
+                self.size,
+                self.style,
+                paint,
+                self.clip_rect,
+            );
+        }
+
         renderer.draw_markers_clipped(
             self.points.as_ref(),
             self.size,
@@ -88,6 +101,17 @@ pub(super) struct RectGridBatch {
     n_rows: usize,
     n_cols: usize,
     cell_borders: bool,
+    cell_border: Option<CellBorderBatch>,
+}
+
+/// Cell outlines stroked after a [`RectGridBatch`] is filled
+#[derive(Debug, Clone)]
+struct CellBorderBatch {
+    color: Color,
+    /// Stroke width in points
+    width: f32,
+    rects: Arc<[(f32, f32, f32, f32)]>,
+    clip_rect: ClipRect,
 }
 
 impl RectGridBatch {
@@ -105,9 +129,7 @@ impl RectGridBatch {
             .values
             .iter()
             .flat_map(|row| row.iter())
-            .map(|&value| {
-                (!data.should_mask_value(value)).then(|| data.get_color(value).with_alpha(alpha))
-            })
+            .map(|&value| data.cell_color(value, alpha))
             .collect::<Vec<_>>();
 
         Some(Self {
@@ -117,6 +139,15 @@ impl RectGridBatch {
             n_rows: data.n_rows,
             n_cols: data.n_cols,
             cell_borders: data.config.cell_borders,
+            cell_border: data
+                .config
+                .cell_border
+                .map(|(color, width)| CellBorderBatch {
+                    color,
+                    width,
+                    rects: data.cell_border_rects(&area, true).into(),
+                    clip_rect: (area.x, area.y, area.width, area.height),
+                }),
         })
     }
 
@@ -155,6 +186,16 @@ impl RectGridBatch {
                 }
             }
         }
+
+        if let Some(border) = &self.cell_border {
+            let width = renderer.render_scale().points_to_pixels(border.width);
+            renderer.draw_rectangle_outlines_clipped(
+                &border.rects,
+                border.color,
+                width,
+                border.clip_rect,
+            )?;
+        }
         Ok(())
     }
 }
@@ -204,11 +245,12 @@ impl SeriesRasterPlan {
         size: f32,
         style: MarkerStyle,
         color: Color,
+        paint: Option<MarkerPaint>,
         clip_rect: ClipRect,
     ) {
         self.batches
             .push(StaticRasterBatch::Markers(MarkerBatch::new(
-                points, size, style, color, clip_rect,
+                points, size, style, color, paint, clip_rect,
             )));
     }
 
@@ -130,10 +130,169 @@ pub(super) fn reduce_line_points_for_raster(
     }
 }
 
-fn is_finite_point(point: &Point2f) -> bool {
+/// Keep the lowest and highest point of every pixel column
+///
+/// Each finite run with more points than the plot has columns is reduced to
+/// at most two points per column, in data order, so spikes survive. Runs
+/// with fewer points or non-monotonic x are kept as-is. Returns `None` when
+/// nothing was dropped.
+pub(super) fn downsample_line_points(
+    points: &[Point2f],
+    mode: DownsampleMode,
+    plot_left: f32,
+    plot_width: f32,
+) -> Option<Vec<Point2f>> {
+    let column_count = plot_width.max(1.0).ceil() as usize;
+    let max_column = column_count.saturating_sub(1) as i32;
+    let mut downsampled = Vec::with_capacity(points.len().min(column_count * 2));
+    for (index, run) in finite_point_runs(points).enumerate() {
+        if index > 0 {
+            downsampled.push(Point2f::new(f32::NAN, f32::NAN));
+        }
+        if run.len() <= column_count || !is_monotonic_x(run) {
+            downsampled.extend_from_slice(run);
+            continue;
+        }
+
+        match mode {
+            DownsampleMode::MinMax => {
+                push_column_min_max(run, plot_left, max_column, &mut downsampled)
+            }
+        }
+    }
+
+    (downsampled.len() < points.len()).then_some(downsampled)
+}
+
+fn push_column_min_max(
+    run: &[Point2f],
+    plot_left: f32,
+    max_column: i32,
+    output: &mut Vec<Point2f>,
+) {
+    let column_of = |point: &Point2f| ((point.x - plot_left).floor() as i32).clamp(0, max_column);
+    let mut start = 0;
+    while start < run.len() {
+        let column = column_of(&run[start]);
+        let (mut min_index, mut max_index) = (start, start);
+        let mut end = start + 1;
+        while end < run.len() && column_of(&run[end]) == column {
+            if run[end].y < run[min_index].y {
+                min_index = end;
+            }
+            if run[end].y > run[max_index].y {
+                max_index = end;
+            }
+            end += 1;
+        }
+
+        output.push(run[min_index.min(max_index)]);
+        if min_index != max_index {
+            output.push(run[min_index.max(max_index)]);
+        }
+        start = end;
+    }
+}
+
+/// Simplify projected line points with Douglas–Peucker
+///
+/// Each finite run is simplified on its own, keeping a non-finite separator
+/// between runs so gaps survive. Returns `None` when nothing was dropped.
+pub(super) fn simplify_line_points(points: &[Point2f], tolerance: f32) -> Option<Vec<Point2f>> {
+    let mut simplified = Vec::with_capacity(points.len());
+    for (index, run) in finite_point_runs(points).enumerate() {
+        if index > 0 {
+            simplified.push(Point2f::new(f32::NAN, f32::NAN));
+        }
+        let kept = douglas_peucker_indices(run.len(), |i| (run[i].x, run[i].y), tolerance);
+        simplified.extend(kept.into_iter().map(|i| run[i]));
+    }
+
+    (simplified.len() < points.len()).then_some(simplified)
+}
+
+/// Simplify one finite run of pixel points with Douglas–Peucker
+pub(super) fn simplify_pixel_run(run: &[(f32, f32)], tolerance: f32) -> Vec<(f32, f32)> {
+    douglas_peucker_indices(run.len(), |i| run[i], tolerance)
+        .into_iter()
+        .map(|i| run[i])
+        .collect()
+}
+
+/// Indices of the points Douglas–Peucker keeps for a polyline of `len`
+/// points, so the result stays within `tolerance` of the original
+///
+/// Distances are measured to the segment rather than its infinite line, so
+/// spikes that double back past an endpoint are kept.
+fn douglas_peucker_indices(
+    len: usize,
+    point_at: impl Fn(usize) -> (f32, f32),
+    tolerance: f32,
+) -> Vec<usize> {
+    if len < 3 || tolerance.is_nan() || tolerance <= 0.0 {
+        return (0..len).collect();
+    }
+
+    let tolerance_sq = f64::from(tolerance) * f64::from(tolerance);
+    let mut keep = vec![false; len];
+    keep[0] = true;
+    keep[len - 1] = true;
+    // An explicit stack keeps million-point curves off the call stack
+    let mut spans = vec![(0, len - 1)];
+    while let Some((start, end)) = spans.pop() {
+        if end <= start + 1 {
+            continue;
+        }
+
+        let (ax, ay) = point_at(start);
+        let (bx, by) = point_at(end);
+        let (ax, ay, dx, dy) = (
+            f64::from(ax),
+            f64::from(ay),
+            f64::from(bx - ax),
+            f64::from(by - ay),
+        );
+        let length_sq = dx * dx + dy * dy;
+        let mut farthest = start;
+        let mut farthest_sq = 0.0;
+        for index in start + 1..end {
+            let (px, py) = point_at(index);
+            let (px, py) = (f64::from(px) - ax, f64::from(py) - ay);
+            let t = if length_sq > 0.0 {
+                ((px * dx + py * dy) / length_sq).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let (ex, ey) = (px - t * dx, py - t * dy);
+            let distance_sq = ex * ex + ey * ey;
+            if distance_sq > farthest_sq {
+                farthest_sq = distance_sq;
+                farthest = index;
+            }
+        }
+
+        if farthest_sq > tolerance_sq {
+            keep[farthest] = true;
+            spans.push((start, farthest));
+            spans.push((farthest, end));
+        }
+    }
+
+    (0..len).filter(|&index| keep[index]).collect()
+}
+
+pub(super) fn is_finite_point(point: &Point2f) -> bool {
     point.x.is_finite() && point.y.is_finite()
 }
 
+/// Split projected line points into the contiguous runs between non-finite
+/// samples, so NaN/inf data breaks the line instead of being bridged.
+pub(super) fn finite_point_runs(points: &[Point2f]) -> impl Iterator<Item = &[Point2f]> {
+    points
+        .split(|point| !is_finite_point(point))
+        .filter(|run| !run.is_empty())
+}
+
 fn is_exactly_redundant_line_point(previous: Point2f, current: Point2f, next: Point2f) -> bool {
     let ab_x = current.x - previous.x;
     let ab_y = current.y - previous.y;
@@ -331,6 +490,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_downsample_line_points_keeps_column_extrema_in_order() {
+        let mut points: Vec<Point2f> = (0..40)
+            .map(|i| Point2f::new(i as f32 * 0.25, (i % 4) as f32))
+            .collect();
+        points[13].y = -50.0;
+        points[26].y = 80.0;
+
+        let downsampled =
+            downsample_line_points(&points, DownsampleMode::MinMax, 0.0, 10.0).expect("reduced");
+
+        assert_eq!(downsampled.len(), 20);
+        assert!(downsampled.contains(&points[13]));
+        assert!(downsampled.contains(&points[26]));
+        assert!(downsampled.windows(2).all(|pair| pair[0].x <= pair[1].x));
+        assert!(downsample_line_points(&points, DownsampleMode::MinMax, 0.0, 40.0).is_none());
+
+        let mut gapped = points.clone();
+        gapped[20] = Point2f::new(f32::NAN, f32::NAN);
+        let downsampled =
+            downsample_line_points(&gapped, DownsampleMode::MinMax, 0.0, 5.0).expect("reduced");
+        assert_eq!(
+            downsampled.iter().filter(|p| !is_finite_point(p)).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_simplify_line_points_keeps_spikes_and_gaps() {
+        let points = vec![
+            Point2f::new(0.0, 0.0),
+            Point2f::new(1.0, 0.1),
+            Point2f::new(2.0, -0.1),
+            Point2f::new(3.0, 20.0),
+            Point2f::new(4.0, 0.1),
+            Point2f::new(5.0, 0.0),
+            Point2f::new(f32::NAN, f32::NAN),
+            Point2f::new(6.0, 0.0),
+            Point2f::new(7.0, 0.2),
+            Point2f::new(8.0, 0.0),
+        ];
+
+        let simplified = simplify_line_points(&points, 0.5).expect("expected simplification");
+
+        let kept: Vec<(f32, f32)> = simplified
+            .iter()
+            .filter(|point| is_finite_point(point))
+            .map(|point| (point.x, point.y))
+            .collect();
+        assert_eq!(
+            kept,
+            vec![
+                (0.0, 0.0),
+                (2.0, -0.1),
+                (3.0, 20.0),
+                (4.0, 0.1),
+                (5.0, 0.0),
+                (6.0, 0.0),
+                (8.0, 0.0)
+            ]
+        );
+        assert_eq!(simplified.iter().filter(|p| !is_finite_point(p)).count(), 1);
+        assert!(simplify_line_points(&points[..3], 0.0).is_none());
+    }
+
     #[test]
     fn test_exact_line_canonicalization_preserves_turns() {
         let points = vec![
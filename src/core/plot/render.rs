@@ -9,6 +9,96 @@ struct ColorbarMeasurementSpec {
     tick_font_size: f32,
     label_font_size: f32,
     show_log_subticks: bool,
+    colorbar: crate::plots::ColorbarConfig,
+}
+
+impl ColorbarMeasurementSpec {
+    fn for_heatmap(data: &crate::plots::HeatmapData) -> Self {
+        Self {
+            vmin: data.vmin,
+            vmax: data.vmax,
+            value_scale: data.config.value_scale.clone(),
+            label: data.config.colorbar_label.clone(),
+            tick_font_size: data.config.colorbar_tick_font_size,
+            label_font_size: data.config.colorbar_label_font_size,
+            show_log_subticks: data.config.colorbar_log_subticks,
+            colorbar: data.config.colorbar_config.clone(),
+        }
+    }
+}
+
+/// Colorbar styling taken from a heatmap, for figure-level colorbars
+#[derive(Debug, Clone)]
+pub(crate) struct HeatmapColorbarStyle {
+    pub(crate) colormap: crate::render::ColorMap,
+    pub(crate) value_scale: AxisScale,
+    pub(crate) label: Option<String>,
+    pub(crate) tick_font_size: f32,
+    pub(crate) label_font_size: f32,
+    pub(crate) show_log_subticks: bool,
+    pub(crate) center: Option<f64>,
+    /// Tick options; figure-level colorbars are always vertical
+    pub(crate) colorbar: crate::plots::ColorbarConfig,
+}
+
+impl HeatmapColorbarStyle {
+    /// Horizontal space needed by a colorbar spanning `vmin..vmax`, including
+    /// the gap to its left
+    pub(crate) fn measure_extent(
+        &self,
+        renderer: &SkiaRenderer,
+        vmin: f64,
+        vmax: f64,
+    ) -> Result<f32> {
+        Plot::measure_colorbar_extent(
+            renderer,
+            &ColorbarMeasurementSpec {
+                vmin,
+                vmax,
+                value_scale: self.value_scale.clone(),
+                label: self.label.clone(),
+                tick_font_size: self.tick_font_size,
+                label_font_size: self.label_font_size,
+                show_log_subticks: self.show_log_subticks,
+                colorbar: self.colorbar.clone(),
+            },
+        )
+    }
+
+    /// Draw the colorbar into the space measured by
+    /// [`HeatmapColorbarStyle::measure_extent`], starting at `left`
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw(
+        &self,
+        renderer: &mut SkiaRenderer,
+        vmin: f64,
+        vmax: f64,
+        left: f32,
+        y: f32,
+        height: f32,
+        foreground: Color,
+    ) -> Result<()> {
+        let render_scale = renderer.render_scale();
+        let width = render_scale.logical_pixels_to_pixels(COLORBAR_WIDTH_PX);
+        let x = left + render_scale.logical_pixels_to_pixels(COLORBAR_MARGIN_PX);
+        renderer.draw_colorbar_with_center(
+            &self.colormap,
+            vmin,
+            vmax,
+            x,
+            y,
+            width,
+            height,
+            &self.value_scale,
+            self.label.as_deref(),
+            foreground,
+            self.tick_font_size,
+            Some(self.label_font_size),
+            self.show_log_subticks,
+            self.center,
+            &self.colorbar,
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -18,13 +108,125 @@ enum AnnotationRenderLayer {
 }
 
 impl Plot {
+    /// Pixel position where the left and bottom spines cross for
+    /// [`SpinePosition::Zero`], or `None` to keep them on the plot-area border
+    pub(super) fn spine_origin_px(
+        &self,
+        plot_area: tiny_skia::Rect,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+    ) -> Option<(f32, f32)> {
+        if self.display.config.spines.position != SpinePosition::Zero {
+            return None;
+        }
+        // Zero outside the view (or on a log axis) pins the spine to the border
+        let x_zero = 0.0_f64.clamp(x_min.min(x_max), x_min.max(x_max));
+        let y_zero = 0.0_f64.clamp(y_min.min(y_max), y_min.max(y_max));
+        Some((
+            Self::scaled_x_pixel(x_zero, x_min, x_max, plot_area, &self.layout.x_scale),
+            Self::scaled_y_pixel(y_zero, y_min, y_max, plot_area, &self.layout.y_scale),
+        ))
+    }
+
+    /// Move tick labels along with spines drawn through `spine_origin`
+    pub(super) fn follow_spine_origin(
+        layout: &mut PlotLayout,
+        plot_area: tiny_skia::Rect,
+        spine_origin: Option<(f32, f32)>,
+    ) {
+        if let Some((x, y)) = spine_origin {
+            layout.xtick_baseline_y += y - plot_area.bottom();
+            layout.ytick_right_x += x - plot_area.left();
+        }
+    }
+
+    /// Shrink the plot area to the shape the [`AspectMode`] asks for for these
+    /// limits, centered in the area it had, and move the axis text with it
+    ///
+    /// With [`AspectMode::Auto`] the first heatmap with a cell aspect, such as
+    /// [`HeatmapConfig::square_cells`](crate::plots::heatmap::HeatmapConfig::square_cells),
+    /// sets the shape instead.
+    pub(super) fn fit_layout_to_aspect(
+        &self,
+        layout: &mut ResolvedLayout,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+    ) {
+        let y_units_per_x_unit =
+            match self.layout.aspect {
+                AspectMode::Auto => {
+                    let heatmap_aspect = self.series_mgr.series.iter().find_map(|series| {
+                        match &series.series_type {
+                            SeriesType::Heatmap { data } => data.y_units_per_x_unit(),
+                            _ => None,
+                        }
+                    });
+                    match heatmap_aspect {
+                        Some(ratio) => ratio,
+                        None => return,
+                    }
+                }
+                AspectMode::Equal => 1.0,
+                AspectMode::Ratio(ratio) => ratio,
+            };
+        let nonlinear =
+            |scale: &AxisScale| matches!(scale, AxisScale::Log | AxisScale::SymLog { .. });
+        let (x_span, y_span) = ((x_max - x_min).abs(), (y_max - y_min).abs());
+        if nonlinear(&self.layout.x_scale)
+            || nonlinear(&self.layout.y_scale)
+            || !(y_units_per_x_unit.is_finite() && y_units_per_x_unit > 0.0)
+            || !(x_span.is_finite() && x_span > 0.0 && y_span.is_finite() && y_span > 0.0)
+        {
+            return;
+        }
+
+        let layout = &mut layout.layout;
+        let width = f64::from(layout.plot_area.width());
+        let height = f64::from(layout.plot_area.height());
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+        let height_per_width = y_span * y_units_per_x_unit / x_span;
+        let (fitted_width, fitted_height) = if height > width * height_per_width {
+            (width, width * height_per_width)
+        } else {
+            (height / height_per_width, height)
+        };
+        let inset_x = ((width - fitted_width) / 2.0) as f32;
+        let inset_y = ((height - fitted_height) / 2.0) as f32;
+
+        layout.plot_area.left += inset_x;
+        layout.plot_area.right -= inset_x;
+        layout.plot_area.top += inset_y;
+        layout.plot_area.bottom -= inset_y;
+        layout.margins.left += inset_x;
+        layout.margins.right += inset_x;
+        layout.margins.top += inset_y;
+        layout.margins.bottom += inset_y;
+        layout.xtick_baseline_y -= inset_y;
+        layout.ytick_right_x += inset_x;
+        if let Some(pos) = layout.title_pos.as_mut() {
+            pos.y += inset_y;
+        }
+        if let Some(pos) = layout.xlabel_pos.as_mut() {
+            pos.y -= inset_y;
+        }
+        if let Some(pos) = layout.ylabel_pos.as_mut() {
+            pos.x += inset_x;
+        }
+    }
+
     pub(crate) fn axis_tick_metrics_px(&self) -> (f32, f32, f32, f32, f32) {
         let lines = &self.display.config.lines;
         let axis_width = self.line_width_px(lines.axis_width);
         let major_tick_size = self.line_width_px(lines.tick_length);
-        let minor_tick_size = self.line_width_px((lines.tick_length * 0.6).max(0.1));
+        let minor_tick_size = self.line_width_px(lines.effective_minor_tick_length());
         let major_tick_width = self.line_width_px(lines.tick_width);
-        let minor_tick_width = self.line_width_px((lines.tick_width * 0.75).max(0.1));
+        let minor_tick_width = self.line_width_px(lines.effective_minor_tick_width());
         (
             axis_width,
             major_tick_size,
@@ -42,6 +244,7 @@ impl Plot {
             | Annotation::Rectangle { .. } => AnnotationRenderLayer::Underlay,
             Annotation::Text { .. }
             | Annotation::Arrow { .. }
+            | Annotation::Callout { .. }
             | Annotation::HLine { .. }
             | Annotation::VLine { .. } => AnnotationRenderLayer::Overlay,
         }
@@ -76,9 +279,23 @@ impl Plot {
         &self,
         mode: RenderExecutionMode,
         time: f64,
+    ) -> Result<(Image, RenderDiagnostics)> {
+        self.render_style_shell_frame(mode, time, None)
+    }
+
+    /// Render through the resolved style shell, which shares no static series
+    /// data with `self`, optionally swapping in a one-off progress callback.
+    fn render_style_shell_frame(
+        &self,
+        mode: RenderExecutionMode,
+        time: f64,
+        progress: Option<ProgressCallback>,
     ) -> Result<(Image, RenderDiagnostics)> {
         let frame = self.resolve_frame(time)?;
-        let style_shell = self.resolved_style_shell(&frame.style);
+        let mut style_shell = self.resolved_style_shell(&frame.style);
+        if progress.is_some() {
+            style_shell.render.progress_callback = progress;
+        }
         let result = style_shell.render_image_with_resolved_frame(mode, &frame);
         if result.is_ok() {
             frame.acknowledge_rendered(self);
@@ -153,7 +370,7 @@ impl Plot {
         let (range_min, range_max) = if min <= max { (min, max) } else { (max, min) };
         let mut ticks = match scale {
             AxisScale::Log => Self::log_minor_tick_values_for_range(range_min, range_max),
-            AxisScale::Linear | AxisScale::SymLog { .. } => {
+            AxisScale::Linear | AxisScale::SymLog { .. } | AxisScale::Time { .. } => {
                 crate::axes::generate_minor_ticks(major_ticks, requested_count)
             }
         };
@@ -171,6 +388,66 @@ impl Plot {
         ticks
     }
 
+    /// Log-axis minor ticks whose mantissa is in `mantissas`
+    ///
+    /// Empty for non-log scales and when no major ticks are labeled.
+    pub(crate) fn labeled_log_minor_ticks(
+        major_ticks: &[f64],
+        min: f64,
+        max: f64,
+        scale: &AxisScale,
+        mantissas: &[u8],
+    ) -> Vec<f64> {
+        if mantissas.is_empty() || major_ticks.is_empty() || !matches!(scale, AxisScale::Log) {
+            return Vec::new();
+        }
+
+        let mut ticks = Self::minor_tick_values_for_scale(major_ticks, min, max, scale, 0);
+        ticks.retain(|tick| {
+            let decade = 10.0_f64.powf(tick.log10().floor());
+            let mantissa = (tick / decade).round();
+            mantissas.iter().any(|&m| f64::from(m) == mantissa)
+        });
+        ticks
+    }
+
+    /// Major tick values plus the log minor ticks selected for labels with
+    /// [`Plot::log_minor_labels`], as `(x, y)`
+    pub(crate) fn tick_label_values(
+        &self,
+        x_ticks: &[f64],
+        y_ticks: &[f64],
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let config = &self.layout.tick_config;
+        let with_minor = |major: &[f64], min, max, scale, mantissas: &[u8]| {
+            let mut values = major.to_vec();
+            values.extend(Self::labeled_log_minor_ticks(
+                major, min, max, scale, mantissas,
+            ));
+            values
+        };
+        (
+            with_minor(
+                x_ticks,
+                x_min,
+                x_max,
+                &self.layout.x_scale,
+                &config.log_minor_labels_x,
+            ),
+            with_minor(
+                y_ticks,
+                y_min,
+                y_max,
+                &self.layout.y_scale,
+                &config.log_minor_labels_y,
+            ),
+        )
+    }
+
     fn log_minor_tick_values_for_range(min: f64, max: f64) -> Vec<f64> {
         if min <= 0.0 || max <= 0.0 || min >= max {
             return Vec::new();
@@ -196,25 +473,29 @@ impl Plot {
     fn tick_values_overlap(left: f64, right: f64, scale: &AxisScale) -> bool {
         match scale {
             AxisScale::Log => left == right,
-            AxisScale::Linear | AxisScale::SymLog { .. } => {
+            AxisScale::Linear | AxisScale::SymLog { .. } | AxisScale::Time { .. } => {
                 (left - right).abs() <= left.abs().max(right.abs()).max(1.0) * 1e-10
             }
         }
     }
 
-    pub(crate) fn grid_tick_pixels(
-        major_pixels: &[f32],
-        minor_pixels: &[f32],
+    /// Major and minor grid line positions selected by the grid mode.
+    ///
+    /// The two sets are drawn separately so minor lines can use the minor
+    /// grid style. Both are empty when the grid axis excludes this axis.
+    pub(crate) fn grid_tick_pixels<'a>(
+        major_pixels: &'a [f32],
+        minor_pixels: &'a [f32],
         mode: &GridMode,
-    ) -> Vec<f32> {
+        axis_visible: bool,
+    ) -> (&'a [f32], &'a [f32]) {
+        if !axis_visible {
+            return (&[], &[]);
+        }
         match mode {
-            GridMode::MajorOnly => major_pixels.to_vec(),
-            GridMode::MinorOnly => minor_pixels.to_vec(),
-            GridMode::Both => major_pixels
-                .iter()
-                .chain(minor_pixels.iter())
-                .copied()
-                .collect(),
+            GridMode::MajorOnly => (major_pixels, &[]),
+            GridMode::MinorOnly => (&[], minor_pixels),
+            GridMode::Both => (major_pixels, minor_pixels),
         }
     }
 
@@ -265,6 +546,12 @@ impl Plot {
             self.display.config.typography.family.clone(),
         )?;
         renderer.set_text_engine_mode(self.display.text_engine);
+        renderer.set_antialias(self.render.antialias);
+        renderer.set_tick_label_formatters(
+            self.layout.x_label_formatter(),
+            self.layout.y_label_formatter(),
+        );
+        renderer.set_xtick_label_rotation(self.layout.tick_config.x_label_rotation);
         renderer.set_render_mode_diagnostics(match mode {
             RenderExecutionMode::Reference => "reference",
             RenderExecutionMode::Optimized => "optimized",
@@ -292,34 +579,16 @@ impl Plot {
                 }
             });
 
-        let violin_data: Vec<(String, f64)> = self
-            .series_mgr
-            .series
-            .iter()
-            .filter_map(|s| {
-                if let SeriesType::Violin { data } = &s.series_type {
-                    data.config
-                        .category
-                        .clone()
-                        .map(|cat| (cat, data.config.x_position))
-                } else {
-                    None
-                }
-            })
-            .collect();
+        let (positioned_categories, positioned_x) = self.positioned_x_categories();
+        let is_positioned_categorical = !positioned_categories.is_empty();
 
-        let (violin_categories, violin_positions): (Vec<String>, Vec<f64>) =
-            violin_data.into_iter().unzip();
-
-        let is_violin_categorical = !violin_categories.is_empty();
-
-        let bar_categories = bar_categories.or(if is_violin_categorical {
-            Some(Cow::Borrowed(violin_categories.as_slice()))
+        let bar_categories = bar_categories.or(if is_positioned_categorical {
+            Some(Cow::Borrowed(positioned_categories.as_slice()))
         } else {
             None
         });
         let content = self.create_plot_content_from_resolved_text(y_min, y_max, frame);
-        let (layout, x_ticks, y_ticks) = self.compute_layout_with_configured_ticks(
+        let (mut layout, x_ticks, y_ticks) = self.compute_layout_with_configured_ticks(
             &renderer,
             (scaled_width, scaled_height),
             &content,
@@ -330,6 +599,8 @@ impl Plot {
             y_max,
         )?;
         let plot_area = Self::plot_area_from_layout(&layout)?;
+        let spine_origin = self.spine_origin_px(plot_area, x_min, x_max, y_min, y_max);
+        Self::follow_spine_origin(&mut layout, plot_area, spine_origin);
 
         let x_tick_pixels: Vec<f32> = x_ticks
             .iter()
@@ -364,25 +635,35 @@ impl Plot {
 
         let draw_axes = Self::needs_cartesian_axes_for_series(&self.series_mgr.series);
         if self.layout.grid_style.visible && draw_axes {
-            let grid_color = self.layout.grid_style.effective_color();
-            let grid_width_px = self.line_width_px(self.layout.grid_style.line_width);
-            let grid_x_pixels = Self::grid_tick_pixels(
+            let grid_style = &self.layout.grid_style;
+            let (grid_x_pixels, minor_grid_x_pixels) = Self::grid_tick_pixels(
                 &x_tick_pixels,
                 &x_minor_tick_pixels,
                 &self.layout.tick_config.grid_mode,
+                self.layout.tick_config.grid_axis.shows_x(),
             );
-            let grid_y_pixels = Self::grid_tick_pixels(
+            let (grid_y_pixels, minor_grid_y_pixels) = Self::grid_tick_pixels(
                 &y_tick_pixels,
                 &y_minor_tick_pixels,
                 &self.layout.tick_config.grid_mode,
+                self.layout.tick_config.grid_axis.shows_y(),
             );
+            // Minor lines first so major lines stay on top
+            renderer.draw_grid(
+                minor_grid_x_pixels,
+                minor_grid_y_pixels,
+                plot_area,
+                grid_style.effective_minor_color(),
+                grid_style.effective_minor_line_style(),
+                self.line_width_px(grid_style.minor_line_width),
+            )?;
             renderer.draw_grid(
-                &grid_x_pixels,
-                &grid_y_pixels,
+                grid_x_pixels,
+                grid_y_pixels,
                 plot_area,
-                grid_color,
-                self.layout.grid_style.line_style.clone(),
-                grid_width_px,
+                grid_style.effective_color(),
+                grid_style.line_style.clone(),
+                self.line_width_px(grid_style.line_width),
             )?;
         }
 
@@ -391,7 +672,7 @@ impl Plot {
             x_min,
             x_max,
             bar_categories.as_ref().map(|categories| categories.len()),
-            &violin_positions,
+            &positioned_x,
         );
 
         let draw_ticks = draw_axes && self.layout.tick_config.enabled;
@@ -406,7 +687,7 @@ impl Plot {
             };
             let (axis_width, major_tick_size, minor_tick_size, major_tick_width, minor_tick_width) =
                 self.axis_tick_metrics_px();
-            renderer.draw_axes_with_minor_ticks_styled(
+            renderer.draw_axes_with_spine_origin(
                 plot_area,
                 x_axis_ticks,
                 &y_tick_pixels,
@@ -421,11 +702,12 @@ impl Plot {
                 minor_tick_size,
                 major_tick_width,
                 minor_tick_width,
+                spine_origin,
             )?;
         } else if draw_axes {
             let (axis_width, major_tick_size, minor_tick_size, major_tick_width, minor_tick_width) =
                 self.axis_tick_metrics_px();
-            renderer.draw_axes_with_minor_ticks_styled(
+            renderer.draw_axes_with_spine_origin(
                 plot_area,
                 &[],
                 &[],
@@ -440,16 +722,17 @@ impl Plot {
                 minor_tick_size,
                 major_tick_width,
                 minor_tick_width,
+                spine_origin,
             )?;
         }
 
         let tick_size_px = pt_to_px(self.display.config.typography.tick_size(), dpi);
 
-        if draw_axes && is_violin_categorical {
+        if draw_axes && is_positioned_categorical {
             renderer.draw_axis_labels_at_categorical_violin(
                 &layout.plot_area,
-                &violin_categories,
-                &violin_positions,
+                &positioned_categories,
+                &positioned_x,
                 x_min,
                 x_max,
                 y_min,
@@ -482,14 +765,16 @@ impl Plot {
                     false,
                 )?;
             } else {
+                let (x_label_ticks, y_label_ticks) =
+                    self.tick_label_values(&x_ticks, &y_ticks, x_min, x_max, y_min, y_max);
                 renderer.draw_axis_labels_at_scaled(
                     &layout.plot_area,
                     x_min,
                     x_max,
                     y_min,
                     y_max,
-                    &x_ticks,
-                    &y_ticks,
+                    &x_label_ticks,
+                    &y_label_ticks,
                     layout.xtick_baseline_y,
                     layout.ytick_right_x,
                     tick_size_px,
@@ -565,6 +850,7 @@ impl Plot {
             &self.layout.y_scale,
             Self::is_overlay_annotation,
         )?;
+        self.draw_insets(&mut renderer, plot_area, x_min, x_max, y_min, y_max)?;
 
         let legend_items = self.collect_legend_items();
         if !legend_items.is_empty() && frame.style.legend.enabled {
@@ -613,12 +899,16 @@ impl Plot {
                             && series.x_errors.is_none()
                             && series.y_errors.is_none()
                     }
+                    SeriesType::Bar { value_labels, .. } => {
+                        series.hatch.is_none()
+                            && value_labels.is_none()
+                            && series.y_errors.is_none()
+                    }
+                    SeriesType::BoxPlot { config, .. } => config.category.is_none(),
                     SeriesType::Scatter { .. }
-                    | SeriesType::Bar { .. }
                     | SeriesType::ErrorBars { .. }
                     | SeriesType::ErrorBarsXY { .. }
-                    | SeriesType::Histogram { .. }
-                    | SeriesType::BoxPlot { .. } => true,
+                    | SeriesType::Histogram { .. } => true,
                     SeriesType::Heatmap { .. }
                     | SeriesType::Kde { .. }
                     | SeriesType::Ecdf { .. }
@@ -628,7 +918,11 @@ impl Plot {
                     | SeriesType::Pie { .. }
                     | SeriesType::Radar { .. }
                     | SeriesType::Polar { .. }
-                    | SeriesType::Quiver { .. } => false,
+                    | SeriesType::Quiver { .. }
+                    | SeriesType::Hexbin { .. }
+                    | SeriesType::Rug { .. }
+                    | SeriesType::Strip { .. }
+                    | SeriesType::ScatterMapped { .. } => false,
                 });
 
         if has_mixed_coordinates
@@ -969,6 +1263,8 @@ impl Plot {
     /// `Signal` sources are sampled at `0.0`; push-based observables and
     /// streaming sources use their latest values. Use `render_at()` to sample
     /// temporal sources at a different time.
+    ///
+    /// The returned pixels are premultiplied RGBA.
     pub fn render(&self) -> Result<Image> {
         self.render_at(0.0)
     }
@@ -1034,6 +1330,42 @@ impl Plot {
         self.render_image_with_mode_at(mode, time)
     }
 
+    /// Render the plot while reporting progress
+    ///
+    /// `progress` receives the completed fraction (`0.0..=1.0`) after each
+    /// series is drawn; the last call reports `1.0`. Combine with
+    /// [`cancel_flag`](Self::cancel_flag) to abort stale renders. Use
+    /// [`on_progress`](Self::on_progress) to report progress from PNG output,
+    /// where an explicit DataShader backend also reports per aggregation chunk.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::AtomicBool;
+    ///
+    /// let x: Vec<f64> = (0..1_000_000).map(|i| i as f64).collect();
+    /// let y: Vec<f64> = x.iter().map(|v| (v * 0.001).sin()).collect();
+    /// let cancel = Arc::new(AtomicBool::new(false));
+    ///
+    /// let plot = Plot::new()
+    ///     .line(&x, &y)
+    ///     .cancel_flag(Arc::clone(&cancel));
+    /// let image = plot.render_with_progress(|fraction| println!("{:.0}%", fraction * 100.0))?;
+    /// # let _ = image;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn render_with_progress<F>(&self, progress: F) -> Result<Image>
+    where
+        F: Fn(f32) + Send + Sync + 'static,
+    {
+        self.validate_before_frame_resolution()?;
+        let mode = self.render_execution_mode(BackendOperation::RasterImage);
+        self.render_style_shell_frame(mode, 0.0, Some(ProgressCallback(Arc::new(progress))))
+            .map(|(image, _)| image)
+    }
+
     /// Render the plot and encode it as PNG bytes.
     pub fn render_png_bytes(&self) -> Result<Vec<u8>> {
         #[cfg(not(target_arch = "wasm32"))]
@@ -1048,6 +1380,58 @@ impl Plot {
         }
     }
 
+    /// Render the plot into an HTML canvas (requires the `wasm` feature)
+    ///
+    /// The canvas is resized to the rendered image and the pixels are copied
+    /// in with `CanvasRenderingContext2d::put_image_data`, replacing whatever
+    /// the canvas held before.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use ruviz::prelude::*;
+    /// use wasm_bindgen::JsCast;
+    ///
+    /// let canvas: web_sys::HtmlCanvasElement = web_sys::window()
+    ///     .and_then(|window| window.document())
+    ///     .and_then(|document| document.get_element_by_id("plot"))
+    ///     .and_then(|element| element.dyn_into().ok())
+    ///     .expect("page should have a <canvas id=\"plot\">");
+    ///
+    /// Plot::new()
+    ///     .line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0])
+    ///     .render_to_canvas(&canvas)?;
+    /// # Ok::<(), ruviz::core::PlottingError>(())
+    /// ```
+    #[cfg(feature = "wasm")]
+    pub fn render_to_canvas(&self, canvas: &web_sys::HtmlCanvasElement) -> Result<()> {
+        use wasm_bindgen::{Clamped, JsCast};
+
+        let canvas_error = |error: wasm_bindgen::JsValue| {
+            PlottingError::RenderError(format!("Canvas rendering failed: {error:?}"))
+        };
+        let image = self.render()?;
+        canvas.set_width(image.width);
+        canvas.set_height(image.height);
+        let context = canvas
+            .get_context("2d")
+            .map_err(canvas_error)?
+            .ok_or_else(|| {
+                PlottingError::RenderError("Canvas has no 2D rendering context".to_string())
+            })?
+            .dyn_into::<web_sys::CanvasRenderingContext2d>()
+            .map_err(|object| canvas_error(object.into()))?;
+        let image_data = web_sys::ImageData::new_with_u8_clamped_array_and_sh(
+            Clamped(image.pixels.as_slice()),
+            image.width,
+            image.height,
+        )
+        .map_err(canvas_error)?;
+        context
+            .put_image_data(&image_data, 0.0, 0.0)
+            .map_err(canvas_error)
+    }
+
     /// Render the plot to PNG bytes while reporting internal raster diagnostics.
     #[cfg(not(target_arch = "wasm32"))]
     #[doc(hidden)]
@@ -1109,70 +1493,287 @@ impl Plot {
 
     /// Render the plot to an external renderer (used for subplots)
     pub fn render_to_renderer(&self, renderer: &mut SkiaRenderer, dpi: f32) -> Result<()> {
+        let image = self.render_subplot_image(renderer.width(), renderer.height(), dpi)?;
+        renderer.draw_subplot(image, 0, 0)
+    }
+
+    /// Render this plot as a `width` x `height` panel at `dpi`
+    pub(super) fn render_subplot_image(&self, width: u32, height: u32, dpi: f32) -> Result<Image> {
         self.validate_before_frame_resolution()?;
         let frame = self.resolve_frame(0.0)?;
         let mut plot = self.resolved_style_shell(&frame.style);
         plot.display.config.figure.dpi = dpi;
-        let plot = plot.set_subplot_output_pixels(renderer.width(), renderer.height());
+        let plot = plot.set_subplot_output_pixels(width, height);
         let mode = plot.render_execution_mode(BackendOperation::RasterImage);
         let (subplot_renderer, _) =
             plot.render_renderer_with_frame_and_diagnostics(mode, &frame)?;
-        renderer.draw_subplot(subplot_renderer.into_image(), 0, 0)?;
         frame.acknowledge_rendered(self);
-        Ok(())
+        Ok(subplot_renderer.into_image())
     }
 
-    /// Calculate total number of data points across all series
-    pub(super) fn create_plot_content_at_time(
+    /// Plot area this plot occupies when drawn by [`Plot::render_to_renderer`]
+    /// into a `width` x `height` canvas at `dpi`.
+    ///
+    /// Outside legends are left out: they are reserved again when the panel
+    /// renders, so subplot figures can align panels on the remaining area.
+    pub(crate) fn subplot_plot_area(
         &self,
-        y_min: f64,
-        y_max: f64,
-        time: f64,
-    ) -> PlotContent {
-        self.create_plot_content_with_text(
-            y_min,
-            y_max,
-            self.display.title.as_ref().map(|title| title.resolve(time)),
-            self.display
-                .xlabel
-                .as_ref()
-                .map(|label| label.resolve(time)),
-            self.display
-                .ylabel
-                .as_ref()
-                .map(|label| label.resolve(time)),
-        )
+        width: u32,
+        height: u32,
+        dpi: f32,
+    ) -> Result<tiny_skia::Rect> {
+        self.validate_before_frame_resolution()?;
+        let frame = self.resolve_frame(0.0)?;
+        let mut plot = self.resolved_style_shell(&frame.style);
+        plot.display.config.figure.dpi = dpi;
+        let plot = plot.set_subplot_output_pixels(width, height);
+        let canvas_size = plot.config_canvas_size();
+        let render_scale = plot.render_scale();
+
+        let mut renderer = SkiaRenderer::with_font_family(
+            canvas_size.0,
+            canvas_size.1,
+            plot.display.theme.clone(),
+            plot.display.config.typography.family.clone(),
+        )?;
+        renderer.set_text_engine_mode(plot.display.text_engine);
+        renderer.set_antialias(plot.render.antialias);
+        renderer.set_render_scale(render_scale);
+
+        let (x_min, x_max, y_min, y_max) =
+            plot.effective_main_panel_bounds_from_resolved(&plot.series_mgr.series, &frame.series)?;
+        let content = plot.create_plot_content_from_resolved_text(y_min, y_max, &frame);
+        let (x_ticks, y_ticks) = if content.show_tick_labels {
+            plot.configured_major_ticks(x_min, x_max, y_min, y_max)
+        } else {
+            (Vec::new(), Vec::new())
+        };
+        let (x_label_ticks, y_label_ticks) =
+            plot.tick_label_values(&x_ticks, &y_ticks, x_min, x_max, y_min, y_max);
+        let mut measurements = plot.measure_layout_text_with_ticks(
+            &renderer,
+            &content,
+            render_scale.dpi(),
+            &plot.layout.x_tick_labels(&x_label_ticks),
+            &plot.layout.y_tick_labels(&y_label_ticks),
+        )?;
+        plot.measure_tick_offset_texts(
+            &renderer,
+            render_scale.dpi(),
+            &x_ticks,
+            &y_ticks,
+            &mut measurements,
+        )?;
+        if let Some(measurements) = measurements.as_mut() {
+            measurements.legend = None;
+        }
+        let mut layout = plot.compute_layout_from_measurements(
+            canvas_size,
+            &content,
+            render_scale.dpi(),
+            measurements.as_ref(),
+        );
+        plot.fit_layout_to_aspect(&mut layout, x_min, x_max, y_min, y_max);
+
+        Self::plot_area_from_layout(&layout)
     }
 
-    pub(super) fn create_plot_content_from_resolved_text(
-        &self,
-        y_min: f64,
-        y_max: f64,
-        frame: &ResolvedFrame<'_>,
-    ) -> PlotContent {
-        self.create_plot_content_with_text(
+    /// Axis limits the plot renders with, as `((x_min, x_max), (y_min, y_max))`.
+    ///
+    /// These are the auto-computed data bounds, including annotation extents
+    /// and the [`autoscale_margin`](Self::autoscale_margin), after manual
+    /// `xlim`/`ylim` overrides are applied, exactly as used by
+    /// [`Plot::render`], `save()`, and `render_to_svg()`. Useful for aligning
+    /// several figures or annotating at the edges of the axes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ruviz::prelude::*;
+    ///
+    /// let plot: Plot = Plot::new()
+    ///     .autoscale_margin(0.0)
+    ///     .line(&[0.0, 1.0, 2.0], &[3.0, 1.0, 4.0])
+    ///     .into();
+    /// let ((x_min, x_max), (y_min, y_max)) = plot.computed_limits()?;
+    /// assert_eq!((x_min, x_max), (0.0, 2.0));
+    /// assert_eq!((y_min, y_max), (1.0, 4.0));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn computed_limits(&self) -> Result<((f64, f64), (f64, f64))> {
+        self.validate_before_frame_resolution()?;
+        let frame = self.resolve_frame(0.0)?;
+        let (x_min, x_max, y_min, y_max) =
+            self.effective_main_panel_bounds_from_resolved(&self.series_mgr.series, &frame.series)?;
+        self.validate_axis_scale_ranges_for_render(
+            &self.series_mgr.series,
+            x_min,
+            x_max,
             y_min,
             y_max,
-            frame.title.clone(),
-            frame.xlabel.clone(),
-            frame.ylabel.clone(),
-        )
+        )?;
+        Ok(((x_min, x_max), (y_min, y_max)))
     }
 
-    fn create_plot_content_with_text(
-        &self,
-        y_min: f64,
-        y_max: f64,
-        title: Option<String>,
-        xlabel: Option<String>,
-        ylabel: Option<String>,
-    ) -> PlotContent {
-        // Estimate max characters in y-tick labels
-        let y_ticks = generate_ticks(y_min, y_max, 6);
-        let max_ytick_chars = y_ticks
-            .iter()
-            .map(|&v| {
-                if v.abs() < 0.001 {
+    /// Map a data coordinate to its pixel position in the [`Plot::render`] output.
+    ///
+    /// Uses the same axis limits, scales, and layout as rendering, so the result
+    /// can be used to overlay custom drawing or place tooltips on the image.
+    /// Points outside the axis limits map outside the plot area.
+    ///
+    /// Returns `None` if the plot cannot be laid out (for example it has no
+    /// data) or the point is not representable on the axis scales, such as
+    /// non-finite values or non-positive values on a log axis.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let plot: Plot = Plot::new().line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0]).into();
+    /// let (px, py) = plot.data_to_pixel(1.0, 1.0).expect("plot has data");
+    /// let image = plot.render()?;
+    /// # let _ = (px, py, image);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn data_to_pixel(&self, x: f64, y: f64) -> Option<(f32, f32)> {
+        if !x.is_finite() || !y.is_finite() {
+            return None;
+        }
+        if matches!(self.layout.x_scale, AxisScale::Log) && x <= 0.0
+            || matches!(self.layout.y_scale, AxisScale::Log) && y <= 0.0
+        {
+            return None;
+        }
+
+        let (plot_area, (x_min, x_max, y_min, y_max)) = self.rendered_plot_frame().ok()?;
+        let px = Self::scaled_x_pixel(x, x_min, x_max, plot_area, &self.layout.x_scale);
+        let py = Self::scaled_y_pixel(y, y_min, y_max, plot_area, &self.layout.y_scale);
+        (px.is_finite() && py.is_finite()).then_some((px, py))
+    }
+
+    /// Map a pixel position in the [`Plot::render`] output back to data coordinates.
+    ///
+    /// This is the inverse of [`Plot::data_to_pixel`]. Returns `None` if the plot
+    /// cannot be laid out, the pixel is not finite, or an axis range is degenerate.
+    pub fn pixel_to_data(&self, px: f32, py: f32) -> Option<(f64, f64)> {
+        if !px.is_finite() || !py.is_finite() {
+            return None;
+        }
+
+        let (plot_area, (x_min, x_max, y_min, y_max)) = self.rendered_plot_frame().ok()?;
+        if x_min == x_max || y_min == y_max {
+            return None;
+        }
+        let x_normalized = f64::from((px - plot_area.left()) / plot_area.width());
+        let y_normalized = f64::from((plot_area.bottom() - py) / plot_area.height());
+        let x = self
+            .layout
+            .x_scale
+            .inverse_normalized_position(x_normalized, x_min, x_max);
+        let y = self
+            .layout
+            .y_scale
+            .inverse_normalized_position(y_normalized, y_min, y_max);
+        (x.is_finite() && y.is_finite()).then_some((x, y))
+    }
+
+    /// Plot area and axis limits laid out by [`Plot::render`].
+    fn rendered_plot_frame(&self) -> Result<(tiny_skia::Rect, (f64, f64, f64, f64))> {
+        self.validate_before_frame_resolution()?;
+        let frame = self.resolve_frame(0.0)?;
+        let plot = self.resolved_style_shell(&frame.style);
+        let canvas_size = plot.config_canvas_size();
+        let render_scale = plot.render_scale();
+
+        let mut renderer = SkiaRenderer::with_font_family(
+            canvas_size.0,
+            canvas_size.1,
+            plot.display.theme.clone(),
+            plot.display.config.typography.family.clone(),
+        )?;
+        renderer.set_text_engine_mode(plot.display.text_engine);
+        renderer.set_antialias(plot.render.antialias);
+        renderer.set_render_scale(render_scale);
+
+        let (x_min, x_max, y_min, y_max) =
+            plot.effective_main_panel_bounds_from_resolved(&plot.series_mgr.series, &frame.series)?;
+        plot.validate_axis_scale_ranges_for_render(
+            &plot.series_mgr.series,
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+        )?;
+        let content = plot.create_plot_content_from_resolved_text(y_min, y_max, &frame);
+        let (layout, _, _) = plot.compute_layout_with_configured_ticks(
+            &renderer,
+            canvas_size,
+            &content,
+            render_scale.dpi(),
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+        )?;
+
+        Ok((
+            Self::plot_area_from_layout(&layout)?,
+            (x_min, x_max, y_min, y_max),
+        ))
+    }
+
+    /// Calculate total number of data points across all series
+    pub(super) fn create_plot_content_at_time(
+        &self,
+        y_min: f64,
+        y_max: f64,
+        time: f64,
+    ) -> PlotContent {
+        self.create_plot_content_with_text(
+            y_min,
+            y_max,
+            self.display.title.as_ref().map(|title| title.resolve(time)),
+            self.display
+                .xlabel
+                .as_ref()
+                .map(|label| label.resolve(time)),
+            self.display
+                .ylabel
+                .as_ref()
+                .map(|label| label.resolve(time)),
+        )
+    }
+
+    pub(super) fn create_plot_content_from_resolved_text(
+        &self,
+        y_min: f64,
+        y_max: f64,
+        frame: &ResolvedFrame<'_>,
+    ) -> PlotContent {
+        self.create_plot_content_with_text(
+            y_min,
+            y_max,
+            frame.title.clone(),
+            frame.xlabel.clone(),
+            frame.ylabel.clone(),
+        )
+    }
+
+    fn create_plot_content_with_text(
+        &self,
+        y_min: f64,
+        y_max: f64,
+        title: Option<String>,
+        xlabel: Option<String>,
+        ylabel: Option<String>,
+    ) -> PlotContent {
+        // Estimate max characters in y-tick labels
+        let y_ticks = generate_ticks(y_min, y_max, 6);
+        let max_ytick_chars = y_ticks
+            .iter()
+            .map(|&v| {
+                if v.abs() < 0.001 {
                     1 // "0"
                 } else if v.abs() > 1000.0 {
                     format!("{:.0e}", v).len()
@@ -1204,15 +1805,7 @@ impl Plot {
             .iter()
             .find_map(|series| match &series.series_type {
                 SeriesType::Heatmap { data } if data.config.colorbar => {
-                    Some(ColorbarMeasurementSpec {
-                        vmin: data.vmin,
-                        vmax: data.vmax,
-                        value_scale: data.config.value_scale.clone(),
-                        label: data.config.colorbar_label.clone(),
-                        tick_font_size: data.config.colorbar_tick_font_size,
-                        label_font_size: data.config.colorbar_label_font_size,
-                        show_log_subticks: data.config.colorbar_log_subticks,
-                    })
+                    Some(ColorbarMeasurementSpec::for_heatmap(data))
                 }
                 SeriesType::Contour { data } if data.config.colorbar => {
                     let (vmin, vmax) = if data.levels.is_empty() {
@@ -1232,14 +1825,97 @@ impl Plot {
                         tick_font_size: data.config.colorbar_tick_font_size,
                         label_font_size: data.config.colorbar_label_font_size,
                         show_log_subticks: false,
+                        colorbar: crate::plots::ColorbarConfig::default(),
+                    })
+                }
+                SeriesType::Hexbin { data } if data.config.colorbar => {
+                    let (vmin, vmax) = data.colorbar_range();
+                    Some(ColorbarMeasurementSpec {
+                        vmin,
+                        vmax,
+                        value_scale: if data.config.log_scale {
+                            AxisScale::Log
+                        } else {
+                            AxisScale::Linear
+                        },
+                        label: data.config.colorbar_label.clone(),
+                        tick_font_size: data.config.colorbar_tick_font_size,
+                        label_font_size: data.config.colorbar_label_font_size,
+                        show_log_subticks: false,
+                        colorbar: crate::plots::ColorbarConfig::default(),
+                    })
+                }
+                SeriesType::ScatterMapped { data } if data.config.colorbar => {
+                    let (vmin, vmax) = data.colorbar_range();
+                    Some(ColorbarMeasurementSpec {
+                        vmin,
+                        vmax,
+                        value_scale: AxisScale::Linear,
+                        label: data.config.colorbar_label.clone(),
+                        tick_font_size: data.config.colorbar_tick_font_size,
+                        label_font_size: data.config.colorbar_label_font_size,
+                        show_log_subticks: false,
+                        colorbar: crate::plots::ColorbarConfig::default(),
                     })
                 }
                 _ => None,
             })
     }
 
-    fn measure_colorbar_right_margin(
-        &self,
+    /// Union of the color ranges of this plot's heatmaps
+    pub(crate) fn heatmap_value_range(&self) -> Option<(f64, f64)> {
+        self.series_mgr
+            .series
+            .iter()
+            .filter_map(|series| match &series.series_type {
+                SeriesType::Heatmap { data } => Some((data.vmin, data.vmax)),
+                _ => None,
+            })
+            .reduce(|(min, max), (vmin, vmax)| (min.min(vmin), max.max(vmax)))
+    }
+
+    /// Colorbar styling of the first heatmap, if any
+    pub(crate) fn heatmap_colorbar_style(&self) -> Option<HeatmapColorbarStyle> {
+        self.series_mgr
+            .series
+            .iter()
+            .find_map(|series| match &series.series_type {
+                SeriesType::Heatmap { data } => Some(HeatmapColorbarStyle {
+                    colormap: data.config.colormap.clone(),
+                    value_scale: data.config.value_scale.clone(),
+                    label: data.config.colorbar_label.clone(),
+                    tick_font_size: data.config.colorbar_tick_font_size,
+                    label_font_size: data.config.colorbar_label_font_size,
+                    show_log_subticks: data.config.colorbar_log_subticks,
+                    center: data.config.center,
+                    colorbar: crate::plots::ColorbarConfig {
+                        orientation: crate::plots::ColorbarOrientation::Vertical,
+                        ..data.config.colorbar_config.clone()
+                    },
+                }),
+                _ => None,
+            })
+    }
+
+    /// Map every heatmap onto `vmin..vmax` and hide their own colorbars
+    pub(crate) fn with_heatmap_value_range(mut self, vmin: f64, vmax: f64) -> Self {
+        for series in &mut self.series_mgr.series {
+            if let SeriesType::Heatmap { data } = &mut series.series_type {
+                let data = std::sync::Arc::make_mut(data);
+                data.vmin = vmin;
+                data.vmax = vmax;
+                data.config.vmin = Some(vmin);
+                data.config.vmax = Some(vmax);
+                data.config.colorbar = false;
+            }
+        }
+        self
+    }
+
+    /// Space a colorbar needs across its bar direction, including the gap to
+    /// the plot: right margin for vertical colorbars, bottom band for
+    /// horizontal ones
+    fn measure_colorbar_extent(
         renderer: &SkiaRenderer,
         spec: &ColorbarMeasurementSpec,
     ) -> Result<f32> {
@@ -1248,15 +1924,18 @@ impl Plot {
         let colorbar_margin = render_scale.logical_pixels_to_pixels(COLORBAR_MARGIN_PX);
         let tick_font_size = render_scale.points_to_pixels(spec.tick_font_size);
         let label_font_size = render_scale.points_to_pixels(spec.label_font_size);
-        let ticks = crate::render::skia::compute_colorbar_ticks(
+        let ticks = crate::render::skia::compute_colorbar_ticks_with_options(
             spec.vmin,
             spec.vmax,
             &spec.value_scale,
             spec.show_log_subticks,
+            spec.colorbar.n_ticks,
+            spec.colorbar.formatter.as_ref(),
         );
+        let horizontal = spec.colorbar.orientation == crate::plots::ColorbarOrientation::Horizontal;
         let max_label_width =
-            Self::measure_tick_label_extent(renderer, &ticks.major_labels, tick_font_size)?
-                .map(|(width, _)| width)
+            Self::measure_tick_label_extent(renderer, &ticks.major_labels, tick_font_size, 0.0)?
+                .map(|(width, height)| if horizontal { height } else { width })
                 .unwrap_or(0.0);
         let rotated_label_width = if let Some(label) = spec.label.as_deref() {
             renderer.measure_text(label, label_font_size)?.1
@@ -1274,6 +1953,20 @@ impl Plot {
         Ok(colorbar_margin + layout.total_extent + outer_padding)
     }
 
+    /// Top edge of a heatmap's horizontal colorbar, which sits in the band
+    /// reserved at the bottom of the canvas
+    pub(super) fn horizontal_colorbar_top(
+        renderer: &SkiaRenderer,
+        data: &crate::plots::HeatmapData,
+    ) -> Result<f32> {
+        let band =
+            Self::measure_colorbar_extent(renderer, &ColorbarMeasurementSpec::for_heatmap(data))?;
+        let colorbar_margin = renderer
+            .render_scale()
+            .logical_pixels_to_pixels(COLORBAR_MARGIN_PX);
+        Ok(renderer.height() as f32 - band + colorbar_margin)
+    }
+
     /// Pre-measure title/xlabel/ylabel for Typst layout parity.
     pub(super) fn measure_layout_text(
         &self,
@@ -1321,13 +2014,33 @@ impl Plot {
             .unwrap_or_default();
 
         if content.show_tick_labels {
-            measurements.xtick =
-                Self::measure_tick_label_extent(renderer, x_tick_labels, tick_size_px)?;
+            let x_rotation = self.layout.tick_config.x_label_rotation;
+            // Rotated category names can be far taller than the numeric ticks
+            // measured by default, so measure the labels actually drawn.
+            let categorical_x_labels = if x_rotation != 0.0 {
+                self.categorical_x_tick_labels()
+            } else {
+                None
+            };
+            measurements.xtick = Self::measure_tick_label_extent(
+                renderer,
+                categorical_x_labels.as_deref().unwrap_or(x_tick_labels),
+                tick_size_px,
+                x_rotation,
+            )?;
             measurements.ytick =
-                Self::measure_tick_label_extent(renderer, y_tick_labels, tick_size_px)?;
+                Self::measure_tick_label_extent(renderer, y_tick_labels, tick_size_px, 0.0)?;
         }
         if let Some(spec) = self.colorbar_measurement_spec() {
-            measurements.right_margin = Some(self.measure_colorbar_right_margin(renderer, &spec)?);
+            let extent = Self::measure_colorbar_extent(renderer, &spec)?;
+            match spec.colorbar.orientation {
+                crate::plots::ColorbarOrientation::Vertical => {
+                    measurements.right_margin = Some(extent);
+                }
+                crate::plots::ColorbarOrientation::Horizontal => {
+                    measurements.colorbar_band = Some(extent);
+                }
+            }
         }
         let legend = self
             .layout
@@ -1341,7 +2054,81 @@ impl Plot {
         Ok(Some(measurements))
     }
 
-    fn measure_legend(
+    /// Record the heights of the axis offset texts the tick formatters add
+    /// for `x_ticks` and `y_ticks`, so the layout leaves room for them
+    pub(super) fn measure_tick_offset_texts(
+        &self,
+        renderer: &SkiaRenderer,
+        dpi: f32,
+        x_ticks: &[f64],
+        y_ticks: &[f64],
+        measurements: &mut Option<LayoutMeasurements>,
+    ) -> Result<()> {
+        let Some(measurements) = measurements.as_mut() else {
+            return Ok(());
+        };
+        let tick_size_px =
+            RenderScale::new(dpi).points_to_pixels(self.display.config.typography.tick_size());
+        let measure = |formatter: Option<TickLabelFormatter>, ticks: &[f64]| {
+            formatter
+                .and_then(|formatter| formatter.offset_text(ticks))
+                .map(|text| renderer.measure_text(&text, tick_size_px).map(|(_, h)| h))
+                .transpose()
+        };
+        measurements.tick_offset_texts = (
+            measure(self.layout.x_label_formatter(), x_ticks)?,
+            measure(self.layout.y_label_formatter(), y_ticks)?,
+        );
+        Ok(())
+    }
+
+    /// Category names drawn on the X axis in place of numeric ticks, if any.
+    fn categorical_x_tick_labels(&self) -> Option<Vec<String>> {
+        let bar_categories = self.series_mgr.series.iter().find_map(|s| {
+            if let SeriesType::Bar { categories, .. } = &s.series_type {
+                Some(categories.clone())
+            } else {
+                None
+            }
+        });
+        bar_categories.or_else(|| {
+            let (categories, _) = self.positioned_x_categories();
+            (!categories.is_empty()).then_some(categories)
+        })
+    }
+
+    /// Category names and X positions of violins, box plots, and strip plots
+    /// placed by category.
+    pub(super) fn positioned_x_categories(&self) -> (Vec<String>, Vec<f64>) {
+        self.series_mgr
+            .series
+            .iter()
+            .flat_map(|s| match &s.series_type {
+                SeriesType::Violin { data } => data
+                    .config
+                    .category
+                    .clone()
+                    .map(|category| (category, data.config.x_position))
+                    .into_iter()
+                    .collect(),
+                SeriesType::BoxPlot { config, .. } => config
+                    .category
+                    .clone()
+                    .map(|category| (category, config.x_position))
+                    .into_iter()
+                    .collect(),
+                SeriesType::Strip { data } => data
+                    .category_names
+                    .iter()
+                    .enumerate()
+                    .map(|(index, name)| (name.clone(), index as f64))
+                    .collect(),
+                _ => Vec::new(),
+            })
+            .unzip()
+    }
+
+    pub(crate) fn measure_legend(
         renderer: &SkiaRenderer,
         legend: &Legend,
         items: &[LegendItem],
@@ -1364,7 +2151,9 @@ impl Plot {
         let content_height =
             rows as f32 * legend.font_size + rows.saturating_sub(1) as f32 * spacing.label_spacing;
         let title_size = if let Some(title) = legend.title.as_deref() {
-            let title_width = renderer.measure_label_text(title, legend.font_size)?.0;
+            let title_width = renderer
+                .measure_label_text_with_weight(title, legend.font_size, FontWeight::Bold)?
+                .0;
             (title_width, legend.font_size + spacing.label_spacing)
         } else {
             (0.0, 0.0)
@@ -1410,15 +2199,72 @@ impl Plot {
                 measured_dimensions,
             ),
         };
-        self.reserve_outside_legend(
-            ResolvedLayout {
-                layout,
-                legend_rect: None,
-            },
-            canvas_size,
-            dpi,
+        let layout = Self::reserve_colorbar_band(
+            Self::reserve_tick_offset_texts(
+                ResolvedLayout {
+                    layout,
+                    legend_rect: None,
+                },
+                measurements,
+            ),
             measurements,
-        )
+        );
+        self.reserve_outside_legend(layout, canvas_size, dpi, measurements)
+    }
+
+    /// Shrink the plot area so the X offset text fits below the tick labels
+    /// and the Y offset text fits above the axis
+    fn reserve_tick_offset_texts(
+        mut layout: ResolvedLayout,
+        measurements: Option<&LayoutMeasurements>,
+    ) -> ResolvedLayout {
+        let Some((x_offset, y_offset)) = measurements.map(|m| m.tick_offset_texts) else {
+            return layout;
+        };
+        if let Some(height) = x_offset {
+            let below_ticks =
+                layout.margins.bottom - (layout.xtick_baseline_y - layout.plot_area.bottom);
+            let extra = (height * 2.4 - below_ticks).max(0.0);
+            layout.plot_area.bottom -= extra;
+            layout.margins.bottom += extra;
+            layout.xtick_baseline_y -= extra;
+            if let Some(pos) = layout.ylabel_pos.as_mut() {
+                pos.y -= extra * 0.5;
+            }
+        }
+        if let Some(height) = y_offset {
+            let extra = (height * 1.6 - layout.plot_area.top).max(0.0);
+            layout.plot_area.top += extra;
+            layout.margins.top += extra;
+            if let Some(pos) = layout.ylabel_pos.as_mut() {
+                pos.y += extra * 0.5;
+            }
+        }
+        layout
+    }
+
+    /// Shrink the plot area from the bottom to make room for a horizontal
+    /// colorbar along the bottom edge of the canvas
+    fn reserve_colorbar_band(
+        mut layout: ResolvedLayout,
+        measurements: Option<&LayoutMeasurements>,
+    ) -> ResolvedLayout {
+        let Some(band) = measurements.and_then(|m| m.colorbar_band) else {
+            return layout;
+        };
+        const MIN_PLOT_EXTENT_PX: f32 = 40.0;
+        let band = band
+            .min((layout.plot_area.bottom - layout.plot_area.top - MIN_PLOT_EXTENT_PX).max(0.0));
+        layout.plot_area.bottom -= band;
+        layout.margins.bottom += band;
+        layout.xtick_baseline_y -= band;
+        if let Some(pos) = layout.xlabel_pos.as_mut() {
+            pos.y -= band;
+        }
+        if let Some(pos) = layout.ylabel_pos.as_mut() {
+            pos.y -= band * 0.5;
+        }
+        layout
     }
 
     fn reserve_outside_legend(
@@ -1536,11 +2382,14 @@ impl Plot {
                 }
                 let left = (layout.plot_area.right - legend_width)
                     .clamp(pad, (canvas_width - legend_width - pad).max(pad));
+                // Stack above a horizontal colorbar band, if any
+                let bottom =
+                    canvas_height - pad - measurements.and_then(|m| m.colorbar_band).unwrap_or(0.0);
                 layout.legend_rect = Some(crate::core::layout::LayoutRect {
                     left,
-                    top: canvas_height - legend_height - pad,
+                    top: bottom - legend_height,
                     right: left + legend_width,
-                    bottom: canvas_height - pad,
+                    bottom,
                 });
             }
             _ => {}
@@ -1718,18 +2567,28 @@ impl Plot {
         y_min: f64,
         y_max: f64,
     ) -> (Vec<f64>, Vec<f64>) {
+        let ticks = |min, max, count, scale, integer| {
+            if integer {
+                crate::axes::generate_integer_ticks_for_scale(min, max, count, scale)
+            } else {
+                crate::axes::generate_ticks_for_scale(min, max, count, scale)
+            }
+        };
+        let config = &self.layout.tick_config;
         (
-            crate::axes::generate_ticks_for_scale(
+            ticks(
                 x_min,
                 x_max,
-                self.layout.tick_config.major_ticks_x,
+                config.major_ticks_x,
                 &self.layout.x_scale,
+                config.integer_x,
             ),
-            crate::axes::generate_ticks_for_scale(
+            ticks(
                 y_min,
                 y_max,
-                self.layout.tick_config.major_ticks_y,
+                config.major_ticks_y,
                 &self.layout.y_scale,
+                config.integer_y,
             ),
         )
     }
@@ -1748,22 +2607,27 @@ impl Plot {
         if !content.show_tick_labels {
             let measurements =
                 self.measure_layout_text_with_ticks(renderer, content, dpi, &[], &[])?;
-            let layout = self.compute_layout_from_measurements(
+            let mut layout = self.compute_layout_from_measurements(
                 canvas_size,
                 content,
                 dpi,
                 measurements.as_ref(),
             );
+            self.fit_layout_to_aspect(&mut layout, x_min, x_max, y_min, y_max);
             return Ok((layout, Vec::new(), Vec::new()));
         }
 
         let (x_ticks, y_ticks) = self.configured_major_ticks(x_min, x_max, y_min, y_max);
-        let x_labels = crate::render::skia::format_tick_labels(&x_ticks);
-        let y_labels = crate::render::skia::format_tick_labels(&y_ticks);
-        let measurements =
+        let (x_label_ticks, y_label_ticks) =
+            self.tick_label_values(&x_ticks, &y_ticks, x_min, x_max, y_min, y_max);
+        let x_labels = self.layout.x_tick_labels(&x_label_ticks);
+        let y_labels = self.layout.y_tick_labels(&y_label_ticks);
+        let mut measurements =
             self.measure_layout_text_with_ticks(renderer, content, dpi, &x_labels, &y_labels)?;
-        let layout =
+        self.measure_tick_offset_texts(renderer, dpi, &x_ticks, &y_ticks, &mut measurements)?;
+        let mut layout =
             self.compute_layout_from_measurements(canvas_size, content, dpi, measurements.as_ref());
+        self.fit_layout_to_aspect(&mut layout, x_min, x_max, y_min, y_max);
 
         Ok((layout, x_ticks, y_ticks))
     }
@@ -1772,12 +2636,19 @@ impl Plot {
         renderer: &SkiaRenderer,
         labels: &[String],
         tick_size_px: f32,
+        rotation_degrees: f32,
     ) -> Result<Option<(f32, f32)>> {
         let mut max_width: f32 = 0.0;
         let mut max_height: f32 = 0.0;
 
         for label in labels {
+            // Blank labels (e.g. hidden by a formatter) take no space.
+            if label.is_empty() {
+                continue;
+            }
             let (width, height) = renderer.measure_label_text(label, tick_size_px)?;
+            let (width, height) =
+                crate::render::text_anchor::rotated_text_extent(width, height, rotation_degrees);
             max_width = max_width.max(width);
             max_height = max_height.max(height);
         }
@@ -1789,187 +2660,238 @@ impl Plot {
         }
     }
 
-    /// Render plot using DataShader optimization for large datasets
-    pub(super) fn render_with_datashader(&self, series_list: &[PlotSeries]) -> Result<Image> {
+    /// Data points a series contributes to a DataShader canvas
+    fn datashader_points(series: &PlotSeries) -> Result<(Vec<f64>, Vec<f64>)> {
         let mut x_values = Vec::new();
         let mut y_values = Vec::new();
 
-        // Collect all points from all series
-        for series in series_list {
-            match &series.series_type {
-                SeriesType::Line { x_data, y_data } | SeriesType::Scatter { x_data, y_data } => {
-                    let x_data = x_data.resolve_cow(0.0);
-                    let y_data = y_data.resolve_cow(0.0);
-                    for (&x, &y) in x_data.iter().zip(y_data.iter()) {
-                        if x.is_finite() && y.is_finite() {
-                            x_values.push(x);
-                            y_values.push(y);
-                        }
+        match &series.series_type {
+            SeriesType::Line { x_data, y_data } | SeriesType::Scatter { x_data, y_data } => {
+                let x_data = x_data.resolve_cow(0.0);
+                let y_data = y_data.resolve_cow(0.0);
+                for (&x, &y) in x_data.iter().zip(y_data.iter()) {
+                    if x.is_finite() && y.is_finite() {
+                        x_values.push(x);
+                        y_values.push(y);
                     }
                 }
-                SeriesType::ErrorBars { x_data, y_data, .. }
-                | SeriesType::ErrorBarsXY { x_data, y_data, .. } => {
-                    let x_data = x_data.resolve_cow(0.0);
-                    let y_data = y_data.resolve_cow(0.0);
-                    for (&x, &y) in x_data.iter().zip(y_data.iter()) {
-                        if x.is_finite() && y.is_finite() {
-                            x_values.push(x);
-                            y_values.push(y);
-                        }
+            }
+            SeriesType::ErrorBars { x_data, y_data, .. }
+            | SeriesType::ErrorBarsXY { x_data, y_data, .. } => {
+                let x_data = x_data.resolve_cow(0.0);
+                let y_data = y_data.resolve_cow(0.0);
+                for (&x, &y) in x_data.iter().zip(y_data.iter()) {
+                    if x.is_finite() && y.is_finite() {
+                        x_values.push(x);
+                        y_values.push(y);
                     }
                 }
-                SeriesType::Bar { values, .. } => {
-                    let values = values.resolve_cow(0.0);
-                    // For bar charts, convert category indices to points
-                    for (i, &value) in values.iter().enumerate() {
+            }
+            SeriesType::Bar { values, .. } => {
+                let values = values.resolve_cow(0.0);
+                // For bar charts, convert category indices to points
+                for (i, &value) in values.iter().enumerate() {
+                    if value.is_finite() {
+                        x_values.push(i as f64);
+                        y_values.push(value);
+                    }
+                }
+            }
+            SeriesType::Heatmap { data } => {
+                // Heatmap has its own grid, convert to points
+                for (row, row_values) in data.values.iter().enumerate() {
+                    for (col, &value) in row_values.iter().enumerate() {
                         if value.is_finite() {
-                            x_values.push(i as f64);
-                            y_values.push(value);
+                            x_values.push(col as f64);
+                            y_values.push(row as f64);
                         }
                     }
                 }
-                SeriesType::Heatmap { data } => {
-                    // Heatmap has its own grid, convert to points
-                    for (row, row_values) in data.values.iter().enumerate() {
-                        for (col, &value) in row_values.iter().enumerate() {
-                            if value.is_finite() {
-                                x_values.push(col as f64);
-                                y_values.push(row as f64);
-                            }
+            }
+            SeriesType::Histogram { .. } => {
+                if let Ok(hist_data) = series.series_type.histogram_data_at(0.0) {
+                    for (i, &count) in hist_data.counts.iter().enumerate() {
+                        if count > 0.0 {
+                            let x_center =
+                                (hist_data.bin_edges[i] + hist_data.bin_edges[i + 1]) / 2.0;
+                            x_values.push(x_center);
+                            y_values.push(count);
                         }
                     }
                 }
-                SeriesType::Histogram { .. } => {
-                    if let Ok(hist_data) = series.series_type.histogram_data_at(0.0) {
-                        for (i, &count) in hist_data.counts.iter().enumerate() {
-                            if count > 0.0 {
-                                let x_center =
-                                    (hist_data.bin_edges[i] + hist_data.bin_edges[i + 1]) / 2.0;
-                                x_values.push(x_center);
-                                y_values.push(count);
-                            }
-                        }
+            }
+            SeriesType::BoxPlot { data, .. } => {
+                if data.is_empty() {
+                    return Err(PlottingError::EmptyDataSet);
+                }
+            }
+            SeriesType::Kde { data } => {
+                // Add KDE points
+                for (&x, &y) in data.x.iter().zip(data.y.iter()) {
+                    if x.is_finite() && y.is_finite() {
+                        x_values.push(x);
+                        y_values.push(y);
                     }
                 }
-                SeriesType::BoxPlot { data, .. } => {
-                    if data.is_empty() {
-                        return Err(PlottingError::EmptyDataSet);
+            }
+            SeriesType::Ecdf { data } => {
+                // Add ECDF points
+                for (&x, &y) in data.x.iter().zip(data.y.iter()) {
+                    if x.is_finite() && y.is_finite() {
+                        x_values.push(x);
+                        y_values.push(y);
                     }
                 }
-                SeriesType::Kde { data } => {
-                    // Add KDE points
-                    for (&x, &y) in data.x.iter().zip(data.y.iter()) {
-                        if x.is_finite() && y.is_finite() {
-                            x_values.push(x);
-                            y_values.push(y);
-                        }
+            }
+            SeriesType::Rug { .. } => {
+                // Rug marks sit on the plot edges rather than at data points
+            }
+            SeriesType::Strip { data } => {
+                // Add jittered strip points
+                for point in &data.points {
+                    x_values.push(point.x);
+                    y_values.push(point.y);
+                }
+            }
+            SeriesType::Violin { data } => {
+                // Add violin KDE points
+                for &y in &data.kde.x {
+                    let x = 0.5; // Centered position
+                    if y.is_finite() {
+                        x_values.push(x);
+                        y_values.push(y);
                     }
                 }
-                SeriesType::Ecdf { data } => {
-                    // Add ECDF points
-                    for (&x, &y) in data.x.iter().zip(data.y.iter()) {
+            }
+            SeriesType::Boxen { data } => {
+                // Add boxen box corner points
+                for boxen_box in &data.boxes {
+                    let rect =
+                        crate::plots::distribution::boxen_rect(boxen_box, 0.5, data.config.orient);
+                    for (x, y) in rect {
                         if x.is_finite() && y.is_finite() {
                             x_values.push(x);
                             y_values.push(y);
                         }
                     }
                 }
-                SeriesType::Violin { data } => {
-                    // Add violin KDE points
-                    for &y in &data.kde.x {
-                        let x = 0.5; // Centered position
-                        if y.is_finite() {
+            }
+            SeriesType::Quiver { data } => {
+                for arrow in &data.arrows {
+                    for (x, y) in [arrow.start, arrow.end] {
+                        if x.is_finite() && y.is_finite() {
                             x_values.push(x);
                             y_values.push(y);
                         }
                     }
                 }
-                SeriesType::Boxen { data } => {
-                    // Add boxen box corner points
-                    for boxen_box in &data.boxes {
-                        let rect = crate::plots::distribution::boxen_rect(
-                            boxen_box,
-                            0.5,
-                            data.config.orient,
-                        );
-                        for (x, y) in rect {
-                            if x.is_finite() && y.is_finite() {
-                                x_values.push(x);
-                                y_values.push(y);
-                            }
-                        }
-                    }
+            }
+            SeriesType::Hexbin { data } => {
+                // Add hexagon centers
+                for bin in &data.bins {
+                    x_values.push(bin.cx);
+                    y_values.push(bin.cy);
                 }
-                SeriesType::Quiver { data } => {
-                    for arrow in &data.arrows {
-                        for (x, y) in [arrow.start, arrow.end] {
-                            if x.is_finite() && y.is_finite() {
-                                x_values.push(x);
-                                y_values.push(y);
-                            }
-                        }
-                    }
+            }
+            SeriesType::ScatterMapped { data } => {
+                for (x, y, _) in data.colored_points() {
+                    x_values.push(x);
+                    y_values.push(y);
                 }
-                SeriesType::Contour { data } => {
-                    // Add contour line segment endpoints
-                    for level in &data.lines {
-                        for &(x1, y1, x2, y2) in &level.segments {
-                            if x1.is_finite() && y1.is_finite() {
-                                x_values.push(x1);
-                                y_values.push(y1);
-                            }
-                            if x2.is_finite() && y2.is_finite() {
-                                x_values.push(x2);
-                                y_values.push(y2);
-                            }
+            }
+            SeriesType::Contour { data } => {
+                // Add contour line segment endpoints
+                for level in &data.lines {
+                    for &(x1, y1, x2, y2) in &level.segments {
+                        if x1.is_finite() && y1.is_finite() {
+                            x_values.push(x1);
+                            y_values.push(y1);
                         }
-                    }
-                }
-                SeriesType::Pie { .. } => {
-                    // Pie charts don't use point-based datashader, use normalized coords
-                    x_values.push(0.5);
-                    y_values.push(0.5);
-                }
-                SeriesType::Radar { data } => {
-                    // Add radar series points (already in cartesian coordinates from polygon)
-                    for series_data in &data.series {
-                        for &(x, y) in &series_data.polygon {
-                            x_values.push(x);
-                            y_values.push(y);
+                        if x2.is_finite() && y2.is_finite() {
+                            x_values.push(x2);
+                            y_values.push(y2);
                         }
                     }
                 }
-                SeriesType::Polar { data } => {
-                    // Add polar plot points (already in cartesian coordinates)
-                    for point in &data.points {
-                        x_values.push(point.x);
-                        y_values.push(point.y);
+            }
+            SeriesType::Pie { .. } => {
+                // Pie charts don't use point-based datashader, use normalized coords
+                x_values.push(0.5);
+                y_values.push(0.5);
+            }
+            SeriesType::Radar { data } => {
+                // Add radar series points (already in cartesian coordinates from polygon)
+                for series_data in &data.series {
+                    for &(x, y) in &series_data.polygon {
+                        x_values.push(x);
+                        y_values.push(y);
                     }
                 }
             }
+            SeriesType::Polar { data } => {
+                // Add polar plot points (already in cartesian coordinates)
+                for point in &data.points {
+                    x_values.push(point.x);
+                    y_values.push(point.y);
+                }
+            }
         }
 
-        if x_values.is_empty() {
+        Ok((x_values, y_values))
+    }
+
+    /// Render plot using DataShader optimization for large datasets
+    ///
+    /// Each series is aggregated onto a DataShader canvas covering the plot
+    /// area of a normally decorated figure, so axes, ticks, grid, title, and
+    /// legend are kept just as in the automatic DataShader path of `save()`.
+    pub(super) fn render_with_datashader(&self, series_list: &[PlotSeries]) -> Result<Image> {
+        let points = series_list
+            .iter()
+            .map(Self::datashader_points)
+            .collect::<Result<Vec<_>>>()?;
+        if points.iter().all(|(x_values, _)| x_values.is_empty()) {
             return Err(PlottingError::EmptyDataSet);
         }
 
-        // Simple DataShader implementation - create basic aggregated image
-        let (canvas_width, canvas_height) = self.config_canvas_size();
-        let mut datashader =
-            DataShader::with_canvas_size(canvas_width as usize, canvas_height as usize);
-        let (x_min, x_max, y_min, y_max) = self.effective_data_bounds_for_series(series_list)?;
-
-        datashader.aggregate_with_bounds(&x_values, &y_values, x_min, x_max, y_min, y_max)?;
-        let ds_image = datashader.render();
-
-        // Convert to Image format
-        let image = Image {
-            width: ds_image.width as u32,
-            height: ds_image.height as u32,
-            pixels: ds_image.pixels,
-        };
+        let mut plot = self.clone();
+        plot.series_mgr.series = series_list.to_vec();
+        let frame = plot.resolve_frame(0.0)?;
+        let (renderer, _) = plot
+            .resolved_style_shell(&frame.style)
+            .render_renderer_with_resolved_frame(
+                RenderExecutionMode::Optimized,
+                &frame,
+                |_plot,
+                 snapshot_series,
+                 _resolved_series,
+                 renderer,
+                 plot_area,
+                 x_min,
+                 x_max,
+                 y_min,
+                 y_max,
+                 _render_scale,
+                 _mode| {
+                    for idx in PlotSeries::draw_order(snapshot_series) {
+                        let (x_values, y_values) = &points[idx];
+                        if x_values.is_empty() {
+                            continue;
+                        }
+                        let mut datashader = DataShader::with_canvas_size(
+                            plot_area.width() as usize,
+                            plot_area.height() as usize,
+                        );
+                        datashader.aggregate_with_bounds(
+                            x_values, y_values, x_min, x_max, y_min, y_max,
+                        )?;
+                        renderer.draw_datashader_image(&datashader.render(), plot_area)?;
+                    }
+                    Ok(())
+                },
+            )?;
 
-        Ok(image)
+        Ok(renderer.into_image())
     }
     /// Select a backend that is safe for every public raster output path.
     ///
@@ -2001,6 +2923,71 @@ impl Plot {
         self
     }
 
+    /// Enable or disable anti-aliasing in raster output
+    ///
+    /// Anti-aliasing is on by default. Turning it off draws lines, markers,
+    /// and fills with hard pixel edges, which keeps one-pixel lines crisp in
+    /// tiny sparkline-style plots. Text stays anti-aliased, and SVG output is
+    /// unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .size_px(160, 40)
+    ///     .line(&[0.0, 1.0, 2.0, 3.0], &[1.0, 3.0, 2.0, 4.0])
+    ///     .antialias(false)
+    ///     .save("sparkline.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn antialias(mut self, enabled: bool) -> Self {
+        self.render.antialias = enabled;
+        self
+    }
+
+    /// Stop renders of this plot when `flag` is set
+    ///
+    /// The flag is checked before each series and between DataShader
+    /// aggregation chunks. Once it is `true`, rendering and saving return
+    /// [`PlottingError::Cancelled`].
+    pub fn cancel_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.render.cancel_flag = Some(flag);
+        self
+    }
+
+    /// Report render progress to `progress`
+    ///
+    /// Every render and save of this plot calls `progress` with the
+    /// completed fraction (`0.0..=1.0`) as series are drawn. With an explicit
+    /// [`BackendType::DataShader`] backend, PNG output also reports after
+    /// each chunk of aggregated points, so million-point scatters update a
+    /// progress bar smoothly.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x: Vec<f64> = (0..10_000_000).map(|i| (i as f64 * 0.37).sin()).collect();
+    /// let y: Vec<f64> = (0..10_000_000).map(|i| (i as f64 * 0.11).cos()).collect();
+    ///
+    /// Plot::new()
+    ///     .scatter(&x, &y)
+    ///     .backend(BackendType::DataShader)
+    ///     .on_progress(|fraction| println!("{:.0}%", fraction * 100.0))
+    ///     .save("density.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn on_progress<F>(mut self, progress: F) -> Self
+    where
+        F: Fn(f32) + Send + Sync + 'static,
+    {
+        self.render.progress_callback = Some(ProgressCallback(Arc::new(progress)));
+        self
+    }
+
     /// Store a GPU backend preference for APIs that inspect plot configuration.
     ///
     /// Public raster operations currently resolve this preference to Skia and
@@ -2078,6 +3065,42 @@ impl Plot {
         Ok(())
     }
 
+    /// Render the plot and encode it into an in-memory buffer.
+    ///
+    /// This is `save()` without the filesystem: PNG and JPEG go through the
+    /// same raster backend as `save()`, and SVG matches `render_to_svg()`.
+    /// JPEG output is flattened onto white since the format has no alpha.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let format = ImageFormat::Png;
+    /// let body = Plot::new()
+    ///     .line(&[1.0, 2.0, 3.0], &[1.0, 4.0, 9.0])
+    ///     .save_to_buffer(format)?;
+    /// // e.g. respond with `Content-Type: format.mime_type()` and `body`
+    /// # let _ = body;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_to_buffer(self, format: ImageFormat) -> Result<Vec<u8>> {
+        match format {
+            ImageFormat::Png => self.render_png_bytes(),
+            ImageFormat::Jpeg => {
+                self.validate_before_frame_resolution()?;
+                let (jpeg_bytes, _, _, frame) = self
+                    .save_raster_bytes_with_backend_unacknowledged(|renderer| {
+                        renderer.encode_jpeg_bytes(crate::export::DEFAULT_JPEG_QUALITY)
+                    })?;
+                frame.acknowledge_rendered(&self);
+                Ok(jpeg_bytes)
+            }
+            ImageFormat::Svg => self.render_to_svg().map(String::into_bytes),
+        }
+    }
+
     /// Render PNG bytes through the same backend-selection path used by `save()`.
     ///
     /// This exists for benchmark tooling so we can measure the `save()` backend
@@ -2123,12 +3146,24 @@ impl Plot {
     fn save_png_bytes_with_backend_unacknowledged(
         &self,
     ) -> Result<(Vec<u8>, &'static str, RenderDiagnostics, ResolvedFrame<'_>)> {
+        self.save_raster_bytes_with_backend_unacknowledged(SkiaRenderer::encode_png_bytes)
+    }
+
+    /// Render through the `save()` backend path and encode with `encode`.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn save_raster_bytes_with_backend_unacknowledged<F>(
+        &self,
+        encode: F,
+    ) -> Result<(Vec<u8>, &'static str, RenderDiagnostics, ResolvedFrame<'_>)>
+    where
+        F: FnOnce(&SkiaRenderer) -> Result<Vec<u8>>,
+    {
         let frame = self.resolve_frame(0.0)?;
         let mode = self.public_png_render_mode_from_resolved(&frame.series);
         let render_plot = self.resolved_style_shell(&frame.style);
         let (renderer, diagnostics) =
             render_plot.render_renderer_with_frame_and_diagnostics(mode, &frame)?;
-        let png_bytes = renderer.encode_png_bytes()?;
+        let png_bytes = encode(&renderer)?;
         let backend = diagnostics.actual_backend_name();
         debug_assert_eq!(
             backend,
@@ -2152,6 +3187,40 @@ impl Plot {
         self.save(path)
     }
 
+    /// Save the plot to a PNG file at `scale` times its DPI
+    ///
+    /// The figure keeps its size in inches and its layout, so `scale = 2.0`
+    /// writes a retina-style image with twice the pixels in each direction
+    /// without changing [`dpi`](Self::dpi) or [`size`](Self::size) by hand.
+    /// Like [`save`](Self::save), the file is PNG-encoded whatever the
+    /// extension of `path`; vector formats have no DPI to scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlottingError::InvalidInput`] if `scale` is not a positive
+    /// finite number.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// // 640×480 at the default 100 DPI becomes a 1280×960 PNG
+    /// Plot::new()
+    ///     .line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0])
+    ///     .save_hires("slide.png", 2.0)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_hires<P: AsRef<Path>>(self, path: P, scale: f32) -> Result<()> {
+        if !(scale.is_finite() && scale > 0.0) {
+            return Err(PlottingError::InvalidInput(format!(
+                "hires scale must be a positive finite number, got {scale}"
+            )));
+        }
+        self.scale_dpi(scale).save(path)
+    }
+
     /// Export to SVG format
     ///
     /// Renders the plot to a vector SVG file with full visual fidelity.
@@ -2161,16 +3230,19 @@ impl Plot {
         self.validate_before_frame_resolution()?;
         let frame = self.resolve_frame(0.0)?;
         let render_plot = self.resolved_style_shell(&frame.style);
-        let svg_content = render_plot.render_to_svg_with_frame(&frame)?;
+        let svg_content = render_plot.render_to_svg_with_frame(&frame, SvgOptions::default())?;
         crate::export::write_bytes_atomic(path, svg_content.as_bytes())?;
         frame.acknowledge_rendered(&self);
         Ok(())
     }
 
+    /// Render the annotations of one layer that are (or are not) clipped to
+    /// the plot area
     fn render_svg_annotations(
         &self,
         svg: &mut crate::export::SvgRenderer,
         layer: AnnotationRenderLayer,
+        clipped: bool,
         plot_area: tiny_skia::Rect,
         x_min: f64,
         x_max: f64,
@@ -2180,6 +3252,7 @@ impl Plot {
         self.annotations
             .iter()
             .filter(|annotation| Self::annotation_render_layer(annotation) == layer)
+            .filter(|annotation| annotation.clips() == clipped)
             .try_for_each(|annotation| {
                 self.render_svg_annotation(svg, annotation, plot_area, x_min, x_max, y_min, y_max)
             })
@@ -2196,9 +3269,17 @@ impl Plot {
         y_max: f64,
     ) -> Result<()> {
         match annotation {
-            Annotation::Text { x, y, text, style } => {
-                let (px, py) =
-                    self.svg_annotation_point(*x, *y, plot_area, x_min, x_max, y_min, y_max);
+            Annotation::Text {
+                x,
+                y,
+                text,
+                style,
+                coords,
+                ..
+            } => {
+                let (px, py) = self.svg_annotation_point_in(
+                    *coords, *x, *y, plot_area, x_min, x_max, y_min, y_max,
+                );
                 svg.draw_styled_text(text, px, py, &self.display.config.typography.family, style)?;
             }
             Annotation::Arrow {
@@ -2207,27 +3288,50 @@ impl Plot {
                 x2,
                 y2,
                 style,
+                coords,
+                ..
             } => {
-                let (px1, py1) =
-                    self.svg_annotation_point(*x1, *y1, plot_area, x_min, x_max, y_min, y_max);
-                let (px2, py2) =
-                    self.svg_annotation_point(*x2, *y2, plot_area, x_min, x_max, y_min, y_max);
-                let width = self.render_scale().points_to_pixels(style.line_width);
-                svg.draw_line(
-                    px1,
-                    py1,
-                    px2,
-                    py2,
-                    style.color,
-                    width,
-                    style.line_style.clone(),
+                let start = self.svg_annotation_point_in(
+                    *coords, *x1, *y1, plot_area, x_min, x_max, y_min, y_max,
                 );
-
-                if !matches!(style.head_style, crate::core::ArrowHead::None) {
-                    self.draw_svg_arrow_head(svg, (px2, py2), (px1, py1), style);
-                }
-                if !matches!(style.tail_style, crate::core::ArrowHead::None) {
-                    self.draw_svg_arrow_head(svg, (px1, py1), (px2, py2), style);
+                let end = self.svg_annotation_point_in(
+                    *coords, *x2, *y2, plot_area, x_min, x_max, y_min, y_max,
+                );
+                self.draw_svg_arrow(svg, start, end, style);
+            }
+            Annotation::Callout {
+                x,
+                y,
+                text,
+                offset,
+                text_style,
+                arrow_style,
+                coords,
+                ..
+            } => {
+                let target = self.svg_annotation_point_in(
+                    *coords, *x, *y, plot_area, x_min, x_max, y_min, y_max,
+                );
+                let render_scale = self.render_scale();
+                let anchor = (
+                    target.0 + render_scale.points_to_pixels(offset.0),
+                    target.1 - render_scale.points_to_pixels(offset.1),
+                );
+                let layout = svg.draw_styled_text(
+                    text,
+                    anchor.0,
+                    anchor.1,
+                    &self.display.config.typography.family,
+                    text_style,
+                )?;
+                let start = match layout {
+                    Some(layout) => layout
+                        .edge_toward((target.0 - anchor.0, target.1 - anchor.1))
+                        .map(|(dx, dy)| (anchor.0 + dx, anchor.1 + dy)),
+                    None => Some(anchor),
+                };
+                if let Some(start) = start {
+                    self.draw_svg_arrow(svg, start, target, arrow_style);
                 }
             }
             Annotation::HLine {
@@ -2272,8 +3376,11 @@ impl Plot {
                 width,
                 height,
                 style,
+                coords,
+                ..
             } => {
-                let (px1, py1) = self.svg_annotation_point(
+                let (px1, py1) = self.svg_annotation_point_in(
+                    *coords,
                     *x,
                     *y + *height,
                     plot_area,
@@ -2282,7 +3389,8 @@ impl Plot {
                     y_min,
                     y_max,
                 );
-                let (px2, py2) = self.svg_annotation_point(
+                let (px2, py2) = self.svg_annotation_point_in(
+                    *coords,
                     *x + *width,
                     *y,
                     plot_area,
@@ -2306,39 +3414,30 @@ impl Plot {
                 y2,
                 style,
                 where_positive,
+                where_mask,
             } => {
-                let len = x.len().min(y1.len()).min(y2.len());
-                if len >= 2 && x.len() == y1.len() && x.len() == y2.len() {
-                    let mut points: Vec<(f32, f32)> = (0..len)
-                        .map(|index| {
-                            let y = if index > 0 && *where_positive && y1[index] < y2[index] {
-                                y2[index]
-                            } else {
-                                y1[index]
-                            };
-                            self.svg_annotation_point(
-                                x[index], y, plot_area, x_min, x_max, y_min, y_max,
-                            )
+                let polygons = crate::core::annotation::fill_between_polygons(
+                    x,
+                    y1,
+                    y2,
+                    *where_positive,
+                    where_mask.as_deref(),
+                );
+                for polygon in polygons {
+                    let points: Vec<(f32, f32)> = polygon
+                        .iter()
+                        .map(|&(x, y)| {
+                            self.svg_annotation_point(x, y, plot_area, x_min, x_max, y_min, y_max)
                         })
                         .collect();
-
-                    points.extend(
-                        (0..len)
-                            .rev()
-                            .filter(|&index| !*where_positive || y1[index] >= y2[index])
-                            .map(|index| {
-                                self.svg_annotation_point(
-                                    x[index], y2[index], plot_area, x_min, x_max, y_min, y_max,
-                                )
-                            }),
-                    );
-
-                    if points.len() >= 3 {
-                        svg.draw_filled_polygon(&points, style.color.with_alpha(style.alpha));
-                        if let Some(edge_color) = style.edge_color {
-                            let width = self.render_scale().points_to_pixels(style.edge_width);
-                            svg.draw_polygon_outline(&points, edge_color, width);
-                        }
+                    svg.draw_filled_polygon(&points, style.color.with_alpha(style.alpha));
+                    if let Some(pattern) = style.hatch {
+                        let hatch_color = style.edge_color.unwrap_or(style.color);
+                        svg.draw_hatch_polygon(&points, pattern, hatch_color);
+                    }
+                    if let Some(edge_color) = style.edge_color {
+                        let width = self.render_scale().points_to_pixels(style.edge_width);
+                        svg.draw_polygon_outline(&points, edge_color, width);
                     }
                 }
             }
@@ -2406,6 +3505,25 @@ impl Plot {
         )
     }
 
+    fn svg_annotation_point_in(
+        &self,
+        coords: crate::core::CoordSystem,
+        x: f64,
+        y: f64,
+        plot_area: tiny_skia::Rect,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+    ) -> (f32, f32) {
+        let (width, height) = self.config_canvas_size();
+        coords
+            .fraction_to_pixels(x, y, plot_area, (width as f32, height as f32))
+            .unwrap_or_else(|| {
+                self.svg_annotation_point(x, y, plot_area, x_min, x_max, y_min, y_max)
+            })
+    }
+
     fn draw_svg_styled_rect(
         &self,
         svg: &mut crate::export::SvgRenderer,
@@ -2467,6 +3585,32 @@ impl Plot {
         }
     }
 
+    fn draw_svg_arrow(
+        &self,
+        svg: &mut crate::export::SvgRenderer,
+        start: (f32, f32),
+        end: (f32, f32),
+        style: &ArrowStyle,
+    ) {
+        let width = self.render_scale().points_to_pixels(style.line_width);
+        svg.draw_line(
+            start.0,
+            start.1,
+            end.0,
+            end.1,
+            style.color,
+            width,
+            style.line_style.clone(),
+        );
+
+        if !matches!(style.head_style, crate::core::ArrowHead::None) {
+            self.draw_svg_arrow_head(svg, end, start, style);
+        }
+        if !matches!(style.tail_style, crate::core::ArrowHead::None) {
+            self.draw_svg_arrow_head(svg, start, end, style);
+        }
+    }
+
     fn draw_svg_arrow_head(
         &self,
         svg: &mut crate::export::SvgRenderer,
@@ -2507,17 +3651,45 @@ impl Plot {
     /// Returns the complete SVG content as a string. This can be saved to a file
     /// or converted to other formats like PDF.
     pub fn render_to_svg(&self) -> Result<String> {
+        self.render_to_svg_options(SvgOptions::default())
+    }
+
+    /// Render the plot to an SVG string with export options
+    ///
+    /// With `text_as_paths`, text is written as filled glyph outlines from the
+    /// fonts used for PNG output, so the SVG looks the same everywhere even
+    /// where those fonts are not installed. Typst-rendered text already embeds
+    /// its glyphs and is unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let svg = Plot::new()
+    ///     .title("Portable text")
+    ///     .line(&[1.0, 2.0, 3.0], &[1.0, 4.0, 9.0])
+    ///     .end_series()
+    ///     .render_to_svg_options(SvgOptions { text_as_paths: true })?;
+    /// std::fs::write("portable.svg", svg)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn render_to_svg_options(&self, options: SvgOptions) -> Result<String> {
         self.validate_before_frame_resolution()?;
         let frame = self.resolve_frame(0.0)?;
         let render_plot = self.resolved_style_shell(&frame.style);
-        let result = render_plot.render_to_svg_with_frame(&frame);
+        let result = render_plot.render_to_svg_with_frame(&frame, options);
         if result.is_ok() {
             frame.acknowledge_rendered(self);
         }
         result
     }
 
-    fn render_to_svg_with_frame(&self, frame: &ResolvedFrame<'_>) -> Result<String> {
+    fn render_to_svg_with_frame(
+        &self,
+        frame: &ResolvedFrame<'_>,
+        options: SvgOptions,
+    ) -> Result<String> {
         use crate::axes::TickLayout;
         use crate::export::SvgRenderer;
 
@@ -2538,6 +3710,8 @@ impl Plot {
         let render_scale = self.render_scale();
         svg.set_render_scale(render_scale);
         svg.set_text_engine_mode(self.display.text_engine);
+        svg.set_xtick_label_rotation(self.layout.tick_config.x_label_rotation);
+        svg.set_text_as_paths(options.text_as_paths);
 
         let (x_min, x_max, y_min, y_max) =
             self.effective_main_panel_bounds_from_resolved(&self.series_mgr.series, &frame.series)?;
@@ -2566,7 +3740,9 @@ impl Plot {
             1.0,
             &self.layout.x_scale,
             self.layout.tick_config.major_ticks_x,
-        );
+        )
+        .with_integer_ticks(self.layout.tick_config.integer_x)
+        .with_label_formatter(self.layout.x_label_formatter().as_ref());
         let y_major_measurement_layout = TickLayout::compute_y_axis(
             y_min,
             y_max,
@@ -2574,20 +3750,54 @@ impl Plot {
             1.0,
             &self.layout.y_scale,
             self.layout.tick_config.major_ticks_y,
-        );
-        let measured_dimensions = self.measure_layout_text_with_ticks(
+        )
+        .with_integer_ticks(self.layout.tick_config.integer_y)
+        .with_label_formatter(self.layout.y_label_formatter().as_ref());
+        let x_minor_measurement_labels = self.layout.x_tick_labels(&Self::labeled_log_minor_ticks(
+            &x_major_measurement_layout.data_positions,
+            x_min,
+            x_max,
+            &self.layout.x_scale,
+            &self.layout.tick_config.log_minor_labels_x,
+        ));
+        let y_minor_measurement_labels = self.layout.y_tick_labels(&Self::labeled_log_minor_ticks(
+            &y_major_measurement_layout.data_positions,
+            y_min,
+            y_max,
+            &self.layout.y_scale,
+            &self.layout.tick_config.log_minor_labels_y,
+        ));
+        let mut measured_dimensions = self.measure_layout_text_with_ticks(
             &measurement_renderer,
             &content,
             self.display.config.figure.dpi,
-            &x_major_measurement_layout.labels,
-            &y_major_measurement_layout.labels,
+            &[
+                x_major_measurement_layout.labels.as_slice(),
+                &x_minor_measurement_labels,
+            ]
+            .concat(),
+            &[
+                y_major_measurement_layout.labels.as_slice(),
+                &y_minor_measurement_labels,
+            ]
+            .concat(),
         )?;
-        let layout = self.compute_layout_from_measurements(
+        if content.show_tick_labels {
+            self.measure_tick_offset_texts(
+                &measurement_renderer,
+                self.display.config.figure.dpi,
+                &x_major_measurement_layout.data_positions,
+                &y_major_measurement_layout.data_positions,
+                &mut measured_dimensions,
+            )?;
+        }
+        let mut layout = self.compute_layout_from_measurements(
             (width_px, height_px),
             &content,
             self.display.config.figure.dpi,
             measured_dimensions.as_ref(),
         );
+        self.fit_layout_to_aspect(&mut layout, x_min, x_max, y_min, y_max);
         let plot_left = layout.plot_area.left;
         let plot_right = layout.plot_area.right;
         let plot_top = layout.plot_area.top;
@@ -2601,18 +3811,30 @@ impl Plot {
                 message: "Invalid plot area from layout".to_string(),
                 position: None,
             })?;
+        let spine_origin = self.spine_origin_px(plot_area, x_min, x_max, y_min, y_max);
+        Self::follow_spine_origin(&mut layout, plot_area, spine_origin);
 
         // Draw background
         svg.draw_rectangle(0.0, 0.0, width, height, self.display.theme.background, true);
 
-        // Check if we have a bar chart (need special X-axis handling)
-        let bar_categories: Option<&Vec<String>> = self.series_mgr.series.iter().find_map(|s| {
-            if let SeriesType::Bar { categories, .. } = &s.series_type {
-                Some(categories)
-            } else {
-                None
-            }
-        });
+        // Check if we have a bar chart or category-positioned violins/boxes
+        // (need special X-axis handling)
+        let bar_categories: Option<(Vec<String>, Vec<f64>)> = self
+            .series_mgr
+            .series
+            .iter()
+            .find_map(|s| {
+                if let SeriesType::Bar { categories, .. } = &s.series_type {
+                    let positions = (0..categories.len()).map(|index| index as f64).collect();
+                    Some((categories.clone(), positions))
+                } else {
+                    None
+                }
+            })
+            .or_else(|| {
+                let (categories, positions) = self.positioned_x_categories();
+                (!categories.is_empty()).then_some((categories, positions))
+            });
 
         // Compute Y-axis tick layout (fix parameter order: pixel_top then pixel_bottom)
         let y_tick_layout = TickLayout::compute_y_axis(
@@ -2622,19 +3844,29 @@ impl Plot {
             plot_bottom,
             &self.layout.y_scale,
             self.layout.tick_config.major_ticks_y,
-        );
+        )
+        .with_integer_ticks(self.layout.tick_config.integer_y)
+        .with_label_formatter(self.layout.y_label_formatter().as_ref());
         let x_tick_layout = if bar_categories.is_none() {
-            Some(TickLayout::compute(
-                x_min,
-                x_max,
-                plot_left,
-                plot_right,
-                &self.layout.x_scale,
-                self.layout.tick_config.major_ticks_x,
-            ))
+            Some(
+                TickLayout::compute(
+                    x_min,
+                    x_max,
+                    plot_left,
+                    plot_right,
+                    &self.layout.x_scale,
+                    self.layout.tick_config.major_ticks_x,
+                )
+                .with_integer_ticks(self.layout.tick_config.integer_x)
+                .with_label_formatter(self.layout.x_label_formatter().as_ref()),
+            )
         } else {
             None
         };
+        let y_offset_text = self
+            .layout
+            .y_label_formatter()
+            .and_then(|formatter| formatter.offset_text(&y_tick_layout.data_positions));
         let y_minor_ticks = Self::minor_tick_values_for_scale(
             &y_tick_layout.data_positions,
             y_min,
@@ -2668,26 +3900,16 @@ impl Plot {
         // Skip grid for non-Cartesian plots (Pie, Radar, Polar)
         let draw_axes = Self::needs_cartesian_axes_for_series(&self.series_mgr.series);
         if self.layout.grid_style.visible && draw_axes {
-            let grid_color = self.layout.grid_style.effective_color();
-            let grid_width_px = self.line_width_px(self.layout.grid_style.line_width);
-            let grid_y_pixels = Self::grid_tick_pixels(
+            let grid_style = &self.layout.grid_style;
+            let (grid_y_pixels, minor_grid_y_pixels) = Self::grid_tick_pixels(
                 &y_tick_layout.pixel_positions,
                 &y_minor_tick_pixels,
                 &self.layout.tick_config.grid_mode,
+                self.layout.tick_config.grid_axis.shows_y(),
             );
-            if bar_categories.is_some() {
+            let (grid_x_pixels, minor_grid_x_pixels) = if bar_categories.is_some() {
                 // For bar charts, only draw horizontal grid lines
-                svg.draw_grid(
-                    &[], // no vertical grid lines for bar charts
-                    &grid_y_pixels,
-                    plot_left,
-                    plot_right,
-                    plot_top,
-                    plot_bottom,
-                    grid_color,
-                    self.layout.grid_style.line_style.clone(),
-                    grid_width_px,
-                );
+                (&[][..], &[][..])
             } else {
                 // For other charts, compute X-axis ticks and draw full grid
                 let x_tick_layout = x_tick_layout.as_ref().ok_or_else(|| {
@@ -2695,33 +3917,43 @@ impl Plot {
                         "missing x tick layout for non-categorical SVG grid".to_string(),
                     )
                 })?;
-                let grid_x_pixels = Self::grid_tick_pixels(
+                Self::grid_tick_pixels(
                     &x_tick_layout.pixel_positions,
                     &x_minor_tick_pixels,
                     &self.layout.tick_config.grid_mode,
-                );
-                svg.draw_grid(
-                    &grid_x_pixels,
-                    &grid_y_pixels,
-                    plot_left,
-                    plot_right,
-                    plot_top,
-                    plot_bottom,
-                    grid_color,
-                    self.layout.grid_style.line_style.clone(),
-                    grid_width_px,
-                );
-            }
+                    self.layout.tick_config.grid_axis.shows_x(),
+                )
+            };
+            // Minor lines first so major lines stay on top
+            svg.draw_grid(
+                minor_grid_x_pixels,
+                minor_grid_y_pixels,
+                plot_left,
+                plot_right,
+                plot_top,
+                plot_bottom,
+                grid_style.effective_minor_color(),
+                grid_style.effective_minor_line_style(),
+                self.line_width_px(grid_style.minor_line_width),
+            );
+            svg.draw_grid(
+                grid_x_pixels,
+                grid_y_pixels,
+                plot_left,
+                plot_right,
+                plot_top,
+                plot_bottom,
+                grid_style.effective_color(),
+                grid_style.line_style.clone(),
+                self.line_width_px(grid_style.line_width),
+            );
         }
 
         if draw_axes && !self.layout.tick_config.enabled {
             let (axis_width, major_tick_size, minor_tick_size, major_tick_width, minor_tick_width) =
                 self.axis_tick_metrics_px();
-            svg.draw_axes_with_minor_ticks_styled(
-                plot_left,
-                plot_right,
-                plot_top,
-                plot_bottom,
+            svg.draw_axes_with_spine_origin(
+                (plot_left, plot_right, plot_top, plot_bottom),
                 &[],
                 &[],
                 &[],
@@ -2735,6 +3967,7 @@ impl Plot {
                 minor_tick_size,
                 major_tick_width,
                 minor_tick_width,
+                spine_origin,
             );
         }
 
@@ -2745,14 +3978,15 @@ impl Plot {
 
         // Draw axes and tick labels
         if draw_axes {
-            if let Some(categories) = bar_categories {
+            if let Some((categories, positions)) = &bar_categories {
                 let x_range = x_max - x_min;
-                let category_x_tick_positions: Vec<f32> = (0..categories.len())
-                    .map(|index| {
+                let category_x_tick_positions: Vec<f32> = positions
+                    .iter()
+                    .map(|&position| {
                         if x_range.abs() < f64::EPSILON {
                             plot_left + plot_width * 0.5
                         } else {
-                            plot_left + (((index as f64) - x_min) / x_range) as f32 * plot_width
+                            plot_left + ((position - x_min) / x_range) as f32 * plot_width
                         }
                     })
                     .collect();
@@ -2766,11 +4000,8 @@ impl Plot {
                         major_tick_width,
                         minor_tick_width,
                     ) = self.axis_tick_metrics_px();
-                    svg.draw_axes_with_minor_ticks_styled(
-                        plot_left,
-                        plot_right,
-                        plot_top,
-                        plot_bottom,
+                    svg.draw_axes_with_spine_origin(
+                        (plot_left, plot_right, plot_top, plot_bottom),
                         &category_x_tick_positions,
                         &y_tick_layout.pixel_positions,
                         &[],
@@ -2784,6 +4015,7 @@ impl Plot {
                         minor_tick_size,
                         major_tick_width,
                         minor_tick_width,
+                        spine_origin,
                     );
 
                     // Draw Y-axis tick labels
@@ -2801,16 +4033,36 @@ impl Plot {
                         self.display.theme.foreground,
                         tick_size_px,
                     )?;
+                    svg.draw_tick_offset_texts(
+                        None,
+                        y_offset_text.as_deref(),
+                        plot_left,
+                        plot_right,
+                        plot_top,
+                        layout.xtick_baseline_y,
+                        self.display.theme.foreground,
+                        tick_size_px,
+                    )?;
 
                     // Draw category labels on X-axis
                     for (category, &x) in categories.iter().zip(category_x_tick_positions.iter()) {
-                        svg.draw_text_centered(
-                            category,
-                            x,
-                            layout.xtick_baseline_y,
-                            tick_size_px,
-                            self.display.theme.foreground,
-                        )?;
+                        if self.layout.tick_config.x_label_rotation == 0.0 {
+                            svg.draw_text_centered(
+                                category,
+                                x,
+                                layout.xtick_baseline_y,
+                                tick_size_px,
+                                self.display.theme.foreground,
+                            )?;
+                        } else {
+                            svg.draw_xtick_label(
+                                category,
+                                x,
+                                layout.xtick_baseline_y,
+                                tick_size_px,
+                                self.display.theme.foreground,
+                            )?;
+                        }
                     }
                 }
             } else {
@@ -2828,11 +4080,8 @@ impl Plot {
                         major_tick_width,
                         minor_tick_width,
                     ) = self.axis_tick_metrics_px();
-                    svg.draw_axes_with_minor_ticks_styled(
-                        plot_left,
-                        plot_right,
-                        plot_top,
-                        plot_bottom,
+                    svg.draw_axes_with_spine_origin(
+                        (plot_left, plot_right, plot_top, plot_bottom),
                         &x_tick_layout.pixel_positions,
                         &y_tick_layout.pixel_positions,
                         &x_minor_tick_pixels,
@@ -2846,12 +4095,65 @@ impl Plot {
                         minor_tick_size,
                         major_tick_width,
                         minor_tick_width,
+                        spine_origin,
+                    );
+                    let x_minor_labeled = Self::labeled_log_minor_ticks(
+                        &x_tick_layout.data_positions,
+                        x_min,
+                        x_max,
+                        &self.layout.x_scale,
+                        &self.layout.tick_config.log_minor_labels_x,
+                    );
+                    let y_minor_labeled = Self::labeled_log_minor_ticks(
+                        &y_tick_layout.data_positions,
+                        y_min,
+                        y_max,
+                        &self.layout.y_scale,
+                        &self.layout.tick_config.log_minor_labels_y,
                     );
+                    let x_label_pixels: Vec<f32> = x_tick_layout
+                        .pixel_positions
+                        .iter()
+                        .copied()
+                        .chain(x_minor_labeled.iter().map(|&tick| {
+                            Self::scaled_x_pixel(
+                                tick,
+                                x_min,
+                                x_max,
+                                plot_area,
+                                &self.layout.x_scale,
+                            )
+                        }))
+                        .collect();
+                    let y_label_pixels: Vec<f32> = y_tick_layout
+                        .pixel_positions
+                        .iter()
+                        .copied()
+                        .chain(y_minor_labeled.iter().map(|&tick| {
+                            Self::scaled_y_pixel(
+                                tick,
+                                y_min,
+                                y_max,
+                                plot_area,
+                                &self.layout.y_scale,
+                            )
+                        }))
+                        .collect();
+                    let x_labels = [
+                        x_tick_layout.labels.clone(),
+                        self.layout.x_tick_labels(&x_minor_labeled),
+                    ]
+                    .concat();
+                    let y_labels = [
+                        y_tick_layout.labels.clone(),
+                        self.layout.y_tick_labels(&y_minor_labeled),
+                    ]
+                    .concat();
                     svg.draw_tick_labels(
-                        &x_tick_layout.pixel_positions,
-                        &x_tick_layout.labels,
-                        &y_tick_layout.pixel_positions,
-                        &y_tick_layout.labels,
+                        &x_label_pixels,
+                        &x_labels,
+                        &y_label_pixels,
+                        &y_labels,
                         plot_left,
                         plot_right,
                         plot_top,
@@ -2861,16 +4163,42 @@ impl Plot {
                         self.display.theme.foreground,
                         tick_size_px,
                     )?;
+                    let x_offset_text = self
+                        .layout
+                        .x_label_formatter()
+                        .and_then(|formatter| formatter.offset_text(&x_tick_layout.data_positions));
+                    svg.draw_tick_offset_texts(
+                        x_offset_text.as_deref(),
+                        y_offset_text.as_deref(),
+                        plot_left,
+                        plot_right,
+                        plot_top,
+                        layout.xtick_baseline_y,
+                        self.display.theme.foreground,
+                        tick_size_px,
+                    )?;
                 }
             }
         }
 
+        self.render_svg_annotations(
+            &mut svg,
+            AnnotationRenderLayer::Underlay,
+            false,
+            plot_area,
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+        )?;
+
         // Create clip path for data
         let clip_id = svg.add_clip_rect(plot_left, plot_top, plot_width, plot_height);
         svg.start_clip_group(&clip_id);
         self.render_svg_annotations(
             &mut svg,
             AnnotationRenderLayer::Underlay,
+            true,
             plot_area,
             x_min,
             x_max,
@@ -2886,9 +4214,12 @@ impl Plot {
             self.inset_rects_for_series(&self.series_mgr.series, plot_area, render_scale)?;
 
         // Render each series
-        for (idx, (series, resolved)) in
-            self.series_mgr.series.iter().zip(&frame.series).enumerate()
-        {
+        let mut progress = self
+            .render
+            .progress(frame.series.iter().map(Self::series_progress_work).sum());
+        for idx in PlotSeries::draw_order(&self.series_mgr.series) {
+            progress.check_cancelled()?;
+            let (series, resolved) = (&self.series_mgr.series[idx], &frame.series[idx]);
             let default_color = series
                 .color
                 .unwrap_or_else(|| self.display.theme.get_color(idx));
@@ -2935,11 +4266,14 @@ impl Plot {
                     series_bounds.3,
                 )?;
             }
+            progress.advance(Self::series_progress_work(resolved));
         }
+        progress.finish();
 
         self.render_svg_annotations(
             &mut svg,
             AnnotationRenderLayer::Overlay,
+            true,
             plot_area,
             x_min,
             x_max,
@@ -2947,6 +4281,17 @@ impl Plot {
             y_max,
         )?;
         svg.end_group(); // End clip group
+        self.render_svg_annotations(
+            &mut svg,
+            AnnotationRenderLayer::Overlay,
+            false,
+            plot_area,
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+        )?;
+        self.draw_insets_svg(&mut svg, plot_area, x_min, x_max, y_min, y_max)?;
 
         // Draw title/xlabel/ylabel using layout-computed positions.
         if let Some(ref pos) = layout.title_pos {
@@ -3030,10 +4375,67 @@ impl Plot {
     /// * `size` - Optional (width_mm, height_mm). If None, uses 160x120mm.
     #[cfg(all(feature = "pdf", not(target_arch = "wasm32")))]
     pub fn save_pdf_with_size<P: AsRef<Path>>(
-        mut self,
+        self,
+        path: P,
+        size: Option<(f64, f64)>,
+    ) -> Result<()> {
+        let svg_content = self.render_pdf_svg(size)?;
+        crate::export::svg_to_pdf_file(&svg_content, path)
+    }
+
+    /// Export to PDF with title, author, subject, and keyword metadata
+    ///
+    /// Produces the same page as [`Self::save_pdf`] and fills the PDF Info
+    /// dictionary from `metadata`, so viewers and repositories show the
+    /// document properties.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use ruviz::export::PdfMetadata;
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0])
+    ///     .title("Growth")
+    ///     .save_pdf_with_metadata(
+    ///         "growth.pdf",
+    ///         PdfMetadata {
+    ///             title: Some("Growth".to_string()),
+    ///             author: Some("Jane Doe".to_string()),
+    ///             ..Default::default()
+    ///         },
+    ///     )?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(all(feature = "pdf", not(target_arch = "wasm32")))]
+    pub fn save_pdf_with_metadata<P: AsRef<Path>>(
+        self,
+        path: P,
+        metadata: crate::export::PdfMetadata,
+    ) -> Result<()> {
+        self.save_pdf_with_size_and_metadata(path, None, metadata)
+    }
+
+    /// Export to PDF with a custom page size in millimeters and document
+    /// metadata
+    ///
+    /// Combines [`Self::save_pdf_with_size`] and
+    /// [`Self::save_pdf_with_metadata`].
+    #[cfg(all(feature = "pdf", not(target_arch = "wasm32")))]
+    pub fn save_pdf_with_size_and_metadata<P: AsRef<Path>>(
+        self,
         path: P,
         size: Option<(f64, f64)>,
+        metadata: crate::export::PdfMetadata,
     ) -> Result<()> {
+        let svg_content = self.render_pdf_svg(size)?;
+        crate::export::svg_to_pdf_file_with_metadata(&svg_content, path, &metadata)
+    }
+
+    /// Render the SVG that PDF export converts, sized to `size` in millimeters
+    /// (160x120mm if `None`)
+    #[cfg(feature = "pdf")]
+    pub(crate) fn render_pdf_svg(mut self, size: Option<(f64, f64)>) -> Result<String> {
         use crate::export::svg_to_pdf::page_sizes;
 
         self.validate_before_frame_resolution()?;
@@ -3047,11 +4449,9 @@ impl Plot {
 
         let frame = self.resolve_frame(0.0)?;
         let render_plot = self.resolved_style_shell(&frame.style);
-        let svg_content = render_plot.render_to_svg_with_frame(&frame)?;
-        let pdf_data = crate::export::svg_to_pdf(&svg_content)?;
-        crate::export::write_bytes_atomic(path, &pdf_data)?;
+        let svg_content = render_plot.render_to_svg_with_frame(&frame, SvgOptions::default())?;
         frame.acknowledge_rendered(&self);
-        Ok(())
+        Ok(svg_content)
     }
 
     // ==========================================================================
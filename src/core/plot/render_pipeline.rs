@@ -3,7 +3,11 @@
 //! This module provides the [`RenderPipeline`] struct which handles
 //! rendering configuration and backend selection for plots.
 
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use super::BackendType;
+use crate::core::{PlottingError, Result};
 
 #[cfg(feature = "parallel")]
 use crate::render::ParallelRenderer;
@@ -44,6 +48,12 @@ pub struct RenderPipeline {
     pub(crate) explicit_output_pixels: Option<(u32, u32)>,
     /// Allow positive child subplot canvases below the top-level dimension minimum.
     pub(crate) allow_subplot_dimensions: bool,
+    /// Flag that stops a render with [`PlottingError::Cancelled`] once set
+    pub(crate) cancel_flag: Option<Arc<AtomicBool>>,
+    /// Callback receiving the completed fraction of series drawing
+    pub(crate) progress_callback: Option<ProgressCallback>,
+    /// Anti-alias lines, markers, and fills in raster output
+    pub(crate) antialias: bool,
     /// Enable GPU acceleration for coordinate transformations
     #[cfg(feature = "gpu")]
     pub(crate) enable_gpu: bool,
@@ -68,6 +78,9 @@ impl RenderPipeline {
             allow_subminimum_dpi: false,
             explicit_output_pixels: None,
             allow_subplot_dimensions: false,
+            cancel_flag: None,
+            progress_callback: None,
+            antialias: true,
             #[cfg(feature = "gpu")]
             enable_gpu: false,
         }
@@ -141,6 +154,65 @@ impl RenderPipeline {
     pub fn pooled_renderer_mut(&mut self) -> Option<&mut crate::render::PooledRenderer> {
         self.pooled_renderer.as_mut()
     }
+
+    /// Start tracking progress over `total_work` units (usually data points)
+    pub(crate) fn progress(&self, total_work: usize) -> RenderProgress<'_> {
+        RenderProgress {
+            callback: self.progress_callback.as_ref(),
+            cancel_flag: self.cancel_flag.as_deref(),
+            total: total_work.max(1),
+            done: 0,
+        }
+    }
+}
+
+/// Callback invoked with the completed fraction of a render
+#[derive(Clone)]
+pub(crate) struct ProgressCallback(pub(crate) Arc<dyn Fn(f32) + Send + Sync>);
+
+impl std::fmt::Debug for ProgressCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ProgressCallback(..)")
+    }
+}
+
+/// Progress and cancellation state for one pass over a plot's series
+///
+/// Drawing loops call [`RenderProgress::check_cancelled`] before each unit
+/// of work and [`RenderProgress::advance`] after it.
+pub(crate) struct RenderProgress<'a> {
+    callback: Option<&'a ProgressCallback>,
+    cancel_flag: Option<&'a AtomicBool>,
+    total: usize,
+    done: usize,
+}
+
+impl RenderProgress<'_> {
+    /// Return [`PlottingError::Cancelled`] if the cancel flag has been set
+    pub(crate) fn check_cancelled(&self) -> Result<()> {
+        if self
+            .cancel_flag
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+        {
+            return Err(PlottingError::Cancelled);
+        }
+        Ok(())
+    }
+
+    /// Record `work` finished units and report the new fraction
+    pub(crate) fn advance(&mut self, work: usize) {
+        self.done = (self.done + work).min(self.total);
+        if let Some(callback) = self.callback {
+            (callback.0)(self.done as f32 / self.total as f32);
+        }
+    }
+
+    /// Mark all work finished
+    pub(crate) fn finish(&mut self) {
+        if self.done < self.total {
+            self.advance(self.total - self.done);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -174,6 +246,31 @@ mod tests {
         assert!(!pipeline.pooled_rendering_enabled());
     }
 
+    #[test]
+    fn test_render_progress_reports_fractions_and_cancellation() {
+        let reported = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&reported);
+        let mut pipeline = RenderPipeline::new();
+        pipeline.progress_callback = Some(ProgressCallback(Arc::new(move |fraction| {
+            sink.lock().unwrap().push(fraction)
+        })));
+        let cancel = Arc::new(AtomicBool::new(false));
+        pipeline.cancel_flag = Some(Arc::clone(&cancel));
+
+        let mut progress = pipeline.progress(4);
+        progress.advance(1);
+        assert!(progress.check_cancelled().is_ok());
+        progress.finish();
+        progress.finish();
+        assert_eq!(*reported.lock().unwrap(), vec![0.25, 1.0]);
+
+        cancel.store(true, Ordering::Relaxed);
+        assert!(matches!(
+            pipeline.progress(1).check_cancelled(),
+            Err(PlottingError::Cancelled)
+        ));
+    }
+
     #[test]
     fn test_auto_optimization() {
         let mut pipeline = RenderPipeline::new();
@@ -237,6 +237,14 @@ impl Plot {
             marker_style_source: None,
             marker_size: None,
             marker_size_source: None,
+            marker_fill: None,
+            marker_edge: None,
+            marker_every: None,
+            simplify: None,
+            downsample: None,
+            visible: true,
+            hatch: None,
+            zorder: None,
             alpha: None,
             alpha_source: None,
             y_errors: None,
@@ -245,6 +253,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         PlotSeriesBuilder::new(self, series)
@@ -312,6 +321,52 @@ impl Plot {
         )
     }
 
+    /// Add a scatter plot whose marker colors encode a third variable
+    ///
+    /// Each point's `c` value is normalized over the finite `c` range (or the
+    /// range set with `clim`) and sampled from `colormap`, like matplotlib's
+    /// `scatter(x, y, c=values, cmap=...)`. Points with a non-finite
+    /// coordinate or value are skipped. Enable `colorbar(true)` to show the
+    /// value scale next to the plot.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x: Vec<f64> = (0..200).map(|i| (i as f64 * 0.37).sin() * 10.0).collect();
+    /// let y: Vec<f64> = (0..200).map(|i| (i as f64 * 0.23).cos() * 10.0).collect();
+    /// let depth: Vec<f64> = x.iter().zip(&y).map(|(x, y)| x.hypot(*y)).collect();
+    ///
+    /// Plot::new()
+    ///     .scatter_mapped(&x, &y, &depth, ColorMap::plasma())
+    ///     .marker_size(6.0)
+    ///     .colorbar(true)
+    ///     .colorbar_label("Distance")
+    ///     .save("scatter_mapped.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn scatter_mapped<X, Y, C>(
+        self,
+        x_data: &X,
+        y_data: &Y,
+        c_data: &C,
+        colormap: crate::render::ColorMap,
+    ) -> PlotBuilder<crate::plots::ScatterMappedConfig>
+    where
+        X: NumericData1D,
+        Y: NumericData1D,
+        C: NumericData1D,
+    {
+        let mut plot = self;
+        let config = crate::plots::ScatterMappedConfig::new(colormap);
+        let x = plot.try_collect_numeric_input(x_data).unwrap_or_default();
+        let y = plot.try_collect_numeric_input(y_data).unwrap_or_default();
+        let c = plot.try_collect_numeric_input(c_data).unwrap_or_default();
+
+        PlotBuilder::new(plot, PlotInput::ColorMapped { x, y, c }, config)
+    }
+
     /// Add a scatter series from source-backed data.
     ///
     /// With `Observable<Vec<f64>>` inputs, [`Observable::set`](crate::data::Observable::set)
@@ -373,6 +428,14 @@ impl Plot {
             marker_style_source: None,
             marker_size: None,
             marker_size_source: None,
+            marker_fill: None,
+            marker_edge: None,
+            marker_every: None,
+            simplify: None,
+            downsample: None,
+            visible: true,
+            hatch: None,
+            zorder: None,
             alpha: None,
             alpha_source: None,
             y_errors: None,
@@ -381,6 +444,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         PlotSeriesBuilder::new(self, series)
@@ -469,6 +533,154 @@ impl Plot {
         )
     }
 
+    /// Add a line with a shaded band between `lower` and `upper`.
+    ///
+    /// This is the usual way to show a confidence interval or min/max envelope
+    /// around a curve. The band is stored as a fill-between annotation drawn
+    /// underneath the line in the line's color with reduced alpha, and the
+    /// series contributes a single legend entry.
+    ///
+    /// The band color is taken from the line when the series is finalized:
+    /// either the explicit [`color`](PlotBuilder::color) or the next palette
+    /// color of the current theme.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x = vec![0.0, 1.0, 2.0, 3.0];
+    /// let y = vec![1.0, 2.0, 1.5, 2.5];
+    /// let lower: Vec<f64> = y.iter().map(|v| v - 0.3).collect();
+    /// let upper: Vec<f64> = y.iter().map(|v| v + 0.3).collect();
+    ///
+    /// Plot::new()
+    ///     .line_with_band(&x, &y, &lower, &upper)
+    ///     .label("mean ± sd")
+    ///     .save("band.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn line_with_band<X, Y, L, U>(
+        self,
+        x_data: &X,
+        y_data: &Y,
+        lower: &L,
+        upper: &U,
+    ) -> PlotBuilder<crate::plots::basic::LineConfig>
+    where
+        X: NumericData1D,
+        Y: NumericData1D,
+        L: NumericData1D,
+        U: NumericData1D,
+    {
+        let (plot, x, y) = self.collect_xy_for_derived_series(x_data, y_data);
+        let (plot, _, lower) = plot.collect_xy_for_derived_series(x_data, lower);
+        let (plot, _, upper) = plot.collect_xy_for_derived_series(x_data, upper);
+
+        PlotBuilder::new(
+            plot,
+            PlotInput::XYBand { x, y, lower, upper },
+            crate::plots::basic::LineConfig::default(),
+        )
+    }
+
+    /// Add a noisy line with its centered moving average drawn on top.
+    ///
+    /// The raw data is drawn as a faint line (30% of the series alpha) and the
+    /// trend as a regular line in the same color, so styling such as
+    /// [`color`](PlotBuilder::color) and [`label`](PlotBuilder::label) applies
+    /// to both and the pair contributes a single legend entry. The trend is a
+    /// sliding-window mean over `window` samples that shrinks at the ends; see
+    /// [`moving_average`](crate::stats::moving_average).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x: Vec<f64> = (0..200).map(|i| i as f64).collect();
+    /// let y: Vec<f64> = x.iter().map(|v| (v * 0.05).sin() + (v * 1.7).sin() * 0.3).collect();
+    ///
+    /// Plot::new()
+    ///     .line_smoothed(&x, &y, 15)
+    ///     .label("sensor")
+    ///     .save("smoothed.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn line_smoothed<X, Y>(
+        self,
+        x_data: &X,
+        y_data: &Y,
+        window: usize,
+    ) -> PlotBuilder<crate::plots::basic::LineConfig>
+    where
+        X: NumericData1D,
+        Y: NumericData1D,
+    {
+        let (plot, x, raw) = self.collect_xy_for_derived_series(x_data, y_data);
+        let y = crate::stats::moving_average(&raw, window);
+
+        PlotBuilder::new(
+            plot,
+            PlotInput::XYSmoothed { x, y, raw },
+            crate::plots::basic::LineConfig::default(),
+        )
+    }
+
+    /// Add the ordinary least-squares fit line of `y` on `x`.
+    ///
+    /// The line spans the range of the finite data points; pairs with a
+    /// non-finite value are left out of the fit. Draw the points themselves
+    /// with [`scatter`](Self::scatter). [`fit_label`](PlotBuilder::fit_label)
+    /// labels the line with its equation and R², and
+    /// [`confidence_band`](PlotBuilder::confidence_band) shades the approximate
+    /// 95% confidence band around it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let y = vec![2.1, 3.9, 6.2, 7.8, 10.1];
+    ///
+    /// Plot::new()
+    ///     .scatter(&x, &y)
+    ///     .end_series()
+    ///     .regression(&x, &y)
+    ///     .fit_label()
+    ///     .confidence_band()
+    ///     .save("regression.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn regression<X, Y>(
+        self,
+        x_data: &X,
+        y_data: &Y,
+    ) -> PlotBuilder<crate::plots::basic::LineConfig>
+    where
+        X: NumericData1D,
+        Y: NumericData1D,
+    {
+        let (plot, x, y) = self.collect_xy_for_derived_series(x_data, y_data);
+        let (x, y): (Vec<f64>, Vec<f64>) = x
+            .iter()
+            .zip(&y)
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .unzip();
+        let fit = crate::plots::regression::compute_regplot(
+            &x,
+            &y,
+            &crate::plots::regression::RegPlotConfig::default(),
+        );
+
+        PlotBuilder::new(
+            plot,
+            PlotInput::XYFit { fit, band: false },
+            crate::plots::basic::LineConfig::default(),
+        )
+    }
+
     /// Add a stem plot with vertical stems from `baseline` to each point.
     ///
     /// Stems are rendered as annotation line segments and point heads are stored
@@ -574,6 +786,54 @@ impl Plot {
         )
     }
 
+    /// Add a bar chart with symmetric error bars on each bar
+    ///
+    /// Each error bar is a whisker with caps centered on the top of its bar,
+    /// extending `errors[i]` above and below `values[i]`. The Y axis range
+    /// grows to include the whiskers. Whisker styling follows
+    /// [`error_config`](PlotBuilder::error_config).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let categories = vec!["Control", "Treatment A", "Treatment B"];
+    /// let means = vec![4.2, 5.8, 6.5];
+    /// let std_devs = vec![0.4, 0.6, 0.3];
+    ///
+    /// Plot::new()
+    ///     .bar_with_errors(&categories, &means, &std_devs)
+    ///     .ylabel("Response")
+    ///     .save("bar_errors.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bar_with_errors<S, V, E>(
+        self,
+        categories: &[S],
+        values: &V,
+        errors: &E,
+    ) -> PlotBuilder<crate::plots::basic::BarConfig>
+    where
+        S: ToString,
+        V: NumericData1D,
+        E: NumericData1D,
+    {
+        let mut builder = self.bar(categories, values).with_yerr(errors);
+        if let (PlotInput::Categorical { values, .. }, Some(y_errors)) =
+            (&builder.input, &builder.style.y_errors)
+            && y_errors.len() != values.len()
+        {
+            let err = PlottingError::DataLengthMismatch {
+                x_len: values.len(),
+                y_len: y_errors.len(),
+                series_index: None,
+            };
+            builder.plot.set_pending_ingestion_error(err);
+        }
+        builder
+    }
+
     /// Add a bar series from source-backed values.
     pub fn bar_source<S, V>(
         self,
@@ -651,6 +911,14 @@ impl Plot {
             marker_style_source: None,
             marker_size: None,
             marker_size_source: None,
+            marker_fill: None,
+            marker_edge: None,
+            marker_every: None,
+            simplify: None,
+            downsample: None,
+            visible: true,
+            hatch: None,
+            zorder: None,
             alpha: None,
             alpha_source: None,
             y_errors: None,
@@ -659,6 +927,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         PlotSeriesBuilder::new(plot, series)
@@ -688,6 +957,14 @@ impl Plot {
             marker_style_source: None,
             marker_size: None,
             marker_size_source: None,
+            marker_fill: None,
+            marker_edge: None,
+            marker_every: None,
+            simplify: None,
+            downsample: None,
+            visible: true,
+            hatch: None,
+            zorder: None,
             alpha: None,
             alpha_source: None,
             y_errors: None,
@@ -696,6 +973,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         PlotSeriesBuilder::new(self, series)
@@ -755,6 +1033,14 @@ impl Plot {
             marker_style_source: None,
             marker_size: None,
             marker_size_source: None,
+            marker_fill: None,
+            marker_edge: None,
+            marker_every: None,
+            simplify: None,
+            downsample: None,
+            visible: true,
+            hatch: None,
+            zorder: None,
             alpha: None,
             alpha_source: None,
             y_errors: None,
@@ -763,11 +1049,50 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         PlotSeriesBuilder::new(plot, series)
     }
 
+    /// Add one box plot per labeled group, side by side.
+    ///
+    /// Group `i` is drawn at x = `i` with its label as the X-axis category,
+    /// so several distributions can be compared in one axes. `config` is
+    /// applied to every box.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let control = vec![4.1, 4.8, 5.0, 5.3, 5.9, 6.2];
+    /// let treated = vec![5.5, 6.1, 6.4, 7.0, 7.2, 9.8];
+    ///
+    /// Plot::new()
+    ///     .boxplots(&[("Control", control), ("Treated", treated)], None)
+    ///     .ylabel("Response")
+    ///     .save("boxplots.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn boxplots<S, D>(self, groups: &[(S, D)], config: Option<BoxPlotConfig>) -> Self
+    where
+        S: AsRef<str>,
+        D: NumericData1D,
+    {
+        let config = config.unwrap_or_default();
+        groups
+            .iter()
+            .enumerate()
+            .fold(self, |plot, (index, (label, data))| {
+                let group_config = config
+                    .clone()
+                    .category(label.as_ref())
+                    .x_position(index as f64);
+                plot.boxplot(data, Some(group_config)).into()
+            })
+    }
+
     /// Add a box plot series from source-backed values.
     pub fn boxplot_source<D: IntoPlotData>(
         self,
@@ -791,6 +1116,14 @@ impl Plot {
             marker_style_source: None,
             marker_size: None,
             marker_size_source: None,
+            marker_fill: None,
+            marker_edge: None,
+            marker_every: None,
+            simplify: None,
+            downsample: None,
+            visible: true,
+            hatch: None,
+            zorder: None,
             alpha: None,
             alpha_source: None,
             y_errors: None,
@@ -799,6 +1132,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         PlotSeriesBuilder::new(self, series)
@@ -868,6 +1202,14 @@ impl Plot {
                     marker_style_source: None,
                     marker_size: None,
                     marker_size_source: None,
+                    marker_fill: None,
+                    marker_edge: None,
+                    marker_every: None,
+                    simplify: None,
+                    downsample: None,
+                    visible: true,
+                    hatch: None,
+                    zorder: None,
                     alpha: None,
                     alpha_source: None,
                     y_errors: None,
@@ -876,6 +1218,7 @@ impl Plot {
                     inset_layout: None,
                     group_id: None,
                     resolved_radar_colors: None,
+                    band_annotation: None,
                 };
                 PlotSeriesBuilder::new(self, series)
             }
@@ -913,6 +1256,14 @@ impl Plot {
                     marker_style_source: None,
                     marker_size: None,
                     marker_size_source: None,
+                    marker_fill: None,
+                    marker_edge: None,
+                    marker_every: None,
+                    simplify: None,
+                    downsample: None,
+                    visible: true,
+                    hatch: None,
+                    zorder: None,
                     alpha: None,
                     alpha_source: None,
                     y_errors: None,
@@ -921,12 +1272,165 @@ impl Plot {
                     inset_layout: None,
                     group_id: None,
                     resolved_radar_colors: None,
+                    band_annotation: None,
                 };
                 PlotSeriesBuilder::new(self, series)
             }
         }
     }
 
+    /// Add a correlation matrix heatmap of named variables
+    ///
+    /// Computes pairwise Pearson correlations (see
+    /// [`correlation_matrix`](crate::stats::correlation_matrix)) and draws
+    /// them with square cells and the diverging `coolwarm` colormap centered
+    /// at 0 over -1..1. Both axes are labeled with the variable names, the
+    /// first variable in the top-left corner, and each cell is annotated with
+    /// its coefficient. All variables must have the same length. Axes keep
+    /// at most ten ticks, so larger matrices name only some variables.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let height = vec![150.0, 160.0, 170.0, 180.0, 190.0];
+    /// let weight = vec![50.0, 58.0, 69.0, 77.0, 90.0];
+    /// let age = vec![40.0, 25.0, 33.0, 51.0, 29.0];
+    ///
+    /// Plot::new()
+    ///     .title("Correlations")
+    ///     .corr_matrix(&[("height", &height), ("weight", &weight), ("age", &age)])
+    ///     .save("corr_matrix.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn corr_matrix<S, D>(mut self, variables: &[(S, D)]) -> PlotSeriesBuilder
+    where
+        S: AsRef<str>,
+        D: NumericData1D,
+    {
+        let names: Vec<String> = variables
+            .iter()
+            .map(|(name, _)| name.as_ref().to_string())
+            .collect();
+        let columns: Vec<Vec<f64>> = variables
+            .iter()
+            .map(|(_, data)| self.collect_numeric_input(data))
+            .collect();
+        let expected_len = columns.first().map_or(0, Vec::len);
+        if let Some(mismatch) = columns
+            .iter()
+            .map(Vec::len)
+            .find(|&len| len != expected_len)
+        {
+            self.set_pending_ingestion_error(PlottingError::DataLengthMismatch {
+                x_len: expected_len,
+                y_len: mismatch,
+                series_index: None,
+            });
+        }
+
+        // Cell centers sit on integers so each tick names one variable
+        let n = names.len();
+        let edge = n as f64 - 0.5;
+        let config = crate::plots::heatmap::HeatmapConfig::new()
+            .colormap(crate::render::ColorMap::coolwarm())
+            .vmin(-1.0)
+            .vmax(1.0)
+            .center(0.0)
+            .extent(-0.5, edge, -0.5, edge)
+            .square_cells(true);
+        let matrix = crate::stats::correlation_matrix(&columns);
+
+        if let Ok(data) = crate::plots::heatmap::process_heatmap(&matrix, config.clone()) {
+            for (row, values) in matrix.iter().enumerate() {
+                for (col, &r) in values.iter().enumerate() {
+                    if data.should_mask_value(r) {
+                        continue;
+                    }
+                    let style = TextStyle::new().color(data.get_text_color(data.get_color(r)));
+                    self = self.annotate(Annotation::text_styled(
+                        col as f64,
+                        (n - 1 - row) as f64,
+                        format!("{r:.2}"),
+                        style,
+                    ));
+                }
+            }
+        }
+
+        let label = |names: Vec<String>, flip: bool| {
+            move |value: f64| {
+                let index = value.round();
+                if (value - index).abs() > 1e-9 || index < 0.0 || index >= names.len() as f64 {
+                    return String::new();
+                }
+                let index = index as usize;
+                names[if flip { names.len() - 1 - index } else { index }].clone()
+            }
+        };
+        self.xaxis_integer(true)
+            .yaxis_integer(true)
+            .major_ticks_x(n + 1)
+            .major_ticks_y(n + 1)
+            .xtick_formatter(label(names.clone(), false))
+            .ytick_formatter(label(names, true))
+            .heatmap(&matrix, Some(config))
+    }
+
+    /// Add a 2D histogram: a heatmap of point counts on an `nx` by `ny` grid
+    ///
+    /// The grid spans the finite `(x, y)` points in data coordinates (see
+    /// [`calculate_histogram_2d`](crate::plots::calculate_histogram_2d)), so
+    /// it lines up with other series on the same axes, and cells are colored
+    /// by count through `colormap` with a colorbar labeled "Count".
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x: Vec<f64> = (0..1000).map(|i| (i as f64 * 0.37).sin()).collect();
+    /// let y: Vec<f64> = (0..1000).map(|i| (i as f64 * 0.23).cos() * 2.0).collect();
+    ///
+    /// Plot::new()
+    ///     .hist2d(&x, &y, (40, 30), ColorMap::viridis())
+    ///     .save("hist2d.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn hist2d<X, Y>(
+        self,
+        x_data: &X,
+        y_data: &Y,
+        bins: (usize, usize),
+        colormap: crate::render::ColorMap,
+    ) -> PlotSeriesBuilder
+    where
+        X: NumericData1D,
+        Y: NumericData1D,
+    {
+        let (mut plot, x, y) = self.collect_xy_for_derived_series(x_data, y_data);
+        let hist = match crate::plots::calculate_histogram_2d(&x, &y, bins) {
+            Ok(hist) => hist,
+            Err(err) => {
+                plot.set_pending_ingestion_error(err);
+                crate::plots::Histogram2D {
+                    counts: vec![vec![0.0]],
+                    x_range: (0.0, 1.0),
+                    y_range: (0.0, 1.0),
+                }
+            }
+        };
+
+        let ((xmin, xmax), (ymin, ymax)) = (hist.x_range, hist.y_range);
+        let config = crate::plots::heatmap::HeatmapConfig::new()
+            .colormap(colormap)
+            .colorbar_label("Count")
+            .extent(xmin, xmax, ymin, ymax)
+            .origin(crate::plots::heatmap::HeatmapOrigin::Lower);
+        plot.heatmap(&hist.counts, Some(config))
+    }
+
     /// Add error bars (Y-direction only)
     pub fn error_bars<X, Y, E>(self, x_data: &X, y_data: &Y, y_errors: &E) -> PlotSeriesBuilder
     where
@@ -975,6 +1479,14 @@ impl Plot {
             marker_style_source: None,
             marker_size: None,
             marker_size_source: None,
+            marker_fill: None,
+            marker_edge: None,
+            marker_every: None,
+            simplify: None,
+            downsample: None,
+            visible: true,
+            hatch: None,
+            zorder: None,
             alpha: None,
             alpha_source: None,
             y_errors: None,
@@ -983,6 +1495,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         PlotSeriesBuilder::new(plot, series)
@@ -1013,6 +1526,14 @@ impl Plot {
             marker_style_source: None,
             marker_size: None,
             marker_size_source: None,
+            marker_fill: None,
+            marker_edge: None,
+            marker_every: None,
+            simplify: None,
+            downsample: None,
+            visible: true,
+            hatch: None,
+            zorder: None,
             alpha: None,
             alpha_source: None,
             y_errors: None,
@@ -1021,11 +1542,62 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         PlotSeriesBuilder::new(self, series)
     }
 
+    /// Add Y error bars with separate lower and upper extents
+    ///
+    /// Each whisker runs from `y - y_lower` to `y + y_upper`, as for an
+    /// asymmetric confidence interval, and the axis limits cover both ends.
+    /// This is [`error_bars`](Self::error_bars) with
+    /// [`with_yerr_asymmetric`](PlotSeriesBuilder::with_yerr_asymmetric) applied.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x = vec![1.0, 2.0, 3.0];
+    /// let y = vec![5.0, 7.0, 6.0];
+    /// let lower = vec![0.5, 0.3, 0.4];
+    /// let upper = vec![1.5, 2.0, 0.9];
+    ///
+    /// Plot::new()
+    ///     .error_bars_asym(&x, &y, &lower, &upper)
+    ///     .label("median and 95% CI")
+    ///     .save("asymmetric_error_bars.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn error_bars_asym<X, Y, L, U>(
+        mut self,
+        x_data: &X,
+        y_data: &Y,
+        y_lower: &L,
+        y_upper: &U,
+    ) -> PlotSeriesBuilder
+    where
+        X: NumericData1D,
+        Y: NumericData1D,
+        L: NumericData1D,
+        U: NumericData1D,
+    {
+        let lower = self.collect_numeric_input(y_lower);
+        let upper = self.collect_numeric_input(y_upper);
+        if lower.len() != upper.len() {
+            self.set_pending_ingestion_error(PlottingError::DataLengthMismatch {
+                x_len: lower.len(),
+                y_len: upper.len(),
+                series_index: None,
+            });
+        }
+
+        self.error_bars(x_data, y_data, &lower)
+            .with_yerr_asymmetric(&lower, &upper)
+    }
+
     /// Add error bars in both X and Y directions
     pub fn error_bars_xy<X, Y, EX, EY>(
         self,
@@ -1089,6 +1661,14 @@ impl Plot {
             marker_style_source: None,
             marker_size: None,
             marker_size_source: None,
+            marker_fill: None,
+            marker_edge: None,
+            marker_every: None,
+            simplify: None,
+            downsample: None,
+            visible: true,
+            hatch: None,
+            zorder: None,
             alpha: None,
             alpha_source: None,
             y_errors: None,
@@ -1097,6 +1677,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         PlotSeriesBuilder::new(plot, series)
@@ -1135,6 +1716,14 @@ impl Plot {
             marker_style_source: None,
             marker_size: None,
             marker_size_source: None,
+            marker_fill: None,
+            marker_edge: None,
+            marker_every: None,
+            simplify: None,
+            downsample: None,
+            visible: true,
+            hatch: None,
+            zorder: None,
             alpha: None,
             alpha_source: None,
             y_errors: None,
@@ -1143,6 +1732,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         PlotSeriesBuilder::new(self, series)
@@ -1193,6 +1783,88 @@ impl Plot {
         )
     }
 
+    /// Start building a rug plot
+    ///
+    /// Draws a short tick along the bottom edge of the plot area at each value,
+    /// like seaborn's `rugplot`, to show raw sample locations next to a KDE or
+    /// histogram. Ticks are 5% of the plot height by default; use `height` to
+    /// change that and `axis` to mark the left edge instead. Non-finite values
+    /// are skipped, and the ticks do not extend the y-axis range.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let data = vec![1.2, 1.9, 2.1, 2.4, 2.8, 3.3, 3.5, 4.1, 5.0];
+    ///
+    /// Plot::new()
+    ///     .kde(&data)
+    ///     .rug(&data)
+    ///     .height(0.03)
+    ///     .save("kde_with_rug.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn rug<T, D: Data1D<T>>(self, data: &D) -> PlotBuilder<crate::plots::RugConfig>
+    where
+        T: Into<f64> + Copy,
+    {
+        let mut data_vec = Vec::with_capacity(data.len());
+        for i in 0..data.len() {
+            if let Some(val) = data.get(i) {
+                data_vec.push((*val).into());
+            }
+        }
+
+        PlotBuilder::new(
+            self,
+            PlotInput::Single(data_vec),
+            crate::plots::RugConfig::default(),
+        )
+    }
+
+    /// Start building a strip plot of labeled groups
+    ///
+    /// Group `i` is drawn as a scatter at x = `i` with its label as the X-axis
+    /// category, each point shifted by a uniform jitter in
+    /// `-jitter_width..=jitter_width` so that samples do not overplot. The
+    /// jitter is capped at half the category spacing and comes from a seeded
+    /// generator, so the same data always yields the same figure; use `seed`
+    /// to pick another layout. Non-finite values are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let control = vec![4.1, 4.8, 5.0, 5.3, 5.9, 6.2];
+    /// let treated = vec![5.5, 6.1, 6.4, 7.0, 7.2, 9.8];
+    ///
+    /// Plot::new()
+    ///     .boxplots(&[("Control", &control), ("Treated", &treated)], None)
+    ///     .strip(&[("Control", &control), ("Treated", &treated)], 0.15)
+    ///     .seed(7)
+    ///     .save("strip.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn strip<S, D>(
+        mut self,
+        groups: &[(S, D)],
+        jitter_width: f64,
+    ) -> PlotBuilder<crate::plots::categorical::StripConfig>
+    where
+        S: AsRef<str>,
+        D: NumericData1D,
+    {
+        let groups = groups
+            .iter()
+            .map(|(label, data)| (label.as_ref().to_string(), self.collect_numeric_input(data)))
+            .collect();
+        let config = crate::plots::categorical::StripConfig::default().jitter(2.0 * jitter_width);
+
+        PlotBuilder::new(self, PlotInput::Groups(groups), config)
+    }
+
     /// Start building an ECDF (Empirical Cumulative Distribution Function) plot
     ///
     /// Returns a `PlotBuilder<EcdfConfig>` for configuring the ECDF plot.
@@ -1286,6 +1958,85 @@ impl Plot {
         )
     }
 
+    /// Add iso-level contour lines for a 2D grid at explicit levels
+    ///
+    /// Grid rows map to y and columns map to x, using the cell indices as
+    /// coordinates (`x = 0..cols`, `y = 0..rows`). Each level is traced with
+    /// marching squares and colored by the contour colormap. Pass an empty
+    /// `levels` slice to fall back to automatically spaced levels.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let grid: Vec<Vec<f64>> = (0..40)
+    ///     .map(|i| (0..40).map(|j| ((i as f64) / 6.0).sin() * ((j as f64) / 6.0).cos()).collect())
+    ///     .collect();
+    ///
+    /// Plot::new()
+    ///     .contour_grid(&grid, &[-0.5, 0.0, 0.5])
+    ///     .save("contour_grid.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn contour_grid<D>(
+        mut self,
+        grid: &D,
+        levels: &[f64],
+    ) -> PlotBuilder<crate::plots::ContourConfig>
+    where
+        D: NumericData2D + ?Sized,
+    {
+        let (flat, rows, cols) = match collect_numeric_data_2d(grid) {
+            Ok(values) => values,
+            Err(err) => {
+                self.set_pending_ingestion_error(err);
+                (vec![], 0, 0)
+            }
+        };
+        let x: Vec<f64> = (0..cols).map(|i| i as f64).collect();
+        let y: Vec<f64> = (0..rows).map(|j| j as f64).collect();
+        let z: Vec<Vec<f64>> = flat.chunks(cols.max(1)).map(<[f64]>::to_vec).collect();
+
+        let mut config = crate::plots::ContourConfig::default();
+        if !levels.is_empty() {
+            config.levels = Some(levels.to_vec());
+        }
+
+        PlotBuilder::new(self, PlotInput::Grid2D { x, y, z }, config)
+    }
+
+    /// Add filled contours for a 2D grid at explicit levels
+    ///
+    /// Same as [`Plot::contour_grid`] but fills the regions between adjacent
+    /// levels with the colormap color of each band.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let grid: Vec<Vec<f64>> = (0..40)
+    ///     .map(|i| (0..40).map(|j| ((i * j) as f64 / 200.0).sin()).collect())
+    ///     .collect();
+    ///
+    /// Plot::new()
+    ///     .contourf_grid(&grid, &[-1.0, -0.5, 0.0, 0.5, 1.0])
+    ///     .colorbar(true)
+    ///     .save("contourf_grid.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn contourf_grid<D>(
+        self,
+        grid: &D,
+        levels: &[f64],
+    ) -> PlotBuilder<crate::plots::ContourConfig>
+    where
+        D: NumericData2D + ?Sized,
+    {
+        self.contour_grid(grid, levels).filled(true)
+    }
+
     /// Add a pie chart for proportional data visualization
     ///
     /// Creates a pie chart with optional labels, exploded segments, and donut style.
@@ -1531,6 +2282,50 @@ impl Plot {
         )
     }
 
+    /// Add a hexagonal binning plot for dense 2D point clouds
+    ///
+    /// Points are assigned to hexagonal cells, `gridsize` hexagons across the
+    /// x-axis, and each non-empty cell is filled with the colormap color of
+    /// its point count. Non-finite points are skipped. Hexbin is a common
+    /// replacement for overplotted scatter plots.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x: Vec<f64> = (0..10_000).map(|i| ((i * 7919) % 1000) as f64 / 100.0).collect();
+    /// let y: Vec<f64> = x.iter().map(|v| v.sin() + (v * 13.0).cos() * 0.3).collect();
+    ///
+    /// Plot::new()
+    ///     .hexbin(&x, &y, 30)
+    ///     .colorbar(true)
+    ///     .colorbar_label("Count")
+    ///     .save("hexbin.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn hexbin<X, Y>(
+        self,
+        x_data: &X,
+        y_data: &Y,
+        gridsize: usize,
+    ) -> PlotBuilder<crate::plots::HexbinConfig>
+    where
+        X: NumericData1D,
+        Y: NumericData1D,
+    {
+        let mut plot = self;
+        let config = crate::plots::HexbinConfig::default().gridsize(gridsize);
+        let Some(x) = plot.try_collect_numeric_input(x_data) else {
+            return PlotBuilder::new(plot, PlotInput::XY(Vec::new(), Vec::new()), config);
+        };
+        let Some(y) = plot.try_collect_numeric_input(y_data) else {
+            return PlotBuilder::new(plot, PlotInput::XY(x, Vec::new()), config);
+        };
+
+        PlotBuilder::new(plot, PlotInput::XY(x, y), config)
+    }
+
     /// Add a Polar line plot for visualizing data in polar coordinates
     ///
     /// Creates a polar plot with r (radius) and theta (angle in radians) data.
@@ -1580,4 +2375,40 @@ impl Plot {
             crate::plots::PolarPlotConfig::default(),
         )
     }
+
+    /// Add a Polar scatter plot (markers only, no connecting lines)
+    ///
+    /// Same as [`Plot::polar_line`] but draws each point as a marker. Useful for
+    /// inherently angular observations such as wind direction or phase.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - Radius values (distance from center)
+    /// * `theta` - Angle values in radians (see `theta_unit` for degrees)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let direction_deg = vec![0.0, 45.0, 90.0, 200.0, 300.0];
+    /// let speed = vec![3.2, 5.1, 2.4, 6.8, 4.0];
+    ///
+    /// Plot::new()
+    ///     .title("Wind")
+    ///     .polar_scatter(&speed, &direction_deg)
+    ///     .theta_unit(ThetaUnit::Degrees)
+    ///     .save("wind.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn polar_scatter<R, T>(self, r: &R, theta: &T) -> PlotBuilder<crate::plots::PolarPlotConfig>
+    where
+        R: Data1D<f64>,
+        T: Data1D<f64>,
+    {
+        let mut builder = self.polar_line(r, theta);
+        builder.config.show_line = false;
+        builder.config.marker_size = 6.0;
+        builder
+    }
 }
@@ -490,6 +490,56 @@ impl PlotSeriesBuilder {
         self
     }
 
+    /// Set marker face color (`None` draws hollow markers)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// Plot::new()
+    ///     .scatter(&[1.0, 2.0, 3.0], &[1.0, 4.0, 9.0])
+    ///     .marker_fill(None)
+    ///     .marker_edge(Color::RED, 1.5)
+    ///     .end_series()
+    ///     .save("hollow_markers.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn marker_fill(mut self, fill: Option<Color>) -> Self {
+        self.series.marker_fill = Some(fill);
+        self
+    }
+
+    /// Set marker edge color and width in points
+    pub fn marker_edge(mut self, color: Color, width: f32) -> Self {
+        self.series.marker_edge = Some((color, width.max(0.0)));
+        self
+    }
+
+    /// Draw a marker on every nth data point only (for line plots)
+    pub fn markevery(mut self, n: usize) -> Self {
+        self.series.marker_every = Some(n.max(1));
+        self
+    }
+
+    /// Simplify the drawn line within `tolerance_px` pixels (for line plots)
+    pub fn simplify(mut self, tolerance_px: f32) -> Self {
+        self.series.simplify = (tolerance_px > 0.0).then_some(tolerance_px);
+        self
+    }
+
+    /// Downsample the drawn line per pixel column (for line plots)
+    pub fn downsample(mut self, mode: DownsampleMode) -> Self {
+        self.series.downsample = Some(mode);
+        self
+    }
+
+    /// Draw a hatch pattern over filled bars (for bar plots)
+    pub fn hatch(mut self, pattern: HatchPattern) -> Self {
+        self.series.hatch = Some(pattern);
+        self
+    }
+
     /// Set transparency
     ///
     /// Values range from 0.0 (fully transparent) to 1.0 (fully opaque).
@@ -521,6 +571,36 @@ impl PlotSeriesBuilder {
         self
     }
 
+    /// Set the draw order of this series
+    ///
+    /// Series with a higher z-order are drawn on top. Series share z-order 0
+    /// by default and ties keep insertion order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x = [1.0, 2.0, 3.0];
+    /// Plot::new()
+    ///     .line(&x, &[1.0, 2.0, 3.0])
+    ///     .zorder(1)  // Keep the fit line above the scatter
+    ///     .scatter(&x, &[1.1, 1.9, 3.2])
+    ///     .end_series()
+    ///     .save("fit_on_top.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn zorder(mut self, zorder: i32) -> Self {
+        self.series.zorder = Some(zorder);
+        self
+    }
+
+    /// Show or hide the series; hidden series keep a faded legend entry
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.series.visible = visible;
+        self
+    }
+
     // ========== Error Bar Modifier Methods ==========
 
     /// Attach symmetric Y error bars to this series
@@ -871,6 +951,23 @@ impl PlotSeriesBuilder {
         self
     }
 
+    /// Set a title drawn in bold above the legend entries
+    pub fn legend_title(mut self, title: impl Into<String>) -> Self {
+        self.plot.layout.legend.title = Some(title.into());
+        self
+    }
+
+    /// Add a legend entry that is not tied to a series
+    pub fn legend_entry(
+        mut self,
+        label: impl Into<String>,
+        item_type: LegendItemType,
+        color: Color,
+    ) -> Self {
+        self.plot = self.plot.legend_entry(label, item_type, color);
+        self
+    }
+
     /// Enable/disable grid
     pub fn grid(mut self, enabled: bool) -> Self {
         self.plot.layout.grid_style.visible = enabled;
@@ -932,6 +1029,12 @@ impl PlotSeriesBuilder {
         self.end_series().save(path)
     }
 
+    /// Render the plot and encode it into an in-memory buffer
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_to_buffer(self, format: crate::export::ImageFormat) -> Result<Vec<u8>> {
+        self.end_series().save_to_buffer(format)
+    }
+
     /// Save the plot to file with custom dimensions
     #[cfg(not(target_arch = "wasm32"))]
     pub fn save_with_size<P: AsRef<Path>>(
@@ -944,6 +1047,12 @@ impl PlotSeriesBuilder {
         self.end_series().save(path)
     }
 
+    /// Save the plot to a PNG file at a multiple of the DPI
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_hires<P: AsRef<Path>>(self, path: P, scale: f32) -> Result<()> {
+        self.end_series().save_hires(path, scale)
+    }
+
     /// Export to SVG
     #[cfg(not(target_arch = "wasm32"))]
     pub fn export_svg<P: AsRef<Path>>(self, path: P) -> Result<()> {
@@ -955,6 +1064,11 @@ impl PlotSeriesBuilder {
         self.end_series().render_to_svg()
     }
 
+    /// Render to SVG string with export options
+    pub fn render_to_svg_options(self, options: crate::export::SvgOptions) -> Result<String> {
+        self.end_series().render_to_svg_options(options)
+    }
+
     /// Export to PDF (requires `pdf` feature)
     #[cfg(all(feature = "pdf", not(target_arch = "wasm32")))]
     pub fn save_pdf<P: AsRef<Path>>(self, path: P) -> Result<()> {
@@ -971,6 +1085,28 @@ impl PlotSeriesBuilder {
         self.end_series().save_pdf_with_size(path, size)
     }
 
+    /// Export to PDF with document metadata (requires `pdf` feature)
+    #[cfg(all(feature = "pdf", not(target_arch = "wasm32")))]
+    pub fn save_pdf_with_metadata<P: AsRef<Path>>(
+        self,
+        path: P,
+        metadata: crate::export::PdfMetadata,
+    ) -> Result<()> {
+        self.end_series().save_pdf_with_metadata(path, metadata)
+    }
+
+    /// Export to PDF with custom size and document metadata (requires `pdf` feature)
+    #[cfg(all(feature = "pdf", not(target_arch = "wasm32")))]
+    pub fn save_pdf_with_size_and_metadata<P: AsRef<Path>>(
+        self,
+        path: P,
+        size: Option<(f64, f64)>,
+        metadata: crate::export::PdfMetadata,
+    ) -> Result<()> {
+        self.end_series()
+            .save_pdf_with_size_and_metadata(path, size, metadata)
+    }
+
     /// Infer and store a backend label (fluent API)
     /// Note: This ends the current series before optimizing
     pub fn auto_optimize(self) -> Plot {
@@ -1035,6 +1171,42 @@ impl PlotSeriesBuilder {
         self
     }
 
+    /// Label a data point with offset text and an arrow pointing at it
+    pub fn annotate_point<S: Into<String>>(
+        mut self,
+        x: f64,
+        y: f64,
+        text: S,
+        offset: (f32, f32),
+        arrow_style: ArrowStyle,
+    ) -> Self {
+        self.plot
+            .annotations
+            .push(Annotation::callout(x, y, text, offset, arrow_style));
+        self
+    }
+
+    /// Label a data point with offset text in a custom style
+    pub fn annotate_point_styled<S: Into<String>>(
+        mut self,
+        x: f64,
+        y: f64,
+        text: S,
+        offset: (f32, f32),
+        arrow_style: ArrowStyle,
+        text_style: TextStyle,
+    ) -> Self {
+        self.plot.annotations.push(Annotation::callout_styled(
+            x,
+            y,
+            text,
+            offset,
+            arrow_style,
+            text_style,
+        ));
+        self
+    }
+
     /// Add a horizontal reference line
     pub fn hline(mut self, y: f64) -> Self {
         self.plot.annotations.push(Annotation::hline(y));
@@ -1125,6 +1297,35 @@ impl PlotSeriesBuilder {
         self
     }
 
+    /// Add a stacked area chart.
+    pub fn stackplot<S, D>(mut self, x: &[f64], layers: &[(S, D)]) -> Self
+    where
+        S: AsRef<str>,
+        D: AsRef<[f64]>,
+    {
+        self.plot = self.plot.stackplot(x, layers);
+        self
+    }
+
+    /// Add a fill between two curves only where `mask` is `true`.
+    pub fn fill_between_where(
+        mut self,
+        x: &[f64],
+        y1: &[f64],
+        y2: &[f64],
+        mask: &[bool],
+        style: FillStyle,
+    ) -> Self {
+        self.plot.annotations.push(Annotation::fill_between_where(
+            x.to_vec(),
+            y1.to_vec(),
+            y2.to_vec(),
+            mask.to_vec(),
+            style,
+        ));
+        self
+    }
+
     /// Add a vertical span (shaded region)
     pub fn axvspan(mut self, x_min: f64, x_max: f64) -> Self {
         self.plot.annotations.push(Annotation::hspan(x_min, x_max));
@@ -1176,4 +1377,22 @@ impl PlotSeriesBuilder {
         }
         self
     }
+
+    /// Pad auto-scaled axes by a fraction of the data range on each side
+    pub fn autoscale_margin(mut self, margin: f32) -> Self {
+        self.plot = self.plot.autoscale_margin(margin);
+        self
+    }
+
+    /// Auto-scale the X axis symmetrically about zero
+    pub fn xlim_symmetric(mut self) -> Self {
+        self.plot = self.plot.xlim_symmetric();
+        self
+    }
+
+    /// Auto-scale the Y axis symmetrically about zero
+    pub fn ylim_symmetric(mut self) -> Self {
+        self.plot = self.plot.ylim_symmetric();
+        self
+    }
 }
@@ -4,7 +4,8 @@ use crate::core::plot::raster_batches::{
     project_xy_points,
 };
 use crate::core::plot::raster_fast_path::{
-    canonicalize_line_points_exact, reduce_line_points_for_raster, should_reduce_line_series,
+    canonicalize_line_points_exact, downsample_line_points, finite_point_runs, is_finite_point,
+    reduce_line_points_for_raster, should_reduce_line_series, simplify_line_points,
 };
 
 impl Plot {
@@ -46,6 +47,14 @@ impl Plot {
             marker_style_source: None,
             marker_size: None,
             marker_size_source: None,
+            marker_fill: None,
+            marker_edge: None,
+            marker_every: None,
+            simplify: None,
+            downsample: None,
+            visible: true,
+            hatch: None,
+            zorder: None,
             alpha: None,
             alpha_source: None,
             y_errors: None,
@@ -54,6 +63,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
@@ -65,6 +75,105 @@ impl Plot {
         Ok(())
     }
 
+    /// Number of data series in the plot
+    pub fn series_count(&self) -> usize {
+        self.series_mgr.len()
+    }
+
+    /// Remove the series at `index`
+    ///
+    /// Later series shift down by one. Auto-colored series keep the color
+    /// they were assigned, and axis bounds are recomputed on the next render.
+    /// The shaded band of a [`line_with_band`](Plot::line_with_band) or
+    /// [`confidence_band`](PlotBuilder::confidence_band)
+    /// series is removed with it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlottingError::InvalidInput`] if `index` is out of range.
+    pub fn remove_series(&mut self, index: usize) -> Result<()> {
+        let count = self.series_mgr.len();
+        let removed = self
+            .series_mgr
+            .remove(index)
+            .ok_or_else(|| series_index_error(index, count))?;
+
+        if let Some(band) = removed.band_annotation {
+            if band < self.annotations.len() {
+                self.annotations.remove(band);
+            }
+            for series in &mut self.series_mgr.series {
+                if let Some(other) = series.band_annotation.as_mut() {
+                    if *other > band {
+                        *other -= 1;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace the data of the line or scatter series at `index`
+    ///
+    /// Styling, label, and color are kept, so an interactive front end can
+    /// update one series without rebuilding the plot. A streaming source
+    /// attached to the series is detached in favor of the new static data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlottingError::InvalidInput`] if `index` is out of range or
+    /// the series is not a line or scatter series, and the same data errors
+    /// as [`Plot::add_line`] for mismatched or empty input.
+    pub fn replace_series_data<X, Y>(&mut self, index: usize, x_data: &X, y_data: &Y) -> Result<()>
+    where
+        X: NumericData1D,
+        Y: NumericData1D,
+    {
+        let count = self.series_mgr.len();
+        if index >= count {
+            return Err(series_index_error(index, count));
+        }
+
+        let x_vec = collect_numeric_data_1d(x_data, self.null_policy)?;
+        let y_vec = collect_numeric_data_1d(y_data, self.null_policy)?;
+
+        if x_vec.len() != y_vec.len() {
+            return Err(PlottingError::DataLengthMismatch {
+                x_len: x_vec.len(),
+                y_len: y_vec.len(),
+                series_index: Some(index),
+            });
+        }
+
+        if x_vec.is_empty() {
+            return Err(PlottingError::EmptyDataSet);
+        }
+
+        let series = &mut self.series_mgr.series[index];
+        match &mut series.series_type {
+            SeriesType::Line {
+                x_data: series_x,
+                y_data: series_y,
+            }
+            | SeriesType::Scatter {
+                x_data: series_x,
+                y_data: series_y,
+            } => {
+                *series_x = PlotData::Static(x_vec);
+                *series_y = PlotData::Static(y_vec);
+            }
+            _ => {
+                return Err(PlottingError::InvalidInput(format!(
+                    "series {} is not a line or scatter series",
+                    index
+                )));
+            }
+        }
+        series.streaming_source = None;
+
+        Ok(())
+    }
+
     /// Internal method to add a KDE series (used by PlotBuilder)
     ///
     /// This method is called by the PlotBuilder when finalizing a KDE series.
@@ -89,6 +198,14 @@ impl Plot {
             marker_style_source: style.marker_style_source,
             marker_size: style.marker_size,
             marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
             alpha: style.alpha,
             alpha_source: style.alpha_source,
             y_errors: None,
@@ -97,6 +214,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
@@ -129,6 +247,14 @@ impl Plot {
             marker_style_source: style.marker_style_source,
             marker_size: style.marker_size,
             marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
             alpha: style.alpha,
             alpha_source: style.alpha_source,
             y_errors: None,
@@ -137,6 +263,105 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
+        };
+
+        let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
+            .then_some(self.series_mgr.auto_color_index);
+        self.series_mgr
+            .push_with_auto_color_slot(series, auto_color_slot);
+        self.series_mgr.auto_color_index += 1;
+        self
+    }
+
+    /// Internal method to add a Rug series
+    pub(crate) fn add_rug_series(
+        mut self,
+        rug_data: crate::plots::RugData,
+        style: crate::core::plot::builder::SeriesStyle,
+    ) -> Self {
+        let series = PlotSeries {
+            series_type: SeriesType::Rug {
+                data: Arc::new(rug_data),
+            },
+            streaming_source: None,
+            label: style.label,
+            color: style.color,
+            color_source: style.color_source,
+            line_width: style.line_width,
+            line_width_source: style.line_width_source,
+            line_style: style.line_style,
+            line_style_source: style.line_style_source,
+            marker_style: style.marker_style,
+            marker_style_source: style.marker_style_source,
+            marker_size: style.marker_size,
+            marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
+            alpha: style.alpha,
+            alpha_source: style.alpha_source,
+            y_errors: None,
+            x_errors: None,
+            error_config: None,
+            inset_layout: None,
+            group_id: None,
+            resolved_radar_colors: None,
+            band_annotation: None,
+        };
+
+        let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
+            .then_some(self.series_mgr.auto_color_index);
+        self.series_mgr
+            .push_with_auto_color_slot(series, auto_color_slot);
+        self.series_mgr.auto_color_index += 1;
+        self
+    }
+
+    /// Internal method to add a Strip series
+    pub(crate) fn add_strip_series(
+        mut self,
+        strip_data: crate::plots::categorical::StripData,
+        style: crate::core::plot::builder::SeriesStyle,
+    ) -> Self {
+        let series = PlotSeries {
+            series_type: SeriesType::Strip {
+                data: Arc::new(strip_data),
+            },
+            streaming_source: None,
+            label: style.label,
+            color: style.color,
+            color_source: style.color_source,
+            line_width: style.line_width,
+            line_width_source: style.line_width_source,
+            line_style: style.line_style,
+            line_style_source: style.line_style_source,
+            marker_style: style.marker_style,
+            marker_style_source: style.marker_style_source,
+            marker_size: style.marker_size,
+            marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
+            alpha: style.alpha,
+            alpha_source: style.alpha_source,
+            y_errors: None,
+            x_errors: None,
+            error_config: None,
+            inset_layout: None,
+            group_id: None,
+            resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
@@ -169,6 +394,63 @@ impl Plot {
             marker_style_source: style.marker_style_source,
             marker_size: style.marker_size,
             marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
+            alpha: style.alpha,
+            alpha_source: style.alpha_source,
+            y_errors: None,
+            x_errors: None,
+            error_config: None,
+            inset_layout: None,
+            group_id: None,
+            resolved_radar_colors: None,
+            band_annotation: None,
+        };
+
+        let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
+            .then_some(self.series_mgr.auto_color_index);
+        self.series_mgr
+            .push_with_auto_color_slot(series, auto_color_slot);
+        self.series_mgr.auto_color_index += 1;
+        self
+    }
+
+    /// Internal method to add a Hexbin series
+    pub(crate) fn add_hexbin_series(
+        mut self,
+        hexbin_data: crate::plots::HexbinPlotData,
+        style: crate::core::plot::builder::SeriesStyle,
+    ) -> Self {
+        let series = PlotSeries {
+            series_type: SeriesType::Hexbin {
+                data: Arc::new(hexbin_data),
+            },
+            streaming_source: None,
+            label: style.label,
+            color: style.color,
+            color_source: style.color_source,
+            line_width: style.line_width,
+            line_width_source: style.line_width_source,
+            line_style: style.line_style,
+            line_style_source: style.line_style_source,
+            marker_style: style.marker_style,
+            marker_style_source: style.marker_style_source,
+            marker_size: style.marker_size,
+            marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
             alpha: style.alpha,
             alpha_source: style.alpha_source,
             y_errors: None,
@@ -177,6 +459,56 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
+        };
+
+        let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
+            .then_some(self.series_mgr.auto_color_index);
+        self.series_mgr
+            .push_with_auto_color_slot(series, auto_color_slot);
+        self.series_mgr.auto_color_index += 1;
+        self
+    }
+
+    /// Internal method to add a color-mapped Scatter series
+    pub(crate) fn add_scatter_mapped_series(
+        mut self,
+        scatter_data: crate::plots::ScatterMappedData,
+        style: crate::core::plot::builder::SeriesStyle,
+    ) -> Self {
+        let series = PlotSeries {
+            series_type: SeriesType::ScatterMapped {
+                data: Arc::new(scatter_data),
+            },
+            streaming_source: None,
+            label: style.label,
+            color: style.color,
+            color_source: style.color_source,
+            line_width: style.line_width,
+            line_width_source: style.line_width_source,
+            line_style: style.line_style,
+            line_style_source: style.line_style_source,
+            marker_style: style.marker_style,
+            marker_style_source: style.marker_style_source,
+            marker_size: style.marker_size,
+            marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
+            alpha: style.alpha,
+            alpha_source: style.alpha_source,
+            y_errors: None,
+            x_errors: None,
+            error_config: None,
+            inset_layout: None,
+            group_id: None,
+            resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
@@ -209,6 +541,14 @@ impl Plot {
             marker_style_source: style.marker_style_source,
             marker_size: style.marker_size,
             marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
             alpha: style.alpha,
             alpha_source: style.alpha_source,
             y_errors: None,
@@ -217,6 +557,7 @@ impl Plot {
             inset_layout: Some(style.inset_layout.unwrap_or_default().normalized()),
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
@@ -249,6 +590,14 @@ impl Plot {
             marker_style_source: style.marker_style_source,
             marker_size: style.marker_size,
             marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
             alpha: style.alpha,
             alpha_source: style.alpha_source,
             y_errors: None,
@@ -257,6 +606,7 @@ impl Plot {
             inset_layout: Some(style.inset_layout.unwrap_or_default().normalized()),
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
@@ -289,6 +639,14 @@ impl Plot {
             marker_style_source: style.marker_style_source,
             marker_size: style.marker_size,
             marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
             alpha: style.alpha,
             alpha_source: style.alpha_source,
             y_errors: None,
@@ -297,6 +655,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
@@ -336,6 +695,14 @@ impl Plot {
             marker_style_source: style.marker_style_source,
             marker_size: style.marker_size,
             marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
             alpha: style.alpha,
             alpha_source: style.alpha_source,
             y_errors: None,
@@ -344,6 +711,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
@@ -376,6 +744,14 @@ impl Plot {
             marker_style_source: style.marker_style_source,
             marker_size: style.marker_size,
             marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
             alpha: style.alpha,
             alpha_source: style.alpha_source,
             y_errors: None,
@@ -384,6 +760,7 @@ impl Plot {
             inset_layout: Some(style.inset_layout.unwrap_or_default().normalized()),
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
@@ -423,6 +800,14 @@ impl Plot {
             marker_style_source: style.marker_style_source,
             marker_size: style.marker_size,
             marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
             alpha: style.alpha,
             alpha_source: style.alpha_source,
             y_errors: None,
@@ -431,6 +816,7 @@ impl Plot {
             inset_layout: None,
             group_id: None,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = (series.color.is_none() && series.color_source.is_none())
@@ -474,10 +860,20 @@ impl Plot {
             line_width_source: style.line_width_source,
             line_style: style.line_style.or(Some(config.line_style.clone())),
             line_style_source: style.line_style_source,
-            marker_style: style.marker_style.or(config.marker),
+            marker_style: style
+                .marker_style
+                .or(config.marker.filter(|_| config.show_markers)),
             marker_style_source: style.marker_style_source,
             marker_size: style.marker_size,
             marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
             alpha: style.alpha.or(Some(config.alpha)),
             alpha_source: style.alpha_source,
             y_errors: style.y_errors,
@@ -486,6 +882,7 @@ impl Plot {
             inset_layout: None,
             group_id,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = if series.color.is_none() && series.color_source.is_none() {
@@ -542,6 +939,14 @@ impl Plot {
             marker_style_source: style.marker_style_source,
             marker_size: style.marker_size,
             marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
             alpha: style.alpha.or(Some(config.alpha)),
             alpha_source: style.alpha_source,
             y_errors: style.y_errors,
@@ -550,6 +955,7 @@ impl Plot {
             inset_layout: None,
             group_id,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = if series.color.is_none() && series.color_source.is_none() {
@@ -593,7 +999,17 @@ impl Plot {
         consume_palette_index: bool,
     ) -> Self {
         let series = PlotSeries {
-            series_type: SeriesType::Bar { categories, values },
+            series_type: SeriesType::Bar {
+                categories,
+                values,
+                value_labels: config.show_labels.then(|| {
+                    config.label_formatter.clone().unwrap_or_else(|| {
+                        crate::core::TickLabelFormatter::new(|value| {
+                            crate::core::TickFormatter::default().format_tick(value)
+                        })
+                    })
+                }),
+            },
             streaming_source: None,
             label: style.label,
             color: style.color.or(config.color),
@@ -606,6 +1022,14 @@ impl Plot {
             marker_style_source: style.marker_style_source,
             marker_size: style.marker_size,
             marker_size_source: style.marker_size_source,
+            marker_fill: style.marker_fill,
+            marker_edge: style.marker_edge,
+            marker_every: style.marker_every,
+            simplify: style.simplify,
+            downsample: style.downsample,
+            visible: style.visible.unwrap_or(true),
+            hatch: style.hatch,
+            zorder: style.zorder,
             alpha: style.alpha.or(Some(config.alpha)),
             alpha_source: style.alpha_source,
             y_errors: style.y_errors,
@@ -614,6 +1038,7 @@ impl Plot {
             inset_layout: None,
             group_id,
             resolved_radar_colors: None,
+            band_annotation: None,
         };
 
         let auto_color_slot = if series.color.is_none() && series.color_source.is_none() {
@@ -685,16 +1110,78 @@ impl Plot {
                     points = reduced.into();
                 }
 
-                raster_plan.push_polyline(
-                    std::sync::Arc::clone(&points),
-                    color,
-                    line_width,
-                    line_style,
-                    clip_rect,
-                );
+                // Marked series skip the raster reduction above, so markevery counts
+                // original data indices; subsample before gaps are dropped.
+                let marker_step = series.marker_step();
+                let marker_points: Option<std::sync::Arc<[crate::core::types::Point2f]>> =
+                    (series.marker_style.is_some() && marker_step > 1).then(|| {
+                        points
+                            .iter()
+                            .step_by(marker_step)
+                            .copied()
+                            .filter(is_finite_point)
+                            .collect()
+                    });
+
+                // Downsampling and simplification only thin the stroked path;
+                // markers keep the points chosen above.
+                let mut line_points = std::sync::Arc::clone(&points);
+                if let Some(mode) = series.downsample
+                    && let Some(downsampled) = downsample_line_points(
+                        line_points.as_ref(),
+                        mode,
+                        plot_area.left(),
+                        plot_area.width(),
+                    )
+                {
+                    line_points = downsampled.into();
+                }
+                if let Some(tolerance) = series.simplify
+                    && let Some(simplified) = simplify_line_points(
+                        line_points.as_ref(),
+                        self.render_scale().logical_pixels_to_pixels(tolerance),
+                    )
+                {
+                    line_points = simplified.into();
+                }
+
+                if !line_points.iter().all(is_finite_point) {
+                    // Non-finite samples leave a gap: stroke each finite run on its own
+                    // and only mark the points that can be placed.
+                    for run in finite_point_runs(line_points.as_ref()) {
+                        raster_plan.push_polyline(
+                            run.into(),
+                            color,
+                            line_width,
+                            line_style.clone(),
+                            clip_rect,
+                        );
+                    }
+                } else {
+                    raster_plan.push_polyline(
+                        line_points,
+                        color,
+                        line_width,
+                        line_style,
+                        clip_rect,
+                    );
+                }
+                if !points.iter().all(is_finite_point) {
+                    points = points.iter().copied().filter(is_finite_point).collect();
+                }
                 if let Some(marker_style) = series.marker_style {
                     let marker_size = self.dpi_scaled_line_width(series.marker_size.unwrap_or(8.0));
-                    raster_plan.push_markers(points, marker_size, marker_style, color, clip_rect);
+                    let paint = series.marker_paint(marker_style, color, |width| {
+                        self.dpi_scaled_line_width(width)
+                    });
+                    raster_plan.push_markers(
+                        marker_points.unwrap_or(points),
+                        marker_size,
+                        marker_style,
+                        color,
+                        paint,
+                        clip_rect,
+                    );
                 }
                 Some(raster_plan)
             }
@@ -712,8 +1199,18 @@ impl Plot {
                     &self.layout.x_scale,
                     &self.layout.y_scale,
                 );
+                let paint = series.marker_paint(marker_style, color, |width| {
+                    self.dpi_scaled_line_width(width)
+                });
                 let mut raster_plan = SeriesRasterPlan::default();
-                raster_plan.push_markers(points, marker_size, marker_style, color, clip_rect);
+                raster_plan.push_markers(
+                    points,
+                    marker_size,
+                    marker_style,
+                    color,
+                    paint,
+                    clip_rect,
+                );
                 Some(raster_plan)
             }
             (SeriesType::Heatmap { data }, ResolvedSeries::Other(_)) => {
@@ -806,12 +1303,25 @@ impl Plot {
 
                 if data.config.colorbar {
                     let colorbar_margin = render_scale.logical_pixels_to_pixels(COLORBAR_MARGIN_PX);
-                    let colorbar_width = render_scale.logical_pixels_to_pixels(COLORBAR_WIDTH_PX);
-                    let colorbar_x = plot_area.right() + colorbar_margin;
-                    let colorbar_y = plot_area.y();
-                    let colorbar_height = plot_area.height();
+                    let colorbar_thickness =
+                        render_scale.logical_pixels_to_pixels(COLORBAR_WIDTH_PX);
+                    let (colorbar_x, colorbar_y, colorbar_width, colorbar_height) =
+                        match data.config.colorbar_config.orientation {
+                            crate::plots::ColorbarOrientation::Vertical => (
+                                plot_area.right() + colorbar_margin,
+                                plot_area.y(),
+                                colorbar_thickness,
+                                plot_area.height(),
+                            ),
+                            crate::plots::ColorbarOrientation::Horizontal => (
+                                plot_area.x(),
+                                Self::horizontal_colorbar_top(renderer, data)?,
+                                plot_area.width(),
+                                colorbar_thickness,
+                            ),
+                        };
 
-                    renderer.draw_colorbar(
+                    renderer.draw_colorbar_with_center(
                         &data.config.colormap,
                         data.vmin,
                         data.vmax,
@@ -825,6 +1335,8 @@ impl Plot {
                         data.config.colorbar_tick_font_size,
                         Some(data.config.colorbar_label_font_size),
                         data.config.colorbar_log_subticks,
+                        data.config.center,
+                        &data.config.colorbar_config,
                     )?;
                 }
             }
@@ -932,6 +1444,95 @@ impl Plot {
         Ok(())
     }
 
+    /// Font size and top edge, in pixels, of the value label for a bar whose
+    /// end is at `py` and whose base is at `py_zero`
+    ///
+    /// The label sits just past the bar end: above bars that grow upward on
+    /// screen and below bars that grow downward.
+    pub(super) fn bar_value_label_placement(&self, py: f32, py_zero: f32) -> (f32, f32) {
+        let render_scale = self.render_scale();
+        let size = render_scale.points_to_pixels(self.display.config.typography.tick_size());
+        let gap = render_scale.points_to_pixels(BAR_VALUE_LABEL_GAP_PT);
+        let top = if py <= py_zero {
+            py - gap - size
+        } else {
+            py + gap
+        };
+        (size, top)
+    }
+
+    /// Center and width, in pixels, of the bar for the category at `index`
+    ///
+    /// Categories sit at integer X positions and bars span 80% of the category
+    /// spacing (matching matplotlib), so anything mapped through the same X
+    /// range, such as error bars, lines up with the bar centers.
+    pub(super) fn category_bar_span(
+        index: usize,
+        x_min: f64,
+        x_max: f64,
+        plot_area: tiny_skia::Rect,
+    ) -> (f32, f32) {
+        let pixels_per_unit = plot_area.width() / (x_max - x_min) as f32;
+        let (center, _) = crate::render::skia::map_data_to_pixels(
+            index as f64,
+            0.0,
+            x_min,
+            x_max,
+            0.0,
+            1.0,
+            plot_area,
+        );
+        (center, 0.8 * pixels_per_unit)
+    }
+
+    /// Pixel endpoints of each rug mark
+    ///
+    /// X rugs stand on the bottom edge of the plot area and Y rugs on its
+    /// left edge. Heights and offsets are fractions of the plot area, so the
+    /// marks keep their size whatever the data range.
+    pub(super) fn rug_segments(
+        &self,
+        data: &crate::plots::RugData,
+        plot_area: tiny_skia::Rect,
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+    ) -> Vec<((f32, f32), (f32, f32))> {
+        let to_pixels = |x: f64, y: f64| {
+            crate::render::skia::map_data_to_pixels_scaled(
+                x,
+                y,
+                x_min,
+                x_max,
+                y_min,
+                y_max,
+                plot_area,
+                &self.layout.x_scale,
+                &self.layout.y_scale,
+            )
+        };
+        let config = &data.config;
+        let mut segments = Vec::new();
+        if config.axis != crate::plots::RugAxis::Y {
+            let base = plot_area.bottom() - config.offset * plot_area.height();
+            let tip = base - config.height * plot_area.height();
+            segments.extend(data.points.iter().map(|&x| {
+                let (px, _) = to_pixels(x, y_min);
+                ((px, base), (px, tip))
+            }));
+        }
+        if config.axis != crate::plots::RugAxis::X {
+            let base = plot_area.left() + config.offset * plot_area.width();
+            let tip = base + config.height * plot_area.width();
+            segments.extend(data.points.iter().map(|&y| {
+                let (_, py) = to_pixels(x_min, y);
+                ((base, py), (tip, py))
+            }));
+        }
+        segments
+    }
+
     /// Helper method to render a single series using normal (non-DataShader) rendering
     pub(super) fn render_series_normal(
         &self,
@@ -977,20 +1578,14 @@ impl Plot {
             | (SeriesType::Scatter { .. }, ResolvedSeries::Scatter { .. }) => unreachable!(
                 "cacheable line/scatter series should return before fallback rendering"
             ),
-            (SeriesType::Bar { .. }, ResolvedSeries::Bar { values, .. }) => {
-                // Bar width as fraction of category spacing (0.8 = 80%, matching matplotlib)
-                let bar_width_fraction = 0.8;
-                let data_range = (x_max - x_min) as f32;
-                let pixels_per_unit = plot_area.width() / data_range;
-                let bar_width = bar_width_fraction * pixels_per_unit;
-
+            (SeriesType::Bar { value_labels, .. }, ResolvedSeries::Bar { values, errors, .. }) => {
                 for (i, &value) in values.iter().enumerate() {
-                    let x = i as f64;
-                    let (px, py) = crate::render::skia::map_data_to_pixels(
-                        x, value, x_min, x_max, y_min, y_max, plot_area,
+                    let (px, bar_width) = Self::category_bar_span(i, x_min, x_max, plot_area);
+                    let (_, py) = crate::render::skia::map_data_to_pixels(
+                        0.0, value, x_min, x_max, y_min, y_max, plot_area,
                     );
                     let (_, py_zero) = crate::render::skia::map_data_to_pixels(
-                        x, 0.0, x_min, x_max, y_min, y_max, plot_area,
+                        0.0, 0.0, x_min, x_max, y_min, y_max, plot_area,
                     );
                     renderer.draw_rectangle_clipped(
                         px - bar_width / 2.0,
@@ -1001,6 +1596,60 @@ impl Plot {
                         true,
                         clip_rect,
                     )?;
+                    if let Some(pattern) = series.hatch {
+                        renderer.draw_hatch_rectangle_clipped(
+                            px - bar_width / 2.0,
+                            py.min(py_zero),
+                            bar_width,
+                            (py - py_zero).abs(),
+                            pattern,
+                            color.darken(BAR_HATCH_DARKEN),
+                            clip_rect,
+                        )?;
+                    }
+                    if let Some(formatter) = value_labels
+                        && value.is_finite()
+                    {
+                        // Keep the label clear of the error bar, as matplotlib does
+                        let (low, high) = bar_y_extent(value, *errors, i);
+                        let (_, py_end) = crate::render::skia::map_data_to_pixels(
+                            0.0,
+                            if value < 0.0 { low } else { high },
+                            x_min,
+                            x_max,
+                            y_min,
+                            y_max,
+                            plot_area,
+                        );
+                        let (size, top) = self.bar_value_label_placement(py_end, py_zero);
+                        renderer.draw_text_centered(
+                            &formatter.format(value),
+                            px,
+                            top,
+                            size,
+                            self.display.theme.foreground,
+                        )?;
+                    }
+                }
+
+                if errors.is_some() {
+                    let positions: Vec<f64> = (0..values.len()).map(|i| i as f64).collect();
+                    Self::render_attached_error_bars(
+                        renderer,
+                        &positions,
+                        values,
+                        *errors,
+                        None,
+                        series.error_config.as_ref(),
+                        self.display.theme.foreground,
+                        x_min,
+                        x_max,
+                        y_min,
+                        y_max,
+                        plot_area,
+                        line_width,
+                        self.render_scale(),
+                    )?;
                 }
             }
             (SeriesType::Histogram { .. }, ResolvedSeries::Histogram { data: hist_data }) => {
@@ -1047,8 +1696,8 @@ impl Plot {
                     })?;
 
                 // Box plot positioning
-                let x_center = 0.5; // Center the box plot
-                let box_width = 0.3; // Box width
+                let x_center = config.x_position;
+                let box_width = 0.3; // Box width in data units
 
                 // Map coordinates to pixels
                 let (x_center_px, _) = crate::render::skia::map_data_to_pixels(
@@ -1100,7 +1749,8 @@ impl Plot {
                     plot_area,
                 );
 
-                let box_half_width = box_width * plot_area.width() * 0.5;
+                let box_half_width =
+                    Self::box_half_width_px(plot_area.width(), box_width, x_min, x_max);
                 let box_left = x_center_px - box_half_width;
                 let box_right = x_center_px + box_half_width;
 
@@ -1227,7 +1877,7 @@ impl Plot {
                     &line_style,
                 )?;
             }
-            (SeriesType::ErrorBars { .. }, ResolvedSeries::ErrorBars { x, y, y_errors }) => {
+            (SeriesType::ErrorBars { .. }, ResolvedSeries::ErrorBars { x, y, y_errors, .. }) => {
                 // Draw markers at data points
                 let marker_size = self.dpi_scaled_line_width(series.marker_size.unwrap_or(8.0));
                 let marker_style = series.marker_style.unwrap_or(MarkerStyle::Circle);
@@ -1355,6 +2005,48 @@ impl Plot {
                     series.line_width,
                 )?;
             }
+            (SeriesType::Rug { data }, ResolvedSeries::Other(_)) => {
+                for ((x1, y1), (x2, y2)) in
+                    self.rug_segments(data, plot_area, x_min, x_max, y_min, y_max)
+                {
+                    renderer.draw_line_clipped(
+                        x1,
+                        y1,
+                        x2,
+                        y2,
+                        color,
+                        line_width,
+                        LineStyle::Solid,
+                        clip_rect,
+                    )?;
+                }
+            }
+            (SeriesType::Strip { data }, ResolvedSeries::Other(_)) => {
+                let marker_size =
+                    self.dpi_scaled_line_width(series.marker_size.unwrap_or(data.config.size));
+                let marker_style = series.marker_style.unwrap_or(MarkerStyle::Circle);
+                for point in &data.points {
+                    let (px, py) = crate::render::skia::map_data_to_pixels_scaled(
+                        point.x,
+                        point.y,
+                        x_min,
+                        x_max,
+                        y_min,
+                        y_max,
+                        plot_area,
+                        &self.layout.x_scale,
+                        &self.layout.y_scale,
+                    );
+                    renderer.draw_marker_clipped(
+                        px,
+                        py,
+                        marker_size,
+                        marker_style,
+                        color,
+                        clip_rect,
+                    )?;
+                }
+            }
             (SeriesType::Violin { data }, ResolvedSeries::Other(_)) => {
                 // Use PlotRender trait to render Violin
                 let plot_area = crate::plots::PlotArea::new(
@@ -1472,6 +2164,106 @@ impl Plot {
                     )?;
                 }
             }
+            (SeriesType::Hexbin { data }, ResolvedSeries::Other(_)) => {
+                // Use PlotRender trait to render Hexbin
+                let hexbin_plot_area = crate::plots::PlotArea::new(
+                    plot_area.x(),
+                    plot_area.y(),
+                    plot_area.width(),
+                    plot_area.height(),
+                    x_min,
+                    x_max,
+                    y_min,
+                    y_max,
+                );
+                data.render_styled(
+                    renderer,
+                    &hexbin_plot_area,
+                    &self.display.theme,
+                    base_color,
+                    alpha,
+                    series.line_width,
+                )?;
+
+                // Draw colorbar if enabled
+                if data.config.colorbar {
+                    let render_scale = self.render_scale();
+                    let colorbar_margin = render_scale.logical_pixels_to_pixels(COLORBAR_MARGIN_PX);
+                    let colorbar_width = render_scale.logical_pixels_to_pixels(COLORBAR_WIDTH_PX);
+                    let (vmin, vmax) = data.colorbar_range();
+                    let value_scale = if data.config.log_scale {
+                        crate::axes::AxisScale::Log
+                    } else {
+                        crate::axes::AxisScale::Linear
+                    };
+                    let colormap = crate::render::ColorMap::by_name(&data.config.cmap)
+                        .unwrap_or_else(crate::render::ColorMap::viridis);
+
+                    renderer.draw_colorbar(
+                        &colormap,
+                        vmin,
+                        vmax,
+                        plot_area.right() + colorbar_margin,
+                        plot_area.y(),
+                        colorbar_width,
+                        plot_area.height(),
+                        &value_scale,
+                        data.config.colorbar_label.as_deref(),
+                        self.display.theme.foreground,
+                        data.config.colorbar_tick_font_size,
+                        Some(data.config.colorbar_label_font_size),
+                        false,
+                    )?;
+                }
+            }
+            (SeriesType::ScatterMapped { data }, ResolvedSeries::Other(_)) => {
+                let marker_size = self.dpi_scaled_line_width(series.marker_size.unwrap_or(10.0));
+                let marker_style = series.marker_style.unwrap_or(MarkerStyle::Circle);
+                for (x, y, point_color) in data.colored_points() {
+                    let (px, py) = crate::render::skia::map_data_to_pixels_scaled(
+                        x,
+                        y,
+                        x_min,
+                        x_max,
+                        y_min,
+                        y_max,
+                        plot_area,
+                        &self.layout.x_scale,
+                        &self.layout.y_scale,
+                    );
+                    renderer.draw_marker_clipped(
+                        px,
+                        py,
+                        marker_size,
+                        marker_style,
+                        point_color.with_alpha(alpha),
+                        clip_rect,
+                    )?;
+                }
+
+                if data.config.colorbar {
+                    let render_scale = self.render_scale();
+                    let colorbar_margin = render_scale.logical_pixels_to_pixels(COLORBAR_MARGIN_PX);
+                    let colorbar_width = render_scale.logical_pixels_to_pixels(COLORBAR_WIDTH_PX);
+                    let (vmin, vmax) = data.colorbar_range();
+
+                    renderer.draw_colorbar(
+                        &data.config.colormap,
+                        vmin,
+                        vmax,
+                        plot_area.right() + colorbar_margin,
+                        plot_area.y(),
+                        colorbar_width,
+                        plot_area.height(),
+                        &crate::axes::AxisScale::Linear,
+                        data.config.colorbar_label.as_deref(),
+                        self.display.theme.foreground,
+                        data.config.colorbar_tick_font_size,
+                        Some(data.config.colorbar_label_font_size),
+                        false,
+                    )?;
+                }
+            }
             (SeriesType::Pie { data }, ResolvedSeries::Other(_)) => {
                 // Use PlotRender trait to render Pie with 1:1 aspect ratio
                 // (uses normalized 0-1 coordinates)
@@ -1523,13 +2315,14 @@ impl Plot {
                 let polar_plot_area = crate::plots::PlotArea::new(
                     polar_x, polar_y, polar_size, polar_size, x_min, x_max, y_min, y_max,
                 );
-                data.render_styled(
+                data.render_styled_with_grid(
                     renderer,
                     &polar_plot_area,
                     &self.display.theme,
                     base_color,
                     alpha,
                     series.line_width,
+                    Some(&self.layout.grid_style),
                 )?;
             }
             _ => unreachable!("resolved series variant must match its declarative series"),
@@ -1595,19 +2388,46 @@ impl Plot {
                     .map(|(&x, &y)| (x, y))
                     .collect();
 
-                renderer
-                    .draw_polyline_clipped(&points, color, line_width, line_style, clip_rect)?;
+                for run in points
+                    .split(|&(px, py)| !(px.is_finite() && py.is_finite()))
+                    .filter(|run| !run.is_empty())
+                {
+                    renderer.draw_polyline_clipped(
+                        run,
+                        color,
+                        line_width,
+                        line_style.clone(),
+                        clip_rect,
+                    )?;
+                }
                 if let Some(marker_style) = series.marker_style {
                     let marker_size = self.dpi_scaled_line_width(series.marker_size.unwrap_or(8.0));
-                    for &(px, py) in &points {
-                        renderer.draw_marker_clipped(
-                            px,
-                            py,
-                            marker_size,
-                            marker_style,
-                            color,
-                            clip_rect,
-                        )?;
+                    let paint = series.marker_paint(marker_style, color, |width| {
+                        self.dpi_scaled_line_width(width)
+                    });
+                    for &(px, py) in points
+                        .iter()
+                        .step_by(series.marker_step())
+                        .filter(|(px, py)| px.is_finite() && py.is_finite())
+                    {
+                        match paint {
+                            Some(paint) => renderer.draw_marker_painted_clipped(
+                                px,
+                                py,
+                                marker_size,
+                                marker_style,
+                                paint,
+                                clip_rect,
+                            )?,
+                            None => renderer.draw_marker_clipped(
+                                px,
+                                py,
+                                marker_size,
+                                marker_style,
+                                color,
+                                clip_rect,
+                            )?,
+                        }
                     }
                 }
             }
@@ -1634,17 +2454,30 @@ impl Plot {
 
                 let marker_size = self.dpi_scaled_line_width(series.marker_size.unwrap_or(10.0));
                 let marker_style = series.marker_style.unwrap_or(MarkerStyle::Circle);
+                let paint = series.marker_paint(marker_style, color, |width| {
+                    self.dpi_scaled_line_width(width)
+                });
 
                 // Draw markers at transformed coordinates
                 for (&px, &py) in x_transformed.iter().zip(y_transformed.iter()) {
-                    renderer.draw_marker_clipped(
-                        px,
-                        py,
-                        marker_size,
-                        marker_style,
-                        color,
-                        clip_rect,
-                    )?;
+                    match paint {
+                        Some(paint) => renderer.draw_marker_painted_clipped(
+                            px,
+                            py,
+                            marker_size,
+                            marker_style,
+                            paint,
+                            clip_rect,
+                        )?,
+                        None => renderer.draw_marker_clipped(
+                            px,
+                            py,
+                            marker_size,
+                            marker_style,
+                            color,
+                            clip_rect,
+                        )?,
+                    }
                 }
             }
             // For other series types, fall back to normal rendering
@@ -1678,10 +2511,16 @@ impl Plot {
                     if x_data.is_empty() {
                         return Err(PlottingError::EmptyDataSet);
                     }
-                    PlottingError::validate_data(&x_data)?;
-                    PlottingError::validate_data(&y_data)?;
+                    if matches!(series.series_type, SeriesType::Line { .. }) {
+                        validate_line_data(&x_data, &y_data)?;
+                    } else {
+                        PlottingError::validate_data(&x_data)?;
+                        PlottingError::validate_data(&y_data)?;
+                    }
                 }
-                SeriesType::Bar { categories, values } => {
+                SeriesType::Bar {
+                    categories, values, ..
+                } => {
                     let values = values.resolve_cow(0.0);
                     if categories.len() != values.len() {
                         return Err(PlottingError::DataLengthMismatch {
@@ -1768,6 +2607,16 @@ impl Plot {
                         return Err(PlottingError::EmptyDataSet);
                     }
                 }
+                SeriesType::Rug { data } => {
+                    if data.is_empty() {
+                        return Err(PlottingError::EmptyDataSet);
+                    }
+                }
+                SeriesType::Strip { data } => {
+                    if data.points.is_empty() {
+                        return Err(PlottingError::EmptyDataSet);
+                    }
+                }
                 SeriesType::Violin { data } => {
                     if data.data.is_empty() {
                         return Err(PlottingError::EmptyDataSet);
@@ -1810,6 +2659,16 @@ impl Plot {
                         return Err(PlottingError::EmptyDataSet);
                     }
                 }
+                SeriesType::Hexbin { data } => {
+                    if data.bins.is_empty() {
+                        return Err(PlottingError::EmptyDataSet);
+                    }
+                }
+                SeriesType::ScatterMapped { data } => {
+                    if data.colored_points().next().is_none() {
+                        return Err(PlottingError::EmptyDataSet);
+                    }
+                }
                 SeriesType::Pie { data } => {
                     if data.values.is_empty() {
                         return Err(PlottingError::EmptyDataSet);
@@ -1861,10 +2720,16 @@ impl Plot {
                     if x.is_empty() && !is_streaming {
                         return Err(PlottingError::EmptyDataSet);
                     }
-                    PlottingError::validate_data(x)?;
-                    PlottingError::validate_data(y)?;
+                    if matches!(series, ResolvedSeries::Line { .. }) {
+                        validate_line_data(x, y)?;
+                    } else {
+                        PlottingError::validate_data(x)?;
+                        PlottingError::validate_data(y)?;
+                    }
                 }
-                ResolvedSeries::Bar { categories, values } => {
+                ResolvedSeries::Bar {
+                    categories, values, ..
+                } => {
                     if categories.len() != values.len() {
                         return Err(PlottingError::DataLengthMismatch {
                             x_len: categories.len(),
@@ -1877,7 +2742,7 @@ impl Plot {
                     }
                     PlottingError::validate_data(values)?;
                 }
-                ResolvedSeries::ErrorBars { x, y, y_errors } => {
+                ResolvedSeries::ErrorBars { x, y, y_errors, .. } => {
                     if x.len() != y.len() || y.len() != y_errors.len() {
                         return Err(PlottingError::DataLengthMismatch {
                             x_len: x.len(),
@@ -1929,6 +2794,12 @@ impl Plot {
                     SeriesType::Ecdf { data } if data.x.is_empty() => {
                         return Err(PlottingError::EmptyDataSet);
                     }
+                    SeriesType::Rug { data } if data.is_empty() => {
+                        return Err(PlottingError::EmptyDataSet);
+                    }
+                    SeriesType::Strip { data } if data.points.is_empty() => {
+                        return Err(PlottingError::EmptyDataSet);
+                    }
                     SeriesType::Violin { data } if data.data.is_empty() => {
                         return Err(PlottingError::EmptyDataSet);
                     }
@@ -1941,6 +2812,14 @@ impl Plot {
                     SeriesType::Contour { data } if data.levels.is_empty() => {
                         return Err(PlottingError::EmptyDataSet);
                     }
+                    SeriesType::Hexbin { data } if data.bins.is_empty() => {
+                        return Err(PlottingError::EmptyDataSet);
+                    }
+                    SeriesType::ScatterMapped { data }
+                        if data.colored_points().next().is_none() =>
+                    {
+                        return Err(PlottingError::EmptyDataSet);
+                    }
                     SeriesType::Pie { data } if data.values.is_empty() => {
                         return Err(PlottingError::EmptyDataSet);
                     }
@@ -2080,3 +2959,20 @@ impl Plot {
         self.validate_runtime_inputs_for_series(&self.series_mgr.series)
     }
 }
+
+/// Lines tolerate NaN/inf samples, which render as gaps, but still need at
+/// least one finite point; otherwise the usual non-finite error is reported.
+fn validate_line_data(x: &[f64], y: &[f64]) -> Result<()> {
+    if x.iter().zip(y).any(|(x, y)| x.is_finite() && y.is_finite()) {
+        return Ok(());
+    }
+    PlottingError::validate_data(x)?;
+    PlottingError::validate_data(y)
+}
+
+fn series_index_error(index: usize, count: usize) -> PlottingError {
+    PlottingError::InvalidInput(format!(
+        "series index {} out of range ({} series)",
+        index, count
+    ))
+}
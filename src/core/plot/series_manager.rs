@@ -84,6 +84,17 @@ impl SeriesManager {
         self.auto_color_slots.push(auto_color_slot);
     }
 
+    /// Remove the series at `index` together with its palette slot
+    ///
+    /// Remaining auto-colored series keep their reserved colors.
+    pub(crate) fn remove(&mut self, index: usize) -> Option<PlotSeries> {
+        if index >= self.series.len() {
+            return None;
+        }
+        self.auto_color_slots.remove(index);
+        Some(self.series.remove(index))
+    }
+
     /// Increment the auto-color index
     pub(crate) fn increment_auto_color(&mut self) {
         self.auto_color_index += 1;
@@ -104,7 +115,9 @@ impl SeriesManager {
                         return Err("Data series cannot be empty");
                     }
                 }
-                SeriesType::Bar { categories, values } => {
+                SeriesType::Bar {
+                    categories, values, ..
+                } => {
                     if categories.len() != values.len() {
                         return Err("Categories and values must have the same length");
                     }
@@ -191,6 +204,20 @@ mod tests {
         assert_ne!(color1, color2);
     }
 
+    #[test]
+    fn test_remove_keeps_remaining_color_slots() {
+        let mut plot = crate::core::Plot::new();
+        plot.add_line(&[0.0, 1.0], &[0.0, 1.0]).unwrap();
+        plot.add_line(&[0.0, 1.0], &[1.0, 0.0]).unwrap();
+        let mut manager = plot.series_mgr.clone();
+
+        assert!(manager.remove(2).is_none());
+        assert!(manager.remove(0).is_some());
+        assert_eq!(manager.len(), 1);
+        assert_eq!(manager.auto_color_slots, vec![Some(1)]);
+        assert_eq!(manager.auto_color_index(), 2);
+    }
+
     #[test]
     fn test_clear() {
         let mut manager = SeriesManager::new();
@@ -1,6 +1,7 @@
 #![allow(clippy::useless_conversion)]
 
 use super::*;
+use crate::axes::InsetAxes;
 use crate::core::{
     FigureConfig, LineConfig as CoreLineConfig, MarginConfig, SpineConfig as CoreSpineConfig,
 };
@@ -430,6 +431,14 @@ fn test_plot_series_static_source_helpers_materialize_values() {
         marker_style_source: None,
         marker_size: None,
         marker_size_source: None,
+        marker_fill: None,
+        marker_edge: None,
+        marker_every: None,
+        simplify: None,
+        downsample: None,
+        visible: true,
+        hatch: None,
+        zorder: None,
         alpha: None,
         alpha_source: None,
         y_errors: None,
@@ -438,6 +447,7 @@ fn test_plot_series_static_source_helpers_materialize_values() {
         inset_layout: None,
         group_id: None,
         resolved_radar_colors: None,
+        band_annotation: None,
     };
 
     series.set_color_source_value(Color::RED.into());
@@ -630,7 +640,7 @@ fn compute_render_plot_area(plot: &Plot) -> tiny_skia::Rect {
 
 fn compute_render_layout(plot: &Plot) -> ResolvedLayout {
     let (x_min, x_max, y_min, y_max) = plot
-        .calculate_data_bounds()
+        .effective_data_bounds()
         .expect("data bounds should be available");
     let content = plot.create_plot_content(y_min, y_max);
     let mut measurement_renderer = crate::render::SkiaRenderer::new(
@@ -658,7 +668,7 @@ fn compute_render_layout(plot: &Plot) -> ResolvedLayout {
 
 fn compute_render_tick_probe_points(plot: &Plot) -> ((u32, u32), (u32, u32)) {
     let (x_min, x_max, y_min, y_max) = plot
-        .calculate_data_bounds()
+        .effective_data_bounds()
         .expect("data bounds should be available");
     let content = plot.create_plot_content(y_min, y_max);
     let mut measurement_renderer = crate::render::SkiaRenderer::new(
@@ -710,7 +720,7 @@ fn compute_render_tick_probe_points(plot: &Plot) -> ((u32, u32), (u32, u32)) {
 
 fn compute_layout_without_tick_measurements(plot: &Plot) -> ResolvedLayout {
     let (x_min, x_max, y_min, y_max) = plot
-        .calculate_data_bounds()
+        .effective_data_bounds()
         .expect("data bounds should be available");
     let content = plot.create_plot_content(y_min, y_max);
 
@@ -861,7 +871,7 @@ fn test_snapshot_validation_isolated_from_later_reactive_mutation() {
     );
 
     let snapshot_series = plot.snapshot_series(0.0);
-    x.set(vec![0.0, f64::NAN]);
+    x.set(vec![f64::NAN, f64::NAN]);
 
     assert!(matches!(
         plot.validate_runtime_inputs(),
@@ -1093,6 +1103,39 @@ fn test_dedicated_error_bars_honor_asymmetric_overrides_in_svg_and_raster() {
     );
 }
 
+#[test]
+fn test_error_bars_asym_extend_bounds_by_each_side() {
+    let x = vec![0.0, 1.0, 2.0];
+    let y = vec![5.0, 7.0, 6.0];
+    let lower = vec![0.5, 3.0, 0.4];
+    let upper = vec![4.0, 0.5, 0.6];
+
+    let plot = Plot::new()
+        .error_bars_asym(&x, &y, &lower, &upper)
+        .into_plot();
+    let (_, _, y_min, y_max) = plot.calculate_data_bounds().unwrap();
+    assert_eq!((y_min, y_max), (4.0, 9.0));
+    let frame = plot.resolve_frame(0.0).unwrap();
+    let (_, _, y_min, y_max) = plot
+        .calculate_data_bounds_from_resolved(&frame.series)
+        .unwrap();
+    assert_eq!((y_min, y_max), (4.0, 9.0));
+    assert_ne!(
+        plot.clone().render_to_svg().unwrap(),
+        Plot::new()
+            .error_bars(&x, &y, &lower)
+            .into_plot()
+            .render_to_svg()
+            .unwrap()
+    );
+
+    let err = Plot::new()
+        .error_bars_asym(&x, &y, &lower, &upper[..2].to_vec())
+        .render()
+        .unwrap_err();
+    assert!(matches!(err, PlottingError::DataLengthMismatch { .. }));
+}
+
 #[test]
 fn test_resolved_histogram_preserves_raw_sample_validation() {
     let plot = Plot::new()
@@ -1291,6 +1334,61 @@ fn test_heatmap_render_cell_borders_are_opt_in() {
     );
 }
 
+#[test]
+fn test_heatmap_cell_border_strokes_shared_edges_in_png_and_svg() {
+    let values = vec![vec![0.5, 0.5], vec![0.5, 0.5]];
+    let plot = |border: Option<(Color, f32)>| {
+        Plot::new()
+            .size_px(240, 160)
+            .heatmap(
+                &values,
+                Some(
+                    crate::plots::heatmap::HeatmapConfig::new()
+                        .colorbar(false)
+                        .vmin(0.0)
+                        .vmax(1.0)
+                        .cell_border(border),
+                ),
+            )
+            .end_series()
+    };
+    let bordered = plot(Some((Color::WHITE, 2.0)));
+    let image = bordered
+        .render()
+        .expect("heatmap with cell border should render");
+    let plot_area = compute_render_plot_area(&bordered);
+    let center_x = (plot_area.left() + plot_area.width() * 0.5).round() as u32;
+    let center_y = (plot_area.top() + plot_area.height() * 0.5).round() as u32;
+    let interior_x = (plot_area.left() + plot_area.width() * 0.25).round() as u32;
+    let interior_y = (plot_area.top() + plot_area.height() * 0.25).round() as u32;
+
+    assert_eq!(
+        image_pixel_rgba(&image, center_x, interior_y),
+        [255, 255, 255, 255]
+    );
+    assert_eq!(
+        image_pixel_rgba(&image, interior_x, center_y),
+        [255, 255, 255, 255]
+    );
+    assert_ne!(
+        image_pixel_rgba(&image, interior_x, interior_y),
+        [255, 255, 255, 255]
+    );
+    let plain = plot(None).render().unwrap();
+    assert_eq!(
+        image_pixel_rgba(&plain, center_x, interior_y),
+        image_pixel_rgba(&plain, interior_x, interior_y)
+    );
+
+    let svg = bordered.render_to_svg().unwrap();
+    let border = svg
+        .lines()
+        .find(|line| line.contains("<path d=\"M") && line.contains(r#"fill="none""#))
+        .expect("cell border path");
+    assert_eq!(border.matches('Z').count(), 4);
+    assert!(border.contains(r#"stroke="rgb(255,255,255)""#));
+}
+
 #[test]
 fn test_heatmap_render_skips_non_finite_cells() {
     let plot = Plot::new()
@@ -1366,6 +1464,32 @@ fn test_filled_contour_without_lines_has_no_cell_seams() {
     );
 }
 
+#[test]
+fn test_contour_grid_uses_cell_indices_and_explicit_levels() {
+    let grid: Vec<Vec<f64>> = (0..6)
+        .map(|row| (0..8).map(|col| (row + col) as f64).collect())
+        .collect();
+    let plot = Plot::new()
+        .contour_grid(&grid, &[3.0, 6.0, 9.0])
+        .end_series();
+
+    let SeriesType::Contour { data } = &plot.series_mgr.series[0].series_type else {
+        panic!("expected contour series");
+    };
+    assert_eq!(data.levels, vec![3.0, 6.0, 9.0]);
+    assert_eq!(data.x.first().copied(), Some(0.0));
+    assert_eq!(data.x.last().copied(), Some(7.0));
+    assert_eq!(data.y.last().copied(), Some(5.0));
+    assert!(!data.lines.is_empty());
+
+    let filled = Plot::new().contourf_grid(&grid, &[3.0, 6.0, 9.0]);
+    assert!(filled.config.filled);
+    filled
+        .end_series()
+        .render()
+        .expect("filled grid contour should render");
+}
+
 #[test]
 fn test_heatmap_render_skips_nonpositive_cells_on_log_scale() {
     let plot = Plot::new()
@@ -1478,6 +1602,59 @@ fn test_heatmap_colorbar_layout_scales_with_dpi() {
     );
 }
 
+#[test]
+fn test_horizontal_heatmap_colorbar_reserves_bottom_band() {
+    let values = vec![vec![0.0, 0.25, 0.5, 0.75], vec![1.0, 1.25, 1.5, 1.75]];
+    let heatmap = |config: crate::plots::heatmap::HeatmapConfig| {
+        Plot::new()
+            .size_px(480, 320)
+            .heatmap(&values, Some(config))
+            .end_series()
+    };
+    let without_colorbar = heatmap(crate::plots::heatmap::HeatmapConfig::new().colorbar(false));
+    let horizontal = heatmap(
+        crate::plots::heatmap::HeatmapConfig::new()
+            .colorbar_orientation(crate::plots::ColorbarOrientation::Horizontal)
+            .colorbar_label("Energy"),
+    );
+
+    let without_layout = compute_render_layout(&without_colorbar);
+    let horizontal_layout = compute_render_layout(&horizontal);
+    assert!(
+        horizontal_layout.margins.bottom > without_layout.margins.bottom + 40.0,
+        "horizontal colorbar should reserve bottom margin: without={} with={}",
+        without_layout.margins.bottom,
+        horizontal_layout.margins.bottom
+    );
+    assert_eq!(
+        horizontal_layout.margins.right,
+        without_layout.margins.right
+    );
+
+    let formatted = heatmap(
+        crate::plots::heatmap::HeatmapConfig::new()
+            .colorbar_orientation(crate::plots::ColorbarOrientation::Horizontal)
+            .colorbar_ticks(3)
+            .colorbar_formatter(|value: f64| format!("{value:.1} J")),
+    );
+    let default_ticks = horizontal.render().unwrap();
+    let formatted_ticks = formatted.render().unwrap();
+    assert_ne!(default_ticks.pixels, formatted_ticks.pixels);
+
+    let ticks = crate::render::skia::compute_colorbar_ticks_with_options(
+        0.0,
+        1.75,
+        &crate::axes::AxisScale::Linear,
+        false,
+        Some(3),
+        Some(&crate::core::TickLabelFormatter::new(|value| {
+            format!("{value:.1} J")
+        })),
+    );
+    assert!(ticks.major_values.len() <= 4);
+    assert!(ticks.major_labels.iter().all(|label| label.ends_with(" J")));
+}
+
 #[test]
 fn test_plot_preserves_reversed_manual_limits() {
     let plot: Plot = Plot::new()
@@ -1854,6 +2031,34 @@ fn test_render_with_datashader_uses_captured_snapshot_for_reactive_series() {
     assert!(image.height > 0);
 }
 
+#[test]
+fn test_render_with_datashader_matches_decorated_save_output() {
+    let x: Vec<f64> = (0..100_001).map(|i| i as f64 * 0.001).collect();
+    let y: Vec<f64> = x.iter().map(|v| v.sin()).collect();
+    let plot: Plot = Plot::new()
+        .title("Large scatter")
+        .xlabel("x")
+        .ylabel("sin(x)")
+        .scatter(&x, &y)
+        .label("samples")
+        .into();
+
+    let (saved, diagnostics) = plot.render_optimized_for_test_with_diagnostics().unwrap();
+    assert!(diagnostics.used_auto_datashader);
+    let standalone = plot
+        .render_with_datashader(&plot.snapshot_series(0.0))
+        .unwrap();
+
+    assert_eq!(
+        (standalone.width, standalone.height),
+        (saved.width, saved.height)
+    );
+    assert!(
+        standalone.pixels == saved.pixels,
+        "standalone DataShader output should keep the axes, labels, and legend of save()"
+    );
+}
+
 #[cfg(feature = "parallel")]
 #[test]
 fn test_render_parallel_path_still_validates_empty_series() {
@@ -2029,6 +2234,82 @@ fn test_svg_legend_default_font_size_uses_typography_and_dpi() {
     );
 }
 
+#[test]
+fn test_svg_legend_title_is_bold_above_entries_and_widens_frame() {
+    let legend_plot = |title: Option<&str>| -> Plot {
+        let plot = Plot::new()
+            .size_px(640, 480)
+            .line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 0.5])
+            .label("a")
+            .legend(Position::TopRight);
+        match title {
+            Some(title) => plot.legend_title(title).into(),
+            None => plot.into(),
+        }
+    };
+    let title = "Temperature (°C) at noon";
+
+    let untitled = legend_plot(None).render_to_svg().unwrap();
+    let titled = legend_plot(Some(title)).render_to_svg().unwrap();
+    let (untitled_label_x, _) = extract_svg_text_xy(&untitled, "a");
+    let (label_x, label_y) = extract_svg_text_xy(&titled, "a");
+    let (title_x, title_y) = extract_svg_text_xy(&titled, title);
+
+    let title_line = titled
+        .lines()
+        .find(|line| line.contains(title))
+        .expect("legend title text");
+    assert!(title_line.contains(r#"font-weight="700""#));
+    assert!(
+        title_y < label_y,
+        "title {title_y} should sit above entry {label_y}"
+    );
+    assert!(
+        label_x < untitled_label_x - 50.0,
+        "a long title should widen the upper-right frame leftward: {label_x} vs {untitled_label_x}"
+    );
+    assert!(title_x > label_x, "title is centered in the widened frame");
+}
+
+#[test]
+fn test_manual_legend_entries_follow_series_entries() {
+    let band = Color::from_hex("#1f77b4").unwrap().with_alpha(0.3);
+    let plot: Plot = Plot::new()
+        .line(&[0.0, 1.0], &[0.0, 1.0])
+        .label("observed")
+        .legend(Position::TopLeft)
+        .legend_entry("95% CI", LegendItemType::Area { edge_color: None }, band)
+        .legend_entry(
+            "model",
+            LegendItemType::Line {
+                style: LineStyle::Dashed,
+                width: 1.5,
+            },
+            Color::BLACK,
+        )
+        .into();
+
+    let entries = plot.collect_legend_entries();
+    let labels: Vec<&str> = entries
+        .iter()
+        .map(|(item, _)| item.label.as_str())
+        .collect();
+    assert_eq!(labels, ["observed", "95% CI", "model"]);
+    assert_eq!(entries[0].1, vec![0]);
+    assert!(entries[1].1.is_empty() && entries[2].1.is_empty());
+    assert_eq!(entries[1].0.color, band);
+    assert!(matches!(
+        entries[1].0.item_type,
+        LegendItemType::Area { .. }
+    ));
+
+    let svg = plot.render_to_svg().unwrap();
+    let (_, observed_y) = extract_svg_text_xy(&svg, "observed");
+    let (_, band_y) = extract_svg_text_xy(&svg, "95% CI");
+    let (_, model_y) = extract_svg_text_xy(&svg, "model");
+    assert!(observed_y < band_y && band_y < model_y);
+}
+
 fn outside_legend_plot(position: LegendPosition, label: &str) -> Plot {
     Plot::new()
         .size_px(640, 480)
@@ -2448,7 +2729,7 @@ fn test_png_annotation_hline_uses_log_y_scale() {
 
     let image = plot.render().unwrap();
     let plot_area = compute_render_plot_area(&plot);
-    let (x_min, x_max, y_min, y_max) = plot.calculate_data_bounds().unwrap();
+    let (x_min, x_max, y_min, y_max) = plot.effective_data_bounds().unwrap();
     let (expected_x, expected_y) = crate::render::skia::map_data_to_pixels_scaled(
         0.5,
         10.0,
@@ -2505,6 +2786,128 @@ fn test_svg_log_axis_draws_minor_tick_marks() {
     );
 }
 
+#[test]
+fn test_log_minor_labels_select_minor_ticks_by_mantissa() {
+    let major_ticks = vec![1.0, 10.0, 100.0];
+    let labeled = Plot::labeled_log_minor_ticks(
+        &major_ticks,
+        1.0,
+        100.0,
+        &crate::axes::AxisScale::Log,
+        &[2, 5],
+    );
+    assert_eq!(labeled, vec![2.0, 5.0, 20.0, 50.0]);
+
+    assert!(
+        Plot::labeled_log_minor_ticks(
+            &major_ticks,
+            1.0,
+            100.0,
+            &crate::axes::AxisScale::Linear,
+            &[2, 5]
+        )
+        .is_empty()
+    );
+    assert!(
+        Plot::labeled_log_minor_ticks(&[], 1.0, 100.0, &crate::axes::AxisScale::Log, &[2])
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_log_minor_labels_are_drawn_in_svg_and_raster() {
+    let plot = || {
+        Plot::new()
+            .size_px(480, 360)
+            .xlim(0.0, 1.0)
+            .ylim(10.0, 1000.0)
+            .yscale(crate::axes::AxisScale::Log)
+            .major_ticks_y(2)
+            .grid(false)
+    };
+
+    let svg = plot().log_minor_labels_y(&[2, 5]).render_to_svg().unwrap();
+    for label in ["20", "50", "200", "500"] {
+        assert!(
+            svg.contains(&format!(">{label}</text>")),
+            "missing minor label {label}"
+        );
+    }
+    assert!(!svg.contains(">30</text>"));
+
+    let plain_svg = plot().render_to_svg().unwrap();
+    assert!(!plain_svg.contains(">20</text>"));
+
+    let plain = plot().render().unwrap();
+    let labeled = plot().log_minor_labels(&[2, 5]).render().unwrap();
+    assert_ne!(plain.pixels, labeled.pixels);
+}
+
+#[test]
+fn test_minor_grid_lines_use_the_minor_grid_style() {
+    let minor_color = Color::new(10, 20, 30);
+    let plot = || {
+        Plot::new()
+            .size_px(480, 360)
+            .xlim(0.0, 10.0)
+            .ylim(0.0, 10.0)
+            .minor_ticks(4)
+            .grid_line_width(1.0)
+            .minor_grid_color(minor_color)
+            .minor_grid_style(LineStyle::Dashed)
+            .minor_grid_width(0.5)
+    };
+    fn minor_lines(svg: &str) -> Vec<&str> {
+        svg.lines()
+            .filter(|line| line.contains(r#"stroke="rgba(10,20,30,0.149)""#))
+            .collect()
+    }
+
+    let svg = plot().grid_both().render_to_svg().unwrap();
+    let lines = minor_lines(&svg);
+    assert!(
+        lines.len() > 20,
+        "expected minor grid lines, got {}",
+        lines.len()
+    );
+    assert!(lines.iter().all(|line| line.contains("stroke-dasharray")));
+    assert!(svg.contains(r#"stroke="rgba(204,204,204,0.298)""#));
+
+    let svg = plot().grid_major_only().render_to_svg().unwrap();
+    assert!(minor_lines(&svg).is_empty());
+    plot().grid_both().render_png_bytes().unwrap();
+}
+
+#[test]
+fn test_grid_axis_limits_grid_lines_to_one_axis() {
+    let grid_lines = |axis: GridAxis| {
+        let svg = Plot::new()
+            .size_px(480, 360)
+            .xlim(0.0, 10.0)
+            .ylim(0.0, 10.0)
+            .grid_axis(axis)
+            .render_to_svg()
+            .unwrap();
+        let (mut vertical, mut horizontal) = (0, 0);
+        for line in svg
+            .lines()
+            .filter(|line| line.contains(r#"stroke="rgba(204,204,204,0.298)""#))
+        {
+            if (parse_svg_attr(line, "x1") - parse_svg_attr(line, "x2")).abs() <= 0.1 {
+                vertical += 1;
+            } else {
+                horizontal += 1;
+            }
+        }
+        (vertical, horizontal)
+    };
+
+    let (vertical, horizontal) = grid_lines(GridAxis::Both);
+    assert!(vertical > 0 && horizontal > 0);
+    assert_eq!(grid_lines(GridAxis::X), (vertical, 0));
+    assert_eq!(grid_lines(GridAxis::Y), (0, horizontal));
+}
+
 #[test]
 fn test_svg_line_uses_log_y_scale_for_geometry() {
     let x = vec![0.0, 1.0, 2.0, 3.0];
@@ -2536,6 +2939,7 @@ fn test_log_axis_rejects_non_positive_render_range() {
     let result = Plot::new()
         .line(&[0.0, 1.0], &[0.0, 1.0])
         .yscale(crate::axes::AxisScale::Log)
+        .ylim(0.0, 1.0)
         .render();
 
     let err = result.expect_err("log scale should reject zero y range bound");
@@ -2735,7 +3139,7 @@ fn test_render_layout_uses_configured_major_ticks() {
         .line(&[0.0, 1.0, 2.0, 3.0], &[1.0, 4.0, 9.0, 16.0])
         .end_series();
     let (x_min, x_max, y_min, y_max) = plot
-        .calculate_data_bounds()
+        .effective_data_bounds()
         .expect("data bounds should be available");
     let content = plot.create_plot_content(y_min, y_max);
     let mut measurement_renderer = crate::render::SkiaRenderer::new(
@@ -2770,6 +3174,7 @@ fn test_render_layout_uses_configured_major_ticks() {
 fn test_render_honors_top_and_right_tick_sides() {
     let base_plot = Plot::new()
         .size_px(400, 300)
+        .autoscale_margin(0.0)
         .line(&[0.0, 10.0, 20.0], &[0.0, 50.0, 100.0])
         .end_series();
     let all_sides = base_plot.clone().ticks_all_sides();
@@ -2805,7 +3210,7 @@ fn test_render_honors_top_and_right_tick_sides() {
 
 fn compute_categorical_render_top_tick_probe(plot: &Plot) -> (u32, u32) {
     let (x_min, x_max, y_min, y_max) = plot
-        .calculate_data_bounds()
+        .effective_data_bounds()
         .expect("data bounds should be available");
     let content = plot.create_plot_content(y_min, y_max);
     let mut measurement_renderer = crate::render::SkiaRenderer::new(
@@ -2937,7 +3342,7 @@ fn test_render_to_svg_uses_layout_positions_for_title_and_labels() {
     let svg = plot.render_to_svg().expect("SVG render should succeed");
 
     let (x_min, x_max, y_min, y_max) = plot
-        .calculate_data_bounds()
+        .effective_data_bounds()
         .expect("data bounds should be available");
     let content = plot.create_plot_content(y_min, y_max);
     let mut measurement_renderer = crate::render::SkiaRenderer::new(
@@ -3140,86 +3545,371 @@ fn test_render_to_svg_ticks_false_honors_despine_config() {
 }
 
 #[test]
-fn test_render_ticks_false_uses_configured_frame_width() {
-    let test_config = |axis_width| PlotConfig {
-        figure: FigureConfig::new(360.0 / 200.0, 260.0 / 200.0, 200.0),
-        lines: CoreLineConfig {
-            axis_width,
-            ..CoreLineConfig::default()
-        },
-        ..PlotConfig::default()
-    };
-
-    let thin_config = test_config(0.3);
-    let thick_config = test_config(6.0);
-
-    let make_plot = |config| -> Plot {
-        Plot::new()
-            .plot_config(config)
-            .grid(false)
-            .ticks(false)
-            .line(&[0.0, 1.0], &[10.0, 11.0])
-            .into()
+fn test_spines_hide_borders_and_their_ticks() {
+    let render = |plot: Plot| {
+        plot.grid(false)
+            .line(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0])
+            .render_to_svg()
+            .expect("SVG render should succeed")
     };
 
-    let thin_plot = make_plot(thin_config);
-    let thick_plot = make_plot(thick_config);
-    let thin_image = thin_plot.render().expect("thin frame render");
-    let thick_image = thick_plot.render().expect("thick frame render");
-    let thin_area = compute_render_plot_area(&thin_plot);
-    let thick_area = compute_render_plot_area(&thick_plot);
-
-    let thin_y = (thin_area.top() + thin_area.height() * 0.5).round() as u32;
-    let thick_y = (thick_area.top() + thick_area.height() * 0.5).round() as u32;
-    let thin_x = (thin_area.left().round() as u32).saturating_sub(1);
-    let thick_x = (thick_area.left().round() as u32).saturating_sub(1);
-
-    let thin_run = dark_pixel_run_right_from(&thin_image, thin_x, thin_y);
-    let thick_run = dark_pixel_run_right_from(&thick_image, thick_x, thick_y);
-
+    let despined = render(Plot::new().despine());
+    let configured = render(Plot::new().plot_config(PlotConfig {
+        spines: CoreSpineConfig::despine(),
+        ..PlotConfig::default()
+    }));
+    assert_eq!(despined, configured);
     assert!(
-        thick_run > thin_run + 4,
-        "configured thick frame should render visibly wider than thin frame: thin={thin_run}, thick={thick_run}"
+        despined.matches("<line ").count() < render(Plot::new()).matches("<line ").count(),
+        "hidden spines should drop their frame lines and tick marks"
     );
+
+    let bottom_only = render(Plot::new().ticks(false).spines(false, false, true, false));
+    assert_eq!(bottom_only.matches("<line ").count(), 1);
 }
 
 #[test]
-fn test_axis_tick_metrics_follow_line_config() {
-    let test_config = |axis_width, tick_width, tick_length| PlotConfig {
-        figure: FigureConfig::new(4.0, 3.0, 144.0),
-        lines: CoreLineConfig {
-            axis_width,
-            tick_width,
-            tick_length,
-            ..CoreLineConfig::default()
-        },
-        ..PlotConfig::default()
+fn test_marker_fill_none_draws_hollow_markers() {
+    let scatter = || {
+        Plot::new()
+            .grid(false)
+            .scatter(&[0.0, 1.0, 2.0], &[0.0, 1.0, 2.0])
+            .color(Color::RED)
+            .marker_size(20.0)
+    };
+    let red_pixels = |image: Image| {
+        (0..image.height)
+            .flat_map(|y| (0..image.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| image_pixel_is_red(&image, x, y))
+            .count()
     };
 
-    let thin = Plot::new()
-        .plot_config(test_config(0.3, 0.2, 2.0))
-        .line(&[0.0, 1.0], &[0.0, 1.0])
-        .end_series();
-    let thick = Plot::new()
-        .plot_config(test_config(2.4, 1.6, 7.0))
-        .line(&[0.0, 1.0], &[0.0, 1.0])
-        .end_series();
-
-    let (thin_axis, thin_tick_len, _, thin_tick_width, _) = thin.axis_tick_metrics_px();
-    let (thick_axis, thick_tick_len, _, thick_tick_width, _) = thick.axis_tick_metrics_px();
-
+    let filled = red_pixels(scatter().render().unwrap());
+    let hollow = red_pixels(scatter().marker_fill(None).render().unwrap());
     assert!(
-        thick_axis > thin_axis * 4.0,
-        "axis width should follow PlotConfig.lines.axis_width: thin={thin_axis}, thick={thick_axis}"
-    );
-    assert!(
-        thick_tick_len > thin_tick_len * 3.0,
-        "tick length should follow PlotConfig.lines.tick_length: thin={thin_tick_len}, thick={thick_tick_len}"
+        hollow > 0 && hollow * 2 < filled,
+        "hollow {hollow} vs filled {filled}"
     );
+
+    let svg = scatter()
+        .marker_fill(None)
+        .marker_edge(Color::BLUE, 2.0)
+        .render_to_svg()
+        .unwrap();
+    let markers: Vec<&str> = svg
+        .lines()
+        .filter(|line| line.contains("<circle"))
+        .collect();
+    assert_eq!(markers.len(), 3);
     assert!(
-        thick_tick_width > thin_tick_width * 4.0,
-        "tick width should follow PlotConfig.lines.tick_width: thin={thin_tick_width}, thick={thick_tick_width}"
+        markers
+            .iter()
+            .all(|line| line.contains(r#"fill="none" stroke="rgb(0,0,255)""#))
     );
+
+    let edged = scatter()
+        .marker_edge(Color::BLACK, 1.0)
+        .render_to_svg()
+        .unwrap();
+    assert!(edged.contains(r#"fill="rgb(255,0,0)" stroke="rgb(0,0,0)""#));
+}
+
+#[test]
+fn test_markevery_subsamples_line_markers() {
+    let x: Vec<f64> = (0..21).map(f64::from).collect();
+    let y: Vec<f64> = x.iter().map(|v| v.sin()).collect();
+    let count = |svg: String, element: &str| {
+        svg.lines()
+            .filter(|line| line.contains(element) && line.contains(r#"fill="rgb(255,0,0)""#))
+            .count()
+    };
+    let line = || Plot::new().grid(false).line(&x, &y).color(Color::RED);
+
+    let every_fifth = line()
+        .marker(MarkerStyle::Square)
+        .markevery(5)
+        .render_to_svg()
+        .unwrap();
+    assert_eq!(count(every_fifth, "<rect"), 5);
+    assert_eq!(
+        count(
+            line().show_markers(true).render_to_svg().unwrap(),
+            "<circle"
+        ),
+        21
+    );
+    assert_eq!(
+        count(
+            line()
+                .marker(MarkerStyle::Circle)
+                .show_markers(false)
+                .render_to_svg()
+                .unwrap(),
+            "<circle"
+        ),
+        0
+    );
+
+    let all = line().marker(MarkerStyle::Circle).render().unwrap();
+    let sparse = line()
+        .marker(MarkerStyle::Circle)
+        .markevery(5)
+        .render()
+        .unwrap();
+    assert_ne!(all.pixels, sparse.pixels);
+}
+
+#[test]
+fn test_simplify_thins_dense_line_within_tolerance() {
+    let x: Vec<f64> = (0..2_000).map(|i| f64::from(i) * 0.005).collect();
+    let y: Vec<f64> = x.iter().map(|v| v.sin()).collect();
+    let line = || Plot::new().grid(false).line(&x, &y).color(Color::RED);
+
+    let full = extract_first_svg_polyline_points(&line().render_to_svg().unwrap());
+    let simplified =
+        extract_first_svg_polyline_points(&line().simplify(0.5).render_to_svg().unwrap());
+    assert_eq!(full.len(), x.len());
+    assert!(simplified.len() * 10 < full.len());
+    assert_eq!(simplified.first(), full.first());
+    assert_eq!(simplified.last(), full.last());
+
+    let raw = line().render().unwrap();
+    let thinned = line().simplify(0.5).render().unwrap();
+    let changed = raw
+        .pixels
+        .chunks_exact(4)
+        .zip(thinned.pixels.chunks_exact(4))
+        .filter(|(lhs, rhs)| lhs.iter().zip(rhs.iter()).any(|(a, b)| a.abs_diff(*b) > 64))
+        .count();
+    assert!(changed * 100 < raw.pixels.len() / 4);
+}
+
+#[test]
+fn test_downsample_min_max_keeps_spikes_in_long_lines() {
+    let x: Vec<f64> = (0..20_000).map(f64::from).collect();
+    let mut y: Vec<f64> = x.iter().map(|v| (v * 0.37).sin() * 0.1).collect();
+    y[12_345] = 5.0;
+    let line = |n: usize| {
+        Plot::new()
+            .grid(false)
+            .ylim(-1.0, 6.0)
+            .line(&x[..n].to_vec(), &y[..n].to_vec())
+            .color(Color::RED)
+    };
+    let top_of = |points: &[(f32, f32)]| {
+        points
+            .iter()
+            .copied()
+            .min_by(|lhs, rhs| lhs.1.total_cmp(&rhs.1))
+            .unwrap()
+    };
+
+    let full = extract_first_svg_polyline_points(&line(x.len()).render_to_svg().unwrap());
+    let downsampled = extract_first_svg_polyline_points(
+        &line(x.len())
+            .downsample(DownsampleMode::MinMax)
+            .render_to_svg()
+            .unwrap(),
+    );
+    assert_eq!(full.len(), x.len());
+    assert!(downsampled.len() <= 2 * 640);
+    assert_eq!(top_of(&downsampled), top_of(&full));
+
+    let short = line(50).downsample(DownsampleMode::MinMax);
+    assert_eq!(
+        extract_first_svg_polyline_points(&short.render_to_svg().unwrap()).len(),
+        50
+    );
+
+    let (spike_x, spike_y) = top_of(&downsampled);
+    let image = line(x.len())
+        .downsample(DownsampleMode::MinMax)
+        .render()
+        .unwrap();
+    assert!(image_has_red_pixel_near(
+        &image,
+        spike_x.round() as u32,
+        spike_y.round() as u32,
+        2
+    ));
+}
+
+#[test]
+fn test_hatch_patterns_fill_bars_and_areas() {
+    let hatch_fills = |svg: String| {
+        svg.lines()
+            .filter(|line| line.contains(r#"fill="url(#hatch"#))
+            .count()
+    };
+    let bars = || Plot::new().bar(&["A", "B", "C"], &[1.0, 3.0, 2.0]);
+
+    let hatched_svg = bars()
+        .hatch(HatchPattern::Diagonal)
+        .render_to_svg()
+        .unwrap();
+    assert!(hatched_svg.contains(r#"<pattern id="hatch"#));
+    assert_eq!(hatch_fills(hatched_svg), 3);
+    assert_eq!(hatch_fills(bars().render_to_svg().unwrap()), 0);
+
+    let plain = bars().render().unwrap();
+    let hatched = bars().hatch(HatchPattern::Dots).render().unwrap();
+    assert_ne!(plain.pixels, hatched.pixels);
+
+    let x = [0.0, 1.0, 2.0, 3.0];
+    let y = [1.0, 2.5, 1.5, 3.0];
+    let area_svg = Plot::new()
+        .area(&x, &y, 0.0)
+        .hatch(HatchPattern::DiagonalCross)
+        .render_to_svg()
+        .unwrap();
+    assert_eq!(hatch_fills(area_svg), 1);
+    let line_svg = Plot::new()
+        .line(&x, &y)
+        .hatch(HatchPattern::DiagonalCross)
+        .render_to_svg()
+        .unwrap();
+    assert_eq!(hatch_fills(line_svg), 0);
+}
+
+#[test]
+fn test_zero_spine_position_crosses_inside_plot_area() {
+    let bottom_spine_y = |position: SpinePosition, y: [f64; 3]| {
+        let svg = Plot::new()
+            .grid(false)
+            .ticks(false)
+            .spines(false, false, true, false)
+            .spine_position(position)
+            .line(&[-1.0, 0.0, 1.0], &y)
+            .render_to_svg()
+            .expect("SVG render should succeed");
+        let line = svg
+            .split("<line ")
+            .nth(1)
+            .expect("bottom spine should be drawn");
+        let y1 = line.split("y1=\"").nth(1).unwrap();
+        y1[..y1.find('"').unwrap()].parse::<f32>().unwrap()
+    };
+
+    let edge = bottom_spine_y(SpinePosition::Edge, [-1.0, 0.5, 1.0]);
+    let zero = bottom_spine_y(SpinePosition::Zero, [-1.0, 0.5, 1.0]);
+    assert!(
+        zero < edge - 10.0,
+        "zero spine {zero} should sit above edge {edge}"
+    );
+
+    let clamped = bottom_spine_y(SpinePosition::Zero, [1.0, 2.0, 3.0]);
+    let clamped_edge = bottom_spine_y(SpinePosition::Edge, [1.0, 2.0, 3.0]);
+    assert!((clamped - clamped_edge).abs() < 0.5);
+}
+
+#[test]
+fn test_render_ticks_false_uses_configured_frame_width() {
+    let test_config = |axis_width| PlotConfig {
+        figure: FigureConfig::new(360.0 / 200.0, 260.0 / 200.0, 200.0),
+        lines: CoreLineConfig {
+            axis_width,
+            ..CoreLineConfig::default()
+        },
+        ..PlotConfig::default()
+    };
+
+    let thin_config = test_config(0.3);
+    let thick_config = test_config(6.0);
+
+    let make_plot = |config| -> Plot {
+        Plot::new()
+            .plot_config(config)
+            .grid(false)
+            .ticks(false)
+            .line(&[0.0, 1.0], &[10.0, 11.0])
+            .into()
+    };
+
+    let thin_plot = make_plot(thin_config);
+    let thick_plot = make_plot(thick_config);
+    let thin_image = thin_plot.render().expect("thin frame render");
+    let thick_image = thick_plot.render().expect("thick frame render");
+    let thin_area = compute_render_plot_area(&thin_plot);
+    let thick_area = compute_render_plot_area(&thick_plot);
+
+    let thin_y = (thin_area.top() + thin_area.height() * 0.5).round() as u32;
+    let thick_y = (thick_area.top() + thick_area.height() * 0.5).round() as u32;
+    let thin_x = (thin_area.left().round() as u32).saturating_sub(1);
+    let thick_x = (thick_area.left().round() as u32).saturating_sub(1);
+
+    let thin_run = dark_pixel_run_right_from(&thin_image, thin_x, thin_y);
+    let thick_run = dark_pixel_run_right_from(&thick_image, thick_x, thick_y);
+
+    assert!(
+        thick_run > thin_run + 4,
+        "configured thick frame should render visibly wider than thin frame: thin={thin_run}, thick={thick_run}"
+    );
+}
+
+#[test]
+fn test_axis_tick_metrics_follow_line_config() {
+    let test_config = |axis_width, tick_width, tick_length| PlotConfig {
+        figure: FigureConfig::new(4.0, 3.0, 144.0),
+        lines: CoreLineConfig {
+            axis_width,
+            tick_width,
+            tick_length,
+            ..CoreLineConfig::default()
+        },
+        ..PlotConfig::default()
+    };
+
+    let thin = Plot::new()
+        .plot_config(test_config(0.3, 0.2, 2.0))
+        .line(&[0.0, 1.0], &[0.0, 1.0])
+        .end_series();
+    let thick = Plot::new()
+        .plot_config(test_config(2.4, 1.6, 7.0))
+        .line(&[0.0, 1.0], &[0.0, 1.0])
+        .end_series();
+
+    let (thin_axis, thin_tick_len, _, thin_tick_width, _) = thin.axis_tick_metrics_px();
+    let (thick_axis, thick_tick_len, _, thick_tick_width, _) = thick.axis_tick_metrics_px();
+
+    assert!(
+        thick_axis > thin_axis * 4.0,
+        "axis width should follow PlotConfig.lines.axis_width: thin={thin_axis}, thick={thick_axis}"
+    );
+    assert!(
+        thick_tick_len > thin_tick_len * 3.0,
+        "tick length should follow PlotConfig.lines.tick_length: thin={thin_tick_len}, thick={thick_tick_len}"
+    );
+    assert!(
+        thick_tick_width > thin_tick_width * 4.0,
+        "tick width should follow PlotConfig.lines.tick_width: thin={thin_tick_width}, thick={thick_tick_width}"
+    );
+}
+
+#[test]
+fn test_tick_length_width_and_pad_setters() {
+    let plot = || Plot::new().dpi(72).line(&[0.0, 1.0], &[0.0, 1.0]);
+    let custom = plot()
+        .tick_length(8.0)
+        .tick_width(2.0)
+        .tick_pad(12.0)
+        .end_series();
+    let (_, major_len, minor_len, major_width, minor_width) = custom.axis_tick_metrics_px();
+    assert_eq!((major_len, major_width), (8.0, 2.0));
+    assert!((minor_len - 4.8).abs() < 1e-4 && (minor_width - 1.5).abs() < 1e-4);
+
+    let minor = plot()
+        .tick_length(8.0)
+        .minor_tick_length(2.0)
+        .minor_tick_width(0.5)
+        .end_series();
+    let (_, _, minor_len, _, minor_width) = minor.axis_tick_metrics_px();
+    assert_eq!((minor_len, minor_width), (2.0, 0.5));
+
+    let default_layout = compute_render_layout(&plot().end_series());
+    let padded_layout = compute_render_layout(&custom);
+    let label_gap = |layout: &ResolvedLayout| layout.xtick_baseline_y - layout.plot_area.bottom;
+    assert!(label_gap(&padded_layout) > label_gap(&default_layout) + 7.0);
+    custom.render().unwrap();
 }
 
 #[test]
@@ -3243,6 +3933,7 @@ fn test_rendered_axis_and_tick_geometry_follow_line_config() {
             .tick_direction_outside()
             .major_ticks_x(3)
             .major_ticks_y(3)
+            .autoscale_margin(0.0)
             .line(&[0.0, 10.0], &[2.0, 8.0])
             .into()
     };
@@ -3294,7 +3985,7 @@ fn test_render_to_svg_typst_uses_layout_anchor_contract() {
     let svg = plot.render_to_svg().expect("SVG render should succeed");
 
     let (x_min, x_max, y_min, y_max) = plot
-        .calculate_data_bounds()
+        .effective_data_bounds()
         .expect("data bounds should be available");
     let content = plot.create_plot_content(y_min, y_max);
     let mut measurement_renderer = crate::render::SkiaRenderer::new(
@@ -3661,6 +4352,64 @@ fn test_public_png_explicit_datashader_uses_density_path_for_large_scatter() {
     assert!(png_bytes.starts_with(b"\x89PNG\r\n\x1a\n"));
 }
 
+#[test]
+fn test_render_with_progress_reports_series_and_aggregation_fractions() {
+    let x: Vec<f64> = (0..10).map(f64::from).collect();
+    let reported = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let sink = Arc::clone(&reported);
+    Plot::new()
+        .line(&x, &x)
+        .scatter(&x[..5].to_vec(), &x[..5].to_vec())
+        .render_with_progress(move |fraction| sink.lock().unwrap().push(fraction))
+        .unwrap();
+    assert_eq!(*reported.lock().unwrap(), vec![10.0 / 15.0, 1.0]);
+
+    let dense: Vec<f64> = (0..300_000).map(|i| f64::from(i) * 1e-5).collect();
+    let reported = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let sink = Arc::clone(&reported);
+    Plot::new()
+        .scatter(&dense, &dense)
+        .backend(BackendType::DataShader)
+        .on_progress(move |fraction| sink.lock().unwrap().push(fraction))
+        .render_png_bytes()
+        .unwrap();
+    let reported = reported.lock().unwrap();
+    assert!(reported.len() > 2);
+    assert!(reported.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(reported.last(), Some(&1.0));
+}
+
+#[test]
+fn test_cancel_flag_stops_render_with_cancelled_error() {
+    let x: Vec<f64> = (0..300_000).map(|i| f64::from(i) * 1e-5).collect();
+    let cancel = Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let plot = Plot::new()
+        .scatter(&x, &x)
+        .backend(BackendType::DataShader)
+        .cancel_flag(Arc::clone(&cancel))
+        .into_plot();
+
+    let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let (flag, counter) = (Arc::clone(&cancel), Arc::clone(&calls));
+    let result = plot
+        .clone()
+        .on_progress(move |_| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            flag.store(true, std::sync::atomic::Ordering::Relaxed);
+        })
+        .render_png_bytes();
+    assert!(matches!(result, Err(PlottingError::Cancelled)));
+    assert_eq!(calls.load(std::sync::atomic::Ordering::Relaxed), 1);
+
+    assert!(matches!(plot.render(), Err(PlottingError::Cancelled)));
+    assert!(matches!(
+        plot.render_to_svg(),
+        Err(PlottingError::Cancelled)
+    ));
+    cancel.store(false, std::sync::atomic::Ordering::Relaxed);
+    assert!(plot.render().is_ok());
+}
+
 #[test]
 #[cfg(not(target_arch = "wasm32"))]
 fn test_public_png_auto_optimize_refuses_unroutable_large_line_backend() {
@@ -5032,6 +5781,43 @@ fn test_plot_series_builder_can_chain_boxplot_without_end_series() {
     ));
 }
 
+#[test]
+fn test_boxplots_place_groups_side_by_side_with_category_labels() {
+    let plot = Plot::new().boxplots(
+        &[
+            ("Control", vec![4.0, 5.0, 6.0, 7.0]),
+            ("Low", vec![1.0, 2.0, 3.0]),
+            ("High", vec![8.0, 9.0, 12.0]),
+        ],
+        None,
+    );
+
+    assert_eq!(plot.series_mgr.series.len(), 3);
+    assert!(
+        plot.series_mgr
+            .series
+            .iter()
+            .all(|series| matches!(series.series_type, SeriesType::BoxPlot { .. }))
+    );
+
+    let (x_min, x_max, y_min, y_max) = plot.calculate_data_bounds().unwrap();
+    assert_eq!((x_min, x_max), (-0.5, 2.5));
+    assert_eq!((y_min, y_max), (1.0, 12.0));
+    assert_eq!(
+        plot.positioned_x_categories(),
+        (
+            vec!["Control".to_string(), "Low".to_string(), "High".to_string()],
+            vec![0.0, 1.0, 2.0]
+        )
+    );
+
+    let svg = plot.clone().render_to_svg().unwrap();
+    for label in ["Control", "Low", "High"] {
+        assert!(svg.contains(&format!(">{label}</text>")), "missing {label}");
+    }
+    plot.render_png_bytes().unwrap();
+}
+
 #[test]
 fn test_mixed_coordinate_plots_keep_cartesian_axes() {
     let theta = vec![0.0, std::f64::consts::PI * 0.5, std::f64::consts::PI];
@@ -5103,6 +5889,27 @@ fn test_mixed_cartesian_polar_raster_render_succeeds() {
     assert!(!image.pixels.is_empty());
 }
 
+#[test]
+fn test_polar_scatter_renders_markers_without_lines() {
+    let theta = vec![0.0, 90.0, 180.0, 270.0];
+    let r = vec![1.0, 2.0, 1.5, 0.5];
+
+    let plot: Plot = Plot::new()
+        .polar_scatter(&r, &theta)
+        .theta_unit(crate::plots::ThetaUnit::Degrees)
+        .into();
+    let SeriesType::Polar { data } = &plot.series_mgr.series[0].series_type else {
+        panic!("expected polar series");
+    };
+    assert!(!data.config.show_line);
+    assert!(data.config.marker_size > 0.0);
+    assert!((data.points[1].y - 2.0).abs() < 1e-10);
+    assert!(!data.r_grid.is_empty());
+
+    let image = plot.render().expect("polar scatter should render");
+    assert!(!image.pixels.is_empty());
+}
+
 #[test]
 fn test_mixed_cartesian_polar_renders_svg_with_inset_geometry() {
     let theta = vec![0.0, std::f64::consts::PI * 0.5, std::f64::consts::PI];
@@ -5266,6 +6073,57 @@ fn test_quiver_bounds_include_arrow_head_vertices() {
     assert!(y_max >= 0.5);
 }
 
+#[test]
+fn test_hexbin_renders_png_and_svg_with_bounds_covering_hexagons() {
+    let x: Vec<f64> = (0..500).map(|i| ((i * 37) % 100) as f64).collect();
+    let y: Vec<f64> = (0..500).map(|i| ((i * 53) % 97) as f64 * 0.01).collect();
+
+    let plot: Plot = Plot::new().hexbin(&x, &y, 12).into();
+    let SeriesType::Hexbin { data } = &plot.series_mgr.series[0].series_type else {
+        panic!("expected hexbin series");
+    };
+    assert!(!data.bins.is_empty());
+
+    let (x_min, x_max, y_min, y_max) = plot.calculate_data_bounds().unwrap();
+    assert!(x_min < 0.0 && x_max > 99.0);
+    assert!(y_min < 0.0 && y_max > 0.96);
+
+    plot.clone().render().expect("hexbin PNG render");
+    let svg = plot.render_to_svg().expect("hexbin SVG render");
+    assert!(svg.matches("<polygon").count() >= data.bins.len());
+}
+
+#[test]
+fn test_hexbin_colorbar_reserves_right_margin() {
+    let x: Vec<f64> = (0..200).map(|i| (i % 20) as f64).collect();
+    let y: Vec<f64> = (0..200).map(|i| (i / 20) as f64).collect();
+
+    let without: Plot = Plot::new().hexbin(&x, &y, 10).into();
+    let with: Plot = Plot::new()
+        .hexbin(&x, &y, 10)
+        .colorbar(true)
+        .colorbar_label("Count")
+        .into();
+
+    with.clone()
+        .render()
+        .expect("hexbin with colorbar should render");
+    assert!(
+        compute_render_plot_area(&with).right() < compute_render_plot_area(&without).right(),
+        "colorbar should shrink the plot area"
+    );
+}
+
+#[test]
+fn test_hexbin_rejects_mismatched_lengths() {
+    let err = Plot::new()
+        .hexbin(&[0.0, 1.0, 2.0], &[0.0, 1.0], 10)
+        .render()
+        .unwrap_err();
+
+    assert!(matches!(err, PlottingError::DataLengthMismatch { .. }));
+}
+
 #[test]
 fn test_pie_svg_scales_edge_width_with_dpi() {
     let mut plot_100: Plot = Plot::new().dpi(100).pie(&[2.0, 3.0, 4.0]).into();
@@ -5919,6 +6777,7 @@ fn test_svg_text_annotation_uses_resolved_typography_and_full_text_style() {
         padding: 3.0,
         border_color: Some(Color::BLUE),
         border_width: 2.0,
+        corner_radius: 0.0,
     };
 
     let svg = Plot::new()
@@ -5948,24 +6807,130 @@ fn test_svg_text_annotation_uses_resolved_typography_and_full_text_style() {
 }
 
 #[test]
-fn test_theme_sets_plot_typography_font_family() {
-    let themed = Plot::new().theme(crate::render::Theme::publication());
-    assert_eq!(
-        themed.get_config().typography.family,
-        crate::render::FontFamily::Name("Times New Roman".to_string())
-    );
+fn test_svg_annotate_point_arrow_starts_at_the_text_box_edge() {
+    let svg = Plot::new()
+        .dpi(144)
+        .line(&[0.0, 1.0], &[0.0, 1.0])
+        .annotate_point(
+            0.5,
+            0.5,
+            "Midpoint",
+            (0.0, 40.0),
+            crate::core::ArrowStyle::new().color(Color::new(1, 2, 3)),
+        )
+        .render_to_svg()
+        .expect("SVG render should succeed");
 
-    let constructed = Plot::with_theme(crate::render::Theme::minimal());
-    assert_eq!(
-        constructed.get_config().typography.family,
-        crate::render::FontFamily::Name("Helvetica".to_string())
+    let group = svg
+        .lines()
+        .find(|line| line.contains(r#"data-ruviz-text-style="annotation""#))
+        .expect("callout text should be present");
+    let translate = group.split("translate(").nth(1).unwrap();
+    let (anchor_x, rest) = translate.split_once(',').unwrap();
+    let anchor_x: f32 = anchor_x.parse().unwrap();
+    let anchor_y: f32 = rest.split(')').next().unwrap().parse().unwrap();
+    let arrow = svg
+        .lines()
+        .find(|line| line.contains("<line") && line.contains(r#"stroke="rgb(1,2,3)""#))
+        .expect("callout arrow should be present");
+
+    assert!((parse_svg_attr(arrow, "x2") - anchor_x).abs() < 0.01);
+    assert!((parse_svg_attr(arrow, "y2") - anchor_y - 80.0).abs() < 0.01);
+    assert!((parse_svg_attr(arrow, "x1") - anchor_x).abs() < 0.01);
+    let start_below_anchor = parse_svg_attr(arrow, "y1") - anchor_y;
+    assert!(
+        start_below_anchor > 5.0 && start_below_anchor < 30.0,
+        "arrow should start at the lower box edge: {start_below_anchor}"
     );
 }
 
 #[test]
-fn test_theme_and_font_family_follow_last_call_precedence() {
-    let theme = crate::render::Theme::publication();
-    let theme_family = crate::render::FontFamily::from(theme.font_family.as_str());
+fn test_svg_axes_and_figure_annotations_ignore_data_limits() {
+    fn anchor(svg: &str, index: usize) -> (f32, f32) {
+        let group = svg
+            .lines()
+            .filter(|line| line.contains(r#"data-ruviz-text-style="annotation""#))
+            .nth(index)
+            .expect("annotation text should be present");
+        let translate = group.split("translate(").nth(1).unwrap();
+        let (x, rest) = translate.split_once(',').unwrap();
+        (
+            x.parse().unwrap(),
+            rest.split(')').next().unwrap().parse().unwrap(),
+        )
+    }
+    let render = |x_max: f64| {
+        Plot::new()
+            .size_px(400, 300)
+            .line(&[0.0, x_max], &[0.0, 1.0])
+            .annotate(Annotation::text(0.02, 0.98, "(a)").coords(crate::core::CoordSystem::Axes))
+            .annotate(Annotation::text(0.5, 0.5, "center").coords(crate::core::CoordSystem::Figure))
+            .annotate(
+                Annotation::text(100.0, 50.0, "far away").coords(crate::core::CoordSystem::Axes),
+            )
+            .render_to_svg()
+            .expect("SVG render should succeed")
+    };
+
+    let narrow = render(1.0);
+    let wide = render(1000.0);
+    assert_eq!(anchor(&narrow, 0), anchor(&wide, 0));
+    assert_eq!(anchor(&narrow, 1), (200.0, 150.0));
+    assert_eq!(anchor(&wide, 1), (200.0, 150.0));
+
+    let clip_start = narrow.find("<g clip-path=").unwrap();
+    let clip_end = clip_start + narrow[clip_start..].find("</g>").unwrap();
+    assert!(
+        narrow.find(">(a)</text>").unwrap() > clip_end,
+        "axes-pinned labels are drawn outside the plot clip"
+    );
+    let plot: Plot = Plot::new()
+        .line(&[0.0, 1.0], &[0.0, 1.0])
+        .annotate(Annotation::text(100.0, 50.0, "far away").coords(crate::core::CoordSystem::Axes))
+        .into();
+    let (x_min, x_max, _, y_max) = plot.calculate_data_bounds().unwrap();
+    assert!(x_min >= -0.5 && x_max <= 1.5 && y_max <= 1.5);
+}
+
+#[test]
+fn test_svg_annotations_clip_to_the_plot_area_unless_opted_out() {
+    let svg = Plot::new()
+        .line(&[0.0, 1.0], &[0.0, 1.0])
+        .annotate(Annotation::text(0.5, 0.5, "clipped"))
+        .annotate(
+            Annotation::text(-0.1, 1.04, "(a)")
+                .coords(crate::core::CoordSystem::Axes)
+                .clip(false),
+        )
+        .render_to_svg()
+        .expect("SVG render should succeed");
+
+    let clip_start = svg.find("<g clip-path=").unwrap();
+    let clip_end = clip_start + svg[clip_start..].find("  </g>\n  </g>").unwrap();
+    let clipped = svg.find(">clipped</text>").unwrap();
+    assert!(clip_start < clipped && clipped < clip_end);
+    assert!(svg.find(">(a)</text>").unwrap() > clip_end);
+}
+
+#[test]
+fn test_theme_sets_plot_typography_font_family() {
+    let themed = Plot::new().theme(crate::render::Theme::publication());
+    assert_eq!(
+        themed.get_config().typography.family,
+        crate::render::FontFamily::Name("Times New Roman".to_string())
+    );
+
+    let constructed = Plot::with_theme(crate::render::Theme::minimal());
+    assert_eq!(
+        constructed.get_config().typography.family,
+        crate::render::FontFamily::Name("Helvetica".to_string())
+    );
+}
+
+#[test]
+fn test_theme_and_font_family_follow_last_call_precedence() {
+    let theme = crate::render::Theme::publication();
+    let theme_family = crate::render::FontFamily::from(theme.font_family.as_str());
 
     let theme_last = Plot::new()
         .font_family("Explicit Font")
@@ -6361,3 +7326,1508 @@ fn test_backend_getter_does_not_sample_reactive_style() {
 
 #[cfg(feature = "typst-math")]
 mod typst;
+
+#[test]
+fn test_xaxis_date_places_ticks_on_calendar_boundaries() {
+    // 2023-11-15 00:00:00 UTC through 06:00:00 in 15-minute samples
+    let start = 1_700_006_400.0;
+    let x: Vec<f64> = (0..=24).map(|i| start + i as f64 * 900.0).collect();
+    let y: Vec<f64> = (0..=24).map(|i| (i as f64 * 0.3).sin()).collect();
+
+    let plot: Plot = Plot::new()
+        .line(&x, &y)
+        .xaxis_date(TimeFormat::auto())
+        .into();
+    assert!(matches!(plot.layout.x_scale, AxisScale::Time { .. }));
+
+    let (x_min, x_max, y_min, y_max) = plot.calculate_data_bounds().unwrap();
+    let (x_ticks, _) = plot.configured_major_ticks(x_min, x_max, y_min, y_max);
+    assert!(x_ticks.len() >= 3);
+    assert!(x_ticks.iter().all(|tick| tick % 3_600.0 == 0.0));
+
+    let labels = crate::render::skia::format_tick_labels_for_scale(&x_ticks, &plot.layout.x_scale);
+    assert!(labels.contains(&"03:00".to_string()), "labels: {labels:?}");
+
+    plot.clone().render().expect("time axis PNG render");
+    let svg = plot.render_to_svg().expect("time axis SVG render");
+    assert!(svg.contains("03:00"));
+}
+
+#[test]
+fn test_tick_formatters_override_axis_labels() {
+    let plot: Plot = Plot::new()
+        .line(&[0.0, 2_000.0, 4_000.0], &[0.0, 0.5, 1.0])
+        .xtick_formatter(TickLabelFormatter::si_prefix(1))
+        .ytick_formatter(TickLabelFormatter::percent(0))
+        .into();
+
+    let (x_min, x_max, y_min, y_max) = plot.calculate_data_bounds().unwrap();
+    let (x_ticks, y_ticks) = plot.configured_major_ticks(x_min, x_max, y_min, y_max);
+    assert!(
+        plot.layout
+            .x_tick_labels(&x_ticks)
+            .contains(&"2k".to_string())
+    );
+    assert!(
+        plot.layout
+            .y_tick_labels(&y_ticks)
+            .contains(&"100%".to_string())
+    );
+
+    plot.clone().render().expect("formatted PNG render");
+    let svg = plot.render_to_svg().expect("formatted SVG render");
+    assert!(svg.contains("2k"));
+    assert!(svg.contains("100%"));
+
+    let bars: Plot = Plot::new()
+        .bar(&["a", "b"], &[1_500.0, 3_000.0])
+        .ytick_formatter(|v: f64| format!("${v:.0}"))
+        .into();
+    let svg = bars.render_to_svg().expect("formatted bar SVG render");
+    assert!(svg.contains("$1000"));
+}
+
+#[test]
+fn test_scientific_notation_factors_out_axis_offset() {
+    let x = [0.0, 1.0, 2.0, 3.0];
+    let y = [0.0, 15_000.0, 30_000.0, 45_000.0];
+    let plot: Plot = Plot::new()
+        .line(&x, &y)
+        .autoscale_margin(0.0)
+        .scientific_notation(true)
+        .into();
+
+    let (x_min, x_max, y_min, y_max) = plot.calculate_data_bounds().unwrap();
+    let (x_ticks, y_ticks) = plot.configured_major_ticks(x_min, x_max, y_min, y_max);
+    assert!(
+        plot.layout
+            .x_tick_labels(&x_ticks)
+            .contains(&"3".to_string())
+    );
+    let y_labels = plot.layout.y_tick_labels(&y_ticks);
+    assert!(y_labels.contains(&"4".to_string()), "labels: {y_labels:?}");
+    assert!(y_labels.iter().all(|label| !label.contains('e')));
+
+    plot.clone().render().expect("offset PNG render");
+    let svg = plot.render_to_svg().expect("offset SVG render");
+    assert_eq!(svg.matches("×10⁴").count(), 1);
+
+    let log_axis: Plot = Plot::new()
+        .line(&x, &y)
+        .yscale(AxisScale::Log)
+        .scientific_notation(true)
+        .into();
+    assert!(log_axis.layout.y_label_formatter().is_none());
+    let off: Plot = Plot::new().line(&x, &y).into();
+    assert!(!off.render_to_svg().unwrap().contains("×10"));
+}
+
+#[test]
+fn test_axis_scientific_toggles_offset_text_per_axis() {
+    let x = [0.0, 200_000.0, 400_000.0, 600_000.0];
+    let y = [0.0, 15_000.0, 30_000.0, 45_000.0];
+    let offsets = |plot: Plot| {
+        let svg = plot.render_to_svg().unwrap();
+        (svg.contains("×10⁵"), svg.contains("×10⁴"))
+    };
+
+    let y_only: Plot = Plot::new()
+        .line(&x, &y)
+        .autoscale_margin(0.0)
+        .yaxis_scientific(true)
+        .into();
+    assert!(y_only.layout.x_label_formatter().is_none());
+    assert!(y_only.layout.y_label_formatter().is_some());
+    assert_eq!(offsets(y_only), (false, true));
+
+    let x_only: Plot = Plot::new()
+        .line(&x, &y)
+        .autoscale_margin(0.0)
+        .xaxis_scientific(true)
+        .into();
+    assert_eq!(offsets(x_only), (true, false));
+
+    let both: Plot = Plot::new()
+        .line(&x, &y)
+        .autoscale_margin(0.0)
+        .scientific_notation(true)
+        .yaxis_scientific(false)
+        .into();
+    assert_eq!(offsets(both), (true, false));
+}
+
+#[test]
+fn test_xtick_rotation_reserves_bottom_margin_for_category_labels() {
+    let categories = ["North America", "South America", "Europe", "Asia Pacific"];
+    let values = [4.0, 2.5, 3.2, 5.1];
+
+    let horizontal: Plot = Plot::new().bar(&categories, &values).into();
+    let diagonal: Plot = Plot::new()
+        .bar(&categories, &values)
+        .xtick_rotation(45.0)
+        .into();
+    let vertical: Plot = Plot::new()
+        .bar(&categories, &values)
+        .xtick_rotation(90.0)
+        .into();
+
+    let horizontal_bottom = compute_render_plot_area(&horizontal).bottom();
+    let diagonal_bottom = compute_render_plot_area(&diagonal).bottom();
+    let vertical_bottom = compute_render_plot_area(&vertical).bottom();
+    assert!(diagonal_bottom < horizontal_bottom);
+    assert!(vertical_bottom < diagonal_bottom);
+
+    diagonal.clone().render().expect("rotated bar PNG render");
+    let svg = diagonal.render_to_svg().expect("rotated bar SVG render");
+    assert!(svg.contains("rotate(-45.0)"));
+
+    let numeric: Plot = Plot::new()
+        .line(&[0.0, 1.0, 2.0], &[1.0, 2.0, 3.0])
+        .xtick_rotation(90.0)
+        .into();
+    numeric
+        .clone()
+        .render()
+        .expect("rotated numeric PNG render");
+    let svg = numeric.render_to_svg().expect("rotated numeric SVG render");
+    assert!(svg.contains("rotate(-90.0)"));
+}
+
+#[test]
+fn test_line_breaks_at_non_finite_values() {
+    let x: Vec<f64> = (0..10).map(f64::from).collect();
+    let mut y: Vec<f64> = x.iter().map(|value| value * 0.5).collect();
+    let continuous: Plot = Plot::new().line(&x, &y).into();
+    y[4] = f64::NAN;
+    y[7] = f64::INFINITY;
+    let gapped: Plot = Plot::new().line(&x, &y).into();
+
+    let polylines = |plot: &Plot| {
+        plot.clone()
+            .render_to_svg()
+            .expect("line SVG render")
+            .matches("<polyline")
+            .count()
+    };
+    assert_eq!(polylines(&gapped), polylines(&continuous) + 2);
+
+    let continuous_png = continuous.render().expect("continuous line PNG render");
+    let gapped_png = gapped.render().expect("gapped line PNG render");
+    assert_ne!(continuous_png.pixels, gapped_png.pixels);
+
+    let err = Plot::new()
+        .line(&[0.0, 1.0], &[f64::NAN, f64::NAN])
+        .render()
+        .expect_err("a line without finite points should fail validation");
+    assert!(matches!(err, PlottingError::InvalidData { .. }));
+}
+
+#[test]
+fn test_scatter_mapped_colors_points_and_reserves_colorbar_margin() {
+    let x = [0.0, 1.0, 2.0, 3.0];
+    let y = [0.0, 1.0, 0.0, 1.0];
+    let c = [0.0, 10.0, f64::NAN, 20.0];
+
+    let plain: Plot = Plot::new()
+        .scatter_mapped(&x, &y, &c, crate::render::ColorMap::viridis())
+        .into();
+    let with_colorbar: Plot = Plot::new()
+        .scatter_mapped(&x, &y, &c, crate::render::ColorMap::viridis())
+        .colorbar(true)
+        .colorbar_label("Value")
+        .into();
+
+    with_colorbar
+        .clone()
+        .render()
+        .expect("color-mapped scatter PNG render");
+    assert!(
+        compute_render_plot_area(&with_colorbar).right() < compute_render_plot_area(&plain).right(),
+        "colorbar should shrink the plot area"
+    );
+
+    let svg = plain
+        .render_to_svg()
+        .expect("color-mapped scatter SVG render");
+    for t in [0.0, 0.5, 1.0] {
+        let color = crate::render::ColorMap::viridis().sample(t);
+        let rgb = format!("rgb({},{},{})", color.r, color.g, color.b);
+        assert!(svg.contains(&rgb), "missing marker color {rgb}");
+    }
+}
+
+#[test]
+fn test_rug_marks_each_value_on_the_bottom_edge_without_extending_y() {
+    let data = vec![1.0, 2.5, f64::NAN, 4.0];
+    let kde_only: Plot = Plot::new().kde(&data).into();
+    let with_rug: Plot = Plot::new()
+        .kde(&data)
+        .rug(&data)
+        .color(Color::new(255, 0, 0))
+        .height(0.1)
+        .into();
+
+    let (_, _, kde_y_min, kde_y_max) = kde_only.calculate_data_bounds().unwrap();
+    let (_, _, y_min, y_max) = with_rug.calculate_data_bounds().unwrap();
+    assert_eq!((y_min, y_max), (kde_y_min, kde_y_max));
+
+    let area = compute_render_plot_area(&with_rug);
+    let svg = with_rug.render_to_svg().unwrap();
+    let marks: Vec<(f32, f32)> = svg
+        .lines()
+        .filter(|line| line.contains("<line") && line.contains("rgba(255,0,0,"))
+        .map(|line| {
+            let attr = |name: &str| -> f32 {
+                let start = line.find(&format!(" {name}=\"")).unwrap() + name.len() + 3;
+                let end = start + line[start..].find('"').unwrap();
+                line[start..end].parse().unwrap()
+            };
+            assert_eq!(attr("x1"), attr("x2"));
+            (attr("y1"), attr("y2"))
+        })
+        .collect();
+    assert_eq!(marks.len(), 3);
+    for (base, tip) in marks {
+        assert!((base - area.bottom()).abs() < 0.01);
+        assert!((base - tip - 0.1 * area.height()).abs() < 0.01);
+    }
+
+    // A lone rug keeps its x range and gets the default y range
+    let lone: Plot = Plot::new().rug(&data).into();
+    let (x_min, x_max, y_min, y_max) = lone.calculate_data_bounds().unwrap();
+    assert_eq!((x_min, x_max, y_min, y_max), (1.0, 4.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_strip_jitters_groups_around_their_category_and_labels_the_axis() {
+    let control = vec![4.0, 5.0, 6.0, 7.0];
+    let treated = vec![5.5, f64::NAN, 8.0];
+    let strip = |seed| -> Plot {
+        Plot::new()
+            .strip(&[("Control", &control), ("Treated", &treated)], 0.2)
+            .seed(seed)
+            .into()
+    };
+    let plot = strip(1);
+
+    let SeriesType::Strip { data } = &plot.series_mgr.series[0].series_type else {
+        panic!("expected a strip series");
+    };
+    assert_eq!(data.points.len(), 6);
+    for point in &data.points {
+        assert!((point.x - point.category as f64).abs() <= 0.2);
+    }
+    let xs = |plot: &Plot| match &plot.series_mgr.series[0].series_type {
+        SeriesType::Strip { data } => data.points.iter().map(|p| p.x).collect::<Vec<_>>(),
+        _ => unreachable!(),
+    };
+    assert_eq!(xs(&strip(1)), xs(&plot));
+    assert_ne!(xs(&strip(2)), xs(&plot));
+
+    let (x_min, x_max, y_min, y_max) = plot.calculate_data_bounds().unwrap();
+    assert_eq!((x_min, x_max), (-0.5, 1.5));
+    assert_eq!((y_min, y_max), (4.0, 8.0));
+    assert_eq!(
+        plot.positioned_x_categories(),
+        (
+            vec!["Control".to_string(), "Treated".to_string()],
+            vec![0.0, 1.0]
+        )
+    );
+
+    let svg = plot.clone().render_to_svg().unwrap();
+    for label in ["Control", "Treated"] {
+        assert!(svg.contains(&format!(">{label}</text>")), "missing {label}");
+    }
+    plot.render_png_bytes().unwrap();
+}
+
+#[test]
+fn test_scatter_mapped_rejects_mismatched_value_length() {
+    let err = Plot::new()
+        .scatter_mapped(
+            &[0.0, 1.0, 2.0],
+            &[0.0, 1.0, 2.0],
+            &[1.0, 2.0],
+            crate::render::ColorMap::viridis(),
+        )
+        .render()
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        PlottingError::DataLengthMismatch {
+            x_len: 3,
+            y_len: 2,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_save_to_buffer_encodes_png_jpeg_and_svg_without_touching_disk() {
+    let plot = Plot::new()
+        .size_px(240, 160)
+        .title("Buffer")
+        .line(&[0.0, 1.0, 2.0], &[1.0, 3.0, 2.0])
+        .end_series();
+
+    let tempdir = tempdir().expect("tempdir should be created");
+    let saved_path = tempdir.path().join("saved.png");
+    plot.clone().save(&saved_path).expect("plot should save");
+
+    let png = plot
+        .clone()
+        .save_to_buffer(ImageFormat::Png)
+        .expect("PNG buffer should encode");
+    assert_eq!(png, std::fs::read(&saved_path).expect("saved PNG readable"));
+
+    let jpeg = plot
+        .clone()
+        .save_to_buffer(ImageFormat::Jpeg)
+        .expect("JPEG buffer should encode");
+    assert_eq!(&jpeg[..2], &[0xFF, 0xD8]);
+    let png_image = ::image::load_from_memory(&png).expect("PNG should decode");
+    let jpeg_image = ::image::load_from_memory(&jpeg).expect("JPEG should decode");
+    assert_eq!(
+        (jpeg_image.width(), jpeg_image.height()),
+        (png_image.width(), png_image.height())
+    );
+
+    let svg = plot
+        .clone()
+        .save_to_buffer(ImageFormat::Svg)
+        .expect("SVG buffer should encode");
+    assert_eq!(
+        svg,
+        plot.render_to_svg()
+            .expect("SVG should render")
+            .into_bytes()
+    );
+    assert_eq!(ImageFormat::Svg.mime_type(), "image/svg+xml");
+}
+
+#[test]
+fn test_render_to_svg_options_outlines_all_plain_text() {
+    let plot = Plot::new()
+        .title("Outlined")
+        .xlabel("x")
+        .ylabel("y")
+        .line(&[0.0, 1.0, 2.0], &[1.0, 3.0, 2.0])
+        .label("series")
+        .legend(Position::TopRight)
+        .end_series();
+
+    let live = plot.render_to_svg().expect("SVG should render");
+    assert!(live.contains(">Outlined</text>"));
+
+    let outlined = plot
+        .render_to_svg_options(SvgOptions {
+            text_as_paths: true,
+        })
+        .expect("outlined SVG should render");
+    assert!(!outlined.contains("<text"), "no live text should remain");
+    assert!(outlined.matches("<path transform=\"translate(").count() >= 4);
+}
+
+#[test]
+fn test_saved_png_records_figure_dpi_in_phys_chunk() {
+    let pixel_dims = |dpi: u32| {
+        let png = Plot::new()
+            .size(2.0, 1.5)
+            .dpi(dpi)
+            .line(&[0.0, 1.0], &[0.0, 1.0])
+            .save_to_buffer(ImageFormat::Png)
+            .expect("PNG buffer should encode");
+        let reader = ::png::Decoder::new(std::io::Cursor::new(png))
+            .read_info()
+            .expect("PNG header should decode");
+        reader
+            .info()
+            .pixel_dims
+            .expect("pHYs chunk should be written")
+    };
+
+    let print = pixel_dims(300);
+    assert_eq!((print.xppu, print.yppu), (11811, 11811));
+    assert_eq!(print.unit, ::png::Unit::Meter);
+    assert_eq!(pixel_dims(100).xppu, 3937);
+}
+
+#[test]
+fn test_save_hires_scales_pixels_and_keeps_figure_inches() {
+    let tempdir = tempdir().expect("tempdir should be created");
+    let saved_size = |plot: Plot, scale: f32| {
+        let path = tempdir.path().join("hires.png");
+        plot.save_hires(&path, scale)
+            .expect("hires plot should save");
+        let image = ::image::open(&path).expect("saved PNG should decode");
+        (image.width(), image.height())
+    };
+    let line = || Plot::new().line(&[0.0, 1.0, 2.0], &[1.0, 3.0, 2.0]);
+
+    assert_eq!(saved_size(line().end_series(), 2.0), (1280, 960));
+    assert_eq!(
+        saved_size(line().size_px(240, 160).end_series(), 1.5),
+        (360, 240)
+    );
+
+    let scaled = line().end_series().scale_dpi(2.0);
+    assert_eq!(scaled.display.config.figure.dpi, 200.0);
+    assert_eq!(
+        (
+            scaled.display.config.figure.width,
+            scaled.display.config.figure.height
+        ),
+        (6.4, 4.8)
+    );
+
+    for scale in [0.0, -1.0, f32::NAN] {
+        let err = line()
+            .save_hires(tempdir.path().join("bad.png"), scale)
+            .unwrap_err();
+        assert!(matches!(err, PlottingError::InvalidInput(_)));
+    }
+}
+
+#[test]
+fn test_data_to_pixel_matches_rendered_marker_and_round_trips() {
+    let plot: Plot = Plot::new()
+        .title("Coordinates")
+        .ylabel("value")
+        .scatter(&[1.0, 2.0, 3.0], &[1.0, 4.0, 9.0])
+        .color(Color::RED)
+        .marker_size(10.0)
+        .into();
+
+    let image = plot.render().unwrap();
+    let (px, py) = plot.data_to_pixel(2.0, 4.0).unwrap();
+    assert!(image_pixel_is_red(
+        &image,
+        px.round() as u32,
+        py.round() as u32
+    ));
+    let (empty_x, empty_y) = plot.data_to_pixel(1.5, 7.0).unwrap();
+    assert!(!image_has_red_pixel_near(
+        &image,
+        empty_x.round() as u32,
+        empty_y.round() as u32,
+        2
+    ));
+
+    let (x, y) = plot.pixel_to_data(px, py).unwrap();
+    assert!((x - 2.0).abs() < 1e-4, "x = {x}");
+    assert!((y - 4.0).abs() < 1e-4, "y = {y}");
+
+    assert!(plot.data_to_pixel(f64::NAN, 1.0).is_none());
+    assert!(plot.pixel_to_data(f32::INFINITY, 1.0).is_none());
+    let log_plot = plot.clone().yscale(AxisScale::Log);
+    assert!(log_plot.data_to_pixel(1.0, -1.0).is_none());
+    assert!(log_plot.data_to_pixel(1.0, 1.0).is_some());
+}
+
+#[test]
+fn test_data_to_pixel_follows_log_axis_layout() {
+    let plot: Plot = Plot::new()
+        .line(&[1.0, 10.0, 100.0], &[1.0, 10.0, 100.0])
+        .into();
+    let plot = plot.xscale(AxisScale::Log).yscale(AxisScale::Log);
+
+    let (left, bottom) = plot.data_to_pixel(1.0, 1.0).unwrap();
+    let (mid_x, mid_y) = plot.data_to_pixel(10.0, 10.0).unwrap();
+    let (right, top) = plot.data_to_pixel(100.0, 100.0).unwrap();
+    assert!(((mid_x - left) - (right - mid_x)).abs() < 0.5);
+    assert!(((bottom - mid_y) - (mid_y - top)).abs() < 0.5);
+
+    let (x, y) = plot.pixel_to_data(mid_x, mid_y).unwrap();
+    assert!((x - 10.0).abs() < 1e-3 && (y - 10.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_computed_limits_match_render_and_save_bounds() {
+    let plot: Plot = Plot::new().line(&[1.0, 2.0, 3.0], &[-2.0, 5.0, 1.0]).into();
+
+    // Default 5% autoscale margin on both axes
+    let ((x_min, x_max), (y_min, y_max)) = plot.computed_limits().unwrap();
+    assert!((x_min - 0.9).abs() < 1e-12 && (x_max - 3.1).abs() < 1e-12);
+    assert!((y_min + 2.35).abs() < 1e-12 && (y_max - 5.35).abs() < 1e-12);
+
+    // render() and the save() PNG path lay out the same limits pixel for pixel.
+    let (left, bottom) = plot.data_to_pixel(x_min, y_min).unwrap();
+    let (right, top) = plot.data_to_pixel(x_max, y_max).unwrap();
+    let rendered = plot.render().unwrap();
+    let saved = ::image::load_from_memory(&plot.clone().render_png_bytes().unwrap())
+        .unwrap()
+        .to_rgba8();
+    assert_eq!(rendered.pixels, saved.into_raw());
+    assert!(left < right && top < bottom);
+
+    let limited = plot.xlim(0.0, 10.0);
+    let ((x_min, x_max), (y_min, y_max)) = limited.computed_limits().unwrap();
+    assert_eq!((x_min, x_max), (0.0, 10.0));
+    assert!((y_min + 2.35).abs() < 1e-12 && (y_max - 5.35).abs() < 1e-12);
+}
+
+#[test]
+fn test_autoscale_margin_pads_auto_axes_on_every_render_path() {
+    let plot: Plot = Plot::new()
+        .autoscale_margin(0.1)
+        .line(&[1.0, 2.0, 3.0], &[-2.0, 5.0, 1.0])
+        .into();
+
+    let ((x_min, x_max), (y_min, y_max)) = plot.computed_limits().unwrap();
+    assert!((x_min - 0.8).abs() < 1e-6 && (x_max - 3.2).abs() < 1e-6);
+    assert!((y_min + 2.7).abs() < 1e-6 && (y_max - 5.7).abs() < 1e-6);
+
+    let rendered = plot.render().unwrap();
+    let saved = ::image::load_from_memory(&plot.clone().render_png_bytes().unwrap())
+        .unwrap()
+        .to_rgba8();
+    assert_eq!(rendered.pixels, saved.into_raw());
+
+    let limited = plot.clone().ylim(0.0, 1.0);
+    let ((x_min, _), (y_min, y_max)) = limited.computed_limits().unwrap();
+    assert!((x_min - 0.8).abs() < 1e-6);
+    assert_eq!((y_min, y_max), (0.0, 1.0));
+
+    let ignored = plot.autoscale_margin(-1.0).autoscale_margin(f32::NAN);
+    assert!((ignored.computed_limits().unwrap().0.0 - 0.8).abs() < 1e-6);
+}
+
+#[test]
+fn test_autoscale_margin_pads_log_axes_in_decades() {
+    let log_x = |x: &[f64]| -> Plot {
+        Plot::new()
+            .autoscale_margin(0.5)
+            .line(&x.to_vec(), &vec![1.0; x.len()])
+            .xscale(AxisScale::Log)
+            .into()
+    };
+
+    let ((x_min, x_max), _) = log_x(&[2.0, 10.0, 300.0]).computed_limits().unwrap();
+    assert!((x_min - 1.0).abs() < 1e-9, "x_min = {x_min}");
+    assert!((x_max - 1000.0).abs() < 1e-6, "x_max = {x_max}");
+
+    // Data already on decade boundaries is not widened further
+    let ((x_min, x_max), _) = log_x(&[1.0, 10.0, 100.0]).computed_limits().unwrap();
+    assert!((x_min - 1.0).abs() < 1e-9 && (x_max - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_default_autoscale_margin_respects_sticky_edges() {
+    // Bars stay flush with their zero baseline while the top is padded
+    let bars: Plot = Plot::new().bar(&["a", "b"], &[2.0, 4.0]).into();
+    let (_, (y_min, y_max)) = bars.computed_limits().unwrap();
+    assert_eq!(y_min, 0.0);
+    assert!((y_max - 4.2).abs() < 1e-9, "y_max = {y_max}");
+
+    // Heatmaps fill their cells edge to edge
+    let heatmap: Plot = Plot::new()
+        .heatmap(&vec![vec![1.0, 2.0], vec![3.0, 4.0]], None)
+        .into();
+    let ((x_min, x_max), (y_min, y_max)) = heatmap.computed_limits().unwrap();
+    assert_eq!((x_min, x_max), (0.0, 2.0));
+    assert_eq!((y_min, y_max), (0.0, 2.0));
+
+    // Plain lines are padded on both sides by 5% of the data range
+    let line: Plot = Plot::new().line(&[0.0, 10.0], &[0.0, 20.0]).into();
+    let ((x_min, x_max), (y_min, y_max)) = line.computed_limits().unwrap();
+    assert!((x_min + 0.5).abs() < 1e-9 && (x_max - 10.5).abs() < 1e-9);
+    assert!((y_min + 1.0).abs() < 1e-9 && (y_max - 21.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_integer_axis_ticks_skip_fractional_values_within_limits() {
+    let counts = || {
+        Plot::new()
+            .major_ticks(10)
+            .line(&[0.0, 1.0, 2.0, 3.0], &[0.0, 2.0, 1.0, 3.0])
+            .end_series()
+            .ylim(-0.4, 3.4)
+    };
+    let plot = counts();
+    let (x_min, x_max, y_min, y_max) = plot.effective_data_bounds().unwrap();
+    let (_, y_ticks) = plot.configured_major_ticks(x_min, x_max, y_min, y_max);
+    assert!(y_ticks.iter().any(|tick| tick.fract() != 0.0));
+
+    let plot = counts().xaxis_integer(true).yaxis_integer(true);
+    let (x_ticks, y_ticks) = plot.configured_major_ticks(x_min, x_max, y_min, y_max);
+    assert_eq!(y_ticks, vec![0.0, 1.0, 2.0, 3.0]);
+    assert!(!x_ticks.is_empty() && x_ticks.iter().all(|tick| tick.fract() == 0.0));
+
+    let svg = plot.render_to_svg().unwrap();
+    assert!(!svg.contains(">0.5<") && !svg.contains(">1.5<"));
+    assert!(svg.contains(">3<"));
+}
+
+#[test]
+fn test_symmetric_limits_center_auto_axes_on_zero() {
+    let residuals = || {
+        Plot::new()
+            .autoscale_margin(0.0)
+            .scatter(&[1.0, 2.0, 4.0], &[0.5, -2.0, 1.0])
+            .end_series()
+    };
+
+    let plot = residuals().ylim_symmetric();
+    let ((x_min, x_max), (y_min, y_max)) = plot.computed_limits().unwrap();
+    assert_eq!((x_min, x_max), (1.0, 4.0));
+    assert_eq!((y_min, y_max), (-2.0, 2.0));
+
+    // The margin pads the symmetric range evenly
+    let plot = residuals().autoscale_margin(0.25).xlim_symmetric();
+    let ((x_min, x_max), _) = plot.computed_limits().unwrap();
+    assert_eq!((x_min, x_max), (-6.0, 6.0));
+
+    // A later manual limit wins, and log axes are never symmetrized
+    let plot = residuals().ylim_symmetric().ylim(-1.0, 3.0);
+    assert_eq!(plot.computed_limits().unwrap().1, (-1.0, 3.0));
+    let plot = residuals().xlim_symmetric().xscale(AxisScale::Log);
+    assert_eq!(plot.computed_limits().unwrap().0, (1.0, 4.0));
+}
+
+#[test]
+fn test_zorder_draws_higher_series_on_top() {
+    let overlay = |line_zorder: i32| {
+        Plot::new()
+            .grid(false)
+            .line(&[0.0, 2.0], &[1.0, 1.0])
+            .color(Color::RED)
+            .line_width(6.0)
+            .zorder(line_zorder)
+            .scatter(&[1.0], &[1.0])
+            .color(Color::BLUE)
+            .marker_size(40.0)
+            .render()
+            .unwrap()
+    };
+    let red_pixels = |image: Image| {
+        (0..image.height)
+            .flat_map(|y| (0..image.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| image_pixel_is_red(&image, x, y))
+            .count()
+    };
+
+    // Equal z-order keeps insertion order, so the marker covers the line
+    let covered = red_pixels(overlay(0));
+    let on_top = red_pixels(overlay(1));
+    assert!(covered < on_top, "covered {covered} vs on top {on_top}");
+
+    let mut plot: Plot = Plot::new()
+        .line(&[0.0, 1.0], &[0.0, 1.0])
+        .zorder(2)
+        .scatter(&[0.0], &[0.0])
+        .line(&[0.0, 1.0], &[1.0, 0.0])
+        .zorder(-1)
+        .into();
+    plot.series_mgr
+        .series
+        .push(plot.series_mgr.series[1].clone());
+    assert_eq!(
+        PlotSeries::draw_order(&plot.series_mgr.series),
+        vec![2, 1, 3, 0]
+    );
+}
+
+#[test]
+fn test_hidden_series_is_not_drawn_but_keeps_faded_legend_entry() {
+    let plot = |visible: bool| {
+        Plot::new()
+            .grid(false)
+            .line(&[0.0, 1.0], &[0.0, 1.0])
+            .color(Color::RED)
+            .line_width(6.0)
+            .label("red")
+            .visible(visible)
+            .line(&[0.0, 1.0], &[1.0, 0.0])
+            .color(Color::BLUE)
+            .label("blue")
+            .legend(Position::BottomRight)
+            .into_plot()
+    };
+    let red_pixels = |image: Image| {
+        (0..image.height)
+            .flat_map(|y| (0..image.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| image_pixel_is_red(&image, x, y))
+            .count()
+    };
+
+    let hidden = plot(false);
+    assert_eq!(PlotSeries::draw_order(&hidden.series_mgr.series), vec![1]);
+    let entries = hidden.collect_legend_entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].1, vec![0]);
+    assert!(entries[0].0.color.a < Color::RED.a);
+    assert_eq!(entries[1].0.color, Color::BLUE);
+
+    assert!(red_pixels(hidden.render().unwrap()) < red_pixels(plot(true).render().unwrap()) / 10);
+}
+
+#[test]
+fn test_hidden_series_is_left_out_of_autoscaled_limits() {
+    let plot = Plot::new()
+        .autoscale_margin(0.0)
+        .line(&[0.0, 1.0], &[0.0, 1.0])
+        .bar(&["a", "b"], &[-50.0, 80.0])
+        .visible(false)
+        .line(&[0.0, 100.0], &[0.0, 100.0])
+        .visible(false)
+        .into_plot();
+    assert_eq!(plot.computed_limits().unwrap(), ((0.0, 1.0), (0.0, 1.0)));
+    assert_eq!(plot.calculate_data_bounds().unwrap(), (0.0, 1.0, 0.0, 1.0));
+    assert!(plot.render().is_ok());
+
+    let all_hidden = Plot::new()
+        .autoscale_margin(0.0)
+        .line(&[5.0, 6.0], &[5.0, 6.0])
+        .visible(false)
+        .into_plot();
+    assert_eq!(
+        all_hidden.computed_limits().unwrap(),
+        ((0.0, 1.0), (0.0, 1.0))
+    );
+}
+
+#[test]
+fn test_remove_and_replace_series_after_construction() {
+    let mut plot = Plot::new().autoscale_margin(0.0);
+    plot.add_line(&[0.0, 1.0], &[0.0, 1.0]).unwrap();
+    plot.add_line(&[0.0, 10.0], &[0.0, 50.0]).unwrap();
+    plot.add_line(&[0.0, 2.0], &[0.0, 3.0]).unwrap();
+    assert_eq!(plot.series_count(), 3);
+
+    plot.remove_series(1).unwrap();
+    assert_eq!(plot.series_count(), 2);
+    assert_eq!(plot.computed_limits().unwrap(), ((0.0, 2.0), (0.0, 3.0)));
+    // Auto colors stay attached to their series after removal
+    assert_eq!(plot.series_mgr.auto_color_slots, vec![Some(0), Some(2)]);
+
+    plot.replace_series_data(0, &[-4.0, 0.0], &[5.0, 6.0])
+        .unwrap();
+    assert_eq!(plot.series_count(), 2);
+    assert_eq!(plot.computed_limits().unwrap(), ((-4.0, 2.0), (0.0, 6.0)));
+    assert!(plot.render().is_ok());
+
+    assert!(matches!(
+        plot.remove_series(2),
+        Err(PlottingError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        plot.replace_series_data(0, &[1.0], &[1.0, 2.0]),
+        Err(PlottingError::DataLengthMismatch { .. })
+    ));
+
+    let mut bars: Plot = Plot::new().bar(&["a"], &[1.0]).into();
+    assert!(matches!(
+        bars.replace_series_data(0, &[0.0], &[1.0]),
+        Err(PlottingError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn test_template_keeps_styling_without_series() {
+    let base: Plot = Plot::with_theme(Theme::dark())
+        .title("Base")
+        .xlim(0.0, 10.0)
+        .line(&[1.0, 2.0], &[3.0, 4.0])
+        .hline(3.5)
+        .into();
+
+    let template = base.template();
+    assert_eq!(template.series_count(), 0);
+    assert!(template.annotations.is_empty());
+    assert_eq!(template.get_theme(), Theme::dark());
+    assert_eq!(template.layout.x_limits, base.layout.x_limits);
+    assert_eq!(base.series_count(), 1);
+
+    let figure: Plot = template.line(&[0.0, 5.0], &[-1.0, 1.0]).into();
+    assert_eq!(figure.series_count(), 1);
+    assert_eq!(figure.computed_limits().unwrap().0, (0.0, 10.0));
+    assert_eq!(figure.series_mgr.auto_color_slots, vec![Some(0)]);
+    let svg = figure.render_to_svg().unwrap();
+    assert!(svg.contains(">Base<"));
+}
+
+#[test]
+fn test_log_axis_bounds_skip_non_positive_values_and_round_to_decades() {
+    let counts = || {
+        Plot::new()
+            .line(&[1.0, 2.0, 3.0, 4.0], &[0.0, -5.0, 3.0, 450.0])
+            .end_series()
+            .yscale(AxisScale::Log)
+    };
+
+    let (_, (y_min, y_max)) = counts().autoscale_margin(0.0).computed_limits().unwrap();
+    assert_eq!((y_min, y_max), (3.0, 450.0));
+    let (_, (y_min, y_max)) = counts().computed_limits().unwrap();
+    assert!((y_min - 1.0).abs() < 1e-12 && (y_max - 1000.0).abs() < 1e-9);
+    assert!(counts().render().is_ok());
+
+    // Bars drop their zero baseline and start at the smallest bar
+    let bars: Plot = Plot::new()
+        .bar(&["a", "b"], &[20.0, 300.0])
+        .yscale(AxisScale::Log)
+        .autoscale_margin(0.0)
+        .into();
+    assert_eq!(bars.computed_limits().unwrap().1, (20.0, 300.0));
+
+    let no_positive = || {
+        Plot::new()
+            .line(&[1.0, 2.0], &[0.0, -1.0])
+            .end_series()
+            .yscale(AxisScale::Log)
+    };
+    assert!(matches!(
+        no_positive().render(),
+        Err(PlottingError::InvalidData { .. })
+    ));
+    assert!(no_positive().ylim(0.1, 10.0).render().is_ok());
+}
+
+#[test]
+fn test_fill_between_where_draws_one_polygon_per_masked_run() {
+    let x = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let a = [2.0, 3.0, 1.0, 0.5, 3.0, 4.0];
+    let b = [1.0; 6];
+    let above: Vec<bool> = a.iter().zip(&b).map(|(a, b)| a > b).collect();
+    let fill_color = Color::new(200, 30, 30);
+    let svg = |mask: &[bool]| {
+        Plot::new()
+            .line(&x, &a)
+            .end_series()
+            .fill_between_where(&x, &a, &b, mask, FillStyle::new().color(fill_color))
+            .render_to_svg()
+            .unwrap()
+    };
+    let fill_polygons = |svg: &str| {
+        svg.lines()
+            .filter(|line| line.contains("<polygon") && line.contains("rgba(200,30,30,"))
+            .count()
+    };
+
+    assert_eq!(fill_polygons(&svg(&above)), 2);
+    assert_eq!(fill_polygons(&svg(&[true; 6])), 1);
+    assert_eq!(fill_polygons(&svg(&[true; 3])), 0);
+
+    let image = Plot::new()
+        .fill_between_where(&x, &a, &b, &above, FillStyle::new())
+        .line(&x, &a)
+        .render();
+    assert!(image.is_ok());
+}
+
+#[test]
+fn test_line_with_band_shades_in_line_color_with_one_legend_entry() {
+    let x = vec![0.0, 1.0, 2.0, 3.0];
+    let y = vec![1.0, 2.0, 1.5, 2.5];
+    let lower: Vec<f64> = y.iter().map(|v| v - 0.5).collect();
+    let upper: Vec<f64> = y.iter().map(|v| v + 3.0).collect();
+
+    let plot: Plot = Plot::new()
+        .line(&x, &y)
+        .end_series()
+        .line_with_band(&x, &y, &lower, &upper)
+        .label("mean")
+        .into();
+    let band_color = match plot.annotations.last() {
+        Some(Annotation::FillBetween { style, .. }) => style.color,
+        other => panic!("expected a fill-between band, got {other:?}"),
+    };
+    assert_eq!(band_color, plot.display.theme.get_color(1));
+    assert_eq!(plot.collect_legend_items().len(), 1);
+    let (_, _, y_min, y_max) = plot.calculate_data_bounds().unwrap();
+    assert!(y_min <= 0.5 && y_max >= 5.5);
+
+    let line_color = Color::new(10, 120, 40);
+    let svg = Plot::new()
+        .line_with_band(&x, &y, &lower, &upper)
+        .color(line_color)
+        .render_to_svg()
+        .unwrap();
+    assert!(
+        svg.lines()
+            .any(|line| line.contains("<polygon") && line.contains("rgba(10,120,40,"))
+    );
+
+    let err = Plot::new()
+        .line_with_band(&x, &y, &lower[..2].to_vec(), &upper)
+        .render()
+        .unwrap_err();
+    assert!(matches!(err, PlottingError::DataLengthMismatch { .. }));
+}
+
+#[test]
+fn test_line_smoothed_draws_faint_raw_line_under_trend_with_one_legend_entry() {
+    let x = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    let y = vec![1.0, 5.0, 3.0, 7.0, 5.0];
+
+    let plot: Plot = Plot::new()
+        .line(&x, &y)
+        .end_series()
+        .line_smoothed(&x, &y, 3)
+        .label("trend")
+        .into();
+    let series = &plot.series_mgr.series;
+    assert_eq!(series.len(), 3);
+    match (&series[1].series_type, &series[2].series_type) {
+        (SeriesType::Line { y_data: raw, .. }, SeriesType::Line { y_data: trend, .. }) => {
+            assert_eq!(raw.resolve(0.0), y);
+            assert_eq!(trend.resolve(0.0), vec![3.0, 3.0, 5.0, 5.0, 6.0]);
+        }
+        other => panic!("expected two line series, got {other:?}"),
+    }
+    assert_eq!(series[1].label, None);
+    assert_eq!(series[1].alpha, Some(0.3));
+    assert_eq!(series[2].label.as_deref(), Some("trend"));
+    assert_eq!(
+        plot.series_mgr.auto_color_slots,
+        vec![Some(0), Some(1), Some(1)]
+    );
+    assert_eq!(plot.collect_legend_items().len(), 1);
+
+    let svg = Plot::new()
+        .line_smoothed(&x, &y, 3)
+        .color(Color::new(10, 120, 40))
+        .render_to_svg()
+        .unwrap();
+    assert_eq!(svg.matches("rgb(10,120,40)").count(), 1);
+    assert!(svg.contains("rgba(10,120,40,0."));
+}
+
+#[test]
+fn test_regression_draws_fit_line_with_equation_label_and_band() {
+    let x = vec![0.0, 1.0, f64::NAN, 2.0, 3.0];
+    let y = vec![1.0, 2.9, 50.0, 5.1, 7.0];
+
+    let plot: Plot = Plot::new()
+        .scatter(&x, &y)
+        .end_series()
+        .regression(&x, &y)
+        .fit_label()
+        .confidence_band()
+        .into();
+    let series = &plot.series_mgr.series[1];
+    let SeriesType::Line { x_data, y_data } = &series.series_type else {
+        panic!("expected a line series, got {:?}", series.series_type);
+    };
+    let (line_x, line_y) = (x_data.resolve(0.0), y_data.resolve(0.0));
+    assert_eq!(line_x.first(), Some(&0.0));
+    assert_eq!(line_x.last(), Some(&3.0));
+    assert!((line_y[0] - 0.97).abs() < 1e-9);
+    assert!((line_y.last().unwrap() - 7.03).abs() < 1e-9);
+    assert_eq!(
+        series.label.as_deref(),
+        Some("y = 2.020x + 0.970 (R² = 0.999)")
+    );
+    match plot.annotations.last() {
+        Some(Annotation::FillBetween { y1, y2, style, .. }) => {
+            assert!(y1[0] < line_y[0] && y2[0] > line_y[0]);
+            assert_eq!(style.color, plot.display.theme.get_color(1));
+        }
+        other => panic!("expected a confidence band, got {other:?}"),
+    }
+    assert_eq!(plot.collect_legend_items().len(), 1);
+
+    let plain: Plot = Plot::new().regression(&x, &y).into();
+    assert!(plain.annotations.is_empty());
+    assert_eq!(plain.series_mgr.series[0].label, None);
+}
+
+#[test]
+fn test_bar_labels_print_formatted_values_past_bar_ends() {
+    let categories = ["A", "B", "C"];
+    let values = [0.25, 0.5, -0.125];
+
+    let svg = Plot::new()
+        .bar(&categories, &values)
+        .bar_label_formatter(TickLabelFormatter::percent(1))
+        .render_to_svg()
+        .unwrap();
+    for label in [">25.0%<", ">50.0%<", ">-12.5%<"] {
+        assert!(svg.contains(label), "missing bar label {label}");
+    }
+
+    let unlabeled = Plot::new()
+        .bar(&categories, &values)
+        .render_to_svg()
+        .unwrap();
+    assert!(!unlabeled.contains(">0.25<"));
+    let labeled = Plot::new()
+        .bar(&categories, &values)
+        .bar_labels()
+        .render_to_svg()
+        .unwrap();
+    assert!(labeled.contains(">0.25<") && labeled.contains(">-0.125<"));
+
+    let plot = Plot::new();
+    let (size, above) = plot.bar_value_label_placement(100.0, 200.0);
+    assert!(above + size < 100.0);
+    let (_, below) = plot.bar_value_label_placement(200.0, 100.0);
+    assert!(below > 200.0);
+
+    let image = Plot::new().bar(&categories, &values).bar_labels().render();
+    assert!(image.is_ok());
+}
+
+#[test]
+fn test_bar_with_errors_draws_whiskers_on_bar_centers() {
+    let categories = ["A", "B", "C"];
+    let values = [4.0, 6.0, 5.0];
+    let errors = [0.5, 1.5, 0.25];
+
+    let plot: Plot = Plot::new()
+        .bar_with_errors(&categories, &values, &errors)
+        .into();
+    let (x_min, x_max, y_min, y_max) = plot.calculate_data_bounds().unwrap();
+    assert_eq!((x_min, x_max), (-0.5, 2.5));
+    assert_eq!((y_min, y_max), (0.0, 7.5));
+
+    // One whisker plus two caps per bar
+    let count_lines = |svg: &str| svg.matches("<line ").count();
+    let plain = Plot::new()
+        .ylim(0.0, 8.0)
+        .bar(&categories, &values)
+        .render_to_svg()
+        .unwrap();
+    let with_errors = Plot::new()
+        .ylim(0.0, 8.0)
+        .bar_with_errors(&categories, &values, &errors)
+        .render_to_svg()
+        .unwrap();
+    assert_eq!(count_lines(&with_errors), count_lines(&plain) + 9);
+
+    let plot_area = tiny_skia::Rect::from_xywh(0.0, 0.0, 300.0, 100.0).unwrap();
+    let (center, width) = Plot::category_bar_span(1, -0.5, 2.5, plot_area);
+    assert!((center - 150.0).abs() < 1e-3);
+    assert!((width - 80.0).abs() < 1e-3);
+
+    assert!(
+        Plot::new()
+            .bar_with_errors(&categories, &values, &errors)
+            .render()
+            .is_ok()
+    );
+    assert!(matches!(
+        Plot::new()
+            .bar_with_errors(&categories, &values, &[0.5, 1.0])
+            .render(),
+        Err(PlottingError::DataLengthMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_inset_draws_zoomed_plot_with_connectors() {
+    let x: Vec<f64> = (0..100).map(|i| f64::from(i) * 0.1).collect();
+    let main = || Plot::new().grid(false).line(&x, &x).color(Color::RED);
+    let zoom = || Plot::new().grid(false).line(&x, &x).color(Color::BLUE);
+    let inset = || InsetAxes::new(0.55, 0.08, 0.4, 0.4).data_limits(2.0, 4.0, 2.0, 4.0);
+    let is_blue = |pixel: &[u8]| pixel[2] > 200 && pixel[0] < 80 && pixel[1] < 80;
+    let blue_pixels = |image: &Image| {
+        image
+            .pixels
+            .chunks_exact(4)
+            .filter(|pixel| is_blue(pixel))
+            .count()
+    };
+
+    assert_eq!(blue_pixels(&main().render().unwrap()), 0);
+    let with_inset = main().inset(inset(), zoom()).render().unwrap();
+    assert!(blue_pixels(&with_inset) > 50);
+
+    let without_connectors = main()
+        .inset(inset().connectors(false), zoom())
+        .render()
+        .unwrap();
+    assert_ne!(with_inset.pixels, without_connectors.pixels);
+
+    let too_small = main()
+        .inset(InsetAxes::new(0.5, 0.5, 0.0001, 0.0001), zoom())
+        .render();
+    assert!(matches!(too_small, Err(PlottingError::InvalidInput(_))));
+}
+
+#[test]
+fn test_inset_svg_embeds_plot_with_prefixed_ids() {
+    let x: Vec<f64> = (0..20).map(f64::from).collect();
+    let svg = Plot::new()
+        .line(&x, &x)
+        .inset(
+            InsetAxes::new(0.1, 0.55, 0.35, 0.35).data_limits(5.0, 10.0, 5.0, 10.0),
+            Plot::new().line(&x, &x),
+        )
+        .render_to_svg()
+        .unwrap();
+
+    assert_eq!(svg.matches("<svg").count(), 2);
+    assert_eq!(svg.matches("<?xml").count(), 1);
+    assert!(svg.contains(r#"id="inset0-clip"#));
+    assert!(svg.contains("url(#inset0-clip"));
+}
+
+#[test]
+fn test_equal_aspect_letterboxes_plot_area_to_data_units() {
+    let t: Vec<f64> = (0..=64)
+        .map(|i| f64::from(i) * std::f64::consts::TAU / 64.0)
+        .collect();
+    let x: Vec<f64> = t.iter().map(|t| 2.0 * t.cos()).collect();
+    let y: Vec<f64> = t.iter().map(|t| t.sin()).collect();
+    let ellipse = |aspect| -> Plot {
+        Plot::new()
+            .autoscale_margin(0.0)
+            .line(&x, &y)
+            .aspect(aspect)
+            .into_plot()
+    };
+    let extent = |points: &[(f32, f32)]| {
+        let span = |values: Vec<f32>| {
+            values.iter().copied().fold(f32::MIN, f32::max)
+                - values.iter().copied().fold(f32::MAX, f32::min)
+        };
+        (
+            span(points.iter().map(|point| point.0).collect()),
+            span(points.iter().map(|point| point.1).collect()),
+        )
+    };
+
+    let auto = ellipse(AspectMode::Auto);
+    let auto_area = auto.subplot_plot_area(640, 480, 100.0).unwrap();
+    let equal = ellipse(AspectMode::Equal);
+    let equal_area = equal.subplot_plot_area(640, 480, 100.0).unwrap();
+    assert!((equal_area.width() / equal_area.height() - 2.0).abs() < 0.01);
+    assert!((equal_area.width() - auto_area.width()).abs() < 0.5);
+    assert!(equal_area.height() < auto_area.height());
+    let center_y = |area: tiny_skia::Rect| (area.top() + area.bottom()) / 2.0;
+    assert!((center_y(equal_area) - center_y(auto_area)).abs() < 0.5);
+
+    let (width, height) = extent(&extract_first_svg_polyline_points(
+        &equal.render_to_svg().unwrap(),
+    ));
+    assert!((width / height - 2.0).abs() < 0.02);
+
+    let stretched = ellipse(AspectMode::Ratio(4.0));
+    let (width, height) = extent(&extract_first_svg_polyline_points(
+        &stretched.render_to_svg().unwrap(),
+    ));
+    assert!((height / width - 2.0).abs() < 0.02);
+}
+
+#[test]
+fn test_heatmap_square_cells_fit_the_plot_area_to_the_grid() {
+    let values: Vec<Vec<f64>> = (0..3)
+        .map(|row| (0..5).map(|col| f64::from(row * 5 + col)).collect())
+        .collect();
+    let heatmap = |config: crate::plots::heatmap::HeatmapConfig| -> Plot {
+        Plot::new()
+            .heatmap(&values, Some(config.colorbar(false)))
+            .into_plot()
+    };
+    let config = crate::plots::heatmap::HeatmapConfig::new;
+
+    let stretched = heatmap(config())
+        .subplot_plot_area(640, 480, 100.0)
+        .unwrap();
+    let square = heatmap(config().square_cells(true))
+        .subplot_plot_area(640, 480, 100.0)
+        .unwrap();
+    assert!((square.width() / 5.0 - square.height() / 3.0).abs() < 0.5);
+    assert!((square.width() - stretched.width()).abs() < 0.5);
+    assert!(square.height() < stretched.height());
+    let center_y = |area: tiny_skia::Rect| (area.top() + area.bottom()) / 2.0;
+    assert!((center_y(square) - center_y(stretched)).abs() < 0.5);
+
+    // Custom extents keep cells square on screen, not in data units
+    let extended = heatmap(config().square_cells(true).extent(0.0, 10.0, 0.0, 3.0))
+        .subplot_plot_area(640, 480, 100.0)
+        .unwrap();
+    assert!((extended.width() / 5.0 - extended.height() / 3.0).abs() < 0.5);
+
+    let tall = heatmap(config().aspect(2.0))
+        .subplot_plot_area(640, 480, 100.0)
+        .unwrap();
+    assert!((tall.height() / 3.0 - 2.0 * tall.width() / 5.0).abs() < 0.5);
+
+    // A plot-level aspect mode wins over the heatmap's cell aspect
+    let equal = heatmap(config().square_cells(true).extent(0.0, 10.0, 0.0, 3.0))
+        .aspect(AspectMode::Equal)
+        .subplot_plot_area(640, 480, 100.0)
+        .unwrap();
+    assert!((equal.width() / 10.0 - equal.height() / 3.0).abs() < 0.5);
+}
+
+#[test]
+fn test_corr_matrix_labels_variables_and_annotates_coefficients() {
+    let a = vec![1.0, 2.0, 3.0, 4.0];
+    let b = vec![2.0, 1.0, 4.0, 3.0];
+    let c = vec![4.0, 3.0, 2.0, 1.0];
+    let plot = Plot::new()
+        .corr_matrix(&[("alpha", &a), ("beta", &b), ("gamma", &c)])
+        .into_plot();
+
+    let svg = plot.clone().render_to_svg().unwrap();
+    for name in ["alpha", "beta", "gamma"] {
+        assert_eq!(svg.matches(&format!(">{name}</text>")).count(), 2);
+    }
+    assert_eq!(svg.matches(">1.00</text>").count(), 3);
+    assert_eq!(svg.matches(">0.60</text>").count(), 2);
+    assert_eq!(svg.matches(">-1.00</text>").count(), 2);
+    assert_eq!(svg.matches(">-0.60</text>").count(), 2);
+
+    let area = plot.subplot_plot_area(640, 480, 100.0).unwrap();
+    assert!((area.width() - area.height()).abs() < 0.5);
+}
+
+#[test]
+fn test_hist2d_bins_points_into_heatmap_in_data_coordinates() {
+    let x = vec![0.0, 1.0, 3.0, 4.0, 4.0];
+    let y = vec![10.0, 11.0, 19.0, 20.0, 20.0];
+    let plot = Plot::new()
+        .hist2d(&x, &y, (2, 2), crate::render::ColorMap::plasma())
+        .into_plot();
+
+    let SeriesType::Heatmap { data } = &plot.series_mgr.series[0].series_type else {
+        panic!("expected a heatmap series");
+    };
+    assert_eq!(data.values, vec![vec![2.0, 0.0], vec![0.0, 3.0]]);
+    assert_eq!((data.x_extent, data.y_extent), ((0.0, 4.0), (10.0, 20.0)));
+    assert_eq!(
+        data.config.origin,
+        crate::plots::heatmap::HeatmapOrigin::Lower
+    );
+    assert_eq!(data.config.colorbar_label.as_deref(), Some("Count"));
+    assert_eq!(
+        plot.calculate_data_bounds().unwrap(),
+        (0.0, 4.0, 10.0, 20.0)
+    );
+
+    let err = Plot::new()
+        .hist2d(&x, &y, (0, 2), crate::render::ColorMap::plasma())
+        .render()
+        .unwrap_err();
+    assert!(matches!(err, PlottingError::InvalidInput(_)));
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_render_batch_saves_each_plot_and_reports_failures_in_order() {
+    let tempdir = tempdir().expect("tempdir should be created");
+    let paths: Vec<_> = (0..3)
+        .map(|index| tempdir.path().join(format!("batch_{index}.png")))
+        .collect();
+    let plots = vec![
+        Plot::new().line(&[0.0, 1.0], &[0.0, 1.0]).into_plot(),
+        Plot::new()
+            .yscale(AxisScale::Log)
+            .line(&[0.0, 1.0], &[-1.0, -2.0])
+            .into_plot(),
+        Plot::new().scatter(&[0.0, 1.0], &[1.0, 0.0]).into_plot(),
+    ];
+    let expected = plots[2].clone().render_png_bytes().unwrap();
+
+    let results = crate::core::render_batch(plots, paths.clone());
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok() && paths[0].exists());
+    assert!(matches!(results[1], Err(PlottingError::InvalidData { .. })));
+    assert!(!paths[1].exists());
+    assert!(results[2].is_ok());
+    assert_eq!(std::fs::read(&paths[2]).unwrap(), expected);
+
+    let mismatched = crate::core::render_batch(vec![Plot::new()], Vec::new());
+    assert_eq!(mismatched.len(), 1);
+    assert!(matches!(mismatched[0], Err(PlottingError::InvalidInput(_))));
+}
+
+#[test]
+fn test_antialias_toggle_removes_blended_line_pixels() {
+    let blended_red_pixels = |antialias: bool| {
+        let image = Plot::new()
+            .size_px(200, 150)
+            .line(&[0.0, 1.0, 2.0, 3.0], &[0.0, 3.0, 1.0, 2.0])
+            .color(Color::new(255, 0, 0))
+            .end_series()
+            .antialias(antialias)
+            .render()
+            .expect("line plot should render");
+        image
+            .pixels
+            .chunks_exact(4)
+            .filter(|px| px[0] == 255 && px[1] == px[2] && px[1] > 0 && px[1] < 255)
+            .count()
+    };
+
+    assert!(blended_red_pixels(true) > 0);
+    assert_eq!(blended_red_pixels(false), 0);
+}
+
+#[test]
+fn test_stackplot_fills_cumulative_bands_with_palette_legend_entries() {
+    let x = [0.0, 1.0, 2.0];
+    let plot = Plot::new()
+        .autoscale_margin(0.0)
+        .stackplot(
+            &x,
+            &[
+                ("a", vec![1.0, 2.0, 3.0]),
+                ("", vec![1.0, 1.0]),
+                ("c", vec![2.0, f64::NAN, 0.5]),
+            ],
+        )
+        .line(&x, &[0.0, 0.0, 0.0])
+        .end_series();
+
+    let bands: Vec<_> = plot
+        .annotations
+        .iter()
+        .filter_map(|annotation| match annotation {
+            Annotation::FillBetween { y1, y2, style, .. } => Some((y1, y2, style.color)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(bands.len(), 3);
+    assert_eq!(bands[0].0, &vec![0.0, 0.0, 0.0]);
+    assert_eq!(bands[0].1, &vec![1.0, 2.0, 3.0]);
+    assert_eq!(bands[1].1, &vec![2.0, 3.0, 3.0]);
+    assert_eq!(bands[2].0, &vec![2.0, 3.0, 3.0]);
+    assert_eq!(bands[2].1, &vec![4.0, 3.0, 3.5]);
+
+    let theme = &plot.display.theme;
+    for (index, band) in bands.iter().enumerate() {
+        assert_eq!(band.2, theme.get_color(index));
+    }
+    assert_eq!(plot.series_mgr.auto_color_slots, vec![Some(3)]);
+
+    let entries = &plot.layout.legend.entries;
+    assert_eq!(entries.len(), 2);
+    assert_eq!(
+        (entries[0].label.as_str(), entries[0].color),
+        ("a", bands[0].2)
+    );
+    assert_eq!(
+        (entries[1].label.as_str(), entries[1].color),
+        ("c", bands[2].2)
+    );
+
+    let (_, (_, y_max)) = plot.computed_limits().unwrap();
+    assert_eq!(y_max, 4.0);
+}
+
+#[test]
+fn test_log_axis_bounds_keep_smallest_positive_value_of_summarized_series() {
+    let mapped: Plot = Plot::new()
+        .scatter_mapped(
+            &[1.0, 2.0, 3.0],
+            &[0.0, 5.0, 50.0],
+            &[0.0, 1.0, 2.0],
+            crate::render::ColorMap::viridis(),
+        )
+        .yscale(AxisScale::Log)
+        .autoscale_margin(0.0)
+        .into();
+    assert_eq!(mapped.computed_limits().unwrap().1, (5.0, 50.0));
+
+    let strip: Plot = Plot::new()
+        .strip(&[("a", vec![-2.0, 0.0, 4.0, 40.0])], 0.0)
+        .yscale(AxisScale::Log)
+        .autoscale_margin(0.0)
+        .into();
+    assert_eq!(strip.computed_limits().unwrap().1, (4.0, 40.0));
+}
+
+#[test]
+fn test_remove_series_removes_its_band() {
+    let mut plot: Plot = Plot::new()
+        .line_with_band(&[0.0, 1.0], &[1.0, 2.0], &[0.5, 1.5], &[1.5, 2.5])
+        .end_series()
+        .line(&[0.0, 1.0], &[3.0, 4.0])
+        .end_series()
+        .line_with_band(&[0.0, 1.0], &[5.0, 6.0], &[4.5, 5.5], &[5.5, 6.5])
+        .annotate(Annotation::text(0.5, 5.0, "note"))
+        .into();
+    let band_lows = |plot: &Plot| -> Vec<f64> {
+        plot.annotations
+            .iter()
+            .filter_map(|annotation| match annotation {
+                Annotation::FillBetween { y1, .. } => Some(y1[0]),
+                _ => None,
+            })
+            .collect()
+    };
+    assert_eq!(plot.annotations.len(), 3);
+
+    plot.remove_series(0).unwrap();
+    assert_eq!(plot.annotations.len(), 2);
+    assert_eq!(band_lows(&plot), vec![4.5]);
+
+    // The remaining band's index shifted with the removal
+    plot.remove_series(1).unwrap();
+    assert_eq!(plot.annotations.len(), 1);
+    assert!(matches!(plot.annotations[0], Annotation::Text { .. }));
+    plot.remove_series(0).unwrap();
+    assert_eq!(plot.annotations.len(), 1);
+}
+
+#[test]
+fn test_box_half_width_is_finite_for_degenerate_x_range() {
+    assert!((Plot::box_half_width_px(400.0, 0.3, 1.0, 1.0) - 60.0).abs() < 1e-4);
+    assert!((Plot::box_half_width_px(400.0, 0.3, 0.0, 3.0) - 20.0).abs() < 1e-4);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_plots_share_thread_pools() {
+    let threads = rayon::current_num_threads() + 1;
+    let first = Plot::new().with_parallel(Some(threads));
+    let second = Plot::new().with_parallel(Some(threads));
+    assert!(std::sync::Arc::ptr_eq(
+        &first.render.parallel_renderer.pool().unwrap(),
+        &second.render.parallel_renderer.pool().unwrap()
+    ));
+
+    let pool = std::sync::Arc::new(
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .build()
+            .unwrap(),
+    );
+    let first = Plot::new().with_shared_pool(std::sync::Arc::clone(&pool));
+    let second = Plot::new().with_shared_pool(std::sync::Arc::clone(&pool));
+    for plot in [&first, &second] {
+        assert!(std::sync::Arc::ptr_eq(
+            &plot.render.parallel_renderer.pool().unwrap(),
+            &pool
+        ));
+    }
+    assert!(Plot::new().render.parallel_renderer.pool().is_none());
+}
+
+#[test]
+fn test_line_with_marker_draws_markers_through_render() {
+    let red_pixels = |image: &Image| {
+        image
+            .pixels
+            .chunks_exact(4)
+            .filter(|px| px[0] > 200 && px[1] < 60 && px[2] < 60)
+            .count()
+    };
+    let plot = |marker: Option<MarkerStyle>| -> Plot {
+        let builder = Plot::new()
+            .size_px(320, 240)
+            .line(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 0.0, 1.0])
+            .color(Color::new(255, 0, 0));
+        match marker {
+            Some(marker) => builder.marker(marker).marker_size(10.0).into(),
+            None => builder.into(),
+        }
+    };
+
+    let plain = plot(None).render().unwrap();
+    let marked = plot(Some(MarkerStyle::Circle)).render().unwrap();
+    assert!(red_pixels(&marked) > red_pixels(&plain) + 100);
+
+    let svg = plot(Some(MarkerStyle::Circle)).render_to_svg().unwrap();
+    assert_eq!(
+        svg.lines()
+            .filter(|line| line.contains("<circle") && line.contains(r#"fill="rgb(255,0,0)""#))
+            .count(),
+        4
+    );
+}
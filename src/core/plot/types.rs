@@ -46,6 +46,8 @@ pub struct Plot {
     pub(super) render: RenderPipeline,
     /// Annotations (text, arrows, lines, shapes)
     pub(super) annotations: Vec<Annotation>,
+    /// Inset plots drawn over the plot area
+    pub(super) insets: Vec<PlotInset>,
     /// Null policy for dataframe-backed numeric ingestion.
     pub(super) null_policy: NullPolicy,
     /// Deferred ingestion error captured during builder-style API calls.
@@ -271,6 +273,31 @@ impl Default for InsetLayout {
     }
 }
 
+/// How a line series drops points before it is stroked
+///
+/// Downsampling runs in pixel space and only engages when a line has more
+/// points than the plot is wide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DownsampleMode {
+    /// Keep the lowest and highest point of each pixel column, so spikes
+    /// survive (the waveform-viewer approach)
+    MinMax,
+}
+
+/// How the plot area's shape relates to the axis limits
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum AspectMode {
+    /// Fill the space available to the plot area (default)
+    #[default]
+    Auto,
+    /// One data unit spans the same number of pixels on both axes
+    Equal,
+    /// One Y data unit spans this many times the pixels of one X data unit
+    Ratio(f64),
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct PlotSeries {
     /// Series type
@@ -299,6 +326,22 @@ pub(crate) struct PlotSeries {
     pub(super) marker_size: Option<f32>,
     /// Reactive marker size sampled at render time.
     pub(super) marker_size_source: Option<ReactiveValue<f32>>,
+    /// Marker face override (`Some(None)` draws outline-only markers)
+    pub(super) marker_fill: Option<Option<Color>>,
+    /// Marker edge color and width in points
+    pub(super) marker_edge: Option<(Color, f32)>,
+    /// Draw a marker on every nth data point only
+    pub(super) marker_every: Option<usize>,
+    /// Douglas–Peucker tolerance in pixels applied to the drawn line
+    pub(super) simplify: Option<f32>,
+    /// Pixel-column downsampling applied to the drawn line
+    pub(super) downsample: Option<DownsampleMode>,
+    /// Whether the series is drawn; hidden series keep their legend entry
+    pub(super) visible: bool,
+    /// Hatch pattern drawn over filled bars
+    pub(super) hatch: Option<HatchPattern>,
+    /// Draw order; higher values draw on top (default 0, ties keep insertion order)
+    pub(super) zorder: Option<i32>,
     /// Alpha/transparency override
     pub(super) alpha: Option<f32>,
     /// Reactive alpha sampled at render time.
@@ -315,6 +358,8 @@ pub(crate) struct PlotSeries {
     pub(super) group_id: Option<usize>,
     /// Frame-resolved colors for multi-series radar payloads.
     pub(super) resolved_radar_colors: Option<Arc<[Color]>>,
+    /// Index of the band annotation drawn with this series, removed with it.
+    pub(super) band_annotation: Option<usize>,
 }
 
 impl PlotSeries {
@@ -427,6 +472,14 @@ impl PlotSeries {
         let line_width = self.line_width.unwrap_or(theme.line_width);
         let line_style = self.line_style.clone().unwrap_or(LineStyle::Solid);
         let marker_style = self.marker_style.unwrap_or(MarkerStyle::Circle);
+        // Hollow series show an open glyph in the legend.
+        let marker_style = match (self.marker_fill, marker_style) {
+            (Some(None), MarkerStyle::Circle) => MarkerStyle::CircleOpen,
+            (Some(None), MarkerStyle::Square) => MarkerStyle::SquareOpen,
+            (Some(None), MarkerStyle::Triangle) => MarkerStyle::TriangleOpen,
+            (Some(None), MarkerStyle::Diamond) => MarkerStyle::DiamondOpen,
+            (_, style) => style,
+        };
         let marker_size = self.marker_size.unwrap_or(6.0);
 
         let item_type = match &self.series_type {
@@ -445,7 +498,9 @@ impl PlotSeries {
                     }
                 }
             }
-            SeriesType::Scatter { .. } => LegendItemType::Scatter {
+            SeriesType::Scatter { .. }
+            | SeriesType::ScatterMapped { .. }
+            | SeriesType::Strip { .. } => LegendItemType::Scatter {
                 marker: marker_style,
                 size: marker_size,
             },
@@ -458,6 +513,7 @@ impl PlotSeries {
             SeriesType::Heatmap { .. } => return None,
             SeriesType::Kde { .. }
             | SeriesType::Ecdf { .. }
+            | SeriesType::Rug { .. }
             | SeriesType::Polar { .. }
             | SeriesType::Quiver { .. } => LegendItemType::Line {
                 style: line_style,
@@ -466,7 +522,7 @@ impl PlotSeries {
             SeriesType::Violin { .. } | SeriesType::Boxen { .. } | SeriesType::Pie { .. } => {
                 LegendItemType::Bar
             }
-            SeriesType::Contour { .. } => return None,
+            SeriesType::Contour { .. } | SeriesType::Hexbin { .. } => return None,
             SeriesType::Radar { .. } => LegendItemType::Area {
                 edge_color: Some(color),
             },
@@ -668,6 +724,16 @@ impl PlotSeries {
                 .is_some_and(ReactiveValue::is_reactive)
     }
 
+    /// Indices of the visible series in `series_list` in drawing order:
+    /// ascending z-order, with ties kept in insertion order.
+    pub(super) fn draw_order(series_list: &[PlotSeries]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..series_list.len())
+            .filter(|&idx| series_list[idx].visible)
+            .collect();
+        order.sort_by_key(|&idx| series_list[idx].zorder.unwrap_or(0));
+        order
+    }
+
     pub(super) fn clone_for_resolved_frame(&self) -> Self {
         Self {
             series_type: self.series_type.clone_without_static_values(),
@@ -683,6 +749,14 @@ impl PlotSeries {
             marker_style_source: self.marker_style_source.clone(),
             marker_size: self.marker_size,
             marker_size_source: self.marker_size_source.clone(),
+            marker_fill: self.marker_fill,
+            marker_edge: self.marker_edge,
+            marker_every: self.marker_every,
+            simplify: self.simplify,
+            downsample: self.downsample,
+            visible: self.visible,
+            hatch: self.hatch,
+            zorder: self.zorder,
             alpha: self.alpha,
             alpha_source: self.alpha_source.clone(),
             y_errors: self.y_errors.clone(),
@@ -691,6 +765,7 @@ impl PlotSeries {
             inset_layout: self.inset_layout,
             group_id: self.group_id,
             resolved_radar_colors: self.resolved_radar_colors.clone(),
+            band_annotation: self.band_annotation,
         }
     }
 
@@ -738,7 +813,16 @@ impl PlotSeries {
                 _ => unreachable!("live paired source is only used by line/scatter"),
             });
         }
-        self.series_type.resolve_for_render(time)
+        Ok(match self.series_type.resolve_for_render(time)? {
+            ResolvedSeries::Bar {
+                categories, values, ..
+            } => ResolvedSeries::Bar {
+                categories,
+                values,
+                errors: self.y_errors.as_ref().map(ErrorValuesRef::from),
+            },
+            resolved => resolved,
+        })
     }
 
     pub(super) fn subscribe_push_updates(
@@ -807,6 +891,44 @@ impl PlotSeries {
         let alpha = self.alpha.unwrap_or(1.0).clamp(0.0, 1.0);
         color.with_alpha((f32::from(color.a) / 255.0) * alpha)
     }
+
+    /// Stride between marked data points (`markevery`), at least 1.
+    pub(super) fn marker_step(&self) -> usize {
+        self.marker_every.unwrap_or(1).max(1)
+    }
+
+    /// Resolve marker face and edge overrides against the series color.
+    ///
+    /// Returns `None` when neither was set, so callers keep the single-color
+    /// marker paths. `points_to_pixels` converts the edge width.
+    pub(super) fn marker_paint(
+        &self,
+        style: MarkerStyle,
+        color: Color,
+        points_to_pixels: impl Fn(f32) -> f32,
+    ) -> Option<MarkerPaint> {
+        if self.marker_fill.is_none() && self.marker_edge.is_none() {
+            return None;
+        }
+
+        let alpha = self.alpha.unwrap_or(1.0).clamp(0.0, 1.0);
+        let with_series_alpha =
+            |color: Color| color.with_alpha((f32::from(color.a) / 255.0) * alpha);
+        let fill = match self.marker_fill {
+            Some(fill) => fill.map(with_series_alpha),
+            None => style.is_filled().then_some(color),
+        };
+        let (edge, edge_width) = match self.marker_edge {
+            Some((edge, width)) => (with_series_alpha(edge), width),
+            None => (color, DEFAULT_MARKER_EDGE_WIDTH),
+        };
+
+        Some(MarkerPaint {
+            fill,
+            edge,
+            edge_width: points_to_pixels(edge_width.max(0.0)),
+        })
+    }
 }
 
 /// Types of plot series
@@ -823,6 +945,8 @@ pub(crate) enum SeriesType {
     Bar {
         categories: Vec<String>,
         values: PlotData,
+        /// Formatter for per-bar value labels, when shown
+        value_labels: Option<crate::core::TickLabelFormatter>,
     },
     ErrorBars {
         x_data: PlotData,
@@ -855,6 +979,14 @@ pub(crate) enum SeriesType {
     Ecdf {
         data: Arc<crate::plots::EcdfData>,
     },
+    /// Rug of ticks marking each value along an axis edge
+    Rug {
+        data: Arc<crate::plots::RugData>,
+    },
+    /// Jittered scatter of values by category
+    Strip {
+        data: Arc<crate::plots::categorical::StripData>,
+    },
     /// Violin plot
     Violin {
         data: Arc<crate::plots::ViolinData>,
@@ -883,6 +1015,14 @@ pub(crate) enum SeriesType {
     Quiver {
         data: Arc<crate::plots::QuiverPlotData>,
     },
+    /// Hexagonal binning plot
+    Hexbin {
+        data: Arc<crate::plots::HexbinPlotData>,
+    },
+    /// Scatter plot with per-point colormap colors
+    ScatterMapped {
+        data: Arc<crate::plots::ScatterMappedData>,
+    },
 }
 
 impl SeriesType {
@@ -1079,9 +1219,14 @@ impl SeriesType {
                 x_data: x_data.clone_without_static_values(),
                 y_data: y_data.clone_without_static_values(),
             },
-            SeriesType::Bar { categories, values } => SeriesType::Bar {
+            SeriesType::Bar {
+                categories,
+                values,
+                value_labels,
+            } => SeriesType::Bar {
                 categories: categories.clone(),
                 values: values.clone_without_static_values(),
+                value_labels: value_labels.clone(),
             },
             SeriesType::ErrorBars {
                 x_data,
@@ -1133,9 +1278,14 @@ impl SeriesType {
                 x_data: PlotData::Static(x_data.resolve(time)),
                 y_data: PlotData::Static(y_data.resolve(time)),
             },
-            SeriesType::Bar { categories, values } => SeriesType::Bar {
+            SeriesType::Bar {
+                categories,
+                values,
+                value_labels,
+            } => SeriesType::Bar {
                 categories: categories.clone(),
                 values: PlotData::Static(values.resolve(time)),
+                value_labels: value_labels.clone(),
             },
             SeriesType::ErrorBars {
                 x_data,
@@ -1311,11 +1461,13 @@ pub(crate) enum ResolvedSeries<'a> {
     Bar {
         categories: &'a [String],
         values: ResolvedData<'a>,
+        errors: Option<ErrorValuesRef<'a>>,
     },
     ErrorBars {
         x: ResolvedData<'a>,
         y: ResolvedData<'a>,
         y_errors: ResolvedData<'a>,
+        errors: Option<ErrorValuesRef<'a>>,
     },
     ErrorBarsXY {
         x: ResolvedData<'a>,
@@ -1394,9 +1546,12 @@ impl SeriesType {
                 x: ResolvedData::from_cow(x_data.resolve_cow(time)),
                 y: ResolvedData::from_cow(y_data.resolve_cow(time)),
             },
-            SeriesType::Bar { categories, values } => ResolvedSeries::Bar {
+            SeriesType::Bar {
+                categories, values, ..
+            } => ResolvedSeries::Bar {
                 categories,
                 values: ResolvedData::from_cow(values.resolve_cow(time)),
+                errors: None,
             },
             SeriesType::ErrorBars {
                 x_data,
@@ -1406,6 +1561,7 @@ impl SeriesType {
                 x: ResolvedData::from_cow(x_data.resolve_cow(time)),
                 y: ResolvedData::from_cow(y_data.resolve_cow(time)),
                 y_errors: ResolvedData::from_cow(y_errors.resolve_cow(time)),
+                errors: None,
             },
             SeriesType::ErrorBarsXY {
                 x_data,
@@ -1443,6 +1599,10 @@ pub(crate) struct LegendConfig {
     pub(crate) corner_radius: Option<f32>,
     /// Number of columns (1 = vertical, >1 = horizontal/multi-column)
     pub(crate) columns: Option<usize>,
+    /// Heading drawn above the entries
+    pub(crate) title: Option<String>,
+    /// Manual entries listed after the series entries
+    pub(crate) entries: Vec<LegendItem>,
 }
 
 impl Default for LegendConfig {
@@ -1453,6 +1613,8 @@ impl Default for LegendConfig {
             font_size: None,
             corner_radius: None,
             columns: None,
+            title: None,
+            entries: Vec::new(),
         }
     }
 }
@@ -1464,6 +1626,7 @@ impl LegendConfig {
             enabled: self.enabled,
             position: self.position,
             font_size: self.font_size.unwrap_or(default_font_size),
+            title: self.title.clone(),
             ..Legend::default()
         };
         if let Some(radius) = self.corner_radius {
@@ -1498,6 +1661,18 @@ pub(crate) struct TickConfig {
     pub(crate) minor_ticks_y: usize,
     /// Grid display mode
     pub(crate) grid_mode: GridMode,
+    /// Axes whose ticks get grid lines
+    pub(crate) grid_axis: GridAxis,
+    /// Counter-clockwise rotation of X tick labels in degrees
+    pub(crate) x_label_rotation: f32,
+    /// Restrict X major ticks to integer values
+    pub(crate) integer_x: bool,
+    /// Restrict Y major ticks to integer values
+    pub(crate) integer_y: bool,
+    /// Mantissas (2-9) of X log-axis minor ticks that get labels
+    pub(crate) log_minor_labels_x: Vec<u8>,
+    /// Mantissas (2-9) of Y log-axis minor ticks that get labels
+    pub(crate) log_minor_labels_y: Vec<u8>,
 }
 
 impl Default for TickConfig {
@@ -1511,6 +1686,12 @@ impl Default for TickConfig {
             major_ticks_y: 8,
             minor_ticks_y: 0,
             grid_mode: GridMode::MajorOnly,
+            grid_axis: GridAxis::Both,
+            x_label_rotation: 0.0,
+            integer_x: false,
+            integer_y: false,
+            log_minor_labels_x: Vec::new(),
+            log_minor_labels_y: Vec::new(),
         }
     }
 }
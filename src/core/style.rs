@@ -140,6 +140,8 @@ impl PlotStyle {
                     grid_width: 0.3,
                     tick_width: 0.5,
                     tick_length: 3.0,
+                    minor_tick_width: None,
+                    minor_tick_length: None,
                 },
                 spacing: SpacingConfig {
                     title_pad: 8.0,
@@ -172,6 +174,8 @@ impl PlotStyle {
                     grid_width: 0.4,
                     tick_width: 0.5,
                     tick_length: 3.5,
+                    minor_tick_width: None,
+                    minor_tick_length: None,
                 },
                 spacing: SpacingConfig {
                     title_pad: 8.0,
@@ -204,6 +208,8 @@ impl PlotStyle {
                     grid_width: 0.3,
                     tick_width: 0.4,
                     tick_length: 2.5,
+                    minor_tick_width: None,
+                    minor_tick_length: None,
                 },
                 spacing: SpacingConfig {
                     title_pad: 6.0,
@@ -239,6 +245,8 @@ impl PlotStyle {
                     grid_width: 0.25,
                     tick_width: 0.4,
                     tick_length: 2.5,
+                    minor_tick_width: None,
+                    minor_tick_length: None,
                 },
                 spacing: SpacingConfig {
                     title_pad: 5.0,
@@ -271,6 +279,8 @@ impl PlotStyle {
                     grid_width: 0.8,
                     tick_width: 1.0,
                     tick_length: 6.0,
+                    minor_tick_width: None,
+                    minor_tick_length: None,
                 },
                 spacing: SpacingConfig {
                     title_pad: 18.0,
@@ -309,6 +319,8 @@ impl PlotStyle {
                     grid_width: 0.6,
                     tick_width: 0.8,
                     tick_length: 5.0,
+                    minor_tick_width: None,
+                    minor_tick_length: None,
                 },
                 spacing: SpacingConfig {
                     title_pad: 14.0,
@@ -341,6 +353,8 @@ impl PlotStyle {
                     grid_width: 0.5,
                     tick_width: 0.6,
                     tick_length: 4.0,
+                    minor_tick_width: None,
+                    minor_tick_length: None,
                 },
                 spacing: SpacingConfig::default(),
                 margins: MarginConfig::default(),
@@ -368,6 +382,8 @@ impl PlotStyle {
                     grid_width: 1.0,
                     tick_width: 1.2,
                     tick_length: 8.0,
+                    minor_tick_width: None,
+                    minor_tick_length: None,
                 },
                 spacing: SpacingConfig {
                     title_pad: 24.0,
@@ -2,7 +2,10 @@
 ///
 /// Provides grid-based layout system for arranging multiple plots
 /// within a single figure, similar to matplotlib's subplot functionality.
-use crate::core::{Plot, PlottingError, REFERENCE_DPI, RenderScale, Result};
+use crate::core::plot::HeatmapColorbarStyle;
+use crate::core::{
+    Legend, LegendItem, LegendPosition, Plot, PlottingError, REFERENCE_DPI, RenderScale, Result,
+};
 use crate::render::{Theme, skia::SkiaRenderer};
 use tiny_skia::Rect;
 
@@ -32,6 +35,87 @@ impl SuptitleLayout {
     }
 }
 
+/// Figure-level colorbar shared by all heatmap subplots
+struct SharedColorbar {
+    style: HeatmapColorbarStyle,
+    vmin: f64,
+    vmax: f64,
+    /// Width reserved on the right of the grid
+    extent: f32,
+}
+
+/// Figure-level legend collecting the entries of all subplots
+struct FigureLegend {
+    legend: Legend,
+    items: Vec<LegendItem>,
+    width: f32,
+    height: f32,
+    /// Gap between an outside legend and the grid
+    pad: f32,
+}
+
+impl FigureLegend {
+    /// Reserve a band beside the grid for outside positions
+    fn reserve(&self, insets: &mut GridInsets) {
+        match self.legend.position {
+            LegendPosition::OutsideRight => insets.right += self.width + self.pad,
+            LegendPosition::OutsideLeft => insets.left += self.width + self.pad,
+            LegendPosition::OutsideUpper => insets.top += self.height + self.pad,
+            LegendPosition::OutsideLower => insets.bottom += self.height + self.pad,
+            _ => {}
+        }
+    }
+
+    /// Legend frame as `(left, top, right, bottom)`
+    ///
+    /// `content` is the figure area inside the margin and below the suptitle;
+    /// `grid` is the part of it left for the subplot grid.
+    fn bounds(
+        &self,
+        render_scale: RenderScale,
+        content: (f32, f32, f32, f32),
+        grid: (f32, f32, f32, f32),
+    ) -> (f32, f32, f32, f32) {
+        let center_x = (grid.0 + grid.2 - self.width) / 2.0;
+        let center_y = (grid.1 + grid.3 - self.height) / 2.0;
+        let (left, top) = match self.legend.position {
+            LegendPosition::OutsideRight => (content.2 - self.width, center_y),
+            LegendPosition::OutsideLeft => (content.0, center_y),
+            LegendPosition::OutsideUpper => (center_x, content.1),
+            LegendPosition::OutsideLower => (center_x, content.3 - self.height),
+            _ => self
+                .legend
+                .scaled_for_render(render_scale)
+                .calculate_position((self.width, self.height), grid),
+        };
+        (left, top, left + self.width, top + self.height)
+    }
+}
+
+/// Axis shared between subplots
+#[derive(Debug, Clone, Copy)]
+enum SharedAxis {
+    X,
+    Y,
+}
+
+/// A subplot laid out in its grid cell, ready to render
+struct SubplotPanel {
+    plot: Plot,
+    rect: Rect,
+    width: u32,
+    height: u32,
+}
+
+/// Space reserved between the figure margin and the subplot grid, in pixels
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct GridInsets {
+    pub(crate) top: f32,
+    pub(crate) right: f32,
+    pub(crate) bottom: f32,
+    pub(crate) left: f32,
+}
+
 /// Grid specification for subplot layout
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GridSpec {
@@ -39,10 +123,13 @@ pub struct GridSpec {
     pub rows: usize,
     /// Number of columns in the subplot grid
     pub cols: usize,
-    /// Vertical spacing between rows (as a fraction of subplot height)
+    /// Vertical gap between rows (as a fraction of the average subplot height)
     pub hspace: f32,
-    /// Horizontal spacing between columns (as a fraction of subplot width)
+    /// Horizontal gap between columns (as a fraction of the average subplot width)
     pub wspace: f32,
+    /// Outer figure padding as `(left, right, top, bottom)` fractions of the
+    /// figure width/height; `None` uses the figure's uniform margin
+    pub margins: Option<(f32, f32, f32, f32)>,
 }
 
 impl GridSpec {
@@ -62,6 +149,73 @@ impl GridSpec {
             cols,
             hspace: 0.0, // No spacing - subplots fill available area
             wspace: 0.0,
+            margins: None,
+        }
+    }
+
+    /// Set horizontal and vertical gaps between subplots
+    ///
+    /// Like matplotlib's `subplots_adjust(wspace=..., hspace=...)`, each gap is
+    /// a fraction of the average subplot width or height; no gap is added
+    /// around the outside of the grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ruviz::prelude::GridSpec;
+    ///
+    /// let grid = GridSpec::new(2, 2).spacing(0.2, 0.4);
+    /// assert_eq!((grid.wspace, grid.hspace), (0.2, 0.4));
+    /// ```
+    pub fn spacing(self, wspace: f32, hspace: f32) -> Self {
+        self.with_wspace(wspace).with_hspace(hspace)
+    }
+
+    /// Set the outer figure padding on each side
+    ///
+    /// Values are fractions of the figure width (`left`, `right`) or height
+    /// (`top`, `bottom`), clamped to `0.0..=0.4`, and replace the figure's
+    /// uniform margin.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ruviz::prelude::GridSpec;
+    ///
+    /// let grid = GridSpec::new(1, 2).margins(0.05, 0.02, 0.1, 0.08);
+    /// assert_eq!(grid.margins, Some((0.05, 0.02, 0.1, 0.08)));
+    /// ```
+    pub fn margins(mut self, left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        let clamp = |value: f32| value.clamp(0.0, 0.4);
+        self.margins = Some((clamp(left), clamp(right), clamp(top), clamp(bottom)));
+        self
+    }
+
+    /// Outer padding in pixels, from [`GridSpec::margins`] or else `margin` as
+    /// a fraction of the smaller figure dimension
+    pub(crate) fn outer_margins(
+        &self,
+        figure_width: u32,
+        figure_height: u32,
+        margin: f32,
+    ) -> GridInsets {
+        let (width, height) = (figure_width as f32, figure_height as f32);
+        match self.margins {
+            Some((left, right, top, bottom)) => GridInsets {
+                top: top * height,
+                right: right * width,
+                bottom: bottom * height,
+                left: left * width,
+            },
+            None => {
+                let margin_px = margin * width.min(height);
+                GridInsets {
+                    top: margin_px,
+                    right: margin_px,
+                    bottom: margin_px,
+                    left: margin_px,
+                }
+            }
         }
     }
 
@@ -121,7 +275,8 @@ impl GridSpec {
     /// * `index` - Subplot index (row * cols + col)
     /// * `figure_width` - Total figure width in pixels
     /// * `figure_height` - Total figure height in pixels
-    /// * `margin` - Margin as fraction of figure size
+    /// * `margin` - Margin as fraction of figure size, unless
+    ///   [`GridSpec::margins`] is set
     /// * `top_offset` - Additional top offset for suptitle (in pixels)
     pub fn subplot_rect(
         &self,
@@ -130,6 +285,28 @@ impl GridSpec {
         figure_height: u32,
         margin: f32,
         top_offset: f32,
+    ) -> Result<Rect> {
+        self.subplot_rect_with_insets(
+            index,
+            figure_width,
+            figure_height,
+            margin,
+            GridInsets {
+                top: top_offset,
+                ..GridInsets::default()
+            },
+        )
+    }
+
+    /// Like [`GridSpec::subplot_rect`], reserving `insets` inside the figure
+    /// margin for figure-level decorations
+    pub(crate) fn subplot_rect_with_insets(
+        &self,
+        index: usize,
+        figure_width: u32,
+        figure_height: u32,
+        margin: f32,
+        insets: GridInsets,
     ) -> Result<Rect> {
         if index >= self.total_subplots() {
             return Err(PlottingError::InvalidInput(format!(
@@ -143,24 +320,21 @@ impl GridSpec {
         let col = index % self.cols;
 
         // Calculate available space after margins
-        let margin_px = margin * figure_width.min(figure_height) as f32;
-        let available_width = figure_width as f32 - 2.0 * margin_px;
-        // Subtract top_offset from available height to reserve space for suptitle
-        let available_height = figure_height as f32 - 2.0 * margin_px - top_offset;
-
-        // Calculate subplot dimensions with spacing
-        let subplot_width = available_width / self.cols as f32;
-        let subplot_height = available_height / self.rows as f32;
-
-        let spacing_x = subplot_width * self.wspace;
-        let spacing_y = subplot_height * self.hspace;
-
-        let plot_width = subplot_width - spacing_x;
-        let plot_height = subplot_height - spacing_y;
-
-        // Calculate subplot position (add top_offset to y position)
-        let x = margin_px + col as f32 * subplot_width + spacing_x / 2.0;
-        let y = margin_px + top_offset + row as f32 * subplot_height + spacing_y / 2.0;
+        let outer = self.outer_margins(figure_width, figure_height, margin);
+        let left = outer.left + insets.left;
+        let top = outer.top + insets.top;
+        let available_width = figure_width as f32 - left - outer.right - insets.right;
+        let available_height = figure_height as f32 - top - outer.bottom - insets.bottom;
+
+        // Gaps sit only between cells and are sized relative to the average
+        // subplot, as in matplotlib's subplots_adjust
+        let plot_width =
+            available_width / (self.cols as f32 + (self.cols - 1) as f32 * self.wspace);
+        let plot_height =
+            available_height / (self.rows as f32 + (self.rows - 1) as f32 * self.hspace);
+
+        let x = left + col as f32 * plot_width * (1.0 + self.wspace);
+        let y = top + row as f32 * plot_height * (1.0 + self.hspace);
 
         Rect::from_xywh(x, y, plot_width, plot_height).ok_or_else(|| {
             PlottingError::InvalidInput("Invalid subplot dimensions calculated".to_string())
@@ -209,6 +383,17 @@ pub struct SubplotFigure {
     theme: Theme,
     /// Figure margin (fraction of figure size)
     margin: f32,
+    /// Share the x-axis within each column
+    sharex: bool,
+    /// Share the y-axis within each row
+    sharey: bool,
+    /// Share the enabled axes across the whole grid instead of per
+    /// column/row (facet grids)
+    share_across_grid: bool,
+    /// Draw one colorbar for all heatmap subplots
+    shared_colorbar: bool,
+    /// Position of the figure-level legend, if any
+    figure_legend: Option<LegendPosition>,
 }
 
 impl SubplotFigure {
@@ -231,8 +416,18 @@ impl SubplotFigure {
         Ok(value.floor() as u32)
     }
 
-    fn margin_pixels(&self, width: u32, height: u32) -> f32 {
-        self.margin * width.min(height) as f32
+    fn outer_margins(&self, width: u32, height: u32) -> GridInsets {
+        self.grid.outer_margins(width, height, self.margin)
+    }
+
+    /// Typography scale factor for a subplot occupying `subplot_rect`
+    ///
+    /// Uses reference-DPI dimensions so small subplots get the same
+    /// typography adjustment at every requested output DPI.
+    fn typography_scale(subplot_rect: Rect, dpi_scale: f32) -> f32 {
+        let reference_dim = 300.0_f32;
+        let subplot_min_dim = subplot_rect.width().min(subplot_rect.height()) / dpi_scale;
+        (subplot_min_dim / reference_dim).clamp(0.35, 1.0)
     }
 
     fn resolved_suptitle_font_size(&self) -> f32 {
@@ -261,7 +456,7 @@ impl SubplotFigure {
             font_size_px,
             text_width,
             text_height,
-            text_top: self.margin_pixels(width, height) + top_inset,
+            text_top: self.outer_margins(width, height).top + top_inset,
             reserved_height: top_inset + text_height + grid_gap,
         }))
     }
@@ -293,6 +488,11 @@ impl SubplotFigure {
             suptitle_font_size: None,
             theme: Theme::default(),
             margin: 0.05, // 5% margin by default - tighter layout
+            sharex: false,
+            sharey: false,
+            share_across_grid: false,
+            shared_colorbar: false,
+            figure_legend: None,
         })
     }
 
@@ -340,12 +540,143 @@ impl SubplotFigure {
         self
     }
 
+    /// Set horizontal and vertical gaps between subplots
+    ///
+    /// See [`GridSpec::spacing`].
+    pub fn spacing(mut self, wspace: f32, hspace: f32) -> Self {
+        self.grid = self.grid.spacing(wspace, hspace);
+        self
+    }
+
     /// Set figure margin
     pub fn margin(mut self, margin: f32) -> Self {
         self.margin = margin.clamp(0.0, 0.4); // Max 40% margin
         self
     }
 
+    /// Set the outer figure padding per side, overriding [`Self::margin`]
+    ///
+    /// See [`GridSpec::margins`].
+    pub fn margins(mut self, left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        self.grid = self.grid.margins(left, right, top, bottom);
+        self
+    }
+
+    /// Share the x-axis among the subplots of each column
+    ///
+    /// Panels in a column use the union of their x ranges, only the bottom
+    /// panel draws x tick labels, and plot areas are aligned so equal x values
+    /// line up vertically.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let t: Vec<f64> = (0..100).map(|i| i as f64 * 0.1).collect();
+    /// let price: Vec<f64> = t.iter().map(|v| 100.0 + 5.0 * v.sin()).collect();
+    /// let volume: Vec<f64> = t.iter().map(|v| 2000.0 * v.cos().abs()).collect();
+    ///
+    /// subplots(2, 1, 800, 600)?
+    ///     .sharex(true)
+    ///     .subplot_at(0, Plot::new().line(&t, &price).into())?
+    ///     .subplot_at(1, Plot::new().line(&t, &volume).xlabel("Time").into())?
+    ///     .save("shared_x.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn sharex(mut self, share: bool) -> Self {
+        self.sharex = share;
+        self
+    }
+
+    /// Share the y-axis among the subplots of each row
+    ///
+    /// Panels in a row use the union of their y ranges, only the leftmost
+    /// panel draws y tick labels, and plot areas are aligned so equal y values
+    /// line up horizontally.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x: Vec<f64> = (0..100).map(|i| i as f64 * 0.1).collect();
+    /// let morning: Vec<f64> = x.iter().map(|v| 10.0 + v.sin()).collect();
+    /// let evening: Vec<f64> = x.iter().map(|v| 25.0 + 3.0 * v.cos()).collect();
+    ///
+    /// subplots(1, 2, 800, 400)?
+    ///     .sharey(true)
+    ///     .subplot_at(0, Plot::new().line(&x, &morning).ylabel("Load").into())?
+    ///     .subplot_at(1, Plot::new().line(&x, &evening).into())?
+    ///     .save("shared_y.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn sharey(mut self, share: bool) -> Self {
+        self.sharey = share;
+        self
+    }
+
+    /// Share one colorbar between all heatmap subplots
+    ///
+    /// Every heatmap is mapped onto the union of the heatmaps' value ranges
+    /// and their own colorbars are replaced by a single colorbar on the right
+    /// of the figure. The colormap, scale and label are taken from the first
+    /// heatmap in grid order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let low = vec![vec![0.0, 1.0], vec![2.0, 3.0]];
+    /// let high = vec![vec![5.0, 6.0], vec![7.0, 8.0]];
+    ///
+    /// subplots(1, 2, 800, 400)?
+    ///     .subplot_at(0, Plot::new().heatmap(&low, None).end_series())?
+    ///     .subplot_at(1, Plot::new().heatmap(&high, None).end_series())?
+    ///     .shared_colorbar(true)
+    ///     .save("shared_colorbar.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn shared_colorbar(mut self, share: bool) -> Self {
+        self.shared_colorbar = share;
+        self
+    }
+
+    /// Draw one legend for the whole figure
+    ///
+    /// Labeled series from every subplot are collected in grid order, entries
+    /// with a label seen earlier are dropped, and the result replaces the
+    /// per-subplot legends. Outside positions reserve a band beside the grid;
+    /// other positions are placed over the grid area.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x = vec![0.0, 1.0, 2.0];
+    /// let left = Plot::new()
+    ///     .line(&x, &[0.0, 1.0, 4.0]).label("model")
+    ///     .scatter(&x, &[0.1, 1.2, 3.9]).label("data")
+    ///     .end_series();
+    /// let right = Plot::new()
+    ///     .line(&x, &[0.0, 2.0, 8.0]).label("model")
+    ///     .end_series();
+    ///
+    /// subplots(1, 2, 800, 400)?
+    ///     .subplot_at(0, left)?
+    ///     .subplot_at(1, right)?
+    ///     .suptitle("Fits")
+    ///     .figure_legend(LegendPosition::OutsideLower)
+    ///     .save("figure_legend.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn figure_legend(mut self, position: LegendPosition) -> Self {
+        self.figure_legend = Some(position);
+        self
+    }
+
     /// Add a plot at the specified subplot position
     ///
     /// Position is calculated as: index = row * cols + col (0-indexed)
@@ -417,6 +748,264 @@ impl SubplotFigure {
         self.plots.iter().filter(|p| p.is_some()).count()
     }
 
+    /// Global value range and styling for the shared heatmap colorbar
+    fn shared_colorbar_layout(&self, renderer: &SkiaRenderer) -> Result<Option<SharedColorbar>> {
+        let plots = self.plots.iter().flatten();
+        let Some((vmin, vmax)) = plots
+            .clone()
+            .filter_map(Plot::heatmap_value_range)
+            .reduce(|(min, max), (vmin, vmax)| (min.min(vmin), max.max(vmax)))
+        else {
+            return Ok(None);
+        };
+        let Some(style) = plots.filter_map(Plot::heatmap_colorbar_style).next() else {
+            return Ok(None);
+        };
+        let extent = style.measure_extent(renderer, vmin, vmax)?;
+
+        Ok(Some(SharedColorbar {
+            style,
+            vmin,
+            vmax,
+            extent,
+        }))
+    }
+
+    /// Deduplicated entries and measured size of the figure legend
+    fn figure_legend_layout(&self, renderer: &SkiaRenderer) -> Result<Option<FigureLegend>> {
+        let Some(position) = self.figure_legend else {
+            return Ok(None);
+        };
+
+        let mut seen = std::collections::HashSet::new();
+        let items: Vec<LegendItem> = self
+            .plots
+            .iter()
+            .flatten()
+            .flat_map(Plot::collect_legend_items)
+            .filter(|item| seen.insert(item.label.clone()))
+            .collect();
+        if items.is_empty() {
+            return Ok(None);
+        }
+
+        let mut legend = Legend {
+            enabled: true,
+            position,
+            font_size: self.theme.legend_font_size,
+            text_color: self.theme.foreground,
+            ..Legend::default()
+        };
+        legend.style.face_color = self.theme.background;
+        legend.style.edge_color = Some(self.theme.grid_color);
+        let (width, height) = Plot::measure_legend(renderer, &legend, &items)?;
+        let scaled = legend.scaled_for_render(renderer.render_scale());
+        let pad = scaled.spacing.to_pixels(scaled.font_size).border_axes_pad;
+
+        Ok(Some(FigureLegend {
+            legend,
+            items,
+            width,
+            height,
+            pad,
+        }))
+    }
+
+    /// Cartesian panels sharing `axis`: each column for X, each row for Y,
+    /// in grid order
+    fn shared_axis_groups(
+        &self,
+        panels: &[Option<SubplotPanel>],
+        axis: SharedAxis,
+    ) -> Vec<Vec<usize>> {
+        let (groups, members) = match axis {
+            SharedAxis::X => (self.grid.cols, self.grid.rows),
+            SharedAxis::Y => (self.grid.rows, self.grid.cols),
+        };
+        (0..groups)
+            .map(|group| {
+                (0..members)
+                    .map(|member| match axis {
+                        SharedAxis::X => member * self.grid.cols + group,
+                        SharedAxis::Y => group * self.grid.cols + member,
+                    })
+                    .filter(|&index| {
+                        panels[index]
+                            .as_ref()
+                            .is_some_and(|panel| panel.plot.needs_cartesian_axes())
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Give each group of panels sharing `axis` the union of their ranges and
+    /// keep tick labels only on the outer panel (bottom for X, left for Y)
+    ///
+    /// Facet grids share one range across every Cartesian panel while still
+    /// labeling only the outer panel of each column or row.
+    fn share_axis_limits(
+        &self,
+        panels: &mut [Option<SubplotPanel>],
+        axis: SharedAxis,
+    ) -> Result<()> {
+        let label_groups = self.shared_axis_groups(panels, axis);
+        let labeled: Vec<usize> = label_groups
+            .iter()
+            .filter_map(|group| match axis {
+                SharedAxis::X => group.last().copied(),
+                SharedAxis::Y => group.first().copied(),
+            })
+            .collect();
+        let limit_groups = if self.share_across_grid {
+            let mut all: Vec<usize> = label_groups.into_iter().flatten().collect();
+            all.sort_unstable();
+            vec![all]
+        } else {
+            label_groups
+        };
+
+        for group in limit_groups {
+            if group.is_empty() {
+                continue;
+            }
+
+            // Union of the panel ranges, keeping an inverted axis inverted
+            let mut shared: Option<(f64, f64)> = None;
+            let mut inverted = false;
+            for &index in &group {
+                let Some(panel) = &panels[index] else {
+                    continue;
+                };
+                let range = match axis {
+                    SharedAxis::X => panel.plot.shared_x_range()?,
+                    SharedAxis::Y => panel.plot.shared_y_range()?,
+                };
+                if let Some((start, end)) = range {
+                    inverted |= start > end;
+                    let (low, high) = (start.min(end), start.max(end));
+                    shared = Some(match shared {
+                        Some((min, max)) => (min.min(low), max.max(high)),
+                        None => (low, high),
+                    });
+                }
+            }
+
+            // Pad the union once, as an auto-scaled panel would pad its own range
+            if let Some(range) = shared {
+                shared = group
+                    .iter()
+                    .find_map(|&index| {
+                        let plot = &panels[index].as_ref()?.plot;
+                        match axis {
+                            SharedAxis::X => plot.autoscale_x_range(range),
+                            SharedAxis::Y => plot.autoscale_y_range(range),
+                        }
+                    })
+                    .or(shared);
+            }
+
+            for &index in &group {
+                let Some(panel) = panels[index].as_mut() else {
+                    continue;
+                };
+                let mut plot = std::mem::take(&mut panel.plot);
+                if let Some((min, max)) = shared {
+                    let (start, end) = if inverted { (max, min) } else { (min, max) };
+                    plot = match axis {
+                        SharedAxis::X => plot.xlim(start, end),
+                        SharedAxis::Y => plot.ylim(start, end),
+                    };
+                }
+                if !labeled.contains(&index) {
+                    plot = match axis {
+                        SharedAxis::X => plot.xtick_formatter(|_: f64| String::new()),
+                        SharedAxis::Y => plot.ytick_formatter(|_: f64| String::new()),
+                    };
+                }
+                panel.plot = plot;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply the shared axes to the Cartesian panels
+    ///
+    /// Panels sharing X get the widest left and right margins of their column
+    /// so their plot areas span the same pixel columns, and panels sharing Y
+    /// the tallest top and bottom margins of their row.
+    fn share_axes(&self, panels: &mut [Option<SubplotPanel>], dpi: f32) -> Result<()> {
+        if !self.sharex && !self.sharey {
+            return Ok(());
+        }
+        if self.sharex {
+            self.share_axis_limits(panels, SharedAxis::X)?;
+        }
+        if self.sharey {
+            self.share_axis_limits(panels, SharedAxis::Y)?;
+        }
+
+        // Margins of every Cartesian panel as (left, right, top, bottom)
+        let mut margins = vec![None; panels.len()];
+        for (index, panel) in panels.iter().enumerate() {
+            let Some(panel) = panel else {
+                continue;
+            };
+            if !panel.plot.needs_cartesian_axes() {
+                continue;
+            }
+            let area = panel
+                .plot
+                .subplot_plot_area(panel.width, panel.height, dpi)?;
+            margins[index] = Some((
+                area.left(),
+                panel.width as f32 - area.right(),
+                area.top(),
+                panel.height as f32 - area.bottom(),
+            ));
+        }
+        let widest = |group: &[usize], margin: fn(&(f32, f32, f32, f32)) -> f32| {
+            group
+                .iter()
+                .filter_map(|&index| margins[index].as_ref().map(margin))
+                .fold(0.0, f32::max)
+        };
+
+        let mut aligned = margins.clone();
+        if self.sharex {
+            for column in self.shared_axis_groups(panels, SharedAxis::X) {
+                let left = widest(&column, |margin| margin.0);
+                let right = widest(&column, |margin| margin.1);
+                for &index in &column {
+                    if let Some(margin) = aligned[index].as_mut() {
+                        (margin.0, margin.1) = (left, right);
+                    }
+                }
+            }
+        }
+        if self.sharey {
+            for row in self.shared_axis_groups(panels, SharedAxis::Y) {
+                let top = widest(&row, |margin| margin.2);
+                let bottom = widest(&row, |margin| margin.3);
+                for &index in &row {
+                    if let Some(margin) = aligned[index].as_mut() {
+                        (margin.2, margin.3) = (top, bottom);
+                    }
+                }
+            }
+        }
+
+        for (panel, margin) in panels.iter_mut().zip(aligned) {
+            if let (Some(panel), Some((left, right, top, bottom))) = (panel.as_mut(), margin) {
+                panel.plot = std::mem::take(&mut panel.plot)
+                    .set_subplot_margins_px(left, right, top, bottom, dpi);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Render all subplots to a single image and save
     ///
     /// # Example
@@ -485,47 +1074,191 @@ impl SubplotFigure {
             )?;
         }
 
-        // Render each subplot
+        let shared_colorbar = if self.shared_colorbar {
+            self.shared_colorbar_layout(&renderer)?
+        } else {
+            None
+        };
+        let figure_legend = self.figure_legend_layout(&renderer)?;
+
+        // Reserve space around the grid for figure-level decorations
+        let mut insets = GridInsets {
+            top: suptitle_height,
+            right: shared_colorbar
+                .as_ref()
+                .map(|colorbar| colorbar.extent)
+                .unwrap_or(0.0),
+            ..GridInsets::default()
+        };
+        if let Some(legend) = &figure_legend {
+            legend.reserve(&mut insets);
+        }
+        let outer = self.outer_margins(width, height);
+        let content = (
+            outer.left,
+            outer.top + suptitle_height,
+            width as f32 - outer.right,
+            height as f32 - outer.bottom,
+        );
+        let grid = (
+            outer.left + insets.left,
+            outer.top + insets.top,
+            width as f32 - outer.right - insets.right,
+            height as f32 - outer.bottom - insets.bottom,
+        );
+
+        // Lay out each subplot in its cell
+        let mut panels = Vec::with_capacity(self.plots.len());
         for (index, plot_opt) in self.plots.iter().enumerate() {
-            if let Some(plot) = plot_opt {
-                // Calculate subplot area with suptitle offset
-                let subplot_rect =
-                    self.grid
-                        .subplot_rect(index, width, height, self.margin, suptitle_height)?;
-
-                // Calculate typography scale factor based on subplot size and DPI
-                // Use reference-DPI dimensions so small subplots get the same
-                // typography adjustment at every requested output DPI.
-                let reference_dim = 300.0_f32;
-                let subplot_min_dim = subplot_rect.width().min(subplot_rect.height()) / dpi_scale;
-                let size_scale = (subplot_min_dim / reference_dim).clamp(0.35, 1.0);
-
-                // Clone plot and scale typography for small subplots
-                let scaled_plot = plot.clone().scale_typography(size_scale);
-
-                // Create a temporary renderer for this subplot
-                let subplot_theme = scaled_plot.get_theme();
-                let subplot_width = Self::rect_pixel(subplot_rect.width(), "width")?;
-                let subplot_height = Self::rect_pixel(subplot_rect.height(), "height")?;
-                PlottingError::validate_subplot_dimensions(subplot_width, subplot_height)?;
-                let mut subplot_renderer =
-                    SkiaRenderer::new(subplot_width, subplot_height, subplot_theme)?;
-
-                scaled_plot.render_to_renderer(&mut subplot_renderer, dpi)?;
-
-                // Copy subplot renderer to main renderer at correct position
-                renderer.draw_subplot(
-                    subplot_renderer.into_image(),
-                    Self::rect_pixel(subplot_rect.left(), "x position")?,
-                    Self::rect_pixel(subplot_rect.top(), "y position")?,
+            let Some(plot) = plot_opt else {
+                panels.push(None);
+                continue;
+            };
+
+            // Calculate subplot area inside the reserved insets
+            let subplot_rect =
+                self.grid
+                    .subplot_rect_with_insets(index, width, height, self.margin, insets)?;
+
+            let size_scale = Self::typography_scale(subplot_rect, dpi_scale);
+
+            let subplot_width = Self::rect_pixel(subplot_rect.width(), "width")?;
+            let subplot_height = Self::rect_pixel(subplot_rect.height(), "height")?;
+            PlottingError::validate_subplot_dimensions(subplot_width, subplot_height)?;
+
+            // Clone plot and scale typography for small subplots
+            let mut plot = plot.clone().scale_typography(size_scale);
+            if let Some(colorbar) = &shared_colorbar {
+                plot = plot.with_heatmap_value_range(colorbar.vmin, colorbar.vmax);
+            }
+            if figure_legend.is_some() {
+                plot = plot.without_legend();
+            }
+
+            panels.push(Some(SubplotPanel {
+                plot,
+                rect: subplot_rect,
+                width: subplot_width,
+                height: subplot_height,
+            }));
+        }
+
+        self.share_axes(&mut panels, dpi)?;
+
+        if let Some(colorbar) = &shared_colorbar {
+            // Span the colorbar over the plot areas of the heatmap panels
+            let mut span: Option<(f32, f32)> = None;
+            for panel in panels.iter().flatten() {
+                if panel.plot.heatmap_value_range().is_none() {
+                    continue;
+                }
+                let area = panel
+                    .plot
+                    .subplot_plot_area(panel.width, panel.height, dpi)?;
+                let (top, bottom) = (
+                    panel.rect.top() + area.top(),
+                    panel.rect.top() + area.bottom(),
+                );
+                span = Some(match span {
+                    Some((min, max)) => (min.min(top), max.max(bottom)),
+                    None => (top, bottom),
+                });
+            }
+            if let Some((top, bottom)) = span {
+                colorbar.style.draw(
+                    &mut renderer,
+                    colorbar.vmin,
+                    colorbar.vmax,
+                    grid.2,
+                    top,
+                    bottom - top,
+                    self.theme.foreground,
                 )?;
             }
         }
 
+        // Render each subplot
+        for panel in panels.into_iter().flatten() {
+            // Create a temporary renderer for this subplot
+            let mut subplot_renderer =
+                SkiaRenderer::new(panel.width, panel.height, panel.plot.get_theme())?;
+
+            panel.plot.render_to_renderer(&mut subplot_renderer, dpi)?;
+
+            // Copy subplot renderer to main renderer at correct position
+            renderer.draw_subplot(
+                subplot_renderer.into_image(),
+                Self::rect_pixel(panel.rect.left(), "x position")?,
+                Self::rect_pixel(panel.rect.top(), "y position")?,
+            )?;
+        }
+
+        if let Some(legend) = &figure_legend {
+            let bounds = legend.bounds(renderer.render_scale(), content, grid);
+            let grid_rect = Rect::from_ltrb(grid.0, grid.1, grid.2, grid.3).ok_or_else(|| {
+                PlottingError::InvalidInput("Invalid subplot grid dimensions".to_string())
+            })?;
+            renderer.draw_legend_full_resolved(
+                &legend.items,
+                &legend.legend,
+                grid_rect,
+                None,
+                Some(bounds),
+            )?;
+        }
+
         // Save the final figure
         renderer.save_png(path)?;
         Ok(())
     }
+
+    /// Save each subplot as its own PNG file in `dir`
+    ///
+    /// The plot at grid index `i` is written to `{prefix}_{i}.png`; empty
+    /// cells are skipped. Each panel is rendered at the pixel size of its grid
+    /// cell in [`Self::save`] output, with the same small-panel typography,
+    /// so it matches its appearance in the figure. Figure-level decorations
+    /// (suptitle, shared axes, shared colorbar, figure legend) are not
+    /// applied, and the cell size does not shrink to make room for them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ruviz::prelude::*;
+    ///
+    /// let x = vec![1.0, 2.0, 3.0];
+    /// let plot = Plot::new().line(&x, &x).end_series();
+    ///
+    /// let figure = subplots(1, 2, 800, 400)?
+    ///     .subplot_at(0, plot.clone())?
+    ///     .subplot_at(1, plot)?;
+    /// figure.save_panels("panels", "trend")?; // panels/trend_0.png, panels/trend_1.png
+    /// figure.save("trend.png")?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn save_panels<P: AsRef<std::path::Path>>(&self, dir: P, prefix: &str) -> Result<()> {
+        let dir = dir.as_ref();
+        for (index, plot) in self.plots.iter().enumerate() {
+            let Some(plot) = plot else {
+                continue;
+            };
+
+            let subplot_rect =
+                self.grid
+                    .subplot_rect(index, self.width, self.height, self.margin, 0.0)?;
+            let subplot_width = Self::rect_pixel(subplot_rect.width(), "width")?;
+            let subplot_height = Self::rect_pixel(subplot_rect.height(), "height")?;
+            PlottingError::validate_subplot_dimensions(subplot_width, subplot_height)?;
+
+            plot.clone()
+                .scale_typography(Self::typography_scale(subplot_rect, 1.0))
+                .dpi(REFERENCE_DPI as u32)
+                .size_px(subplot_width, subplot_height)
+                .save(dir.join(format!("{prefix}_{index}.png")))?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Convenience function to create a subplot figure
@@ -593,6 +1326,61 @@ pub fn subplots_default(rows: usize, cols: usize) -> Result<SubplotFigure> {
     SubplotFigure::new(rows, cols, width, height)
 }
 
+/// Build one subplot per group with shared axis limits, like seaborn's `FacetGrid`
+///
+/// `plot_fn` receives each group's data and returns its panel. Panels fill a
+/// grid of `ncols` columns (fewer for fewer groups) in group order, each titled
+/// with its group name. Every panel uses the union of all panels' X and Y
+/// ranges, so any two groups compare directly; only the bottom panel of each
+/// column draws X tick labels and only the leftmost panel of each row draws Y
+/// tick labels, as with [`SubplotFigure::sharex`] and
+/// [`SubplotFigure::sharey`]. The figure has the default subplot size; see
+/// [`subplots_default`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ruviz::prelude::*;
+///
+/// let groups = [
+///     ("control", vec![1.0, 2.0, 2.5, 3.0]),
+///     ("treated", vec![2.0, 3.5, 4.0, 6.0]),
+///     ("placebo", vec![1.2, 1.8, 2.2, 2.9]),
+/// ];
+/// let days = vec![0.0, 1.0, 2.0, 3.0];
+///
+/// facet_grid(&groups, 2, |values| Plot::new().line(&days, values))?
+///     .suptitle("Response by group")
+///     .save("facets.png")?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn facet_grid<S, D, P, F>(
+    groups: &[(S, D)],
+    ncols: usize,
+    mut plot_fn: F,
+) -> Result<SubplotFigure>
+where
+    S: AsRef<str>,
+    P: Into<Plot>,
+    F: FnMut(&D) -> P,
+{
+    if ncols == 0 || groups.is_empty() {
+        return Err(PlottingError::InvalidInput(
+            "Facet grid needs at least one group and one column".to_string(),
+        ));
+    }
+
+    let cols = ncols.min(groups.len());
+    let mut figure = subplots_default(groups.len().div_ceil(cols), cols)?
+        .sharex(true)
+        .sharey(true);
+    figure.share_across_grid = true;
+    for (index, (name, data)) in groups.iter().enumerate() {
+        figure = figure.subplot_at(index, plot_fn(data).into().title(name.as_ref()))?;
+    }
+    Ok(figure)
+}
+
 #[cfg(test)]
 #[allow(deprecated)]
 mod tests {
@@ -655,12 +1443,12 @@ mod tests {
         let grid = GridSpec::new(1, 2).with_wspace(0.25);
 
         assert_rect(
-            grid.subplot_rect(0, 800, 400, 0.0, 0.0).unwrap(),
-            (50.0, 0.0, 300.0, 400.0),
+            grid.subplot_rect(0, 900, 400, 0.0, 0.0).unwrap(),
+            (0.0, 0.0, 400.0, 400.0),
         );
         assert_rect(
-            grid.subplot_rect(1, 800, 400, 0.0, 0.0).unwrap(),
-            (450.0, 0.0, 300.0, 400.0),
+            grid.subplot_rect(1, 900, 400, 0.0, 0.0).unwrap(),
+            (500.0, 0.0, 400.0, 400.0),
         );
     }
 
@@ -669,12 +1457,12 @@ mod tests {
         let grid = GridSpec::new(2, 1).with_hspace(0.5);
 
         assert_rect(
-            grid.subplot_rect(0, 800, 600, 0.0, 0.0).unwrap(),
-            (0.0, 75.0, 800.0, 150.0),
+            grid.subplot_rect(0, 800, 500, 0.0, 0.0).unwrap(),
+            (0.0, 0.0, 800.0, 200.0),
         );
         assert_rect(
-            grid.subplot_rect(1, 800, 600, 0.0, 0.0).unwrap(),
-            (0.0, 375.0, 800.0, 150.0),
+            grid.subplot_rect(1, 800, 500, 0.0, 0.0).unwrap(),
+            (0.0, 300.0, 800.0, 200.0),
         );
     }
 
@@ -683,18 +1471,39 @@ mod tests {
         let grid = GridSpec::new(2, 2).with_hspace(0.5).with_wspace(0.25);
 
         for (index, expected) in [
-            (0, (50.0, 75.0, 300.0, 150.0)),
-            (1, (450.0, 75.0, 300.0, 150.0)),
-            (2, (50.0, 375.0, 300.0, 150.0)),
-            (3, (450.0, 375.0, 300.0, 150.0)),
+            (0, (0.0, 0.0, 400.0, 200.0)),
+            (1, (500.0, 0.0, 400.0, 200.0)),
+            (2, (0.0, 300.0, 400.0, 200.0)),
+            (3, (500.0, 300.0, 400.0, 200.0)),
         ] {
             assert_rect(
-                grid.subplot_rect(index, 800, 600, 0.0, 0.0).unwrap(),
+                grid.subplot_rect(index, 900, 500, 0.0, 0.0).unwrap(),
                 expected,
             );
         }
     }
 
+    #[test]
+    fn test_grid_margins_override_uniform_margin_per_side() {
+        let grid = GridSpec::new(1, 2)
+            .spacing(0.25, 0.0)
+            .margins(0.1, 0.0, 0.2, 0.1);
+
+        // 1000x500: left 100, top 100, bottom 50, leaving 900x350 for the grid
+        assert_rect(
+            grid.subplot_rect(0, 1000, 500, 0.3, 0.0).unwrap(),
+            (100.0, 100.0, 400.0, 350.0),
+        );
+        assert_rect(
+            grid.subplot_rect(1, 1000, 500, 0.3, 0.0).unwrap(),
+            (600.0, 100.0, 400.0, 350.0),
+        );
+        assert_eq!(
+            GridSpec::new(1, 1).margins(-1.0, 0.1, 0.5, 0.2).margins,
+            Some((0.0, 0.1, 0.4, 0.2))
+        );
+    }
+
     #[test]
     fn test_suptitle_measurement_drives_title_and_grid_top_geometry() {
         let without_title = SubplotFigure::new(1, 1, 800, 600).unwrap().margin(0.1);
@@ -844,6 +1653,298 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sharex_unions_ranges_hides_inner_labels_and_aligns_plot_areas() {
+        let x_top: Vec<f64> = (0..=10).map(f64::from).collect();
+        let y_top: Vec<f64> = x_top.iter().map(|v| v.sin()).collect();
+        let x_bottom: Vec<f64> = (5..=20).map(f64::from).collect();
+        let y_bottom: Vec<f64> = x_bottom.iter().map(|v| 100_000.0 * v.cos()).collect();
+        let top: Plot = Plot::new().line(&x_top, &y_top).into();
+        let bottom: Plot = Plot::new().line(&x_bottom, &y_bottom).into();
+
+        let figure = SubplotFigure::new(2, 1, 600, 500)
+            .unwrap()
+            .sharex(true)
+            .subplot_at(0, top.clone())
+            .unwrap()
+            .subplot_at(1, bottom.clone())
+            .unwrap();
+        let mut panels: Vec<Option<SubplotPanel>> = [top, bottom]
+            .into_iter()
+            .enumerate()
+            .map(|(index, plot)| {
+                let rect = figure
+                    .grid
+                    .subplot_rect(index, 600, 500, figure.margin, 0.0)
+                    .unwrap();
+                Some(SubplotPanel {
+                    plot,
+                    rect,
+                    width: rect.width() as u32,
+                    height: rect.height() as u32,
+                })
+            })
+            .collect();
+        let plot_area = |panel: &SubplotPanel| {
+            panel
+                .plot
+                .subplot_plot_area(panel.width, panel.height, REFERENCE_DPI)
+                .unwrap()
+        };
+        let before: Vec<Rect> = panels.iter().flatten().map(plot_area).collect();
+        assert!(
+            (before[0].left() - before[1].left()).abs() > 1.0,
+            "wider y tick labels should offset the unshared bottom panel"
+        );
+
+        figure.share_axes(&mut panels, REFERENCE_DPI).unwrap();
+        let panels: Vec<SubplotPanel> = panels.into_iter().flatten().collect();
+        let after: Vec<Rect> = panels.iter().map(plot_area).collect();
+
+        for panel in &panels {
+            // Union of 0..10 and 5..20, padded once by the default 5% margin
+            let (min, max) = panel.plot.shared_x_range().unwrap().unwrap();
+            assert!((min + 1.0).abs() < 1e-9 && (max - 21.0).abs() < 1e-9);
+        }
+        assert_close(after[0].left(), after[1].left(), "shared left edge");
+        assert_close(after[0].right(), after[1].right(), "shared right edge");
+        assert!(
+            after[0].bottom() > before[0].bottom(),
+            "hidden x tick labels should give the top panel its bottom margin back"
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sharex.png");
+        figure.save(&path).unwrap();
+        assert_eq!(image::image_dimensions(path).unwrap(), (600, 500));
+    }
+
+    #[test]
+    fn test_save_panels_writes_each_plot_at_its_cell_size() {
+        let plot: Plot = Plot::new().line(&[0.0, 1.0], &[0.0, 1.0]).into();
+        let figure = subplots(2, 2, 800, 600)
+            .unwrap()
+            .subplot_at(0, plot.clone())
+            .unwrap()
+            .subplot_at(3, plot)
+            .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+
+        figure.save_panels(dir.path(), "panel").unwrap();
+
+        let cell = figure
+            .grid
+            .subplot_rect(3, 800, 600, figure.margin, 0.0)
+            .unwrap();
+        let expected = (cell.width().floor() as u32, cell.height().floor() as u32);
+        for index in [0, 3] {
+            let path = dir.path().join(format!("panel_{index}.png"));
+            assert_eq!(image::image_dimensions(path).unwrap(), expected);
+        }
+        assert!(!dir.path().join("panel_1.png").exists());
+        assert!(!dir.path().join("panel_2.png").exists());
+    }
+
+    #[test]
+    fn test_facet_grid_titles_panels_and_shares_limits() {
+        let groups = [
+            ("a", vec![0.0, 1.0]),
+            ("b", vec![0.0, 10.0]),
+            ("c", vec![-5.0, 0.0]),
+        ];
+        let figure = facet_grid(&groups, 2, |y| {
+            let plot = Plot::new().line(&[0.0, y.len() as f64], y);
+            if y[1] > 5.0 {
+                plot.xlim(10.0, 0.0)
+            } else {
+                plot
+            }
+        })
+        .unwrap();
+
+        assert_eq!((figure.grid.rows, figure.grid.cols), (2, 2));
+        assert_eq!(figure.subplot_count(), 3);
+        assert!(figure.sharex && figure.sharey);
+        for (plot, name) in figure.plots.iter().flatten().zip(["a", "b", "c"]) {
+            assert!(
+                plot.render_to_svg()
+                    .unwrap()
+                    .contains(&format!(">{name}</text>"))
+            );
+        }
+
+        let mut panels: Vec<Option<SubplotPanel>> = figure
+            .plots
+            .iter()
+            .enumerate()
+            .map(|(index, plot)| {
+                let rect = figure
+                    .grid
+                    .subplot_rect(index, figure.width, figure.height, figure.margin, 0.0)
+                    .unwrap();
+                Some(SubplotPanel {
+                    plot: plot.clone()?,
+                    rect,
+                    width: rect.width() as u32,
+                    height: rect.height() as u32,
+                })
+            })
+            .collect();
+        figure.share_axes(&mut panels, REFERENCE_DPI).unwrap();
+        let range = |index: usize| {
+            let plot = &panels[index].as_ref().unwrap().plot;
+            (
+                plot.shared_x_range().unwrap().unwrap(),
+                plot.shared_y_range().unwrap().unwrap(),
+            )
+        };
+        let close = |(min, max): (f64, f64), expected: (f64, f64)| {
+            (min - expected.0).abs() < 1e-9 && (max - expected.1).abs() < 1e-9
+        };
+
+        // Every panel, across rows and columns, gets the same union padded
+        // once by the 5% margin
+        let (a_x, a_y) = range(0);
+        let (b_x, b_y) = range(1);
+        let (c_x, c_y) = range(2);
+        assert!(close(b_x, (10.5, -0.5)), "an inverted panel stays inverted");
+        assert_eq!(a_x, b_x);
+        assert_eq!(a_x, c_x);
+        assert!(close(a_y, (-5.75, 10.75)));
+        assert_eq!(a_y, b_y);
+        assert_eq!(a_y, c_y);
+
+        let single = facet_grid(&groups[..1], 4, |y| Plot::new().line(&[0.0, 1.0], y)).unwrap();
+        assert_eq!((single.grid.rows, single.grid.cols), (1, 1));
+        assert!(facet_grid(&groups, 0, |y| Plot::new().line(&[0.0, 1.0], y)).is_err());
+    }
+
+    #[test]
+    fn test_shared_colorbar_unions_heatmap_ranges_and_reserves_right_space() {
+        let low: Plot = Plot::new()
+            .heatmap(&vec![vec![0.0, 1.0], vec![2.0, 3.0]], None)
+            .into();
+        let high: Plot = Plot::new()
+            .heatmap(&vec![vec![5.0, 6.0], vec![7.0, 10.0]], None)
+            .into();
+        let line: Plot = Plot::new().line(&[0.0, 1.0], &[0.0, 1.0]).into();
+
+        let figure = SubplotFigure::new(1, 3, 900, 300)
+            .unwrap()
+            .shared_colorbar(true)
+            .subplot_at(0, low.clone())
+            .unwrap()
+            .subplot_at(1, high)
+            .unwrap()
+            .subplot_at(2, line)
+            .unwrap();
+        let (renderer, width, height) = renderer_for(&figure, REFERENCE_DPI);
+        let colorbar = figure.shared_colorbar_layout(&renderer).unwrap().unwrap();
+        assert_eq!((colorbar.vmin, colorbar.vmax), (0.0, 10.0));
+        assert!(colorbar.extent > 0.0);
+
+        let shared = low.with_heatmap_value_range(colorbar.vmin, colorbar.vmax);
+        assert_eq!(shared.heatmap_value_range(), Some((0.0, 10.0)));
+        assert!(
+            shared
+                .heatmap_colorbar_style()
+                .is_some_and(|style| style.center.is_none())
+        );
+
+        let plain = figure
+            .grid
+            .subplot_rect(2, width, height, figure.margin, 0.0)
+            .unwrap();
+        let inset = figure
+            .grid
+            .subplot_rect_with_insets(
+                2,
+                width,
+                height,
+                figure.margin,
+                GridInsets {
+                    right: colorbar.extent,
+                    ..GridInsets::default()
+                },
+            )
+            .unwrap();
+        assert_close(
+            plain.right() - inset.right(),
+            colorbar.extent,
+            "reserved colorbar width",
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("shared_colorbar.png");
+        figure.save(&path).unwrap();
+        let image = image::open(path).unwrap().to_rgba8();
+        // The bar itself starts 10px right of the grid and is 20px wide
+        let colorbar_x = (inset.right() + 20.0) as u32;
+        let has_colorbar_ink = (0..height).any(|y| {
+            let pixel = image.get_pixel(colorbar_x, y);
+            pixel[0] != pixel[2]
+        });
+        assert!(
+            has_colorbar_ink,
+            "colorbar should be drawn right of the grid"
+        );
+    }
+
+    #[test]
+    fn test_figure_legend_dedupes_labels_and_reserves_outside_band() {
+        let x = [0.0, 1.0, 2.0];
+        let left: Plot = Plot::new()
+            .line(&x, &[0.0, 1.0, 4.0])
+            .label("model")
+            .scatter(&x, &[0.1, 1.2, 3.9])
+            .label("data")
+            .into();
+        let right: Plot = Plot::new()
+            .line(&x, &[0.0, 2.0, 8.0])
+            .label("model")
+            .line(&x, &[1.0, 1.0, 1.0])
+            .into();
+
+        let figure = SubplotFigure::new(1, 2, 800, 400)
+            .unwrap()
+            .figure_legend(LegendPosition::OutsideLower)
+            .subplot_at(0, left)
+            .unwrap()
+            .subplot_at(1, right)
+            .unwrap();
+        let (renderer, _, _) = renderer_for(&figure, REFERENCE_DPI);
+        let legend = figure.figure_legend_layout(&renderer).unwrap().unwrap();
+        let labels: Vec<&str> = legend
+            .items
+            .iter()
+            .map(|item| item.label.as_str())
+            .collect();
+        assert_eq!(labels, ["model", "data"]);
+
+        let mut insets = GridInsets::default();
+        legend.reserve(&mut insets);
+        assert_close(insets.bottom, legend.height + legend.pad, "legend band");
+        assert_eq!((insets.top, insets.right, insets.left), (0.0, 0.0, 0.0));
+
+        let content = (20.0, 20.0, 780.0, 380.0);
+        let grid = (20.0, 20.0, 780.0, 380.0 - insets.bottom);
+        let (left, top, right, bottom) = legend.bounds(renderer.render_scale(), content, grid);
+        assert_close(bottom, 380.0, "legend bottom");
+        assert!(top >= grid.3, "outside legend should sit below the grid");
+        assert_close((left + right) / 2.0, 400.0, "legend center");
+
+        let inside = figure.clone().figure_legend(LegendPosition::UpperLeft);
+        let inside_legend = inside.figure_legend_layout(&renderer).unwrap().unwrap();
+        let mut insets = GridInsets::default();
+        inside_legend.reserve(&mut insets);
+        assert_eq!(insets, GridInsets::default());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("figure_legend.png");
+        figure.save(&path).unwrap();
+        assert_eq!(image::image_dimensions(path).unwrap(), (800, 400));
+    }
+
     #[test]
     fn test_subplot_figure_creation() {
         let figure = SubplotFigure::new(2, 3, 800, 600).unwrap();
@@ -19,9 +19,15 @@
 //! // Result: "5" (not "5.0")
 //! ```
 
+use std::fmt;
+use std::sync::Arc;
+
 /// Nice numbers for tick selection (powers of 10 multiplied by these)
 const NICE_NUMBERS: [f64; 4] = [1.0, 2.0, 5.0, 10.0];
 
+/// SI prefixes from pico (10^-12) to peta (10^15), indexed by exponent / 3 + 4
+const SI_PREFIXES: [&str; 10] = ["p", "n", "µ", "m", "", "k", "M", "G", "T", "P"];
+
 /// Tick formatter configuration
 ///
 /// Provides nice number selection for tick values and clean label formatting
@@ -312,6 +318,273 @@ impl TickFormatter {
     }
 }
 
+/// Custom tick label formatter, the equivalent of matplotlib's `FuncFormatter`
+///
+/// Set per axis with `Plot::xtick_formatter` / `Plot::ytick_formatter`. Any
+/// `Fn(f64) -> String + Send + Sync` closure converts into a formatter, and the
+/// constructors below cover common cases.
+///
+/// # Example
+///
+/// ```rust
+/// use ruviz::core::TickLabelFormatter;
+///
+/// assert_eq!(TickLabelFormatter::percent(0).format(0.25), "25%");
+/// assert_eq!(TickLabelFormatter::si_prefix(1).format(1_500_000.0), "1.5M");
+/// assert_eq!(TickLabelFormatter::currency("$", 2).format(-1234.5), "-$1,234.50");
+/// assert_eq!(TickLabelFormatter::fixed(2).format(3.0), "3.00");
+/// assert_eq!(TickLabelFormatter::si_prefix_sig(2).format(123_456.0), "120k");
+/// assert_eq!(TickLabelFormatter::scientific().format(-0.00042), "-4.2e-4");
+///
+/// let hours = TickLabelFormatter::new(|v| format!("{v}h"));
+/// assert_eq!(hours.format(12.0), "12h");
+/// ```
+#[derive(Clone)]
+pub struct TickLabelFormatter {
+    format: Arc<dyn Fn(f64) -> String + Send + Sync>,
+    /// Factor a common power of ten out of each tick set
+    scientific_offset: bool,
+}
+
+impl TickLabelFormatter {
+    /// Create a formatter from a closure
+    pub fn new<F>(formatter: F) -> Self
+    where
+        F: Fn(f64) -> String + Send + Sync + 'static,
+    {
+        Self {
+            format: Arc::new(formatter),
+            scientific_offset: false,
+        }
+    }
+
+    /// Default labels with a common power of ten factored out, like
+    /// matplotlib's offset text
+    ///
+    /// When the largest tick would otherwise switch to e-notation (at or
+    /// above 10⁴, or below 10⁻⁴), the ticks are divided by its power of ten
+    /// and the axis shows the factor once as `"×10ⁿ"` (see
+    /// [`offset_text`](Self::offset_text)): ticks at 10000, 20000, and 30000
+    /// read `"1"`, `"2"`, `"3"` with `"×10⁴"` at the axis end.
+    /// `Plot::scientific_notation(true)` applies this to linear axes that
+    /// have no custom formatter.
+    pub fn scientific_offset() -> Self {
+        let formatter = TickFormatter::default();
+        Self {
+            format: Arc::new(move |value| formatter.format_tick(value)),
+            scientific_offset: true,
+        }
+    }
+
+    /// Fixed number of decimal places: `fixed(2)` formats `3.0` as `"3.00"`
+    pub fn fixed(decimals: usize) -> Self {
+        Self::new(move |value| format!("{value:.decimals$}"))
+    }
+
+    /// Percent of a fraction: `percent(0)` formats `0.25` as `"25%"`
+    pub fn percent(decimals: usize) -> Self {
+        Self::new(move |value| format!("{:.decimals$}%", value * 100.0))
+    }
+
+    /// Currency with thousands separators: `currency("$", 2)` formats `-1234.5` as `"-$1,234.50"`
+    pub fn currency(symbol: impl Into<String>, decimals: usize) -> Self {
+        let symbol = symbol.into();
+        Self::new(move |value| {
+            let formatted = format!("{:.decimals$}", value.abs());
+            let (integer, fraction) = match formatted.split_once('.') {
+                Some((integer, fraction)) => (integer, Some(fraction)),
+                None => (formatted.as_str(), None),
+            };
+            let sign = if value < 0.0 && formatted.bytes().any(|b| matches!(b, b'1'..=b'9')) {
+                "-"
+            } else {
+                ""
+            };
+            let mut label = format!("{sign}{symbol}{}", group_thousands(integer));
+            if let Some(fraction) = fraction {
+                label.push('.');
+                label.push_str(fraction);
+            }
+            label
+        })
+    }
+
+    /// SI prefixes (p, n, µ, m, k, M, G, T, P) with up to `decimals` places:
+    /// `si_prefix(1)` formats `1500.0` as `"1.5k"` and `2000.0` as `"2k"`
+    pub fn si_prefix(decimals: usize) -> Self {
+        Self::new(move |value| format_si_prefix(value, decimals))
+    }
+
+    /// SI prefixes rounded to `sig_figs` significant figures:
+    /// `si_prefix_sig(2)` formats `123456.0` as `"120k"` and `1500.0` as `"1.5k"`
+    pub fn si_prefix_sig(sig_figs: usize) -> Self {
+        Self::new(move |value| format_si_prefix_sig(value, sig_figs.max(1)))
+    }
+
+    /// Scientific notation with up to three mantissa places:
+    /// `scientific()` formats `1500.0` as `"1.5e3"` and `2e6` as `"2e6"`
+    pub fn scientific() -> Self {
+        Self::new(|value| {
+            let formatted = format!("{value:.3e}");
+            match formatted.split_once('e') {
+                Some((mantissa, exponent)) => {
+                    format!(
+                        "{}e{exponent}",
+                        TickFormatter::trim_trailing_zeros(mantissa)
+                    )
+                }
+                None => formatted,
+            }
+        })
+    }
+
+    /// Scientific notation with exactly `decimals` mantissa places:
+    /// `scientific_decimals(2)` formats `1500.0` as `"1.50e3"`
+    pub fn scientific_decimals(decimals: usize) -> Self {
+        Self::new(move |value| format!("{value:.decimals$e}"))
+    }
+
+    /// Format a single tick value
+    ///
+    /// A [`scientific_offset`](Self::scientific_offset) formatter needs the
+    /// whole tick set to choose its exponent, so it formats single values
+    /// without an offset.
+    pub fn format(&self, value: f64) -> String {
+        (self.format)(value)
+    }
+
+    /// Format every tick value
+    pub fn format_ticks(&self, values: &[f64]) -> Vec<String> {
+        if !self.scientific_offset {
+            return values.iter().map(|&value| self.format(value)).collect();
+        }
+        let formatter = TickFormatter::default();
+        match self.offset_exponent(values) {
+            Some(exponent) => {
+                let scale = 10_f64.powi(exponent);
+                let scaled: Vec<f64> = values.iter().map(|value| value / scale).collect();
+                formatter.format_ticks(&scaled)
+            }
+            None => formatter.format_ticks(values),
+        }
+    }
+
+    /// The `"×10ⁿ"` factor a [`scientific_offset`](Self::scientific_offset)
+    /// formatter took out of `values`, if any
+    pub fn offset_text(&self, values: &[f64]) -> Option<String> {
+        self.offset_exponent(values)
+            .map(|exponent| format!("×10{}", superscript_exponent(exponent)))
+    }
+
+    fn offset_exponent(&self, values: &[f64]) -> Option<i32> {
+        if !self.scientific_offset {
+            return None;
+        }
+        let largest = values
+            .iter()
+            .filter(|value| value.is_finite())
+            .fold(0.0_f64, |largest, value| largest.max(value.abs()));
+        let threshold = TickFormatter::default().scientific_threshold;
+        if largest == 0.0 || (largest < threshold && largest >= 1.0 / threshold) {
+            return None;
+        }
+        Some(largest.log10().floor() as i32)
+    }
+}
+
+impl<F> From<F> for TickLabelFormatter
+where
+    F: Fn(f64) -> String + Send + Sync + 'static,
+{
+    fn from(formatter: F) -> Self {
+        Self::new(formatter)
+    }
+}
+
+impl fmt::Debug for TickLabelFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TickLabelFormatter(..)")
+    }
+}
+
+/// Exponent written with Unicode superscript digits, e.g. `-4` as `"⁻⁴"`
+pub(crate) fn superscript_exponent(exponent: i32) -> String {
+    let mut formatted = String::new();
+    if exponent < 0 {
+        formatted.push('⁻');
+    }
+    for digit in exponent.unsigned_abs().to_string().chars() {
+        formatted.push(match digit {
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            _ => digit,
+        });
+    }
+    formatted
+}
+
+fn group_thousands(digits: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+fn format_si_prefix(value: f64, decimals: usize) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    if value == 0.0 {
+        return "0".to_string();
+    }
+
+    let mut exponent = ((value.abs().log10() / 3.0).floor() as i32 * 3).clamp(-12, 15);
+    let mut formatted = format!("{:.decimals$}", value / 10_f64.powi(exponent));
+    // Rounding can carry into the next prefix, e.g. 999.96 -> "1000.0".
+    if exponent < 15 && formatted.trim_start_matches('-').starts_with("1000") {
+        exponent += 3;
+        formatted = format!("{:.decimals$}", value / 10_f64.powi(exponent));
+    }
+
+    let prefix = SI_PREFIXES[(exponent / 3 + 4) as usize];
+    format!("{}{prefix}", TickFormatter::trim_trailing_zeros(&formatted))
+}
+
+fn format_si_prefix_sig(value: f64, sig_figs: usize) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    if value == 0.0 {
+        return "0".to_string();
+    }
+
+    // Round in decimal through e-notation, which also handles carries such as
+    // 999.96 -> "1.0e3".
+    let rounded = format!("{value:.prec$e}", prec = sig_figs - 1);
+    let (_, decimal_exponent) = rounded.split_once('e').expect("e-notation has an exponent");
+    let decimal_exponent: i32 = decimal_exponent.parse().expect("valid exponent");
+    let rounded: f64 = rounded.parse().expect("valid e-notation");
+
+    let exponent = (decimal_exponent.div_euclid(3) * 3).clamp(-12, 15);
+    let decimals = (sig_figs as i32 - 1 - (decimal_exponent - exponent)).max(0) as usize;
+    let formatted = format!("{:.decimals$}", rounded / 10_f64.powi(exponent));
+
+    let prefix = SI_PREFIXES[(exponent / 3 + 4) as usize];
+    format!("{}{prefix}", TickFormatter::trim_trailing_zeros(&formatted))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -466,4 +739,59 @@ mod tests {
         assert_eq!(TickFormatter::trim_trailing_zeros("5"), "5");
         assert_eq!(TickFormatter::trim_trailing_zeros("0.100"), "0.1");
     }
+
+    #[test]
+    fn test_tick_label_formatter_builtins() {
+        let percent = TickLabelFormatter::percent(1);
+        assert_eq!(
+            percent.format_ticks(&[0.0, 0.125, 1.0]),
+            vec!["0.0%", "12.5%", "100.0%"]
+        );
+
+        let si = TickLabelFormatter::si_prefix(2);
+        assert_eq!(
+            si.format_ticks(&[0.0, 0.0025, 999.999, 1_250.0, -3.0e9, 1.0e18]),
+            vec!["0", "2.5m", "1k", "1.25k", "-3G", "1000P"]
+        );
+
+        let currency = TickLabelFormatter::currency("€", 0);
+        assert_eq!(
+            currency.format_ticks(&[0.0, 999.0, 1_000.0, -1_234_567.0, -0.2]),
+            vec!["€0", "€999", "€1,000", "-€1,234,567", "€0"]
+        );
+
+        let si_sig = TickLabelFormatter::si_prefix_sig(2);
+        assert_eq!(
+            si_sig.format_ticks(&[0.0, 123_456.0, 1_500.0, 2.0e6, 999.6, -0.00123, 12.0]),
+            vec!["0", "120k", "1.5k", "2M", "1k", "-1.2m", "12"]
+        );
+        assert_eq!(TickLabelFormatter::si_prefix_sig(3).format(0.5), "500m");
+        assert_eq!(TickLabelFormatter::si_prefix_sig(1).format(1.0e18), "1000P");
+
+        let scientific = TickLabelFormatter::scientific();
+        assert_eq!(
+            scientific.format_ticks(&[0.0, 1_500.0, 2.0e6, -0.025, 0.1 + 0.2]),
+            vec!["0e0", "1.5e3", "2e6", "-2.5e-2", "3e-1"]
+        );
+
+        let scientific = TickLabelFormatter::scientific_decimals(1);
+        assert_eq!(
+            scientific.format_ticks(&[0.0, 1_500.0, 2.0e6, -0.025, 9.96]),
+            vec!["0.0e0", "1.5e3", "2.0e6", "-2.5e-2", "1.0e1"]
+        );
+
+        let offset = TickLabelFormatter::scientific_offset();
+        let ticks = [0.0, 10_000.0, 20_000.0, 30_000.0];
+        assert_eq!(offset.format_ticks(&ticks), vec!["0", "1", "2", "3"]);
+        assert_eq!(offset.offset_text(&ticks).as_deref(), Some("×10⁴"));
+        let ticks = [-0.00002, 0.0, 0.00004];
+        assert_eq!(offset.format_ticks(&ticks), vec!["-2", "0", "4"]);
+        assert_eq!(offset.offset_text(&ticks).as_deref(), Some("×10⁻⁵"));
+        assert_eq!(offset.format_ticks(&[0.0, 0.5, 1.0]), vec!["0", "0.5", "1"]);
+        assert_eq!(offset.offset_text(&[0.0, 0.5, 1.0]), None);
+        assert_eq!(TickLabelFormatter::fixed(1).offset_text(&ticks), None);
+
+        let closure: TickLabelFormatter = (|v: f64| format!("{v:+}")).into();
+        assert_eq!(closure.format(2.0), "+2");
+    }
 }
@@ -0,0 +1,247 @@
+//! CSV loading for numeric plot data (requires the `csv` feature)
+//!
+//! [`CsvData`] reads a delimited text file into numeric columns that can be
+//! passed straight to the plotting API. Cells that are empty or do not parse as
+//! numbers become `NaN`, so every column keeps one value per row and the
+//! renderers skip those points the same way they skip any other non-finite
+//! value.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use ruviz::data::csv::{CsvData, CsvOptions};
+//! use ruviz::prelude::*;
+//!
+//! let csv = CsvData::from_path("measurements.csv")?;
+//! Plot::new()
+//!     .line(csv.column("time")?, csv.column("voltage")?)
+//!     .save("voltage.png")?;
+//!
+//! // Semicolon-separated file without a header row
+//! let raw = CsvData::from_path_with_options("raw.csv", &CsvOptions::new().delimiter(b';').has_headers(false))?;
+//! let x = raw.column_at(0)?;
+//! ```
+
+use std::io::Read;
+use std::path::Path;
+
+use crate::core::{PlottingError, Result};
+
+/// Parsing options for [`CsvData`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Field delimiter byte (default `b','`)
+    pub delimiter: u8,
+    /// Whether the first row holds column names (default `true`)
+    pub has_headers: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            has_headers: true,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Create options for a comma-separated file with a header row
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the field delimiter, e.g. `b';'` or `b'\t'`
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set whether the first row holds column names
+    ///
+    /// Without a header row, columns are named by their zero-based index
+    /// (`"0"`, `"1"`, ...).
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+}
+
+/// Numeric columns loaded from a CSV source.
+///
+/// All columns have the same length. Non-numeric and missing cells are stored
+/// as `NaN`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvData {
+    headers: Vec<String>,
+    columns: Vec<Vec<f64>>,
+}
+
+impl CsvData {
+    /// Load a comma-separated file with a header row
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_path_with_options(path, &CsvOptions::default())
+    }
+
+    /// Load a file using custom parsing options
+    pub fn from_path_with_options<P: AsRef<Path>>(path: P, options: &CsvOptions) -> Result<Self> {
+        let file = std::fs::File::open(path.as_ref())?;
+        Self::from_reader(file, options)
+    }
+
+    /// Parse CSV data from any reader
+    pub fn from_reader<R: Read>(reader: R, options: &CsvOptions) -> Result<Self> {
+        let mut reader = ::csv::ReaderBuilder::new()
+            .delimiter(options.delimiter)
+            .has_headers(options.has_headers)
+            .flexible(true)
+            .from_reader(reader);
+
+        let mut headers: Vec<String> = if options.has_headers {
+            reader
+                .headers()
+                .map_err(csv_error)?
+                .iter()
+                .map(|name| name.trim().to_string())
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let mut columns: Vec<Vec<f64>> = vec![Vec::new(); headers.len()];
+
+        for (rows, record) in reader.records().enumerate() {
+            let record = record.map_err(csv_error)?;
+            // Ragged rows widen the table; earlier rows read as missing cells
+            while columns.len() < record.len() {
+                columns.push(vec![f64::NAN; rows]);
+            }
+            for (index, column) in columns.iter_mut().enumerate() {
+                column.push(record.get(index).map_or(f64::NAN, parse_cell));
+            }
+        }
+
+        while headers.len() < columns.len() {
+            headers.push(headers.len().to_string());
+        }
+
+        Ok(Self { headers, columns })
+    }
+
+    /// Column names in file order
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    /// Number of data rows
+    pub fn len(&self) -> usize {
+        self.columns.first().map_or(0, Vec::len)
+    }
+
+    /// Whether the source had no data rows
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of columns
+    pub fn column_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Look up a column by its header name
+    pub fn column(&self, name: &str) -> Result<&Vec<f64>> {
+        self.headers
+            .iter()
+            .position(|header| header == name)
+            .map(|index| &self.columns[index])
+            .ok_or_else(|| {
+                PlottingError::InvalidInput(format!(
+                    "CSV column '{}' not found (available: {})",
+                    name,
+                    self.headers.join(", ")
+                ))
+            })
+    }
+
+    /// Look up a column by its zero-based position
+    pub fn column_at(&self, index: usize) -> Result<&Vec<f64>> {
+        self.columns.get(index).ok_or_else(|| {
+            PlottingError::InvalidInput(format!(
+                "CSV column index {} out of range ({} columns)",
+                index,
+                self.columns.len()
+            ))
+        })
+    }
+}
+
+fn parse_cell(cell: &str) -> f64 {
+    cell.trim().parse().unwrap_or(f64::NAN)
+}
+
+fn csv_error(err: ::csv::Error) -> PlottingError {
+    PlottingError::DataExtractionFailed {
+        source: "CSV".to_string(),
+        message: err.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str, options: CsvOptions) -> CsvData {
+        CsvData::from_reader(text.as_bytes(), &options).unwrap()
+    }
+
+    #[test]
+    fn test_columns_by_name_and_index() {
+        let data = parse("x, y\n1,2\n3,4\n", CsvOptions::new());
+
+        assert_eq!(data.headers(), ["x", "y"]);
+        assert_eq!(data.len(), 2);
+        assert_eq!(data.column("x").unwrap(), &vec![1.0, 3.0]);
+        assert_eq!(data.column_at(1).unwrap(), &vec![2.0, 4.0]);
+        assert!(data.column("z").is_err());
+        assert!(data.column_at(2).is_err());
+    }
+
+    #[test]
+    fn test_non_numeric_and_missing_cells_become_nan() {
+        let data = parse("a;b\n1;n/a\n;2.5\n3\n", CsvOptions::new().delimiter(b';'));
+
+        let a = data.column("a").unwrap();
+        let b = data.column("b").unwrap();
+        assert_eq!(a.len(), 3);
+        assert_eq!(b.len(), 3);
+        assert_eq!(a[0], 1.0);
+        assert!(a[1].is_nan());
+        assert!(b[0].is_nan());
+        assert_eq!(b[1], 2.5);
+        assert!(b[2].is_nan());
+    }
+
+    #[test]
+    fn test_headerless_columns_are_named_by_index() {
+        let data = parse(
+            "1\t2\n3\t4\n",
+            CsvOptions::new().delimiter(b'\t').has_headers(false),
+        );
+
+        assert_eq!(data.headers(), ["0", "1"]);
+        assert_eq!(data.column("1").unwrap(), &vec![2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_from_path_reads_file_and_feeds_plot() {
+        let tempdir = tempfile::tempdir().expect("tempdir");
+        let path = tempdir.path().join("data.csv");
+        std::fs::write(&path, "t,v\n0,1\n1,nan\n2,3\n").unwrap();
+
+        let data = CsvData::from_path(&path).unwrap();
+
+        let image = crate::core::Plot::new()
+            .line(data.column("t").unwrap(), data.column("v").unwrap())
+            .render();
+        assert!(image.is_ok());
+    }
+}
@@ -1,15 +1,40 @@
 //! Simple DataShader implementation without parallel features
 //! This will be used to make tests pass initially, then enhanced
 
+use crate::axes::AxisScale;
+use crate::core::Colorbar;
 use crate::core::error::{PlottingError, Result};
 use crate::core::types::{BoundingBox, Point2f};
+use crate::render::ColorMap;
 use std::sync::atomic::{AtomicU32, Ordering};
 
+/// Points aggregated between progress callbacks in chunked aggregation
+const AGGREGATION_CHUNK_POINTS: usize = 1 << 16;
+
+/// How the values of points landing in the same canvas cell are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AggMode {
+    /// Number of points in the cell
+    #[default]
+    Count,
+    /// Sum of the cell's values
+    Sum,
+    /// Mean of the cell's values
+    Mean,
+    /// Smallest value in the cell
+    Min,
+    /// Largest value in the cell
+    Max,
+}
+
 /// Simple DataShader canvas for aggregation
 pub struct DataShaderCanvas {
     width: usize,
     height: usize,
     canvas: Vec<AtomicU32>,
+    /// Per-cell running sum, minimum, or maximum for value aggregation
+    accumulators: Vec<f64>,
+    agg_mode: AggMode,
     bounds: BoundingBox,
     total_points: u64,
 }
@@ -24,6 +49,8 @@ impl DataShaderCanvas {
             width,
             height,
             canvas,
+            accumulators: vec![0.0; canvas_size],
+            agg_mode: AggMode::Count,
             bounds: BoundingBox::new(0.0, 1.0, 0.0, 1.0),
             total_points: 0,
         }
@@ -64,6 +91,8 @@ impl DataShaderCanvas {
             width,
             height,
             canvas,
+            accumulators: vec![0.0; canvas_size],
+            agg_mode: AggMode::Count,
             bounds,
             total_points: 0,
         }
@@ -89,6 +118,11 @@ impl DataShaderCanvas {
         self.height
     }
 
+    /// Get the aggregation mode of the last aggregation
+    pub fn agg_mode(&self) -> AggMode {
+        self.agg_mode
+    }
+
     /// Clear the canvas
     pub fn clear(&self) {
         for cell in &self.canvas {
@@ -128,8 +162,9 @@ impl DataShaderCanvas {
         self.aggregate_points(&point2f_vec);
     }
 
-    /// Aggregate points
+    /// Aggregate points, counting them per cell
     pub fn aggregate_points(&mut self, points: &[Point2f]) {
+        self.agg_mode = AggMode::Count;
         for point in points {
             if let Some((grid_x, grid_y)) = self.world_to_grid(point) {
                 let idx = grid_y * self.width + grid_x;
@@ -142,6 +177,65 @@ impl DataShaderCanvas {
         self.total_points += points.len() as u64;
     }
 
+    /// Aggregate a value per point, combining values in each cell with `mode`
+    ///
+    /// Points whose value is not finite are skipped unless `mode` is
+    /// [`AggMode::Count`].
+    pub fn aggregate_values(&mut self, points: &[(f64, f64)], values: &[f64], mode: AggMode) {
+        self.agg_mode = mode;
+        for (&(x, y), &value) in points.iter().zip(values) {
+            if mode != AggMode::Count && !value.is_finite() {
+                continue;
+            }
+            let Some((grid_x, grid_y)) = self.world_to_grid(&Point2f::new(x as f32, y as f32))
+            else {
+                continue;
+            };
+            let idx = grid_y * self.width + grid_x;
+            if idx >= self.canvas.len() {
+                continue;
+            }
+
+            // The first point in a cell overwrites whatever a previous
+            // aggregation left in its accumulator
+            let previous_count = self.canvas[idx].fetch_add(1, Ordering::Relaxed);
+            let accumulator = &mut self.accumulators[idx];
+            *accumulator = match mode {
+                AggMode::Count => 0.0,
+                _ if previous_count == 0 => value,
+                AggMode::Sum | AggMode::Mean => *accumulator + value,
+                AggMode::Min => accumulator.min(value),
+                AggMode::Max => accumulator.max(value),
+            };
+        }
+
+        self.total_points += points.len().min(values.len()) as u64;
+    }
+
+    /// Get the aggregated value at grid position, or `None` for an empty cell
+    pub fn get_value(&self, grid_x: usize, grid_y: usize) -> Option<f64> {
+        let count = self.get_count(grid_x, grid_y)?;
+        if count == 0 {
+            return None;
+        }
+
+        let accumulator = self.accumulators[grid_y * self.width + grid_x];
+        Some(match self.agg_mode {
+            AggMode::Count => f64::from(count),
+            AggMode::Sum | AggMode::Min | AggMode::Max => accumulator,
+            AggMode::Mean => accumulator / f64::from(count),
+        })
+    }
+
+    /// Get the aggregated values row by row from `min_y` upward, with NaN for
+    /// empty cells
+    pub fn values(&self) -> Vec<f64> {
+        (0..self.height)
+            .flat_map(|grid_y| (0..self.width).map(move |grid_x| (grid_x, grid_y)))
+            .map(|(grid_x, grid_y)| self.get_value(grid_x, grid_y).unwrap_or(f64::NAN))
+            .collect()
+    }
+
     /// Get aggregated count at grid position
     pub fn get_count(&self, grid_x: usize, grid_y: usize) -> Option<u32> {
         if grid_x >= self.width || grid_y >= self.height {
@@ -220,6 +314,64 @@ impl DataShaderCanvas {
 
         pixels
     }
+
+    /// Smallest and largest aggregated values after `scale` is applied, or
+    /// `None` when no cell has a value to show
+    fn color_range(&self, scale: &AxisScale) -> Option<(f64, f64)> {
+        let (min, max) = self
+            .values()
+            .into_iter()
+            .filter_map(|value| scaled_color_value(value, scale))
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        (min <= max).then_some((min, max))
+    }
+
+    /// Create RGBA image data by mapping aggregated values through `colormap`
+    ///
+    /// Values are transformed by `scale` first (see [`DataShader::color_scale`]),
+    /// then the smallest maps to the start of the colormap and the largest to
+    /// its end. Empty cells, and negative values on a log scale, stay
+    /// transparent.
+    pub fn to_colormapped_image_data(&self, colormap: &ColorMap, scale: &AxisScale) -> Vec<u8> {
+        let values = self.values();
+        let (min, max) = self.color_range(scale).unwrap_or((0.0, 0.0));
+        let mut pixels = Vec::with_capacity(self.width * self.height * 4);
+
+        for y in 0..self.height {
+            let source_y = self.height - 1 - y;
+            for &value in &values[source_y * self.width..(source_y + 1) * self.width] {
+                let Some(value) = scaled_color_value(value, scale) else {
+                    pixels.extend_from_slice(&[0, 0, 0, 0]);
+                    continue;
+                };
+                let t = if max > min {
+                    (value - min) / (max - min)
+                } else {
+                    0.5
+                };
+                let color = colormap.sample(t);
+                pixels.extend_from_slice(&[color.r, color.g, color.b, color.a]);
+            }
+        }
+
+        pixels
+    }
+}
+
+/// Apply a DataShader color scale to an aggregated value
+///
+/// Log scaling uses `log1p`, so zero stays finite and maps to zero.
+fn scaled_color_value(value: f64, scale: &AxisScale) -> Option<f64> {
+    if !value.is_finite() {
+        return None;
+    }
+    match scale {
+        AxisScale::Log if value < 0.0 => None,
+        AxisScale::Log => Some(value.ln_1p()),
+        _ => Some(value),
+    }
 }
 
 /// Statistics about aggregated data
@@ -254,6 +406,7 @@ impl DataShaderImage {
 /// DataShader facade - simple aggregation for massive datasets
 pub struct DataShader {
     canvas: DataShaderCanvas,
+    color_scale: AxisScale,
 }
 
 impl Default for DataShader {
@@ -272,9 +425,21 @@ impl DataShader {
     pub fn with_canvas_size(width: usize, height: usize) -> Self {
         Self {
             canvas: DataShaderCanvas::new(width, height),
+            color_scale: AxisScale::Linear,
         }
     }
 
+    /// Set how aggregated values are scaled before the colormap lookup
+    ///
+    /// `AxisScale::Log` maps `log1p(value)` (datashader's `how='log'`), which
+    /// keeps sparse pixels visible next to a few hyper-dense ones. Other
+    /// scales map values linearly. Applies to [`DataShader::render_colormapped`]
+    /// and [`DataShader::colorbar`].
+    pub fn color_scale(mut self, scale: AxisScale) -> Self {
+        self.color_scale = scale;
+        self
+    }
+
     /// Check if DataShader should be activated for the given point count
     pub fn should_activate(point_count: usize) -> bool {
         point_count >= 100_000
@@ -323,15 +488,79 @@ impl DataShader {
             return Err(PlottingError::EmptyDataSet);
         }
 
-        // Auto-calculate bounds if not set
+        self.fit_bounds(x_data, y_data);
+        self.aggregate_with_current_bounds(x_data, y_data, |_| Ok(()))
+    }
+
+    /// Aggregate a third value per point, combining the values that land in
+    /// each cell with `mode`
+    ///
+    /// Bounds are fitted to the data as in [`DataShader::aggregate`]. Use
+    /// [`DataShader::render_colormapped`] to turn the result into an image.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ruviz::data::{AggMode, DataShader};
+    /// use ruviz::render::ColorMap;
+    ///
+    /// let x = vec![0.0, 0.1, 1.0];
+    /// let y = vec![0.0, 0.1, 1.0];
+    /// let temperature = vec![10.0, 20.0, 35.0];
+    ///
+    /// let mut shader = DataShader::with_canvas_size(2, 2);
+    /// shader.aggregate_with(&x, &y, &temperature, AggMode::Mean)?;
+    /// assert_eq!(shader.canvas().get_value(0, 0), Some(15.0));
+    ///
+    /// let image = shader.render_colormapped(&ColorMap::viridis());
+    /// assert_eq!(image.pixels.len(), 2 * 2 * 4);
+    /// # Ok::<(), ruviz::core::PlottingError>(())
+    /// ```
+    pub fn aggregate_with(
+        &mut self,
+        x_data: &[f64],
+        y_data: &[f64],
+        values: &[f64],
+        mode: AggMode,
+    ) -> Result<()> {
+        if x_data.len() != y_data.len() {
+            return Err(PlottingError::DataLengthMismatch {
+                x_len: x_data.len(),
+                y_len: y_data.len(),
+                series_index: None,
+            });
+        }
+        if values.len() != x_data.len() {
+            return Err(PlottingError::DataLengthMismatch {
+                x_len: x_data.len(),
+                y_len: values.len(),
+                series_index: None,
+            });
+        }
+
+        if x_data.is_empty() {
+            return Err(PlottingError::EmptyDataSet);
+        }
+
+        self.fit_bounds(x_data, y_data);
+        self.canvas.clear();
+        let points: Vec<(f64, f64)> = x_data
+            .iter()
+            .zip(y_data.iter())
+            .map(|(&x, &y)| (x, y))
+            .collect();
+        self.canvas.aggregate_values(&points, values, mode);
+
+        Ok(())
+    }
+
+    fn fit_bounds(&mut self, x_data: &[f64], y_data: &[f64]) {
         let x_min = x_data.iter().fold(f64::INFINITY, |a, &b| a.min(b));
         let x_max = x_data.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b));
         let y_min = y_data.iter().fold(f64::INFINITY, |a, &b| a.min(b));
         let y_max = y_data.iter().fold(f64::NEG_INFINITY, |a, &b| a.max(b));
 
         self.set_bounds(x_min, y_min, x_max, y_max);
-
-        self.aggregate_with_current_bounds(x_data, y_data)
     }
 
     /// Aggregate data points using explicit `x_min/x_max/y_min/y_max` bounds
@@ -344,6 +573,25 @@ impl DataShader {
         x_max: f64,
         y_min: f64,
         y_max: f64,
+    ) -> Result<()> {
+        self.aggregate_with_bounds_chunked(x_data, y_data, x_min, x_max, y_min, y_max, |_| Ok(()))
+    }
+
+    /// Like [`DataShader::aggregate_with_bounds`], but aggregates in chunks
+    /// and calls `on_chunk` with each chunk's point count
+    ///
+    /// An error returned by `on_chunk` stops aggregation, which lets renders
+    /// report progress and honor cancellation.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn aggregate_with_bounds_chunked(
+        &mut self,
+        x_data: &[f64],
+        y_data: &[f64],
+        x_min: f64,
+        x_max: f64,
+        y_min: f64,
+        y_max: f64,
+        on_chunk: impl FnMut(usize) -> Result<()>,
     ) -> Result<()> {
         if x_data.len() != y_data.len() {
             return Err(PlottingError::DataLengthMismatch {
@@ -359,19 +607,29 @@ impl DataShader {
 
         Self::validate_explicit_bounds(x_min, x_max, y_min, y_max)?;
         self.set_bounds(x_min, y_min, x_max, y_max);
-        self.aggregate_with_current_bounds(x_data, y_data)
+        self.aggregate_with_current_bounds(x_data, y_data, on_chunk)
     }
 
-    fn aggregate_with_current_bounds(&mut self, x_data: &[f64], y_data: &[f64]) -> Result<()> {
+    fn aggregate_with_current_bounds(
+        &mut self,
+        x_data: &[f64],
+        y_data: &[f64],
+        mut on_chunk: impl FnMut(usize) -> Result<()>,
+    ) -> Result<()> {
         self.canvas.clear();
 
-        // Create point tuples and aggregate
-        let points: Vec<(f64, f64)> = x_data
-            .iter()
-            .zip(y_data.iter())
-            .map(|(&x, &y)| (x, y))
-            .collect();
-        self.canvas.aggregate(&points);
+        for (x_chunk, y_chunk) in x_data
+            .chunks(AGGREGATION_CHUNK_POINTS)
+            .zip(y_data.chunks(AGGREGATION_CHUNK_POINTS))
+        {
+            let points: Vec<(f64, f64)> = x_chunk
+                .iter()
+                .zip(y_chunk.iter())
+                .map(|(&x, &y)| (x, y))
+                .collect();
+            self.canvas.aggregate(&points);
+            on_chunk(points.len())?;
+        }
 
         Ok(())
     }
@@ -381,11 +639,51 @@ impl DataShader {
         self.canvas.statistics()
     }
 
+    /// Get the aggregation canvas
+    pub fn canvas(&self) -> &DataShaderCanvas {
+        &self.canvas
+    }
+
     /// Render to image data
     pub fn render(&self) -> DataShaderImage {
         let pixels = self.canvas.to_image_data();
         DataShaderImage::new(self.canvas.width(), self.canvas.height(), pixels)
     }
+
+    /// Render aggregated values to RGBA image data through `colormap`
+    pub fn render_colormapped(&self, colormap: &ColorMap) -> DataShaderImage {
+        let pixels = self
+            .canvas
+            .to_colormapped_image_data(colormap, &self.color_scale);
+        DataShaderImage::new(self.canvas.width(), self.canvas.height(), pixels)
+    }
+
+    /// Build a colorbar matching [`DataShader::render_colormapped`]
+    ///
+    /// Tick labels show the original aggregated values, also on a log color
+    /// scale, where ticks are spaced in `log1p` space. Fails with
+    /// [`PlottingError::EmptyDataSet`] when no cell has a value.
+    pub fn colorbar(&self, colormap: ColorMap) -> Result<Colorbar> {
+        let (min, max) = self
+            .canvas
+            .color_range(&self.color_scale)
+            .ok_or(PlottingError::EmptyDataSet)?;
+        let colorbar = Colorbar::new(colormap, min, max);
+        if !matches!(self.color_scale, AxisScale::Log) {
+            return Ok(colorbar);
+        }
+
+        let count_labels = self.canvas.agg_mode() == AggMode::Count;
+        let formatter = crate::core::TickLabelFormatter::new(move |value: f64| {
+            let original = value.exp_m1();
+            if count_labels {
+                format!("{:.0}", original)
+            } else {
+                crate::core::TickFormatter::default().format_tick(original)
+            }
+        });
+        Ok(colorbar.config(crate::plots::ColorbarConfig::new().formatter(formatter)))
+    }
 }
 
 #[cfg(test)]
@@ -594,4 +892,88 @@ mod tests {
             other => panic!("expected InvalidInput, got {other:?}"),
         }
     }
+
+    #[test]
+    fn test_datashader_aggregate_with_combines_values_per_mode() {
+        let x_data = vec![0.0, 0.1, 0.2, 1.0];
+        let y_data = vec![0.0, 0.1, 0.2, 1.0];
+        let values = vec![2.0, 4.0, 9.0, -1.0];
+        let mut ds = DataShader::with_canvas_size(2, 2);
+
+        for (mode, expected) in [
+            (AggMode::Count, 3.0),
+            (AggMode::Sum, 15.0),
+            (AggMode::Mean, 5.0),
+            (AggMode::Min, 2.0),
+            (AggMode::Max, 9.0),
+        ] {
+            ds.aggregate_with(&x_data, &y_data, &values, mode).unwrap();
+            assert_eq!(ds.canvas().agg_mode(), mode);
+            assert_eq!(ds.canvas().get_value(0, 0), Some(expected), "{mode:?}");
+            assert_eq!(ds.canvas().get_value(1, 0), None);
+        }
+        assert_eq!(ds.canvas().get_value(1, 1), Some(-1.0));
+
+        let err = ds
+            .aggregate_with(&x_data, &y_data, &values[..3], AggMode::Mean)
+            .unwrap_err();
+        assert!(matches!(err, PlottingError::DataLengthMismatch { .. }));
+    }
+
+    #[test]
+    fn test_datashader_render_colormapped_spans_value_range() {
+        let mut ds = DataShader::with_canvas_size(2, 2);
+        ds.aggregate_with(
+            &[0.0, 1.0, 1.0],
+            &[0.0, 1.0, 1.0],
+            &[5.0, 10.0, f64::NAN],
+            AggMode::Max,
+        )
+        .unwrap();
+        assert_eq!(ds.canvas().get_value(1, 1), Some(10.0));
+
+        let colormap = ColorMap::viridis();
+        let image = ds.render_colormapped(&colormap);
+        let pixel = |x: usize, y: usize| {
+            let start = (y * image.width + x) * 4;
+            image.pixels[start..start + 4].to_vec()
+        };
+        let rgba = |color: crate::render::Color| vec![color.r, color.g, color.b, color.a];
+
+        // Image rows run top-down, so y_max is the first row
+        assert_eq!(pixel(0, 1), rgba(colormap.sample(0.0)));
+        assert_eq!(pixel(1, 0), rgba(colormap.sample(1.0)));
+        assert_eq!(pixel(1, 1)[3], 0);
+    }
+
+    #[test]
+    fn test_datashader_log_color_scale_lifts_sparse_cells() {
+        // Cells holding 1, 10, and 1000 points along the diagonal
+        let mut x_data = vec![0.0, 0.5];
+        x_data.extend(std::iter::repeat_n(0.5, 9));
+        x_data.extend(std::iter::repeat_n(1.0, 1000));
+        let y_data = x_data.clone();
+        let colormap = ColorMap::viridis();
+        let middle_pixel = |shader: &DataShader| {
+            let image = shader.render_colormapped(&colormap);
+            let start = (image.width + 1) * 4;
+            image.pixels[start..start + 4].to_vec()
+        };
+        let rgba = |color: crate::render::Color| vec![color.r, color.g, color.b, color.a];
+
+        let mut linear = DataShader::with_canvas_size(3, 3);
+        linear.aggregate(&x_data, &y_data).unwrap();
+        assert_eq!(middle_pixel(&linear), rgba(colormap.sample(9.0 / 999.0)));
+
+        let mut log = DataShader::with_canvas_size(3, 3).color_scale(AxisScale::Log);
+        log.aggregate(&x_data, &y_data).unwrap();
+        let t = (11.0_f64.ln() - 2.0_f64.ln()) / (1001.0_f64.ln() - 2.0_f64.ln());
+        assert_eq!(middle_pixel(&log), rgba(colormap.sample(t)));
+
+        assert!(log.colorbar(colormap.clone()).unwrap().render().is_ok());
+        assert!(matches!(
+            DataShader::new().colorbar(colormap),
+            Err(PlottingError::EmptyDataSet)
+        ));
+    }
 }
@@ -1,5 +1,7 @@
 //! Data handling and trait definitions
 
+#[cfg(feature = "csv")]
+pub mod csv;
 pub mod datashader_simple;
 pub mod elements;
 pub mod impls;
@@ -14,7 +16,11 @@ pub mod transform;
 pub mod validation;
 pub mod zero_copy;
 
-pub use datashader_simple::{DataShader, DataShaderCanvas, DataShaderImage, DataShaderStats};
+#[cfg(feature = "csv")]
+pub use csv::{CsvData, CsvOptions};
+pub use datashader_simple::{
+    AggMode, DataShader, DataShaderCanvas, DataShaderImage, DataShaderStats,
+};
 pub use elements::{
     ErrorBar, LineSegment, MarkerInstance, PlotElementStats, PlotElementStorage, Polygon,
     PoolStats, TextAlignment, TextElement, get_plot_element_storage,
@@ -390,6 +390,18 @@ impl NumericData2D for [Vec<f64>] {
     }
 }
 
+/// Copy a 2D array view into a row-major buffer.
+///
+/// Standard-layout arrays (including row slices of one) are copied in a single
+/// pass; transposed or strided views fall back to logical-order iteration.
+#[cfg(feature = "ndarray_support")]
+fn ndarray_row_major_2d(view: ndarray::ArrayView2<'_, f64>) -> Vec<f64> {
+    match view.as_slice() {
+        Some(contiguous) => contiguous.to_vec(),
+        None => view.iter().copied().collect(),
+    }
+}
+
 #[cfg(feature = "ndarray_support")]
 impl NumericData2D for ndarray::Array2<f64> {
     fn shape(&self) -> (usize, usize) {
@@ -397,14 +409,7 @@ impl NumericData2D for ndarray::Array2<f64> {
     }
 
     fn try_collect_row_major_f64(&self) -> Result<Vec<f64>, PlottingError> {
-        let (rows, cols) = (self.nrows(), self.ncols());
-        let mut values = Vec::with_capacity(rows * cols);
-        for r in 0..rows {
-            for c in 0..cols {
-                values.push(self[(r, c)]);
-            }
-        }
-        Ok(values)
+        Ok(ndarray_row_major_2d(self.view()))
     }
 }
 
@@ -415,14 +420,7 @@ impl<'a> NumericData2D for ndarray::ArrayView2<'a, f64> {
     }
 
     fn try_collect_row_major_f64(&self) -> Result<Vec<f64>, PlottingError> {
-        let (rows, cols) = (self.nrows(), self.ncols());
-        let mut values = Vec::with_capacity(rows * cols);
-        for r in 0..rows {
-            for c in 0..cols {
-                values.push(self[(r, c)]);
-            }
-        }
-        Ok(values)
+        Ok(ndarray_row_major_2d(self.view()))
     }
 }
 
@@ -646,6 +644,33 @@ impl NumericData1D for polars::prelude::Series {
     }
 }
 
+/// DataFrame columns (`df.column("x")?`) dispatch on dtype like [`polars::prelude::Series`];
+/// null errors name the offending column.
+#[cfg(feature = "polars_support")]
+impl NumericData1D for polars::prelude::Column {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn try_collect_f64_with_policy(
+        &self,
+        null_policy: NullPolicy,
+    ) -> Result<Vec<f64>, PlottingError> {
+        collect_polars_series(self.as_materialized_series(), null_policy).map_err(|err| match err {
+            PlottingError::NullValueNotAllowed {
+                source,
+                column: None,
+                null_count,
+            } => PlottingError::NullValueNotAllowed {
+                source,
+                column: Some(self.name().to_string()),
+                null_count,
+            },
+            other => other,
+        })
+    }
+}
+
 #[cfg(feature = "polars_support")]
 macro_rules! impl_polars_numeric_data_1d {
     ($ty:ty, $source:expr, $map:expr) => {
@@ -811,6 +836,27 @@ mod tests {
         assert_eq!(flat, vec![1.0, 2.0, 3.0, 4.0]);
     }
 
+    #[cfg(feature = "ndarray_support")]
+    #[test]
+    fn test_ndarray_view2_slices_and_transposes_stay_row_major() {
+        use ndarray::{array, s};
+
+        let arr = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+
+        let block = arr.slice(s![1.., ..2]);
+        assert_eq!(NumericData2D::shape(&block), (2, 2));
+        assert_eq!(
+            block.try_collect_row_major_f64().unwrap(),
+            vec![4.0, 5.0, 7.0, 8.0]
+        );
+
+        let transposed = arr.t();
+        assert_eq!(
+            transposed.try_collect_row_major_f64().unwrap(),
+            vec![1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]
+        );
+    }
+
     #[cfg(any(feature = "nalgebra_support", feature = "nalgebra"))]
     #[test]
     fn test_nalgebra_data1d() {
@@ -0,0 +1,251 @@
+//! Animated GIF export
+//!
+//! Stitches a sequence of rendered [`Image`]s (for example from
+//! `Plot::render()`) into a looping animated GIF.
+
+use super::{AtomicTempFile, validate_rgba_image};
+use crate::{
+    animation::encoders::{Encoder, GifEncoder, Quality},
+    core::plot::Image,
+    core::{PlottingError, Result},
+};
+use std::path::{Path, PathBuf};
+
+/// Writer for animated GIFs built from rendered frames
+///
+/// Each RGBA frame is flattened onto a white background and handed to
+/// [`GifEncoder`], which quantizes it to a 256-color palette and streams it to
+/// a temporary file next to the destination. All frames must share the
+/// dimensions of the first one. [`GifWriter::finish`] moves the file into
+/// place atomically; nothing appears at the destination before then.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use ruviz::export::GifWriter;
+/// use ruviz::prelude::*;
+///
+/// let mut writer = GifWriter::new("anim.gif", 10.0)?;
+/// for step in 0..20 {
+///     let phase = step as f64 * 0.3;
+///     let x: Vec<f64> = (0..100).map(|i| i as f64 * 0.1).collect();
+///     let y: Vec<f64> = x.iter().map(|v| (v + phase).sin()).collect();
+///     let image = Plot::new().line(&x, &y).render()?;
+///     writer.add_frame(&image)?;
+/// }
+/// writer.finish()?;
+/// ```
+pub struct GifWriter {
+    path: PathBuf,
+    frame_delay: u16,
+    // Declared before `temp` so the file is closed before the temp file is removed
+    encoder: Option<GifEncoder>,
+    temp: Option<AtomicTempFile>,
+    width: u32,
+    height: u32,
+    frame_count: usize,
+}
+
+impl GifWriter {
+    /// Create a writer for `path` playing back at `fps` frames per second
+    ///
+    /// GIF frame delays are stored in centiseconds, so the effective rate is
+    /// rounded to the nearest representable delay.
+    pub fn new<P: AsRef<Path>>(path: P, fps: f64) -> Result<Self> {
+        if !fps.is_finite() || fps <= 0.0 {
+            return Err(PlottingError::InvalidInput(format!(
+                "GIF frame rate must be positive and finite, got {fps}"
+            )));
+        }
+
+        Ok(Self {
+            path: path.as_ref().to_path_buf(),
+            frame_delay: (100.0 / fps).round().clamp(1.0, f64::from(u16::MAX)) as u16,
+            encoder: None,
+            temp: None,
+            width: 0,
+            height: 0,
+            frame_count: 0,
+        })
+    }
+
+    /// Frame delay in centiseconds
+    pub fn frame_delay(&self) -> u16 {
+        self.frame_delay
+    }
+
+    /// Number of frames added so far
+    pub fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    /// Append a frame
+    ///
+    /// Pixels are interpreted as premultiplied RGBA, as produced by
+    /// `Plot::render()`; opaque images are encoded unchanged.
+    pub fn add_frame(&mut self, image: &Image) -> Result<()> {
+        validate_rgba_image(image)?;
+        check_gif_dimensions(image)?;
+
+        if self.encoder.is_none() {
+            let temp = AtomicTempFile::reserve(&self.path)?;
+            let mut encoder =
+                GifEncoder::new(temp.path(), Quality::Medium)?.with_frame_delay(self.frame_delay);
+            encoder.init(image.width, image.height)?;
+            self.encoder = Some(encoder);
+            self.temp = Some(temp);
+            self.width = image.width;
+            self.height = image.height;
+        } else if (image.width, image.height) != (self.width, self.height) {
+            return Err(PlottingError::InvalidInput(format!(
+                "GIF frame size mismatch: expected {}x{}, got {}x{}",
+                self.width, self.height, image.width, image.height
+            )));
+        }
+
+        self.encoder
+            .as_mut()
+            .expect("GIF encoder initialized above")
+            .encode_frame(&flatten_onto_white(&image.pixels), 0)?;
+        self.frame_count += 1;
+
+        Ok(())
+    }
+
+    /// Finish the animation and move it into place
+    ///
+    /// Returns an error if no frames were added.
+    pub fn finish(self) -> Result<()> {
+        let (Some(encoder), Some(temp)) = (self.encoder, self.temp) else {
+            return Err(PlottingError::InvalidInput(
+                "cannot write a GIF without frames".to_string(),
+            ));
+        };
+        Box::new(encoder).finalize()?;
+        temp.commit()
+    }
+}
+
+fn check_gif_dimensions(image: &Image) -> Result<()> {
+    let max = u32::from(u16::MAX);
+    if (1..=max).contains(&image.width) && (1..=max).contains(&image.height) {
+        return Ok(());
+    }
+    Err(PlottingError::InvalidInput(format!(
+        "GIF frames must be between 1x1 and {max}x{max} pixels, got {}x{}",
+        image.width, image.height
+    )))
+}
+
+/// Flatten premultiplied RGBA pixels onto white as RGB
+///
+/// GIF has no partial transparency.
+fn flatten_onto_white(pixels: &[u8]) -> Vec<u8> {
+    pixels
+        .chunks_exact(4)
+        .flat_map(|px| {
+            let background = 255 - px[3];
+            [
+                px[0].saturating_add(background),
+                px[1].saturating_add(background),
+                px[2].saturating_add(background),
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn solid_image(width: u32, height: u32, rgba: [u8; 4]) -> Image {
+        Image::new(width, height, rgba.repeat((width * height) as usize))
+    }
+
+    #[test]
+    fn test_gif_writer_writes_looping_animation() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("anim.gif");
+
+        let mut writer = GifWriter::new(&path, 25.0).unwrap();
+        assert_eq!(writer.frame_delay(), 4);
+        writer
+            .add_frame(&solid_image(8, 6, [255, 0, 0, 255]))
+            .unwrap();
+        writer
+            .add_frame(&solid_image(8, 6, [0, 0, 255, 255]))
+            .unwrap();
+        assert!(!path.exists());
+        writer.finish().unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options
+            .read_info(std::fs::File::open(&path).unwrap())
+            .unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (8, 6));
+
+        let mut first_pixels = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 4);
+            first_pixels.push(frame.buffer[..4].to_vec());
+        }
+        assert_eq!(first_pixels.len(), 2);
+        assert!(first_pixels[0][0] > 200 && first_pixels[0][2] < 50);
+        assert!(first_pixels[1][2] > 200 && first_pixels[1][0] < 50);
+    }
+
+    #[test]
+    fn test_gif_writer_rejects_invalid_input() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("anim.gif");
+
+        assert!(GifWriter::new(&path, 0.0).is_err());
+        assert!(GifWriter::new(&path, f64::NAN).is_err());
+        assert!(GifWriter::new(&path, 10.0).unwrap().finish().is_err());
+
+        let mut writer = GifWriter::new(&path, 10.0).unwrap();
+        writer
+            .add_frame(&solid_image(4, 4, [0, 0, 0, 255]))
+            .unwrap();
+        assert!(
+            writer
+                .add_frame(&solid_image(5, 4, [0, 0, 0, 255]))
+                .is_err()
+        );
+        assert!(writer.add_frame(&Image::new(4, 4, vec![0; 3])).is_err());
+        assert_eq!(writer.frame_count(), 1);
+    }
+
+    #[test]
+    fn test_gif_writer_leaves_no_temp_files() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("anim.gif");
+        let entries = || std::fs::read_dir(dir.path()).unwrap().count();
+
+        let mut abandoned = GifWriter::new(&path, 10.0).unwrap();
+        abandoned
+            .add_frame(&solid_image(4, 4, [0, 0, 0, 255]))
+            .unwrap();
+        assert_eq!(entries(), 1);
+        drop(abandoned);
+        assert_eq!(entries(), 0);
+
+        let mut writer = GifWriter::new(&path, 10.0).unwrap();
+        writer
+            .add_frame(&solid_image(4, 4, [0, 0, 0, 255]))
+            .unwrap();
+        writer.finish().unwrap();
+        assert_eq!(entries(), 1);
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn test_transparent_pixels_flatten_onto_white() {
+        assert_eq!(
+            flatten_onto_white(&[0, 0, 0, 0, 64, 0, 0, 128]),
+            vec![255, 255, 255, 191, 127, 127]
+        );
+    }
+}
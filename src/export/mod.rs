@@ -4,6 +4,8 @@
 //! - PNG: Raster export via `Plot::save()`
 //! - SVG: Vector export via `Plot::to_svg()` or `Plot::render_to_svg()`
 //! - PDF: Vector export via `Plot::save_pdf()` (requires `pdf` feature)
+//! - In-memory PNG, JPEG, or SVG bytes via `Plot::save_to_buffer()` and [`ImageFormat`]
+//! - GIF: Animated export of rendered frames via [`GifWriter`] (requires `animation` feature)
 //!
 //! The PDF export uses an SVG -> PDF pipeline for high-quality vector output.
 
@@ -13,6 +15,7 @@ use crate::{
 };
 use image::{
     ColorType, ImageEncoder,
+    codecs::jpeg::JpegEncoder,
     codecs::png::{CompressionType, FilterType, PngEncoder},
 };
 use std::fs::{self, File, OpenOptions};
@@ -28,21 +31,66 @@ use windows_sys::Win32::Storage::FileSystem::{MOVEFILE_REPLACE_EXISTING, MoveFil
 
 const TEMP_FILE_CREATE_RETRIES: usize = 8;
 
+/// JPEG quality used by `Plot::save_to_buffer(ImageFormat::Jpeg)`
+pub(crate) const DEFAULT_JPEG_QUALITY: u8 = 90;
+
 pub mod svg;
 
 #[cfg(feature = "pdf")]
 pub mod pdf;
 
+#[cfg(feature = "animation")]
+pub mod gif;
+
 #[cfg(feature = "pdf")]
 pub mod svg_to_pdf;
 
-pub use svg::SvgRenderer;
+pub use svg::{SvgOptions, SvgRenderer};
 
 #[cfg(feature = "pdf")]
 pub use pdf::PdfRenderer;
 
+#[cfg(feature = "animation")]
+pub use gif::GifWriter;
+
 #[cfg(feature = "pdf")]
-pub use svg_to_pdf::{page_sizes, svg_to_pdf, svg_to_pdf_file};
+pub use svg_to_pdf::{
+    PdfDocument, PdfMetadata, page_sizes, svg_to_pdf, svg_to_pdf_file,
+    svg_to_pdf_file_with_metadata, svg_to_pdf_with_metadata,
+};
+
+/// Encoded output format for in-memory export
+///
+/// Used by `Plot::save_to_buffer()` to pick the encoding of the returned bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    /// Lossless raster PNG
+    Png,
+    /// Lossy raster JPEG; transparent areas are flattened onto white
+    Jpeg,
+    /// Vector SVG document
+    Svg,
+}
+
+impl ImageFormat {
+    /// MIME type, e.g. for an HTTP `Content-Type` header
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "image/png",
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Svg => "image/svg+xml",
+        }
+    }
+
+    /// Conventional file extension, without the leading dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::Svg => "svg",
+        }
+    }
+}
 
 fn validate_rgba_image(image: &Image) -> Result<()> {
     let expected_len = (image.width as usize)
@@ -77,6 +125,72 @@ pub fn encode_rgba_png(image: &Image) -> Result<Vec<u8>> {
     Ok(bytes)
 }
 
+/// Encode an in-memory RGBA image as PNG bytes that record `dpi`.
+///
+/// The resolution goes into the PNG `pHYs` chunk as pixels per meter, so
+/// layout tools (Word, InDesign, LaTeX) place the image at its intended
+/// physical size. A non-finite or non-positive `dpi` omits the chunk.
+pub fn encode_rgba_png_with_dpi(image: &Image, dpi: f32) -> Result<Vec<u8>> {
+    validate_rgba_image(image)?;
+
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, image.width, image.height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    // Same settings as `encode_rgba_png`
+    encoder.set_compression(png::Compression::Fast);
+    encoder.set_filter(png::Filter::Adaptive);
+    encoder.set_pixel_dims(png_pixel_dims(dpi));
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&image.pixels))
+        .map_err(|err| PlottingError::RenderError(format!("failed to encode PNG: {err}")))?;
+
+    Ok(bytes)
+}
+
+/// `pHYs` chunk contents for `dpi`, in pixels per meter
+fn png_pixel_dims(dpi: f32) -> Option<png::PixelDimensions> {
+    const METERS_PER_INCH: f64 = 0.0254;
+
+    let pixels_per_meter = (f64::from(dpi) / METERS_PER_INCH).round();
+    if !(1.0..=f64::from(u32::MAX)).contains(&pixels_per_meter) {
+        return None;
+    }
+    Some(png::PixelDimensions {
+        xppu: pixels_per_meter as u32,
+        yppu: pixels_per_meter as u32,
+        unit: png::Unit::Meter,
+    })
+}
+
+/// Encode an in-memory straight-alpha RGBA image as JPEG bytes.
+///
+/// JPEG has no alpha channel, so pixels are composited onto white.
+/// `quality` ranges from 1 (smallest) to 100 (best).
+pub fn encode_rgba_jpeg(image: &Image, quality: u8) -> Result<Vec<u8>> {
+    validate_rgba_image(image)?;
+
+    let rgb: Vec<u8> = image
+        .pixels
+        .chunks_exact(4)
+        .flat_map(|px| {
+            let alpha = u32::from(px[3]);
+            let flatten = |channel: u8| {
+                ((u32::from(channel) * alpha + 255 * (255 - alpha) + 127) / 255) as u8
+            };
+            [flatten(px[0]), flatten(px[1]), flatten(px[2])]
+        })
+        .collect();
+
+    let mut bytes = Vec::new();
+    JpegEncoder::new_with_quality(&mut bytes, quality.clamp(1, 100))
+        .write_image(&rgb, image.width, image.height, ColorType::Rgb8.into())
+        .map_err(|err| PlottingError::RenderError(format!("failed to encode JPEG: {err}")))?;
+
+    Ok(bytes)
+}
+
 fn atomic_temp_path(path: &Path) -> PathBuf {
     static TEMP_PATH_NONCE: AtomicU64 = AtomicU64::new(0);
     let parent = path.parent().unwrap_or_else(|| Path::new("."));
@@ -324,6 +438,70 @@ where
     Ok(())
 }
 
+/// Temporary file reserved next to an export destination
+///
+/// For writers that stream into a file they open themselves. The file is
+/// renamed over the destination by [`commit`](Self::commit) and removed if
+/// dropped before then.
+#[cfg(feature = "animation")]
+pub(crate) struct AtomicTempFile {
+    temp_path: PathBuf,
+    destination_path: PathBuf,
+    remove_on_drop: bool,
+}
+
+#[cfg(feature = "animation")]
+impl AtomicTempFile {
+    pub(crate) fn reserve(path: &Path) -> Result<Self> {
+        let destination_path = resolve_atomic_destination(path).map_err(PlottingError::IoError)?;
+        ensure_parent_dir(&destination_path).map_err(PlottingError::IoError)?;
+        let (temp_path, file) =
+            create_atomic_temp_file(&destination_path).map_err(PlottingError::IoError)?;
+        drop(file);
+
+        Ok(Self {
+            temp_path,
+            destination_path,
+            remove_on_drop: true,
+        })
+    }
+
+    /// Path the caller should write to
+    pub(crate) fn path(&self) -> &Path {
+        &self.temp_path
+    }
+
+    /// Sync the written file and move it into place
+    pub(crate) fn commit(mut self) -> Result<()> {
+        let result = OpenOptions::new()
+            .write(true)
+            .open(&self.temp_path)
+            .and_then(|file| file.sync_all())
+            .map_err(|err| AtomicWriteFailure::cleanup(PlottingError::IoError(err)))
+            .and_then(|()| rename_temp_into_place(&self.temp_path, &self.destination_path));
+
+        match result {
+            Ok(()) => {
+                self.remove_on_drop = false;
+                Ok(())
+            }
+            Err(failure) => {
+                self.remove_on_drop = failure.cleanup_temp;
+                Err(failure.error)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "animation")]
+impl Drop for AtomicTempFile {
+    fn drop(&mut self) {
+        if self.remove_on_drop {
+            cleanup_temp_file(&self.temp_path);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -370,4 +548,25 @@ mod tests {
             b"new-bytes"
         );
     }
+
+    #[test]
+    fn jpeg_encoding_flattens_transparency_onto_white() {
+        let mut pixels = [0, 0, 0, 0].repeat(16 * 8);
+        pixels.extend([0, 0, 255, 255].repeat(16 * 8));
+        let image = Image::new(16, 16, pixels);
+
+        let bytes = encode_rgba_jpeg(&image, 95).expect("encode jpeg");
+        assert_eq!(&bytes[..2], &[0xFF, 0xD8]);
+
+        let decoded = image::load_from_memory(&bytes)
+            .expect("decode jpeg")
+            .to_rgb8();
+        assert_eq!(decoded.dimensions(), (16, 16));
+        let top = decoded.get_pixel(8, 2);
+        let bottom = decoded.get_pixel(8, 13);
+        assert!(top.0.iter().all(|&channel| channel > 240), "{top:?}");
+        assert!(bottom[2] > 200 && bottom[0] < 50, "{bottom:?}");
+
+        assert!(encode_rgba_jpeg(&Image::new(2, 2, vec![0; 3]), 90).is_err());
+    }
 }
@@ -4,15 +4,17 @@
 //! This renderer is also used as the intermediate format for PDF export.
 
 use crate::core::{
-    Legend, LegendItem, LegendItemType, LegendPosition, LegendSpacingPixels, LegendStyle,
-    PlottingError, RenderScale, Result, SpineConfig, TextAlign, TextStyle, find_best_position,
+    HatchPattern, Legend, LegendItem, LegendItemType, LegendPosition, LegendSpacingPixels,
+    LegendStyle, PlottingError, RenderScale, Result, SpineConfig, TextAlign, TextStyle,
+    find_best_position,
     plot::{TextEngineMode, TickDirection, TickSides},
 };
 use crate::render::{
-    Color, FontConfig, FontFamily, FontWeight, LineStyle, MarkerStyle, TextRenderer,
+    Color, FontConfig, FontFamily, FontWeight, LineStyle, MarkerPaint, MarkerStyle, TextRenderer,
+    text::TextOutline,
     text_anchor::{
-        TextPlacementMetrics, annotation_text_layout, center_anchor_to_baseline,
-        top_anchor_to_baseline,
+        AnnotationTextLayout, TextPlacementMetrics, annotation_text_layout,
+        center_anchor_to_baseline, top_anchor_to_baseline,
     },
     typst_text::{self, TypstBackendKind, TypstTextAnchor},
 };
@@ -20,6 +22,16 @@ use std::borrow::Cow;
 use std::fmt::Write as FmtWrite;
 use std::path::Path;
 
+/// Options for SVG export via `Plot::render_to_svg_options()`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SvgOptions {
+    /// Write plain text as filled glyph outlines instead of `<text>` elements
+    ///
+    /// The SVG then renders identically on machines without the plot's
+    /// fonts, at the cost of text that can no longer be selected or edited.
+    pub text_as_paths: bool,
+}
+
 /// SVG renderer for vector-based plot export
 pub struct SvgRenderer {
     width: f32,
@@ -27,6 +39,8 @@ pub struct SvgRenderer {
     content: String,
     defs: String,
     clip_id_counter: u32,
+    /// Hatch `<pattern>` definitions already emitted, keyed by pattern and stroke color.
+    hatch_fills: Vec<(HatchPattern, Color, String)>,
     /// Shared render scale for unit conversion.
     render_scale: RenderScale,
     /// Active text rendering engine.
@@ -35,6 +49,10 @@ pub struct SvgRenderer {
     text_renderer: TextRenderer,
     /// Font family for plain SVG text and Typst-rendered SVG text.
     font_family: FontFamily,
+    /// Counter-clockwise X tick label rotation in degrees.
+    xtick_label_rotation: f32,
+    /// Write plain text as filled glyph outlines instead of `<text>`.
+    text_as_paths: bool,
 }
 
 impl SvgRenderer {
@@ -51,6 +69,7 @@ impl SvgRenderer {
             content: String::new(),
             defs: String::new(),
             clip_id_counter: 0,
+            hatch_fills: Vec::new(),
             render_scale: RenderScale::from_canvas_size(
                 width.max(1.0).round() as u32,
                 height.max(1.0).round() as u32,
@@ -59,6 +78,8 @@ impl SvgRenderer {
             text_engine_mode: TextEngineMode::Plain,
             text_renderer: TextRenderer::new(),
             font_family,
+            xtick_label_rotation: 0.0,
+            text_as_paths: false,
         }
     }
 
@@ -95,11 +116,30 @@ impl SvgRenderer {
         self.text_engine_mode = mode;
     }
 
+    /// Set the counter-clockwise rotation, in degrees, applied to X tick labels.
+    pub fn set_xtick_label_rotation(&mut self, degrees: f32) {
+        self.xtick_label_rotation = degrees;
+    }
+
     /// Get text rendering backend mode.
     pub fn text_engine_mode(&self) -> TextEngineMode {
         self.text_engine_mode
     }
 
+    /// Write plain text as filled glyph outlines instead of `<text>` elements.
+    ///
+    /// The outlines come from the fonts loaded for raster rendering, so the
+    /// SVG looks the same on machines without those fonts. Text falls back
+    /// to `<text>` when no fonts are available. Typst text is unaffected.
+    pub fn set_text_as_paths(&mut self, text_as_paths: bool) {
+        self.text_as_paths = text_as_paths;
+    }
+
+    /// Whether plain text is written as glyph outlines.
+    pub fn text_as_paths(&self) -> bool {
+        self.text_as_paths
+    }
+
     /// Set the font family used by plain and Typst text rendering.
     pub fn set_font_family<F>(&mut self, family: F)
     where
@@ -177,6 +217,73 @@ impl SvgRenderer {
         s
     }
 
+    /// One `<tspan>` per line of `text`, the first on `baseline_y` and the
+    /// rest spaced like the plain text layout (1.2 × font size)
+    fn line_tspans(&self, text: &str, x: f32, baseline_y: f32, size: f32) -> String {
+        let line_height = size * 1.2;
+        let mut tspans = String::new();
+        for (line_index, line) in text.split('\n').enumerate() {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let line_y = baseline_y + line_index as f32 * line_height;
+            write!(
+                tspans,
+                r#"<tspan x="{:.2}" y="{:.2}">{}</tspan>"#,
+                x,
+                line_y,
+                self.escape_xml(line)
+            )
+            .unwrap();
+        }
+        tspans
+    }
+
+    /// Glyph outlines for each line of `text`, or `None` when text should stay
+    /// live (outlines disabled or no fonts available)
+    fn text_outlines(&self, text: &str, config: &FontConfig) -> Result<Option<Vec<TextOutline>>> {
+        if !self.text_as_paths {
+            return Ok(None);
+        }
+        text.split('\n')
+            .map(|line| {
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                self.text_renderer.text_outline(line, config)
+            })
+            .collect::<Result<Option<Vec<_>>>>()
+    }
+
+    /// Write one filled `<path>` per outlined line, aligned at `x` like
+    /// `text-anchor` and spaced like [`Self::line_tspans`]
+    fn write_text_outlines(
+        &mut self,
+        lines: &[TextOutline],
+        x: f32,
+        baseline_y: f32,
+        size: f32,
+        align: TextAlign,
+        color: Color,
+        indent: &str,
+    ) {
+        let fill = self.color_to_svg(color);
+        let line_height = size * 1.2;
+        for (line_index, line) in lines.iter().enumerate() {
+            if line.path_data.is_empty() {
+                continue;
+            }
+            let line_x = match align {
+                TextAlign::Left => x,
+                TextAlign::Center => x - line.width / 2.0,
+                TextAlign::Right => x - line.width,
+            };
+            let line_y = baseline_y + line_index as f32 * line_height;
+            writeln!(
+                self.content,
+                r#"{indent}<path transform="translate({:.2},{:.2})" d="{}" fill="{}"/>"#,
+                line_x, line_y, line.path_data, fill
+            )
+            .unwrap();
+        }
+    }
+
     /// Escape XML special characters
     fn escape_xml(&self, text: &str) -> String {
         text.replace('&', "&amp;")
@@ -343,6 +450,38 @@ impl SvgRenderer {
         }
     }
 
+    /// Stroke many rectangle outlines as one path
+    ///
+    /// Shared edges are covered once, so translucent strokes between
+    /// neighbouring rectangles do not darken.
+    pub fn draw_rectangle_outlines(
+        &mut self,
+        rects: &[(f32, f32, f32, f32)],
+        color: Color,
+        line_width: f32,
+    ) {
+        if rects.is_empty() {
+            return;
+        }
+
+        let color_str = self.color_to_svg(color);
+        let path_data: String = rects
+            .iter()
+            .map(|(x, y, width, height)| {
+                format!(
+                    "M{:.2},{:.2}h{:.2}v{:.2}h{:.2}Z",
+                    x, y, width, height, -width
+                )
+            })
+            .collect();
+        writeln!(
+            self.content,
+            r#"  <path d="{}" fill="none" stroke="{}" stroke-width="{:.2}" stroke-linejoin="miter"/>"#,
+            path_data, color_str, line_width
+        )
+        .unwrap();
+    }
+
     /// Draw a filled or stroked rectangle with rounded corners
     pub fn draw_rounded_rectangle(
         &mut self,
@@ -456,6 +595,112 @@ impl SvgRenderer {
         .unwrap();
     }
 
+    /// Draw a hatch pattern over a rectangle.
+    pub fn draw_hatch_rectangle(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        pattern: HatchPattern,
+        color: Color,
+    ) {
+        let fill = self.hatch_pattern_fill(pattern, color);
+        writeln!(
+            self.content,
+            r#"  <rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="{}" stroke="none"/>"#,
+            x, y, width, height, fill
+        )
+        .unwrap();
+    }
+
+    /// Draw a hatch pattern over a polygon.
+    pub fn draw_hatch_polygon(
+        &mut self,
+        points: &[(f32, f32)],
+        pattern: HatchPattern,
+        color: Color,
+    ) {
+        if points.len() < 3 {
+            return;
+        }
+
+        let fill = self.hatch_pattern_fill(pattern, color);
+        let points_str = points
+            .iter()
+            .map(|(x, y)| format!("{:.2},{:.2}", x, y))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        writeln!(
+            self.content,
+            r#"  <polygon points="{}" fill="{}" stroke="none"/>"#,
+            points_str, fill
+        )
+        .unwrap();
+    }
+
+    /// Return the `url(#id)` fill reference for `pattern`, defining its `<pattern>` tile on first use.
+    ///
+    /// Tiles use user-space units anchored at the canvas origin, matching the
+    /// raster renderer, and are rotated so every stroke is horizontal or vertical
+    /// within the tile.
+    fn hatch_pattern_fill(&mut self, pattern: HatchPattern, color: Color) -> String {
+        if let Some((_, _, fill)) = self
+            .hatch_fills
+            .iter()
+            .find(|(p, c, _)| *p == pattern && *c == color)
+        {
+            return fill.clone();
+        }
+
+        // Shares the clip counter so every id in `<defs>` stays unique.
+        self.clip_id_counter += 1;
+        let pattern_id = format!("hatch{}", self.clip_id_counter);
+        let spacing = self.points_to_pixels(HatchPattern::SPACING_PT).max(1.0);
+        let line_width = self.points_to_pixels(HatchPattern::LINE_WIDTH_PT).max(0.1);
+        let color_str = self.color_to_svg(color);
+
+        let angles = pattern.line_angles();
+        let rotation = angles.first().copied().unwrap_or(0.0);
+        let mut tile = String::new();
+        if angles.is_empty() {
+            write!(
+                tile,
+                r#"<circle cx="{:.2}" cy="{:.2}" r="{:.2}" fill="{}"/>"#,
+                spacing / 2.0,
+                spacing / 2.0,
+                line_width,
+                color_str
+            )
+            .unwrap();
+        }
+        for angle in angles {
+            let (x1, y1, x2, y2) = if (angle - rotation).abs() < f32::EPSILON {
+                (0.0, spacing / 2.0, spacing, spacing / 2.0)
+            } else {
+                (spacing / 2.0, 0.0, spacing / 2.0, spacing)
+            };
+            write!(
+                tile,
+                r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-width="{:.2}"/>"#,
+                x1, y1, x2, y2, color_str, line_width
+            )
+            .unwrap();
+        }
+
+        // SVG rotations are clockwise because y grows downward.
+        writeln!(
+            self.defs,
+            r#"    <pattern id="{}" patternUnits="userSpaceOnUse" width="{:.2}" height="{:.2}" patternTransform="rotate({:.0})">{}</pattern>"#,
+            pattern_id, spacing, spacing, -rotation, tile
+        )
+        .unwrap();
+        let fill = format!("url(#{})", pattern_id);
+        self.hatch_fills.push((pattern, color, fill.clone()));
+        fill
+    }
+
     /// Draw a polygon outline.
     pub fn draw_polygon_outline(&mut self, points: &[(f32, f32)], color: Color, width: f32) {
         if points.len() < 3 {
@@ -637,6 +882,97 @@ impl SvgRenderer {
         }
     }
 
+    /// Draw a marker with separate face and edge colors.
+    pub fn draw_marker_painted(
+        &mut self,
+        x: f32,
+        y: f32,
+        size: f32,
+        style: MarkerStyle,
+        paint: MarkerPaint,
+    ) {
+        let radius = size / 2.0;
+        let fill = paint
+            .fill
+            .map_or_else(|| "none".to_string(), |fill| self.color_to_svg(fill));
+        let stroke = if paint.edge_width > 0.0 {
+            format!(
+                r#" stroke="{}" stroke-width="{:.2}""#,
+                self.color_to_svg(paint.edge),
+                paint.edge_width
+            )
+        } else {
+            String::new()
+        };
+
+        let polygon = |points: &[(f32, f32)]| {
+            points
+                .iter()
+                .map(|(px, py)| format!("{:.2},{:.2}", px, py))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        match style {
+            MarkerStyle::Circle | MarkerStyle::CircleOpen => writeln!(
+                self.content,
+                r#"  <circle cx="{:.2}" cy="{:.2}" r="{:.2}" fill="{}"{}/>"#,
+                x, y, radius, fill, stroke
+            )
+            .unwrap(),
+            MarkerStyle::Square | MarkerStyle::SquareOpen => writeln!(
+                self.content,
+                r#"  <rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="{}"{}/>"#,
+                x - radius,
+                y - radius,
+                size,
+                size,
+                fill,
+                stroke
+            )
+            .unwrap(),
+            MarkerStyle::Triangle | MarkerStyle::TriangleOpen | MarkerStyle::TriangleDown => {
+                let tip = if style == MarkerStyle::TriangleDown {
+                    radius
+                } else {
+                    -radius
+                };
+                let points = polygon(&[
+                    (x, y + tip),
+                    (x - radius * 0.866, y - tip * 0.5),
+                    (x + radius * 0.866, y - tip * 0.5),
+                ]);
+                writeln!(
+                    self.content,
+                    r#"  <polygon points="{}" fill="{}"{}/>"#,
+                    points, fill, stroke
+                )
+                .unwrap();
+            }
+            MarkerStyle::Diamond | MarkerStyle::DiamondOpen => {
+                let points = polygon(&[
+                    (x, y - radius),
+                    (x + radius, y),
+                    (x, y + radius),
+                    (x - radius, y),
+                ]);
+                writeln!(
+                    self.content,
+                    r#"  <polygon points="{}" fill="{}"{}/>"#,
+                    points, fill, stroke
+                )
+                .unwrap();
+            }
+            MarkerStyle::Plus | MarkerStyle::Cross | MarkerStyle::Star => {
+                self.draw_marker(x, y, size, style, paint.edge);
+            }
+        }
+    }
+
+    /// Draw an annotation text box anchored at `(x, y)`
+    ///
+    /// Returns the box layout relative to the anchor, or `None` when nothing
+    /// was drawn.
     pub(crate) fn draw_styled_text(
         &mut self,
         text: &str,
@@ -644,7 +980,7 @@ impl SvgRenderer {
         y: f32,
         family: &FontFamily,
         style: &TextStyle,
-    ) -> Result<()> {
+    ) -> Result<Option<AnnotationTextLayout>> {
         let font_size = self.points_to_pixels(style.font_size.max(0.1));
         let padding = self.points_to_pixels(style.padding.max(0.0));
         let border_width = self.points_to_pixels(style.border_width.max(0.0));
@@ -653,7 +989,7 @@ impl SvgRenderer {
         let border_visible =
             border_width > 0.0 && style.border_color.is_some_and(|color| color.a > 0);
         if !text_visible && !background_visible && !border_visible {
-            return Ok(());
+            return Ok(None);
         }
 
         let weight = FontWeight::Normal;
@@ -707,13 +1043,23 @@ impl SvgRenderer {
                 .filter(|_| border_visible)
                 .map(|color| self.color_to_svg(color))
                 .unwrap_or_else(|| "none".to_string());
+            let max_radius = (layout.box_width.min(layout.box_height) / 2.0).max(0.0);
+            let corner_radius = self
+                .points_to_pixels(style.corner_radius.max(0.0))
+                .min(max_radius);
+            let rounding = if corner_radius >= 0.1 {
+                format!(r#" rx="{corner_radius:.2}" ry="{corner_radius:.2}""#)
+            } else {
+                String::new()
+            };
             writeln!(
                 self.content,
-                r#"    <rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="{}" stroke="{}" stroke-width="{:.2}"/>"#,
+                r#"    <rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}"{} fill="{}" stroke="{}" stroke-width="{:.2}"/>"#,
                 layout.box_x,
                 layout.box_y,
                 layout.box_width,
                 layout.box_height,
+                rounding,
                 fill,
                 stroke,
                 border_width
@@ -728,7 +1074,17 @@ impl SvgRenderer {
                     let color = self.color_to_svg(style.color);
                     let text_anchor = Self::svg_text_anchor(style.align);
                     let baseline_y = layout.text_y + metrics.baseline_from_top;
-                    if text.contains('\n') {
+                    if let Some(lines) = self.text_outlines(text, &config)? {
+                        self.write_text_outlines(
+                            &lines,
+                            0.0,
+                            baseline_y,
+                            font_size,
+                            style.align,
+                            style.color,
+                            "    ",
+                        );
+                    } else if text.contains('\n') {
                         write!(
                             self.content,
                             r#"    <text x="0" font-family="{}" font-size="{:.1}" font-weight="{}" fill="{}" text-anchor="{}" xml:space="preserve">"#,
@@ -784,7 +1140,7 @@ impl SvgRenderer {
         }
 
         writeln!(self.content, "  </g>").unwrap();
-        Ok(())
+        Ok(Some(layout))
     }
 
     /// Draw text at specified position.
@@ -793,16 +1149,41 @@ impl SvgRenderer {
         match self.text_engine_mode {
             TextEngineMode::Plain => {
                 let color_str = self.color_to_svg(color);
-                let escaped_text = self.escape_xml(text);
                 let metrics = self.plain_text_metrics(text, size)?;
                 let baseline_y = top_anchor_to_baseline(y, metrics);
                 let font_family = self.escaped_font_family();
-                writeln!(
-                    self.content,
-                    r#"  <text x="{:.2}" y="{:.2}" font-family="{}" font-size="{:.1}" fill="{}">{}</text>"#,
-                    x, baseline_y, font_family, size, color_str, escaped_text
-                )
-                .unwrap();
+                let config = FontConfig::new(self.font_family.clone(), size);
+                if let Some(lines) = self.text_outlines(text, &config)? {
+                    self.write_text_outlines(
+                        &lines,
+                        x,
+                        baseline_y,
+                        size,
+                        TextAlign::Left,
+                        color,
+                        "  ",
+                    );
+                } else if text.contains('\n') {
+                    let tspans = self.line_tspans(text, x, baseline_y, size);
+                    writeln!(
+                        self.content,
+                        r#"  <text font-family="{}" font-size="{:.1}" fill="{}" xml:space="preserve">{}</text>"#,
+                        font_family, size, color_str, tspans
+                    )
+                    .unwrap();
+                } else {
+                    writeln!(
+                        self.content,
+                        r#"  <text x="{:.2}" y="{:.2}" font-family="{}" font-size="{:.1}" fill="{}">{}</text>"#,
+                        x,
+                        baseline_y,
+                        font_family,
+                        size,
+                        color_str,
+                        self.escape_xml(text)
+                    )
+                    .unwrap();
+                }
                 Ok(())
             }
             #[cfg(feature = "typst-math")]
@@ -881,27 +1262,25 @@ impl SvgRenderer {
                 let weight_attr = weight
                     .map(|weight| format!(r#" font-weight="{}""#, weight.numeric()))
                     .unwrap_or_default();
-                if text.contains('\n') {
+                if let Some(lines) = self.text_outlines(text, &config)? {
+                    self.write_text_outlines(
+                        &lines,
+                        x,
+                        baseline_y,
+                        size,
+                        TextAlign::Center,
+                        color,
+                        "  ",
+                    );
+                } else if text.contains('\n') {
                     write!(
                         self.content,
                         r#"  <text x="{:.2}" font-family="{}" font-size="{:.1}"{} fill="{}" text-anchor="middle" xml:space="preserve">"#,
                         x, font_family, size, weight_attr, color_str
                     )
                     .unwrap();
-                    let line_height = size * 1.2;
-                    for (line_index, line) in text.split('\n').enumerate() {
-                        let line = line.strip_suffix('\r').unwrap_or(line);
-                        let line_y = baseline_y + line_index as f32 * line_height;
-                        write!(
-                            self.content,
-                            r#"<tspan x="{:.2}" y="{:.2}">{}</tspan>"#,
-                            x,
-                            line_y,
-                            self.escape_xml(line)
-                        )
-                        .unwrap();
-                    }
-                    writeln!(self.content, "</text>").unwrap();
+                    let tspans = self.line_tspans(text, x, baseline_y, size);
+                    writeln!(self.content, "{tspans}</text>").unwrap();
                 } else {
                     writeln!(
                         self.content,
@@ -968,16 +1347,50 @@ impl SvgRenderer {
         match self.text_engine_mode {
             TextEngineMode::Plain => {
                 let color_str = self.color_to_svg(color);
-                let escaped_text = self.escape_xml(text);
                 let metrics = self.plain_text_metrics(text, size)?;
                 let center_baseline_y = center_anchor_to_baseline(0.0, metrics);
                 let font_family = self.escaped_font_family();
-                writeln!(
-                    self.content,
-                    r#"  <g transform="translate({:.2},{:.2}) rotate({:.1})"><text x="0" y="{:.2}" font-family="{}" font-size="{:.1}" fill="{}" text-anchor="middle">{}</text></g>"#,
-                    x, y, angle, center_baseline_y, font_family, size, color_str, escaped_text
-                )
-                .unwrap();
+                let config = FontConfig::new(self.font_family.clone(), size);
+                if let Some(lines) = self.text_outlines(text, &config)? {
+                    writeln!(
+                        self.content,
+                        r#"  <g transform="translate({:.2},{:.2}) rotate({:.1})">"#,
+                        x, y, angle
+                    )
+                    .unwrap();
+                    self.write_text_outlines(
+                        &lines,
+                        0.0,
+                        center_baseline_y,
+                        size,
+                        TextAlign::Center,
+                        color,
+                        "    ",
+                    );
+                    writeln!(self.content, "  </g>").unwrap();
+                } else if text.contains('\n') {
+                    let tspans = self.line_tspans(text, 0.0, center_baseline_y, size);
+                    writeln!(
+                        self.content,
+                        r#"  <g transform="translate({:.2},{:.2}) rotate({:.1})"><text font-family="{}" font-size="{:.1}" fill="{}" text-anchor="middle" xml:space="preserve">{}</text></g>"#,
+                        x, y, angle, font_family, size, color_str, tspans
+                    )
+                    .unwrap();
+                } else {
+                    writeln!(
+                        self.content,
+                        r#"  <g transform="translate({:.2},{:.2}) rotate({:.1})"><text x="0" y="{:.2}" font-family="{}" font-size="{:.1}" fill="{}" text-anchor="middle">{}</text></g>"#,
+                        x,
+                        y,
+                        angle,
+                        center_baseline_y,
+                        font_family,
+                        size,
+                        color_str,
+                        self.escape_xml(text)
+                    )
+                    .unwrap();
+                }
                 Ok(())
             }
             #[cfg(feature = "typst-math")]
@@ -1301,10 +1714,52 @@ impl SvgRenderer {
         tick_width: f32,
         minor_tick_width: f32,
     ) {
+        self.draw_axes_with_spine_origin(
+            (plot_left, plot_right, plot_top, plot_bottom),
+            x_major_ticks,
+            y_major_ticks,
+            x_minor_ticks,
+            y_minor_ticks,
+            tick_direction,
+            tick_sides,
+            spines,
+            color,
+            axis_width,
+            major_tick_size,
+            minor_tick_size,
+            tick_width,
+            minor_tick_width,
+            None,
+        );
+    }
+
+    /// Like [`Self::draw_axes_with_minor_ticks_styled`], optionally drawing
+    /// the left and bottom spines through `spine_origin` (pixel x, y) instead
+    /// of along the plot-area border. `plot_bounds` is
+    /// `(left, right, top, bottom)`.
+    pub(crate) fn draw_axes_with_spine_origin(
+        &mut self,
+        plot_bounds: (f32, f32, f32, f32),
+        x_major_ticks: &[f32],
+        y_major_ticks: &[f32],
+        x_minor_ticks: &[f32],
+        y_minor_ticks: &[f32],
+        tick_direction: &TickDirection,
+        tick_sides: &TickSides,
+        spines: &SpineConfig,
+        color: Color,
+        axis_width: f32,
+        major_tick_size: f32,
+        minor_tick_size: f32,
+        tick_width: f32,
+        minor_tick_width: f32,
+        spine_origin: Option<(f32, f32)>,
+    ) {
+        let (plot_left, plot_right, plot_top, plot_bottom) = plot_bounds;
         let spine_offset = self.render_scale.points_to_pixels(spines.offset.max(0.0));
-        let bottom_spine_y = plot_bottom + spine_offset;
+        let (left_spine_x, bottom_spine_y) =
+            spine_origin.unwrap_or((plot_left - spine_offset, plot_bottom + spine_offset));
         let top_spine_y = plot_top - spine_offset;
-        let left_spine_x = plot_left - spine_offset;
         let right_spine_x = plot_right + spine_offset;
 
         if spines.bottom {
@@ -1440,6 +1895,41 @@ impl SvgRenderer {
         }
     }
 
+    /// Draw one X tick label below `top_y`, honoring the configured label rotation
+    pub fn draw_xtick_label(
+        &mut self,
+        label: &str,
+        tick_x: f32,
+        top_y: f32,
+        font_size: f32,
+        color: Color,
+    ) -> Result<()> {
+        let label_snippet = self.generated_label(label);
+        let (text_width, text_height) = self.measure_text_for_layout(&label_snippet, font_size)?;
+        if self.xtick_label_rotation.abs() <= f32::EPSILON {
+            let label_x = (tick_x - text_width / 2.0)
+                .max(0.0)
+                .min(self.width - text_width);
+            return self.draw_text(&label_snippet, label_x, top_y, font_size, color);
+        }
+
+        let (center_x, center_y) = crate::render::text_anchor::rotated_xtick_label_center(
+            tick_x,
+            top_y,
+            text_width,
+            text_height,
+            self.xtick_label_rotation,
+        );
+        self.draw_text_rotated(
+            &label_snippet,
+            center_x,
+            center_y,
+            font_size,
+            color,
+            -self.xtick_label_rotation,
+        )
+    }
+
     /// Draw axis tick labels
     pub fn draw_tick_labels(
         &mut self,
@@ -1460,11 +1950,7 @@ impl SvgRenderer {
         for (i, &x) in x_ticks.iter().enumerate() {
             if x >= plot_left && x <= plot_right {
                 if let Some(label) = x_labels.get(i) {
-                    let label_snippet = self.generated_label(label);
-                    let (text_width, _) =
-                        self.measure_text_for_layout(&label_snippet, font_size)?;
-                    let label_x = (x - text_width / 2.0).max(0.0).min(self.width - text_width);
-                    self.draw_text(&label_snippet, label_x, xtick_baseline_y, font_size, color)?;
+                    self.draw_xtick_label(label, x, xtick_baseline_y, font_size, color)?;
                 }
             }
         }
@@ -1486,6 +1972,35 @@ impl SvgRenderer {
         Ok(())
     }
 
+    /// Draw the `×10ⁿ` axis offset texts: X at the right end below its tick
+    /// labels, Y above the top of the axis
+    pub fn draw_tick_offset_texts(
+        &mut self,
+        x_offset: Option<&str>,
+        y_offset: Option<&str>,
+        plot_left: f32,
+        plot_right: f32,
+        plot_top: f32,
+        xtick_baseline_y: f32,
+        color: Color,
+        font_size: f32,
+    ) -> Result<()> {
+        if let Some(text) = x_offset {
+            let (text_width, text_height) = self.measure_text_for_layout(text, font_size)?;
+            let x = (plot_right - text_width).max(0.0);
+            let y = (xtick_baseline_y + text_height * 1.2)
+                .min(self.height - text_height)
+                .max(0.0);
+            self.draw_text(text, x, y, font_size, color)?;
+        }
+        if let Some(text) = y_offset {
+            let (_, text_height) = self.measure_text_for_layout(text, font_size)?;
+            let y = (plot_top - text_height * 1.4).max(0.0);
+            self.draw_text(text, plot_left, y, font_size, color)?;
+        }
+        Ok(())
+    }
+
     /// Draw legend
     pub fn draw_legend(
         &mut self,
@@ -1831,12 +2346,13 @@ impl SvgRenderer {
                     + (legend.columns.saturating_sub(1)) as f32 * spacing.column_spacing;
                 let content_height = items_per_col as f32 * legend.font_size
                     + (items_per_col.saturating_sub(1)) as f32 * spacing.label_spacing;
-                let title_height = if legend.title.is_some() {
-                    legend.font_size + spacing.label_spacing
+                let (title_width, title_height) = if let Some(title) = legend.title.as_deref() {
+                    let (w, _) = self.measure_text_for_layout(title, legend.font_size)?;
+                    (w, legend.font_size + spacing.label_spacing)
                 } else {
-                    0.0
+                    (0.0, 0.0)
                 };
-                let width = content_width + spacing.border_pad * 2.0;
+                let width = content_width.max(title_width) + spacing.border_pad * 2.0;
                 let height = content_height + title_height + spacing.border_pad * 2.0;
                 (width, height, max_label_width)
             }
@@ -1888,10 +2404,17 @@ impl SvgRenderer {
         let item_x = legend_x + spacing.border_pad;
         let mut item_y = legend_y + spacing.border_pad + legend.font_size / 2.0;
 
-        // Draw title if present
+        // Draw the title in bold, placed on its row like the entry labels
         if let Some(ref title) = legend.title {
             let title_x = legend_x + legend_width / 2.0;
-            self.draw_text_centered(title, title_x, item_y, legend.font_size, legend.text_color)?;
+            self.draw_text_centered_with_weight(
+                title,
+                title_x,
+                item_y - legend.font_size * 0.65,
+                legend.font_size,
+                legend.text_color,
+                FontWeight::Bold,
+            )?;
             item_y += legend.font_size + spacing.label_spacing;
         }
 
@@ -1973,6 +2496,26 @@ impl SvgRenderer {
         writeln!(self.content, "  </g>").unwrap();
     }
 
+    /// Embed a complete SVG document with its top-left corner at (`x`, `y`)
+    ///
+    /// Element ids and their references are prefixed with `id_prefix` so the
+    /// embedded document's clip paths and patterns cannot collide with ours.
+    pub(crate) fn draw_embedded_svg(&mut self, svg: &str, x: f32, y: f32, id_prefix: &str) {
+        let embedded = self
+            .strip_xml_declaration(svg)
+            .replace(r#"id=""#, &format!(r#"id="{id_prefix}"#))
+            .replace("url(#", &format!("url(#{id_prefix}"))
+            .replace(r##"href="#"##, &format!(r##"href="#{id_prefix}"##));
+        writeln!(
+            self.content,
+            r#"  <g transform="translate({:.2},{:.2})">{}</g>"#,
+            x,
+            y,
+            embedded.trim_end()
+        )
+        .unwrap();
+    }
+
     /// Render to SVG string
     pub fn to_svg_string(&self) -> String {
         let mut svg = String::new();
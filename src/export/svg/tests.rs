@@ -130,6 +130,52 @@ fn test_plain_svg_generic_font_families_are_unquoted() {
     }
 }
 
+#[test]
+fn test_text_as_paths_writes_aligned_glyph_outlines() {
+    let mut renderer = SvgRenderer::new(200.0, 100.0);
+    renderer.set_text_as_paths(true);
+    renderer
+        .draw_text("Left", 10.0, 20.0, 12.0, Color::BLACK)
+        .unwrap();
+    renderer
+        .draw_text_centered("Mid", 100.0, 40.0, 12.0, Color::BLACK)
+        .unwrap();
+    renderer
+        .draw_text_rotated("Y\nAxis", 50.0, 50.0, 12.0, Color::new(255, 0, 0), -90.0)
+        .unwrap();
+
+    let svg = renderer.to_svg_string();
+    assert!(!svg.contains("<text"), "text should be outlined: {svg}");
+    let glyph_paths: Vec<&str> = svg_element_lines(&svg, "path")
+        .into_iter()
+        .filter(|line| line.contains("transform=\"translate("))
+        .collect();
+    assert_eq!(glyph_paths.len(), 4, "one path per line: {svg}");
+    assert!(
+        glyph_paths
+            .iter()
+            .all(|line| svg_attr_value(line, "d").starts_with('M'))
+    );
+
+    let translate_x = |line: &str| {
+        let translate = svg_attr_value(line, "transform");
+        translate["translate(".len()..translate.find(',').unwrap()]
+            .parse::<f32>()
+            .unwrap()
+    };
+    assert_eq!(translate_x(glyph_paths[0]), 10.0);
+    let centered_x = translate_x(glyph_paths[1]);
+    assert!(centered_x < 100.0 && centered_x > 80.0, "{centered_x}");
+    assert_eq!(
+        glyph_paths
+            .iter()
+            .filter(|line| svg_attr_value(line, "fill") == "rgb(255,0,0)")
+            .count(),
+        2
+    );
+    assert!(svg.contains(r#"<g transform="translate(50.00,50.00) rotate(-90.0)">"#));
+}
+
 #[test]
 fn test_plain_svg_named_font_family_is_css_quoted() {
     let mut renderer = SvgRenderer::with_font_family(
@@ -705,6 +751,7 @@ fn styled_text_defaults_to_center_middle_and_scales_decoration() {
             padding: 3.0,
             border_color: Some(Color::BLUE),
             border_width: 1.5,
+            corner_radius: 0.0,
         };
         renderer
             .draw_styled_text("Anchor", 120.0, 80.0, &FontFamily::SansSerif, &style)
@@ -738,6 +785,36 @@ fn styled_text_defaults_to_center_middle_and_scales_decoration() {
     assert!(low.contains(r#"text-anchor="middle""#));
 }
 
+#[test]
+fn styled_text_corner_radius_rounds_the_box_and_is_clamped() {
+    let render = |radius: f32| {
+        let mut renderer = SvgRenderer::new(240.0, 160.0);
+        renderer.set_render_scale(RenderScale::new(144.0));
+        let style = TextStyle::new()
+            .background(Color::WHITE)
+            .border(Color::BLACK, 1.0)
+            .corner_radius(radius);
+        renderer
+            .draw_styled_text("Boxed", 120.0, 80.0, &FontFamily::SansSerif, &style)
+            .unwrap();
+        let svg = renderer.to_svg_string();
+        svg_element_lines(&svg, "rect")
+            .into_iter()
+            .find(|line| line.contains(r#"fill="rgb(255,255,255)""#))
+            .unwrap()
+            .to_string()
+    };
+
+    let square = render(0.0);
+    assert!(!square.contains("rx="));
+    let rounded = render(3.0);
+    assert_eq!(svg_attr_value(&rounded, "rx"), "6.00");
+    assert_eq!(svg_attr_value(&rounded, "ry"), "6.00");
+    let pill = render(500.0);
+    let half_height = parse_svg_attr(&pill, "height") / 2.0;
+    assert_approx_eq(parse_svg_attr(&pill, "rx"), half_height);
+}
+
 #[test]
 fn styled_text_honors_alignment_counter_clockwise_rotation_and_font_family() {
     let mut renderer = SvgRenderer::new(240.0, 160.0);
@@ -752,6 +829,7 @@ fn styled_text_honors_alignment_counter_clockwise_rotation_and_font_family() {
         padding: 2.0,
         border_color: Some(Color::new_rgba(40, 50, 60, 128)),
         border_width: 2.0,
+        corner_radius: 0.0,
     };
     renderer
         .draw_styled_text(
@@ -924,6 +1002,43 @@ fn weighted_multiline_title_uses_matching_metrics_tspans_and_svg_whitespace() {
     assert!(metrics.height >= 24.0);
 }
 
+#[test]
+fn rotated_and_plain_multiline_text_stack_lines_as_tspans() {
+    let mut renderer = SvgRenderer::new(240.0, 160.0);
+    let text = "Voltage\n(mV)";
+    renderer
+        .draw_text_rotated(text, 20.0, 80.0, 12.0, Color::BLACK, -90.0)
+        .unwrap();
+    renderer
+        .draw_text("Left\naligned", 40.0, 30.0, 12.0, Color::BLACK)
+        .unwrap();
+
+    let metrics = renderer.plain_text_metrics(text, 12.0).unwrap();
+    let svg = renderer.to_svg_string();
+    let rotated = svg
+        .lines()
+        .find(|line| line.contains("Voltage"))
+        .expect("rotated multiline label");
+    assert!(rotated.contains("rotate(-90.0)"));
+    assert!(!rotated.contains('\n'));
+    assert_eq!(rotated.matches(r#"<tspan x="0.00""#).count(), 2);
+    let first_tspan = rotated.find("<tspan ").unwrap();
+    let second_tspan = rotated.rfind("<tspan ").unwrap();
+    let first_y = parse_svg_attr(&rotated[first_tspan..], "y");
+    assert_approx_eq(first_y, center_anchor_to_baseline(0.0, metrics));
+    assert_approx_eq(
+        parse_svg_attr(&rotated[second_tspan..], "y") - first_y,
+        14.4,
+    );
+
+    let plain = svg
+        .lines()
+        .find(|line| line.contains("Left"))
+        .expect("plain multiline text");
+    assert_eq!(plain.matches(r#"<tspan x="40.00""#).count(), 2);
+    assert!(plain.ends_with(">aligned</tspan></text>"));
+}
+
 #[cfg(feature = "typst-math")]
 #[test]
 fn typst_decorated_whitespace_annotation_uses_plain_one_line_geometry() {
@@ -18,9 +18,9 @@ fn pdf_font_database() -> Result<svg2pdf::usvg::fontdb::Database> {
     Ok(database)
 }
 
-/// Convert SVG string to PDF bytes
+/// Parse SVG for PDF conversion with the PDF font database
 #[cfg(feature = "pdf")]
-pub fn svg_to_pdf(svg_data: &str) -> Result<Vec<u8>> {
+fn parse_svg_tree(svg_data: &str) -> Result<svg2pdf::usvg::Tree> {
     // Use svg2pdf's re-exported usvg to ensure version compatibility
     use svg2pdf::usvg;
 
@@ -34,8 +34,14 @@ pub fn svg_to_pdf(svg_data: &str) -> Result<Vec<u8>> {
         ..Default::default()
     };
 
-    let tree = usvg::Tree::from_str(svg_data, &options)
-        .map_err(|e| PlottingError::RenderError(format!("Failed to parse SVG: {}", e)))?;
+    usvg::Tree::from_str(svg_data, &options)
+        .map_err(|e| PlottingError::RenderError(format!("Failed to parse SVG: {}", e)))
+}
+
+/// Convert SVG string to PDF bytes
+#[cfg(feature = "pdf")]
+pub fn svg_to_pdf(svg_data: &str) -> Result<Vec<u8>> {
+    let tree = parse_svg_tree(svg_data)?;
 
     // Convert to PDF
     let pdf_data = svg2pdf::to_pdf(
@@ -48,6 +54,20 @@ pub fn svg_to_pdf(svg_data: &str) -> Result<Vec<u8>> {
     Ok(pdf_data)
 }
 
+/// Convert SVG string to PDF bytes, filling the Info dictionary from `metadata`
+///
+/// Empty metadata gives the same bytes as [`svg_to_pdf`]; otherwise the page
+/// is assembled by [`PdfDocument`], which writes the Info dictionary.
+#[cfg(feature = "pdf")]
+pub fn svg_to_pdf_with_metadata(svg_data: &str, metadata: &PdfMetadata) -> Result<Vec<u8>> {
+    if *metadata == PdfMetadata::default() {
+        return svg_to_pdf(svg_data);
+    }
+    let mut document = PdfDocument::new().metadata(metadata.clone());
+    document.add_svg(svg_data)?;
+    document.to_bytes()
+}
+
 /// Convert SVG string to PDF and save to file
 #[cfg(feature = "pdf")]
 pub fn svg_to_pdf_file<P: AsRef<Path>>(svg_data: &str, path: P) -> Result<()> {
@@ -55,6 +75,210 @@ pub fn svg_to_pdf_file<P: AsRef<Path>>(svg_data: &str, path: P) -> Result<()> {
     crate::export::write_bytes_atomic(path, &pdf_data)
 }
 
+/// Convert SVG string to PDF with document metadata and save to file
+#[cfg(feature = "pdf")]
+pub fn svg_to_pdf_file_with_metadata<P: AsRef<Path>>(
+    svg_data: &str,
+    path: P,
+    metadata: &PdfMetadata,
+) -> Result<()> {
+    let pdf_data = svg_to_pdf_with_metadata(svg_data, metadata)?;
+    crate::export::write_bytes_atomic(path, &pdf_data)
+}
+
+/// Document information written into a PDF's Info dictionary
+///
+/// Viewers show these as the document properties, and institutional
+/// repositories index them. Unset fields are left out.
+#[cfg(feature = "pdf")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PdfMetadata {
+    /// Document title
+    pub title: Option<String>,
+    /// Name of the person or organization that created the document
+    pub author: Option<String>,
+    /// What the document is about
+    pub subject: Option<String>,
+    /// Keywords, written comma-separated
+    pub keywords: Vec<String>,
+}
+
+/// One converted page: the plot as a form XObject plus its page size in points
+#[cfg(feature = "pdf")]
+struct PdfPage {
+    chunk: pdf_writer::Chunk,
+    xobject: pdf_writer::Ref,
+    width: f32,
+    height: f32,
+}
+
+/// Multi-page PDF with one plot per page
+///
+/// Each plot is converted when it is added, and [`PdfDocument::save`] writes
+/// the pages in order. Single-page export with metadata
+/// ([`svg_to_pdf_with_metadata`]) assembles its one page through this type too.
+/// Pages default to [`page_sizes::PLOT_DEFAULT`] (like `save_pdf`); set a
+/// document default with [`PdfDocument::page_size`] or size one page with
+/// [`PdfDocument::add_plot_with_size`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ruviz::export::PdfDocument;
+/// use ruviz::prelude::*;
+///
+/// let x: Vec<f64> = (0..20).map(f64::from).collect();
+/// let mut doc = PdfDocument::new();
+/// for power in 1..=3 {
+///     let y: Vec<f64> = x.iter().map(|v| v.powi(power)).collect();
+///     doc.add_plot(Plot::new().line(&x, &y).title(format!("x^{power}")))?;
+/// }
+/// doc.save("report.pdf")?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "pdf")]
+#[derive(Default)]
+pub struct PdfDocument {
+    page_size: Option<(f64, f64)>,
+    metadata: PdfMetadata,
+    pages: Vec<PdfPage>,
+}
+
+#[cfg(feature = "pdf")]
+impl PdfDocument {
+    /// Create an empty document
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the default page size in millimeters for plots added afterwards
+    pub fn page_size(mut self, width_mm: f64, height_mm: f64) -> Self {
+        self.page_size = Some((width_mm, height_mm));
+        self
+    }
+
+    /// Set the title, author, subject, and keywords written to the document
+    pub fn metadata(mut self, metadata: PdfMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Number of pages added so far
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Render `plot` onto a new page at the document page size
+    pub fn add_plot(&mut self, plot: impl Into<crate::core::Plot>) -> Result<()> {
+        self.add_page(plot.into(), self.page_size)
+    }
+
+    /// Render `plot` onto a new page of `(width_mm, height_mm)`
+    pub fn add_plot_with_size(
+        &mut self,
+        plot: impl Into<crate::core::Plot>,
+        size: (f64, f64),
+    ) -> Result<()> {
+        self.add_page(plot.into(), Some(size))
+    }
+
+    fn add_page(&mut self, plot: crate::core::Plot, size: Option<(f64, f64)>) -> Result<()> {
+        self.add_svg(&plot.render_pdf_svg(size)?)
+    }
+
+    /// Convert `svg_data` onto a new page the size of the SVG, one SVG pixel
+    /// per point
+    fn add_svg(&mut self, svg_data: &str) -> Result<()> {
+        let tree = parse_svg_tree(svg_data)?;
+        let (chunk, xobject) = svg2pdf::to_chunk(&tree, svg2pdf::ConversionOptions::default())
+            .map_err(|e| {
+                PlottingError::RenderError(format!("Failed to convert SVG to PDF: {:?}", e))
+            })?;
+        let size = tree.size();
+        self.pages.push(PdfPage {
+            chunk,
+            xobject,
+            width: size.width(),
+            height: size.height(),
+        });
+        Ok(())
+    }
+
+    /// Assemble the pages into PDF bytes
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, TextStr};
+
+        if self.pages.is_empty() {
+            return Err(PlottingError::InvalidInput(
+                "PDF document has no pages".to_string(),
+            ));
+        }
+
+        let mut alloc = Ref::new(1);
+        let catalog_id = alloc.bump();
+        let page_tree_id = alloc.bump();
+        let mut pdf = Pdf::new();
+        let mut page_ids = Vec::with_capacity(self.pages.len());
+        let xobject_name = Name(b"P1");
+
+        for page in &self.pages {
+            let page_id = alloc.bump();
+            let content_id = alloc.bump();
+            page_ids.push(page_id);
+
+            // Each chunk numbers its objects from 1, so move them past ours
+            let mut ids = std::collections::HashMap::new();
+            let chunk = page
+                .chunk
+                .renumber(|old| *ids.entry(old).or_insert_with(|| alloc.bump()));
+            let xobject = ids[&page.xobject];
+
+            let mut pdf_page = pdf.page(page_id);
+            pdf_page.media_box(Rect::new(0.0, 0.0, page.width, page.height));
+            pdf_page.parent(page_tree_id);
+            pdf_page.contents(content_id);
+            pdf_page.resources().x_objects().pair(xobject_name, xobject);
+            pdf_page.finish();
+
+            // The XObject spans the unit square; scale it to fill the page
+            let mut content = Content::new();
+            content.transform([page.width, 0.0, 0.0, page.height, 0.0, 0.0]);
+            content.x_object(xobject_name);
+            pdf.stream(content_id, &content.finish());
+            pdf.extend(&chunk);
+        }
+
+        pdf.catalog(catalog_id).pages(page_tree_id);
+        let page_count = page_ids.len() as i32;
+        pdf.pages(page_tree_id).kids(page_ids).count(page_count);
+
+        let metadata = &self.metadata;
+        if *metadata != PdfMetadata::default() {
+            let keywords = metadata.keywords.join(", ");
+            let mut info = pdf.document_info(alloc.bump());
+            if let Some(title) = &metadata.title {
+                info.title(TextStr(title));
+            }
+            if let Some(author) = &metadata.author {
+                info.author(TextStr(author));
+            }
+            if let Some(subject) = &metadata.subject {
+                info.subject(TextStr(subject));
+            }
+            if !keywords.is_empty() {
+                info.keywords(TextStr(&keywords));
+            }
+        }
+
+        Ok(pdf.finish())
+    }
+
+    /// Write the document to `path`
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        crate::export::write_bytes_atomic(path, &self.to_bytes()?)
+    }
+}
+
 /// Page sizes in millimeters
 pub mod page_sizes {
     /// A4 paper size (210mm x 297mm)
@@ -121,6 +345,76 @@ mod tests {
         assert!(svg_to_pdf(svg).unwrap().starts_with(b"%PDF-"));
     }
 
+    #[test]
+    #[cfg(feature = "pdf")]
+    fn test_pdf_document_writes_one_page_per_plot() {
+        let mut doc = PdfDocument::new().page_size(100.0, 80.0);
+        assert!(doc.to_bytes().is_err());
+
+        let plot: crate::core::Plot = crate::core::Plot::new()
+            .line(&[0.0, 1.0, 2.0], &[1.0, 3.0, 2.0])
+            .into();
+        doc.add_plot(plot.clone()).unwrap();
+        doc.add_plot_with_size(plot, page_sizes::A4_LANDSCAPE)
+            .unwrap();
+        assert_eq!(doc.page_count(), 2);
+
+        let pdf = doc.to_bytes().unwrap();
+        assert!(pdf.starts_with(b"%PDF-"));
+        assert!(String::from_utf8_lossy(&pdf).contains("/Count 2"));
+    }
+
+    #[test]
+    #[cfg(feature = "pdf")]
+    fn test_pdf_document_writes_info_dictionary() {
+        let plot = crate::core::Plot::new().line(&[0.0, 1.0], &[0.0, 1.0]);
+        let mut doc = PdfDocument::new().metadata(PdfMetadata {
+            title: Some("Yield curve".to_string()),
+            author: Some("Lab A".to_string()),
+            subject: None,
+            keywords: vec!["rates".to_string(), "2026".to_string()],
+        });
+        doc.add_plot(plot).unwrap();
+
+        let pdf = doc.to_bytes().unwrap();
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains("/Title (Yield curve)"));
+        assert!(text.contains("/Author (Lab A)"));
+        assert!(text.contains("/Keywords (rates, 2026)"));
+        assert!(!text.contains("/Subject"));
+        assert!(text.contains("/Info "));
+    }
+
+    #[test]
+    #[cfg(feature = "pdf")]
+    fn test_svg_to_pdf_with_metadata_writes_info_dictionary() {
+        let svg = r#"<svg width="200" height="150" xmlns="http://www.w3.org/2000/svg">
+  <rect width="100%" height="100%" fill="white"/>
+</svg>"#;
+        let metadata = PdfMetadata {
+            title: Some("Single page".to_string()),
+            ..Default::default()
+        };
+
+        let pdf = svg_to_pdf_with_metadata(svg, &metadata).unwrap();
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains("/Title (Single page)"));
+
+        // Without metadata the output is exactly svg2pdf's single-page PDF
+        let plain = svg_to_pdf(svg).unwrap();
+        let expected = svg2pdf::to_pdf(
+            &parse_svg_tree(svg).unwrap(),
+            svg2pdf::ConversionOptions::default(),
+            svg2pdf::PageOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(plain, expected);
+        assert_eq!(
+            svg_to_pdf_with_metadata(svg, &PdfMetadata::default()).unwrap(),
+            expected
+        );
+    }
+
     #[test]
     fn test_page_size_conversion() {
         // 25.4mm = 96 pixels (at 96 DPI)
@@ -151,6 +151,19 @@ impl RealTimeRenderer {
         session.viewport_snapshot().map(Some)
     }
 
+    pub(crate) fn legend_entry_at(&self, position: Point2D) -> Option<Vec<usize>> {
+        self.interactive_session
+            .as_ref()?
+            .legend_entry_at(ViewportPoint::new(position.x, position.y))
+    }
+
+    pub(crate) fn hidden_series(&self) -> Vec<usize> {
+        self.interactive_session
+            .as_ref()
+            .map(InteractivePlotSession::hidden_series)
+            .unwrap_or_default()
+    }
+
     pub(crate) fn restore_visible_bounds(
         &mut self,
         visible_bounds: crate::core::ViewportRect,
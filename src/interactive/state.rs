@@ -29,6 +29,9 @@ pub struct InteractionState {
     pub brush_start: Option<Point2D>,
     pub linked_plots: Vec<PlotId>,
 
+    // Series toggled off from the legend
+    pub hidden_series: HashSet<usize>,
+
     // Hover and tooltip
     pub hover_point: Option<DataPoint>,
     pub tooltip_visible: bool,
@@ -69,6 +72,8 @@ impl Default for InteractionState {
             brush_start: None,
             linked_plots: Vec::new(),
 
+            hidden_series: HashSet::new(),
+
             hover_point: None,
             tooltip_visible: false,
             tooltip_content: String::new(),
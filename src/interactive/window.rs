@@ -180,6 +180,18 @@ struct ContextMenuState {
 }
 
 /// Interactive window for displaying plots with real-time interactions
+///
+/// Mouse and keyboard input changes the visible axis limits and re-renders
+/// the plot:
+///
+/// - Scroll wheel: zoom in or out around the cursor
+/// - Left drag: pan
+/// - Left click on a legend entry: hide or show its series
+/// - Right drag: zoom to the dragged rectangle
+/// - Right click: open the context menu
+/// - `r` or `Escape`: reset to the plot's own limits (`Escape` closes an
+///   open context menu instead)
+/// - `Delete`: clear the selection
 pub struct InteractiveWindow {
     window: Option<Arc<Window>>,
     surface_context: Option<SoftbufferContext<OwnedDisplayHandle>>,
@@ -1194,6 +1206,7 @@ impl InteractiveWindow {
         self.interaction_state.tooltip_visible = false;
         self.interaction_state.tooltip_content.clear();
         self.interaction_state.tooltip_position = Point2D::zero();
+        self.interaction_state.hidden_series = self.renderer.hidden_series().into_iter().collect();
         Ok(())
     }
 
@@ -1231,6 +1244,17 @@ impl InteractiveWindow {
             return Ok(());
         }
 
+        if self.renderer.legend_entry_at(position).is_some() {
+            self.reset_pointer_state();
+            self.clear_pending_hover();
+            return self.apply_plot_input(
+                PlotInputEvent::ToggleLegendEntry {
+                    position_px: ViewportPoint::new(position.x, position.y),
+                },
+                false,
+            );
+        }
+
         if !self.plot_area_contains(position)? {
             self.reset_pointer_state();
             return Ok(());
@@ -1461,6 +1485,11 @@ impl InteractiveWindow {
                     self.apply_plot_input(PlotInputEvent::ResetView, true)
                 }
             }
+            "r" => {
+                self.close_context_menu();
+                self.reset_pointer_state();
+                self.apply_plot_input(PlotInputEvent::ResetView, true)
+            }
             "Delete" => self.apply_plot_input(PlotInputEvent::ClearSelection, false),
             _ => Ok(()),
         }
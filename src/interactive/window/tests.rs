@@ -444,6 +444,45 @@ async fn test_right_click_opens_context_menu_without_zooming() {
     assert!(window.context_menu.is_some());
 }
 
+#[tokio::test]
+async fn test_r_key_resets_zoomed_and_panned_view() {
+    let mut window = interactive_window_for_test().await;
+    let home = viewport_snapshot(&window);
+    let center = plot_area_center(&window);
+    window.mouse_position = PhysicalPosition::new(center.x, center.y);
+    window
+        .handle_scroll_delta(LINE_SCROLL_DELTA_PX)
+        .expect("scroll zoom should succeed");
+    window
+        .handle_left_button_pressed(center)
+        .expect("mouse down should succeed");
+    window
+        .handle_pointer_moved(Point2D::new(center.x + 40.0, center.y + 24.0))
+        .expect("pan drag should succeed");
+    window
+        .handle_left_button_released(Point2D::new(center.x + 40.0, center.y + 24.0))
+        .expect("mouse up should succeed");
+    window
+        .render_frame()
+        .expect("render after pan should succeed");
+    assert_ne!(
+        viewport_snapshot(&window).visible_bounds,
+        home.visible_bounds
+    );
+
+    window
+        .handle_key_string("r")
+        .expect("r should reset the view");
+    window
+        .render_frame()
+        .expect("render after reset should succeed");
+
+    assert_visible_bounds_close(
+        viewport_snapshot(&window).visible_bounds,
+        home.visible_bounds,
+    );
+}
+
 #[tokio::test]
 async fn test_escape_closes_context_menu_without_resetting_view() {
     let mut window = interactive_window_for_test().await;
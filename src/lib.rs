@@ -821,26 +821,29 @@ pub mod animation;
 
 /// Convenience re-exports for common usage
 pub mod prelude {
-    pub use crate::axes::AxisScale;
+    pub use crate::axes::{AxisScale, TimeFormat};
     pub use crate::core::{
-        Annotation, AnnotationId, ArrowHead, ArrowStyle, BackendType, BuilderWhen, FillStyle,
-        FramePacing, FrameStats, GridSpec, HatchPattern, HitResult, Image, ImageTarget,
-        InsetAnchor, InsetLayout, InteractiveFrame, InteractivePlotSession,
-        InteractiveViewportSnapshot, IntoPlot, LayerRenderState, Legend, LegendAnchor, LegendItem,
-        LegendItemType, LegendPosition, Plot, PlotBuilder, PlotInput, PlotInputEvent, PlotSource,
-        Position, PreparedPlot, QualityPolicy, ReactiveSubscription, ReactiveValue,
-        RenderTargetKind, Result, SeriesStyle, ShapeStyle, SubplotFigure, SurfaceCapability,
-        SurfaceTarget, TextAlign, TextStyle, TextVAlign, TickDirection, TickSides, ViewportPoint,
-        ViewportRect, subplots, subplots_default,
+        Annotation, AnnotationId, ArrowHead, ArrowStyle, AspectMode, BackendType, BuilderWhen,
+        Colorbar, CoordSystem, DownsampleMode, FillStyle, FramePacing, FrameStats, GridAxis,
+        GridSpec, HatchPattern, HitResult, Image, ImageTarget, InsetAnchor, InsetLayout,
+        InteractiveFrame, InteractivePlotSession, InteractiveViewportSnapshot, IntoPlot,
+        LayerRenderState, Legend, LegendAnchor, LegendItem, LegendItemType, LegendPosition, Plot,
+        PlotBuilder, PlotInput, PlotInputEvent, PlotSource, Position, PreparedPlot, QualityPolicy,
+        ReactiveSubscription, ReactiveValue, RenderTargetKind, Result, SeriesStyle, ShapeStyle,
+        SpinePosition, SubplotFigure, SurfaceCapability, SurfaceTarget, TextAlign, TextStyle,
+        TextVAlign, TickDirection, TickLabelFormatter, TickSides, ViewportPoint, ViewportRect,
+        facet_grid, subplots, subplots_default,
     };
     pub use crate::data::{
-        Data1D, DataShader, DataShaderCanvas, NullPolicy, NumericData1D, NumericData2D,
+        AggMode, Data1D, DataShader, DataShaderCanvas, NullPolicy, NumericData1D, NumericData2D,
     };
+    pub use crate::export::{ImageFormat, SvgOptions};
     pub use crate::plots::{
-        BoxenConfig, BoxenOrientation, ContourConfig, HeatmapConfig, HeatmapOrigin, Interpolation,
-        PieConfig, PlotArea, PlotCompute, PlotConfig, PlotData, PlotRender, PolarPlotConfig,
-        QuiverConfig, QuiverPivot, RadarConfig, StemMarker, StemOrientation, StepWhere,
-        ViolinConfig,
+        BandwidthMethod, BoxenConfig, BoxenOrientation, ColorbarConfig, ColorbarOrientation,
+        ContourConfig, HeatmapConfig, HeatmapOrigin, HexbinConfig, Interpolation, PieConfig,
+        PlotArea, PlotCompute, PlotConfig, PlotData, PlotRender, PolarPlotConfig, QuiverConfig,
+        QuiverPivot, RadarConfig, RugAxis, RugConfig, ScatterMappedConfig, StemMarker,
+        StemOrientation, StepWhere, ThetaUnit, ViolinConfig,
     };
     pub use crate::render::{
         Color, ColorMap, FontConfig, FontFamily, FontStyle, FontWeight, LineStyle, MarkerStyle,
@@ -850,6 +853,9 @@ pub mod prelude {
     // Top-level convenience functions
     pub use crate::{bar, line, scatter};
 
+    #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+    pub use crate::core::render_batch;
+
     #[cfg(all(feature = "interactive", not(target_arch = "wasm32")))]
     pub use crate::interactive::{
         event::{InteractionEvent, Point2D, Rectangle, Vector2D},
@@ -2,6 +2,7 @@
 //!
 //! Provides [`BarConfig`] for configuring bar chart appearance.
 
+use crate::core::TickLabelFormatter;
 use crate::plots::traits::PlotConfig;
 use crate::render::Color;
 
@@ -50,6 +51,10 @@ pub struct BarConfig {
     pub bottom: f64,
     /// Whether to align bars to the left of their position
     pub align_left: bool,
+    /// Whether to print each bar's value at its end
+    pub show_labels: bool,
+    /// Value label formatter (None = default tick formatting)
+    pub label_formatter: Option<TickLabelFormatter>,
 }
 
 impl Default for BarConfig {
@@ -63,6 +68,8 @@ impl Default for BarConfig {
             orientation: BarOrientation::Vertical,
             bottom: 0.0,
             align_left: false,
+            show_labels: false,
+            label_formatter: None,
         }
     }
 }
@@ -152,6 +159,23 @@ impl BarConfig {
         self
     }
 
+    /// Set whether to print each bar's value at its end
+    ///
+    /// Labels sit just above positive bars and just below negative ones.
+    pub fn show_labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Format value labels with a [`TickLabelFormatter`] or `Fn(f64) -> String` closure
+    ///
+    /// Also turns value labels on.
+    pub fn label_formatter(mut self, formatter: impl Into<TickLabelFormatter>) -> Self {
+        self.label_formatter = Some(formatter.into());
+        self.show_labels = true;
+        self
+    }
+
     /// Create a horizontal bar configuration
     pub fn horizontal() -> Self {
         Self::default().orientation(BarOrientation::Horizontal)
@@ -3,6 +3,7 @@
 //! This module provides configuration structs for fundamental plot types:
 //! - [`LineConfig`] - Line plot configuration
 //! - [`ScatterConfig`] - Scatter plot configuration
+//! - [`ScatterMappedConfig`] - Color-mapped scatter plot configuration
 //! - [`BarConfig`] - Bar chart configuration
 //!
 //! These configs integrate with [`PlotBuilder<C>`](crate::core::PlotBuilder) to provide
@@ -31,7 +32,9 @@
 mod bar;
 mod line;
 mod scatter;
+mod scatter_mapped;
 
 pub use bar::{BarConfig, BarOrientation};
 pub use line::LineConfig;
 pub use scatter::ScatterConfig;
+pub use scatter_mapped::{ScatterMappedConfig, ScatterMappedData, compute_scatter_mapped};
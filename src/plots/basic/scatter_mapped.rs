@@ -0,0 +1,229 @@
+//! Color-mapped scatter plot
+//!
+//! Provides [`ScatterMappedConfig`] and [`ScatterMappedData`] for scatter
+//! plots whose marker colors encode a third variable through a [`ColorMap`],
+//! like matplotlib's `scatter(x, y, c=values, cmap=...)`.
+
+use crate::plots::traits::{PlotConfig, PlotData};
+use crate::render::{Color, ColorMap};
+
+/// Configuration for color-mapped scatter plots
+///
+/// Marker style and size come from the series style (`marker`,
+/// `marker_size`), so only the color mapping and colorbar live here.
+///
+/// # Example
+///
+/// ```rust
+/// use ruviz::plots::basic::ScatterMappedConfig;
+/// use ruviz::render::ColorMap;
+///
+/// let config = ScatterMappedConfig::new(ColorMap::plasma())
+///     .clim(0.0, 1.0)
+///     .colorbar(true);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScatterMappedConfig {
+    /// Colormap sampled by each point's normalized value
+    pub colormap: ColorMap,
+    /// Value mapped to the low end of the colormap (None = data minimum)
+    pub vmin: Option<f64>,
+    /// Value mapped to the high end of the colormap (None = data maximum)
+    pub vmax: Option<f64>,
+    /// Show colorbar
+    pub colorbar: bool,
+    /// Label for the colorbar
+    pub colorbar_label: Option<String>,
+    /// Font size for colorbar tick labels (in points)
+    pub colorbar_tick_font_size: f32,
+    /// Font size for colorbar label (in points)
+    pub colorbar_label_font_size: f32,
+}
+
+impl Default for ScatterMappedConfig {
+    fn default() -> Self {
+        Self::new(ColorMap::viridis())
+    }
+}
+
+impl PlotConfig for ScatterMappedConfig {}
+
+impl ScatterMappedConfig {
+    /// Create a new configuration using the given colormap
+    pub fn new(colormap: ColorMap) -> Self {
+        Self {
+            colormap,
+            vmin: None,
+            vmax: None,
+            colorbar: false,
+            colorbar_label: None,
+            colorbar_tick_font_size: 10.0,
+            colorbar_label_font_size: 11.0,
+        }
+    }
+
+    /// Set the colormap
+    pub fn colormap(mut self, colormap: ColorMap) -> Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// Set the value range mapped onto the colormap
+    ///
+    /// Values outside the range are clamped to the colormap ends.
+    pub fn clim(mut self, vmin: f64, vmax: f64) -> Self {
+        self.vmin = Some(vmin);
+        self.vmax = Some(vmax);
+        self
+    }
+
+    /// Enable/disable colorbar
+    pub fn colorbar(mut self, show: bool) -> Self {
+        self.colorbar = show;
+        self
+    }
+
+    /// Set the colorbar label
+    pub fn colorbar_label<S: Into<String>>(mut self, label: S) -> Self {
+        self.colorbar_label = Some(label.into());
+        self
+    }
+}
+
+/// Computed data for a color-mapped scatter plot
+#[derive(Debug, Clone)]
+pub struct ScatterMappedData {
+    /// X coordinates
+    pub x: Vec<f64>,
+    /// Y coordinates
+    pub y: Vec<f64>,
+    /// Per-point values mapped through the colormap
+    pub c: Vec<f64>,
+    /// Value range mapped onto the colormap
+    pub value_range: (f64, f64),
+    /// Configuration used
+    pub config: ScatterMappedConfig,
+}
+
+impl ScatterMappedData {
+    /// Colormap color for a value, or `None` for non-finite values
+    pub fn value_color(&self, value: f64) -> Option<Color> {
+        if !value.is_finite() {
+            return None;
+        }
+        let (vmin, vmax) = self.value_range;
+        let range = vmax - vmin;
+        let t = if range > 0.0 {
+            ((value - vmin) / range).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        Some(self.config.colormap.sample(t))
+    }
+
+    /// Points that can be drawn, with their colormap colors
+    ///
+    /// Points with a non-finite coordinate or value are skipped.
+    pub fn colored_points(&self) -> impl Iterator<Item = (f64, f64, Color)> + '_ {
+        self.x
+            .iter()
+            .zip(&self.y)
+            .zip(&self.c)
+            .filter(|((x, y), _)| x.is_finite() && y.is_finite())
+            .filter_map(|((&x, &y), &c)| self.value_color(c).map(|color| (x, y, color)))
+    }
+
+    /// Value range shown by the colorbar
+    pub fn colorbar_range(&self) -> (f64, f64) {
+        self.value_range
+    }
+}
+
+/// Compute color-mapped scatter data
+///
+/// The color range defaults to the finite extent of `c`; explicit `vmin`/`vmax`
+/// in the configuration take precedence.
+pub fn compute_scatter_mapped(
+    x: &[f64],
+    y: &[f64],
+    c: &[f64],
+    config: &ScatterMappedConfig,
+) -> ScatterMappedData {
+    let (data_min, data_max) = c
+        .iter()
+        .copied()
+        .filter(|value| value.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+    let (data_min, data_max) = if data_min <= data_max {
+        (data_min, data_max)
+    } else {
+        (0.0, 1.0)
+    };
+    let vmin = config.vmin.filter(|value| value.is_finite());
+    let vmax = config.vmax.filter(|value| value.is_finite());
+
+    ScatterMappedData {
+        x: x.to_vec(),
+        y: y.to_vec(),
+        c: c.to_vec(),
+        value_range: (vmin.unwrap_or(data_min), vmax.unwrap_or(data_max)),
+        config: config.clone(),
+    }
+}
+
+impl PlotData for ScatterMappedData {
+    fn data_bounds(&self) -> ((f64, f64), (f64, f64)) {
+        let mut x_bounds = (f64::INFINITY, f64::NEG_INFINITY);
+        let mut y_bounds = (f64::INFINITY, f64::NEG_INFINITY);
+        for (x, y, _) in self.colored_points() {
+            x_bounds = (x_bounds.0.min(x), x_bounds.1.max(x));
+            y_bounds = (y_bounds.0.min(y), y_bounds.1.max(y));
+        }
+        (x_bounds, y_bounds)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.colored_points().next().is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colors_follow_value_range_and_skip_non_finite_points() {
+        let config = ScatterMappedConfig::new(ColorMap::viridis());
+        let data = compute_scatter_mapped(
+            &[0.0, 1.0, 2.0, f64::NAN],
+            &[0.0, 1.0, 2.0, 3.0],
+            &[10.0, f64::NAN, 20.0, 15.0],
+            &config,
+        );
+
+        assert_eq!(data.value_range, (10.0, 20.0));
+        let points: Vec<_> = data.colored_points().collect();
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].2, ColorMap::viridis().sample(0.0));
+        assert_eq!(points[1].2, ColorMap::viridis().sample(1.0));
+        assert_eq!(data.data_bounds(), ((0.0, 2.0), (0.0, 2.0)));
+    }
+
+    #[test]
+    fn test_clim_overrides_and_clamps_value_range() {
+        let config = ScatterMappedConfig::new(ColorMap::viridis()).clim(0.0, 100.0);
+        let data = compute_scatter_mapped(&[0.0, 1.0], &[0.0, 1.0], &[50.0, 500.0], &config);
+
+        assert_eq!(data.colorbar_range(), (0.0, 100.0));
+        assert_eq!(
+            data.value_color(50.0),
+            Some(ColorMap::viridis().sample(0.5))
+        );
+        assert_eq!(
+            data.value_color(500.0),
+            Some(ColorMap::viridis().sample(1.0))
+        );
+    }
+}
@@ -34,6 +34,10 @@ pub struct BoxPlotConfig {
     pub cap_width: Option<f32>,
     /// Outlier marker size (default 6.0)
     pub flier_size: Option<f32>,
+    /// Category name for this box (for X-axis label)
+    pub category: Option<String>,
+    /// X position for this box (default: 0.5 for single box)
+    pub x_position: f64,
 }
 
 /// Methods for detecting outliers
@@ -490,6 +494,8 @@ impl Default for BoxPlotConfig {
             median_width: None,
             cap_width: None,
             flier_size: None,
+            category: None,
+            x_position: 0.5,
         }
     }
 }
@@ -574,6 +580,22 @@ impl BoxPlotConfig {
         self.flier_size = Some(size);
         self
     }
+
+    /// Set category name for this box
+    ///
+    /// The category name is displayed on the X-axis instead of numeric values.
+    pub fn category<S: Into<String>>(mut self, name: S) -> Self {
+        self.category = Some(name.into());
+        self
+    }
+
+    /// Set X position for this box
+    ///
+    /// Used when plotting multiple boxes to control their horizontal positions.
+    pub fn x_position(mut self, pos: f64) -> Self {
+        self.x_position = pos;
+        self
+    }
 }
 
 /// Calculate box plot statistics from data
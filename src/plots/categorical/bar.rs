@@ -10,7 +10,7 @@
 //! - [`PlotData`] for `StackedBarData` and `GroupedBarData`
 //! - [`PlotRender`] for `StackedBarData` and `GroupedBarData`
 
-use crate::core::Result;
+use crate::core::{Result, TickLabelFormatter};
 use crate::plots::traits::{PlotArea, PlotCompute, PlotConfig, PlotData, PlotRender};
 use crate::render::skia::SkiaRenderer;
 use crate::render::{Color, LineStyle, Theme};
@@ -32,6 +32,8 @@ pub struct StackedBarConfig {
     pub edge_width: f32,
     /// Orientation
     pub orientation: BarOrientation,
+    /// Scale each category to fractions of its total (100% stacked bars)
+    pub normalize: bool,
 }
 
 /// Configuration for grouped bar chart
@@ -72,6 +74,7 @@ impl Default for StackedBarConfig {
             edge_color: None,
             edge_width: 0.0,
             orientation: BarOrientation::Vertical,
+            normalize: false,
         }
     }
 }
@@ -123,6 +126,26 @@ impl StackedBarConfig {
         self.orientation = BarOrientation::Vertical;
         self
     }
+
+    /// Stack each category's shares of its total instead of raw values
+    ///
+    /// Every segment is divided by the category total (the sum of absolute
+    /// values), so each bar reaches 1.0 and shows composition rather than
+    /// size. Categories whose values are all zero stay empty. Pair with
+    /// [`value_axis_formatter`](Self::value_axis_formatter) to label the
+    /// value axis in percent.
+    pub fn normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Tick label formatter for the value axis
+    ///
+    /// Returns whole-number percent labels (`0.25` as `"25%"`) for normalized
+    /// stacks and `None` otherwise.
+    pub fn value_axis_formatter(&self) -> Option<TickLabelFormatter> {
+        self.normalize.then(|| TickLabelFormatter::percent(0))
+    }
 }
 
 impl Default for GroupedBarConfig {
@@ -234,6 +257,13 @@ pub fn compute_stacked_bars(
         return vec![];
     }
 
+    let normalized;
+    let values = if config.normalize {
+        normalized = normalize_stacked_values(values, categories);
+        &normalized
+    } else {
+        values
+    };
     let num_series = values.len();
     let bar_width = config.width;
     let half_width = bar_width / 2.0;
@@ -276,6 +306,32 @@ pub fn compute_stacked_bars(
     bars
 }
 
+/// Divide each category's values by the category's sum of absolute values
+///
+/// Categories with a zero total keep their (all zero) values.
+pub fn normalize_stacked_values(values: &[Vec<f64>], categories: usize) -> Vec<Vec<f64>> {
+    let totals: Vec<f64> = (0..categories)
+        .map(|cat_idx| {
+            values
+                .iter()
+                .filter_map(|series| series.get(cat_idx))
+                .map(|value| value.abs())
+                .sum()
+        })
+        .collect();
+
+    values
+        .iter()
+        .map(|series| {
+            series
+                .iter()
+                .zip(&totals)
+                .map(|(&value, &total)| if total > 0.0 { value / total } else { value })
+                .collect()
+        })
+        .collect()
+}
+
 /// Compute grouped bar rectangles
 ///
 /// # Arguments
@@ -459,7 +515,14 @@ impl PlotCompute for StackedBar {
         }
 
         let bars = compute_stacked_bars(input.values, input.num_categories, config);
-        let value_range = stacked_bar_range(input.values);
+        let value_range = if config.normalize {
+            stacked_bar_range(&normalize_stacked_values(
+                input.values,
+                input.num_categories,
+            ))
+        } else {
+            stacked_bar_range(input.values)
+        };
 
         Ok(StackedBarData {
             bars,
@@ -647,6 +710,31 @@ mod tests {
         assert!((bars[3].height - 5.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_normalized_stacked_bars_sum_to_one() {
+        let values = vec![vec![30.0, 2.0, 0.0], vec![10.0, 6.0, 0.0]];
+        let config = StackedBarConfig::default().normalize(true);
+        let bars = compute_stacked_bars(&values, 3, &config);
+
+        assert!((bars[0].height - 0.75).abs() < 1e-10);
+        assert!((bars[3].y - 0.75).abs() < 1e-10);
+        assert!((bars[3].height - 0.25).abs() < 1e-10);
+        assert!((bars[1].height - 0.25).abs() < 1e-10);
+        assert!((bars[4].y + bars[4].height - 1.0).abs() < 1e-10);
+        // All-zero categories stay empty instead of dividing by zero
+        assert_eq!((bars[2].height, bars[5].height), (0.0, 0.0));
+
+        let data = StackedBar::compute(BarInput::new(&values, 3), &config).unwrap();
+        assert!((data.value_range.1 - 1.0).abs() < 1e-10);
+
+        let formatter = config.value_axis_formatter().unwrap();
+        assert_eq!(
+            formatter.format_ticks(&[0.0, 0.25, 1.0]),
+            ["0%", "25%", "100%"]
+        );
+        assert!(StackedBarConfig::default().value_axis_formatter().is_none());
+    }
+
     #[test]
     fn test_grouped_bars() {
         let values = vec![vec![10.0, 20.0], vec![15.0, 25.0]];
@@ -14,11 +14,11 @@ pub mod swarm;
 pub use bar::{
     BarInput, BarOrientation, BarRect, GroupedBar, GroupedBarConfig, GroupedBarData, StackedBar,
     StackedBarConfig, StackedBarData, compute_grouped_bars, compute_stacked_bars,
-    grouped_bar_range, stacked_bar_range,
+    grouped_bar_range, normalize_stacked_values, stacked_bar_range,
 };
 pub use strip::{
-    Strip, StripConfig, StripData, StripInput, StripOrientation, StripPoint, compute_strip_points,
-    strip_range,
+    Strip, StripConfig, StripData, StripInput, StripOrientation, StripPoint, compute_strip_groups,
+    compute_strip_points, strip_range,
 };
 pub use swarm::{
     Swarm, SwarmConfig, SwarmData, SwarmInput, SwarmOrientation, SwarmPoint, compute_swarm_points,
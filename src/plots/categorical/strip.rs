@@ -132,7 +132,13 @@ struct SimpleRng {
 
 impl SimpleRng {
     fn new(seed: u64) -> Self {
-        Self { state: seed }
+        // xorshift never leaves the all-zero state
+        let state = if seed == 0 {
+            0x9E37_79B9_7F4A_7C15
+        } else {
+            seed
+        };
+        Self { state }
     }
 
     fn next_f64(&mut self) -> f64 {
@@ -207,6 +213,37 @@ pub fn compute_strip_points(
     points
 }
 
+/// Compute strip plot data for labeled groups of values
+///
+/// Group `i` becomes category `i`, named by its label. Non-finite values are
+/// skipped.
+pub fn compute_strip_groups<S: AsRef<str>>(
+    groups: &[(S, Vec<f64>)],
+    config: &StripConfig,
+) -> StripData {
+    let (categories, values): (Vec<usize>, Vec<f64>) = groups
+        .iter()
+        .enumerate()
+        .flat_map(|(index, (_, values))| {
+            values
+                .iter()
+                .copied()
+                .filter(|value| value.is_finite())
+                .map(move |value| (index, value))
+        })
+        .unzip();
+
+    StripData {
+        points: compute_strip_points(&categories, &values, None, config),
+        num_categories: groups.len(),
+        category_names: groups
+            .iter()
+            .map(|(name, _)| name.as_ref().to_string())
+            .collect(),
+        config: config.clone(),
+    }
+}
+
 /// Compute data range for strip plot
 pub fn strip_range(
     points: &[StripPoint],
@@ -242,6 +279,8 @@ pub struct StripData {
     pub points: Vec<StripPoint>,
     /// Number of categories
     pub num_categories: usize,
+    /// Category names shown on the categorical axis, by category index
+    pub category_names: Vec<String>,
     /// Configuration used to compute this data
     pub(crate) config: StripConfig,
 }
@@ -291,6 +330,7 @@ impl PlotCompute for Strip {
         Ok(StripData {
             points,
             num_categories,
+            category_names: Vec::new(),
             config: config.clone(),
         })
     }
@@ -377,6 +417,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_strip_groups_jitter_within_width_and_repeat_per_seed() {
+        let groups = vec![
+            ("a", vec![1.0, 2.0, f64::NAN, 3.0]),
+            ("b", (0..50).map(f64::from).collect()),
+        ];
+        let config = StripConfig::default().jitter(0.4);
+        let data = compute_strip_groups(&groups, &config);
+
+        assert_eq!(data.points.len(), 53);
+        assert_eq!(data.num_categories, 2);
+        assert_eq!(data.category_names, vec!["a", "b"]);
+        for point in &data.points {
+            assert!((point.x - point.category as f64).abs() <= 0.2);
+            assert_eq!(point.y, point.value);
+        }
+
+        let again = compute_strip_groups(&groups, &config);
+        let reseeded = compute_strip_groups(&groups, &config.clone().seed(7));
+        let xs = |data: &StripData| data.points.iter().map(|p| p.x).collect::<Vec<_>>();
+        assert_eq!(xs(&again), xs(&data));
+        assert_ne!(xs(&reseeded), xs(&data));
+
+        // A zero seed still jitters
+        let zero = compute_strip_groups(&groups, &config.seed(0));
+        let first = zero.points[0].x;
+        assert!(zero.points[..3].iter().any(|point| point.x != first));
+    }
+
     #[test]
     fn test_strip_range() {
         let categories = vec![0, 1, 2];
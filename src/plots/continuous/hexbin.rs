@@ -2,6 +2,9 @@
 //!
 //! Provides hexagonal binning for visualizing 2D point density.
 //!
+//! Binning happens in axis-normalized space so hexagons stay regular on
+//! screen regardless of the relative x/y data ranges.
+//!
 //! # Trait-Based API
 //!
 //! Hexbin plots implement the core plot traits:
@@ -14,7 +17,7 @@ use crate::core::Result;
 use crate::plots::traits::{PlotArea, PlotCompute, PlotConfig, PlotData, PlotRender};
 use crate::render::skia::SkiaRenderer;
 use crate::render::{Color, ColorMap, LineStyle, Theme};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Configuration for hexbin plot
 #[derive(Debug, Clone)]
@@ -37,6 +40,14 @@ pub struct HexbinConfig {
     pub alpha: f32,
     /// Logarithmic color scale
     pub log_scale: bool,
+    /// Show colorbar
+    pub colorbar: bool,
+    /// Label for the colorbar
+    pub colorbar_label: Option<String>,
+    /// Font size for colorbar tick labels (in points)
+    pub colorbar_tick_font_size: f32,
+    /// Font size for colorbar label (in points)
+    pub colorbar_label_font_size: f32,
 }
 
 /// Aggregation function for hexbin values
@@ -68,6 +79,10 @@ impl Default for HexbinConfig {
             edge_width: 0.0,
             alpha: 1.0,
             log_scale: false,
+            colorbar: false,
+            colorbar_label: None,
+            colorbar_tick_font_size: 10.0,
+            colorbar_label_font_size: 11.0,
         }
     }
 }
@@ -119,6 +134,18 @@ impl HexbinConfig {
         self.log_scale = log;
         self
     }
+
+    /// Enable or disable colorbar
+    pub fn colorbar(mut self, show: bool) -> Self {
+        self.colorbar = show;
+        self
+    }
+
+    /// Set the colorbar label
+    pub fn colorbar_label<S: Into<String>>(mut self, label: S) -> Self {
+        self.colorbar_label = Some(label.into());
+        self
+    }
 }
 
 // Implement PlotConfig marker trait
@@ -259,22 +286,36 @@ pub fn compute_hexbin(
     }
 
     let n = x.len().min(y.len());
+    let finite = |i: &usize| x[*i].is_finite() && y[*i].is_finite();
 
     // Find data bounds
-    let x_min = x.iter().copied().fold(f64::INFINITY, f64::min);
-    let x_max = x.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-    let y_min = y.iter().copied().fold(f64::INFINITY, f64::min);
-    let y_max = y.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-
-    // Calculate hex size based on gridsize
-    let x_range = x_max - x_min;
-    let hex_size = x_range / (config.gridsize as f64 * 1.5);
-
-    // Bin points
-    let mut bin_data: HashMap<(i64, i64), Vec<f64>> = HashMap::new();
-
-    for i in 0..n {
-        let (q, r) = hex_index(x[i] - x_min, y[i] - y_min, hex_size);
+    let x_min = (0..n)
+        .filter(finite)
+        .map(|i| x[i])
+        .fold(f64::INFINITY, f64::min);
+    let x_max = (0..n)
+        .filter(finite)
+        .map(|i| x[i])
+        .fold(f64::NEG_INFINITY, f64::max);
+    let y_min = (0..n)
+        .filter(finite)
+        .map(|i| y[i])
+        .fold(f64::INFINITY, f64::min);
+    let y_max = (0..n)
+        .filter(finite)
+        .map(|i| y[i])
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    // Bin in normalized [0, 1] space; degenerate axes get a unit span
+    let x_span = if x_max > x_min { x_max - x_min } else { 1.0 };
+    let y_span = if y_max > y_min { y_max - y_min } else { 1.0 };
+    let hex_size = 1.0 / (config.gridsize.max(1) as f64 * 1.5);
+
+    // Bin points (ordered map keeps output deterministic)
+    let mut bin_data: BTreeMap<(i64, i64), Vec<f64>> = BTreeMap::new();
+
+    for i in (0..n).filter(finite) {
+        let (q, r) = hex_index((x[i] - x_min) / x_span, (y[i] - y_min) / y_span, hex_size);
         let val = values.map_or(1.0, |v| v.get(i).copied().unwrap_or(1.0));
         bin_data.entry((q, r)).or_default().push(val);
     }
@@ -283,6 +324,10 @@ pub fn compute_hexbin(
     let mut bins = Vec::new();
     let mut min_value = f64::INFINITY;
     let mut max_value = f64::NEG_INFINITY;
+    let mut bounds = (
+        (f64::INFINITY, f64::NEG_INFINITY),
+        (f64::INFINITY, f64::NEG_INFINITY),
+    );
 
     for ((q, r), vals) in bin_data {
         let count = vals.len();
@@ -311,17 +356,34 @@ pub fn compute_hexbin(
         max_value = max_value.max(value);
 
         let (cx, cy) = hex_to_center(q, r, hex_size);
-        let vertices = HexBin::compute_vertices(cx + x_min, cy + y_min, hex_size);
+        let vertices = HexBin::compute_vertices(cx, cy, hex_size)
+            .map(|(vx, vy)| (x_min + vx * x_span, y_min + vy * y_span));
+        for &(vx, vy) in &vertices {
+            bounds.0.0 = bounds.0.0.min(vx);
+            bounds.0.1 = bounds.0.1.max(vx);
+            bounds.1.0 = bounds.1.0.min(vy);
+            bounds.1.1 = bounds.1.1.max(vy);
+        }
 
         bins.push(HexBin {
-            cx: cx + x_min,
-            cy: cy + y_min,
+            cx: x_min + cx * x_span,
+            cy: y_min + cy * y_span,
             value,
             count,
             vertices,
         });
     }
 
+    if bins.is_empty() {
+        return HexbinPlotData {
+            bins,
+            hex_size: hex_size * x_span,
+            value_range: (0.0, 1.0),
+            bounds: ((0.0, 1.0), (0.0, 1.0)),
+            config: config.clone(),
+        };
+    }
+
     // Apply maxcnt
     if let Some(max) = config.maxcnt {
         max_value = max_value.min(max as f64);
@@ -338,13 +400,38 @@ pub fn compute_hexbin(
 
     HexbinPlotData {
         bins,
-        hex_size,
+        hex_size: hex_size * x_span,
         value_range: (min_value, max_value),
-        bounds: ((x_min, x_max), (y_min, y_max)),
+        bounds,
         config: config.clone(),
     }
 }
 
+impl HexbinPlotData {
+    /// Colormap color for a bin, clamped to the data value range
+    pub fn bin_color(&self, bin: &HexBin) -> Color {
+        let (min_value, max_value) = self.value_range;
+        let value_range = max_value - min_value;
+        let t = if value_range > 0.0 {
+            ((bin.value - min_value) / value_range).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        let cmap = ColorMap::by_name(&self.config.cmap).unwrap_or_else(ColorMap::viridis);
+        cmap.sample(t).with_alpha(self.config.alpha)
+    }
+
+    /// Value range shown by the colorbar, in unscaled units
+    pub fn colorbar_range(&self) -> (f64, f64) {
+        let (min_value, max_value) = self.value_range;
+        if self.config.log_scale {
+            (min_value.exp(), max_value.exp())
+        } else {
+            (min_value, max_value)
+        }
+    }
+}
+
 /// Compute data range for hexbin plot
 pub fn hexbin_range(data: &HexbinPlotData) -> ((f64, f64), (f64, f64)) {
     data.bounds
@@ -380,31 +467,37 @@ impl PlotData for HexbinPlotData {
 
 impl PlotRender for HexbinPlotData {
     fn render(
+        &self,
+        renderer: &mut SkiaRenderer,
+        area: &PlotArea,
+        theme: &Theme,
+        color: Color,
+    ) -> Result<()> {
+        self.render_styled(renderer, area, theme, color, 1.0, None)
+    }
+
+    fn render_styled(
         &self,
         renderer: &mut SkiaRenderer,
         area: &PlotArea,
         _theme: &Theme,
         _color: Color,
+        alpha: f32,
+        line_width: Option<f32>,
     ) -> Result<()> {
         if self.bins.is_empty() {
             return Ok(());
         }
 
         let config = &self.config;
-        let (min_value, max_value) = self.value_range;
-        let value_range = max_value - min_value;
-
-        // Get colormap
-        let cmap = ColorMap::by_name(&config.cmap).unwrap_or_else(ColorMap::viridis);
+        let alpha = alpha.clamp(0.0, 1.0);
+        let edge_width = renderer
+            .render_scale()
+            .points_to_pixels(line_width.unwrap_or(config.edge_width));
 
         for bin in &self.bins {
-            // Calculate color based on value
-            let t = if value_range > 0.0 {
-                (bin.value - min_value) / value_range
-            } else {
-                0.5
-            };
-            let fill_color = cmap.sample(t).with_alpha(config.alpha);
+            let fill_color = self.bin_color(bin);
+            let fill_color = fill_color.with_alpha((f32::from(fill_color.a) / 255.0) * alpha);
 
             // Convert vertices to screen coordinates
             let screen_vertices: Vec<(f32, f32)> = bin
@@ -418,7 +511,7 @@ impl PlotRender for HexbinPlotData {
 
             // Draw edge if configured
             if let Some(edge_color) = config.edge_color {
-                if config.edge_width > 0.0 {
+                if edge_width > 0.0 {
                     // Draw hexagon outline
                     for i in 0..6 {
                         let (x1, y1) = screen_vertices[i];
@@ -429,7 +522,7 @@ impl PlotRender for HexbinPlotData {
                             x2,
                             y2,
                             edge_color,
-                            config.edge_width,
+                            edge_width,
                             LineStyle::Solid,
                         )?;
                     }
@@ -497,6 +590,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_hexbin_hexagons_stay_regular_for_unequal_ranges() {
+        // y range is 1000x smaller than x range; points on distinct rows must not merge
+        let x = vec![0.0, 0.0, 1000.0];
+        let y = vec![0.0, 1.0, 1.0];
+        let config = HexbinConfig::default().gridsize(10);
+        let data = compute_hexbin(&x, &y, None, &config);
+
+        assert_eq!(data.bins.len(), 3);
+        let ((x_lo, x_hi), (y_lo, y_hi)) = data.bounds;
+        assert!(x_lo < 0.0 && x_hi > 1000.0);
+        assert!(y_lo < 0.0 && y_hi > 1.0);
+    }
+
+    #[test]
+    fn test_hexbin_is_deterministic_and_skips_non_finite() {
+        let x: Vec<f64> = (0..200).map(|i| ((i * 37) % 101) as f64).collect();
+        let mut y: Vec<f64> = (0..200).map(|i| ((i * 53) % 89) as f64).collect();
+        y[5] = f64::NAN;
+        let config = HexbinConfig::default().gridsize(8);
+        let first = compute_hexbin(&x, &y, None, &config);
+        let second = compute_hexbin(&x, &y, None, &config);
+
+        let centers = |data: &HexbinPlotData| -> Vec<(f64, f64)> {
+            data.bins.iter().map(|bin| (bin.cx, bin.cy)).collect()
+        };
+        assert_eq!(centers(&first), centers(&second));
+        assert_eq!(first.bins.iter().map(|bin| bin.count).sum::<usize>(), 199);
+    }
+
+    #[test]
+    fn test_hexbin_colorbar_range_undoes_log_scale() {
+        let x = vec![0.0, 0.0, 0.0, 10.0];
+        let y = vec![0.0, 0.0, 0.0, 10.0];
+        let config = HexbinConfig::default().gridsize(5).log_scale(true);
+        let data = compute_hexbin(&x, &y, None, &config);
+        let (vmin, vmax) = data.colorbar_range();
+
+        assert!((vmin - 1.0).abs() < 1e-10);
+        assert!((vmax - 3.0).abs() < 1e-10);
+    }
+
     #[test]
     fn test_hexbin_empty() {
         let x: Vec<f64> = vec![];
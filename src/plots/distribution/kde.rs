@@ -25,6 +25,7 @@
 
 use crate::core::error::Result;
 use crate::core::style_utils::StyleResolver;
+use crate::plots::distribution::BandwidthMethod;
 use crate::plots::traits::{PlotArea, PlotCompute, PlotConfig, PlotData, PlotRender};
 use crate::render::{Color, LineStyle, SkiaRenderer, Theme};
 use crate::stats::kde::{kde_1d, kde_2d};
@@ -50,8 +51,10 @@ use crate::stats::kde::{kde_1d, kde_2d};
 /// ```
 #[derive(Debug, Clone)]
 pub struct KdeConfig {
-    /// Bandwidth method (None = Scott's rule)
+    /// Explicit bandwidth (None = chosen by `bandwidth_method`)
     pub bandwidth: Option<f64>,
+    /// Rule used to choose the bandwidth when none is set explicitly
+    pub bandwidth_method: BandwidthMethod,
     /// Number of points for density curve
     pub n_points: usize,
     /// Fill under the curve
@@ -76,6 +79,7 @@ impl Default for KdeConfig {
     fn default() -> Self {
         Self {
             bandwidth: None,
+            bandwidth_method: BandwidthMethod::Scott,
             n_points: 200,
             fill: true,
             fill_alpha: 0.3,
@@ -104,6 +108,12 @@ impl KdeConfig {
         self
     }
 
+    /// Set the bandwidth selection rule (Scott's rule by default)
+    pub fn bandwidth_method(mut self, method: BandwidthMethod) -> Self {
+        self.bandwidth_method = method;
+        self
+    }
+
     /// Set number of points
     pub fn n_points(mut self, n: usize) -> Self {
         self.n_points = n.max(10);
@@ -230,7 +240,10 @@ pub fn compute_kde(data: &[f64], config: &KdeConfig) -> KdeData {
         };
     }
 
-    let kde = kde_1d(data, config.bandwidth, Some(config.n_points));
+    let bandwidth = config
+        .bandwidth
+        .unwrap_or_else(|| config.bandwidth_method.bandwidth(data));
+    let kde = kde_1d(data, Some(bandwidth), Some(config.n_points));
 
     let (x, y) = if config.cumulative {
         // Convert to cumulative distribution
@@ -655,6 +668,31 @@ mod tests {
         assert!(empty_kde.is_empty());
     }
 
+    #[test]
+    fn test_kde_bandwidth_method_selects_rule_and_explicit_bandwidth_wins() {
+        let data = vec![0.0, 0.1, 0.2, 0.3, 5.0, 5.1, 5.2, 5.3, 20.0];
+
+        let scott = compute_kde(&data, &KdeConfig::default());
+        let silverman = compute_kde(
+            &data,
+            &KdeConfig::new().bandwidth_method(BandwidthMethod::Silverman),
+        );
+        let explicit = compute_kde(
+            &data,
+            &KdeConfig::new()
+                .bandwidth_method(BandwidthMethod::Silverman)
+                .bandwidth(0.25),
+        );
+
+        assert_eq!(scott.bandwidth, BandwidthMethod::Scott.bandwidth(&data));
+        assert_eq!(
+            silverman.bandwidth,
+            BandwidthMethod::Silverman.bandwidth(&data)
+        );
+        assert!(silverman.bandwidth < scott.bandwidth);
+        assert_eq!(explicit.bandwidth, 0.25);
+    }
+
     #[test]
     fn test_kde_config_implements_plot_config() {
         // Verify that KdeConfig implements PlotConfig (compile-time check)
@@ -23,7 +23,7 @@
 //! # Matplotlib/Seaborn Compatibility
 //!
 //! This implementation matches seaborn's `rugplot()` function:
-//! - Default height is 5% of axis range
+//! - Default height is 5% of the plot area
 //! - Lines are drawn perpendicular to the axis
 //! - Alpha defaults to 0.7 for visual density
 
@@ -46,7 +46,7 @@ pub enum RugAxis {
 /// Configuration for rug plots
 #[derive(Debug, Clone)]
 pub struct RugConfig {
-    /// Height of rug marks as fraction of axis range (default: 0.05)
+    /// Height of rug marks as fraction of the plot area (default: 0.05)
     pub height: f32,
     /// Which axis to draw on
     pub axis: RugAxis,
@@ -56,7 +56,7 @@ pub struct RugConfig {
     pub alpha: f32,
     /// Color for rug marks
     pub color: Option<Color>,
-    /// Offset from axis edge as fraction (default: 0.0)
+    /// Offset from axis edge as fraction of the plot area (default: 0.0)
     pub offset: f32,
 }
 
@@ -74,7 +74,7 @@ impl Default for RugConfig {
 }
 
 impl RugConfig {
-    /// Set the height of rug marks as fraction of axis range
+    /// Set the height of rug marks as fraction of the plot area
     pub fn height(mut self, height: f32) -> Self {
         self.height = height;
         self
@@ -16,7 +16,7 @@ use crate::core::style_utils::StyleResolver;
 use crate::plots::traits::{PlotArea, PlotCompute, PlotConfig, PlotData, PlotRender};
 use crate::render::skia::SkiaRenderer;
 use crate::render::{Color, LineStyle, Theme};
-use crate::stats::kde::{KdeResult, kde_1d};
+use crate::stats::kde::{KdeResult, kde_1d, scotts_rule, silvermans_rule};
 
 /// Configuration for violin plot
 #[derive(Debug, Clone)]
@@ -62,12 +62,23 @@ pub struct ViolinConfig {
 pub enum BandwidthMethod {
     /// Scott's rule (default)
     Scott,
-    /// Silverman's rule
+    /// Silverman's rule of thumb: `0.9 * min(std, IQR / 1.34) * n^(-1/5)`
     Silverman,
     /// Fixed bandwidth value
     Fixed(f64),
 }
 
+impl BandwidthMethod {
+    /// Kernel bandwidth this method selects for `data`
+    pub fn bandwidth(self, data: &[f64]) -> f64 {
+        match self {
+            BandwidthMethod::Scott => scotts_rule(data),
+            BandwidthMethod::Silverman => silvermans_rule(data),
+            BandwidthMethod::Fixed(bw) => bw,
+        }
+    }
+}
+
 /// Scaling method for violin width
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ViolinScale {
@@ -263,11 +274,8 @@ impl ViolinData {
         let max = sorted[n - 1];
 
         // Compute KDE
-        let bandwidth = match config.bandwidth {
-            BandwidthMethod::Fixed(bw) => Some(bw),
-            _ => None, // Use Scott's rule by default
-        };
-        let kde = kde_1d(&sorted, bandwidth, Some(config.n_points));
+        let bandwidth = config.bandwidth.bandwidth(&sorted);
+        let kde = kde_1d(&sorted, Some(bandwidth), Some(config.n_points));
 
         // Compute quartiles
         let q1 = percentile(&sorted, 25.0);
@@ -19,8 +19,24 @@
 //!     .colorbar_label_font_size(11.0);  // Axis label size
 //! ```
 //!
-//! When using `AxisScale::Log`, the effective `vmin`/`vmax` range must remain
-//! strictly positive.
+//! Wide heatmaps can move the colorbar below the plot and control its ticks
+//! with [`ColorbarConfig`]:
+//!
+//! ```rust,ignore
+//! use ruviz::prelude::{ColorbarOrientation, HeatmapConfig, TickLabelFormatter};
+//!
+//! let config = HeatmapConfig::default()
+//!     .colorbar_orientation(ColorbarOrientation::Horizontal)
+//!     .colorbar_ticks(4)
+//!     .colorbar_formatter(TickLabelFormatter::percent(0));
+//! ```
+//!
+//! `value_scale` drives the colormap lookup: `AxisScale::Log` spreads decades
+//! evenly across the colormap and labels the colorbar with the original decade
+//! values. When using `AxisScale::Log`, the effective `vmin`/`vmax` range must
+//! remain strictly positive; non-positive cells are masked like NaN. Data with
+//! zeros or negative values can use `AxisScale::SymLog { linthresh }`, which is
+//! linear inside `±linthresh` and logarithmic outside it.
 //!
 //! # Trait-Based API
 //!
@@ -31,6 +47,7 @@
 
 use crate::axes::AxisScale;
 use crate::core::Result as PlotResult;
+use crate::core::TickLabelFormatter;
 use crate::core::style_utils::StyleResolver;
 use crate::plots::traits::{PlotArea, PlotConfig, PlotData, PlotRender};
 use crate::render::skia::SkiaRenderer;
@@ -60,6 +77,54 @@ pub enum HeatmapOrigin {
     Lower,
 }
 
+/// Direction a colorbar runs in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorbarOrientation {
+    /// Vertical bar to the right of the plot, values increasing upward
+    #[default]
+    Vertical,
+    /// Horizontal bar below the plot, values increasing to the right
+    Horizontal,
+}
+
+/// Colorbar placement and tick options
+#[derive(Debug, Clone, Default)]
+pub struct ColorbarConfig {
+    /// Vertical bar on the right or horizontal bar below the plot
+    pub orientation: ColorbarOrientation,
+    /// Target number of major ticks (None = automatic)
+    ///
+    /// Logarithmic colorbars keep one major tick per decade.
+    pub n_ticks: Option<usize>,
+    /// Tick label formatter (None = scale-aware default formatting)
+    pub formatter: Option<TickLabelFormatter>,
+}
+
+impl ColorbarConfig {
+    /// Create a new ColorbarConfig with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the colorbar orientation
+    pub fn orientation(mut self, orientation: ColorbarOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the target number of major ticks
+    pub fn n_ticks(mut self, n_ticks: usize) -> Self {
+        self.n_ticks = Some(n_ticks.max(2));
+        self
+    }
+
+    /// Format tick labels with a [`TickLabelFormatter`] or `Fn(f64) -> String` closure
+    pub fn formatter(mut self, formatter: impl Into<TickLabelFormatter>) -> Self {
+        self.formatter = Some(formatter.into());
+        self
+    }
+}
+
 /// Configuration for heatmap rendering
 #[derive(Debug, Clone)]
 pub struct HeatmapConfig {
@@ -81,6 +146,8 @@ pub struct HeatmapConfig {
     pub colorbar_label_font_size: f32,
     /// Whether logarithmic colorbars draw minor subticks
     pub colorbar_log_subticks: bool,
+    /// Colorbar orientation and tick options
+    pub colorbar_config: ColorbarConfig,
     /// Custom labels for X axis ticks
     pub xticklabels: Option<Vec<String>>,
     /// Custom labels for Y axis ticks
@@ -91,18 +158,25 @@ pub struct HeatmapConfig {
     pub annotate: bool,
     /// Format string for annotations (e.g., "{:.2}")
     pub annotation_format: String,
-    /// Aspect ratio (None = auto, Some(1.0) = square cells)
+    /// Cell height-to-width ratio on screen (None = fill the plot area, Some(1.0) = square cells)
     pub aspect: Option<f64>,
     /// Alpha transparency for the heatmap (0.0 - 1.0)
     pub alpha: f32,
-    /// Whether heatmap cells should draw visible borders
+    /// Whether heatmap cells should draw visible borders, darker than each fill
     pub cell_borders: bool,
+    /// Stroke around each cell as (color, width in points), drawn after the
+    /// fill and any `cell_borders` outlines
+    pub cell_border: Option<(Color, f32)>,
     /// Whether SymLog heatmaps should derive linthresh from the smallest positive finite value
     pub symlog_auto_linthresh: bool,
     /// Physical extent for the heatmap grid as (xmin, xmax, ymin, ymax)
     pub extent: Option<(f64, f64, f64, f64)>,
     /// Physical Y edge adjacent to row 0
     pub origin: HeatmapOrigin,
+    /// Value mapped to the colormap midpoint (None = plain `vmin`..`vmax` mapping)
+    pub center: Option<f64>,
+    /// Color for masked cells such as NaN (None = leave them transparent)
+    pub bad_color: Option<Color>,
 }
 
 impl Default for HeatmapConfig {
@@ -117,6 +191,7 @@ impl Default for HeatmapConfig {
             colorbar_tick_font_size: 12.0, // Readable colorbar tick labels
             colorbar_label_font_size: 14.0, // Larger for visibility
             colorbar_log_subticks: true,
+            colorbar_config: ColorbarConfig::default(),
             xticklabels: None,
             yticklabels: None,
             interpolation: Interpolation::Nearest,
@@ -125,9 +200,12 @@ impl Default for HeatmapConfig {
             aspect: None,
             alpha: 1.0,
             cell_borders: false,
+            cell_border: None,
             symlog_auto_linthresh: false,
             extent: None,
             origin: HeatmapOrigin::Upper,
+            center: None,
+            bad_color: None,
         }
     }
 }
@@ -201,6 +279,33 @@ impl HeatmapConfig {
         self
     }
 
+    /// Set the colorbar orientation and tick options
+    pub fn colorbar_config(mut self, config: ColorbarConfig) -> Self {
+        self.colorbar_config = config;
+        self
+    }
+
+    /// Set the colorbar orientation.
+    ///
+    /// [`ColorbarOrientation::Horizontal`] draws the colorbar below the plot,
+    /// reserving bottom margin instead of right margin.
+    pub fn colorbar_orientation(mut self, orientation: ColorbarOrientation) -> Self {
+        self.colorbar_config.orientation = orientation;
+        self
+    }
+
+    /// Set the target number of colorbar major ticks
+    pub fn colorbar_ticks(mut self, n_ticks: usize) -> Self {
+        self.colorbar_config = self.colorbar_config.n_ticks(n_ticks);
+        self
+    }
+
+    /// Format colorbar tick labels, like `Plot::xtick_formatter` does for the axes
+    pub fn colorbar_formatter(mut self, formatter: impl Into<TickLabelFormatter>) -> Self {
+        self.colorbar_config = self.colorbar_config.formatter(formatter);
+        self
+    }
+
     /// Set custom X axis tick labels
     pub fn xticklabels(mut self, labels: Vec<String>) -> Self {
         self.xticklabels = Some(labels);
@@ -231,7 +336,11 @@ impl HeatmapConfig {
         self
     }
 
-    /// Set aspect ratio (1.0 = square cells)
+    /// Set the on-screen height-to-width ratio of every cell (1.0 = square cells)
+    ///
+    /// The plot area shrinks to the matching shape, centered in the space it
+    /// would otherwise fill. A plot-level [`AspectMode`](crate::core::AspectMode)
+    /// other than `Auto` takes precedence.
     pub fn aspect(mut self, ratio: f64) -> Self {
         self.aspect = Some(ratio);
         self
@@ -243,14 +352,40 @@ impl HeatmapConfig {
         self
     }
 
+    /// Draw square cells, as for correlation and confusion matrices.
+    ///
+    /// Shorthand for [`aspect(1.0)`](Self::aspect); `false` lets the cells
+    /// stretch to fill the plot area again.
+    pub fn square_cells(mut self, square: bool) -> Self {
+        self.aspect = square.then_some(1.0);
+        self
+    }
+
     /// Enable or disable visible cell borders.
     ///
-    /// Borders are disabled by default so heatmaps render as continuous tiles.
+    /// Each cell is outlined in a darker shade of its own fill color. Borders
+    /// are disabled by default so heatmaps render as continuous tiles. For
+    /// one uniform color and width use [`cell_border`](Self::cell_border),
+    /// which is drawn on top of these outlines when both are set.
     pub fn cell_borders(mut self, enabled: bool) -> Self {
         self.cell_borders = enabled;
         self
     }
 
+    /// Stroke every cell with `(color, width)`, the width in points.
+    ///
+    /// The strokes are drawn after all cells are filled, centered on the cell
+    /// edges, so thin white borders such as `Some((Color::WHITE, 1.0))` keep
+    /// similar neighbouring cells apart in small grids like correlation
+    /// matrices. `None` (the default) draws no stroke, and so does a
+    /// non-positive or non-finite width. This is independent of
+    /// [`cell_borders`](Self::cell_borders): when both are set, the darker
+    /// per-cell outlines are drawn first and this stroke covers them.
+    pub fn cell_border(mut self, border: Option<(Color, f32)>) -> Self {
+        self.cell_border = border.filter(|(_, width)| width.is_finite() && *width > 0.0);
+        self
+    }
+
     /// Derive `SymLog` linthresh from the smallest positive finite heatmap value.
     ///
     /// When enabled, the configured `AxisScale::SymLog { .. }` linthresh is
@@ -279,6 +414,26 @@ impl HeatmapConfig {
         self.origin = origin;
         self
     }
+
+    /// Anchor the colormap midpoint at `center`.
+    ///
+    /// Values below and above `center` are scaled independently onto the
+    /// lower and upper halves of the colormap (matplotlib's `TwoSlopeNorm`),
+    /// so a diverging colormap's neutral color marks `center` even for
+    /// asymmetric data. An automatic range that does not straddle `center`
+    /// is mirrored around it. Non-finite values are ignored.
+    pub fn center(mut self, center: f64) -> Self {
+        self.center = center.is_finite().then_some(center);
+        self
+    }
+
+    /// Set the color for masked cells (NaN, infinities, and non-positive values on a log scale).
+    ///
+    /// Masked cells are left transparent by default.
+    pub fn bad_color(mut self, color: Color) -> Self {
+        self.bad_color = Some(color);
+        self
+    }
 }
 
 // Implement PlotConfig marker trait
@@ -314,6 +469,15 @@ impl HeatmapData {
         matches!(self.config.interpolation, Interpolation::Nearest) && alpha >= 1.0
     }
 
+    /// Y data units per X data unit that give cells the configured aspect
+    pub(crate) fn y_units_per_x_unit(&self) -> Option<f64> {
+        let aspect = self
+            .config
+            .aspect
+            .filter(|aspect| aspect.is_finite() && *aspect > 0.0)?;
+        Some(aspect * self.x_step().abs() / self.y_step().abs())
+    }
+
     fn x_step(&self) -> f64 {
         (self.x_extent.1 - self.x_extent.0) / self.n_cols.max(1) as f64
     }
@@ -388,11 +552,22 @@ impl HeatmapData {
     }
 
     fn normalized_value(&self, value: f64) -> f64 {
-        self.config
-            .value_scale
-            .normalized_position(value, self.vmin, self.vmax)
+        match self.config.center {
+            Some(center) => crate::axes::scale::centered_normalized_position(
+                &self.config.value_scale,
+                value,
+                self.vmin,
+                self.vmax,
+                center,
+            ),
+            None => self
+                .config
+                .value_scale
+                .normalized_position(value, self.vmin, self.vmax),
+        }
     }
 
+    /// Whether a cell is masked: non-finite values, and non-positive values on a log scale
     pub fn should_mask_value(&self, value: f64) -> bool {
         if !value.is_finite() {
             return true;
@@ -402,11 +577,29 @@ impl HeatmapData {
     }
 
     /// Get color for a specific cell value
+    ///
+    /// Masked values map to `config.bad_color`, or [`Color::TRANSPARENT`] when unset.
     pub fn get_color(&self, value: f64) -> Color {
+        if self.should_mask_value(value) {
+            return self.config.bad_color.unwrap_or(Color::TRANSPARENT);
+        }
+
         let normalized = self.normalized_value(value).clamp(0.0, 1.0);
         self.config.colormap.sample(normalized)
     }
 
+    /// Get the fill for a cell at `alpha`, or `None` when the cell should not be drawn
+    pub(crate) fn cell_color(&self, value: f64, alpha: f32) -> Option<Color> {
+        if self.should_mask_value(value) {
+            return self
+                .config
+                .bad_color
+                .map(|color| color.with_alpha(color.a as f32 / 255.0 * alpha));
+        }
+
+        Some(self.get_color(value).with_alpha(alpha))
+    }
+
     /// Get a contrasting text color for annotations
     pub fn get_text_color(&self, background: Color) -> Color {
         // Calculate relative luminance
@@ -465,10 +658,9 @@ impl HeatmapData {
             }
 
             for col in 0..self.n_cols {
-                let value = self.values[row][col];
-                if self.should_mask_value(value) {
+                let Some(cell_color) = self.cell_color(self.values[row][col], alpha) else {
                     continue;
-                }
+                };
 
                 let left = x_edges[col].min(x_edges[col + 1]);
                 let right = x_edges[col].max(x_edges[col + 1]);
@@ -476,7 +668,6 @@ impl HeatmapData {
                     continue;
                 }
 
-                let cell_color = self.get_color(value).with_alpha(alpha);
                 let x = left as f32;
                 let y = top as f32;
                 let width = (right - left) as f32;
@@ -507,12 +698,9 @@ impl HeatmapData {
     ) -> PlotResult<()> {
         for row in 0..self.n_rows {
             for col in 0..self.n_cols {
-                let value = self.values[row][col];
-                if self.should_mask_value(value) {
+                let Some(cell_color) = self.cell_color(self.values[row][col], alpha) else {
                     continue;
-                }
-
-                let cell_color = self.get_color(value).with_alpha(alpha);
+                };
 
                 let (x, y, width, height) = self.cell_screen_rect(area, row, col);
                 let left = x.max(area.x);
@@ -543,17 +731,60 @@ impl HeatmapData {
         Ok(())
     }
 
+    /// Screen rectangles of the drawn cells, as `(x, y, width, height)`
+    ///
+    /// With `pixel_aligned` the rectangles follow the snapped edges used by
+    /// the pixel-aligned grid fill so strokes land on the shared seams.
+    pub(crate) fn cell_border_rects(
+        &self,
+        area: &PlotArea,
+        pixel_aligned: bool,
+    ) -> Vec<(f32, f32, f32, f32)> {
+        let edges = pixel_aligned.then(|| self.pixel_aligned_screen_edges(area));
+        let mut rects = Vec::with_capacity(self.n_rows * self.n_cols);
+        for row in 0..self.n_rows {
+            for col in 0..self.n_cols {
+                if self.cell_color(self.values[row][col], 1.0).is_none() {
+                    continue;
+                }
+                rects.push(match &edges {
+                    Some((x_edges, y_edges)) => {
+                        let left = x_edges[col].min(x_edges[col + 1]) as f32;
+                        let right = x_edges[col].max(x_edges[col + 1]) as f32;
+                        let top = y_edges[row].min(y_edges[row + 1]) as f32;
+                        let bottom = y_edges[row].max(y_edges[row + 1]) as f32;
+                        (left, top, right - left, bottom - top)
+                    }
+                    None => self.cell_screen_rect(area, row, col),
+                });
+            }
+        }
+        rects
+    }
+
     pub(crate) fn draw_cells_batch(
         &self,
         renderer: &mut SkiaRenderer,
         area: &PlotArea,
         alpha: f32,
     ) -> PlotResult<()> {
-        if self.can_use_pixel_aligned_grid_fast_path(alpha) {
-            return self.draw_cells_pixel_aligned_grid(renderer, area, alpha);
+        let pixel_aligned = self.can_use_pixel_aligned_grid_fast_path(alpha);
+        if pixel_aligned {
+            self.draw_cells_pixel_aligned_grid(renderer, area, alpha)?;
+        } else {
+            self.draw_cells_legacy(renderer, area, alpha)?;
         }
 
-        self.draw_cells_legacy(renderer, area, alpha)
+        if let Some((color, width)) = self.config.cell_border {
+            let width = renderer.render_scale().points_to_pixels(width);
+            renderer.draw_rectangle_outlines_clipped(
+                &self.cell_border_rects(area, pixel_aligned),
+                color,
+                width,
+                (area.x, area.y, area.width, area.height),
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -651,10 +882,20 @@ pub fn process_heatmap(data: &[Vec<f64>], config: HeatmapConfig) -> Result<Heatm
             };
             (vmin, vmax)
         }
-        _ => (
-            config.vmin.unwrap_or(data_min),
-            config.vmax.unwrap_or(data_max),
-        ),
+        _ => {
+            let mut vmin = config.vmin.unwrap_or(data_min);
+            let mut vmax = config.vmax.unwrap_or(data_max);
+            // Mirror a one-sided range around the center so both halves exist.
+            if let Some(center) = config.center {
+                if vmin >= center {
+                    vmin = 2.0 * center - vmax;
+                }
+                if vmax <= center {
+                    vmax = 2.0 * center - vmin;
+                }
+            }
+            (vmin, vmax)
+        }
     };
     config.value_scale.validate_range(vmin, vmax)?;
 
@@ -855,6 +1096,7 @@ mod tests {
         assert_eq!(config.value_scale, AxisScale::Linear);
         assert!(config.colorbar_log_subticks);
         assert!(!config.cell_borders);
+        assert!(config.cell_border.is_none());
         assert!(!config.symlog_auto_linthresh);
         assert!(config.extent.is_none());
         assert_eq!(config.origin, HeatmapOrigin::Upper);
@@ -937,9 +1179,11 @@ mod tests {
             .colorbar_label("Temperature")
             .colorbar_log_subticks(false)
             .cell_borders(true)
+            .cell_border(Some((Color::WHITE, 0.5)))
             .symlog_auto_linthresh(true)
             .extent(0.0, 3.0, 0.0, 2.0)
             .origin(HeatmapOrigin::Lower)
+            .square_cells(true)
             .annotate(true);
 
         assert_eq!(config.vmin, Some(0.0));
@@ -949,10 +1193,19 @@ mod tests {
         assert_eq!(config.colorbar_label, Some("Temperature".to_string()));
         assert!(!config.colorbar_log_subticks);
         assert!(config.cell_borders);
+        assert_eq!(config.cell_border, Some((Color::WHITE, 0.5)));
+        assert!(
+            HeatmapConfig::new()
+                .cell_border(Some((Color::WHITE, 0.0)))
+                .cell_border
+                .is_none()
+        );
         assert!(config.symlog_auto_linthresh);
         assert_eq!(config.extent, Some((0.0, 3.0, 0.0, 2.0)));
         assert_eq!(config.origin, HeatmapOrigin::Lower);
+        assert_eq!(config.aspect, Some(1.0));
         assert!(config.annotate);
+        assert_eq!(config.square_cells(false).aspect, None);
     }
 
     #[test]
@@ -1107,6 +1360,83 @@ mod tests {
         assert_eq!(log_mid, expected_mid);
     }
 
+    #[test]
+    fn test_heatmap_get_color_uses_symlog_value_scale_for_zero_and_negatives() {
+        let data = vec![vec![-100.0, -1.0, 0.0, 1.0, 100.0]];
+        let config = HeatmapConfig::new().value_scale(AxisScale::SymLog { linthresh: 1.0 });
+        let heatmap = process_heatmap(&data, config).unwrap();
+        let cmap = &heatmap.config.colormap;
+
+        assert!(
+            data[0]
+                .iter()
+                .all(|&value| !heatmap.should_mask_value(value))
+        );
+        assert_eq!(heatmap.get_color(-100.0), cmap.sample(0.0));
+        assert_eq!(heatmap.get_color(-1.0), cmap.sample(1.0 / 3.0));
+        assert_eq!(heatmap.get_color(0.0), cmap.sample(0.5));
+        assert_eq!(heatmap.get_color(1.0), cmap.sample(2.0 / 3.0));
+        assert_eq!(heatmap.get_color(100.0), cmap.sample(1.0));
+    }
+
+    #[test]
+    fn test_heatmap_center_maps_midpoint_and_scales_halves_independently() {
+        let data = vec![vec![-1.0, 0.0, 4.0]];
+        let config = HeatmapConfig::new()
+            .colormap(ColorMap::coolwarm())
+            .center(0.0);
+        let heatmap = process_heatmap(&data, config).unwrap();
+        let cmap = &heatmap.config.colormap;
+
+        assert_eq!(heatmap.get_color(0.0), cmap.sample(0.5));
+        assert_eq!(heatmap.get_color(-1.0), cmap.sample(0.0));
+        assert_eq!(heatmap.get_color(-0.5), cmap.sample(0.25));
+        assert_eq!(heatmap.get_color(2.0), cmap.sample(0.75));
+        assert_eq!(heatmap.get_color(4.0), cmap.sample(1.0));
+    }
+
+    #[test]
+    fn test_heatmap_center_mirrors_one_sided_auto_range() {
+        let data = vec![vec![1.0, 3.0]];
+        let heatmap = process_heatmap(&data, HeatmapConfig::new().center(0.0)).unwrap();
+
+        assert_eq!((heatmap.vmin, heatmap.vmax), (-3.0, 3.0));
+        assert_eq!(heatmap.get_color(0.0), heatmap.config.colormap.sample(0.5));
+    }
+
+    #[test]
+    fn test_nan_cells_are_masked_or_drawn_in_bad_color() {
+        let values = vec![vec![f64::NAN, 1.0], vec![2.0, 3.0]];
+        let area = PlotArea::new(10.0, 10.0, 100.0, 100.0, 0.0, 2.0, 0.0, 2.0);
+        let pixel_at = |image: &Image, x: usize, y: usize| {
+            let offset = (y * image.width as usize + x) * 4;
+            image.pixels[offset..offset + 4].to_vec()
+        };
+
+        let masked = process_heatmap(&values, HeatmapConfig::new().colorbar(false)).unwrap();
+        assert_eq!((masked.data_min, masked.data_max), (1.0, 3.0));
+        assert_eq!(masked.get_color(f64::NAN), Color::TRANSPARENT);
+        assert_eq!(masked.cell_color(f64::NAN, 1.0), None);
+
+        let bad = Color::new(255, 0, 255);
+        let filled =
+            process_heatmap(&values, HeatmapConfig::new().colorbar(false).bad_color(bad)).unwrap();
+        assert_eq!(filled.get_color(f64::NAN), bad);
+        assert_eq!(filled.cell_color(f64::NAN, 0.5), Some(bad.with_alpha(0.5)));
+
+        for use_legacy in [true, false] {
+            let background = render_heatmap_cells(&masked, &area, use_legacy).unwrap();
+            let painted = render_heatmap_cells(&filled, &area, use_legacy).unwrap();
+            let blank = SkiaRenderer::new(120, 120, Theme::default())
+                .unwrap()
+                .into_image();
+
+            assert_eq!(pixel_at(&background, 30, 30), pixel_at(&blank, 30, 30));
+            assert_eq!(pixel_at(&painted, 30, 30), vec![255, 0, 255, 255]);
+            assert_eq!(pixel_at(&background, 80, 30), pixel_at(&painted, 80, 30));
+        }
+    }
+
     #[test]
     fn test_process_heatmap_log_scale_ignores_nonpositive_cells_for_auto_range() {
         let data = vec![vec![0.0, 1.0], vec![10.0, 100.0]];
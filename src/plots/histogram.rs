@@ -26,21 +26,43 @@ pub struct HistogramConfig {
     pub edge_width: Option<f32>,
     /// Bar width as fraction of bin width (0.0-1.0, default 0.9)
     pub bar_width: Option<f32>,
+    /// Explicit bin edges, used verbatim in place of `bins`, `range`, and `bin_method`
+    pub bin_edges: Option<Vec<f64>>,
+    /// How values outside explicit `bin_edges` are handled
+    pub bin_overflow: BinOverflow,
+    /// Space bin edges geometrically (equal width in log space) for log x axes
+    pub log_bins: bool,
 }
 
-/// Methods for calculating histogram bin edges
+/// Methods for choosing the histogram bin count when `bins` is not set
+///
+/// Like numpy's `histogram_bin_edges`, the rules look only at values inside the
+/// binned range (the configured `range`, or the data extent), and width-based
+/// rules use `ceil(range / width)` equal-width bins, falling back to a single
+/// bin when the estimated width is zero.
 #[derive(Debug, Clone, Copy)]
 pub enum BinMethod {
-    /// Equal-width bins (default)
+    /// Ten equal-width bins
     Uniform,
-    /// Sturges' rule: ceil(log2(n) + 1)
+    /// Sturges' rule: ceil(log2(n) + 1) (default)
     Sturges,
-    /// Scott's rule: 3.5 * std / n^(1/3)
+    /// Scott's rule: width = 3.5 * std / n^(1/3)
     Scott,
-    /// Freedman-Diaconis rule: 2 * IQR / n^(1/3)
+    /// Freedman-Diaconis rule: width = 2 * IQR / n^(1/3), numpy's `bins='fd'`
     FreedmanDiaconis,
 }
 
+/// Handling of values that fall outside explicit histogram bin edges
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BinOverflow {
+    /// Skip values below the first edge or above the last edge (default)
+    #[default]
+    Drop,
+    /// Count values below the first edge in the first bin and values above
+    /// the last edge in the last bin
+    Clamp,
+}
+
 /// Computed histogram data
 #[derive(Debug, Clone)]
 pub struct HistogramData {
@@ -187,6 +209,9 @@ impl Default for HistogramConfig {
             edge_color: None,
             edge_width: None,
             bar_width: None,
+            bin_edges: None,
+            bin_overflow: BinOverflow::Drop,
+            log_bins: false,
         }
     }
 }
@@ -199,6 +224,7 @@ impl HistogramConfig {
         Self::default()
     }
 
+    /// Set a fixed number of equal-width bins, overriding `bin_method`
     pub fn bins(mut self, bins: usize) -> Self {
         self.bins = Some(bins);
         self
@@ -219,6 +245,7 @@ impl HistogramConfig {
         self
     }
 
+    /// Set the rule that picks the bin count when `bins` is not set
     pub fn bin_method(mut self, method: BinMethod) -> Self {
         self.bin_method = method;
         self
@@ -247,6 +274,34 @@ impl HistogramConfig {
         self.bar_width = Some(width.clamp(0.0, 1.0));
         self
     }
+
+    /// Set explicit bin edges, e.g. log-spaced or matched to a reference dataset.
+    ///
+    /// Edges must be finite and strictly increasing, with at least two entries.
+    /// Each bin is half-open `[left, right)` except the last, which also includes
+    /// its right edge. `bins`, `range`, and `bin_method` are ignored when set.
+    pub fn bin_edges(mut self, edges: Vec<f64>) -> Self {
+        self.bin_edges = Some(edges);
+        self
+    }
+
+    /// Set how values outside explicit bin edges are handled
+    pub fn bin_overflow(mut self, overflow: BinOverflow) -> Self {
+        self.bin_overflow = overflow;
+        self
+    }
+
+    /// Use geometrically spaced bins, equal width in log space, to pair with a
+    /// log-scaled x axis.
+    ///
+    /// Edges run from the smallest to the largest positive value (or across
+    /// `range`, which must then be positive); non-positive values are dropped.
+    /// `bins` and `bin_method` pick the bin count from the log-transformed
+    /// values. Explicit `bin_edges` take precedence.
+    pub fn log_bins(mut self, log_bins: bool) -> Self {
+        self.log_bins = log_bins;
+        self
+    }
 }
 
 /// Calculate histogram from data
@@ -261,6 +316,116 @@ where
     let values = crate::data::collect_finite_values_sorted(data)?;
     let n_samples = values.len();
 
+    let (bin_edges, mut counts) = match &config.bin_edges {
+        Some(edges) => explicit_bin_counts(&values, edges, config.bin_overflow)?,
+        None if config.log_bins => log_bin_counts(&values, config)?,
+        None => uniform_bin_counts(&values, config)?,
+    };
+
+    // Apply cumulative if requested
+    if config.cumulative {
+        for i in 1..counts.len() {
+            counts[i] += counts[i - 1];
+        }
+    }
+
+    // Apply density normalization if requested
+    let is_density = config.density;
+    if config.density {
+        let total = counts.iter().sum::<f64>();
+        if total > 0.0 {
+            for (count, edges) in counts.iter_mut().zip(bin_edges.windows(2)) {
+                *count /= total * (edges[1] - edges[0]);
+            }
+        }
+    }
+
+    // Extract styling from config, using defaults for None values
+    let fill_alpha = config.fill_alpha.unwrap_or(defaults::HISTOGRAM_FILL_ALPHA);
+    let edge_color = config.edge_color;
+    let edge_width = config.edge_width.unwrap_or(defaults::PATCH_LINE_WIDTH);
+    let bar_width = config.bar_width.unwrap_or(defaults::HISTOGRAM_BAR_WIDTH);
+
+    Ok(HistogramData {
+        bin_edges,
+        counts,
+        n_samples,
+        is_density,
+        fill_alpha,
+        edge_color,
+        edge_width,
+        bar_width,
+    })
+}
+
+/// Point counts on a regular 2D grid of bins
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram2D {
+    /// Counts indexed as `counts[row][col]`, with row 0 holding the lowest `y` bin
+    pub counts: Vec<Vec<f64>>,
+    /// Outer X edges of the grid as (min, max)
+    pub x_range: (f64, f64),
+    /// Outer Y edges of the grid as (min, max)
+    pub y_range: (f64, f64),
+}
+
+/// Count `(x, y)` points in `nx` by `ny` equal-size bins spanning the data
+///
+/// Pairs with a non-finite value are skipped. Like the 1D histogram, each bin
+/// is half-open except the last in each direction, which also includes the
+/// data maximum, and a constant coordinate is widened so its bins have a width.
+pub fn calculate_histogram_2d(x: &[f64], y: &[f64], bins: (usize, usize)) -> Result<Histogram2D> {
+    let (nx, ny) = bins;
+    if nx == 0 || ny == 0 {
+        return Err(PlottingError::InvalidInput(
+            "Number of bins must be greater than 0".to_string(),
+        ));
+    }
+    let points: Vec<(f64, f64)> = x
+        .iter()
+        .zip(y)
+        .map(|(&x, &y)| (x, y))
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .collect();
+    if points.is_empty() {
+        return Err(PlottingError::EmptyDataSet);
+    }
+
+    let span = |values: &mut dyn Iterator<Item = f64>| {
+        let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(v), max.max(v))
+        });
+        if (max - min).abs() < f64::EPSILON {
+            let epsilon = if min.abs() > f64::EPSILON {
+                min.abs() * 0.1
+            } else {
+                1.0
+            };
+            (min - epsilon, max + epsilon)
+        } else {
+            (min, max)
+        }
+    };
+    let x_range = span(&mut points.iter().map(|(x, _)| *x));
+    let y_range = span(&mut points.iter().map(|(_, y)| *y));
+    let bin_index = |value: f64, (min, max): (f64, f64), n: usize| {
+        (((value - min) / (max - min) * n as f64).floor() as usize).min(n - 1)
+    };
+
+    let mut counts = vec![vec![0.0; nx]; ny];
+    for (x, y) in points {
+        counts[bin_index(y, y_range, ny)][bin_index(x, x_range, nx)] += 1.0;
+    }
+
+    Ok(Histogram2D {
+        counts,
+        x_range,
+        y_range,
+    })
+}
+
+/// Bin edges and counts for `n_bins` equal-width bins over the data or configured range
+fn uniform_bin_counts(values: &[f64], config: &HistogramConfig) -> Result<(Vec<f64>, Vec<f64>)> {
     // Determine range
     let (mut data_min, mut data_max) = match config.range {
         Some((min, max)) => (min, max),
@@ -295,7 +460,7 @@ where
             }
             bins
         }
-        None => calculate_optimal_bins(&values, config.bin_method),
+        None => calculate_optimal_bins(values, config.bin_method, data_min, data_max),
     };
 
     // Create bin edges
@@ -303,7 +468,7 @@ where
 
     // Count values in each bin
     let mut counts = vec![0.0; n_bins];
-    for &value in &values {
+    for &value in values {
         if value < data_min || value > data_max {
             continue; // Skip out-of-range values
         }
@@ -319,63 +484,127 @@ where
         }
     }
 
-    // Apply cumulative if requested
-    if config.cumulative {
-        for i in 1..counts.len() {
-            counts[i] += counts[i - 1];
+    Ok((bin_edges, counts))
+}
+
+/// Bin edges and counts for bins of equal width in log space over the positive
+/// data or configured range
+fn log_bin_counts(values: &[f64], config: &HistogramConfig) -> Result<(Vec<f64>, Vec<f64>)> {
+    let positive = &values[values.partition_point(|&value| value <= 0.0)..];
+    let (mut data_min, mut data_max) = match config.range {
+        Some((min, max)) if min <= 0.0 => {
+            return Err(PlottingError::InvalidInput(format!(
+                "Log histogram range must be positive, got min {min} and max {max}"
+            )));
         }
+        Some(range) => range,
+        None => match (positive.first(), positive.last()) {
+            (Some(&min), Some(&max)) => (min, max),
+            _ => {
+                return Err(PlottingError::InvalidInput(
+                    "Log histogram bins need at least one positive value".to_string(),
+                ));
+            }
+        },
+    };
+
+    // Widen a single repeated value by 10% either way, as for linear bins
+    if data_max == data_min {
+        data_min /= 1.1;
+        data_max *= 1.1;
+    }
+    if data_max <= data_min {
+        return Err(PlottingError::InvalidInput(
+            "Histogram range max must be greater than min".to_string(),
+        ));
     }
 
-    // Apply density normalization if requested
-    let is_density = config.density;
-    if config.density {
-        let bin_width = (data_max - data_min) / n_bins as f64;
-        let total_area = counts.iter().sum::<f64>() * bin_width;
-        if total_area > 0.0 {
-            for count in &mut counts {
-                *count /= total_area;
-            }
+    let (log_min, log_max) = (data_min.log10(), data_max.log10());
+    let n_bins = match config.bins {
+        Some(0) => {
+            return Err(PlottingError::InvalidInput(
+                "Number of bins must be greater than 0".to_string(),
+            ));
+        }
+        Some(bins) => bins,
+        None => {
+            let log_values: Vec<f64> = positive.iter().map(|value| value.log10()).collect();
+            calculate_optimal_bins(&log_values, config.bin_method, log_min, log_max)
         }
+    };
+
+    let mut edges: Vec<f64> = create_bin_edges(log_min, log_max, n_bins)
+        .into_iter()
+        .map(|edge| 10f64.powf(edge))
+        .collect();
+    // Pin the outer edges so rounding cannot drop the extreme values
+    edges[0] = data_min;
+    edges[n_bins] = data_max;
+
+    explicit_bin_counts(positive, &edges, BinOverflow::Drop)
+}
+
+/// Counts for caller-supplied bin edges
+fn explicit_bin_counts(
+    values: &[f64],
+    edges: &[f64],
+    overflow: BinOverflow,
+) -> Result<(Vec<f64>, Vec<f64>)> {
+    if edges.len() < 2 {
+        return Err(PlottingError::InvalidInput(
+            "Histogram bin edges need at least two values".to_string(),
+        ));
+    }
+    if edges.iter().any(|edge| !edge.is_finite()) || edges.windows(2).any(|w| w[1] <= w[0]) {
+        return Err(PlottingError::InvalidInput(
+            "Histogram bin edges must be finite and strictly increasing".to_string(),
+        ));
     }
 
-    // Extract styling from config, using defaults for None values
-    let fill_alpha = config.fill_alpha.unwrap_or(defaults::HISTOGRAM_FILL_ALPHA);
-    let edge_color = config.edge_color;
-    let edge_width = config.edge_width.unwrap_or(defaults::PATCH_LINE_WIDTH);
-    let bar_width = config.bar_width.unwrap_or(defaults::HISTOGRAM_BAR_WIDTH);
+    let n_bins = edges.len() - 1;
+    let (first, last) = (edges[0], edges[n_bins]);
+    let mut counts = vec![0.0; n_bins];
+    for &value in values {
+        let bin_idx = if value < first || value > last {
+            match overflow {
+                BinOverflow::Drop => continue,
+                BinOverflow::Clamp if value < first => 0,
+                BinOverflow::Clamp => n_bins - 1,
+            }
+        } else {
+            // Edges at or below `value`, so the last edge falls in the last bin
+            (edges.partition_point(|&edge| edge <= value) - 1).min(n_bins - 1)
+        };
+        counts[bin_idx] += 1.0;
+    }
 
-    Ok(HistogramData {
-        bin_edges,
-        counts,
-        n_samples,
-        is_density,
-        fill_alpha,
-        edge_color,
-        edge_width,
-        bar_width,
-    })
+    Ok((edges.to_vec(), counts))
 }
 
-fn calculate_optimal_bins(values: &[f64], method: BinMethod) -> usize {
+fn calculate_optimal_bins(values: &[f64], method: BinMethod, min: f64, max: f64) -> usize {
+    // `values` is sorted, so the in-range values are one contiguous run
+    let start = values.partition_point(|&value| value < min);
+    let end = values.partition_point(|&value| value <= max);
+    let values = &values[start..end];
+    if values.is_empty() {
+        return 1;
+    }
+
     let n = values.len() as f64;
+    let bins_for_width = |bin_width: f64| {
+        if bin_width > 0.0 && bin_width.is_finite() {
+            ((max - min) / bin_width).ceil().max(1.0) as usize
+        } else {
+            1 // All values are the same
+        }
+    };
 
     match method {
         BinMethod::Uniform => 10, // Default fallback
         BinMethod::Sturges => (n.log2() + 1.0).ceil() as usize,
-        BinMethod::Scott => {
-            let std_dev = calculate_std_dev(values);
-            let bin_width = 3.5 * std_dev / n.powf(1.0 / 3.0);
-            let range = values.last().unwrap() - values.first().unwrap();
-            (range / bin_width).ceil().max(1.0) as usize
-        }
+        BinMethod::Scott => bins_for_width(3.5 * calculate_std_dev(values) / n.powf(1.0 / 3.0)),
         BinMethod::FreedmanDiaconis => {
-            let iqr = calculate_iqr(values);
-            if iqr == 0.0 {
-                return 1; // All values are the same
-            }
-            let bin_width = 2.0 * iqr / n.powf(1.0 / 3.0);
-            let range = values.last().unwrap() - values.first().unwrap();
-            (range / bin_width).ceil().max(1.0) as usize
+            bins_for_width(2.0 * calculate_iqr(values) / n.powf(1.0 / 3.0))
         }
     }
 }
@@ -520,6 +749,132 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_histogram_freedman_diaconis_matches_numpy_fd() {
+        let data: Vec<f64> = (0..100).map(f64::from).collect();
+        let config = HistogramConfig::new().bin_method(BinMethod::FreedmanDiaconis);
+
+        // numpy.histogram_bin_edges(numpy.arange(100), bins='fd') has 5 bins
+        let result = calculate_histogram(&data, &config).unwrap();
+        assert_eq!(result.counts.len(), 5);
+
+        // With a range, only the values inside it drive the estimate:
+        // IQR 24.5 over 50 values gives width ~13.3 across a span of 49
+        let ranged = calculate_histogram(&data, &config.range(0.0, 49.0)).unwrap();
+        assert_eq!(ranged.counts.len(), 4);
+        assert_eq!(ranged.counts.iter().sum::<f64>(), 50.0);
+    }
+
+    #[test]
+    fn test_histogram_width_rules_fall_back_to_one_bin_for_constant_data() {
+        let data = vec![3.0; 20];
+        for method in [BinMethod::Scott, BinMethod::FreedmanDiaconis] {
+            let config = HistogramConfig::new().bin_method(method);
+            let result = calculate_histogram(&data, &config).unwrap();
+            assert_eq!(result.counts, vec![20.0]);
+        }
+    }
+
+    #[test]
+    fn test_histogram_explicit_log_spaced_bin_edges() {
+        let data = vec![0.5, 1.0, 5.0, 9.9, 10.0, 50.0, 100.0, 250.0];
+        let edges = vec![1.0, 10.0, 100.0];
+        let config = HistogramConfig::new().bins(50).bin_edges(edges.clone());
+
+        let result = calculate_histogram(&data, &config).unwrap();
+
+        assert_eq!(result.bin_edges, edges);
+        // [1, 10) and [10, 100]: 0.5 and 250.0 are dropped
+        assert_eq!(result.counts, vec![3.0, 3.0]);
+
+        let clamped = calculate_histogram(
+            &data,
+            &HistogramConfig::new()
+                .bin_edges(edges)
+                .bin_overflow(BinOverflow::Clamp),
+        )
+        .unwrap();
+        assert_eq!(clamped.counts, vec![4.0, 4.0]);
+    }
+
+    #[test]
+    fn test_histogram_log_bins_are_equal_width_in_log_space() {
+        let data = vec![-1.0, 0.0, 1.0, 2.0, 9.0, 10.0, 50.0, 99.0, 1000.0];
+        let config = HistogramConfig::new().bins(3).log_bins(true);
+
+        let result = calculate_histogram(&data, &config).unwrap();
+
+        assert_eq!(result.bin_edges.len(), 4);
+        for (edge, expected) in result.bin_edges.iter().zip([1.0, 10.0, 100.0, 1000.0]) {
+            assert!((edge - expected).abs() < 1e-9 * expected);
+        }
+        // Non-positive values are dropped and 1000.0 lands in the last bin
+        assert_eq!(result.counts, vec![3.0, 3.0, 1.0]);
+
+        let ranged = HistogramConfig::new()
+            .bins(2)
+            .range(10.0, 1000.0)
+            .log_bins(true);
+        let result = calculate_histogram(&data, &ranged).unwrap();
+        assert_eq!(result.counts, vec![3.0, 1.0]);
+        assert!((result.bin_edges[1] - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_histogram_log_bins_reject_non_positive_data_and_ranges() {
+        let log = HistogramConfig::new().log_bins(true);
+        let non_positive = vec![-2.0, 0.0];
+        let positive = vec![1.0, 2.0];
+        let repeated = vec![5.0, 5.0];
+        assert!(calculate_histogram(&non_positive, &log).is_err());
+        assert!(calculate_histogram(&positive, &log.clone().range(0.0, 10.0)).is_err());
+
+        let single = calculate_histogram(&repeated, &log).unwrap();
+        assert_eq!(single.counts.iter().sum::<f64>(), 2.0);
+    }
+
+    #[test]
+    fn test_histogram_2d_counts_points_per_cell() {
+        let x = vec![0.0, 0.4, 0.6, 1.0, 1.0, f64::NAN];
+        let y = vec![0.0, 0.1, 2.0, 2.0, 1.9, 1.0];
+
+        let hist = calculate_histogram_2d(&x, &y, (2, 2)).unwrap();
+
+        assert_eq!(hist.x_range, (0.0, 1.0));
+        assert_eq!(hist.y_range, (0.0, 2.0));
+        // Row 0 is the lower y half; the maxima land in the last bins
+        assert_eq!(hist.counts, vec![vec![2.0, 0.0], vec![0.0, 3.0]]);
+
+        let flat = calculate_histogram_2d(&x, &[5.0; 6], (1, 3)).unwrap();
+        assert_eq!(flat.y_range, (4.5, 5.5));
+        assert_eq!(flat.counts, vec![vec![0.0], vec![5.0], vec![0.0]]);
+
+        assert!(calculate_histogram_2d(&x, &y, (0, 2)).is_err());
+        assert!(calculate_histogram_2d(&[f64::NAN], &[1.0], (2, 2)).is_err());
+    }
+
+    #[test]
+    fn test_histogram_explicit_bin_edges_density_uses_each_bin_width() {
+        let data = vec![0.5, 1.5, 2.5, 3.5];
+        let config = HistogramConfig::new()
+            .bin_edges(vec![0.0, 1.0, 4.0])
+            .density(true);
+
+        let result = calculate_histogram(&data, &config).unwrap();
+
+        assert!((result.counts[0] - 0.25).abs() < 1e-12);
+        assert!((result.counts[1] - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_histogram_rejects_invalid_bin_edges() {
+        let data = vec![1.0, 2.0, 3.0];
+        for edges in [vec![1.0], vec![0.0, 2.0, 2.0], vec![0.0, f64::NAN, 3.0]] {
+            let config = HistogramConfig::new().bin_edges(edges);
+            assert!(calculate_histogram(&data, &config).is_err());
+        }
+    }
+
     #[test]
     fn test_histogram_identical_values() {
         let data = vec![5.0; 100]; // All identical values
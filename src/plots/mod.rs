@@ -52,32 +52,42 @@ pub mod vector;
 pub use traits::{PlotArea, PlotCompute, PlotConfig, PlotData, PlotRender, StyledShape};
 
 // Basic plot config exports
-pub use basic::{BarConfig, BarOrientation, LineConfig, ScatterConfig};
+pub use basic::{
+    BarConfig, BarOrientation, LineConfig, ScatterConfig, ScatterMappedConfig, ScatterMappedData,
+    compute_scatter_mapped,
+};
 
 // Distribution plot exports
 pub use distribution::{
-    Boxen, BoxenConfig, BoxenData, BoxenOrientation, Ecdf, EcdfConfig, EcdfData, EcdfStat, Kde,
-    KdeConfig, KdeData, Violin, ViolinConfig, ViolinData, compute_boxen, compute_ecdf, compute_kde,
+    BandwidthMethod, Boxen, BoxenConfig, BoxenData, BoxenOrientation, Ecdf, EcdfConfig, EcdfData,
+    EcdfStat, Kde, KdeConfig, KdeData, Rug, RugAxis, RugConfig, RugData, Violin, ViolinConfig,
+    ViolinData, compute_boxen, compute_ecdf, compute_kde,
 };
 
 pub use boxplot::{BoxPlotConfig, BoxPlotData, calculate_box_plot};
 pub use heatmap::{
-    HeatmapConfig, HeatmapData, HeatmapOrigin, Interpolation, process_heatmap, process_heatmap_flat,
+    ColorbarConfig, ColorbarOrientation, HeatmapConfig, HeatmapData, HeatmapOrigin, Interpolation,
+    process_heatmap, process_heatmap_flat,
+};
+pub use histogram::{
+    BinMethod, BinOverflow, Histogram2D, HistogramConfig, HistogramData, calculate_histogram,
+    calculate_histogram_2d,
 };
-pub use histogram::{BinMethod, HistogramConfig, HistogramData, calculate_histogram};
 pub use statistics::{iqr, mean, median, percentile, std_dev};
 
 // Contour plot exports
 pub use continuous::contour::{
     ContourConfig, ContourInterpolation, ContourPlotData, compute_contour_plot,
 };
+// Hexbin plot exports
+pub use continuous::hexbin::{HexbinConfig, HexbinPlotData, ReduceFunction, compute_hexbin};
 pub use discrete::{StemConfig, StemMarker, StemOrientation, StepConfig, StepWhere};
 
 // Pie chart exports
 pub use composition::pie::{PieConfig, PieData};
 
 // Polar and Radar exports
-pub use polar::polar_plot::{PolarPlotConfig, PolarPlotData, compute_polar_plot};
+pub use polar::polar_plot::{PolarPlotConfig, PolarPlotData, ThetaUnit, compute_polar_plot};
 pub use polar::radar::{
     RadarConfig, RadarPlotData, compute_radar_chart, compute_radar_chart_with_labels,
 };
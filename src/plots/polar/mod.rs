@@ -40,7 +40,7 @@ pub mod radar;
 
 pub use polar_plot::{
     PolarPlot, PolarPlotConfig, PolarPlotData, PolarPlotInput, PolarPoint, PositionedLabel,
-    circle_vertices, compute_polar_plot, polar_grid,
+    ThetaUnit, circle_vertices, compute_polar_plot, polar_grid, polar_r_ticks,
 };
 pub use radar::{
     Radar, RadarConfig, RadarInput, RadarPlotData, RadarSeries, compute_radar_chart,
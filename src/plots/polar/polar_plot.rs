@@ -2,6 +2,9 @@
 //!
 //! Provides polar scatter, line, and bar plots with configurable axis labels.
 //!
+//! Theta is interpreted as the angle and r as the distance from the origin.
+//! Circular radial grid lines and angular spokes replace the rectilinear grid.
+//!
 //! # Axis Labels
 //!
 //! Polar plots support angular (theta) and radial (r) axis labels:
@@ -19,6 +22,11 @@
 //!     .r_label_position(22.5)       // Position at 22.5° from right
 //!     .label_font_size(10.0);       // Font size in points
 //!
+//! // Angles supplied in degrees, labelled as fractions of π
+//! let config = PolarPlotConfig::new()
+//!     .theta_unit(ThetaUnit::Degrees)
+//!     .theta_label_unit(ThetaUnit::Radians);
+//!
 //! // Hide labels for cleaner appearance
 //! let minimal = PolarPlotConfig::new()
 //!     .show_theta_labels(false)
@@ -38,6 +46,26 @@ use crate::plots::traits::{PlotArea, PlotCompute, PlotConfig, PlotData, PlotRend
 use crate::render::skia::SkiaRenderer;
 use crate::render::{Color, LineStyle, MarkerStyle, Theme};
 
+/// Unit used for polar angles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThetaUnit {
+    /// Angles in degrees (0-360)
+    Degrees,
+    /// Angles in radians (0-2π)
+    #[default]
+    Radians,
+}
+
+impl ThetaUnit {
+    /// Convert an angle in this unit to radians
+    pub fn to_radians(self, theta: f64) -> f64 {
+        match self {
+            ThetaUnit::Degrees => theta.to_radians(),
+            ThetaUnit::Radians => theta,
+        }
+    }
+}
+
 /// Configuration for polar plots
 #[derive(Debug, Clone)]
 pub struct PolarPlotConfig {
@@ -71,6 +99,12 @@ pub struct PolarPlotConfig {
     pub r_label_position: f64,
     /// Font size for axis labels
     pub label_font_size: f32,
+    /// Connect points with lines (false for polar scatter)
+    pub show_line: bool,
+    /// Unit of the input theta values
+    pub theta_unit: ThetaUnit,
+    /// Unit used for angular tick labels
+    pub theta_label_unit: ThetaUnit,
 }
 
 impl Default for PolarPlotConfig {
@@ -91,6 +125,9 @@ impl Default for PolarPlotConfig {
             show_r_labels: true,
             r_label_position: 22.5, // degrees
             label_font_size: 10.0,
+            show_line: true,
+            theta_unit: ThetaUnit::Radians,
+            theta_label_unit: ThetaUnit::Degrees,
         }
     }
 }
@@ -160,6 +197,63 @@ impl PolarPlotConfig {
         self.label_font_size = size.max(1.0);
         self
     }
+
+    /// Set theta direction (true = counter-clockwise)
+    pub fn theta_direction(mut self, counter_clockwise: bool) -> Self {
+        self.theta_direction = counter_clockwise;
+        self
+    }
+
+    /// Show/hide radial grid circles
+    pub fn show_rgrid(mut self, show: bool) -> Self {
+        self.show_rgrid = show;
+        self
+    }
+
+    /// Show/hide angular grid spokes
+    pub fn show_thetgrid(mut self, show: bool) -> Self {
+        self.show_thetgrid = show;
+        self
+    }
+
+    /// Set target number of radial grid circles
+    pub fn rgrid_count(mut self, count: usize) -> Self {
+        self.rgrid_count = count.max(1);
+        self
+    }
+
+    /// Set number of angular grid spokes
+    pub fn thetgrid_count(mut self, count: usize) -> Self {
+        self.thetgrid_count = count.max(1);
+        self
+    }
+
+    /// Show/hide connecting lines between points
+    pub fn show_line(mut self, show: bool) -> Self {
+        self.show_line = show;
+        self
+    }
+
+    /// Set the unit of the input theta values
+    pub fn theta_unit(mut self, unit: ThetaUnit) -> Self {
+        self.theta_unit = unit;
+        self
+    }
+
+    /// Set the unit used for angular tick labels
+    pub fn theta_label_unit(mut self, unit: ThetaUnit) -> Self {
+        self.theta_label_unit = unit;
+        self
+    }
+
+    /// Map a data angle to the screen-frame angle in radians
+    fn adjusted_theta(&self, theta_rad: f64) -> f64 {
+        if self.theta_direction {
+            theta_rad + self.theta_offset
+        } else {
+            -theta_rad + self.theta_offset
+        }
+    }
 }
 
 // Implement PlotConfig marker trait
@@ -227,6 +321,10 @@ pub struct PolarPlotData {
     pub theta_labels: Vec<PositionedLabel>,
     /// Radial axis labels
     pub r_labels: Vec<PositionedLabel>,
+    /// Radii of the circular grid lines (outermost is the plot boundary)
+    pub r_grid: Vec<f64>,
+    /// Angular grid spokes as (start, end) points
+    pub theta_grid: Vec<((f64, f64), (f64, f64))>,
     /// Configuration used
     pub(crate) config: PolarPlotConfig,
 }
@@ -235,7 +333,7 @@ pub struct PolarPlotData {
 pub struct PolarPlotInput<'a> {
     /// Radius values
     pub r: &'a [f64],
-    /// Theta values (in radians)
+    /// Theta values (in [`PolarPlotConfig::theta_unit`])
     pub theta: &'a [f64],
 }
 
@@ -250,7 +348,7 @@ impl<'a> PolarPlotInput<'a> {
 ///
 /// # Arguments
 /// * `r` - Radius values
-/// * `theta` - Theta values (in radians)
+/// * `theta` - Theta values (in `config.theta_unit`, radians by default)
 /// * `config` - Polar plot configuration
 ///
 /// # Returns
@@ -266,6 +364,8 @@ pub fn compute_polar_plot(r: &[f64], theta: &[f64], config: &PolarPlotConfig) ->
             fill_polygon: vec![],
             theta_labels: vec![],
             r_labels: vec![],
+            r_grid: vec![],
+            theta_grid: vec![],
             config: config.clone(),
         };
     }
@@ -274,12 +374,8 @@ pub fn compute_polar_plot(r: &[f64], theta: &[f64], config: &PolarPlotConfig) ->
     let mut r_max = 0.0_f64;
 
     for i in 0..n {
-        // Apply theta offset and direction
-        let adjusted_theta = if config.theta_direction {
-            theta[i] + config.theta_offset
-        } else {
-            -theta[i] + config.theta_offset
-        };
+        // Apply unit conversion, theta offset and direction
+        let adjusted_theta = config.adjusted_theta(config.theta_unit.to_radians(theta[i]));
 
         let point = PolarPoint::from_polar(r[i], adjusted_theta);
         r_max = r_max.max(r[i].abs());
@@ -299,17 +395,19 @@ pub fn compute_polar_plot(r: &[f64], theta: &[f64], config: &PolarPlotConfig) ->
         vec![]
     };
 
+    let thetgrid_count = config.thetgrid_count.max(1);
+    let r_ticks = polar_r_ticks(r_max, config.rgrid_count);
+
     // Compute theta labels (0°, 45°, 90°, etc.) positioned at edge of plot
     let theta_labels = if config.show_theta_labels {
         let label_radius = r_max * 1.12; // Position slightly outside the plot
-        (0..config.thetgrid_count)
+        (0..thetgrid_count)
             .map(|i| {
-                let angle = 2.0 * PI * i as f64 / config.thetgrid_count as f64;
-                let degrees = (angle * 180.0 / PI).round() as i32;
+                let angle = config.adjusted_theta(2.0 * PI * i as f64 / thetgrid_count as f64);
                 PositionedLabel {
                     x: label_radius * angle.cos(),
                     y: label_radius * angle.sin(),
-                    text: format!("{}°", degrees),
+                    text: format_theta_label(i, thetgrid_count, config.theta_label_unit),
                 }
             })
             .collect()
@@ -319,15 +417,43 @@ pub fn compute_polar_plot(r: &[f64], theta: &[f64], config: &PolarPlotConfig) ->
 
     // Compute radial labels positioned along r_label_position angle
     let r_labels = if config.show_r_labels {
-        let label_angle = config.r_label_position * PI / 180.0; // Convert to radians
-        (1..=config.rgrid_count)
+        let label_angle = config.r_label_position.to_radians();
+        let step = r_ticks
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .next()
+            .unwrap_or(r_max);
+        r_ticks
+            .iter()
+            .map(|&radius| PositionedLabel {
+                x: radius * label_angle.cos(),
+                y: radius * label_angle.sin(),
+                text: format_r_label(radius, step),
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+
+    // Grid circles at the nice r ticks, closed by the outer boundary
+    let r_grid = if config.show_rgrid {
+        let mut radii = r_ticks.clone();
+        if radii
+            .last()
+            .is_none_or(|&last| (r_max - last).abs() > r_max * 1e-9)
+        {
+            radii.push(r_max);
+        }
+        radii
+    } else {
+        vec![]
+    };
+
+    let theta_grid = if config.show_thetgrid {
+        (0..thetgrid_count)
             .map(|i| {
-                let radius = r_max * i as f64 / config.rgrid_count as f64;
-                PositionedLabel {
-                    x: radius * label_angle.cos(),
-                    y: radius * label_angle.sin(),
-                    text: format!("{:.1}", radius),
-                }
+                let angle = config.adjusted_theta(2.0 * PI * i as f64 / thetgrid_count as f64);
+                ((0.0, 0.0), (r_max * angle.cos(), r_max * angle.sin()))
             })
             .collect()
     } else {
@@ -340,10 +466,69 @@ pub fn compute_polar_plot(r: &[f64], theta: &[f64], config: &PolarPlotConfig) ->
         fill_polygon,
         theta_labels,
         r_labels,
+        r_grid,
+        theta_grid,
         config: config.clone(),
     }
 }
 
+/// Generate "nice" radial tick values in `(0, r_max]`
+///
+/// Uses the same 1-2-5 step selection as Cartesian axes so ring labels
+/// read as round numbers instead of fractions of the data maximum.
+pub fn polar_r_ticks(r_max: f64, target_count: usize) -> Vec<f64> {
+    if !(r_max.is_finite() && r_max > 0.0) {
+        return vec![];
+    }
+
+    crate::axes::generate_ticks(0.0, r_max, target_count.max(1) + 1)
+        .into_iter()
+        .filter(|&tick| tick > 0.0 && tick <= r_max * (1.0 + 1e-9))
+        .collect()
+}
+
+/// Format the label for the `index`-th of `count` evenly spaced angular ticks
+fn format_theta_label(index: usize, count: usize, unit: ThetaUnit) -> String {
+    match unit {
+        ThetaUnit::Degrees => {
+            let degrees = (360.0 * index as f64 / count as f64).round() as i64;
+            format!("{}°", degrees)
+        }
+        ThetaUnit::Radians => {
+            // angle = 2π·index/count, reduced to a fraction of π
+            let mut numerator = 2 * index;
+            let mut denominator = count;
+            let divisor = gcd(numerator, denominator);
+            numerator /= divisor;
+            denominator /= divisor;
+            match (numerator, denominator) {
+                (0, _) => "0".to_string(),
+                (1, 1) => "π".to_string(),
+                (n, 1) => format!("{}π", n),
+                (1, d) => format!("π/{}", d),
+                (n, d) => format!("{}π/{}", n, d),
+            }
+        }
+    }
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.max(1)
+}
+
+/// Format a radial tick with just enough decimals for the tick step
+fn format_r_label(value: f64, step: f64) -> String {
+    let decimals = if step >= 1.0 || step <= 0.0 {
+        0
+    } else {
+        (-step.log10() - 1e-9).ceil().max(0.0) as usize
+    };
+    format!("{:.*}", decimals, value)
+}
+
 /// Generate polar grid lines
 ///
 /// # Arguments
@@ -437,6 +622,19 @@ impl PlotRender for PolarPlotData {
         color: Color,
         alpha: f32,
         line_width: Option<f32>,
+    ) -> Result<()> {
+        self.render_styled_with_grid(renderer, area, theme, color, alpha, line_width, None)
+    }
+
+    fn render_styled_with_grid(
+        &self,
+        renderer: &mut SkiaRenderer,
+        area: &PlotArea,
+        theme: &Theme,
+        color: Color,
+        alpha: f32,
+        line_width: Option<f32>,
+        grid_style: Option<&crate::core::GridStyle>,
     ) -> Result<()> {
         if self.points.is_empty() {
             return Ok(());
@@ -451,6 +649,41 @@ impl PlotRender for PolarPlotData {
         let marker_size_px = render_scale.points_to_pixels(config.marker_size);
         let label_font_size_px = render_scale.points_to_pixels(config.label_font_size);
 
+        // Draw circular and angular grid lines beneath the data
+        if grid_style.is_none_or(|style| style.visible) {
+            let grid_color = grid_style.map_or(theme.grid_color, |style| style.effective_color());
+            let grid_line_width =
+                render_scale.points_to_pixels(grid_style.map_or(0.5, |style| style.line_width));
+            let grid_line_style = grid_style
+                .map(|style| style.line_style.clone())
+                .unwrap_or(LineStyle::Solid);
+            for &radius in &self.r_grid {
+                let circle: Vec<(f32, f32)> = circle_vertices(0.0, 0.0, radius, 72)
+                    .into_iter()
+                    .map(|(x, y)| area.data_to_screen(x, y))
+                    .collect();
+                renderer.draw_polyline(
+                    &circle,
+                    grid_color,
+                    grid_line_width,
+                    grid_line_style.clone(),
+                )?;
+            }
+            for &((x1, y1), (x2, y2)) in &self.theta_grid {
+                let (sx1, sy1) = area.data_to_screen(x1, y1);
+                let (sx2, sy2) = area.data_to_screen(x2, y2);
+                renderer.draw_line(
+                    sx1,
+                    sy1,
+                    sx2,
+                    sy2,
+                    grid_color,
+                    grid_line_width,
+                    grid_line_style.clone(),
+                )?;
+            }
+        }
+
         // Draw fill if enabled
         if config.fill && !self.fill_polygon.is_empty() {
             let fill_color = base_color.with_alpha(
@@ -465,7 +698,7 @@ impl PlotRender for PolarPlotData {
         }
 
         // Draw lines connecting points
-        if self.points.len() > 1 {
+        if config.show_line && self.points.len() > 1 {
             for i in 0..self.points.len() - 1 {
                 let p1 = &self.points[i];
                 let p2 = &self.points[i + 1];
@@ -557,6 +790,87 @@ mod tests {
         assert_eq!(vertices.len(), 5); // 4 segments + closing point
     }
 
+    #[test]
+    fn test_polar_r_ticks_are_nice_numbers() {
+        assert_eq!(polar_r_ticks(10.0, 5), vec![2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert_eq!(polar_r_ticks(2.7, 5), vec![1.0, 2.0]);
+        assert_eq!(polar_r_ticks(0.9, 5), vec![0.2, 0.4, 0.6, 0.8]);
+        assert!(polar_r_ticks(0.0, 5).is_empty());
+    }
+
+    #[test]
+    fn test_polar_grid_closes_at_r_max() {
+        let r = vec![1.0, 2.7];
+        let theta = vec![0.0, PI / 2.0];
+        let data = compute_polar_plot(&r, &theta, &PolarPlotConfig::default());
+
+        assert_eq!(data.r_grid.len(), 3);
+        assert!((data.r_grid[2] - 2.7).abs() < 1e-10);
+        assert_eq!(data.theta_grid.len(), 12);
+        assert_eq!(data.r_labels.len(), 2);
+        assert_eq!(data.r_labels[0].text, "1");
+
+        let small = compute_polar_plot(&[0.9], &[0.0], &PolarPlotConfig::default());
+        assert_eq!(small.r_labels[0].text, "0.2");
+
+        let hidden = PolarPlotConfig::new()
+            .show_rgrid(false)
+            .show_thetgrid(false);
+        let data = compute_polar_plot(&r, &theta, &hidden);
+        assert!(data.r_grid.is_empty());
+        assert!(data.theta_grid.is_empty());
+    }
+
+    #[test]
+    fn test_theta_label_units() {
+        let r = vec![1.0];
+        let theta = vec![0.0];
+        let degrees = compute_polar_plot(&r, &theta, &PolarPlotConfig::new().thetgrid_count(4));
+        let texts: Vec<_> = degrees
+            .theta_labels
+            .iter()
+            .map(|l| l.text.as_str())
+            .collect();
+        assert_eq!(texts, vec!["0°", "90°", "180°", "270°"]);
+
+        let config = PolarPlotConfig::new()
+            .thetgrid_count(8)
+            .theta_label_unit(ThetaUnit::Radians);
+        let radians = compute_polar_plot(&r, &theta, &config);
+        let texts: Vec<_> = radians
+            .theta_labels
+            .iter()
+            .map(|l| l.text.as_str())
+            .collect();
+        assert_eq!(
+            texts,
+            vec!["0", "π/4", "π/2", "3π/4", "π", "5π/4", "3π/2", "7π/4"]
+        );
+    }
+
+    #[test]
+    fn test_theta_unit_degrees_input() {
+        let config = PolarPlotConfig::new().theta_unit(ThetaUnit::Degrees);
+        let data = compute_polar_plot(&[2.0], &[90.0], &config);
+        assert!(data.points[0].x.abs() < 1e-10);
+        assert!((data.points[0].y - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_theta_labels_follow_offset_and_direction() {
+        let config = PolarPlotConfig::new()
+            .theta_offset(PI / 2.0)
+            .theta_direction(false)
+            .thetgrid_count(4);
+        let data = compute_polar_plot(&[1.0], &[0.0], &config);
+
+        // 90° label sits to the right when zero is at the top and angles run clockwise
+        let label = &data.theta_labels[1];
+        assert_eq!(label.text, "90°");
+        assert!(label.x > 0.0);
+        assert!(label.y.abs() < 1e-10);
+    }
+
     #[test]
     fn test_polar_config_implements_plot_config() {
         fn assert_plot_config<T: PlotConfig>() {}
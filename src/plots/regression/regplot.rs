@@ -114,6 +114,37 @@ pub struct RegPlotData {
     pub r_squared: f64,
 }
 
+impl RegPlotData {
+    /// Fit equation and R² formatted for a legend label
+    ///
+    /// Terms run from the highest power down, e.g. `y = 2.000x - 1.000 (R² = 0.950)`.
+    /// Returns an empty string when there is no fit.
+    pub fn equation(&self) -> String {
+        if self.coefficients.is_empty() {
+            return String::new();
+        }
+
+        let mut equation = String::from("y =");
+        for (power, &c) in self.coefficients.iter().enumerate().rev() {
+            let sign = if c < 0.0 { '-' } else { '+' };
+            let term = match power {
+                0 => String::new(),
+                1 => "x".to_string(),
+                2 => "x²".to_string(),
+                3 => "x³".to_string(),
+                _ => format!("x^{power}"),
+            };
+            if equation.len() == 3 {
+                let lead = if c < 0.0 { "-" } else { "" };
+                equation.push_str(&format!(" {lead}{:.3}{term}", c.abs()));
+            } else {
+                equation.push_str(&format!(" {sign} {:.3}{term}", c.abs()));
+            }
+        }
+        format!("{equation} (R² = {:.3})", self.r_squared)
+    }
+}
+
 /// Compute regression plot data
 ///
 /// # Arguments
@@ -354,6 +385,23 @@ mod tests {
         assert!(data.ci_upper.is_some());
     }
 
+    #[test]
+    fn test_regplot_equation_lists_terms_from_highest_power() {
+        let x = vec![0.0, 1.0, 2.0, 3.0];
+        let y: Vec<f64> = x.iter().map(|&xi| 2.0 * xi - 1.0).collect();
+        let data = compute_regplot(&x, &y, &RegPlotConfig::default().ci(None));
+        assert_eq!(data.equation(), "y = 2.000x - 1.000 (R² = 1.000)");
+
+        let y: Vec<f64> = x.iter().map(|&xi| -0.5 * xi * xi + 3.0).collect();
+        let data = compute_regplot(&x, &y, &RegPlotConfig::default().order(2).ci(None));
+        assert!(data.equation().starts_with("y = -0.500x² "));
+
+        assert_eq!(
+            compute_regplot(&[1.0], &[2.0], &RegPlotConfig::default()).equation(),
+            ""
+        );
+    }
+
     #[test]
     fn test_residplot() {
         let x = vec![1.0, 2.0, 3.0, 4.0, 5.0];
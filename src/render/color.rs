@@ -87,15 +87,8 @@ impl Color {
 
     /// Create a Color from a named color string
     ///
-    /// Supports common color names like "red", "blue", "green", etc.
-    /// Case-insensitive. Returns `None` for unrecognized names.
-    ///
-    /// # Supported Colors
-    ///
-    /// - Primary: "red", "green", "blue"
-    /// - Secondary: "cyan", "magenta", "yellow"
-    /// - Neutral: "black", "white", "gray" (or "grey")
-    /// - Other: "orange", "purple", "pink", "brown"
+    /// Accepts the 148 standard CSS/X11 color names. Returns `None` for
+    /// unrecognized names; see [`Color::from_name`] for details.
     ///
     /// # Example
     ///
@@ -115,38 +108,111 @@ impl Color {
     /// assert!(unknown.is_none());
     /// ```
     pub fn named(name: &str) -> Option<Self> {
-        match name.to_lowercase().as_str() {
-            "red" => Some(Self::RED),
-            "green" => Some(Self::GREEN),
-            "blue" => Some(Self::BLUE),
-            "yellow" => Some(Self::YELLOW),
-            "orange" => Some(Self::ORANGE),
-            "purple" => Some(Self::PURPLE),
-            "cyan" => Some(Self::CYAN),
-            "magenta" => Some(Self::MAGENTA),
-            "black" => Some(Self::BLACK),
-            "white" => Some(Self::WHITE),
-            "gray" | "grey" => Some(Self::GRAY),
-            "lightgray" | "lightgrey" | "light_gray" | "light_grey" => Some(Self::LIGHT_GRAY),
-            "darkgray" | "darkgrey" | "dark_gray" | "dark_grey" => Some(Self::DARK_GRAY),
-            "pink" => Some(Self::new(255, 192, 203)),
-            "brown" => Some(Self::new(139, 69, 19)),
-            "lime" => Some(Self::new(0, 255, 0)),
-            "navy" => Some(Self::new(0, 0, 128)),
-            "teal" => Some(Self::new(0, 128, 128)),
-            "olive" => Some(Self::new(128, 128, 0)),
-            "maroon" => Some(Self::new(128, 0, 0)),
-            "aqua" => Some(Self::CYAN),
-            "fuchsia" => Some(Self::MAGENTA),
-            "silver" => Some(Self::new(192, 192, 192)),
-            "coral" => Some(Self::new(255, 127, 80)),
-            "salmon" => Some(Self::new(250, 128, 114)),
-            "gold" => Some(Self::new(255, 215, 0)),
-            "indigo" => Some(Self::new(75, 0, 130)),
-            "violet" => Some(Self::new(238, 130, 238)),
-            "crimson" => Some(Self::new(220, 20, 60)),
-            _ => None,
-        }
+        Self::from_name(name).ok()
+    }
+
+    /// Create a Color from a standard CSS/X11 color name
+    ///
+    /// Covers all 148 CSS named colors (`"steelblue"`, `"rebeccapurple"`,
+    /// ...), including both `gray` and `grey` spellings. Matching ignores
+    /// case, spaces, underscores, and hyphens, so `"Light Gray"` and
+    /// `"light_gray"` both resolve to `lightgray`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ruviz::render::Color;
+    ///
+    /// assert_eq!(Color::from_name("steelblue").unwrap(), Color::new(70, 130, 180));
+    /// assert!(Color::from_name("notacolor").is_err());
+    /// ```
+    pub fn from_name(name: &str) -> Result<Self, ColorError> {
+        let key: String = name
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '_' | '-'))
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        CSS_NAMED_COLORS
+            .binary_search_by(|(candidate, _)| candidate.cmp(&key.as_str()))
+            .map(|index| Self::from_rgb_u32(CSS_NAMED_COLORS[index].1))
+            .map_err(|_| ColorError::UnknownName(name.to_string()))
+    }
+
+    /// Create an opaque Color from hue, saturation, and lightness
+    ///
+    /// `hue` is in degrees and wraps around (so `-30.0` equals `330.0`);
+    /// `saturation` and `lightness` are fractions clamped to `0.0..=1.0`.
+    /// Stepping the hue evenly gives well-separated colors for many series.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ruviz::render::Color;
+    ///
+    /// assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::RED);
+    /// let hues: Vec<Color> = (0..12).map(|i| Color::from_hsl(i as f32 * 30.0, 0.65, 0.5)).collect();
+    /// ```
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let saturation = clamp_unit(saturation);
+        let lightness = clamp_unit(lightness);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        Self::from_hue_chroma(hue, chroma, lightness - chroma / 2.0)
+    }
+
+    /// Create an opaque Color from hue, saturation, and value (brightness)
+    ///
+    /// `hue` is in degrees and wraps around; `saturation` and `value` are
+    /// fractions clamped to `0.0..=1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ruviz::render::Color;
+    ///
+    /// assert_eq!(Color::from_hsv(240.0, 1.0, 1.0), Color::BLUE);
+    /// assert_eq!(Color::from_hsv(0.0, 0.0, 0.0), Color::BLACK);
+    /// ```
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let chroma = clamp_unit(value) * clamp_unit(saturation);
+        Self::from_hue_chroma(hue, chroma, clamp_unit(value) - chroma)
+    }
+
+    /// Shared hue-sector conversion for [`from_hsl`](Self::from_hsl) and
+    /// [`from_hsv`](Self::from_hsv)
+    fn from_hue_chroma(hue: f32, chroma: f32, offset: f32) -> Self {
+        let hue = if hue.is_finite() {
+            hue.rem_euclid(360.0)
+        } else {
+            0.0
+        } / 60.0;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let channel = |value: f32| ((value + offset) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Self::new(channel(r), channel(g), channel(b))
+    }
+
+    /// Format as a lowercase hex string, `"#rrggbb"` or `"#rrggbbaa"` when
+    /// not fully opaque
+    ///
+    /// The result round-trips through [`Color::from_hex`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ruviz::render::Color;
+    ///
+    /// assert_eq!(Color::new(70, 130, 180).to_hex(), "#4682b4");
+    /// assert_eq!(Color::new_rgba(255, 0, 0, 128).to_hex(), "#ff000080");
+    /// ```
+    pub fn to_hex(self) -> String {
+        self.to_string()
     }
 
     /// Suggest similar color names for typos
@@ -538,6 +604,45 @@ impl Color {
         palette[index % palette.len()]
     }
 
+    /// Get the Okabe–Ito colorblind-safe palette
+    ///
+    /// Eight colors chosen by Okabe and Ito to stay distinguishable under
+    /// protanopia, deuteranopia, and tritanopia. Orange comes first and black
+    /// last, so the first seven series are colored and black is kept for
+    /// reference data.
+    ///
+    /// Hues are separated, but lightness is not uniform. WCAG contrast
+    /// against a white background is about 5.2:1 for blue, 3.9:1 vermillion,
+    /// 3.4:1 bluish green, 3.1:1 reddish purple, 2.3:1 orange and sky blue,
+    /// and only 1.3:1 for yellow. Yellow is best for fills and dark
+    /// backgrounds; thin lines and small markers should also vary line style
+    /// or marker shape. Against black, every color except blue reaches at
+    /// least 5:1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ruviz::prelude::*;
+    ///
+    /// let theme = Theme::builder()
+    ///     .palette(Color::colorblind_palette().iter().copied())
+    ///     .build();
+    /// assert_eq!(theme.get_color(0), Color::from_hex("#E69F00").unwrap());
+    /// ```
+    pub fn colorblind_palette() -> &'static [Color] {
+        static PALETTE: &[Color] = &[
+            Color::from_rgb_u32(0xe69f00), // Orange
+            Color::from_rgb_u32(0x56b4e9), // Sky blue
+            Color::from_rgb_u32(0x009e73), // Bluish green
+            Color::from_rgb_u32(0xf0e442), // Yellow
+            Color::from_rgb_u32(0x0072b2), // Blue
+            Color::from_rgb_u32(0xd55e00), // Vermillion
+            Color::from_rgb_u32(0xcc79a7), // Reddish purple
+            Color::from_rgb_u32(0x000000), // Black
+        ];
+        PALETTE
+    }
+
     /// Create Color from 24-bit RGB integer
     const fn from_rgb_u32(rgb: u32) -> Self {
         Self {
@@ -563,10 +668,36 @@ impl fmt::Display for Color {
     }
 }
 
+/// Serialized as a `"#rrggbb"` (or `"#rrggbbaa"`) hex string
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserialized from a hex string accepted by [`Color::from_hex`] or a CSS
+/// color name accepted by [`Color::from_name`]
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Color::from_hex(&value)
+            .or_else(|_| Color::from_name(&value))
+            .map_err(|_| {
+                serde::de::Error::custom(format!(
+                    "invalid color '{value}': expected a hex string or CSS color name"
+                ))
+            })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ColorError {
     InvalidHex,
     InvalidLength,
+    /// Not one of the CSS/X11 color names accepted by [`Color::from_name`]
+    UnknownName(String),
 }
 
 /// ColorMap for mapping scalar values to colors (used by DataShader)
@@ -848,12 +979,173 @@ impl fmt::Display for ColorError {
                 f,
                 "Invalid color string length (expected 3, 6, or 8 characters)"
             ),
+            ColorError::UnknownName(name) => write!(f, "Unknown color name '{}'", name),
         }
     }
 }
 
 impl std::error::Error for ColorError {}
 
+fn clamp_unit(value: f32) -> f32 {
+    if value.is_finite() {
+        value.clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// CSS Color Module Level 4 named colors, sorted by name for binary search
+const CSS_NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xF0F8FF),
+    ("antiquewhite", 0xFAEBD7),
+    ("aqua", 0x00FFFF),
+    ("aquamarine", 0x7FFFD4),
+    ("azure", 0xF0FFFF),
+    ("beige", 0xF5F5DC),
+    ("bisque", 0xFFE4C4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xFFEBCD),
+    ("blue", 0x0000FF),
+    ("blueviolet", 0x8A2BE2),
+    ("brown", 0xA52A2A),
+    ("burlywood", 0xDEB887),
+    ("cadetblue", 0x5F9EA0),
+    ("chartreuse", 0x7FFF00),
+    ("chocolate", 0xD2691E),
+    ("coral", 0xFF7F50),
+    ("cornflowerblue", 0x6495ED),
+    ("cornsilk", 0xFFF8DC),
+    ("crimson", 0xDC143C),
+    ("cyan", 0x00FFFF),
+    ("darkblue", 0x00008B),
+    ("darkcyan", 0x008B8B),
+    ("darkgoldenrod", 0xB8860B),
+    ("darkgray", 0xA9A9A9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xA9A9A9),
+    ("darkkhaki", 0xBDB76B),
+    ("darkmagenta", 0x8B008B),
+    ("darkolivegreen", 0x556B2F),
+    ("darkorange", 0xFF8C00),
+    ("darkorchid", 0x9932CC),
+    ("darkred", 0x8B0000),
+    ("darksalmon", 0xE9967A),
+    ("darkseagreen", 0x8FBC8F),
+    ("darkslateblue", 0x483D8B),
+    ("darkslategray", 0x2F4F4F),
+    ("darkslategrey", 0x2F4F4F),
+    ("darkturquoise", 0x00CED1),
+    ("darkviolet", 0x9400D3),
+    ("deeppink", 0xFF1493),
+    ("deepskyblue", 0x00BFFF),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1E90FF),
+    ("firebrick", 0xB22222),
+    ("floralwhite", 0xFFFAF0),
+    ("forestgreen", 0x228B22),
+    ("fuchsia", 0xFF00FF),
+    ("gainsboro", 0xDCDCDC),
+    ("ghostwhite", 0xF8F8FF),
+    ("gold", 0xFFD700),
+    ("goldenrod", 0xDAA520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xADFF2F),
+    ("grey", 0x808080),
+    ("honeydew", 0xF0FFF0),
+    ("hotpink", 0xFF69B4),
+    ("indianred", 0xCD5C5C),
+    ("indigo", 0x4B0082),
+    ("ivory", 0xFFFFF0),
+    ("khaki", 0xF0E68C),
+    ("lavender", 0xE6E6FA),
+    ("lavenderblush", 0xFFF0F5),
+    ("lawngreen", 0x7CFC00),
+    ("lemonchiffon", 0xFFFACD),
+    ("lightblue", 0xADD8E6),
+    ("lightcoral", 0xF08080),
+    ("lightcyan", 0xE0FFFF),
+    ("lightgoldenrodyellow", 0xFAFAD2),
+    ("lightgray", 0xD3D3D3),
+    ("lightgreen", 0x90EE90),
+    ("lightgrey", 0xD3D3D3),
+    ("lightpink", 0xFFB6C1),
+    ("lightsalmon", 0xFFA07A),
+    ("lightseagreen", 0x20B2AA),
+    ("lightskyblue", 0x87CEFA),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xB0C4DE),
+    ("lightyellow", 0xFFFFE0),
+    ("lime", 0x00FF00),
+    ("limegreen", 0x32CD32),
+    ("linen", 0xFAF0E6),
+    ("magenta", 0xFF00FF),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66CDAA),
+    ("mediumblue", 0x0000CD),
+    ("mediumorchid", 0xBA55D3),
+    ("mediumpurple", 0x9370DB),
+    ("mediumseagreen", 0x3CB371),
+    ("mediumslateblue", 0x7B68EE),
+    ("mediumspringgreen", 0x00FA9A),
+    ("mediumturquoise", 0x48D1CC),
+    ("mediumvioletred", 0xC71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xF5FFFA),
+    ("mistyrose", 0xFFE4E1),
+    ("moccasin", 0xFFE4B5),
+    ("navajowhite", 0xFFDEAD),
+    ("navy", 0x000080),
+    ("oldlace", 0xFDF5E6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6B8E23),
+    ("orange", 0xFFA500),
+    ("orangered", 0xFF4500),
+    ("orchid", 0xDA70D6),
+    ("palegoldenrod", 0xEEE8AA),
+    ("palegreen", 0x98FB98),
+    ("paleturquoise", 0xAFEEEE),
+    ("palevioletred", 0xDB7093),
+    ("papayawhip", 0xFFEFD5),
+    ("peachpuff", 0xFFDAB9),
+    ("peru", 0xCD853F),
+    ("pink", 0xFFC0CB),
+    ("plum", 0xDDA0DD),
+    ("powderblue", 0xB0E0E6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xFF0000),
+    ("rosybrown", 0xBC8F8F),
+    ("royalblue", 0x4169E1),
+    ("saddlebrown", 0x8B4513),
+    ("salmon", 0xFA8072),
+    ("sandybrown", 0xF4A460),
+    ("seagreen", 0x2E8B57),
+    ("seashell", 0xFFF5EE),
+    ("sienna", 0xA0522D),
+    ("silver", 0xC0C0C0),
+    ("skyblue", 0x87CEEB),
+    ("slateblue", 0x6A5ACD),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xFFFAFA),
+    ("springgreen", 0x00FF7F),
+    ("steelblue", 0x4682B4),
+    ("tan", 0xD2B48C),
+    ("teal", 0x008080),
+    ("thistle", 0xD8BFD8),
+    ("tomato", 0xFF6347),
+    ("turquoise", 0x40E0D0),
+    ("violet", 0xEE82EE),
+    ("wheat", 0xF5DEB3),
+    ("white", 0xFFFFFF),
+    ("whitesmoke", 0xF5F5F5),
+    ("yellow", 0xFFFF00),
+    ("yellowgreen", 0x9ACD32),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -897,6 +1189,49 @@ mod tests {
         assert!(Color::from_hex("").is_err());
     }
 
+    #[test]
+    fn test_css_color_names() {
+        for (name, _) in CSS_NAMED_COLORS {
+            assert!(Color::from_name(name).is_ok(), "{name} should resolve");
+        }
+        assert_eq!(
+            Color::from_name("SteelBlue").unwrap(),
+            Color::new(70, 130, 180)
+        );
+        assert_eq!(Color::from_name("light_gray").unwrap(), Color::LIGHT_GRAY);
+        assert_eq!(
+            Color::named("rebecca purple"),
+            Some(Color::new(102, 51, 153))
+        );
+        assert_eq!(
+            Color::from_name("blurple"),
+            Err(ColorError::UnknownName("blurple".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_hsl_hsv_and_hex_round_trip() {
+        assert_eq!(Color::from_hsl(120.0, 1.0, 0.25), Color::GREEN);
+        assert_eq!(Color::from_hsl(-60.0, 1.0, 0.5), Color::MAGENTA);
+        assert_eq!(Color::from_hsl(200.0, 0.0, 0.5), Color::new(128, 128, 128));
+        assert_eq!(Color::from_hsv(60.0, 1.0, 1.0), Color::YELLOW);
+        assert_eq!(Color::from_hsv(207.0, 0.61, 0.71), Color::new(71, 131, 181));
+        assert_eq!(Color::from_hsv(f32::NAN, 2.0, 1.0), Color::RED);
+
+        for color in [Color::new(70, 130, 180), Color::new_rgba(1, 2, 3, 4)] {
+            assert_eq!(Color::from_hex(&color.to_hex()).unwrap(), color);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_color_serde_accepts_hex_and_names() {
+        assert_eq!(serde_json::to_string(&Color::RED).unwrap(), "\"#ff0000\"");
+        let parsed: Vec<Color> = serde_json::from_str(r##"["#4682b4", "steelblue"]"##).unwrap();
+        assert_eq!(parsed[0], parsed[1]);
+        assert!(serde_json::from_str::<Color>("\"blurple\"").is_err());
+    }
+
     #[test]
     fn test_predefined_colors() {
         assert_eq!(Color::RED, Color::new(255, 0, 0));
@@ -33,7 +33,7 @@ pub use primitives::{Arc, Arrow, Polygon, Wedge};
 #[cfg(feature = "simd")]
 pub use simd::{CoordinateBounds, PixelViewport, SIMDPerformanceInfo, SIMDTransformer};
 pub use skia::SkiaRenderer;
-pub use style::{LineStyle, MarkerStyle};
+pub use style::{LineStyle, MarkerPaint, MarkerStyle};
 pub use text::{FontConfig, FontFamily, FontStyle, FontWeight};
 pub use text::{
     TextRenderer, get_font_system, get_swash_cache, initialize_text_system, register_font_bytes,
@@ -7,8 +7,27 @@ use crate::{
 
 #[cfg(feature = "simd")]
 use crate::render::simd::{CoordinateBounds, PixelViewport, SIMDTransformer};
-use rayon::prelude::*;
-use std::sync::{Arc, Mutex};
+use rayon::{ThreadPool, prelude::*};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Pool with `threads` workers, built once per thread count and shared by
+/// every renderer that asks for that count
+fn shared_pool(threads: usize) -> Option<Arc<ThreadPool>> {
+    static POOLS: OnceLock<Mutex<HashMap<usize, Arc<ThreadPool>>>> = OnceLock::new();
+    let mut pools = POOLS.get_or_init(Default::default).lock().ok()?;
+    if let Some(pool) = pools.get(&threads) {
+        return Some(Arc::clone(pool));
+    }
+    let pool = Arc::new(
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .ok()?,
+    );
+    pools.insert(threads, Arc::clone(&pool));
+    Some(pool)
+}
 
 /// Parallel rendering engine for high-performance plot rendering
 ///
@@ -24,6 +43,8 @@ pub struct ParallelRenderer {
     chunked_processing: bool,
     /// Chunk size for processing large series
     chunk_size: usize,
+    /// Caller-supplied pool that parallel work runs on
+    pool: Option<Arc<ThreadPool>>,
     /// SIMD transformer for vectorized coordinate operations
     #[cfg(feature = "simd")]
     simd_transformer: SIMDTransformer,
@@ -37,18 +58,55 @@ impl ParallelRenderer {
             parallel_threshold: 2,
             chunked_processing: true,
             chunk_size: 10_000,
+            pool: None,
             #[cfg(feature = "simd")]
             simd_transformer: SIMDTransformer::new(),
         }
     }
 
     /// Create parallel renderer with custom thread count
+    ///
+    /// A count other than the global rayon pool's runs on a pool shared by
+    /// every renderer configured with that count, built on first use.
     pub fn with_threads(threads: usize) -> Self {
         let mut renderer = Self::new();
         renderer.max_threads = threads.max(1);
         renderer
     }
 
+    /// Run parallel work on `pool` instead of the global rayon pool
+    ///
+    /// Share one pool across many renderers to keep batch workloads from
+    /// setting up executors per plot.
+    pub fn with_pool(mut self, pool: Arc<ThreadPool>) -> Self {
+        self.max_threads = pool.current_num_threads();
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Pool this renderer's work runs on; `None` means the global rayon pool
+    pub(crate) fn pool(&self) -> Option<Arc<ThreadPool>> {
+        if let Some(pool) = &self.pool {
+            return Some(Arc::clone(pool));
+        }
+        if self.max_threads != rayon::current_num_threads() {
+            return shared_pool(self.max_threads);
+        }
+        None
+    }
+
+    /// Run `op` on this renderer's pool
+    fn install<R, F>(&self, op: F) -> R
+    where
+        R: Send,
+        F: FnOnce() -> R + Send,
+    {
+        match self.pool() {
+            Some(pool) => pool.install(op),
+            None => op(),
+        }
+    }
+
     /// Create parallel renderer with SIMD configuration
     #[cfg(feature = "simd")]
     pub fn with_simd(mut self, simd_threshold: usize) -> Self {
@@ -73,7 +131,7 @@ impl ParallelRenderer {
     pub fn thread_config(&self) -> ParallelConfig {
         ParallelConfig {
             max_threads: self.max_threads,
-            current_threads: rayon::current_num_threads(),
+            current_threads: self.install(rayon::current_num_threads),
             parallel_threshold: self.parallel_threshold,
             chunked_processing: self.chunked_processing,
             chunk_size: self.chunk_size,
@@ -105,35 +163,13 @@ impl ParallelRenderer {
                 .collect();
         }
 
-        // Configure thread pool if needed
-        let pool_result = if rayon::current_num_threads() != self.max_threads {
-            rayon::ThreadPoolBuilder::new()
-                .num_threads(self.max_threads)
-                .build_global()
-        } else {
-            Ok(())
-        };
-
-        let results: Result<Vec<SeriesRenderData>> = match pool_result {
-            Ok(_) => {
-                // Use parallel processing
-                series_data
-                    .par_iter()
-                    .enumerate()
-                    .map(|(i, data)| processor(data, i))
-                    .collect()
-            }
-            Err(_) => {
-                // Fallback to sequential if thread pool creation fails
-                series_data
-                    .iter()
-                    .enumerate()
-                    .map(|(i, data)| processor(data, i))
-                    .collect()
-            }
-        };
-
-        results
+        self.install(|| {
+            series_data
+                .par_iter()
+                .enumerate()
+                .map(|(i, data)| processor(data, i))
+                .collect()
+        })
     }
 
     /// Transform coordinates in parallel chunks with SIMD acceleration and memory pooling
@@ -200,17 +236,19 @@ impl ParallelRenderer {
 
         let chunks: Vec<&[f64]> = x_data.chunks(self.chunk_size).collect();
         let y_chunks: Vec<&[f64]> = y_data.chunks(self.chunk_size).collect();
-        let chunk_results: Vec<Vec<Point2f>> = chunks
-            .par_iter()
-            .zip(y_chunks.par_iter())
-            .map(|(x_chunk, y_chunk)| {
-                x_chunk
-                    .iter()
-                    .zip(y_chunk.iter())
-                    .map(|(&x, &y)| project(x, y))
-                    .collect()
-            })
-            .collect();
+        let chunk_results: Vec<Vec<Point2f>> = self.install(|| {
+            chunks
+                .par_iter()
+                .zip(y_chunks.par_iter())
+                .map(|(x_chunk, y_chunk)| {
+                    x_chunk
+                        .iter()
+                        .zip(y_chunk.iter())
+                        .map(|(&x, &y)| project(x, y))
+                        .collect()
+                })
+                .collect()
+        });
 
         for chunk_result in chunk_results {
             output_vec.extend(chunk_result);
@@ -291,19 +329,21 @@ impl ParallelRenderer {
                 let y_chunks: Vec<&[f64]> = y_data.chunks(self.chunk_size).collect();
 
                 // Process chunks in parallel, each using memory-optimized SIMD
-                let chunk_results: Result<Vec<Vec<Point2f>>> = chunks
-                    .par_iter()
-                    .zip(y_chunks.par_iter())
-                    .map(|(x_chunk, y_chunk)| {
-                        // Each thread gets its own memory manager access
-                        self.simd_transformer.transform_coordinates_simd(
-                            x_chunk,
-                            y_chunk,
-                            simd_bounds.clone(),
-                            viewport.clone(),
-                        )
-                    })
-                    .collect();
+                let chunk_results: Result<Vec<Vec<Point2f>>> = self.install(|| {
+                    chunks
+                        .par_iter()
+                        .zip(y_chunks.par_iter())
+                        .map(|(x_chunk, y_chunk)| {
+                            // Each thread gets its own memory manager access
+                            self.simd_transformer.transform_coordinates_simd(
+                                x_chunk,
+                                y_chunk,
+                                simd_bounds.clone(),
+                                viewport.clone(),
+                            )
+                        })
+                        .collect()
+                });
 
                 match chunk_results {
                     Ok(results) => {
@@ -380,30 +420,32 @@ impl ParallelRenderer {
         let chunk_size = self.chunk_size;
         let chunk_count = points.len().div_ceil(chunk_size);
 
-        let segments: Vec<LineSegment> = (0..chunk_count)
-            .into_par_iter()
-            .map(|chunk_idx| {
-                let start_idx = chunk_idx * chunk_size;
-                let end_idx = ((chunk_idx + 1) * chunk_size + 1).min(points.len());
+        let segments: Vec<LineSegment> = self.install(|| {
+            (0..chunk_count)
+                .into_par_iter()
+                .map(|chunk_idx| {
+                    let start_idx = chunk_idx * chunk_size;
+                    let end_idx = ((chunk_idx + 1) * chunk_size + 1).min(points.len());
 
-                if start_idx >= points.len() - 1 {
-                    return Vec::new();
-                }
+                    if start_idx >= points.len() - 1 {
+                        return Vec::new();
+                    }
 
-                let chunk = &points[start_idx..end_idx];
-                chunk
-                    .windows(2)
-                    .map(|segment| LineSegment {
-                        start: segment[0],
-                        end: segment[1],
-                        style: line_style.clone(),
-                        color,
-                        width: line_width,
-                    })
-                    .collect::<Vec<LineSegment>>()
-            })
-            .flatten()
-            .collect();
+                    let chunk = &points[start_idx..end_idx];
+                    chunk
+                        .windows(2)
+                        .map(|segment| LineSegment {
+                            start: segment[0],
+                            end: segment[1],
+                            style: line_style.clone(),
+                            color,
+                            width: line_width,
+                        })
+                        .collect::<Vec<LineSegment>>()
+                })
+                .flatten()
+                .collect()
+        });
 
         Ok(segments)
     }
@@ -430,21 +472,23 @@ impl ParallelRenderer {
         }
 
         // Parallel processing
-        let markers: Vec<MarkerInstance> = points
-            .par_chunks(self.chunk_size)
-            .map(|chunk| {
-                chunk
-                    .iter()
-                    .map(|&point| MarkerInstance {
-                        position: point,
-                        style: marker_style,
-                        color,
-                        size,
-                    })
-                    .collect::<Vec<MarkerInstance>>()
-            })
-            .flatten()
-            .collect();
+        let markers: Vec<MarkerInstance> = self.install(|| {
+            points
+                .par_chunks(self.chunk_size)
+                .map(|chunk| {
+                    chunk
+                        .iter()
+                        .map(|&point| MarkerInstance {
+                            position: point,
+                            style: marker_style,
+                            color,
+                            size,
+                        })
+                        .collect::<Vec<MarkerInstance>>()
+                })
+                .flatten()
+                .collect()
+        });
 
         Ok(markers)
     }
@@ -465,7 +509,7 @@ impl ParallelRenderer {
         PerformanceStats {
             available_threads: num_cpus::get(),
             configured_threads: self.max_threads,
-            active_threads: rayon::current_num_threads(),
+            active_threads: self.install(rayon::current_num_threads),
             parallel_threshold: self.parallel_threshold,
             chunked_processing: self.chunked_processing,
             chunk_size: self.chunk_size,
@@ -572,6 +616,8 @@ pub enum RenderSeriesType {
         cells: Vec<HeatmapCell>,
         n_rows: usize,
         n_cols: usize,
+        /// Stroke around every cell as (color, width in pixels)
+        cell_border: Option<(Color, f32)>,
     },
 }
 
@@ -956,4 +1002,39 @@ mod tests {
         assert_eq!(points, 77);
         assert_eq!(time, std::time::Duration::from_millis(17));
     }
+
+    #[test]
+    fn test_renderers_share_pools() {
+        let threads = rayon::current_num_threads() + 1;
+        let first = shared_pool(threads).unwrap();
+        let second = shared_pool(threads).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(
+            ParallelRenderer::with_threads(threads).install(rayon::current_num_threads),
+            threads
+        );
+
+        let pool = Arc::new(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(3)
+                .build()
+                .unwrap(),
+        );
+        let renderer = ParallelRenderer::new().with_pool(Arc::clone(&pool));
+        assert_eq!(renderer.max_threads, 3);
+        assert_eq!(renderer.thread_config().current_threads, 3);
+        let on_pool = renderer
+            .process_series_parallel(&[0, 1, 2], |_, _| {
+                assert!(pool.current_thread_index().is_some());
+                Ok(SeriesRenderData {
+                    series_type: RenderSeriesType::Line { segments: vec![] },
+                    color: Color::BLACK,
+                    line_width: 1.0,
+                    alpha: 1.0,
+                    label: None,
+                })
+            })
+            .unwrap();
+        assert_eq!(on_pool.len(), 3);
+    }
 }
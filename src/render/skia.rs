@@ -1,13 +1,15 @@
 use crate::{
     core::{
-        ComputedMargins, CoordinateTransform, LayoutRect, Legend, LegendItem, LegendItemType,
-        LegendPosition, LegendSpacingPixels, LegendStyle, PlottingError, RenderScale, Result,
-        SpacingConfig, SpineConfig, TextPosition, TickFormatter, find_best_position,
+        ComputedMargins, CoordinateTransform, HatchPattern, LayoutRect, Legend, LegendItem,
+        LegendItemType, LegendPosition, LegendSpacingPixels, LegendStyle, PlottingError,
+        RenderScale, Result, SpacingConfig, SpineConfig, TextPosition, TickFormatter,
+        TickLabelFormatter, find_best_position,
         plot::{Image, RenderDiagnostics, TextEngineMode, TickDirection, TickSides},
         pt_to_px,
     },
     render::{
-        Color, FontConfig, FontFamily, FontWeight, LineStyle, MarkerStyle, TextRenderer, Theme,
+        Color, FontConfig, FontFamily, FontWeight, LineStyle, MarkerPaint, MarkerStyle,
+        TextRenderer, Theme,
         typst_text::{self, TypstBackendKind, TypstTextAnchor},
     },
 };
@@ -28,7 +30,7 @@ pub use self::utils::{
 };
 pub(crate) use self::utils::{
     colorbar_major_label_anchor_center_from_top, colorbar_major_label_top,
-    compute_colorbar_layout_metrics,
+    compute_colorbar_layout_metrics, compute_colorbar_ticks_with_options, format_axis_tick_labels,
 };
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -131,6 +133,20 @@ pub(crate) struct MarkerSprite {
     pub scanlines: Option<Arc<[MarkerSpriteScanline]>>,
 }
 
+/// Where a legend's frame, title, and entries land on the canvas
+struct LegendPlacement {
+    /// Legend scaled to the render DPI
+    legend: Legend,
+    spacing: LegendSpacingPixels,
+    /// Frame as `(x, y, width, height)`
+    frame: (f32, f32, f32, f32),
+    /// Center y of the title row, when the legend has a title
+    title_y: Option<f32>,
+    col_width: f32,
+    /// `(item index, column x, row center y)` for each entry that fits
+    entries: Vec<(usize, f32, f32)>,
+}
+
 /// Tiny-skia based renderer with cosmic-text for professional typography
 pub struct SkiaRenderer {
     width: u32,
@@ -144,10 +160,18 @@ pub struct SkiaRenderer {
     render_scale: RenderScale,
     /// Active text rendering engine.
     text_engine_mode: TextEngineMode,
+    /// Whether shapes are drawn with anti-aliasing.
+    antialias: bool,
     clip_mask_cache: HashMap<ClipMaskKey, Arc<Mask>>,
     marker_path_cache: HashMap<MarkerPathKey, Arc<tiny_skia::Path>>,
     marker_sprite_cache: HashMap<MarkerSpriteKey, Arc<MarkerSprite>>,
     render_diagnostics: RenderDiagnostics,
+    /// Custom X-axis tick label formatter
+    x_tick_formatter: Option<TickLabelFormatter>,
+    /// Custom Y-axis tick label formatter
+    y_tick_formatter: Option<TickLabelFormatter>,
+    /// Counter-clockwise X tick label rotation in degrees
+    xtick_label_rotation: f32,
 }
 
 impl SkiaRenderer {
@@ -186,10 +210,14 @@ impl SkiaRenderer {
             font_config,
             render_scale: RenderScale::from_canvas_size(width, height, crate::core::REFERENCE_DPI),
             text_engine_mode: TextEngineMode::Plain,
+            antialias: true,
             clip_mask_cache: HashMap::new(),
             marker_path_cache: HashMap::new(),
             marker_sprite_cache: HashMap::new(),
             render_diagnostics: RenderDiagnostics::default(),
+            x_tick_formatter: None,
+            y_tick_formatter: None,
+            xtick_label_rotation: 0.0,
         })
     }
 
@@ -240,6 +268,36 @@ impl SkiaRenderer {
         self.text_engine_mode = mode;
     }
 
+    /// Enable or disable anti-aliasing of lines, markers, and fills.
+    ///
+    /// Text is always anti-aliased.
+    pub fn set_antialias(&mut self, enabled: bool) {
+        if self.antialias != enabled {
+            self.antialias = enabled;
+            self.marker_sprite_cache.clear();
+        }
+    }
+
+    /// Whether lines, markers, and fills are anti-aliased.
+    pub fn antialias(&self) -> bool {
+        self.antialias
+    }
+
+    /// Set custom tick label formatters used when drawing axis tick labels.
+    pub fn set_tick_label_formatters(
+        &mut self,
+        x_formatter: Option<TickLabelFormatter>,
+        y_formatter: Option<TickLabelFormatter>,
+    ) {
+        self.x_tick_formatter = x_formatter;
+        self.y_tick_formatter = y_formatter;
+    }
+
+    /// Set the counter-clockwise rotation, in degrees, applied to X tick labels.
+    pub fn set_xtick_label_rotation(&mut self, degrees: f32) {
+        self.xtick_label_rotation = degrees;
+    }
+
     /// Get text rendering backend mode.
     pub fn text_engine_mode(&self) -> TextEngineMode {
         self.text_engine_mode
@@ -420,6 +478,7 @@ impl SkiaRenderer {
         let mut sprite_renderer = SkiaRenderer::new(side, side, self.theme.clone())?;
         sprite_renderer.set_render_scale(self.render_scale);
         sprite_renderer.set_text_engine_mode(self.text_engine_mode);
+        sprite_renderer.set_antialias(self.antialias);
         sprite_renderer.pixmap.fill(tiny_skia::Color::TRANSPARENT);
 
         let phase_step = 1.0 / Self::marker_subpixel_phases() as f32;
@@ -807,6 +866,46 @@ impl SkiaRenderer {
         minor_tick_size: f32,
         major_tick_width: f32,
         minor_tick_width: f32,
+    ) -> Result<()> {
+        self.draw_axes_with_spine_origin(
+            plot_area,
+            x_major_ticks,
+            y_major_ticks,
+            x_minor_ticks,
+            y_minor_ticks,
+            tick_direction,
+            tick_sides,
+            spines,
+            color,
+            axis_width,
+            major_tick_size,
+            minor_tick_size,
+            major_tick_width,
+            minor_tick_width,
+            None,
+        )
+    }
+
+    /// Like [`Self::draw_axes_with_minor_ticks_styled`], optionally drawing
+    /// the left and bottom spines through `spine_origin` (pixel x, y) instead
+    /// of along the plot-area border.
+    pub(crate) fn draw_axes_with_spine_origin(
+        &mut self,
+        plot_area: Rect,
+        x_major_ticks: &[f32],
+        y_major_ticks: &[f32],
+        x_minor_ticks: &[f32],
+        y_minor_ticks: &[f32],
+        tick_direction: &TickDirection,
+        tick_sides: &TickSides,
+        spines: &SpineConfig,
+        color: Color,
+        axis_width: f32,
+        major_tick_size: f32,
+        minor_tick_size: f32,
+        major_tick_width: f32,
+        minor_tick_width: f32,
+        spine_origin: Option<(f32, f32)>,
     ) -> Result<()> {
         fn snap_stroke_coord(coord: f32, width: f32) -> f32 {
             if !coord.is_finite() || !width.is_finite() {
@@ -830,9 +929,13 @@ impl SkiaRenderer {
         let plot_right = snap_endpoint(plot_area.right());
         let plot_top = snap_endpoint(plot_area.top());
         let plot_bottom = snap_endpoint(plot_area.bottom());
-        let bottom_spine_y = snap_stroke_coord(plot_area.bottom() + spine_offset, axis_width);
+        let (left_spine_x, bottom_spine_y) = spine_origin.unwrap_or((
+            plot_area.left() - spine_offset,
+            plot_area.bottom() + spine_offset,
+        ));
+        let bottom_spine_y = snap_stroke_coord(bottom_spine_y, axis_width);
         let top_spine_y = snap_stroke_coord(plot_area.top() - spine_offset, axis_width);
-        let left_spine_x = snap_stroke_coord(plot_area.left() - spine_offset, axis_width);
+        let left_spine_x = snap_stroke_coord(left_spine_x, axis_width);
         let right_spine_x = snap_stroke_coord(plot_area.right() + spine_offset, axis_width);
 
         if spines.bottom {
@@ -1320,6 +1423,112 @@ impl SkiaRenderer {
         }
     }
 
+    /// Draw text rotated counter-clockwise by `degrees` around its center at `(x, y)`.
+    pub fn draw_text_rotated_angle(
+        &mut self,
+        text: &str,
+        x: f32,
+        y: f32,
+        size: f32,
+        color: Color,
+        degrees: f32,
+    ) -> Result<()> {
+        match self.text_engine_mode {
+            TextEngineMode::Plain => {
+                let config = FontConfig::new(self.font_config.family.clone(), size);
+                let metrics = self.text_renderer.measure_text_placement(text, &config)?;
+                let glyph_guard = size.ceil().max(2.0);
+                let layer_width = (metrics.width + 2.0 * glyph_guard).ceil().max(1.0) as u32;
+                let layer_height = (metrics.height + 2.0 * glyph_guard).ceil().max(1.0) as u32;
+                crate::render::text::validate_text_raster_size(
+                    layer_width,
+                    layer_height,
+                    "Rotated text",
+                )?;
+                let mut layer = Pixmap::new(layer_width, layer_height).ok_or(
+                    PlottingError::RenderError("Failed to allocate rotated text layer".to_string()),
+                )?;
+                layer.fill(tiny_skia::Color::TRANSPARENT);
+                self.text_renderer.render_text(
+                    &mut layer,
+                    text,
+                    glyph_guard,
+                    glyph_guard,
+                    &config,
+                    color,
+                )?;
+                let transform = Transform::from_translate(
+                    -(glyph_guard + metrics.width / 2.0),
+                    -(glyph_guard + metrics.height / 2.0),
+                )
+                .post_rotate(-degrees)
+                .post_translate(x, y);
+                let paint = PixmapPaint {
+                    quality: FilterQuality::Bilinear,
+                    ..PixmapPaint::default()
+                };
+                self.pixmap
+                    .draw_pixmap(0, 0, layer.as_ref(), &paint, transform, None);
+                Ok(())
+            }
+            #[cfg(feature = "typst-math")]
+            TextEngineMode::Typst => {
+                let size_pt = self.typst_size_pt(size);
+                let rendered = typst_text::render_raster_with_font_family(
+                    text,
+                    size_pt,
+                    color,
+                    -degrees,
+                    &self.font_config.family,
+                    "Skia rotated text rendering",
+                )?;
+                let (draw_x, draw_y) = typst_text::anchored_top_left(
+                    x,
+                    y,
+                    rendered.width,
+                    rendered.height,
+                    TypstTextAnchor::Center,
+                );
+                self.draw_typst_raster(&rendered, draw_x, draw_y);
+                Ok(())
+            }
+        }
+    }
+
+    /// Draw one X tick label below `top_y`, honoring the configured label rotation.
+    fn draw_xtick_label(
+        &mut self,
+        label: &str,
+        tick_x: f32,
+        top_y: f32,
+        size: f32,
+        color: Color,
+    ) -> Result<()> {
+        let (text_width, text_height) = self.measure_text(label, size)?;
+        if self.xtick_label_rotation.abs() <= f32::EPSILON {
+            let label_x = (tick_x - text_width / 2.0)
+                .max(0.0)
+                .min(self.width() as f32 - text_width);
+            return self.draw_text(label, label_x, top_y, size, color);
+        }
+
+        let (center_x, center_y) = crate::render::text_anchor::rotated_xtick_label_center(
+            tick_x,
+            top_y,
+            text_width,
+            text_height,
+            self.xtick_label_rotation,
+        );
+        self.draw_text_rotated_angle(
+            label,
+            center_x,
+            center_y,
+            size,
+            color,
+            self.xtick_label_rotation,
+        )
+    }
+
     /// Draw text centered horizontally at the given position.
     /// `y` is interpreted as the top of the text rendering area.
     pub fn draw_text_centered(
@@ -1435,8 +1644,17 @@ impl SkiaRenderer {
     }
 
     pub(crate) fn measure_label_text(&self, text: &str, size: f32) -> Result<(f32, f32)> {
+        self.measure_label_text_with_weight(text, size, FontWeight::Normal)
+    }
+
+    pub(crate) fn measure_label_text_with_weight(
+        &self,
+        text: &str,
+        size: f32,
+        weight: FontWeight,
+    ) -> Result<(f32, f32)> {
         let label_snippet = self.generated_label(text);
-        self.measure_text(&label_snippet, size)
+        self.measure_text_with_weight(&label_snippet, size, weight)
     }
 
     fn generated_label<'a>(&self, text: &'a str) -> Cow<'a, str> {
@@ -1797,7 +2015,7 @@ impl SkiaRenderer {
         // Create border paint
         let mut paint = tiny_skia::Paint::default();
         paint.set_color_rgba8(color.r, color.g, color.b, color.a);
-        paint.anti_alias = true;
+        paint.anti_alias = self.antialias;
 
         // Create stroke
         let stroke = tiny_skia::Stroke {
@@ -1975,8 +2193,8 @@ impl SkiaRenderer {
             position: None,
         })?;
 
-        let x_labels = format_tick_labels_for_scale(x_ticks, x_scale);
-        let y_labels = format_tick_labels_for_scale(y_ticks, y_scale);
+        let x_labels = format_axis_tick_labels(x_ticks, x_scale, self.x_tick_formatter.as_ref());
+        let y_labels = format_axis_tick_labels(y_ticks, y_scale, self.y_tick_formatter.as_ref());
 
         if show_tick_labels {
             for (tick_value, label_text) in x_ticks.iter().zip(x_labels.iter()) {
@@ -1984,11 +2202,7 @@ impl SkiaRenderer {
                     Self::x_label_center_scaled(plot_area, *tick_value, x_min, x_max, x_scale);
 
                 let label_snippet = self.generated_label(label_text);
-                let (text_width, _) = self.measure_text(&label_snippet, tick_size)?;
-                let label_x = (x_pixel - text_width / 2.0)
-                    .max(0.0)
-                    .min(self.width() as f32 - text_width);
-                self.draw_text(&label_snippet, label_x, xtick_baseline_y, tick_size, color)?;
+                self.draw_xtick_label(&label_snippet, x_pixel, xtick_baseline_y, tick_size, color)?;
             }
 
             for (tick_value, label_text) in y_ticks.iter().zip(y_labels.iter()) {
@@ -2001,6 +2215,15 @@ impl SkiaRenderer {
                 let centered_y = y_pixel - text_height / 2.0;
                 self.draw_text(&label_snippet, label_x, centered_y, tick_size, color)?;
             }
+
+            self.draw_tick_offset_texts(
+                plot_area,
+                x_ticks,
+                y_ticks,
+                xtick_baseline_y,
+                tick_size,
+                color,
+            )?;
         }
 
         if draw_border {
@@ -2010,6 +2233,43 @@ impl SkiaRenderer {
         Ok(())
     }
 
+    /// Draw the `×10ⁿ` offset text of axes whose formatter factored out a
+    /// common power of ten: X at the right end below its tick labels, Y above
+    /// the top of the axis
+    fn draw_tick_offset_texts(
+        &mut self,
+        plot_area: &LayoutRect,
+        x_ticks: &[f64],
+        y_ticks: &[f64],
+        xtick_baseline_y: f32,
+        tick_size: f32,
+        color: Color,
+    ) -> Result<()> {
+        let x_offset = self
+            .x_tick_formatter
+            .as_ref()
+            .and_then(|formatter| formatter.offset_text(x_ticks));
+        if let Some(text) = x_offset {
+            let (text_width, text_height) = self.measure_text(&text, tick_size)?;
+            let x = (plot_area.right - text_width).max(0.0);
+            let y = (xtick_baseline_y + text_height * 1.2)
+                .min(self.height() as f32 - text_height)
+                .max(0.0);
+            self.draw_text(&text, x, y, tick_size, color)?;
+        }
+
+        let y_offset = self
+            .y_tick_formatter
+            .as_ref()
+            .and_then(|formatter| formatter.offset_text(y_ticks));
+        if let Some(text) = y_offset {
+            let (_, text_height) = self.measure_text(&text, tick_size)?;
+            let y = (plot_area.top - text_height * 1.4).max(0.0);
+            self.draw_text(&text, plot_area.left, y, tick_size, color)?;
+        }
+        Ok(())
+    }
+
     /// Draw axis tick labels with categorical x-axis labels for bar charts
     ///
     /// Similar to `draw_axis_labels_at` but uses category names instead of numeric ticks
@@ -2055,16 +2315,21 @@ impl SkiaRenderer {
                     let x_center = Self::x_label_center(plot_area, i as f64, x_min, x_max);
 
                     let label_snippet = self.generated_label(category);
-                    let (text_width, _) = self.measure_text(&label_snippet, tick_size)?;
-                    let label_x = (x_center - text_width / 2.0)
-                        .max(0.0)
-                        .min(self.width() as f32 - text_width);
-
-                    self.draw_text(&label_snippet, label_x, xtick_baseline_y, tick_size, color)?;
+                    self.draw_xtick_label(
+                        &label_snippet,
+                        x_center,
+                        xtick_baseline_y,
+                        tick_size,
+                        color,
+                    )?;
                 }
             }
 
-            let y_labels = format_tick_labels(y_ticks);
+            let y_labels = format_axis_tick_labels(
+                y_ticks,
+                &crate::axes::AxisScale::Linear,
+                self.y_tick_formatter.as_ref(),
+            );
             for (tick_value, label_text) in y_ticks.iter().zip(y_labels.iter()) {
                 let y_pixel = Self::y_label_center(plot_area, *tick_value, y_min, y_max);
 
@@ -2074,6 +2339,15 @@ impl SkiaRenderer {
                 let centered_y = y_pixel - text_height / 2.0;
                 self.draw_text(&label_snippet, label_x, centered_y, tick_size, color)?;
             }
+
+            self.draw_tick_offset_texts(
+                plot_area,
+                &[],
+                y_ticks,
+                xtick_baseline_y,
+                tick_size,
+                color,
+            )?;
         }
 
         if draw_border {
@@ -2136,15 +2410,20 @@ impl SkiaRenderer {
                 let x_center = Self::x_label_center(plot_area, x_pos, x_min, x_max);
 
                 let label_snippet = self.generated_label(category);
-                let (text_width, _) = self.measure_text(&label_snippet, tick_size)?;
-                let label_x = (x_center - text_width / 2.0)
-                    .max(0.0)
-                    .min(self.width() as f32 - text_width);
-
-                self.draw_text(&label_snippet, label_x, xtick_baseline_y, tick_size, color)?;
+                self.draw_xtick_label(
+                    &label_snippet,
+                    x_center,
+                    xtick_baseline_y,
+                    tick_size,
+                    color,
+                )?;
             }
 
-            let y_labels = format_tick_labels(y_ticks);
+            let y_labels = format_axis_tick_labels(
+                y_ticks,
+                &crate::axes::AxisScale::Linear,
+                self.y_tick_formatter.as_ref(),
+            );
             for (tick_value, label_text) in y_ticks.iter().zip(y_labels.iter()) {
                 let y_pixel = Self::y_label_center(plot_area, *tick_value, y_min, y_max);
 
@@ -2154,6 +2433,15 @@ impl SkiaRenderer {
                 let centered_y = y_pixel - text_height / 2.0;
                 self.draw_text(&label_snippet, label_x, centered_y, tick_size, color)?;
             }
+
+            self.draw_tick_offset_texts(
+                plot_area,
+                &[],
+                y_ticks,
+                xtick_baseline_y,
+                tick_size,
+                color,
+            )?;
         }
 
         if draw_border {
@@ -2622,27 +2910,15 @@ impl SkiaRenderer {
             return self.draw_rectangle_outline(x, y, width, height, color, line_width);
         }
 
-        // Build rounded rectangle path
-        let mut pb = PathBuilder::new();
-
-        pb.move_to(x + radius, y);
-        pb.line_to(x + width - radius, y);
-        pb.quad_to(x + width, y, x + width, y + radius);
-        pb.line_to(x + width, y + height - radius);
-        pb.quad_to(x + width, y + height, x + width - radius, y + height);
-        pb.line_to(x + radius, y + height);
-        pb.quad_to(x, y + height, x, y + height - radius);
-        pb.line_to(x, y + radius);
-        pb.quad_to(x, y, x + radius, y);
-        pb.close();
-
-        let path = pb.finish().ok_or(PlottingError::RenderError(
-            "Failed to create rounded rectangle outline path".to_string(),
-        ))?;
+        let path = primitives::rounded_rect_path(x, y, width, height, radius).ok_or(
+            PlottingError::RenderError(
+                "Failed to create rounded rectangle outline path".to_string(),
+            ),
+        )?;
 
         let mut paint = Paint::default();
         paint.set_color(color.to_tiny_skia_color());
-        paint.anti_alias = true;
+        paint.anti_alias = self.antialias;
 
         let stroke = Stroke {
             width: line_width,
@@ -2757,12 +3033,104 @@ impl SkiaRenderer {
         data_bboxes: Option<&[(f32, f32, f32, f32)]>,
         resolved_rect: Option<(f32, f32, f32, f32)>,
     ) -> Result<()> {
-        if items.is_empty() || !legend.enabled {
+        let Some(placement) =
+            self.legend_placement(items, legend, plot_area, data_bboxes, resolved_rect)
+        else {
             return Ok(());
+        };
+        let legend = &placement.legend;
+        let (legend_x, legend_y, legend_width, legend_height) = placement.frame;
+
+        // Draw frame
+        self.draw_legend_frame(
+            legend_x,
+            legend_y,
+            legend_width,
+            legend_height,
+            &legend.style,
+        )?;
+
+        // Draw the title in bold, placed on its row like the entry labels
+        if let (Some(title), Some(title_y)) = (&legend.title, placement.title_y) {
+            let title_x = legend_x + legend_width / 2.0;
+            self.draw_text_centered_with_weight(
+                title,
+                title_x,
+                title_y - legend.font_size * 0.65,
+                legend.font_size,
+                legend.text_color,
+                FontWeight::Bold,
+            )?;
+        }
+
+        for &(idx, col_x, row_y) in &placement.entries {
+            let item = &items[idx];
+
+            // Draw handle
+            self.draw_legend_handle(item, col_x, row_y, &placement.spacing)?;
+
+            // Draw label - vertically centered with handle
+            let text_x =
+                col_x + placement.spacing.handle_length + placement.spacing.handle_text_pad;
+            // Center text vertically on handle
+            let centered_y = row_y - legend.font_size * 0.65;
+            self.draw_text(
+                &item.label,
+                text_x,
+                centered_y,
+                legend.font_size,
+                legend.text_color,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Pixel bounds of each legend entry drawn by
+    /// [`draw_legend_full_resolved`](Self::draw_legend_full_resolved), as
+    /// `(item index, rect)` pairs covering the handle, label, and row gap.
+    ///
+    /// Entries clipped by a capped legend rectangle are left out.
+    pub(crate) fn legend_entry_rects(
+        &self,
+        items: &[LegendItem],
+        legend: &Legend,
+        plot_area: Rect,
+        resolved_rect: Option<(f32, f32, f32, f32)>,
+    ) -> Vec<(usize, Rect)> {
+        let Some(placement) = self.legend_placement(items, legend, plot_area, None, resolved_rect)
+        else {
+            return Vec::new();
+        };
+        let row_height = placement.legend.font_size + placement.spacing.label_spacing;
+        placement
+            .entries
+            .iter()
+            .filter_map(|&(idx, col_x, row_y)| {
+                Rect::from_xywh(
+                    col_x,
+                    row_y - row_height / 2.0,
+                    placement.col_width,
+                    row_height,
+                )
+                .map(|rect| (idx, rect))
+            })
+            .collect()
+    }
+
+    fn legend_placement(
+        &self,
+        items: &[LegendItem],
+        legend: &Legend,
+        plot_area: Rect,
+        data_bboxes: Option<&[(f32, f32, f32, f32)]>,
+        resolved_rect: Option<(f32, f32, f32, f32)>,
+    ) -> Option<LegendPlacement> {
+        if items.is_empty() || !legend.enabled {
+            return None;
         }
 
         let legend = legend.scaled_for_render(self.render_scale);
-        let legend = &legend;
         let spacing = legend.spacing.to_pixels(legend.font_size);
 
         // Estimate character width for size calculation
@@ -2771,7 +3139,7 @@ impl SkiaRenderer {
         // Calculate legend size
         let (legend_width, legend_height) = resolved_rect
             .map(|(left, top, right, bottom)| (right - left, bottom - top))
-            .unwrap_or_else(|| self.calculate_legend_dimensions(items, legend, char_width));
+            .unwrap_or_else(|| self.calculate_legend_dimensions(items, &legend, char_width));
 
         // Determine position
         let plot_bounds = (
@@ -2813,25 +3181,16 @@ impl SkiaRenderer {
                 resolved_legend.calculate_position((legend_width, legend_height), plot_bounds)
             });
 
-        // Draw frame
-        self.draw_legend_frame(
-            legend_x,
-            legend_y,
-            legend_width,
-            legend_height,
-            &legend.style,
-        )?;
-
         // Starting position for items (inside padding)
         let item_x = legend_x + spacing.border_pad;
         let mut item_y = legend_y + spacing.border_pad + legend.font_size / 2.0;
 
-        // Draw title if present
-        if let Some(ref title) = legend.title {
-            let title_x = legend_x + legend_width / 2.0;
-            self.draw_text_centered(title, title_x, item_y, legend.font_size, legend.text_color)?;
+        // Leave room for the title if present
+        let title_y = legend.title.as_ref().map(|_| {
+            let title_y = item_y;
             item_y += legend.font_size + spacing.label_spacing;
-        }
+            title_y
+        });
 
         // Calculate items per column
         let items_per_col = items.len().div_ceil(legend.columns);
@@ -2853,7 +3212,8 @@ impl SkiaRenderer {
             .map(|(_, _, _, bottom)| bottom - spacing.border_pad)
             .unwrap_or(f32::INFINITY);
 
-        // Draw items column by column
+        // Place items column by column
+        let mut entries = Vec::with_capacity(items.len());
         for col in 0..legend.columns {
             let col_x = item_x + col as f32 * (col_width + spacing.column_spacing);
             let mut row_y = item_y;
@@ -2866,29 +3226,19 @@ impl SkiaRenderer {
                 if row_y > max_row_y {
                     break;
                 }
-
-                let item = &items[idx];
-
-                // Draw handle
-                self.draw_legend_handle(item, col_x, row_y, &spacing)?;
-
-                // Draw label - vertically centered with handle
-                let text_x = col_x + spacing.handle_length + spacing.handle_text_pad;
-                // Center text vertically on handle
-                let centered_y = row_y - legend.font_size * 0.65;
-                self.draw_text(
-                    &item.label,
-                    text_x,
-                    centered_y,
-                    legend.font_size,
-                    legend.text_color,
-                )?;
-
+                entries.push((idx, col_x, row_y));
                 row_y += legend.font_size + spacing.label_spacing;
             }
         }
 
-        Ok(())
+        Some(LegendPlacement {
+            legend,
+            spacing,
+            frame: (legend_x, legend_y, legend_width, legend_height),
+            title_y,
+            col_width,
+            entries,
+        })
     }
 
     /// Draw a colorbar for heatmaps
@@ -2927,10 +3277,90 @@ impl SkiaRenderer {
         label_font_size: Option<f32>,
         show_log_subticks: bool,
     ) -> Result<()> {
+        self.draw_colorbar_with_center(
+            colormap,
+            vmin,
+            vmax,
+            x,
+            y,
+            width,
+            height,
+            value_scale,
+            label,
+            foreground_color,
+            tick_font_size,
+            label_font_size,
+            show_log_subticks,
+            None,
+            &crate::plots::ColorbarConfig::default(),
+        )
+    }
+
+    /// Draw a colorbar whose colormap midpoint is anchored at `center`.
+    ///
+    /// With `Some(center)`, ticks are placed with the same two-slope
+    /// normalization used for the mapped colors, so `center` sits at the
+    /// middle of the bar. `None` behaves like [`Self::draw_colorbar`].
+    ///
+    /// `colorbar` selects the orientation, tick count, and tick label
+    /// formatter. Horizontal colorbars run from `vmin` on the left to `vmax`
+    /// on the right, with ticks and the label below the bar.
+    pub(crate) fn draw_colorbar_with_center(
+        &mut self,
+        colormap: &crate::render::ColorMap,
+        vmin: f64,
+        vmax: f64,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        value_scale: &crate::axes::AxisScale,
+        label: Option<&str>,
+        foreground_color: Color,
+        tick_font_size: f32,
+        label_font_size: Option<f32>,
+        show_log_subticks: bool,
+        center: Option<f64>,
+        colorbar: &crate::plots::ColorbarConfig,
+    ) -> Result<()> {
+        let value_position = |value: f64| {
+            match center {
+                Some(center) => crate::axes::scale::centered_normalized_position(
+                    value_scale,
+                    value,
+                    vmin,
+                    vmax,
+                    center,
+                ),
+                None => value_scale.normalized_position(value, vmin, vmax),
+            }
+            .clamp(0.0, 1.0)
+        };
         let tick_font_size_px = self.points_to_pixels(tick_font_size);
         let label_font_size_px = label_font_size
             .map(|size| self.points_to_pixels(size))
             .unwrap_or(tick_font_size_px * 1.1);
+        let ticks = compute_colorbar_ticks_with_options(
+            vmin,
+            vmax,
+            value_scale,
+            show_log_subticks,
+            colorbar.n_ticks,
+            colorbar.formatter.as_ref(),
+        );
+
+        if colorbar.orientation == crate::plots::ColorbarOrientation::Horizontal {
+            return self.draw_horizontal_colorbar(
+                colormap,
+                &ticks,
+                value_position,
+                (x, y, width, height),
+                label,
+                foreground_color,
+                tick_font_size_px,
+                label_font_size_px,
+            );
+        }
 
         // Draw the colorbar gradient (vertical, from vmax at top to vmin at bottom)
         // Use one segment per pixel row to eliminate anti-aliasing artifacts
@@ -2952,7 +3382,6 @@ impl SkiaRenderer {
         let stroke_width = self.logical_pixels_to_pixels(1.0);
         self.draw_rectangle_outline(x, y, width, height, foreground_color, stroke_width)?;
 
-        let ticks = compute_colorbar_ticks(vmin, vmax, value_scale, show_log_subticks);
         let mut measured_major_labels = Vec::with_capacity(ticks.major_labels.len());
         let mut max_label_width: f32 = 0.0;
         for label_text in &ticks.major_labels {
@@ -2980,9 +3409,7 @@ impl SkiaRenderer {
         );
 
         for minor_value in &ticks.minor_values {
-            let t = value_scale
-                .normalized_position(*minor_value, vmin, vmax)
-                .clamp(0.0, 1.0);
+            let t = value_position(*minor_value);
             let tick_y = y + height * (1.0 - t as f32);
 
             self.draw_line(
@@ -3003,9 +3430,7 @@ impl SkiaRenderer {
             .zip(measured_major_labels.iter())
         {
             // Map value to Y position (top = vmax, bottom = vmin)
-            let t = value_scale
-                .normalized_position(*value, vmin, vmax)
-                .clamp(0.0, 1.0);
+            let t = value_position(*value);
             let tick_y = y + height * (1.0 - t as f32);
 
             // Draw tick mark
@@ -3053,6 +3478,105 @@ impl SkiaRenderer {
         Ok(())
     }
 
+    /// Draw a colorbar whose gradient runs left to right, with ticks, tick
+    /// labels, and the label stacked below the bar.
+    fn draw_horizontal_colorbar(
+        &mut self,
+        colormap: &crate::render::ColorMap,
+        ticks: &ColorbarTicks,
+        value_position: impl Fn(f64) -> f64,
+        (x, y, width, height): (f32, f32, f32, f32),
+        label: Option<&str>,
+        foreground_color: Color,
+        tick_font_size_px: f32,
+        label_font_size_px: f32,
+    ) -> Result<()> {
+        // One segment per pixel column, vmin on the left
+        let num_segments = (width as usize).max(50);
+        let segment_width = width / num_segments as f32;
+        for i in 0..num_segments {
+            let normalized = i as f64 / (num_segments - 1).max(1) as f64;
+            let segment_x = x + i as f32 * segment_width;
+            self.draw_solid_rectangle(
+                segment_x,
+                y,
+                segment_width + 0.5,
+                height,
+                colormap.sample(normalized),
+            )?;
+        }
+
+        let stroke_width = self.logical_pixels_to_pixels(1.0);
+        self.draw_rectangle_outline(x, y, width, height, foreground_color, stroke_width)?;
+
+        let mut max_label_height: f32 = 0.0;
+        for label_text in &ticks.major_labels {
+            let label_snippet = self.generated_label(label_text);
+            max_label_height =
+                max_label_height.max(self.measure_text(&label_snippet, tick_font_size_px)?.1);
+        }
+        let label_height = label
+            .map(|label| self.measure_text(label, label_font_size_px))
+            .transpose()?
+            .map(|(_, height)| height);
+        // The vertical layout transposed: widths along x become heights along y
+        let layout = compute_colorbar_layout_metrics(
+            height,
+            tick_font_size_px,
+            max_label_height,
+            label_height,
+        );
+
+        for minor_value in &ticks.minor_values {
+            let tick_x = x + width * value_position(*minor_value) as f32;
+            self.draw_line(
+                tick_x,
+                y + height,
+                tick_x,
+                y + height + layout.minor_tick_width,
+                foreground_color,
+                stroke_width * 0.8,
+                LineStyle::Solid,
+            )?;
+        }
+
+        for (value, label_text) in ticks.major_values.iter().zip(ticks.major_labels.iter()) {
+            let tick_x = x + width * value_position(*value) as f32;
+            self.draw_line(
+                tick_x,
+                y + height,
+                tick_x,
+                y + height + layout.major_tick_width,
+                foreground_color,
+                stroke_width,
+                LineStyle::Solid,
+            )?;
+            let label_snippet = self.generated_label(label_text);
+            self.draw_text_centered(
+                &label_snippet,
+                tick_x,
+                y + layout.tick_label_x_offset,
+                tick_font_size_px,
+                foreground_color,
+            )?;
+        }
+
+        if let Some(((label, label_center_offset), label_height)) = label
+            .zip(layout.rotated_label_center_x_offset)
+            .zip(label_height)
+        {
+            self.draw_text_centered(
+                label,
+                x + width / 2.0,
+                y + label_center_offset - label_height / 2.0,
+                label_font_size_px,
+                foreground_color,
+            )?;
+        }
+
+        Ok(())
+    }
+
     /// Consume the renderer and convert to an `Image`.
     ///
     /// The returned pixel buffer preserves tiny-skia's native premultiplied
@@ -3080,18 +3604,33 @@ impl SkiaRenderer {
     }
 
     /// Save the current pixmap as a PNG with straight-alpha RGBA encoding.
+    ///
+    /// The file records the render scale's DPI; see [`Self::encode_png_bytes`].
     pub fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<()> {
         crate::export::write_bytes_atomic(path, &self.encode_png_bytes()?)
     }
 
     /// Encode the current pixmap as PNG bytes with straight-alpha RGBA encoding.
+    ///
+    /// The render scale's DPI is written to the `pHYs` chunk so the image
+    /// keeps its physical size when placed in documents.
     pub fn encode_png_bytes(&self) -> Result<Vec<u8>> {
         let image = Image {
             width: self.width,
             height: self.height,
             pixels: self.pixmap.clone().take_demultiplied(),
         };
-        crate::export::encode_rgba_png(&image)
+        crate::export::encode_rgba_png_with_dpi(&image, self.render_scale.dpi())
+    }
+
+    /// Encode the current pixmap as JPEG bytes, flattening transparency onto white.
+    pub fn encode_jpeg_bytes(&self, quality: u8) -> Result<Vec<u8>> {
+        let image = Image {
+            width: self.width,
+            height: self.height,
+            pixels: self.pixmap.clone().take_demultiplied(),
+        };
+        crate::export::encode_rgba_jpeg(&image, quality)
     }
 
     /// Export as SVG (simplified - tiny-skia doesn't directly support SVG export)
@@ -1,8 +1,12 @@
 use super::*;
-use crate::{axes::AxisScale, render::text_anchor::annotation_text_layout};
+use crate::{
+    axes::AxisScale,
+    render::text_anchor::{AnnotationTextLayout, annotation_text_layout},
+};
 
 struct AnnotationTransform<'a> {
     plot_area: Rect,
+    canvas_size: (f32, f32),
     x_min: f64,
     x_max: f64,
     y_min: f64,
@@ -26,6 +30,12 @@ impl AnnotationTransform<'_> {
         )
     }
 
+    fn point_in(&self, coords: crate::core::CoordSystem, x: f64, y: f64) -> (f32, f32) {
+        coords
+            .fraction_to_pixels(x, y, self.plot_area, self.canvas_size)
+            .unwrap_or_else(|| self.point(x, y))
+    }
+
     fn x_pixel(&self, x: f64) -> f32 {
         let normalized = self.x_scale.normalized_position(x, self.x_min, self.x_max);
         self.plot_area.left() + normalized as f32 * self.plot_area.width()
@@ -143,6 +153,7 @@ impl SkiaRenderer {
 
         let transform = AnnotationTransform {
             plot_area,
+            canvas_size: (self.width() as f32, self.height() as f32),
             x_min,
             x_max,
             y_min,
@@ -151,10 +162,58 @@ impl SkiaRenderer {
             y_scale,
         };
 
-        annotations
+        // Consecutive clipped annotations are drawn onto a transparent layer
+        // that is composited through the plot-area mask, keeping draw order
+        let mut clipped_canvas = None;
+        for annotation in annotations
             .iter()
             .filter(|annotation| should_draw(annotation))
-            .try_for_each(|annotation| self.draw_annotation(annotation, &transform, dpi))
+        {
+            if annotation.clips() != clipped_canvas.is_some() {
+                match clipped_canvas.take() {
+                    Some(canvas) => self.finish_clipped_annotations(canvas, plot_area)?,
+                    None => clipped_canvas = Some(self.start_clipped_annotations()?),
+                }
+            }
+            if let Err(err) = self.draw_annotation(annotation, &transform, dpi) {
+                if let Some(canvas) = clipped_canvas {
+                    self.pixmap = canvas;
+                }
+                return Err(err);
+            }
+        }
+        match clipped_canvas {
+            Some(canvas) => self.finish_clipped_annotations(canvas, plot_area),
+            None => Ok(()),
+        }
+    }
+
+    /// Swap in a transparent layer for clipped annotations, returning the canvas
+    fn start_clipped_annotations(&mut self) -> Result<Pixmap> {
+        let layer = Pixmap::new(self.width, self.height).ok_or(PlottingError::RenderError(
+            "Failed to allocate clipped annotation layer".to_string(),
+        ))?;
+        Ok(std::mem::replace(&mut self.pixmap, layer))
+    }
+
+    /// Restore the canvas and composite the clipped annotation layer onto it
+    fn finish_clipped_annotations(&mut self, canvas: Pixmap, plot_area: Rect) -> Result<()> {
+        let layer = std::mem::replace(&mut self.pixmap, canvas);
+        let mask = self.get_clip_mask((
+            plot_area.x(),
+            plot_area.y(),
+            plot_area.width(),
+            plot_area.height(),
+        ))?;
+        self.pixmap.draw_pixmap(
+            0,
+            0,
+            layer.as_ref(),
+            &PixmapPaint::default(),
+            Transform::identity(),
+            Some(mask.as_ref()),
+        );
+        Ok(())
     }
 
     /// Render a single annotation
@@ -167,8 +226,17 @@ impl SkiaRenderer {
         use crate::core::Annotation;
 
         match annotation {
-            Annotation::Text { x, y, text, style } => {
-                self.draw_annotation_text(*x, *y, text, style, transform, dpi)
+            Annotation::Text {
+                x,
+                y,
+                text,
+                style,
+                coords,
+                ..
+            } => {
+                let (px, py) = transform.point_in(*coords, *x, *y);
+                self.draw_annotation_text(px, py, text, style, dpi)
+                    .map(|_| ())
             }
             Annotation::Arrow {
                 x1,
@@ -176,7 +244,30 @@ impl SkiaRenderer {
                 x2,
                 y2,
                 style,
-            } => self.draw_annotation_arrow(*x1, *y1, *x2, *y2, style, transform, dpi),
+                coords,
+                ..
+            } => {
+                let start = transform.point_in(*coords, *x1, *y1);
+                let end = transform.point_in(*coords, *x2, *y2);
+                self.draw_annotation_arrow(start, end, style, dpi)
+            }
+            Annotation::Callout {
+                x,
+                y,
+                text,
+                offset,
+                text_style,
+                arrow_style,
+                coords,
+                ..
+            } => self.draw_annotation_callout(
+                transform.point_in(*coords, *x, *y),
+                text,
+                *offset,
+                text_style,
+                arrow_style,
+                dpi,
+            ),
             Annotation::HLine {
                 y,
                 style,
@@ -195,14 +286,32 @@ impl SkiaRenderer {
                 width,
                 height,
                 style,
-            } => self.draw_annotation_rect(*x, *y, *width, *height, style, transform),
+                coords,
+                ..
+            } => {
+                let corner = transform.point_in(*coords, *x, *y + *height);
+                let opposite = transform.point_in(*coords, *x + *width, *y);
+                self.draw_annotation_rect(corner, opposite, style)
+            }
             Annotation::FillBetween {
                 x,
                 y1,
                 y2,
                 style,
                 where_positive,
-            } => self.draw_annotation_fill_between(x, y1, y2, style, *where_positive, transform),
+                where_mask,
+            } => {
+                let polygons = crate::core::annotation::fill_between_polygons(
+                    x,
+                    y1,
+                    y2,
+                    *where_positive,
+                    where_mask.as_deref(),
+                );
+                polygons.iter().try_for_each(|polygon| {
+                    self.draw_annotation_fill_polygon(polygon, style, transform)
+                })
+            }
             Annotation::HSpan {
                 x_min: xmin,
                 x_max: xmax,
@@ -216,17 +325,43 @@ impl SkiaRenderer {
         }
     }
 
-    /// Draw a text annotation at data coordinates.
+    /// Draw a callout: text offset from `target` and an arrow from its box to `target`.
+    fn draw_annotation_callout(
+        &mut self,
+        target: (f32, f32),
+        text: &str,
+        offset: (f32, f32),
+        text_style: &crate::core::TextStyle,
+        arrow_style: &crate::core::ArrowStyle,
+        dpi: f32,
+    ) -> Result<()> {
+        let render_scale = RenderScale::new(dpi);
+        let anchor = (
+            target.0 + render_scale.points_to_pixels(offset.0),
+            target.1 - render_scale.points_to_pixels(offset.1),
+        );
+        let start = match self.draw_annotation_text(anchor.0, anchor.1, text, text_style, dpi)? {
+            Some(layout) => match layout.edge_toward((target.0 - anchor.0, target.1 - anchor.1)) {
+                Some((dx, dy)) => (anchor.0 + dx, anchor.1 + dy),
+                None => return Ok(()),
+            },
+            None => anchor,
+        };
+        self.draw_annotation_arrow(start, target, arrow_style, dpi)
+    }
+
+    /// Draw a text annotation anchored at a pixel position.
+    ///
+    /// Returns the text box layout relative to the anchor, or `None` when
+    /// nothing was drawn.
     fn draw_annotation_text(
         &mut self,
-        x: f64,
-        y: f64,
+        px: f32,
+        py: f32,
         text: &str,
         style: &crate::core::TextStyle,
-        transform: &AnnotationTransform<'_>,
         dpi: f32,
-    ) -> Result<()> {
-        let (px, py) = transform.point(x, y);
+    ) -> Result<Option<AnnotationTextLayout>> {
         let render_scale = RenderScale::new(dpi);
         let font_size_px = render_scale.points_to_pixels(style.font_size.max(0.1));
         let padding_px = render_scale.points_to_pixels(style.padding.max(0.0));
@@ -236,7 +371,7 @@ impl SkiaRenderer {
         let border_visible =
             border_width_px > 0.0 && style.border_color.is_some_and(|color| color.a > 0);
         if !text_visible && !background_visible && !border_visible {
-            return Ok(());
+            return Ok(None);
         }
         let font = FontConfig::new(self.font_config.family.clone(), font_size_px)
             .weight(FontWeight::Normal);
@@ -281,27 +416,25 @@ impl SkiaRenderer {
         );
         let local_to_canvas = Transform::from_rotate(layout.rotation).post_translate(px, py);
 
-        if (background_visible || border_visible)
-            && let Some(rect) = Rect::from_xywh(
+        if background_visible || border_visible {
+            let corner_radius_px = render_scale.points_to_pixels(style.corner_radius.max(0.0));
+            if let Some(path) = super::primitives::rounded_rect_path(
                 layout.box_x,
                 layout.box_y,
                 layout.box_width,
                 layout.box_height,
-            )
-        {
-            let mut path = PathBuilder::new();
-            path.push_rect(rect);
-            if let Some(path) = path.finish() {
+                corner_radius_px,
+            ) {
                 if background_visible && let Some(background) = style.background {
                     let mut paint = Paint::default();
                     paint.set_color(background.to_tiny_skia_color());
-                    paint.anti_alias = true;
+                    paint.anti_alias = self.antialias;
                     self.fill_path_masked(&path, &paint, FillRule::Winding, local_to_canvas, None)?;
                 }
                 if border_visible && let Some(border_color) = style.border_color {
                     let mut paint = Paint::default();
                     paint.set_color(border_color.to_tiny_skia_color());
-                    paint.anti_alias = true;
+                    paint.anti_alias = self.antialias;
                     let stroke = Stroke {
                         width: border_width_px,
                         ..Stroke::default()
@@ -312,13 +445,13 @@ impl SkiaRenderer {
         }
 
         if !text_visible {
-            return Ok(());
+            return Ok(Some(layout));
         }
 
         match self.text_engine_mode {
             TextEngineMode::Plain => {
                 if layout.rotation.abs() <= f32::EPSILON {
-                    return self.text_renderer.render_text_aligned(
+                    self.text_renderer.render_text_aligned(
                         &mut self.pixmap,
                         text,
                         px + layout.text_x,
@@ -327,7 +460,8 @@ impl SkiaRenderer {
                         style.align,
                         &font,
                         style.color,
-                    );
+                    )?;
+                    return Ok(Some(layout));
                 }
 
                 let glyph_guard = font_size_px.ceil().max(2.0);
@@ -365,7 +499,7 @@ impl SkiaRenderer {
                 };
                 self.pixmap
                     .draw_pixmap(0, 0, layer.as_ref(), &paint, text_transform, None);
-                Ok(())
+                Ok(Some(layout))
             }
             #[cfg(feature = "typst-math")]
             TextEngineMode::Typst => {
@@ -374,7 +508,7 @@ impl SkiaRenderer {
                     .expect("Typst annotation rendering must produce a raster");
                 if layout.rotation.abs() <= f32::EPSILON {
                     self.draw_typst_raster(&rendered, px + layout.text_x, py + layout.text_y);
-                    return Ok(());
+                    return Ok(Some(layout));
                 }
 
                 let scale_x = rendered.pixmap.width().max(1) as f32 / rendered.width.max(1e-6);
@@ -395,25 +529,19 @@ impl SkiaRenderer {
                     text_transform,
                     None,
                 );
-                Ok(())
+                Ok(Some(layout))
             }
         }
     }
 
-    /// Draw an arrow annotation
+    /// Draw an arrow annotation from `start` to `end` in pixels
     fn draw_annotation_arrow(
         &mut self,
-        x1: f64,
-        y1: f64,
-        x2: f64,
-        y2: f64,
+        (px1, py1): (f32, f32),
+        (px2, py2): (f32, f32),
         style: &crate::core::ArrowStyle,
-        transform: &AnnotationTransform<'_>,
         dpi: f32,
     ) -> Result<()> {
-        let (px1, py1) = transform.point(x1, y1);
-        let (px2, py2) = transform.point(x2, y2);
-
         let line_width_px = pt_to_px(style.line_width, dpi);
 
         // Draw the arrow shaft
@@ -511,7 +639,7 @@ impl SkiaRenderer {
 
         let mut paint = Paint::default();
         paint.set_color(color.to_tiny_skia_color());
-        paint.anti_alias = true;
+        paint.anti_alias = self.antialias;
 
         self.pixmap.fill_path(
             &path,
@@ -574,19 +702,13 @@ impl SkiaRenderer {
         )
     }
 
-    /// Draw a rectangle annotation
+    /// Draw a rectangle annotation between two opposite corners in pixels
     fn draw_annotation_rect(
         &mut self,
-        x: f64,
-        y: f64,
-        width: f64,
-        height: f64,
+        (px1, py1): (f32, f32),
+        (px2, py2): (f32, f32),
         style: &crate::core::ShapeStyle,
-        transform: &AnnotationTransform<'_>,
     ) -> Result<()> {
-        let (px1, py1) = transform.point(x, y + height);
-        let (px2, py2) = transform.point(x + width, y);
-
         let rect_width = (px2 - px1).abs();
         let rect_height = (py2 - py1).abs();
         let rect_x = px1.min(px2);
@@ -598,7 +720,7 @@ impl SkiaRenderer {
                 let mut paint = Paint::default();
                 let color_with_alpha = fill_color.with_alpha(style.fill_alpha);
                 paint.set_color(color_with_alpha.to_tiny_skia_color());
-                paint.anti_alias = true;
+                paint.anti_alias = self.antialias;
 
                 self.pixmap
                     .fill_rect(rect, &paint, Transform::identity(), None);
@@ -608,7 +730,7 @@ impl SkiaRenderer {
             if let Some(edge_color) = &style.edge_color {
                 let mut paint = Paint::default();
                 paint.set_color(edge_color.to_tiny_skia_color());
-                paint.anti_alias = true;
+                paint.anti_alias = self.antialias;
 
                 let mut stroke = Stroke {
                     width: style.edge_width.max(0.1),
@@ -631,45 +753,22 @@ impl SkiaRenderer {
         Ok(())
     }
 
-    /// Draw a fill between two curves
-    fn draw_annotation_fill_between(
+    /// Draw one polygon of a fill between two curves
+    fn draw_annotation_fill_polygon(
         &mut self,
-        x: &[f64],
-        y1: &[f64],
-        y2: &[f64],
+        polygon: &[(f64, f64)],
         style: &crate::core::FillStyle,
-        where_positive: bool,
         transform: &AnnotationTransform<'_>,
     ) -> Result<()> {
-        if x.len() < 2 || x.len() != y1.len() || x.len() != y2.len() {
-            return Ok(()); // Nothing to draw
-        }
-
-        // Build polygon path
         let mut path = PathBuilder::new();
-
-        // Forward along y1
-        let (start_x, start_y) = transform.point(x[0], y1[0]);
-        path.move_to(start_x, start_y);
-
-        for i in 1..x.len() {
-            if !where_positive || y1[i] >= y2[i] {
-                let (px, py) = transform.point(x[i], y1[i]);
-                path.line_to(px, py);
+        for (index, &(x, y)) in polygon.iter().enumerate() {
+            let (px, py) = transform.point(x, y);
+            if index == 0 {
+                path.move_to(px, py);
             } else {
-                let (px, py) = transform.point(x[i], y2[i]);
-                path.line_to(px, py);
-            }
-        }
-
-        // Backward along y2 (in reverse order)
-        for i in (0..x.len()).rev() {
-            if !where_positive || y1[i] >= y2[i] {
-                let (px, py) = transform.point(x[i], y2[i]);
                 path.line_to(px, py);
             }
         }
-
         path.close();
 
         if let Some(path) = path.finish() {
@@ -677,7 +776,7 @@ impl SkiaRenderer {
             let color_with_alpha = style.color.with_alpha(style.alpha);
             let mut paint = Paint::default();
             paint.set_color(color_with_alpha.to_tiny_skia_color());
-            paint.anti_alias = true;
+            paint.anti_alias = self.antialias;
 
             self.pixmap.fill_path(
                 &path,
@@ -687,11 +786,17 @@ impl SkiaRenderer {
                 None,
             );
 
+            // Hatch strokes use the edge color, falling back to the opaque fill color
+            if let Some(pattern) = style.hatch {
+                let hatch_color = style.edge_color.unwrap_or(style.color);
+                self.draw_hatch_in_path(&path, pattern, hatch_color, None)?;
+            }
+
             // Draw edge if specified
             if let Some(edge_color) = &style.edge_color {
                 let mut edge_paint = Paint::default();
                 edge_paint.set_color(edge_color.to_tiny_skia_color());
-                edge_paint.anti_alias = true;
+                edge_paint.anti_alias = self.antialias;
 
                 let stroke = Stroke {
                     width: style.edge_width.max(0.1),
@@ -740,7 +845,7 @@ impl SkiaRenderer {
                 let mut paint = Paint::default();
                 let color_with_alpha = fill_color.with_alpha(style.fill_alpha);
                 paint.set_color(color_with_alpha.to_tiny_skia_color());
-                paint.anti_alias = true;
+                paint.anti_alias = self.antialias;
 
                 self.pixmap
                     .fill_rect(rect, &paint, Transform::identity(), None);
@@ -784,7 +889,7 @@ impl SkiaRenderer {
                 let mut paint = Paint::default();
                 let color_with_alpha = fill_color.with_alpha(style.fill_alpha);
                 paint.set_color(color_with_alpha.to_tiny_skia_color());
-                paint.anti_alias = true;
+                paint.anti_alias = self.antialias;
 
                 self.pixmap
                     .fill_rect(rect, &paint, Transform::identity(), None);
@@ -4,6 +4,50 @@ use crate::{
     render::color::{scale_premultiplied_rgba, source_over_premultiplied_rgba},
 };
 
+/// Rectangle path with corners rounded by `corner_radius`, clamped to half the
+/// smaller side; radii below 0.1 px give square corners
+pub(super) fn rounded_rect_path(
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    corner_radius: f32,
+) -> Option<tiny_skia::Path> {
+    let max_radius = (width.min(height) / 2.0).max(0.0);
+    let radius = corner_radius.min(max_radius);
+    let mut pb = PathBuilder::new();
+    if radius < 0.1 {
+        pb.push_rect(Rect::from_xywh(x, y, width, height)?);
+        return pb.finish();
+    }
+
+    // Start at top-left, after the corner arc
+    pb.move_to(x + radius, y);
+
+    // Top edge
+    pb.line_to(x + width - radius, y);
+    // Top-right corner
+    pb.quad_to(x + width, y, x + width, y + radius);
+
+    // Right edge
+    pb.line_to(x + width, y + height - radius);
+    // Bottom-right corner
+    pb.quad_to(x + width, y + height, x + width - radius, y + height);
+
+    // Bottom edge
+    pb.line_to(x + radius, y + height);
+    // Bottom-left corner
+    pb.quad_to(x, y + height, x, y + height - radius);
+
+    // Left edge
+    pb.line_to(x, y + radius);
+    // Top-left corner
+    pb.quad_to(x, y, x + radius, y);
+
+    pb.close();
+    pb.finish()
+}
+
 impl SkiaRenderer {
     /// Map renderer font size to Typst size units.
     pub(super) fn typst_size_pt(&self, size_px: f32) -> f32 {
@@ -95,7 +139,7 @@ impl SkiaRenderer {
     ) -> Result<()> {
         let mut paint = Paint::default();
         paint.set_color(color.to_tiny_skia_color());
-        paint.anti_alias = true;
+        paint.anti_alias = self.antialias;
         paint.set_color_rgba8(color.r, color.g, color.b, color.a);
 
         let mut stroke = Stroke {
@@ -145,7 +189,7 @@ impl SkiaRenderer {
 
         let mut paint = Paint::default();
         paint.set_color(color.to_tiny_skia_color());
-        paint.anti_alias = true;
+        paint.anti_alias = self.antialias;
 
         let mut stroke = Stroke {
             width: width.max(0.1),
@@ -204,7 +248,7 @@ impl SkiaRenderer {
         let mask = self.get_clip_mask(clip_rect)?;
         let mut paint = Paint::default();
         paint.set_color(color.to_tiny_skia_color());
-        paint.anti_alias = true;
+        paint.anti_alias = self.antialias;
 
         let mut stroke = Stroke {
             width,
@@ -235,7 +279,7 @@ impl SkiaRenderer {
         )
     }
 
-    fn get_clip_mask(&mut self, clip_rect: (f32, f32, f32, f32)) -> Result<Arc<Mask>> {
+    pub(super) fn get_clip_mask(&mut self, clip_rect: (f32, f32, f32, f32)) -> Result<Arc<Mask>> {
         let key = ClipMaskKey::new(clip_rect);
         if let Some(mask) = self.clip_mask_cache.get(&key) {
             return Ok(Arc::clone(mask));
@@ -317,7 +361,7 @@ impl SkiaRenderer {
     ) -> Result<()> {
         let mut paint = Paint::default();
         paint.set_color(color.to_tiny_skia_color());
-        paint.anti_alias = true;
+        paint.anti_alias = self.antialias;
 
         let path = self
             .marker_path(
@@ -402,7 +446,7 @@ impl SkiaRenderer {
             )?;
 
             fill_paint.set_color(fill_color);
-            fill_paint.anti_alias = true;
+            fill_paint.anti_alias = self.antialias;
 
             // Fill the rectangle
             self.fill_path_masked(
@@ -425,7 +469,7 @@ impl SkiaRenderer {
             )?;
 
             border_paint.set_color(border_color);
-            border_paint.anti_alias = true;
+            border_paint.anti_alias = self.antialias;
 
             // Professional border stroke (1.0px width)
             let stroke = Stroke {
@@ -440,7 +484,7 @@ impl SkiaRenderer {
             // Outline only
             let mut paint = Paint::default();
             paint.set_color(color.to_tiny_skia_color());
-            paint.anti_alias = true;
+            paint.anti_alias = self.antialias;
 
             let stroke = Stroke::default();
             self.stroke_path_masked(&path, &paint, &stroke, Transform::identity(), mask)?;
@@ -449,6 +493,126 @@ impl SkiaRenderer {
         Ok(())
     }
 
+    /// Draw a hatch pattern over a rectangle, clipped to the rectangle and the plot area.
+    pub fn draw_hatch_rectangle_clipped(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        pattern: HatchPattern,
+        color: Color,
+        clip_rect: (f32, f32, f32, f32),
+    ) -> Result<()> {
+        let Some(rect) = Rect::from_xywh(x, y, width, height) else {
+            return Ok(());
+        };
+        let shape = PathBuilder::from_rect(rect);
+        let clip = self.get_clip_mask(clip_rect)?;
+        self.draw_hatch_in_path(&shape, pattern, color, Some(clip.as_ref()))
+    }
+
+    /// Stroke `pattern` inside `shape`.
+    ///
+    /// Strokes are anchored to the canvas origin rather than to the shape, so
+    /// neighbouring shapes with the same pattern line up seamlessly.
+    pub(super) fn draw_hatch_in_path(
+        &mut self,
+        shape: &tiny_skia::Path,
+        pattern: HatchPattern,
+        color: Color,
+        clip: Option<&Mask>,
+    ) -> Result<()> {
+        let mask = match clip {
+            Some(clip) => {
+                let mut mask = clip.clone();
+                mask.intersect_path(shape, FillRule::Winding, true, Transform::identity());
+                mask
+            }
+            None => {
+                let mut mask = Mask::new(self.width, self.height).ok_or(
+                    PlottingError::RenderError("Failed to create hatch mask".to_string()),
+                )?;
+                mask.fill_path(shape, FillRule::Winding, true, Transform::identity());
+                mask
+            }
+        };
+
+        let spacing = self.points_to_pixels(HatchPattern::SPACING_PT).max(1.0);
+        let line_width = self.points_to_pixels(HatchPattern::LINE_WIDTH_PT).max(0.1);
+        let bounds = shape.bounds();
+        let corners = [
+            (bounds.left(), bounds.top()),
+            (bounds.right(), bounds.top()),
+            (bounds.left(), bounds.bottom()),
+            (bounds.right(), bounds.bottom()),
+        ];
+
+        let mut paint = Paint::default();
+        paint.set_color(color.to_tiny_skia_color());
+        paint.anti_alias = self.antialias;
+
+        let mut hatch = PathBuilder::new();
+        if pattern.line_angles().is_empty() {
+            let first_col = (bounds.left() / spacing).floor() as i64;
+            let last_col = (bounds.right() / spacing).ceil() as i64;
+            let first_row = (bounds.top() / spacing).floor() as i64;
+            let last_row = (bounds.bottom() / spacing).ceil() as i64;
+            for row in first_row..=last_row {
+                for col in first_col..=last_col {
+                    hatch.push_circle(col as f32 * spacing, row as f32 * spacing, line_width);
+                }
+            }
+            if let Some(dots) = hatch.finish() {
+                self.fill_path_masked(
+                    &dots,
+                    &paint,
+                    FillRule::Winding,
+                    Transform::identity(),
+                    Some(&mask),
+                )?;
+            }
+            return Ok(());
+        }
+
+        for angle in pattern.line_angles() {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            // Screen y grows downward, so the stroke direction flips its y component.
+            let direction = (cos, -sin);
+            let normal = (sin, cos);
+            let span = |axis: (f32, f32)| {
+                corners
+                    .iter()
+                    .map(|&(px, py)| px * axis.0 + py * axis.1)
+                    .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), v| {
+                        (lo.min(v), hi.max(v))
+                    })
+            };
+            let (normal_min, normal_max) = span(normal);
+            let (along_min, along_max) = span(direction);
+
+            let first = (normal_min / spacing).floor() as i64;
+            let last = (normal_max / spacing).ceil() as i64;
+            for step in first..=last {
+                let offset = step as f32 * spacing;
+                let (cx, cy) = (normal.0 * offset, normal.1 * offset);
+                hatch.move_to(cx + direction.0 * along_min, cy + direction.1 * along_min);
+                hatch.line_to(cx + direction.0 * along_max, cy + direction.1 * along_max);
+            }
+        }
+
+        if let Some(lines) = hatch.finish() {
+            let stroke = Stroke {
+                width: line_width,
+                line_cap: LineCap::Butt,
+                ..Stroke::default()
+            };
+            self.stroke_path_masked(&lines, &paint, &stroke, Transform::identity(), Some(&mask))?;
+        }
+
+        Ok(())
+    }
+
     /// Draw a solid color rectangle with no transparency or border
     /// Used for gradient segments like colorbar where 100% opacity and no anti-aliasing is needed
     pub fn draw_solid_rectangle(
@@ -541,7 +705,7 @@ impl SkiaRenderer {
         ))?;
         let mut paint = Paint::default();
         paint.set_color(color.to_tiny_skia_color());
-        paint.anti_alias = true;
+        paint.anti_alias = self.antialias;
         self.fill_path_masked(
             &path,
             &paint,
@@ -613,6 +777,45 @@ impl SkiaRenderer {
         Ok(())
     }
 
+    /// Stroke many rectangle outlines as one path, clipped to `clip_rect`.
+    ///
+    /// Shared edges are covered once, so translucent strokes between
+    /// neighbouring rectangles do not darken.
+    pub fn draw_rectangle_outlines_clipped(
+        &mut self,
+        rects: &[(f32, f32, f32, f32)],
+        color: Color,
+        line_width: f32,
+        clip_rect: (f32, f32, f32, f32),
+    ) -> Result<()> {
+        let mut path = PathBuilder::new();
+        for &(x, y, width, height) in rects {
+            if let Some(rect) = Rect::from_xywh(x, y, width, height) {
+                path.push_rect(rect);
+            }
+        }
+        let Some(path) = path.finish() else {
+            return Ok(());
+        };
+
+        let mut paint = Paint::default();
+        paint.set_color(color.to_tiny_skia_color());
+        paint.anti_alias = self.antialias;
+        let stroke = Stroke {
+            width: line_width,
+            line_join: LineJoin::Miter,
+            ..Stroke::default()
+        };
+        let mask = self.get_clip_mask(clip_rect)?;
+        self.stroke_path_masked(
+            &path,
+            &paint,
+            &stroke,
+            Transform::identity(),
+            Some(mask.as_ref()),
+        )
+    }
+
     /// Draw a rounded rectangle with the given corner radius
     pub fn draw_rounded_rectangle(
         &mut self,
@@ -633,37 +836,9 @@ impl SkiaRenderer {
             return self.draw_rectangle(x, y, width, height, color, filled);
         }
 
-        // Build rounded rectangle path manually
-        let mut pb = PathBuilder::new();
-
-        // Start at top-left, after the corner arc
-        pb.move_to(x + radius, y);
-
-        // Top edge
-        pb.line_to(x + width - radius, y);
-        // Top-right corner
-        pb.quad_to(x + width, y, x + width, y + radius);
-
-        // Right edge
-        pb.line_to(x + width, y + height - radius);
-        // Bottom-right corner
-        pb.quad_to(x + width, y + height, x + width - radius, y + height);
-
-        // Bottom edge
-        pb.line_to(x + radius, y + height);
-        // Bottom-left corner
-        pb.quad_to(x, y + height, x, y + height - radius);
-
-        // Left edge
-        pb.line_to(x, y + radius);
-        // Top-left corner
-        pb.quad_to(x, y, x + radius, y);
-
-        pb.close();
-
-        let path = pb.finish().ok_or(PlottingError::RenderError(
-            "Failed to create rounded rectangle path".to_string(),
-        ))?;
+        let path = rounded_rect_path(x, y, width, height, radius).ok_or(
+            PlottingError::RenderError("Failed to create rounded rectangle path".to_string()),
+        )?;
 
         if filled {
             let mut fill_paint = Paint::default();
@@ -673,7 +848,7 @@ impl SkiaRenderer {
             )?;
 
             fill_paint.set_color(fill_color);
-            fill_paint.anti_alias = true;
+            fill_paint.anti_alias = self.antialias;
 
             self.pixmap.fill_path(
                 &path,
@@ -686,7 +861,7 @@ impl SkiaRenderer {
             // Outline only
             let mut paint = Paint::default();
             paint.set_color(color.to_tiny_skia_color());
-            paint.anti_alias = true;
+            paint.anti_alias = self.antialias;
 
             let stroke = Stroke::default();
             self.pixmap
@@ -724,7 +899,7 @@ impl SkiaRenderer {
         )?;
 
         paint.set_color(fill_color);
-        paint.anti_alias = true;
+        paint.anti_alias = self.antialias;
 
         self.pixmap.fill_path(
             &path,
@@ -794,7 +969,7 @@ impl SkiaRenderer {
         )?;
 
         paint.set_color(fill_color);
-        paint.anti_alias = true;
+        paint.anti_alias = self.antialias;
 
         // Draw with clip mask
         self.fill_path_masked(
@@ -834,7 +1009,7 @@ impl SkiaRenderer {
 
         let mut paint = Paint::default();
         paint.set_color(color.to_tiny_skia_color());
-        paint.anti_alias = true;
+        paint.anti_alias = self.antialias;
 
         let stroke = Stroke {
             width,
@@ -945,7 +1120,7 @@ impl SkiaRenderer {
             MarkerStyle::Triangle | MarkerStyle::TriangleOpen | MarkerStyle::TriangleDown => {
                 let mut paint = Paint::default();
                 paint.set_color(color.to_tiny_skia_color());
-                paint.anti_alias = true;
+                paint.anti_alias = self.antialias;
                 let path = self
                     .marker_path(style, size)?
                     .ok_or(PlottingError::RenderError(
@@ -972,7 +1147,7 @@ impl SkiaRenderer {
             MarkerStyle::Diamond | MarkerStyle::DiamondOpen => {
                 let mut paint = Paint::default();
                 paint.set_color(color.to_tiny_skia_color());
-                paint.anti_alias = true;
+                paint.anti_alias = self.antialias;
                 let path = self
                     .marker_path(style, size)?
                     .ok_or(PlottingError::RenderError(
@@ -1094,6 +1269,111 @@ impl SkiaRenderer {
         Ok(())
     }
 
+    /// Draw a marker with separate face and edge colors
+    pub fn draw_marker_painted(
+        &mut self,
+        x: f32,
+        y: f32,
+        size: f32,
+        style: MarkerStyle,
+        paint: MarkerPaint,
+    ) -> Result<()> {
+        self.draw_marker_painted_with_mask(x, y, size, style, paint, None)
+    }
+
+    pub fn draw_marker_painted_clipped(
+        &mut self,
+        x: f32,
+        y: f32,
+        size: f32,
+        style: MarkerStyle,
+        paint: MarkerPaint,
+        clip_rect: (f32, f32, f32, f32),
+    ) -> Result<()> {
+        let mask = self.get_clip_mask(clip_rect)?;
+        self.draw_marker_painted_with_mask(x, y, size, style, paint, Some(mask.as_ref()))
+    }
+
+    pub fn draw_markers_painted_clipped(
+        &mut self,
+        points: &[Point2f],
+        size: f32,
+        style: MarkerStyle,
+        paint: MarkerPaint,
+        clip_rect: (f32, f32, f32, f32),
+    ) -> Result<()> {
+        if points.is_empty() || size <= 0.0 {
+            return Ok(());
+        }
+
+        let mask = self.get_clip_mask(clip_rect)?;
+        for point in points {
+            self.draw_marker_painted_with_mask(
+                point.x,
+                point.y,
+                size,
+                style,
+                paint,
+                Some(mask.as_ref()),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn draw_marker_painted_with_mask(
+        &mut self,
+        x: f32,
+        y: f32,
+        size: f32,
+        style: MarkerStyle,
+        paint: MarkerPaint,
+        mask: Option<&Mask>,
+    ) -> Result<()> {
+        let path = match style {
+            MarkerStyle::Square | MarkerStyle::SquareOpen => {
+                let half_size = size * 0.5;
+                Rect::from_xywh(-half_size, -half_size, size, size)
+                    .map(PathBuilder::from_rect)
+                    .map(Arc::new)
+            }
+            MarkerStyle::Plus | MarkerStyle::Cross | MarkerStyle::Star => {
+                // Line-based markers have no face to fill.
+                return self.draw_marker_with_mask_vector(x, y, size, style, paint.edge, mask);
+            }
+            _ => self.marker_path(style, size)?,
+        };
+        let path = path.ok_or(PlottingError::RenderError(
+            "Failed to create marker path".to_string(),
+        ))?;
+        let transform = Transform::from_translate(x, y);
+
+        if let Some(fill) = paint.fill.filter(|fill| fill.a > 0) {
+            let mut fill_paint = Paint::default();
+            fill_paint.set_color(fill.to_tiny_skia_color());
+            fill_paint.anti_alias = self.antialias;
+            self.fill_path_masked(
+                path.as_ref(),
+                &fill_paint,
+                FillRule::Winding,
+                transform,
+                mask,
+            )?;
+        }
+
+        if paint.edge_width > 0.0 && paint.edge.a > 0 {
+            let mut edge_paint = Paint::default();
+            edge_paint.set_color(paint.edge.to_tiny_skia_color());
+            edge_paint.anti_alias = self.antialias;
+            let stroke = Stroke {
+                width: paint.edge_width,
+                ..Stroke::default()
+            };
+            self.stroke_path_masked(path.as_ref(), &edge_paint, &stroke, transform, mask)?;
+        }
+
+        Ok(())
+    }
+
     fn should_use_marker_sprite_compositor(
         point_count: usize,
         size: f32,
@@ -849,6 +849,40 @@ fn test_draw_polyline_clipped_keeps_pixels_inside_clip_rect() {
     assert_eq!(count_red_pixels_outside_rect(&image, clip_rect), 0);
 }
 
+#[test]
+fn annotations_are_clipped_to_the_plot_area_unless_opted_out() {
+    let plot_area = Rect::from_xywh(40.0, 40.0, 240.0, 240.0).unwrap();
+    let render = |clip: bool| {
+        let mut renderer = SkiaRenderer::new(320, 320, Theme::light()).unwrap();
+        let box_style = crate::core::TextStyle::default()
+            .color(Color::TRANSPARENT)
+            .background(Color::RED)
+            .padding(6.0);
+        let annotations = [
+            crate::core::Annotation::rectangle_styled(
+                0.8,
+                0.8,
+                0.5,
+                0.5,
+                crate::core::ShapeStyle::default().fill(Color::RED),
+            )
+            .clip(clip),
+            crate::core::Annotation::hline_styled(0.5, Color::BLUE, 2.0, LineStyle::Solid),
+            crate::core::Annotation::text_styled(1.0, 0.2, "MMMM", box_style).clip(clip),
+        ];
+        renderer
+            .draw_annotations(&annotations, plot_area, 0.0, 1.0, 0.0, 1.0, 72.0)
+            .unwrap();
+        renderer.into_image()
+    };
+
+    let clipped = render(true);
+    assert_eq!(count_red_pixels_outside_rect(&clipped, plot_area), 0);
+    assert!(!red_pixel_positions(&clipped).is_empty());
+    let unclipped = render(false);
+    assert!(count_red_pixels_outside_rect(&unclipped, plot_area) > 100);
+}
+
 #[test]
 fn test_draw_datashader_image_scales_into_plot_area() {
     let theme = Theme::dark();
@@ -1202,6 +1236,79 @@ fn text_annotation_default_anchor_is_center_middle_and_scales_with_dpi() {
     assert!(high_height > low_height * 1.8);
 }
 
+#[test]
+fn text_annotation_corner_radius_rounds_background_box() {
+    let style = crate::core::TextStyle::default()
+        .font_size(12.0)
+        .color(Color::TRANSPARENT)
+        .background(Color::RED)
+        .padding(4.0);
+    let square = render_text_annotation("MMMMMMMM", style.clone(), 72.0);
+    let rounded = render_text_annotation("MMMMMMMM", style.corner_radius(6.0), 72.0);
+    let square_positions = red_pixel_positions(&square);
+    let rounded_positions = red_pixel_positions(&rounded);
+    let (left, top, right, bottom) = position_bounds(&square_positions);
+    assert_eq!(
+        position_bounds(&rounded_positions),
+        (left, top, right, bottom)
+    );
+
+    let is_red = |image: &Image, x: f32, y: f32| {
+        let pixel = image_pixel_rgba(image, x as u32, y as u32);
+        pixel[0] > 200 && pixel[1] < 80 && pixel[2] < 80
+    };
+    for (x, y) in [(left, top), (right, top), (left, bottom), (right, bottom)] {
+        assert!(is_red(&square, x, y));
+        assert!(!is_red(&rounded, x, y));
+    }
+    let center = ((left + right) / 2.0, (top + bottom) / 2.0);
+    assert!(is_red(&rounded, center.0, top) && is_red(&rounded, left, center.1));
+}
+
+#[test]
+fn callout_arrow_runs_from_the_text_box_edge_to_the_point() {
+    let mut renderer = SkiaRenderer::new(320, 320, Theme::light()).unwrap();
+    let plot_area = Rect::from_xywh(40.0, 40.0, 240.0, 240.0).unwrap();
+    let text_style = crate::core::TextStyle::default()
+        .font_size(12.0)
+        .color(Color::TRANSPARENT)
+        .background(Color::RED)
+        .padding(4.0);
+    let arrow_style = crate::core::ArrowStyle::new()
+        .color(Color::new(0, 0, 255))
+        .line_width(2.0);
+    let annotation = crate::core::Annotation::callout_styled(
+        0.5,
+        0.5,
+        "MMMM",
+        (0.0, 60.0),
+        arrow_style,
+        text_style,
+    );
+    renderer
+        .draw_annotations(&[annotation], plot_area, 0.0, 1.0, 0.0, 1.0, 72.0)
+        .unwrap();
+    let image = renderer.into_image();
+
+    let (box_left, _, box_right, box_bottom) = position_bounds(&red_pixel_positions(&image));
+    assert!(box_left < 160.0 && box_right > 160.0 && box_bottom < 120.0);
+    let mut blue = Vec::new();
+    for y in 0..image.height {
+        for x in 0..image.width {
+            let pixel = image_pixel_rgba(&image, x, y);
+            if pixel[2] > 200 && pixel[0] < 80 && pixel[1] < 80 {
+                blue.push((x as f32, y as f32));
+            }
+        }
+    }
+    let (_, arrow_top, _, arrow_bottom) = position_bounds(&blue);
+    assert!(
+        arrow_top >= box_bottom - 1.0,
+        "arrow should start at the box edge: top={arrow_top} box_bottom={box_bottom}"
+    );
+    assert!((arrow_bottom - 160.0).abs() <= 1.0);
+}
+
 #[test]
 fn positive_text_annotation_rotation_is_counter_clockwise() {
     let style = crate::core::TextStyle::default()
@@ -428,10 +428,25 @@ pub fn format_tick_labels_for_scale(values: &[f64], scale: &crate::axes::AxisSca
             .iter()
             .map(|&value| format_log_tick_label(value))
             .collect(),
+        crate::axes::AxisScale::Time { format } => {
+            crate::axes::format_time_tick_labels(values, format)
+        }
         _ => format_tick_labels(values),
     }
 }
 
+/// Format axis tick labels, preferring a custom formatter over scale-aware defaults.
+pub(crate) fn format_axis_tick_labels(
+    values: &[f64],
+    scale: &crate::axes::AxisScale,
+    formatter: Option<&TickLabelFormatter>,
+) -> Vec<String> {
+    match formatter {
+        Some(formatter) => formatter.format_ticks(values),
+        None => format_tick_labels_for_scale(values, scale),
+    }
+}
+
 pub fn format_log_tick_label(value: f64) -> String {
     if !value.is_finite() || value <= 0.0 {
         return format_tick_label(value);
@@ -439,49 +454,39 @@ pub fn format_log_tick_label(value: f64) -> String {
 
     let exponent = value.log10();
     if (exponent.round() - exponent).abs() < 1e-10 {
-        format!("10{}", superscript_exponent(exponent.round() as i32))
+        format!(
+            "10{}",
+            crate::core::tick_formatter::superscript_exponent(exponent.round() as i32)
+        )
     } else {
         format_tick_label(value)
     }
 }
 
-fn superscript_exponent(exponent: i32) -> String {
-    let exponent = exponent as i64;
-    let mut formatted = String::new();
-    if exponent < 0 {
-        formatted.push('⁻');
-    }
-
-    for digit in exponent.abs().to_string().chars() {
-        let superscript = match digit {
-            '0' => '⁰',
-            '1' => '¹',
-            '2' => '²',
-            '3' => '³',
-            '4' => '⁴',
-            '5' => '⁵',
-            '6' => '⁶',
-            '7' => '⁷',
-            '8' => '⁸',
-            '9' => '⁹',
-            _ => digit,
-        };
-        formatted.push(superscript);
-    }
-
-    formatted
+pub fn compute_colorbar_ticks(
+    vmin: f64,
+    vmax: f64,
+    scale: &crate::axes::AxisScale,
+    show_log_subticks: bool,
+) -> ColorbarTicks {
+    compute_colorbar_ticks_with_options(vmin, vmax, scale, show_log_subticks, None, None)
 }
 
-pub fn compute_colorbar_ticks(
+/// Colorbar ticks with a target major tick count and an optional label formatter.
+///
+/// `n_ticks` is ignored for logarithmic scales, which keep one major tick per decade.
+pub(crate) fn compute_colorbar_ticks_with_options(
     vmin: f64,
     vmax: f64,
     scale: &crate::axes::AxisScale,
     show_log_subticks: bool,
+    n_ticks: Option<usize>,
+    formatter: Option<&TickLabelFormatter>,
 ) -> ColorbarTicks {
     match scale {
         crate::axes::AxisScale::Log => {
             let major_values = generate_log_colorbar_major_ticks(vmin, vmax);
-            let major_labels = format_tick_labels_for_scale(&major_values, scale);
+            let major_labels = format_axis_tick_labels(&major_values, scale, formatter);
             let minor_values = if show_log_subticks {
                 generate_log_colorbar_minor_ticks(vmin, vmax)
             } else {
@@ -495,8 +500,9 @@ pub fn compute_colorbar_ticks(
             }
         }
         _ => {
-            let major_values = crate::axes::generate_ticks_for_scale(vmin, vmax, 6, scale);
-            let major_labels = format_tick_labels_for_scale(&major_values, scale);
+            let major_values =
+                crate::axes::generate_ticks_for_scale(vmin, vmax, n_ticks.unwrap_or(6), scale);
+            let major_labels = format_axis_tick_labels(&major_values, scale, formatter);
 
             ColorbarTicks {
                 major_values,
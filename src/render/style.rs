@@ -1,3 +1,5 @@
+use super::Color;
+
 /// Line style enumeration for plot lines and borders
 ///
 /// Defines different visual styles for drawing lines in plots.
@@ -21,6 +23,8 @@
 ///
 /// ![Line styles](https://raw.githubusercontent.com/Ameyanagi/ruviz/main/docs/assets/rustdoc/line_styles.png)
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum LineStyle {
     /// Solid continuous line (default)
     Solid,
@@ -296,6 +300,42 @@ impl std::fmt::Display for MarkerStyle {
     }
 }
 
+/// Separate face and edge colors for a marker
+///
+/// Mirrors matplotlib's `markerfacecolor`, `markeredgecolor` and
+/// `markeredgewidth`. A `fill` of `None` draws the outline only, which keeps
+/// overlapping points legible. Line-based markers (plus, cross, star) have no
+/// face and are drawn in the edge color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarkerPaint {
+    /// Face color, or `None` for an outline-only marker
+    pub fill: Option<Color>,
+    /// Edge color
+    pub edge: Color,
+    /// Edge width in pixels (0 disables the outline)
+    pub edge_width: f32,
+}
+
+impl MarkerPaint {
+    /// Outline-only marker with the given edge
+    pub fn hollow(edge: Color, edge_width: f32) -> Self {
+        Self {
+            fill: None,
+            edge,
+            edge_width: edge_width.max(0.0),
+        }
+    }
+
+    /// Filled marker with an outline
+    pub fn filled(fill: Color, edge: Color, edge_width: f32) -> Self {
+        Self {
+            fill: Some(fill),
+            edge,
+            edge_width: edge_width.max(0.0),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
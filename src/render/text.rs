@@ -20,11 +20,12 @@
 //! renderer.render_text(&mut pixmap, "Hello 日本語", 10.0, 50.0, &config, Color::BLACK)?;
 //! ```
 
+use std::fmt::Write as _;
 use std::sync::{Mutex, MutexGuard, OnceLock};
 
 use cosmic_text::{
-    Attrs, Buffer, CacheKey, Color as CosmicColor, Family, FontSystem, Metrics, Shaping,
-    Style as CosmicStyle, SwashCache, SwashContent, Weight as CosmicWeight,
+    Attrs, Buffer, CacheKey, CacheKeyFlags, Color as CosmicColor, Family, FontSystem, Metrics,
+    Shaping, Style as CosmicStyle, SwashCache, SwashContent, Weight as CosmicWeight,
 };
 use swash::scale::Source as SwashSource;
 use tiny_skia::{Pixmap, PixmapMut, PremultipliedColorU8};
@@ -38,6 +39,15 @@ use crate::render::{
 };
 
 const MAX_TEXT_RASTER_DIMENSION: u32 = 8_192;
+
+/// Glyph outlines of one shaped line of text
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct TextOutline {
+    /// SVG path data relative to the left end of the baseline
+    pub(crate) path_data: String,
+    /// Advance width of the line in pixels
+    pub(crate) width: f32,
+}
 const MAX_TEXT_RASTER_BYTES: usize = 128 * 1024 * 1024;
 
 trait PixmapTarget {
@@ -410,8 +420,30 @@ impl From<String> for FontFamily {
     }
 }
 
+/// Serialized as its CSS family string (see [`FontFamily::as_str`])
+#[cfg(feature = "serde")]
+impl serde::Serialize for FontFamily {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FontFamily {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer).map(FontFamily::from)
+    }
+}
+
 /// Font weight specification
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FontWeight {
     /// Thin weight (100)
     Thin,
@@ -1001,6 +1033,90 @@ impl TextRenderer {
         Ok(TextPlacementMetrics::new(width, height, baseline_from_top))
     }
 
+    /// Shape one line of text and convert its glyphs to SVG path data.
+    ///
+    /// The path is relative to the left end of the baseline, with y pointing
+    /// down. Returns `None` when no fonts are registered, so callers can fall
+    /// back to live text.
+    pub(crate) fn text_outline(
+        &self,
+        text: &str,
+        config: &FontConfig,
+    ) -> Result<Option<TextOutline>> {
+        let mut font_system = lock_font_system()?;
+        if font_system.db().is_empty() {
+            log::debug!("Skipping text outlines because no fonts are registered");
+            return Ok(None);
+        }
+        if !is_renderable_text(text) {
+            return Ok(Some(TextOutline::default()));
+        }
+        let mut swash_cache = lock_swash_cache()?;
+
+        let metrics = Metrics::new(config.size, config.size * 1.2);
+        let mut buffer = Buffer::new(&mut font_system, metrics);
+
+        let buffer_width = (text.len() as f32 * config.size * 2.0).max(800.0);
+        let buffer_height = text_buffer_height(text, config.size, 100.0);
+        buffer.set_size(&mut font_system, Some(buffer_width), Some(buffer_height));
+
+        let attrs = config.to_cosmic_attrs();
+        buffer.set_text(&mut font_system, text, &attrs, Shaping::Advanced, None);
+        buffer.shape_until_scroll(&mut font_system, false);
+
+        let mut outline = TextOutline::default();
+        let Some(run) = buffer.layout_runs().next() else {
+            return Ok(Some(outline));
+        };
+        outline.width = run.line_w;
+        for glyph in run.glyphs.iter() {
+            // Undo the pixel snapping of `physical` so glyphs keep their
+            // fractional pen positions.
+            let physical = glyph.physical((0.0, 0.0), 1.0);
+            let origin_x = physical.x as f32 + physical.cache_key.x_bin.as_float();
+            let origin_y = physical.y as f32 + physical.cache_key.y_bin.as_float();
+            // Hinting snaps outlines to this size's pixel grid, which only
+            // distorts them once the SVG is scaled.
+            let mut cache_key = physical.cache_key;
+            cache_key.flags |= CacheKeyFlags::DISABLE_HINTING;
+            let Some(commands) = swash_cache.get_outline_commands(&mut font_system, cache_key)
+            else {
+                continue;
+            };
+
+            // Font outlines point y up; SVG points y down.
+            let point = |p: swash::zeno::Point| (origin_x + p.x, origin_y - p.y);
+            for command in commands {
+                match *command {
+                    swash::zeno::Command::MoveTo(to) => {
+                        let (x, y) = point(to);
+                        write!(outline.path_data, "M{x:.2} {y:.2}").unwrap();
+                    }
+                    swash::zeno::Command::LineTo(to) => {
+                        let (x, y) = point(to);
+                        write!(outline.path_data, "L{x:.2} {y:.2}").unwrap();
+                    }
+                    swash::zeno::Command::QuadTo(control, to) => {
+                        let ((cx, cy), (x, y)) = (point(control), point(to));
+                        write!(outline.path_data, "Q{cx:.2} {cy:.2} {x:.2} {y:.2}").unwrap();
+                    }
+                    swash::zeno::Command::CurveTo(control1, control2, to) => {
+                        let ((c1x, c1y), (c2x, c2y), (x, y)) =
+                            (point(control1), point(control2), point(to));
+                        write!(
+                            outline.path_data,
+                            "C{c1x:.2} {c1y:.2} {c2x:.2} {c2y:.2} {x:.2} {y:.2}"
+                        )
+                        .unwrap();
+                    }
+                    swash::zeno::Command::Close => outline.path_data.push('Z'),
+                }
+            }
+        }
+
+        Ok(Some(outline))
+    }
+
     /// Measure tight ink bounds for shaped text.
     ///
     /// Unlike `measure_text_placement`, this returns the bounds of the rasterized
@@ -70,6 +70,33 @@ pub(crate) struct AnnotationTextLayout {
     pub rotation: f32,
 }
 
+impl AnnotationTextLayout {
+    /// Point on the (rotated) box edge facing `target`, both relative to the anchor.
+    ///
+    /// Follows the ray from the box center toward `target`; returns `None` when
+    /// `target` lies inside the box.
+    pub(crate) fn edge_toward(&self, target: (f32, f32)) -> Option<(f32, f32)> {
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+        let local = (
+            target.0 * cos + target.1 * sin,
+            -target.0 * sin + target.1 * cos,
+        );
+        let half = (self.box_width / 2.0, self.box_height / 2.0);
+        let center = (self.box_x + half.0, self.box_y + half.1);
+        let (dx, dy) = (local.0 - center.0, local.1 - center.1);
+        let scale = [(dx, half.0), (dy, half.1)]
+            .into_iter()
+            .filter(|(delta, _)| delta.abs() > f32::EPSILON)
+            .map(|(delta, extent)| extent / delta.abs())
+            .fold(f32::INFINITY, f32::min);
+        if !scale.is_finite() || scale >= 1.0 {
+            return None;
+        }
+        let edge = (center.0 + dx * scale, center.1 + dy * scale);
+        Some((edge.0 * cos - edge.1 * sin, edge.0 * sin + edge.1 * cos))
+    }
+}
+
 pub(crate) fn annotation_text_layout(
     metrics: TextPlacementMetrics,
     align: crate::core::TextAlign,
@@ -100,6 +127,36 @@ pub(crate) fn annotation_text_layout(
     }
 }
 
+/// Axis-aligned `(width, height)` of a `width`x`height` text box rotated by `degrees`.
+pub(crate) fn rotated_text_extent(width: f32, height: f32, degrees: f32) -> (f32, f32) {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    let (sin, cos) = (sin.abs(), cos.abs());
+    (width * cos + height * sin, width * sin + height * cos)
+}
+
+/// Center of an x tick label rotated counter-clockwise by `degrees`, hanging below `top_y`.
+///
+/// Like matplotlib's `rotation=45, ha="right"`, the rotated box ends at the tick for
+/// positive angles and starts at it for negative angles; vertical labels stay centered.
+pub(crate) fn rotated_xtick_label_center(
+    tick_x: f32,
+    top_y: f32,
+    width: f32,
+    height: f32,
+    degrees: f32,
+) -> (f32, f32) {
+    let (rotated_width, rotated_height) = rotated_text_extent(width, height, degrees);
+    let folded = degrees.rem_euclid(180.0);
+    let center_x = if folded.abs() < 1e-3 || (folded - 90.0).abs() < 1e-3 {
+        tick_x
+    } else if folded < 90.0 {
+        tick_x - rotated_width / 2.0
+    } else {
+        tick_x + rotated_width / 2.0
+    };
+    (center_x, top_y + rotated_height / 2.0)
+}
+
 /// Convert an anchor coordinate and rendered bounds into top-left draw coordinates.
 pub fn anchor_to_top_left(
     anchor_x: f32,
@@ -179,4 +236,43 @@ mod tests {
         assert_eq!((layout.box_width, layout.box_height), (14.0, 8.0));
         assert_eq!(layout.rotation, -30.0);
     }
+
+    #[test]
+    fn annotation_box_edge_faces_the_target() {
+        let layout = annotation_text_layout(
+            TextPlacementMetrics::new(10.0, 4.0, 3.0),
+            crate::core::TextAlign::Center,
+            crate::core::TextVAlign::Middle,
+            2.0,
+            0.0,
+        );
+
+        assert_eq!(layout.edge_toward((0.0, 20.0)), Some((0.0, 4.0)));
+        assert_eq!(layout.edge_toward((-21.0, 0.0)), Some((-7.0, 0.0)));
+        assert_eq!(layout.edge_toward((3.0, 1.0)), None);
+
+        let rotated = AnnotationTextLayout {
+            rotation: -90.0,
+            ..layout
+        };
+        let (x, y) = rotated.edge_toward((0.0, 20.0)).unwrap();
+        assert!(x.abs() < 1e-4 && (y - 7.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn rotated_xtick_labels_hang_below_the_tick() {
+        let (w, h) = rotated_text_extent(40.0, 10.0, 90.0);
+        assert!((w - 10.0).abs() < 1e-4 && (h - 40.0).abs() < 1e-4);
+
+        let (cx, cy) = rotated_xtick_label_center(100.0, 50.0, 40.0, 10.0, 90.0);
+        assert!((cx - 100.0).abs() < 1e-4 && (cy - 70.0).abs() < 1e-4);
+
+        let (extent_w, extent_h) = rotated_text_extent(40.0, 10.0, 45.0);
+        let (cx, cy) = rotated_xtick_label_center(100.0, 50.0, 40.0, 10.0, 45.0);
+        assert!((cx + extent_w / 2.0 - 100.0).abs() < 1e-4);
+        assert!((cy - extent_h / 2.0 - 50.0).abs() < 1e-4);
+
+        let (cx, _) = rotated_xtick_label_center(100.0, 50.0, 40.0, 10.0, -45.0);
+        assert!((cx - extent_w / 2.0 - 100.0).abs() < 1e-4);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::render::{Color, LineStyle};
+use crate::render::{Color, FontConfig, LineStyle};
 
 /// Comprehensive theme system for consistent plot styling
 ///
@@ -36,7 +36,9 @@ use crate::render::{Color, LineStyle};
 /// | Default | Dark | Seaborn | Publication |
 /// |---------|------|---------|-------------|
 /// | ![Default](https://raw.githubusercontent.com/Ameyanagi/ruviz/main/docs/assets/rustdoc/theme_default.png) | ![Dark](https://raw.githubusercontent.com/Ameyanagi/ruviz/main/docs/assets/rustdoc/theme_dark.png) | ![Seaborn](https://raw.githubusercontent.com/Ameyanagi/ruviz/main/docs/assets/rustdoc/theme_seaborn.png) | ![Publication](https://raw.githubusercontent.com/Ameyanagi/ruviz/main/docs/assets/rustdoc/theme_publication.png) |
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Theme {
     /// Background color of the plot area
     pub background: Color,
@@ -73,15 +75,21 @@ pub struct Theme {
 impl Theme {
     /// Create a new theme builder
     ///
+    /// The builder starts from [`Theme::default()`], so only the properties
+    /// you set are changed.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
     /// use ruviz::prelude::*;
     ///
-    /// let custom_theme = Theme::builder()
+    /// let brand = Theme::builder()
     ///     .background(Color::from_hex("#1a1a2e").unwrap())
     ///     .foreground(Color::WHITE)
-    ///     .font_size(12.0)
+    ///     .grid_color(Color::from_hex("#2e2e4a").unwrap())
+    ///     .palette(vec![Color::from_hex("#e94560").unwrap(), Color::from_hex("#0f3460").unwrap()])
+    ///     .line_width(2.0)
+    ///     .font_config(FontConfig::new(FontFamily::Monospace, 12.0))
     ///     .build();
     /// ```
     pub fn builder() -> ThemeBuilder {
@@ -218,6 +226,10 @@ impl Theme {
     }
 
     /// Create colorblind-friendly theme
+    ///
+    /// The light theme with the Okabe–Ito palette from
+    /// [`Color::colorblind_palette`]; series without an explicit color cycle
+    /// through it.
     pub fn colorblind_friendly() -> Self {
         let mut theme = Self::light();
         theme.color_palette = Self::colorblind_palette();
@@ -399,18 +411,8 @@ impl Theme {
     }
 
     fn colorblind_palette() -> Vec<Color> {
-        // Optimized for deuteranopia/protanopia (most common color blindness)
-        vec![
-            Color::from_hex("#1f77b4").unwrap(), // Blue
-            Color::from_hex("#ff7f0e").unwrap(), // Orange
-            Color::from_hex("#2ca02c").unwrap(), // Green
-            Color::from_hex("#d62728").unwrap(), // Red
-            Color::from_hex("#9467bd").unwrap(), // Purple
-            Color::from_hex("#8c564b").unwrap(), // Brown
-            Color::from_hex("#e377c2").unwrap(), // Pink
-            Color::from_hex("#bcbd22").unwrap(), // Olive
-            Color::from_hex("#17becf").unwrap(), // Cyan
-        ]
+        // Okabe–Ito: distinguishable under all common color vision deficiencies
+        Color::colorblind_palette().to_vec()
     }
 
     /// Wong palette (Bang Wong's accessibility-tested colorblind-friendly palette)
@@ -545,10 +547,62 @@ impl Theme {
             grid_width: 0.5 * scale,
             tick_width: 0.6 * scale,
             tick_length: 4.0, // Standard tick length
+            minor_tick_width: None,
+            minor_tick_length: None,
         }
     }
 }
 
+/// TOML persistence for sharing a house style across projects
+///
+/// Colors are written as hex strings and missing keys fall back to
+/// [`Theme::default()`], so a style file only needs the values it changes.
+/// `Theme` also implements `serde::Serialize`/`Deserialize` for other formats
+/// such as JSON.
+#[cfg(feature = "serde")]
+impl Theme {
+    /// Parse a theme from a TOML string
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ruviz::prelude::*;
+    ///
+    /// let theme = Theme::from_toml_str(
+    ///     r##"
+    ///     background = "#1a1a2e"
+    ///     color_palette = ["#e94560", "#0f3460"]
+    ///     font_family = "serif"
+    ///     "##,
+    /// )?;
+    /// assert_eq!(theme.background, Color::from_hex("#1a1a2e").unwrap());
+    /// assert_eq!(theme.foreground, Theme::default().foreground);
+    /// # Ok::<(), ruviz::core::PlottingError>(())
+    /// ```
+    pub fn from_toml_str(toml: &str) -> crate::core::Result<Self> {
+        ::toml::from_str(toml)
+            .map_err(|err| crate::core::PlottingError::ThemeError(format!("invalid theme: {err}")))
+    }
+
+    /// Serialize the theme to a TOML string
+    pub fn to_toml_string(&self) -> crate::core::Result<String> {
+        ::toml::to_string(self).map_err(|err| {
+            crate::core::PlottingError::ThemeError(format!("failed to serialize theme: {err}"))
+        })
+    }
+
+    /// Load a theme from a TOML file
+    pub fn from_toml_file<P: AsRef<std::path::Path>>(path: P) -> crate::core::Result<Self> {
+        Self::from_toml_str(&std::fs::read_to_string(path)?)
+    }
+
+    /// Write the theme to a TOML file
+    pub fn to_toml_file<P: AsRef<std::path::Path>>(&self, path: P) -> crate::core::Result<()> {
+        std::fs::write(path, self.to_toml_string()?)?;
+        Ok(())
+    }
+}
+
 impl Default for Theme {
     /// Default theme is the light theme
     fn default() -> Self {
@@ -599,6 +653,13 @@ impl ThemeBuilder {
         self
     }
 
+    /// Set font family and default font size from a [`FontConfig`]
+    ///
+    /// Weight and style are not part of a theme and are ignored.
+    pub fn font_config(self, font: FontConfig) -> Self {
+        self.font(font.family.as_str()).font_size(font.size)
+    }
+
     /// Set default font size
     pub fn font_size(mut self, size: f32) -> Self {
         self.theme.font_size = size.max(6.0);
@@ -721,6 +782,8 @@ mod tests {
         assert_eq!(publication.font_family, "Times New Roman");
         assert_eq!(minimal.font_family, "Helvetica");
         assert!(colorblind.colorblind_friendly);
+        assert_eq!(colorblind.color_palette, Color::colorblind_palette());
+        assert_eq!(colorblind.get_color(8), Color::colorblind_palette()[0]);
     }
 
     #[test]
@@ -811,6 +874,52 @@ mod tests {
         assert!(theme.colorblind_friendly);
     }
 
+    #[test]
+    fn test_theme_builder_starts_from_default_and_accepts_font_config() {
+        let palette = vec![Color::RED, Color::GREEN];
+        let theme = Theme::builder()
+            .grid_color(Color::BLUE)
+            .palette(palette.clone())
+            .font_config(FontConfig::new(crate::render::FontFamily::Serif, 11.0))
+            .build();
+
+        let default = Theme::default();
+        assert_eq!(theme.grid_color, Color::BLUE);
+        assert_eq!(theme.color_palette, palette);
+        assert_eq!(theme.font_family, "serif");
+        assert_eq!(theme.font_size, 11.0);
+        assert_eq!(theme.background, default.background);
+        assert_eq!(theme.title_font_size, default.title_font_size);
+        assert_eq!(
+            theme.to_typography_config().family,
+            crate::render::FontFamily::Serif
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_theme_toml_round_trip_and_partial_files() {
+        let theme = Theme::builder()
+            .background(Color::new_rgba(16, 32, 48, 128))
+            .line_style(LineStyle::Custom(vec![4.0, 2.0]))
+            .font("Fira Sans")
+            .build();
+
+        let toml = theme.to_toml_string().unwrap();
+        assert!(toml.contains("background = \"#10203080\""));
+        assert_eq!(Theme::from_toml_str(&toml).unwrap(), theme);
+
+        let tempdir = tempfile::tempdir().expect("tempdir");
+        let path = tempdir.path().join("theme.toml");
+        theme.to_toml_file(&path).unwrap();
+        assert_eq!(Theme::from_toml_file(&path).unwrap(), theme);
+
+        let partial = Theme::from_toml_str("grid_color = \"#ccc\"").unwrap();
+        assert_eq!(partial.grid_color, Color::new(204, 204, 204));
+        assert_eq!(partial.color_palette, Theme::default().color_palette);
+        assert!(Theme::from_toml_str("background = \"not a color\"").is_err());
+    }
+
     #[test]
     fn test_color_cycling() {
         let theme = Theme::light();
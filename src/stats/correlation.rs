@@ -0,0 +1,105 @@
+//! Correlation analysis
+//!
+//! Provides Pearson correlation coefficients for correlation-matrix heatmaps.
+
+/// Pearson correlation coefficient of `x` and `y`
+///
+/// Only positions where both values are finite are used, like pandas'
+/// pairwise-complete `DataFrame.corr`; extra values in the longer slice are
+/// ignored. Returns NaN when fewer than two pairs remain or either variable
+/// is constant over them.
+pub fn pearson_correlation(x: &[f64], y: &[f64]) -> f64 {
+    let pairs: Vec<(f64, f64)> = x
+        .iter()
+        .zip(y)
+        .map(|(&x, &y)| (x, y))
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .collect();
+    if pairs.len() < 2 {
+        return f64::NAN;
+    }
+
+    let n = pairs.len() as f64;
+    let x_mean = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let y_mean = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let mut ss_xy = 0.0;
+    let mut ss_xx = 0.0;
+    let mut ss_yy = 0.0;
+    for (x, y) in pairs {
+        let dx = x - x_mean;
+        let dy = y - y_mean;
+        ss_xy += dx * dy;
+        ss_xx += dx * dx;
+        ss_yy += dy * dy;
+    }
+
+    if ss_xx <= 0.0 || ss_yy <= 0.0 {
+        return f64::NAN;
+    }
+    // Rounding can push perfectly correlated data just past ±1
+    (ss_xy / (ss_xx * ss_yy).sqrt()).clamp(-1.0, 1.0)
+}
+
+/// Pairwise Pearson correlations between `variables`
+///
+/// Entry `[i][j]` is [`pearson_correlation`] of variables `i` and `j`, so the
+/// matrix is symmetric with ones on the diagonal (NaN for constant
+/// variables).
+pub fn correlation_matrix(variables: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = variables.len();
+    let mut matrix = vec![vec![f64::NAN; n]; n];
+    for i in 0..n {
+        for j in i..n {
+            let r = pearson_correlation(&variables[i], &variables[j]);
+            matrix[i][j] = r;
+            matrix[j][i] = r;
+        }
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pearson_correlation_of_linear_relations() {
+        let x = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let up: Vec<f64> = x.iter().map(|v| 3.0 * v + 1.0).collect();
+        let down: Vec<f64> = x.iter().map(|v| -0.5 * v).collect();
+
+        assert!((pearson_correlation(&x, &up) - 1.0).abs() < 1e-12);
+        assert!((pearson_correlation(&x, &down) + 1.0).abs() < 1e-12);
+        let r = pearson_correlation(&[1.0, 2.0, 3.0, 4.0], &[2.0, 1.0, 4.0, 3.0]);
+        assert!((r - 0.6).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_pearson_correlation_skips_missing_pairs_and_flags_constants() {
+        let x = vec![1.0, f64::NAN, 2.0, 3.0];
+        let y = vec![2.0, 100.0, 4.0, f64::INFINITY];
+        assert!((pearson_correlation(&x, &y) - 1.0).abs() < 1e-12);
+
+        assert!(pearson_correlation(&[1.0, 2.0, 3.0], &[5.0, 5.0, 5.0]).is_nan());
+        assert!(pearson_correlation(&[1.0], &[2.0]).is_nan());
+    }
+
+    #[test]
+    fn test_correlation_matrix_is_symmetric_with_unit_diagonal() {
+        let variables = vec![
+            vec![1.0, 2.0, 3.0, 4.0],
+            vec![2.0, 1.0, 4.0, 3.0],
+            vec![4.0, 3.0, 2.0, 1.0],
+        ];
+        let matrix = correlation_matrix(&variables);
+
+        for (i, row) in matrix.iter().enumerate() {
+            assert_eq!(row[i], 1.0);
+            for (j, &r) in row.iter().enumerate() {
+                assert_eq!(r, matrix[j][i]);
+            }
+        }
+        assert_eq!(matrix[0][2], -1.0);
+    }
+}
@@ -143,7 +143,7 @@ fn gaussian_kernel(x: f64) -> f64 {
 }
 
 /// Scott's rule for bandwidth selection
-fn scotts_rule(data: &[f64]) -> f64 {
+pub(crate) fn scotts_rule(data: &[f64]) -> f64 {
     let n = data.len() as f64;
     if n < 2.0 {
         return 1.0;
@@ -157,6 +157,32 @@ fn scotts_rule(data: &[f64]) -> f64 {
     1.06 * std_dev * n.powf(-0.2)
 }
 
+/// Silverman's rule of thumb for bandwidth selection
+///
+/// Uses the smaller of the standard deviation and the normalized IQR, which
+/// keeps bimodal and heavy-tailed data from being oversmoothed.
+pub(crate) fn silvermans_rule(data: &[f64]) -> f64 {
+    let n = data.len() as f64;
+    if n < 2.0 {
+        return 1.0;
+    }
+
+    let mut sorted = data.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let mean = sorted.iter().sum::<f64>() / n;
+    let variance = sorted.iter().map(|&x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    let std_dev = variance.sqrt();
+    let iqr = crate::plots::statistics::iqr(&sorted);
+    let spread = if iqr > 0.0 {
+        std_dev.min(iqr / 1.34)
+    } else {
+        std_dev
+    };
+
+    // Silverman's rule: h = 0.9 * min(sigma, IQR / 1.34) * n^(-1/5)
+    0.9 * spread * n.powf(-0.2)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,6 +219,20 @@ mod tests {
         assert_eq!(density[0].len(), 10);
     }
 
+    #[test]
+    fn test_silvermans_rule_uses_smaller_of_std_and_iqr() {
+        // Heavy tail: one far outlier inflates the standard deviation, not the IQR
+        let mut data: Vec<f64> = (0..20).map(f64::from).collect();
+        data.push(1000.0);
+        let n: f64 = 21.0;
+        let iqr = crate::plots::statistics::iqr(&data);
+
+        let bw = silvermans_rule(&data);
+
+        assert!((bw - 0.9 * (iqr / 1.34) * n.powf(-0.2)).abs() < 1e-12);
+        assert!(bw < scotts_rule(&data));
+    }
+
     #[test]
     fn test_scotts_rule() {
         // Normal distribution with std=1
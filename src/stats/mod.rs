@@ -3,7 +3,9 @@
 //! This module provides statistical algorithms used by various plot types:
 //! - Kernel density estimation (KDE) for violin and density plots
 //! - Regression analysis for regplot and residplot
+//! - Pearson correlation for correlation-matrix heatmaps
 //! - Quantile calculations for boxen plots
+//! - Moving averages for smoothed line overlays
 //! - Contour extraction using marching squares
 //! - Beeswarm algorithm for non-overlapping point placement
 //! - Hierarchical clustering for clustermaps
@@ -11,13 +13,17 @@
 pub mod beeswarm;
 pub mod clustering;
 pub mod contour;
+pub mod correlation;
 pub mod kde;
 pub mod quantile;
 pub mod regression;
+pub mod smoothing;
 
 pub use beeswarm::beeswarm_positions;
 pub use clustering::{Linkage, LinkageMethod, linkage};
 pub use contour::{ContourLevel, contour_lines, marching_squares};
+pub use correlation::{correlation_matrix, pearson_correlation};
 pub use kde::{KdeResult, gaussian_kde, kde_1d, kde_2d};
 pub use quantile::{letter_values, quantiles};
 pub use regression::{RegressionResult, linear_regression, polynomial_regression};
+pub use smoothing::moving_average;
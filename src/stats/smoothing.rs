@@ -0,0 +1,58 @@
+//! Smoothing
+//!
+//! Provides the centered moving average used by `Plot::line_smoothed`.
+
+/// Centered moving average of `values` over `window` samples
+///
+/// Each output value is the mean of the finite values in a window centered
+/// on its position; even windows reach one sample further forward. The window
+/// shrinks at both ends instead of padding, so the output has the same length
+/// as the input. Windows without any finite value yield NaN, and a `window`
+/// of 0 or 1 returns the input unchanged.
+pub fn moving_average(values: &[f64], window: usize) -> Vec<f64> {
+    if window <= 1 {
+        return values.to_vec();
+    }
+
+    let behind = (window - 1) / 2;
+    let ahead = window / 2;
+    (0..values.len())
+        .map(|i| {
+            let start = i.saturating_sub(behind);
+            let end = (i + ahead + 1).min(values.len());
+            let (sum, count) = values[start..end]
+                .iter()
+                .filter(|v| v.is_finite())
+                .fold((0.0, 0usize), |(sum, count), v| (sum + v, count + 1));
+            if count == 0 {
+                f64::NAN
+            } else {
+                sum / count as f64
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moving_average_shrinks_window_at_edges() {
+        let values = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+
+        assert_eq!(moving_average(&values, 3), vec![1.5, 2.0, 3.0, 4.0, 4.5]);
+        assert_eq!(moving_average(&values, 4), vec![2.0, 2.5, 3.5, 4.0, 4.5]);
+        assert_eq!(moving_average(&values, 1), values);
+        assert_eq!(moving_average(&values, 0), values);
+        assert!(moving_average(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn test_moving_average_skips_non_finite_values() {
+        let smoothed = moving_average(&[1.0, f64::NAN, 3.0, f64::NAN, f64::NAN], 3);
+
+        assert_eq!(&smoothed[..4], &[1.0, 2.0, 3.0, 3.0]);
+        assert!(smoothed[4].is_nan());
+    }
+}
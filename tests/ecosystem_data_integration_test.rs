@@ -100,3 +100,54 @@ fn test_polars_null_policy_drop() {
 
     assert!(result.is_ok(), "drop null policy should allow plotting");
 }
+
+#[cfg(feature = "polars_support")]
+#[test]
+fn test_polars_dataframe_columns_of_mixed_numeric_dtypes() {
+    use polars::prelude::*;
+
+    let df = df! {
+        "i32" => [1i32, 2, 3],
+        "i64" => [10i64, 20, 30],
+        "f32" => [0.5f32, 1.5, 2.5],
+        "f64" => [Some(1.0), None, Some(3.0)],
+        "name" => ["a", "b", "c"],
+    }
+    .unwrap();
+
+    let result = Plot::new()
+        .line(df.column("i32").unwrap(), df.column("i64").unwrap())
+        .scatter(df.column("i32").unwrap(), df.column("f32").unwrap())
+        .render();
+    assert!(
+        result.is_ok(),
+        "integer/float columns should plot: {result:?}"
+    );
+
+    let strict = Plot::new()
+        .line(df.column("i32").unwrap(), df.column("f64").unwrap())
+        .render()
+        .unwrap_err();
+    assert!(
+        matches!(
+            &strict,
+            PlottingError::NullValueNotAllowed { column: Some(column), null_count: 1, .. } if column == "f64"
+        ),
+        "unexpected error: {strict:?}"
+    );
+
+    let filled = Plot::new()
+        .null_policy(NullPolicy::FillNaN)
+        .line(df.column("i32").unwrap(), df.column("f64").unwrap())
+        .render();
+    assert!(filled.is_ok(), "FillNaN should plot nulls as gaps");
+
+    let text = Plot::new()
+        .line(df.column("i32").unwrap(), df.column("name").unwrap())
+        .render()
+        .unwrap_err();
+    assert!(
+        matches!(text, PlottingError::DataTypeUnsupported { .. }),
+        "unexpected error: {text:?}"
+    );
+}
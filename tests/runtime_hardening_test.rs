@@ -92,7 +92,7 @@ fn render_rejects_fill_between_length_mismatch() {
 #[test]
 fn render_rejects_nan_data_before_bounds_calculation() {
     let err = Plot::new()
-        .line(&[0.0, f64::NAN], &[1.0, 2.0])
+        .scatter(&[0.0, f64::NAN], &[1.0, 2.0])
         .render()
         .expect_err("NaN data should fail validation");
 